@@ -1,4 +1,4 @@
-use crate::{service::dex::ExchangeId, Amount, AssetId, Program};
+use crate::{service::dex::ExchangeId, shared::Displayed, Amount, AssetId, Program};
 use alloc::{
 	borrow::Cow,
 	collections::{BTreeMap, VecDeque},
@@ -95,6 +95,179 @@ pub enum Instruction<Payload, Account, Assets> {
 		give: Assets,
 		want: Assets,
 	},
+	/// Execute `then` if the `condition` holds for the current balance of the program,
+	/// `otherwise` if it does not.
+	///
+	/// Branches are executed inline, within the same program and virtual wallet.
+	IfBalance {
+		condition: BalanceCondition,
+		then: VecDeque<Self>,
+		#[serde(skip_serializing_if = "VecDeque::is_empty", default)]
+		otherwise: VecDeque<Self>,
+	},
+	/// Abort the program if the `condition` does not hold for the current balance of the
+	/// program.
+	///
+	/// Used to fail early, for example after an `Exchange` which returned less than expected.
+	Assert {
+		condition: BalanceCondition,
+	},
+	/// Execute `body` while the `condition` holds, at most `max_iterations` times.
+	///
+	/// The condition is evaluated before each iteration. `max_iterations` must not exceed
+	/// [`ExecutionLimits::max_loop_iterations`].
+	Loop {
+		condition: BalanceCondition,
+		max_iterations: u32,
+		body: VecDeque<Self>,
+	},
+}
+
+/// How the balance of the program is compared against the amount in [`BalanceCondition`].
+#[cfg_attr(feature = "std", derive(schemars::JsonSchema))]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Comparison {
+	Less,
+	LessOrEqual,
+	Equal,
+	GreaterOrEqual,
+	Greater,
+}
+
+/// Condition on the balance of `asset_id` held by the program.
+#[cfg_attr(feature = "std", derive(schemars::JsonSchema))]
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct BalanceCondition {
+	pub asset_id: AssetId,
+	pub comparison: Comparison,
+	pub amount: Displayed<u128>,
+}
+
+impl BalanceCondition {
+	/// Whether condition holds for the given `balance` of `asset_id`.
+	pub fn holds(&self, balance: u128) -> bool {
+		let amount = self.amount.0;
+		match self.comparison {
+			Comparison::Less => balance < amount,
+			Comparison::LessOrEqual => balance <= amount,
+			Comparison::Equal => balance == amount,
+			Comparison::GreaterOrEqual => balance >= amount,
+			Comparison::Greater => balance > amount,
+		}
+	}
+}
+
+/// Bounds on control flow, enforced before a program is executed.
+///
+/// Decoding does not check the limits, so that they can change without breaking the wire format.
+/// Interpreters check each program with [`crate::Program::check_execution_limits`] right before
+/// executing it and fail the execution if any limit is exceeded.
+///
+/// A step is a single executed instruction or evaluated condition. Instructions of spawned
+/// programs are not counted, they are bounded by the network they are executed on.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct ExecutionLimits {
+	/// Maximal number of steps program may execute in the worst case.
+	pub max_steps: u64,
+	/// Maximal `max_iterations` of a single `Loop`.
+	pub max_loop_iterations: u32,
+	/// Maximal nesting of `IfBalance` and `Loop`.
+	pub max_depth: u32,
+	/// Gas charged for each executed step.
+	pub gas_per_step: u64,
+}
+
+impl ExecutionLimits {
+	pub const DEFAULT_MAX_STEPS: u64 = 1024;
+	pub const DEFAULT_MAX_LOOP_ITERATIONS: u32 = 64;
+	pub const DEFAULT_MAX_DEPTH: u32 = 8;
+	pub const DEFAULT_GAS_PER_STEP: u64 = 10_000;
+
+	/// Worst case number of steps the `instructions` execute, failing if any limit is exceeded.
+	pub fn check<Payload, Account, Assets>(
+		&self,
+		instructions: &VecDeque<Instruction<Payload, Account, Assets>>,
+	) -> Result<u64, ControlFlowError> {
+		let steps = self.steps(instructions, 0)?;
+		if steps > self.max_steps {
+			return Err(ControlFlowError::TooManySteps { steps, max_steps: self.max_steps })
+		}
+		Ok(steps)
+	}
+
+	/// Worst case gas the `instructions` consume, failing if any limit is exceeded.
+	pub fn gas<Payload, Account, Assets>(
+		&self,
+		instructions: &VecDeque<Instruction<Payload, Account, Assets>>,
+	) -> Result<u64, ControlFlowError> {
+		self.check(instructions).map(|steps| steps.saturating_mul(self.gas_per_step))
+	}
+
+	fn steps<Payload, Account, Assets>(
+		&self,
+		instructions: &VecDeque<Instruction<Payload, Account, Assets>>,
+		depth: u32,
+	) -> Result<u64, ControlFlowError> {
+		instructions.iter().try_fold(0_u64, |total, instruction| {
+			let steps = match instruction {
+				Instruction::Transfer { .. } |
+				Instruction::Call { .. } |
+				Instruction::Spawn { .. } |
+				Instruction::Exchange { .. } |
+				Instruction::Assert { .. } => 1,
+				Instruction::IfBalance { then, otherwise, .. } => {
+					let depth = self.nest(depth)?;
+					let branch = self.steps(then, depth)?.max(self.steps(otherwise, depth)?);
+					branch.saturating_add(1)
+				},
+				Instruction::Loop { max_iterations, body, .. } => {
+					let depth = self.nest(depth)?;
+					if *max_iterations > self.max_loop_iterations {
+						return Err(ControlFlowError::TooManyIterations {
+							iterations: *max_iterations,
+							max_iterations: self.max_loop_iterations,
+						})
+					}
+					// condition is evaluated once more after the last iteration
+					let iteration = self.steps(body, depth)?.saturating_add(1);
+					iteration.saturating_mul((*max_iterations).into()).saturating_add(1)
+				},
+			};
+			Ok(total.saturating_add(steps))
+		})
+	}
+
+	fn nest(&self, depth: u32) -> Result<u32, ControlFlowError> {
+		let depth = depth.saturating_add(1);
+		if depth > self.max_depth {
+			return Err(ControlFlowError::TooDeep { max_depth: self.max_depth })
+		}
+		Ok(depth)
+	}
+}
+
+impl Default for ExecutionLimits {
+	fn default() -> Self {
+		Self {
+			max_steps: Self::DEFAULT_MAX_STEPS,
+			max_loop_iterations: Self::DEFAULT_MAX_LOOP_ITERATIONS,
+			max_depth: Self::DEFAULT_MAX_DEPTH,
+			gas_per_step: Self::DEFAULT_GAS_PER_STEP,
+		}
+	}
+}
+
+/// Program violates [`ExecutionLimits`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ControlFlowError {
+	/// `IfBalance` and `Loop` are nested deeper than allowed
+	TooDeep { max_depth: u32 },
+	/// `Loop` allows more iterations than allowed
+	TooManyIterations { iterations: u32, max_iterations: u32 },
+	/// Program may execute more steps than allowed
+	TooManySteps { steps: u64, max_steps: u64 },
 }
 
 /// Error types for late binding operation
@@ -221,4 +394,78 @@ mod tests {
 		// Bindings not in sorted order.
 		check_err(LateBindingError::InvalidBinding, "<>", &[(1, FOO.clone()), (0, BAR.clone())]);
 	}
+
+	type TestInstruction = Instruction<Vec<u8>, Vec<u8>, crate::Funds>;
+
+	fn condition(comparison: Comparison) -> BalanceCondition {
+		BalanceCondition { asset_id: 1_u128.into(), comparison, amount: Displayed(100) }
+	}
+
+	fn assert_instruction() -> TestInstruction {
+		Instruction::Assert { condition: condition(Comparison::Greater) }
+	}
+
+	#[test]
+	fn test_balance_condition() {
+		assert!(condition(Comparison::Less).holds(99));
+		assert!(!condition(Comparison::Less).holds(100));
+		assert!(condition(Comparison::LessOrEqual).holds(100));
+		assert!(condition(Comparison::Equal).holds(100));
+		assert!(!condition(Comparison::Equal).holds(101));
+		assert!(condition(Comparison::GreaterOrEqual).holds(100));
+		assert!(!condition(Comparison::Greater).holds(100));
+	}
+
+	#[test]
+	fn test_control_flow_json() {
+		let want: TestInstruction = Instruction::Loop {
+			condition: condition(Comparison::GreaterOrEqual),
+			max_iterations: 4,
+			body: VecDeque::from([Instruction::IfBalance {
+				condition: condition(Comparison::Less),
+				then: VecDeque::from([assert_instruction()]),
+				otherwise: VecDeque::new(),
+			}]),
+		};
+		let json = serde_json_wasm::to_string(&want).unwrap();
+		assert!(!json.contains("otherwise"));
+		let got: TestInstruction = serde_json_wasm::from_str(&json).unwrap();
+		assert_eq!(want, got);
+	}
+
+	#[test]
+	fn test_execution_limits() {
+		let limits = ExecutionLimits::default();
+		let looped = |max_iterations, body| -> VecDeque<TestInstruction> {
+			VecDeque::from([Instruction::Loop {
+				condition: condition(Comparison::Greater),
+				max_iterations,
+				body,
+			}])
+		};
+
+		// 3 iterations of condition and body, plus final condition
+		let program = looped(3, VecDeque::from([assert_instruction()]));
+		assert_eq!(limits.check(&program), Ok(7));
+		assert_eq!(limits.gas(&program), Ok(7 * ExecutionLimits::DEFAULT_GAS_PER_STEP));
+
+		// longest branch is taken
+		let program: VecDeque<TestInstruction> = VecDeque::from([Instruction::IfBalance {
+			condition: condition(Comparison::Equal),
+			then: VecDeque::from([assert_instruction(), assert_instruction()]),
+			otherwise: VecDeque::from([assert_instruction()]),
+		}]);
+		assert_eq!(limits.check(&program), Ok(3));
+
+		assert_eq!(
+			limits.check(&looped(65, VecDeque::new())),
+			Err(ControlFlowError::TooManyIterations { iterations: 65, max_iterations: 64 })
+		);
+
+		let nested = looped(64, looped(64, VecDeque::from([assert_instruction()])));
+		assert!(matches!(limits.check(&nested), Err(ControlFlowError::TooManySteps { .. })));
+
+		let deep = (0..9).fold(VecDeque::new(), |body, _| looped(1, body));
+		assert_eq!(limits.check(&deep), Err(ControlFlowError::TooDeep { max_depth: 8 }));
+	}
 }
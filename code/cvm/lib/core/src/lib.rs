@@ -51,6 +51,7 @@ mod protocol;
 pub mod service;
 pub mod shared;
 pub mod transport;
+pub mod validation;
 
 pub use crate::{
 	asset::*, bridge::*, instruction::*, network::*, packet::*, program::*, protocol::*,
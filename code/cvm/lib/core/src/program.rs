@@ -1,4 +1,4 @@
-use crate::prelude::*;
+use crate::{prelude::*, ControlFlowError, ExecutionLimits, Instruction};
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 
@@ -15,3 +15,15 @@ pub struct Program<Instructions> {
 	/// list of instructions to be executed
 	pub instructions: Instructions,
 }
+
+impl<Payload, Account, Assets> Program<VecDeque<Instruction<Payload, Account, Assets>>> {
+	/// Worst case number of steps executing the program takes, failing if it exceeds `limits`.
+	///
+	/// Must be called by interpreters before executing the program.
+	pub fn check_execution_limits(
+		&self,
+		limits: &ExecutionLimits,
+	) -> Result<u64, ControlFlowError> {
+		limits.check(&self.instructions)
+	}
+}
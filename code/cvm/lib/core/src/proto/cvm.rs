@@ -157,6 +157,9 @@ where
 			pb::program::instruction::Instruction::Spawn(s) => s.try_into(),
 			pb::program::instruction::Instruction::Call(c) => c.try_into(),
 			pb::program::instruction::Instruction::Exchange(x) => x.try_into(),
+			pb::program::instruction::Instruction::IfBalance(i) => i.try_into(),
+			pb::program::instruction::Instruction::Assert(a) => a.try_into(),
+			pb::program::instruction::Instruction::Loop(l) => l.try_into(),
 		}
 	}
 }
@@ -222,6 +225,56 @@ where
 	}
 }
 
+impl<TAbiEncoded, TAccount, TAssets> TryFrom<pb::program::IfBalance>
+	for crate::Instruction<TAbiEncoded, TAccount, TAssets>
+where
+	TAbiEncoded: TryFrom<Vec<u8>>,
+	TAccount: TryFrom<Vec<u8>>,
+	TAssets: From<Vec<(crate::AssetId, crate::Amount)>>,
+{
+	type Error = ();
+
+	fn try_from(value: pb::program::IfBalance) -> Result<Self, Self::Error> {
+		Ok(crate::Instruction::IfBalance {
+			condition: value.condition.non_empty()?.try_into()?,
+			then: super::try_from_sequence(value.then)?,
+			otherwise: super::try_from_sequence(value.otherwise)?,
+		})
+	}
+}
+
+impl<TAbiEncoded, TAccount, TAssets> TryFrom<pb::program::Assert>
+	for crate::Instruction<TAbiEncoded, TAccount, TAssets>
+where
+	TAbiEncoded: TryFrom<Vec<u8>>,
+	TAccount: TryFrom<Vec<u8>>,
+	TAssets: From<Vec<(crate::AssetId, crate::Amount)>>,
+{
+	type Error = ();
+
+	fn try_from(value: pb::program::Assert) -> Result<Self, Self::Error> {
+		Ok(crate::Instruction::Assert { condition: value.condition.non_empty()?.try_into()? })
+	}
+}
+
+impl<TAbiEncoded, TAccount, TAssets> TryFrom<pb::program::Loop>
+	for crate::Instruction<TAbiEncoded, TAccount, TAssets>
+where
+	TAbiEncoded: TryFrom<Vec<u8>>,
+	TAccount: TryFrom<Vec<u8>>,
+	TAssets: From<Vec<(crate::AssetId, crate::Amount)>>,
+{
+	type Error = ();
+
+	fn try_from(value: pb::program::Loop) -> Result<Self, Self::Error> {
+		Ok(crate::Instruction::Loop {
+			condition: value.condition.non_empty()?.try_into()?,
+			max_iterations: value.max_iterations,
+			body: super::try_from_sequence(value.body)?,
+		})
+	}
+}
+
 impl<TAbiEncoded, TAccount, TAssets> TryFrom<pb::program::Call>
 	for crate::Instruction<TAbiEncoded, TAccount, TAssets>
 where
@@ -270,6 +323,19 @@ where
 					give: give.into().into_iter().map(|asset| asset.into()).collect(),
 					want: want.into().into_iter().map(|asset| asset.into()).collect(),
 				}),
+			Instruction::IfBalance { condition, then, otherwise } =>
+				Msg::IfBalance(pb::program::IfBalance {
+					condition: Some(condition.into()),
+					then: super::from_sequence(then),
+					otherwise: super::from_sequence(otherwise),
+				}),
+			Instruction::Assert { condition } =>
+				Msg::Assert(pb::program::Assert { condition: Some(condition.into()) }),
+			Instruction::Loop { condition, max_iterations, body } => Msg::Loop(pb::program::Loop {
+				condition: Some(condition.into()),
+				max_iterations,
+				body: super::from_sequence(body),
+			}),
 		};
 		Self { instruction: Some(instruction) }
 	}
@@ -317,6 +383,48 @@ super::define_conversion! {
 	}
 }
 
+super::define_conversion! {
+	(condition: pb::program::BalanceCondition) -> {
+		let comparison = pb::program::Comparison::from_i32(condition.comparison).ok_or(())?;
+		Ok(Self {
+			asset_id: condition.asset_id.non_empty()?.into(),
+			comparison: comparison.into(),
+			amount: condition.amount.non_empty()?.into(),
+		})
+	}
+	(condition: crate::BalanceCondition) -> {
+		Self {
+			asset_id: Some(condition.asset_id.into()),
+			comparison: pb::program::Comparison::from(condition.comparison) as i32,
+			amount: Some(condition.amount.into()),
+		}
+	}
+}
+
+impl From<pb::program::Comparison> for crate::Comparison {
+	fn from(comparison: pb::program::Comparison) -> Self {
+		match comparison {
+			pb::program::Comparison::Less => Self::Less,
+			pb::program::Comparison::LessOrEqual => Self::LessOrEqual,
+			pb::program::Comparison::Equal => Self::Equal,
+			pb::program::Comparison::GreaterOrEqual => Self::GreaterOrEqual,
+			pb::program::Comparison::Greater => Self::Greater,
+		}
+	}
+}
+
+impl From<crate::Comparison> for pb::program::Comparison {
+	fn from(comparison: crate::Comparison) -> Self {
+		match comparison {
+			crate::Comparison::Less => Self::Less,
+			crate::Comparison::LessOrEqual => Self::LessOrEqual,
+			crate::Comparison::Equal => Self::Equal,
+			crate::Comparison::GreaterOrEqual => Self::GreaterOrEqual,
+			crate::Comparison::Greater => Self::Greater,
+		}
+	}
+}
+
 impl From<pb::program::Register> for crate::Register {
 	fn from(reg: pb::program::Register) -> Self {
 		match reg {
@@ -399,3 +507,86 @@ impl From<crate::Amount> for pb::program::Balance {
 		Self { absolute, ratio }
 	}
 }
+
+#[test]
+fn test_control_flow_encoding() {
+	use super::Isomorphism;
+	use crate::{Amount, BalanceCondition, Comparison, Instruction};
+
+	type Program = CVMProgram<Vec<u8>, Vec<u8>, Vec<(crate::AssetId, Amount)>>;
+
+	let condition = |comparison| BalanceCondition {
+		asset_id: 42_u128.into(),
+		comparison,
+		amount: Displayed(1_000),
+	};
+	let transfer = Instruction::Transfer {
+		to: Destination::Tip,
+		assets: vec![(42_u128.into(), Amount::absolute(100))],
+	};
+	let want = Program {
+		tag: b"control flow".to_vec(),
+		instructions: VecDeque::from([
+			Instruction::Assert { condition: condition(Comparison::GreaterOrEqual) },
+			Instruction::IfBalance {
+				condition: condition(Comparison::Less),
+				then: VecDeque::from([transfer.clone()]),
+				otherwise: VecDeque::new(),
+			},
+			Instruction::Loop {
+				condition: condition(Comparison::Greater),
+				max_iterations: 3,
+				body: VecDeque::from([Instruction::IfBalance {
+					condition: condition(Comparison::Equal),
+					then: VecDeque::new(),
+					otherwise: VecDeque::from([transfer]),
+				}]),
+			},
+		]),
+	};
+	let got = Program::decode(want.clone().encode().as_slice()).unwrap();
+	assert_eq!(want, got);
+}
+
+#[test]
+fn test_decoding_does_not_enforce_execution_limits() {
+	use super::Isomorphism;
+	use crate::{
+		Amount, BalanceCondition, Comparison, ControlFlowError, ExecutionLimits, Instruction,
+	};
+
+	type Program = CVMProgram<Vec<u8>, Vec<u8>, Vec<(crate::AssetId, Amount)>>;
+
+	let looping = |max_iterations| Program {
+		tag: Vec::new(),
+		instructions: VecDeque::from([Instruction::Loop {
+			condition: BalanceCondition {
+				asset_id: 42_u128.into(),
+				comparison: Comparison::Greater,
+				amount: Displayed(0),
+			},
+			max_iterations,
+			body: VecDeque::from([Instruction::Transfer {
+				to: Destination::Tip,
+				assets: vec![(42_u128.into(), Amount::absolute(1))],
+			}]),
+		}]),
+	};
+	let limits = ExecutionLimits::default();
+	let allowed = looping(ExecutionLimits::DEFAULT_MAX_LOOP_ITERATIONS);
+	assert_eq!(Program::decode(allowed.clone().encode().as_slice()).unwrap(), allowed);
+	assert!(allowed.check_execution_limits(&limits).is_ok());
+
+	// limits may change between versions, so programs exceeding them still decode and are
+	// rejected by the interpreter executing them
+	let unbounded = looping(ExecutionLimits::DEFAULT_MAX_LOOP_ITERATIONS + 1);
+	let decoded = Program::decode(unbounded.clone().encode().as_slice()).unwrap();
+	assert_eq!(decoded, unbounded);
+	assert_eq!(
+		decoded.check_execution_limits(&limits),
+		Err(ControlFlowError::TooManyIterations {
+			iterations: ExecutionLimits::DEFAULT_MAX_LOOP_ITERATIONS + 1,
+			max_iterations: ExecutionLimits::DEFAULT_MAX_LOOP_ITERATIONS,
+		})
+	);
+}
@@ -1,14 +1,70 @@
-use crate::{prelude::*, NetworkId};
+use crate::{prelude::*, shared::Displayed, Bindings, NetworkId};
 
 pub mod osmosis_std;
 
-pub type ExchangeId = crate::shared::Displayed<u128>;
+pub type ExchangeId = Displayed<u128>;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "std", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum ExchangeType {
-	OsmosisCrossChainSwap { pool_id: u64, token_a: String, token_b: String },
+	OsmosisCrossChainSwap {
+		pool_id: u64,
+		token_a: String,
+		token_b: String,
+	},
+	/// Pablo pool on Picasso or Composable.
+	///
+	/// Swapped by `Swap` message of DEX precompile at `contract`, which dispatches to the `Amm`
+	/// of the runtime (`dex-router` or `pablo` directly).
+	Pablo {
+		contract: String,
+		pool_id: Displayed<u128>,
+		token_a: String,
+		token_b: String,
+	},
+	/// Astroport compatible CosmWasm pair contract.
+	///
+	/// Swapped by `Swap` message of the pair, with `give` sent as funds or CW20 `Send`.
+	AstroportPair {
+		contract: String,
+		token_a: String,
+		token_b: String,
+	},
+	/// Any other venue, exchange is executed as `Call` instruction.
+	///
+	/// `bindings` are applied to `payload` same as for `Call`, `give` is sent as funds along.
+	Call {
+		/// If JSON, than hex encoded non prefixed lower case string.
+		#[serde(serialize_with = "hex::serialize", deserialize_with = "hex::deserialize")]
+		#[cfg_attr(feature = "std", schemars(schema_with = "String::json_schema"))]
+		payload: Vec<u8>,
+		#[serde(skip_serializing_if = "Vec::is_empty", default)]
+		bindings: Bindings,
+	},
+}
+
+impl ExchangeType {
+	/// Local denominations (as in `AssetReference::denom`) of assets which can be swapped, if
+	/// known.
+	pub fn pair(&self) -> Option<(&str, &str)> {
+		match self {
+			Self::OsmosisCrossChainSwap { token_a, token_b, .. } |
+			Self::Pablo { token_a, token_b, .. } |
+			Self::AstroportPair { token_a, token_b, .. } => Some((token_a, token_b)),
+			Self::Call { .. } => None,
+		}
+	}
+
+	/// Whether `give` can be swapped to `want` on this exchange.
+	///
+	/// Adapters are not introspectable, so they are assumed to support any pair.
+	pub fn supports(&self, give: &str, want: &str) -> bool {
+		match self.pair() {
+			Some((a, b)) => (a == give && b == want) || (a == want && b == give),
+			None => true,
+		}
+	}
 }
 
 /// allows to execute Exchange instruction
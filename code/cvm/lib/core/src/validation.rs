@@ -0,0 +1,848 @@
+//! Static validation of programs before they are sent across chains.
+//!
+//! Validation does not execute anything, it walks the program against a snapshot of gateway
+//! configuration and reports everything which will fail (or likely fail) on the way.
+use crate::{
+	gateway::{AssetItem, ConfigSubMsg, NetworkItem, OtherNetworkItem, RelativeTimeout},
+	prelude::*,
+	service::dex::{ExchangeId, ExchangeItem},
+	shared::{Displayed, XcFundsFilter, XcInstruction, XcProgram},
+	Amount, AssetId, BalanceCondition, ControlFlowError, ExecutionLimits, Instruction, NetworkId,
+};
+use alloc::collections::{BTreeMap, BTreeSet};
+
+/// Programs nested deeper than this are rejected, so that validation is bounded.
+pub const MAX_SPAWN_DEPTH: u32 = 16;
+
+/// Snapshot of gateway configuration programs are validated against.
+///
+/// Usually built from the same `ConfigSubMsg`s which were used to configure gateways.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ValidationContext {
+	pub networks: BTreeMap<NetworkId, NetworkItem>,
+	/// (from, to) connectivity
+	pub network_to_network: BTreeMap<(NetworkId, NetworkId), OtherNetworkItem>,
+	/// (network, asset) registered assets
+	pub assets: BTreeMap<(NetworkId, AssetId), AssetItem>,
+	/// (this asset, other network) => other asset
+	pub asset_to_network: BTreeMap<(AssetId, NetworkId), AssetId>,
+	pub exchanges: BTreeMap<ExchangeId, ExchangeItem>,
+	/// (from, to) fees paid to relay a spawn, if known
+	pub hop_fees: BTreeMap<(NetworkId, NetworkId), Vec<(AssetId, Displayed<u128>)>>,
+	/// limits control flow of each program is checked against
+	pub limits: ExecutionLimits,
+}
+
+impl ValidationContext {
+	/// Builds context from configuration messages, later messages override earlier ones.
+	pub fn from_config<'a>(messages: impl IntoIterator<Item = &'a ConfigSubMsg>) -> Self {
+		let mut this = Self::default();
+		messages.into_iter().for_each(|message| this.apply(message));
+		this
+	}
+
+	pub fn apply(&mut self, message: &ConfigSubMsg) {
+		match message {
+			ConfigSubMsg::ForceNetwork(network) => {
+				self.networks.insert(network.network_id, network.clone());
+			},
+			ConfigSubMsg::ForceNetworkToNetwork(msg) => {
+				self.network_to_network.insert((msg.from, msg.to), msg.other.clone());
+			},
+			ConfigSubMsg::ForceAsset(asset) => {
+				self.assets.insert((asset.network_id, asset.asset_id), asset.clone());
+			},
+			ConfigSubMsg::ForceAssetToNetworkMap { this_asset, other_network, other_asset } => {
+				self.asset_to_network.insert((*this_asset, *other_network), *other_asset);
+			},
+			ConfigSubMsg::ForceExchange(exchange) => {
+				self.exchanges.insert(exchange.exchange_id, exchange.clone());
+			},
+			ConfigSubMsg::ForceRemoveAsset { asset_id } => {
+				self.assets.retain(|(_, id), _| id != asset_id);
+			},
+			ConfigSubMsg::Force(messages) =>
+				messages.iter().for_each(|message| self.apply(message)),
+			ConfigSubMsg::ForceInstantiate { .. } => {},
+		}
+	}
+
+	/// Sets fees paid when spawning from `from` to `to`.
+	pub fn with_hop_fee(
+		mut self,
+		from: NetworkId,
+		to: NetworkId,
+		fees: Vec<(AssetId, Displayed<u128>)>,
+	) -> Self {
+		self.hop_fees.insert((from, to), fees);
+		self
+	}
+
+	/// Validates `program` which starts on `network_id`.
+	///
+	/// `funds` are assets the program is funded with, when known overdraws are checked against
+	/// them.
+	pub fn validate(
+		&self,
+		network_id: NetworkId,
+		program: &XcProgram,
+		funds: Option<&[(AssetId, Displayed<u128>)]>,
+	) -> ValidationReport {
+		let mut report = ValidationReport::default();
+		if !self.networks.contains_key(&network_id) {
+			report.error(&[], network_id, DiagnosticKind::UnknownNetwork { network_id });
+		}
+		let balances = funds.map(|funds| {
+			funds.iter().fold(BTreeMap::new(), |mut balances, (asset_id, amount)| {
+				let balance = balances.entry(*asset_id).or_insert(0_u128);
+				*balance = balance.saturating_add(amount.0);
+				balances
+			})
+		});
+		self.validate_program(&mut report, &mut Vec::new(), network_id, program, balances, 0, 0);
+		report
+	}
+
+	#[allow(clippy::too_many_arguments)]
+	fn validate_program(
+		&self,
+		report: &mut ValidationReport,
+		path: &mut Vec<u32>,
+		network_id: NetworkId,
+		program: &XcProgram,
+		mut balances: Option<BTreeMap<AssetId, u128>>,
+		elapsed_seconds: u64,
+		spawn_depth: u32,
+	) {
+		if spawn_depth > MAX_SPAWN_DEPTH {
+			report.error(path, network_id, DiagnosticKind::MaxDepthExceeded);
+			return
+		}
+		if let Err(err) = self.limits.check(&program.instructions) {
+			report.error(path, network_id, err.into());
+		}
+		self.validate_instructions(
+			report,
+			path,
+			network_id,
+			&program.instructions,
+			&mut balances,
+			elapsed_seconds,
+			spawn_depth,
+		);
+	}
+
+	#[allow(clippy::too_many_arguments)]
+	fn validate_instructions(
+		&self,
+		report: &mut ValidationReport,
+		path: &mut Vec<u32>,
+		network_id: NetworkId,
+		instructions: &VecDeque<XcInstruction>,
+		balances: &mut Option<BTreeMap<AssetId, u128>>,
+		elapsed_seconds: u64,
+		spawn_depth: u32,
+	) {
+		for (index, instruction) in instructions.iter().enumerate() {
+			path.push(index as u32);
+			self.validate_instruction(
+				report,
+				path,
+				network_id,
+				instruction,
+				balances,
+				elapsed_seconds,
+				spawn_depth,
+			);
+			path.pop();
+		}
+	}
+
+	#[allow(clippy::too_many_arguments)]
+	fn validate_instruction(
+		&self,
+		report: &mut ValidationReport,
+		path: &mut Vec<u32>,
+		network_id: NetworkId,
+		instruction: &XcInstruction,
+		balances: &mut Option<BTreeMap<AssetId, u128>>,
+		elapsed_seconds: u64,
+		spawn_depth: u32,
+	) {
+		match instruction {
+			Instruction::Transfer { assets, .. } => {
+				self.check_assets(report, path, network_id, assets);
+				Self::withdraw(report, path, network_id, assets, balances);
+			},
+			Instruction::Call { bindings, .. } =>
+				if bindings.windows(2).any(|pair| matches!(pair, [(a, _), (b, _)] if a > b)) {
+					report.error(path, network_id, DiagnosticKind::UnsortedBindings);
+				},
+			Instruction::Exchange { exchange_id, give, want } => {
+				match self.exchanges.get(exchange_id) {
+					Some(exchange) if exchange.network_id == network_id => {
+						let denom = |assets: &XcFundsFilter| match assets.0.as_slice() {
+							[(asset_id, _)] => self
+								.assets
+								.get(&(network_id, *asset_id))
+								.map(|asset| (*asset_id, asset.local.denom())),
+							_ => None,
+						};
+						if let (Some((give, give_denom)), Some((want, want_denom))) =
+							(denom(give), denom(want))
+						{
+							if !exchange.exchange.supports(&give_denom, &want_denom) {
+								report.error(
+									path,
+									network_id,
+									DiagnosticKind::UnsupportedPair {
+										exchange_id: *exchange_id,
+										give,
+										want,
+									},
+								);
+							}
+						}
+					},
+					Some(exchange) => report.error(
+						path,
+						network_id,
+						DiagnosticKind::ExchangeOnOtherNetwork {
+							exchange_id: *exchange_id,
+							exchange_network_id: exchange.network_id,
+						},
+					),
+					None => report.error(
+						path,
+						network_id,
+						DiagnosticKind::UnknownExchange { exchange_id: *exchange_id },
+					),
+				}
+				self.check_assets(report, path, network_id, give);
+				self.check_assets(report, path, network_id, want);
+				Self::withdraw(report, path, network_id, give, balances);
+				// amount received is known only on execution
+				if let Some(balances) = balances {
+					want.0.iter().for_each(|(asset_id, _)| {
+						balances.remove(asset_id);
+					});
+				}
+			},
+			Instruction::Spawn { network_id: to, assets, program, .. } => {
+				let to = *to;
+				self.check_assets(report, path, network_id, assets);
+				let sent = Self::withdraw(report, path, network_id, assets, balances);
+
+				let timeout_seconds = match self.network_to_network.get(&(network_id, to)) {
+					Some(connection) => {
+						if connection.ics_20.is_none() && connection.ics27_channel.is_none() {
+							report.error(
+								path,
+								network_id,
+								DiagnosticKind::NoRoute { from: network_id, to },
+							);
+						}
+						match connection.counterparty_timeout {
+							RelativeTimeout::Seconds(seconds) => u64::from(seconds),
+						}
+					},
+					None => {
+						report.error(
+							path,
+							network_id,
+							DiagnosticKind::NoRoute { from: network_id, to },
+						);
+						0
+					},
+				};
+				match self.networks.get(&to) {
+					Some(network) if network.gateway.is_some() => {},
+					Some(_) =>
+						report.error(path, network_id, DiagnosticKind::NoGateway { network_id: to }),
+					None => report.error(
+						path,
+						network_id,
+						DiagnosticKind::UnknownNetwork { network_id: to },
+					),
+				}
+
+				let mut child_balances = sent.as_ref().map(|_| BTreeMap::new());
+				for (asset_id, _) in assets.0.iter() {
+					match self.asset_to_network.get(&(*asset_id, to)) {
+						Some(other_asset) => {
+							if let (Some(child), Some(sent)) = (&mut child_balances, &sent) {
+								if let Some(amount) = sent.get(asset_id) {
+									child.insert(*other_asset, *amount);
+								}
+							}
+						},
+						None => report.error(
+							path,
+							network_id,
+							DiagnosticKind::AssetNotRoutable {
+								asset_id: *asset_id,
+								from: network_id,
+								to,
+							},
+						),
+					}
+				}
+
+				let elapsed_seconds = elapsed_seconds.saturating_add(timeout_seconds);
+				report.hops.push(HopEstimate {
+					path: path.clone(),
+					from: network_id,
+					to,
+					timeout_seconds,
+					elapsed_timeout_seconds: elapsed_seconds,
+					fees: self.hop_fees.get(&(network_id, to)).cloned().unwrap_or_default(),
+				});
+				report.total_timeout_seconds =
+					u64::max(report.total_timeout_seconds, elapsed_seconds);
+				self.validate_program(
+					report,
+					path,
+					to,
+					program,
+					child_balances,
+					elapsed_seconds,
+					spawn_depth + 1,
+				);
+			},
+			Instruction::Assert { condition } =>
+				self.check_condition(report, path, network_id, condition),
+			Instruction::IfBalance { condition, then, otherwise } => {
+				self.check_condition(report, path, network_id, condition);
+				for (branch, instructions) in [then, otherwise].into_iter().enumerate() {
+					path.push(branch as u32);
+					self.validate_instructions(
+						report,
+						path,
+						network_id,
+						instructions,
+						&mut balances.clone(),
+						elapsed_seconds,
+						spawn_depth,
+					);
+					path.pop();
+				}
+				// which branch is taken is known only on execution
+				*balances = None;
+			},
+			Instruction::Loop { condition, body, .. } => {
+				self.check_condition(report, path, network_id, condition);
+				// number of iterations is known only on execution
+				*balances = None;
+				self.validate_instructions(
+					report,
+					path,
+					network_id,
+					body,
+					balances,
+					elapsed_seconds,
+					spawn_depth,
+				);
+			},
+		}
+	}
+
+	fn check_condition(
+		&self,
+		report: &mut ValidationReport,
+		path: &[u32],
+		network_id: NetworkId,
+		condition: &BalanceCondition,
+	) {
+		if !self.assets.contains_key(&(network_id, condition.asset_id)) {
+			report.error(
+				path,
+				network_id,
+				DiagnosticKind::UnknownAsset { asset_id: condition.asset_id, network_id },
+			);
+		}
+	}
+
+	fn check_assets(
+		&self,
+		report: &mut ValidationReport,
+		path: &[u32],
+		network_id: NetworkId,
+		assets: &XcFundsFilter,
+	) {
+		let mut seen = BTreeSet::new();
+		for (asset_id, amount) in assets.0.iter() {
+			if !seen.insert(*asset_id) {
+				report.error(
+					path,
+					network_id,
+					DiagnosticKind::DuplicateAsset { asset_id: *asset_id },
+				);
+			}
+			if !self.assets.contains_key(&(network_id, *asset_id)) {
+				report.error(
+					path,
+					network_id,
+					DiagnosticKind::UnknownAsset { asset_id: *asset_id, network_id },
+				);
+			}
+			if amount.slope.0 > Amount::MAX_PARTS {
+				report.error(
+					path,
+					network_id,
+					DiagnosticKind::SlopeOverflow { asset_id: *asset_id, slope: amount.slope },
+				);
+			}
+			if amount.intercept.0 == 0 && amount.slope.0 == 0 {
+				report.warning(
+					path,
+					network_id,
+					DiagnosticKind::ZeroAmount { asset_id: *asset_id },
+				);
+			}
+		}
+	}
+
+	/// Subtracts `assets` from known balances, returning amounts withdrawn when all are known.
+	fn withdraw(
+		report: &mut ValidationReport,
+		path: &[u32],
+		network_id: NetworkId,
+		assets: &XcFundsFilter,
+		balances: &mut Option<BTreeMap<AssetId, u128>>,
+	) -> Option<BTreeMap<AssetId, u128>> {
+		let balances = balances.as_mut()?;
+		let mut withdrawn = BTreeMap::new();
+		let mut all_known = true;
+		for (asset_id, amount) in assets.0.iter() {
+			let Some(available) = balances.get(asset_id).copied() else {
+				all_known = false;
+				continue
+			};
+			let partial = amount.slope.0 != 0 && amount.slope.0 != Amount::MAX_PARTS;
+			if amount.intercept.0 > available {
+				let kind = DiagnosticKind::Overdraw {
+					asset_id: *asset_id,
+					requested: amount.intercept,
+					available: Displayed(available),
+				};
+				if partial {
+					// `Amount::apply` underflows
+					report.error(path, network_id, kind);
+				} else if amount.slope.0 == 0 {
+					// absolute amount is capped by balance
+					report.warning(path, network_id, kind);
+				}
+			}
+			match amount.apply(available) {
+				Ok(taken) => {
+					balances.insert(*asset_id, available.saturating_sub(taken));
+					withdrawn.insert(*asset_id, taken);
+				},
+				Err(_) => {
+					balances.remove(asset_id);
+					all_known = false;
+				},
+			}
+		}
+		all_known.then_some(withdrawn)
+	}
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "std", derive(schemars::JsonSchema))]
+pub enum Severity {
+	/// Program will fail
+	Error,
+	/// Program may behave not as expected
+	Warning,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "std", derive(schemars::JsonSchema))]
+pub enum DiagnosticKind {
+	UnknownNetwork {
+		network_id: NetworkId,
+	},
+	/// Network has no gateway to execute spawned program
+	NoGateway {
+		network_id: NetworkId,
+	},
+	/// There is no IBC connection configured between networks
+	NoRoute {
+		from: NetworkId,
+		to: NetworkId,
+	},
+	UnknownAsset {
+		asset_id: AssetId,
+		network_id: NetworkId,
+	},
+	/// Asset has no mapping on target network
+	AssetNotRoutable {
+		asset_id: AssetId,
+		from: NetworkId,
+		to: NetworkId,
+	},
+	DuplicateAsset {
+		asset_id: AssetId,
+	},
+	UnknownExchange {
+		exchange_id: ExchangeId,
+	},
+	ExchangeOnOtherNetwork {
+		exchange_id: ExchangeId,
+		exchange_network_id: NetworkId,
+	},
+	/// Exchange does not swap between these assets
+	UnsupportedPair {
+		exchange_id: ExchangeId,
+		give: AssetId,
+		want: AssetId,
+	},
+	/// Slope is more than 100%
+	SlopeOverflow {
+		asset_id: AssetId,
+		slope: Displayed<u64>,
+	},
+	/// Absolute amount is more than available
+	Overdraw {
+		asset_id: AssetId,
+		requested: Displayed<u128>,
+		available: Displayed<u128>,
+	},
+	ZeroAmount {
+		asset_id: AssetId,
+	},
+	/// Call bindings must be sorted by index
+	UnsortedBindings,
+	MaxDepthExceeded,
+	/// `IfBalance` and `Loop` are nested deeper than allowed
+	ControlFlowTooDeep {
+		max_depth: u32,
+	},
+	/// `Loop` allows more iterations than allowed
+	TooManyIterations {
+		iterations: u32,
+		max_iterations: u32,
+	},
+	/// Program may execute more steps than allowed
+	TooManySteps {
+		steps: u64,
+		max_steps: u64,
+	},
+}
+
+impl From<ControlFlowError> for DiagnosticKind {
+	fn from(err: ControlFlowError) -> Self {
+		match err {
+			ControlFlowError::TooDeep { max_depth } => Self::ControlFlowTooDeep { max_depth },
+			ControlFlowError::TooManyIterations { iterations, max_iterations } =>
+				Self::TooManyIterations { iterations, max_iterations },
+			ControlFlowError::TooManySteps { steps, max_steps } =>
+				Self::TooManySteps { steps, max_steps },
+		}
+	}
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "std", derive(schemars::JsonSchema))]
+pub struct Diagnostic {
+	pub severity: Severity,
+	/// indexes of instructions, one per nesting level of spawned programs and loops,
+	/// `IfBalance` adds branch index (0 for `then`, 1 for `otherwise`) before instruction index
+	pub path: Vec<u32>,
+	/// network instruction is executed on
+	pub network_id: NetworkId,
+	pub kind: DiagnosticKind,
+}
+
+/// Estimation of one spawn of program to other network.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "std", derive(schemars::JsonSchema))]
+pub struct HopEstimate {
+	pub path: Vec<u32>,
+	pub from: NetworkId,
+	pub to: NetworkId,
+	/// counterparty timeout configured for the connection
+	pub timeout_seconds: u64,
+	/// sum of timeouts of all hops up to and including this one
+	pub elapsed_timeout_seconds: u64,
+	pub fees: Vec<(AssetId, Displayed<u128>)>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "std", derive(schemars::JsonSchema))]
+pub struct ValidationReport {
+	pub diagnostics: Vec<Diagnostic>,
+	pub hops: Vec<HopEstimate>,
+	/// worst case time until program finishes or times out
+	pub total_timeout_seconds: u64,
+}
+
+impl ValidationReport {
+	/// No errors were found, warnings are allowed.
+	pub fn is_valid(&self) -> bool {
+		!self.diagnostics.iter().any(|diagnostic| diagnostic.severity == Severity::Error)
+	}
+
+	/// Sums fees of all hops per asset.
+	pub fn total_fees(&self) -> Vec<(AssetId, Displayed<u128>)> {
+		self.hops
+			.iter()
+			.flat_map(|hop| hop.fees.iter())
+			.fold(BTreeMap::<AssetId, u128>::new(), |mut total, (asset_id, amount)| {
+				let entry = total.entry(*asset_id).or_default();
+				*entry = entry.saturating_add(amount.0);
+				total
+			})
+			.into_iter()
+			.map(|(asset_id, amount)| (asset_id, Displayed(amount)))
+			.collect()
+	}
+
+	fn error(&mut self, path: &[u32], network_id: NetworkId, kind: DiagnosticKind) {
+		self.push(Severity::Error, path, network_id, kind)
+	}
+
+	fn warning(&mut self, path: &[u32], network_id: NetworkId, kind: DiagnosticKind) {
+		self.push(Severity::Warning, path, network_id, kind)
+	}
+
+	fn push(
+		&mut self,
+		severity: Severity,
+		path: &[u32],
+		network_id: NetworkId,
+		kind: DiagnosticKind,
+	) {
+		self.diagnostics
+			.push(Diagnostic { severity, path: path.to_vec(), network_id, kind })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		gateway::{AssetReference, ForceNetworkToNetworkMsg, GatewayId, IcsPair},
+		Destination, Funds, Program,
+	};
+	use ibc_rs_scale::core::ics24_host::identifier::ChannelId;
+
+	const PICASSO: NetworkId = NetworkId(1);
+	const CENTAURI: NetworkId = NetworkId(2);
+	const PICA_ON_PICASSO: u128 = 1;
+	const PICA_ON_CENTAURI: u128 = 2;
+
+	fn network(network_id: NetworkId) -> ConfigSubMsg {
+		ConfigSubMsg::ForceNetwork(NetworkItem {
+			network_id,
+			gateway: Some(GatewayId::CosmWasm {
+				contract: Addr::unchecked("gateway"),
+				interpreter_code_id: 1,
+				admin: Addr::unchecked("admin"),
+			}),
+			accounts: None,
+			ibc: None,
+		})
+	}
+
+	fn asset(asset_id: u128, network_id: NetworkId) -> ConfigSubMsg {
+		ConfigSubMsg::ForceAsset(AssetItem {
+			asset_id: asset_id.into(),
+			network_id,
+			local: AssetReference::Native { denom: "ppica".to_string() },
+			bridged: None,
+		})
+	}
+
+	fn context() -> ValidationContext {
+		ValidationContext::from_config(&[
+			network(PICASSO),
+			network(CENTAURI),
+			ConfigSubMsg::ForceNetworkToNetwork(ForceNetworkToNetworkMsg {
+				from: PICASSO,
+				to: CENTAURI,
+				other: OtherNetworkItem {
+					ics_20: Some(IcsPair { source: ChannelId::new(0), sink: ChannelId::new(1) }),
+					counterparty_timeout: RelativeTimeout::Seconds(600),
+					ics27_channel: None,
+					use_shortcut: None,
+				},
+			}),
+			asset(PICA_ON_PICASSO, PICASSO),
+			asset(PICA_ON_CENTAURI, CENTAURI),
+			ConfigSubMsg::ForceAssetToNetworkMap {
+				this_asset: PICA_ON_PICASSO.into(),
+				other_network: CENTAURI,
+				other_asset: PICA_ON_CENTAURI.into(),
+			},
+		])
+	}
+
+	fn spawn(network_id: NetworkId, assets: XcFundsFilter, inner: Vec<XcInstruction>) -> XcProgram {
+		Program {
+			tag: vec![],
+			instructions: [Instruction::Spawn {
+				network_id,
+				salt: vec![],
+				assets,
+				program: Program { tag: vec![], instructions: inner.into() },
+			}]
+			.into(),
+		}
+	}
+
+	#[test]
+	fn valid_spawn_is_estimated() {
+		let context = context().with_hop_fee(
+			PICASSO,
+			CENTAURI,
+			vec![(PICA_ON_PICASSO.into(), Displayed(10))],
+		);
+		let program = spawn(
+			CENTAURI,
+			Funds::one(PICA_ON_PICASSO.into(), Amount::everything()),
+			vec![Instruction::Transfer {
+				to: Destination::Tip,
+				assets: Funds::one(PICA_ON_CENTAURI.into(), 100_u128),
+			}],
+		);
+		let report =
+			context.validate(PICASSO, &program, Some(&[(PICA_ON_PICASSO.into(), Displayed(1000))]));
+		assert!(report.is_valid(), "{:?}", report.diagnostics);
+		assert_eq!(report.hops.len(), 1);
+		assert_eq!(report.total_timeout_seconds, 600);
+		assert_eq!(report.total_fees(), vec![(PICA_ON_PICASSO.into(), Displayed(10))]);
+	}
+
+	#[test]
+	fn unreachable_network_and_unmapped_asset() {
+		let program =
+			spawn(NetworkId(3), Funds::one(PICA_ON_PICASSO.into(), Amount::everything()), vec![]);
+		let report = context().validate(PICASSO, &program, None);
+		assert!(!report.is_valid());
+		let kinds: Vec<_> = report.diagnostics.into_iter().map(|d| d.kind).collect();
+		assert!(kinds.contains(&DiagnosticKind::NoRoute { from: PICASSO, to: NetworkId(3) }));
+		assert!(kinds.contains(&DiagnosticKind::UnknownNetwork { network_id: NetworkId(3) }));
+		assert!(kinds.contains(&DiagnosticKind::AssetNotRoutable {
+			asset_id: PICA_ON_PICASSO.into(),
+			from: PICASSO,
+			to: NetworkId(3),
+		}));
+	}
+
+	#[test]
+	fn overdraw_is_detected() {
+		let program = spawn(
+			CENTAURI,
+			Funds::one(PICA_ON_PICASSO.into(), Amount::new(500, Amount::MAX_PARTS / 2)),
+			vec![],
+		);
+		let report = context().validate(
+			PICASSO,
+			&program,
+			Some(&[(PICA_ON_PICASSO.into(), Displayed(100))]),
+		);
+		assert!(!report.is_valid());
+		assert!(matches!(
+			report.diagnostics[0].kind,
+			DiagnosticKind::Overdraw { requested: Displayed(500), available: Displayed(100), .. }
+		));
+
+		let program = spawn(
+			CENTAURI,
+			Funds::one(PICA_ON_PICASSO.into(), Amount::ratio(Amount::MAX_PARTS + 1)),
+			vec![],
+		);
+		let report = context().validate(PICASSO, &program, None);
+		assert!(matches!(report.diagnostics[0].kind, DiagnosticKind::SlopeOverflow { .. }));
+	}
+
+	#[test]
+	fn control_flow_is_checked() {
+		let condition = |asset_id: u128| BalanceCondition {
+			asset_id: asset_id.into(),
+			comparison: crate::Comparison::Less,
+			amount: Displayed(100),
+		};
+		let program = spawn(
+			CENTAURI,
+			Funds::one(PICA_ON_PICASSO.into(), Amount::everything()),
+			vec![
+				Instruction::IfBalance {
+					condition: condition(PICA_ON_CENTAURI),
+					then: [Instruction::Assert { condition: condition(PICA_ON_PICASSO) }].into(),
+					otherwise: Default::default(),
+				},
+				Instruction::Loop {
+					condition: condition(PICA_ON_CENTAURI),
+					max_iterations: ExecutionLimits::DEFAULT_MAX_LOOP_ITERATIONS + 1,
+					body: Default::default(),
+				},
+			],
+		);
+		let report = context().validate(PICASSO, &program, None);
+		assert!(!report.is_valid());
+		let diagnostics: Vec<_> =
+			report.diagnostics.into_iter().map(|d| (d.path, d.kind)).collect();
+		assert_eq!(
+			diagnostics,
+			vec![
+				(
+					vec![0],
+					DiagnosticKind::TooManyIterations {
+						iterations: ExecutionLimits::DEFAULT_MAX_LOOP_ITERATIONS + 1,
+						max_iterations: ExecutionLimits::DEFAULT_MAX_LOOP_ITERATIONS,
+					}
+				),
+				(
+					vec![0, 0, 0, 0],
+					DiagnosticKind::UnknownAsset {
+						asset_id: PICA_ON_PICASSO.into(),
+						network_id: CENTAURI,
+					}
+				),
+			]
+		);
+	}
+
+	#[test]
+	fn exchange_pair_is_checked() {
+		let mut context = context();
+		context.apply(&ConfigSubMsg::ForceExchange(ExchangeItem {
+			exchange_id: Displayed(1),
+			network_id: PICASSO,
+			exchange: crate::service::dex::ExchangeType::Pablo {
+				contract: "pablo".to_string(),
+				pool_id: Displayed(0),
+				token_a: "ppica".to_string(),
+				token_b: "usdt".to_string(),
+			},
+		}));
+		// both sides are `ppica`, which pool does not swap
+		let program = Program {
+			tag: vec![],
+			instructions: [Instruction::Exchange {
+				exchange_id: Displayed(1),
+				give: Funds::one(PICA_ON_PICASSO.into(), Amount::everything()),
+				want: Funds::one(PICA_ON_PICASSO.into(), Amount::absolute(1)),
+			}]
+			.into(),
+		};
+		let report = context.validate(PICASSO, &program, None);
+		assert!(!report.is_valid());
+		assert_eq!(
+			report.diagnostics[0].kind,
+			DiagnosticKind::UnsupportedPair {
+				exchange_id: Displayed(1),
+				give: PICA_ON_PICASSO.into(),
+				want: PICA_ON_PICASSO.into(),
+			}
+		);
+	}
+}
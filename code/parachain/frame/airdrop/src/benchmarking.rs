@@ -0,0 +1,149 @@
+use super::*;
+use crate::{
+	models::{Identity, Proof},
+	Pallet as Airdrop,
+};
+use composable_support::{
+	signature_verification::{ethereum_signable_message, get_encoded_vec},
+	types::{EcdsaSignature, EthereumAddress},
+};
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{assert_ok, traits::fungible::Mutate};
+use frame_system::RawOrigin;
+use sp_core::keccak_256;
+use sp_runtime::traits::Zero;
+use sp_std::vec::Vec;
+
+const UNIT: u128 = 1_000_000_000_000;
+
+type EthKey = libsecp256k1::SecretKey;
+
+fn eth_key() -> EthKey {
+	EthKey::parse(&keccak_256(b"airdrop")).expect("valid secret; qed;")
+}
+
+fn eth_identity<T: Config>(secret: &EthKey) -> IdentityOf<T> {
+	let public = libsecp256k1::PublicKey::from_secret_key(secret);
+	let mut address = EthereumAddress::default();
+	address.0.copy_from_slice(&keccak_256(&public.serialize()[1..65])[12..]);
+	Identity::Ethereum(address)
+}
+
+fn eth_proof<T: Config>(
+	secret: &EthKey,
+	airdrop_id: T::AirdropId,
+	reward_account: &T::AccountId,
+) -> ProofOf<T> {
+	let msg = keccak_256(&ethereum_signable_message(
+		T::Prefix::get(),
+		&(airdrop_id, reward_account).using_encoded(get_encoded_vec),
+	));
+	let (sig, recovery_id) = libsecp256k1::sign(&libsecp256k1::Message::parse(&msg), secret);
+	let mut r = [0_u8; 65];
+	r[0..64].copy_from_slice(&sig.serialize()[..]);
+	r[64] = recovery_id.serialize();
+	Proof::Ethereum(EcdsaSignature(r))
+}
+
+/// `count` recipients, the first one being `eth_key`.
+fn recipients<T: Config>(count: u32) -> Vec<RecipientOf<T>>
+where
+	T::Balance: From<u128>,
+{
+	(0..count)
+		.map(|i| {
+			let identity = if i == 0 {
+				eth_identity::<T>(&eth_key())
+			} else {
+				Identity::Ethereum(EthereumAddress(
+					keccak_256(&i.to_le_bytes())[12..].try_into().expect("20 bytes; qed;"),
+				))
+			};
+			(identity, UNIT.into(), T::Moment::zero())
+		})
+		.collect()
+}
+
+fn create_airdrop<T: Config>(creator: T::AccountId, recipient_count: u32) -> T::AirdropId
+where
+	T::Balance: From<u128>,
+{
+	assert_ok!(T::RecipientFundAsset::mint_into(&creator, (1_000_000 * UNIT).into()));
+	assert_ok!(Airdrop::<T>::create_airdrop(
+		RawOrigin::Signed(creator.clone()).into(),
+		None,
+		T::Moment::zero()
+	));
+	let airdrop_id = AirdropCount::<T>::get();
+	if recipient_count > 0 {
+		assert_ok!(Airdrop::<T>::add_recipient(
+			RawOrigin::Signed(creator).into(),
+			airdrop_id,
+			recipients::<T>(recipient_count)
+		));
+	}
+	airdrop_id
+}
+
+benchmarks! {
+  where_clause { where T::Balance: From<u128> }
+	create_airdrop {
+		let caller: T::AccountId = whitelisted_caller();
+		assert_ok!(T::RecipientFundAsset::mint_into(&caller, (1_000_000 * UNIT).into()));
+	}: _(RawOrigin::Signed(caller), None, T::Moment::zero())
+
+	add_recipient {
+		let x in 1 .. 1_000;
+		let caller: T::AccountId = whitelisted_caller();
+		let airdrop_id = create_airdrop::<T>(caller.clone(), 0);
+	}: _(RawOrigin::Signed(caller), airdrop_id, recipients::<T>(x))
+
+	remove_recipient {
+		let caller: T::AccountId = whitelisted_caller();
+		let airdrop_id = create_airdrop::<T>(caller.clone(), 1);
+	}: _(RawOrigin::Signed(caller), airdrop_id, eth_identity::<T>(&eth_key()))
+
+	commit_recipients {
+		let caller: T::AccountId = whitelisted_caller();
+		let airdrop_id = create_airdrop::<T>(caller.clone(), 0);
+	}: _(RawOrigin::Signed(caller), airdrop_id, H256::repeat_byte(1), (1_000 * UNIT).into())
+
+	enable_airdrop {
+		let caller: T::AccountId = whitelisted_caller();
+		let airdrop_id = create_airdrop::<T>(caller.clone(), 1);
+	}: _(RawOrigin::Signed(caller), airdrop_id)
+
+	disable_airdrop {
+		let x in 1 .. 1_000;
+		let caller: T::AccountId = whitelisted_caller();
+		let airdrop_id = create_airdrop::<T>(caller.clone(), x);
+	}: _(RawOrigin::Signed(caller), airdrop_id)
+
+	claim {
+		let x in 1 .. 1_000;
+		let creator: T::AccountId = whitelisted_caller();
+		let airdrop_id = create_airdrop::<T>(creator.clone(), x);
+		assert_ok!(Airdrop::<T>::enable_airdrop(RawOrigin::Signed(creator.clone()).into(), airdrop_id));
+		let proof = eth_proof::<T>(&eth_key(), airdrop_id, &creator);
+	}: _(RawOrigin::None, airdrop_id, creator, proof)
+
+	claim_committed {
+		let x in 1 .. T::MaxMerkleProofLength::get();
+		let creator: T::AccountId = whitelisted_caller();
+		let airdrop_id = create_airdrop::<T>(creator.clone(), 0);
+		let amount: T::Balance = UNIT.into();
+		let leaf = merkle_leaf(&eth_identity::<T>(&eth_key()), amount, T::Moment::zero());
+		let merkle_proof: Vec<H256> = (0..x).map(|i| H256::repeat_byte(i as u8)).collect();
+		assert_ok!(Airdrop::<T>::commit_recipients(
+			RawOrigin::Signed(creator.clone()).into(),
+			airdrop_id,
+			merkle_root(leaf, &merkle_proof),
+			amount
+		));
+		assert_ok!(Airdrop::<T>::enable_airdrop(RawOrigin::Signed(creator.clone()).into(), airdrop_id));
+		let proof = eth_proof::<T>(&eth_key(), airdrop_id, &creator);
+		let merkle_proof = merkle_proof.try_into().expect("proof fits; qed;");
+	}: _(RawOrigin::None, airdrop_id, creator, proof, amount, T::Moment::zero(), merkle_proof)
+}
+
+impl_benchmark_test_suite!(Airdrop, crate::mock::new_test_ext(), crate::mock::Test);
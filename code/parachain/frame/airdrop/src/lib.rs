@@ -0,0 +1,886 @@
+//! # Airdrop Pallet
+//!
+//! Distributes native tokens to accounts of other chains. It implements
+//! [`Airdropper`](composable_traits::airdrop::Airdropper).
+//!
+//! ## Overview
+//!
+//! Anyone can create an airdrop, locking a `Stake` returned once the airdrop ends. Many airdrops
+//! can run at the same time, each with its own creator, funds, start and vesting schedule.
+//!
+//! Recipients are identified by a relay chain, Ethereum or Cosmos key. The creator funds them
+//! either by storing each of them with `add_recipient`, or by committing to a Merkle tree of
+//! recipients with `commit_recipients`, only the root being stored on chain.
+//!
+//! Once started, a recipient claims by signing `Prefix` followed by the hex encoded airdrop and
+//! account to reward, see [`models::Proof`]. Claims are unsigned and free. The fund of a recipient
+//! vests linearly over its vesting period, by steps of the `schedule` of the airdrop.
+//!
+//! Recipients can claim for `ClaimPeriod` after the start of the airdrop. The creator can then
+//! disable it to reclaim the unclaimed funds, which is also possible before the airdrop starts.
+//! The recipients of an ended airdrop are removed by batches of `RecipientRemovalsPerBlock`, the
+//! rest being removed over the following blocks.
+//!
+//! ## Interface
+//!
+//! - `create_airdrop`: create an airdrop, optionally starting at a given time.
+//! - `add_recipient`: store and fund recipients.
+//! - `remove_recipient`: remove a recipient which did not claim yet, refunding its fund.
+//! - `commit_recipients`: fund the recipients of a Merkle tree.
+//! - `enable_airdrop`: start an airdrop now.
+//! - `disable_airdrop`: end an airdrop, refunding the unclaimed funds and the stake.
+//! - `claim`: claim the vested fund of a stored recipient.
+//! - `claim_committed`: claim the vested fund of a committed recipient.
+#![cfg_attr(
+	not(test),
+	warn(
+		clippy::disallowed_methods,
+		clippy::disallowed_types,
+		clippy::todo,
+		clippy::unwrap_used,
+		clippy::panic
+	)
+)]
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(
+	bad_style,
+	bare_trait_objects,
+	improper_ctypes,
+	non_shorthand_field_patterns,
+	no_mangle_generic_items,
+	overflowing_literals,
+	path_statements,
+	patterns_in_fns_without_body,
+	private_in_public,
+	unconditional_recursion,
+	unused_allocation,
+	unused_comparisons,
+	unused_parens,
+	while_true,
+	trivial_casts,
+	trivial_numeric_casts,
+	unused_extern_crates
+)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarking;
+
+pub mod models;
+
+pub use crate::weights::WeightInfo;
+
+use codec::Encode;
+use sp_core::H256;
+use sp_io::hashing::blake2_256;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::{
+		models::{Airdrop, AirdropState, Identity, Proof, RecipientFund},
+		WeightInfo,
+	};
+	use codec::{Encode, FullCodec};
+	use composable_support::{
+		math::safe::{safe_multiply_by_rational, SafeAdd, SafeArithmetic, SafeSub},
+		signature_verification::{cosmos_recover, ethereum_recover, get_encoded_vec, verify_relay},
+	};
+	use composable_traits::{
+		airdrop::Airdropper,
+		currency::{BalanceLike, MathBalance},
+	};
+	use core::fmt::Debug;
+	use frame_support::{
+		dispatch::DispatchResultWithPostInfo,
+		pallet_prelude::*,
+		traits::{
+			fungible::{Inspect, Mutate},
+			tokens::Preservation,
+			Time,
+		},
+		weights::Weight,
+		PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_core::H256;
+	use sp_runtime::{
+		traits::{AccountIdConversion, AtLeast32Bit, Convert, One, SaturatedConversion, Zero},
+		AccountId32,
+	};
+	use sp_std::vec::Vec;
+
+	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+	pub type BalanceOf<T> = <T as Config>::Balance;
+	pub type MomentOf<T> = <T as Config>::Moment;
+	pub type AirdropOf<T> = Airdrop<AccountIdOf<T>, BalanceOf<T>, MomentOf<T>>;
+	pub type IdentityOf<T> = Identity<<T as Config>::RelayChainAccountId>;
+	pub type ProofOf<T> = Proof<<T as Config>::RelayChainAccountId>;
+	pub type RecipientFundOf<T> = RecipientFund<BalanceOf<T>, MomentOf<T>>;
+	/// A recipient with its fund and vesting period.
+	pub type RecipientOf<T> = (IdentityOf<T>, BalanceOf<T>, MomentOf<T>);
+	pub type MerkleProofOf<T> = BoundedVec<H256, <T as Config>::MaxMerkleProofLength>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		#[allow(missing_docs)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Type representing the unique ID of an airdrop.
+		type AirdropId: FullCodec
+			+ MaxEncodedLen
+			+ Default
+			+ Debug
+			+ TypeInfo
+			+ Eq
+			+ PartialEq
+			+ Ord
+			+ Copy
+			+ Zero
+			+ One
+			+ SafeArithmetic;
+
+		/// Type representing the Balance of an account.
+		type Balance: BalanceLike + MathBalance;
+
+		/// Type used to express timestamps.
+		type Moment: AtLeast32Bit + Parameter + Default + Copy + MaxEncodedLen + FullCodec;
+
+		/// A conversion function from `Self::Moment` to `Self::Balance`.
+		type Convert: Convert<Self::Moment, Self::Balance>;
+
+		/// The relay chain account id.
+		type RelayChainAccountId: Parameter
+			+ MaybeSerializeDeserialize
+			+ MaxEncodedLen
+			+ Into<AccountId32>
+			+ Ord;
+
+		/// The asset airdropped and used for the `Stake`.
+		type RecipientFundAsset: Inspect<Self::AccountId, Balance = Self::Balance>
+			+ Mutate<Self::AccountId>;
+
+		/// The time provider.
+		type Time: Time<Moment = Self::Moment>;
+
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The arbitrary prefix used for the proof.
+		#[pallet::constant]
+		type Prefix: Get<&'static [u8]>;
+
+		/// Amount locked by the creator of an airdrop until it ends.
+		#[pallet::constant]
+		type Stake: Get<BalanceOf<Self>>;
+
+		/// Time after the start of an airdrop during which recipients can claim.
+		#[pallet::constant]
+		type ClaimPeriod: Get<MomentOf<Self>>;
+
+		/// Maximum depth of the Merkle tree of committed recipients.
+		#[pallet::constant]
+		type MaxMerkleProofLength: Get<u32>;
+
+		/// Maximum number of recipients of ended airdrops removed per block.
+		#[pallet::constant]
+		type RecipientRemovalsPerBlock: Get<u32>;
+
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		AirdropCreated {
+			airdrop_id: T::AirdropId,
+			by: T::AccountId,
+		},
+		RecipientsAdded {
+			airdrop_id: T::AirdropId,
+			number: u32,
+			unclaimed_funds: T::Balance,
+		},
+		RecipientRemoved {
+			airdrop_id: T::AirdropId,
+			recipient: IdentityOf<T>,
+			unclaimed_funds: T::Balance,
+		},
+		RecipientsCommitted {
+			airdrop_id: T::AirdropId,
+			merkle_root: H256,
+			unclaimed_funds: T::Balance,
+		},
+		AirdropStarted {
+			airdrop_id: T::AirdropId,
+			at: T::Moment,
+		},
+		/// The airdrop was disabled or fully claimed, `unclaimed_funds` were returned to its
+		/// creator.
+		AirdropEnded {
+			airdrop_id: T::AirdropId,
+			at: T::Moment,
+			unclaimed_funds: T::Balance,
+		},
+		Claimed {
+			airdrop_id: T::AirdropId,
+			identity: IdentityOf<T>,
+			recipient: T::AccountId,
+			amount: T::Balance,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		AirdropNotFound,
+		AirdropAlreadyStarted,
+		AirdropNotStarted,
+		AirdropExpired,
+		/// Recipients can still claim.
+		AirdropIsActive,
+		NotAirdropCreator,
+		BackToTheFuture,
+		RecipientNotFound,
+		RecipientAlreadyAdded,
+		RecipientAlreadyClaimed,
+		RecipientsAlreadyCommitted,
+		InvalidMerkleProof,
+		InvalidProof,
+		NothingToClaim,
+	}
+
+	#[pallet::storage]
+	#[allow(clippy::disallowed_types)] // AirdropId is ValueQuery with default 0
+	pub type AirdropCount<T: Config> = StorageValue<_, T::AirdropId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn airdrops)]
+	pub type Airdrops<T: Config> = StorageMap<_, Twox64Concat, T::AirdropId, AirdropOf<T>>;
+
+	/// Stored recipients, and committed recipients which claimed at least once.
+	#[pallet::storage]
+	#[pallet::getter(fn recipient_funds)]
+	pub type RecipientFunds<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AirdropId,
+		Blake2_128Concat,
+		IdentityOf<T>,
+		RecipientFundOf<T>,
+	>;
+
+	/// Ended airdrops whose recipients are still being removed.
+	#[pallet::storage]
+	pub type EndedAirdrops<T: Config> = StorageMap<_, Twox64Concat, T::AirdropId, ()>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_now: T::BlockNumber) -> Weight {
+			let Some(airdrop_id) = EndedAirdrops::<T>::iter_keys().next() else {
+				return T::DbWeight::get().reads(1)
+			};
+			let removal = RecipientFunds::<T>::clear_prefix(
+				airdrop_id,
+				T::RecipientRemovalsPerBlock::get(),
+				None,
+			);
+			if removal.maybe_cursor.is_none() {
+				EndedAirdrops::<T>::remove(airdrop_id);
+			}
+			T::DbWeight::get().reads_writes(1, 1).saturating_add(
+				T::DbWeight::get().reads_writes(removal.loops.into(), removal.unique.into()),
+			)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create an airdrop, starting at `start_at` if provided. The fund of recipients vests
+		/// by steps of `vesting_schedule`.
+		///
+		/// Emits `AirdropCreated` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::create_airdrop())]
+		pub fn create_airdrop(
+			origin: OriginFor<T>,
+			start_at: Option<T::Moment>,
+			vesting_schedule: T::Moment,
+		) -> DispatchResult {
+			let creator = ensure_signed(origin)?;
+			<Self as Airdropper>::create_airdrop(creator, start_at, vesting_schedule)
+		}
+
+		/// Store and fund recipients of an airdrop not yet started.
+		///
+		/// Emits `RecipientsAdded` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::add_recipient(recipients.len() as u32))]
+		pub fn add_recipient(
+			origin: OriginFor<T>,
+			airdrop_id: T::AirdropId,
+			recipients: Vec<RecipientOf<T>>,
+		) -> DispatchResult {
+			let origin_id = ensure_signed(origin)?;
+			<Self as Airdropper>::add_recipient(origin_id, airdrop_id, recipients)
+		}
+
+		/// Remove a recipient which did not claim yet, refunding its fund to the creator.
+		///
+		/// Emits `RecipientRemoved` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::remove_recipient())]
+		pub fn remove_recipient(
+			origin: OriginFor<T>,
+			airdrop_id: T::AirdropId,
+			recipient: IdentityOf<T>,
+		) -> DispatchResult {
+			let origin_id = ensure_signed(origin)?;
+			<Self as Airdropper>::remove_recipient(origin_id, airdrop_id, recipient)
+		}
+
+		/// Commit to a Merkle tree of recipients, funding them with `funds`. Leaves are
+		/// [`merkle_leaf`](crate::merkle_leaf), nodes hash their children sorted. Can be called
+		/// once per airdrop, before it starts. Committed recipients are counted as they claim.
+		///
+		/// Emits `RecipientsCommitted` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::commit_recipients())]
+		pub fn commit_recipients(
+			origin: OriginFor<T>,
+			airdrop_id: T::AirdropId,
+			merkle_root: H256,
+			funds: T::Balance,
+		) -> DispatchResult {
+			let origin_id = ensure_signed(origin)?;
+			Self::do_commit_recipients(origin_id, airdrop_id, merkle_root, funds)
+		}
+
+		/// Start an airdrop now.
+		///
+		/// Emits `AirdropStarted` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::enable_airdrop())]
+		pub fn enable_airdrop(origin: OriginFor<T>, airdrop_id: T::AirdropId) -> DispatchResult {
+			let origin_id = ensure_signed(origin)?;
+			<Self as Airdropper>::enable_airdrop(origin_id, airdrop_id)
+		}
+
+		/// End an airdrop not yet started or expired, refunding the unclaimed funds and the stake
+		/// to the creator.
+		///
+		/// Emits `AirdropEnded` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::disable_airdrop(Self::removed_recipients(*airdrop_id)))]
+		pub fn disable_airdrop(origin: OriginFor<T>, airdrop_id: T::AirdropId) -> DispatchResult {
+			let origin_id = ensure_signed(origin)?;
+			<Self as Airdropper>::disable_airdrop(origin_id, airdrop_id)?;
+			Ok(())
+		}
+
+		/// Claim the vested fund of a stored recipient, identified by `proof`, to
+		/// `reward_account`.
+		///
+		/// The proof should be:
+		/// ```haskell
+		/// proof = sign (concat prefix (hex (encode (airdrop_id, reward_account))))
+		/// ```
+		///
+		/// Emits `Claimed` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::claim(Self::removed_recipients(*airdrop_id)))]
+		pub fn claim(
+			origin: OriginFor<T>,
+			airdrop_id: T::AirdropId,
+			reward_account: T::AccountId,
+			proof: ProofOf<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+			let identity = get_identity::<T>(proof, airdrop_id, &reward_account, T::Prefix::get())?;
+			<Self as Airdropper>::claim(airdrop_id, identity, reward_account)
+		}
+
+		/// Claim the vested fund of a committed recipient, proving its `amount` and
+		/// `vesting_period` with `merkle_proof`. Following claims can use `claim`.
+		///
+		/// Emits `Claimed` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::claim_committed(merkle_proof.len() as u32).saturating_add(
+			T::WeightInfo::claim(Self::removed_recipients(*airdrop_id)),
+		))]
+		pub fn claim_committed(
+			origin: OriginFor<T>,
+			airdrop_id: T::AirdropId,
+			reward_account: T::AccountId,
+			proof: ProofOf<T>,
+			amount: T::Balance,
+			vesting_period: T::Moment,
+			merkle_proof: MerkleProofOf<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+			let identity = get_identity::<T>(proof, airdrop_id, &reward_account, T::Prefix::get())?;
+			Self::do_claim(
+				airdrop_id,
+				identity,
+				reward_account,
+				Some((amount, vesting_period, &merkle_proof[..])),
+			)
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let (airdrop_id, reward_account, proof, committed) = match call {
+				Call::claim { airdrop_id, reward_account, proof } =>
+					(airdrop_id, reward_account, proof, None),
+				Call::claim_committed {
+					airdrop_id,
+					reward_account,
+					proof,
+					amount,
+					vesting_period,
+					merkle_proof,
+				} => (
+					airdrop_id,
+					reward_account,
+					proof,
+					Some((*amount, *vesting_period, &merkle_proof[..])),
+				),
+				_ => return Err(InvalidTransaction::Call.into()),
+			};
+			let identity =
+				get_identity::<T>(proof.clone(), *airdrop_id, reward_account, T::Prefix::get())
+					.map_err(|_| InvalidTransaction::Custom(ValidityError::InvalidProof as u8))?;
+			let airdrop = Airdrops::<T>::get(airdrop_id)
+				.ok_or(InvalidTransaction::Custom(ValidityError::NotClaimable as u8))?;
+			let start = Self::ensure_claimable(&airdrop)
+				.map_err(|_| InvalidTransaction::Custom(ValidityError::NotClaimable as u8))?;
+			let fund = Self::recipient_fund(*airdrop_id, &airdrop, &identity, committed)
+				.map_err(|_| InvalidTransaction::Custom(ValidityError::NoFunds as u8))?;
+			match claimable_amount::<T>(&fund, start, airdrop.schedule) {
+				Ok(amount) if !amount.is_zero() =>
+					ValidTransaction::with_tag_prefix("AirdropClaim")
+						.and_provides((airdrop_id, identity))
+						.build(),
+				_ => InvalidTransaction::Custom(ValidityError::NothingToClaim as u8).into(),
+			}
+		}
+	}
+
+	#[repr(u8)]
+	pub enum ValidityError {
+		InvalidProof = 0,
+		NotClaimable = 1,
+		NoFunds = 2,
+		NothingToClaim = 3,
+	}
+
+	impl<T: Config> Airdropper for Pallet<T> {
+		type AccountId = T::AccountId;
+		type AirdropId = T::AirdropId;
+		type AirdropStart = T::Moment;
+		type Balance = T::Balance;
+		type Proof = ProofOf<T>;
+		type Recipient = IdentityOf<T>;
+		type RecipientCollection = Vec<RecipientOf<T>>;
+		type Identity = IdentityOf<T>;
+		type VestingSchedule = T::Moment;
+
+		fn create_airdrop(
+			creator_id: Self::AccountId,
+			start: Option<Self::AirdropStart>,
+			schedule: Self::VestingSchedule,
+		) -> DispatchResult {
+			if let Some(at) = start {
+				ensure!(at >= T::Time::now(), Error::<T>::BackToTheFuture);
+			}
+			let airdrop_id = AirdropCount::<T>::try_mutate(|count| -> Result<_, DispatchError> {
+				*count = count.safe_add(&T::AirdropId::one())?;
+				Ok(*count)
+			})?;
+			T::RecipientFundAsset::transfer(
+				&creator_id,
+				&Self::account_id(airdrop_id),
+				T::Stake::get(),
+				Preservation::Preserve,
+			)?;
+			Airdrops::<T>::insert(
+				airdrop_id,
+				Airdrop {
+					creator: creator_id.clone(),
+					total_funds: T::Balance::zero(),
+					total_recipients: 0,
+					claimed_funds: T::Balance::zero(),
+					start,
+					schedule,
+					merkle_root: None,
+				},
+			);
+			Self::deposit_event(Event::<T>::AirdropCreated { airdrop_id, by: creator_id });
+			if let Some(at) = start {
+				Self::deposit_event(Event::<T>::AirdropStarted { airdrop_id, at });
+			}
+			Ok(())
+		}
+
+		fn add_recipient(
+			origin_id: Self::AccountId,
+			airdrop_id: Self::AirdropId,
+			recipients: Self::RecipientCollection,
+		) -> DispatchResult {
+			let mut airdrop = Self::get_created_airdrop(&origin_id, airdrop_id)?;
+			let number = recipients.len() as u32;
+			let funds = recipients.into_iter().try_fold(
+				T::Balance::zero(),
+				|funds, (identity, total, vesting_period)| -> Result<_, DispatchError> {
+					ensure!(
+						!RecipientFunds::<T>::contains_key(airdrop_id, &identity),
+						Error::<T>::RecipientAlreadyAdded
+					);
+					RecipientFunds::<T>::insert(
+						airdrop_id,
+						identity,
+						RecipientFund { total, claimed: T::Balance::zero(), vesting_period },
+					);
+					Ok(funds.safe_add(&total)?)
+				},
+			)?;
+			Self::fund(&mut airdrop, airdrop_id, funds)?;
+			airdrop.total_recipients = airdrop.total_recipients.safe_add(&number)?;
+			Airdrops::<T>::insert(airdrop_id, airdrop);
+			Self::deposit_event(Event::<T>::RecipientsAdded {
+				airdrop_id,
+				number,
+				unclaimed_funds: funds,
+			});
+			Ok(())
+		}
+
+		fn remove_recipient(
+			origin_id: Self::AccountId,
+			airdrop_id: Self::AirdropId,
+			recipient: Self::Recipient,
+		) -> DispatchResult {
+			let mut airdrop = Self::get_airdrop(airdrop_id)?;
+			ensure!(airdrop.creator == origin_id, Error::<T>::NotAirdropCreator);
+			let fund = RecipientFunds::<T>::get(airdrop_id, &recipient)
+				.ok_or(Error::<T>::RecipientNotFound)?;
+			ensure!(fund.claimed.is_zero(), Error::<T>::RecipientAlreadyClaimed);
+			T::RecipientFundAsset::transfer(
+				&Self::account_id(airdrop_id),
+				&airdrop.creator,
+				fund.total,
+				Preservation::Expendable,
+			)?;
+			airdrop.total_funds = airdrop.total_funds.safe_sub(&fund.total)?;
+			airdrop.total_recipients = airdrop.total_recipients.safe_sub(&1)?;
+			RecipientFunds::<T>::remove(airdrop_id, &recipient);
+			Airdrops::<T>::insert(airdrop_id, airdrop);
+			Self::deposit_event(Event::<T>::RecipientRemoved {
+				airdrop_id,
+				recipient,
+				unclaimed_funds: fund.total,
+			});
+			Ok(())
+		}
+
+		fn enable_airdrop(
+			origin_id: Self::AccountId,
+			airdrop_id: Self::AirdropId,
+		) -> DispatchResult {
+			let mut airdrop = Self::get_created_airdrop(&origin_id, airdrop_id)?;
+			let at = T::Time::now();
+			airdrop.start = Some(at);
+			Airdrops::<T>::insert(airdrop_id, airdrop);
+			Self::deposit_event(Event::<T>::AirdropStarted { airdrop_id, at });
+			Ok(())
+		}
+
+		fn disable_airdrop(
+			origin_id: Self::AccountId,
+			airdrop_id: Self::AirdropId,
+		) -> Result<Self::Balance, DispatchError> {
+			let airdrop = Self::get_airdrop(airdrop_id)?;
+			ensure!(airdrop.creator == origin_id, Error::<T>::NotAirdropCreator);
+			ensure!(
+				Self::airdrop_state(&airdrop) != AirdropState::Enabled,
+				Error::<T>::AirdropIsActive
+			);
+			Self::end_airdrop(airdrop_id, airdrop)
+		}
+
+		fn claim(
+			airdrop_id: Self::AirdropId,
+			remote_account: Self::Identity,
+			reward_account: Self::AccountId,
+		) -> DispatchResultWithPostInfo {
+			Self::do_claim(airdrop_id, remote_account, reward_account, None)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Account holding the funds and the stake of an airdrop.
+		pub fn account_id(airdrop_id: T::AirdropId) -> T::AccountId {
+			T::PalletId::get().into_sub_account_truncating(airdrop_id)
+		}
+
+		pub fn airdrop_state(airdrop: &AirdropOf<T>) -> AirdropState {
+			let now = T::Time::now();
+			match airdrop.start {
+				Some(start) if start <= now =>
+					if now.saturating_sub(start) < T::ClaimPeriod::get() {
+						AirdropState::Enabled
+					} else {
+						AirdropState::Expired
+					},
+				_ => AirdropState::Created,
+			}
+		}
+
+		/// Number of recipients removed if the airdrop ends now.
+		fn removed_recipients(airdrop_id: T::AirdropId) -> u32 {
+			Airdrops::<T>::get(airdrop_id)
+				.map_or(0, |airdrop| airdrop.total_recipients)
+				.min(T::RecipientRemovalsPerBlock::get())
+		}
+
+		fn get_airdrop(airdrop_id: T::AirdropId) -> Result<AirdropOf<T>, DispatchError> {
+			Airdrops::<T>::get(airdrop_id).ok_or_else(|| Error::<T>::AirdropNotFound.into())
+		}
+
+		/// Get an airdrop which can still be modified by its creator.
+		fn get_created_airdrop(
+			origin_id: &T::AccountId,
+			airdrop_id: T::AirdropId,
+		) -> Result<AirdropOf<T>, DispatchError> {
+			let airdrop = Self::get_airdrop(airdrop_id)?;
+			ensure!(airdrop.creator == *origin_id, Error::<T>::NotAirdropCreator);
+			ensure!(
+				Self::airdrop_state(&airdrop) == AirdropState::Created,
+				Error::<T>::AirdropAlreadyStarted
+			);
+			Ok(airdrop)
+		}
+
+		/// Ensure recipients can claim, returning the start of the airdrop.
+		fn ensure_claimable(airdrop: &AirdropOf<T>) -> Result<T::Moment, DispatchError> {
+			match (Self::airdrop_state(airdrop), airdrop.start) {
+				(AirdropState::Enabled, Some(start)) => Ok(start),
+				(AirdropState::Expired, _) => Err(Error::<T>::AirdropExpired.into()),
+				_ => Err(Error::<T>::AirdropNotStarted.into()),
+			}
+		}
+
+		/// Transfer `funds` for new recipients from the creator.
+		fn fund(
+			airdrop: &mut AirdropOf<T>,
+			airdrop_id: T::AirdropId,
+			funds: T::Balance,
+		) -> DispatchResult {
+			T::RecipientFundAsset::transfer(
+				&airdrop.creator,
+				&Self::account_id(airdrop_id),
+				funds,
+				Preservation::Preserve,
+			)?;
+			airdrop.total_funds = airdrop.total_funds.safe_add(&funds)?;
+			Ok(())
+		}
+
+		fn do_commit_recipients(
+			origin_id: T::AccountId,
+			airdrop_id: T::AirdropId,
+			merkle_root: H256,
+			funds: T::Balance,
+		) -> DispatchResult {
+			let mut airdrop = Self::get_created_airdrop(&origin_id, airdrop_id)?;
+			ensure!(airdrop.merkle_root.is_none(), Error::<T>::RecipientsAlreadyCommitted);
+			Self::fund(&mut airdrop, airdrop_id, funds)?;
+			airdrop.merkle_root = Some(merkle_root);
+			Airdrops::<T>::insert(airdrop_id, airdrop);
+			Self::deposit_event(Event::<T>::RecipientsCommitted {
+				airdrop_id,
+				merkle_root,
+				unclaimed_funds: funds,
+			});
+			Ok(())
+		}
+
+		/// Get the fund of a stored recipient, or of a committed one proven by `committed`.
+		fn recipient_fund(
+			airdrop_id: T::AirdropId,
+			airdrop: &AirdropOf<T>,
+			identity: &IdentityOf<T>,
+			committed: Option<(T::Balance, T::Moment, &[H256])>,
+		) -> Result<RecipientFundOf<T>, DispatchError> {
+			match (RecipientFunds::<T>::get(airdrop_id, identity), committed) {
+				(Some(fund), _) => Ok(fund),
+				(None, Some((total, vesting_period, merkle_proof))) => {
+					let leaf = crate::merkle_leaf(identity, total, vesting_period);
+					ensure!(
+						airdrop.merkle_root == Some(crate::merkle_root(leaf, merkle_proof)),
+						Error::<T>::InvalidMerkleProof
+					);
+					Ok(RecipientFund { total, claimed: T::Balance::zero(), vesting_period })
+				},
+				(None, None) => Err(Error::<T>::RecipientNotFound.into()),
+			}
+		}
+
+		fn do_claim(
+			airdrop_id: T::AirdropId,
+			identity: IdentityOf<T>,
+			reward_account: T::AccountId,
+			committed: Option<(T::Balance, T::Moment, &[H256])>,
+		) -> DispatchResultWithPostInfo {
+			let mut airdrop = Self::get_airdrop(airdrop_id)?;
+			let start = Self::ensure_claimable(&airdrop)?;
+			let mut fund = Self::recipient_fund(airdrop_id, &airdrop, &identity, committed)?;
+			let amount = claimable_amount::<T>(&fund, start, airdrop.schedule)?;
+			ensure!(!amount.is_zero(), Error::<T>::NothingToClaim);
+			T::RecipientFundAsset::transfer(
+				&Self::account_id(airdrop_id),
+				&reward_account,
+				amount,
+				Preservation::Expendable,
+			)?;
+			fund.claimed = fund.claimed.safe_add(&amount)?;
+			if !RecipientFunds::<T>::contains_key(airdrop_id, &identity) {
+				// committed recipient claiming for the first time
+				airdrop.total_recipients = airdrop.total_recipients.safe_add(&1)?;
+			}
+			RecipientFunds::<T>::insert(airdrop_id, &identity, fund);
+			airdrop.claimed_funds = airdrop.claimed_funds.safe_add(&amount)?;
+			Self::deposit_event(Event::<T>::Claimed {
+				airdrop_id,
+				identity,
+				recipient: reward_account,
+				amount,
+			});
+			if airdrop.claimed_funds >= airdrop.total_funds {
+				Self::end_airdrop(airdrop_id, airdrop)?;
+			} else {
+				Airdrops::<T>::insert(airdrop_id, airdrop);
+			}
+			Ok(Pays::No.into())
+		}
+
+		/// Return what is left and the stake to the creator, and remove the airdrop. Recipients
+		/// left after the first batch are removed by `on_initialize`.
+		fn end_airdrop(
+			airdrop_id: T::AirdropId,
+			airdrop: AirdropOf<T>,
+		) -> Result<T::Balance, DispatchError> {
+			let account = Self::account_id(airdrop_id);
+			T::RecipientFundAsset::transfer(
+				&account,
+				&airdrop.creator,
+				T::RecipientFundAsset::balance(&account),
+				Preservation::Expendable,
+			)?;
+			let unclaimed_funds = airdrop.total_funds.safe_sub(&airdrop.claimed_funds)?;
+			let removal = RecipientFunds::<T>::clear_prefix(
+				airdrop_id,
+				T::RecipientRemovalsPerBlock::get(),
+				None,
+			);
+			if removal.maybe_cursor.is_some() {
+				EndedAirdrops::<T>::insert(airdrop_id, ());
+			}
+			Airdrops::<T>::remove(airdrop_id);
+			Self::deposit_event(Event::<T>::AirdropEnded {
+				airdrop_id,
+				at: T::Time::now(),
+				unclaimed_funds,
+			});
+			Ok(unclaimed_funds)
+		}
+	}
+
+	/// The amount of a fund vested but not yet claimed, for an airdrop started at `start`.
+	pub fn claimable_amount<T: Config>(
+		fund: &RecipientFundOf<T>,
+		start: T::Moment,
+		schedule: T::Moment,
+	) -> Result<T::Balance, DispatchError> {
+		let vesting_point = T::Time::now().saturating_sub(start);
+		let vested = if vesting_point >= fund.vesting_period {
+			fund.total
+		} else {
+			// Current window, rounded to the previous step.
+			let vesting_window = if schedule.is_zero() {
+				vesting_point
+			} else {
+				vesting_point.saturating_sub(vesting_point % schedule)
+			};
+			safe_multiply_by_rational(
+				fund.total.into(),
+				T::Convert::convert(vesting_window).into(),
+				T::Convert::convert(fund.vesting_period).into(),
+			)?
+			.saturated_into()
+		};
+		Ok(vested.safe_sub(&fund.claimed)?)
+	}
+
+	/// Retrieve the identity of a recipient from a proof. The signed message binds the airdrop
+	/// with the reward account, so that a proof can't be replayed on another airdrop.
+	///
+	/// # Errors
+	/// * `InvalidProof` - The proof was invalid for the airdrop and the reward account
+	pub fn get_identity<T: Config>(
+		proof: ProofOf<T>,
+		airdrop_id: T::AirdropId,
+		reward_account: &T::AccountId,
+		prefix: &[u8],
+	) -> Result<IdentityOf<T>, DispatchError> {
+		let claim = (airdrop_id, reward_account);
+		let claim_encoded = claim.using_encoded(get_encoded_vec);
+		match proof {
+			Proof::RelayChain(relay_account, relay_proof) => {
+				ensure!(
+					verify_relay(prefix, claim, relay_account.clone().into(), &relay_proof),
+					Error::<T>::InvalidProof
+				);
+				Ok(Identity::RelayChain(relay_account))
+			},
+			Proof::Ethereum(eth_proof) => ethereum_recover(prefix, &claim_encoded, &eth_proof)
+				.map(Identity::Ethereum)
+				.map_err(|_| Error::<T>::InvalidProof.into()),
+			Proof::Cosmos(cosmos_address, cosmos_proof) =>
+				cosmos_recover(prefix, &claim_encoded, cosmos_address, &cosmos_proof)
+					.map(Identity::Cosmos)
+					.map_err(|_| Error::<T>::InvalidProof.into()),
+		}
+	}
+}
+
+/// Leaf of a committed recipient in the Merkle tree of an airdrop.
+pub fn merkle_leaf<Identity: Encode, Balance: Encode, Moment: Encode>(
+	identity: &Identity,
+	amount: Balance,
+	vesting_period: Moment,
+) -> H256 {
+	blake2_256(&(identity, amount, vesting_period).encode()).into()
+}
+
+/// Root of a Merkle tree from a leaf and the siblings on its path, children of a node being
+/// hashed in ascending order.
+pub fn merkle_root(leaf: H256, proof: &[H256]) -> H256 {
+	proof.iter().fold(leaf, |node, sibling| {
+		let (left, right) = if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+		blake2_256(&[left.as_bytes(), right.as_bytes()].concat()).into()
+	})
+}
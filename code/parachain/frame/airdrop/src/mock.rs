@@ -0,0 +1,228 @@
+#![cfg(test)]
+
+use crate::{
+	self as pallet_airdrop,
+	models::{Identity, Proof},
+	IdentityOf,
+};
+use codec::Encode;
+use composable_support::{
+	signature_verification::{ethereum_signable_message, get_encoded_vec},
+	types::{CosmosEcdsaSignature, CosmosPublicKey, EcdsaSignature, EthereumAddress},
+};
+use frame_support::{
+	parameter_types,
+	traits::{ConstU32, ConstU64, Everything},
+	PalletId,
+};
+use sp_core::{ed25519, keccak_256, sha2_256, Pair, H256};
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, ConvertInto, IdentityLookup},
+	AccountId32, MultiSignature,
+};
+
+pub type AirdropId = u64;
+pub type BlockNumber = u64;
+pub type Moment = u64;
+pub type RelayChainAccountId = AccountId32;
+pub type EthKey = libsecp256k1::SecretKey;
+
+pub const PROOF_PREFIX: &[u8] = b"picasso-";
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Timestamp: pallet_timestamp,
+		Airdrop: pallet_airdrop,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+pub type AccountId = u128;
+
+#[allow(dead_code)]
+pub static ALICE: AccountId = 1;
+#[allow(dead_code)]
+pub static BOB: AccountId = 2;
+#[allow(dead_code)]
+pub static CHARLIE: AccountId = 3;
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+pub type Balance = u128;
+pub type Amount = i128;
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type FreezeIdentifier = [u8; 8];
+	type HoldIdentifier = [u8; 8];
+	type MaxHolds = ConstU32<32>;
+	type MaxFreezes = ConstU32<32>;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = Moment;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<5>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const AirdropPalletId: PalletId = PalletId(*b"pal_aird");
+	pub const Prefix: &'static [u8] = PROOF_PREFIX;
+	pub const Stake: Balance = 100;
+	pub const ClaimPeriod: Moment = 1_000_000;
+}
+
+impl pallet_airdrop::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AirdropId = AirdropId;
+	type Balance = Balance;
+	type Moment = Moment;
+	type Convert = ConvertInto;
+	type RelayChainAccountId = RelayChainAccountId;
+	type RecipientFundAsset = Balances;
+	type Time = Timestamp;
+	type PalletId = AirdropPalletId;
+	type Prefix = Prefix;
+	type Stake = Stake;
+	type ClaimPeriod = ClaimPeriod;
+	type MaxMerkleProofLength = ConstU32<16>;
+	type RecipientRemovalsPerBlock = ConstU32<2>;
+	type WeightInfo = ();
+}
+
+/// A key of another chain, able to prove its ownership to claim for a reward account.
+pub enum ClaimKey {
+	Relay(ed25519::Pair),
+	Ethereum(EthKey),
+	Cosmos(EthKey),
+}
+
+impl ClaimKey {
+	pub fn from_seed(seed: u8) -> [ClaimKey; 3] {
+		let secret = keccak_256(&[seed]);
+		[
+			ClaimKey::Relay(ed25519::Pair::from_seed(&secret)),
+			ClaimKey::Ethereum(EthKey::parse(&secret).expect("valid secret; qed")),
+			ClaimKey::Cosmos(EthKey::parse(&keccak_256(&secret)).expect("valid secret; qed")),
+		]
+	}
+
+	pub fn identity(&self) -> IdentityOf<Test> {
+		match self {
+			ClaimKey::Relay(pair) => Identity::RelayChain(pair.public().into()),
+			ClaimKey::Ethereum(secret) => {
+				let public = libsecp256k1::PublicKey::from_secret_key(secret);
+				let mut address = EthereumAddress::default();
+				address.0.copy_from_slice(&keccak_256(&public.serialize()[1..65])[12..]);
+				Identity::Ethereum(address)
+			},
+			ClaimKey::Cosmos(secret) => Identity::Cosmos(CosmosPublicKey::Secp256k1(
+				libsecp256k1::PublicKey::from_secret_key(secret).serialize_compressed(),
+			)),
+		}
+	}
+
+	/// Sign the claim of `airdrop_id` to `reward_account` as expected by the pallet.
+	pub fn proof(
+		&self,
+		airdrop_id: AirdropId,
+		reward_account: AccountId,
+	) -> Proof<RelayChainAccountId> {
+		let msg = (airdrop_id, reward_account).using_encoded(get_encoded_vec);
+		match self {
+			ClaimKey::Relay(pair) => {
+				let msg = [&b"<Bytes>"[..], PROOF_PREFIX, &msg[..], &b"</Bytes>"[..]].concat();
+				Proof::RelayChain(pair.public().into(), MultiSignature::Ed25519(pair.sign(&msg)))
+			},
+			ClaimKey::Ethereum(secret) => {
+				let msg = keccak_256(&ethereum_signable_message(PROOF_PREFIX, &msg));
+				let (sig, recovery_id) =
+					libsecp256k1::sign(&libsecp256k1::Message::parse(&msg), secret);
+				let mut r = [0_u8; 65];
+				r[0..64].copy_from_slice(&sig.serialize()[..]);
+				r[64] = recovery_id.serialize();
+				Proof::Ethereum(EcdsaSignature(r))
+			},
+			ClaimKey::Cosmos(secret) => {
+				let msg = sha2_256(&[PROOF_PREFIX, &msg[..]].concat());
+				let (sig, _) = libsecp256k1::sign(&libsecp256k1::Message::parse(&msg), secret);
+				let public =
+					libsecp256k1::PublicKey::from_secret_key(secret).serialize_compressed();
+				Proof::Cosmos(
+					CosmosPublicKey::Secp256k1(public),
+					CosmosEcdsaSignature(sig.serialize()),
+				)
+			},
+		}
+	}
+}
+
+/// Root of a Merkle tree of two leaves, and the proof of each leaf.
+pub fn merkle_tree(leaves: [H256; 2]) -> (H256, [Vec<H256>; 2]) {
+	(crate::merkle_root(leaves[0], &[leaves[1]]), [vec![leaves[1]], vec![leaves[0]]])
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::default()
+		.build_storage::<Test>()
+		.expect("success")
+		.into();
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		Timestamp::set_timestamp(1_000);
+	});
+	ext
+}
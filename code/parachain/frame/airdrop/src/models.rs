@@ -0,0 +1,60 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use composable_support::types::{
+	CosmosEcdsaSignature, CosmosPublicKey, EcdsaSignature, EthereumAddress,
+};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::{MultiSignature, RuntimeDebug};
+
+#[derive(Clone, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug, PartialEq, Eq)]
+pub struct Airdrop<AccountId, Balance, Moment> {
+	/// Creator of the airdrop, the only one allowed to manage it.
+	pub creator: AccountId,
+	/// Funds to distribute, including the committed ones.
+	pub total_funds: Balance,
+	/// Number of recipients, including the committed ones.
+	pub total_recipients: u32,
+	/// Funds claimed so far.
+	pub claimed_funds: Balance,
+	/// Time at which recipients can start claiming.
+	pub start: Option<Moment>,
+	/// Minimum time between two claims of a recipient, each claim releasing the funds vested
+	/// by whole steps.
+	pub schedule: Moment,
+	/// Root of the Merkle tree of committed recipients, see [`merkle_leaf`](crate::merkle_leaf).
+	pub merkle_root: Option<H256>,
+}
+
+#[derive(Copy, Clone, Encode, Decode, TypeInfo, RuntimeDebug, PartialEq, Eq)]
+pub enum AirdropState {
+	/// Recipients can be added until the airdrop starts.
+	Created,
+	/// Recipients can claim.
+	Enabled,
+	/// The claim period is over, the creator can reclaim what is left.
+	Expired,
+}
+
+#[derive(Clone, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug, PartialEq, Eq)]
+pub struct RecipientFund<Balance, Period> {
+	pub total: Balance,
+	pub claimed: Balance,
+	/// Time after the start of the airdrop at which the whole fund is vested.
+	pub vesting_period: Period,
+}
+
+/// Signature of `prefix` followed by the hex encoded SCALE encoding of the airdrop ID and the
+/// reward account, by the key of a recipient.
+#[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum Proof<AccountId> {
+	RelayChain(AccountId, MultiSignature),
+	Ethereum(EcdsaSignature),
+	Cosmos(CosmosPublicKey, CosmosEcdsaSignature),
+}
+
+#[derive(Hash, Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum Identity<AccountId> {
+	RelayChain(AccountId),
+	Ethereum(EthereumAddress),
+	Cosmos(CosmosPublicKey),
+}
@@ -0,0 +1,316 @@
+use crate::{mock::*, models::AirdropState, pallet::*};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::DispatchResult,
+	traits::{
+		fungible::{Inspect, Mutate},
+		Hooks,
+	},
+	unsigned::ValidateUnsigned,
+};
+use sp_runtime::{
+	transaction_validity::{InvalidTransaction, TransactionSource},
+	DispatchError,
+};
+
+const FUND: Balance = 1_000;
+const VESTING_PERIOD: Moment = 100;
+
+fn create_airdrop(schedule: Moment) -> AirdropId {
+	assert_ok!(Balances::mint_into(&ALICE, 1_000_000));
+	assert_ok!(Airdrop::create_airdrop(RuntimeOrigin::signed(ALICE), None, schedule));
+	AirdropCount::<Test>::get()
+}
+
+fn add_recipients(airdrop_id: AirdropId, keys: &[ClaimKey]) {
+	assert_ok!(Airdrop::add_recipient(
+		RuntimeOrigin::signed(ALICE),
+		airdrop_id,
+		keys.iter().map(|key| (key.identity(), FUND, VESTING_PERIOD)).collect()
+	));
+}
+
+fn claim(airdrop_id: AirdropId, key: &ClaimKey, reward_account: AccountId) -> DispatchResult {
+	Airdrop::claim(
+		RuntimeOrigin::none(),
+		airdrop_id,
+		reward_account,
+		key.proof(airdrop_id, reward_account),
+	)
+	.map(|_| ())
+	.map_err(|e| e.error)
+}
+
+#[test]
+fn add_and_remove_recipients() {
+	new_test_ext().execute_with(|| {
+		let keys = ClaimKey::from_seed(1);
+		let airdrop_id = create_airdrop(10);
+		System::assert_last_event(Event::<Test>::AirdropCreated { airdrop_id, by: ALICE }.into());
+
+		// 1. Recipients are funded by the creator.
+		add_recipients(airdrop_id, &keys);
+		let account = Airdrop::account_id(airdrop_id);
+		assert_eq!(Balances::balance(&account), Stake::get() + 3 * FUND);
+		assert_noop!(
+			Airdrop::add_recipient(
+				RuntimeOrigin::signed(ALICE),
+				airdrop_id,
+				vec![(keys[0].identity(), FUND, VESTING_PERIOD)]
+			),
+			Error::<Test>::RecipientAlreadyAdded
+		);
+
+		// 2. Only the creator can remove a recipient, which is refunded.
+		assert_noop!(
+			Airdrop::remove_recipient(RuntimeOrigin::signed(BOB), airdrop_id, keys[0].identity()),
+			Error::<Test>::NotAirdropCreator
+		);
+		assert_ok!(Airdrop::remove_recipient(
+			RuntimeOrigin::signed(ALICE),
+			airdrop_id,
+			keys[0].identity()
+		));
+		assert_eq!(Balances::balance(&account), Stake::get() + 2 * FUND);
+		let airdrop = Airdrop::airdrops(airdrop_id).expect("airdrop exists");
+		assert_eq!((airdrop.total_recipients, airdrop.total_funds), (2, 2 * FUND));
+
+		// 3. Recipients can't be added once started.
+		assert_ok!(Airdrop::enable_airdrop(RuntimeOrigin::signed(ALICE), airdrop_id));
+		assert_noop!(
+			Airdrop::add_recipient(
+				RuntimeOrigin::signed(ALICE),
+				airdrop_id,
+				vec![(keys[0].identity(), FUND, VESTING_PERIOD)]
+			),
+			Error::<Test>::AirdropAlreadyStarted
+		);
+	})
+}
+
+#[test]
+fn claims_vest_by_schedule() {
+	new_test_ext().execute_with(|| {
+		let keys = ClaimKey::from_seed(1);
+		let airdrop_id = create_airdrop(10);
+		add_recipients(airdrop_id, &keys);
+		assert_noop!(claim(airdrop_id, &keys[0], BOB), Error::<Test>::AirdropNotStarted);
+		assert_ok!(Airdrop::enable_airdrop(RuntimeOrigin::signed(ALICE), airdrop_id));
+
+		// 1. Claims release the fund vested by whole steps of the schedule.
+		Timestamp::set_timestamp(1_025);
+		assert_ok!(claim(airdrop_id, &keys[0], BOB));
+		assert_eq!(Balances::balance(&BOB), 200);
+		System::assert_last_event(
+			Event::<Test>::Claimed {
+				airdrop_id,
+				identity: keys[0].identity(),
+				recipient: BOB,
+				amount: 200,
+			}
+			.into(),
+		);
+		assert_noop!(claim(airdrop_id, &keys[0], BOB), Error::<Test>::NothingToClaim);
+
+		// 2. Every kind of key can claim, to any account.
+		Timestamp::set_timestamp(1_000 + VESTING_PERIOD);
+		assert_ok!(claim(airdrop_id, &keys[0], BOB));
+		assert_ok!(claim(airdrop_id, &keys[1], CHARLIE));
+		assert_eq!(Balances::balance(&BOB), FUND);
+		assert_eq!(Balances::balance(&CHARLIE), FUND);
+
+		// 3. The last claim ends the airdrop and returns the stake.
+		let creator_balance = Balances::balance(&ALICE);
+		assert_ok!(claim(airdrop_id, &keys[2], CHARLIE));
+		System::assert_last_event(
+			Event::<Test>::AirdropEnded { airdrop_id, at: 1_100, unclaimed_funds: 0 }.into(),
+		);
+		assert_eq!(Balances::balance(&ALICE), creator_balance + Stake::get());
+		assert!(Airdrop::airdrops(airdrop_id).is_none());
+		assert!(Airdrop::recipient_funds(airdrop_id, keys[0].identity()).is_none());
+	})
+}
+
+#[test]
+fn committed_recipients_claim_with_merkle_proof() {
+	new_test_ext().execute_with(|| {
+		let keys = ClaimKey::from_seed(2);
+		let airdrop_id = create_airdrop(0);
+		let leaves = [
+			crate::merkle_leaf(&keys[0].identity(), FUND, 0_u64),
+			crate::merkle_leaf(&keys[1].identity(), 2 * FUND, VESTING_PERIOD),
+		];
+		let (merkle_root, proofs) = merkle_tree(leaves);
+		assert_ok!(Airdrop::commit_recipients(
+			RuntimeOrigin::signed(ALICE),
+			airdrop_id,
+			merkle_root,
+			3 * FUND
+		));
+		assert_noop!(
+			Airdrop::commit_recipients(
+				RuntimeOrigin::signed(ALICE),
+				airdrop_id,
+				merkle_root,
+				3 * FUND
+			),
+			Error::<Test>::RecipientsAlreadyCommitted
+		);
+		assert_ok!(Airdrop::enable_airdrop(RuntimeOrigin::signed(ALICE), airdrop_id));
+		let claim_committed = |key: &ClaimKey, amount, vesting_period, proof: &[_]| {
+			Airdrop::claim_committed(
+				RuntimeOrigin::none(),
+				airdrop_id,
+				BOB,
+				key.proof(airdrop_id, BOB),
+				amount,
+				vesting_period,
+				proof.to_vec().try_into().expect("proof fits"),
+			)
+		};
+
+		// 1. The committed fund must match the Merkle tree.
+		assert_noop!(
+			claim_committed(&keys[0], 2 * FUND, 0, &proofs[0]),
+			Error::<Test>::InvalidMerkleProof
+		);
+		assert_noop!(
+			claim_committed(&keys[0], FUND, 0, &proofs[1]),
+			Error::<Test>::InvalidMerkleProof
+		);
+		let airdrop = Airdrop::airdrops(airdrop_id).expect("airdrop exists");
+		assert_eq!(airdrop.total_recipients, 0);
+		assert_ok!(claim_committed(&keys[0], FUND, 0, &proofs[0]));
+		assert_eq!(Balances::balance(&BOB), FUND);
+		let airdrop = Airdrop::airdrops(airdrop_id).expect("airdrop exists");
+		assert_eq!(airdrop.total_recipients, 1);
+
+		// 2. Once revealed, the recipient is stored and claims like the others.
+		Timestamp::set_timestamp(1_050);
+		assert_ok!(claim_committed(&keys[1], 2 * FUND, VESTING_PERIOD, &proofs[1]));
+		assert_eq!(Balances::balance(&BOB), 2 * FUND);
+		assert_noop!(claim(airdrop_id, &keys[0], BOB), Error::<Test>::NothingToClaim);
+		Timestamp::set_timestamp(1_100);
+		assert_ok!(claim(airdrop_id, &keys[1], BOB));
+		assert_eq!(Balances::balance(&BOB), 3 * FUND);
+		assert!(Airdrop::airdrops(airdrop_id).is_none());
+	})
+}
+
+#[test]
+fn disable_refunds_creator_before_start_or_after_expiry() {
+	new_test_ext().execute_with(|| {
+		let keys = ClaimKey::from_seed(3);
+
+		// 1. An airdrop not yet started can be disabled.
+		let airdrop_id = create_airdrop(10);
+		add_recipients(airdrop_id, &keys);
+		let creator_balance = Balances::balance(&ALICE);
+		assert_ok!(Airdrop::disable_airdrop(RuntimeOrigin::signed(ALICE), airdrop_id));
+		assert_eq!(Balances::balance(&ALICE), creator_balance + Stake::get() + 3 * FUND);
+
+		// Recipients are removed by batches of `RecipientRemovalsPerBlock`.
+		let remaining = || {
+			keys.iter()
+				.filter(|key| Airdrop::recipient_funds(airdrop_id, key.identity()).is_some())
+				.count()
+		};
+		assert_eq!(remaining(), 1);
+		assert!(EndedAirdrops::<Test>::contains_key(airdrop_id));
+		Airdrop::on_initialize(2);
+		assert_eq!(remaining(), 0);
+		assert!(!EndedAirdrops::<Test>::contains_key(airdrop_id));
+
+		// 2. Started, it can only be disabled after the claim period.
+		let airdrop_id = create_airdrop(10);
+		add_recipients(airdrop_id, &keys);
+		assert_ok!(Airdrop::enable_airdrop(RuntimeOrigin::signed(ALICE), airdrop_id));
+		assert_noop!(
+			Airdrop::disable_airdrop(RuntimeOrigin::signed(ALICE), airdrop_id),
+			Error::<Test>::AirdropIsActive
+		);
+		Timestamp::set_timestamp(1_000 + VESTING_PERIOD);
+		assert_ok!(claim(airdrop_id, &keys[0], BOB));
+		Timestamp::set_timestamp(1_000 + ClaimPeriod::get());
+		let airdrop = Airdrop::airdrops(airdrop_id).expect("airdrop exists");
+		assert_eq!(Airdrop::airdrop_state(&airdrop), AirdropState::Expired);
+		assert_noop!(claim(airdrop_id, &keys[1], BOB), Error::<Test>::AirdropExpired);
+		assert_noop!(
+			Airdrop::disable_airdrop(RuntimeOrigin::signed(BOB), airdrop_id),
+			Error::<Test>::NotAirdropCreator
+		);
+		assert_ok!(Airdrop::disable_airdrop(RuntimeOrigin::signed(ALICE), airdrop_id));
+		System::assert_last_event(
+			Event::<Test>::AirdropEnded {
+				airdrop_id,
+				at: 1_000 + ClaimPeriod::get(),
+				unclaimed_funds: 2 * FUND,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::balance(&Airdrop::account_id(airdrop_id)), 0);
+	})
+}
+
+#[test]
+fn unsigned_claims_require_valid_proof() {
+	new_test_ext().execute_with(|| {
+		let keys = ClaimKey::from_seed(4);
+		let airdrop_id = create_airdrop(10);
+		add_recipients(airdrop_id, &keys);
+		assert_ok!(Airdrop::enable_airdrop(RuntimeOrigin::signed(ALICE), airdrop_id));
+		Timestamp::set_timestamp(1_000 + VESTING_PERIOD);
+
+		// A proof for another reward account identifies another key.
+		let call = Call::<Test>::claim {
+			airdrop_id,
+			reward_account: BOB,
+			proof: keys[1].proof(airdrop_id, CHARLIE),
+		};
+		assert_eq!(
+			Airdrop::validate_unsigned(TransactionSource::External, &call),
+			Err(InvalidTransaction::Custom(ValidityError::NoFunds as u8).into())
+		);
+		assert_noop!(
+			Airdrop::claim(
+				RuntimeOrigin::none(),
+				airdrop_id,
+				BOB,
+				keys[1].proof(airdrop_id, CHARLIE)
+			),
+			Error::<Test>::RecipientNotFound
+		);
+		assert_noop!(
+			Airdrop::claim(
+				RuntimeOrigin::signed(BOB),
+				airdrop_id,
+				BOB,
+				keys[1].proof(airdrop_id, BOB)
+			),
+			DispatchError::BadOrigin
+		);
+		let call = Call::<Test>::claim {
+			airdrop_id,
+			reward_account: BOB,
+			proof: keys[1].proof(airdrop_id, BOB),
+		};
+		assert_ok!(Airdrop::validate_unsigned(TransactionSource::External, &call));
+
+		// A proof for another airdrop of the same recipient can't be replayed.
+		let other_airdrop_id = create_airdrop(10);
+		add_recipients(other_airdrop_id, &keys);
+		assert_ok!(Airdrop::enable_airdrop(RuntimeOrigin::signed(ALICE), other_airdrop_id));
+		Timestamp::set_timestamp(1_000 + 2 * VESTING_PERIOD);
+		for key in &keys {
+			let proof = key.proof(airdrop_id, BOB);
+			let call = Call::<Test>::claim {
+				airdrop_id: other_airdrop_id,
+				reward_account: BOB,
+				proof: proof.clone(),
+			};
+			assert!(Airdrop::validate_unsigned(TransactionSource::External, &call).is_err());
+			assert!(Airdrop::claim(RuntimeOrigin::none(), other_airdrop_id, BOB, proof).is_err());
+		}
+		assert_ok!(claim(other_airdrop_id, &keys[1], BOB));
+	})
+}
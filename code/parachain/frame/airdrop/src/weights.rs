@@ -0,0 +1,46 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+pub trait WeightInfo {
+	fn create_airdrop() -> Weight;
+	fn add_recipient(x: u32) -> Weight;
+	fn remove_recipient() -> Weight;
+	fn commit_recipients() -> Weight;
+	fn enable_airdrop() -> Weight;
+	fn disable_airdrop(x: u32) -> Weight;
+	fn claim(x: u32) -> Weight;
+	fn claim_committed(x: u32) -> Weight;
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_airdrop() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn add_recipient(_x: u32) -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn remove_recipient() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn commit_recipients() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn enable_airdrop() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn disable_airdrop(_x: u32) -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn claim(_x: u32) -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn claim_committed(_x: u32) -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+}
@@ -7,15 +7,23 @@ use crate::{self as pallet_assets_registry};
 #[allow(unused_imports)]
 use crate::Pallet as AssetsRegistry;
 use composable_traits::{
-	assets::{AssetInfo, AssetInfoUpdate, BiBoundedAssetName, BiBoundedAssetSymbol},
+	assets::{
+		AssetInfo, AssetInfoUpdate, AssetVerification, BiBoundedAssetName, BiBoundedAssetSymbol,
+		BiBoundedAssetTag, BiBoundedIbcDenom, ExtendedAssetMetadata, GenerateAssetId,
+	},
+	currency::FeeAssetPriceSource,
 	rational,
 	storage::UpdateValue,
 };
 
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::Currency;
 use frame_system::RawOrigin;
 use primitives::currency::{ForeignAssetId, VersionedMultiLocation};
-use sp_runtime::traits::Zero;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	Permill,
+};
 use sp_std::prelude::*;
 use xcm::{
 	latest::MultiLocation,
@@ -107,6 +115,99 @@ benchmarks! {
 			.expect("Asset exists");
 		let location_new =T::ForeignAssetId::decode(&mut ForeignAssetId::Xcm(VersionedMultiLocation::V3(MultiLocation::new(1, X1(Parachain(4321))))).encode().as_ref()).expect("ok");
 	}: _(RawOrigin::Root, local_asset_id, Some(location_new))
+
+	set_extended_metadata {
+		let protocol_id = *b"benc";
+		let nonce = 1_u64;
+		let asset_info = AssetInfo {
+			name: Some(BiBoundedAssetName::from_vec(b"Cosmos Hub".to_vec()).expect("String is within bounds")),
+			symbol: Some(BiBoundedAssetSymbol::from_vec(b"ATOM".to_vec()).expect("String is within bounds")),
+			decimals: Some(6),
+			existential_deposit: T::Balance::zero(),
+			ratio: Some(rational!(42 / 123)),
+		};
+
+		AssetsRegistry::<T>::register_asset(
+			RawOrigin::Root.into(),
+			protocol_id,
+			nonce,
+			None,
+			asset_info,
+		)
+		.expect("Asset details are non-duplicate and valid");
+		let local_asset_id = AssetsRegistry::<T>::generate_asset_id(protocol_id, nonce);
+		let metadata = ExtendedAssetMetadata {
+			ibc_denom: Some(BiBoundedIbcDenom::from_vec(b"transfer/channel-2/uatom".to_vec()).expect("String is within bounds")),
+			verification: AssetVerification::Verified,
+			tags: vec![BiBoundedAssetTag::from_vec(b"cosmos".to_vec()).expect("String is within bounds")].try_into().expect("Tags are within bounds"),
+			..Default::default()
+		};
+		AssetsRegistry::<T>::set_extended_metadata(RawOrigin::Root.into(), local_asset_id, metadata.clone())
+			.expect("Asset exists");
+	}: _(RawOrigin::Root, local_asset_id, metadata)
+
+	propose_asset {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, T::ProposalDeposit::get().saturating_mul(10_u64.into()));
+		let location = T::ForeignAssetId::decode(&mut ForeignAssetId::Xcm(VersionedMultiLocation::V3(MultiLocation::here())).encode().as_ref()).expect("ok");
+		let min_fees = (0..MAX_PROPOSAL_MIN_FEES).map(|network_id| (network_id, T::MinProposedFee::get())).collect::<Vec<_>>().try_into().expect("Fees are within bounds");
+	}: _(
+		RawOrigin::Signed(caller),
+		location,
+		Some(BiBoundedAssetName::from_vec(b"Kusama".to_vec()).expect("String is within bounds")),
+		Some(BiBoundedAssetSymbol::from_vec(b"KSM".to_vec()).expect("String is within bounds")),
+		Some(12),
+		T::MinProposedExistentialDeposit::get(),
+		min_fees
+	)
+
+	approve_asset_proposal {
+		let proposer: T::AccountId = account("proposer", 0, 0);
+		T::Currency::make_free_balance_be(&proposer, T::ProposalDeposit::get().saturating_mul(10_u64.into()));
+		let location = T::ForeignAssetId::decode(&mut ForeignAssetId::Xcm(VersionedMultiLocation::V3(MultiLocation::here())).encode().as_ref()).expect("ok");
+		let min_fees = (0..MAX_PROPOSAL_MIN_FEES).map(|network_id| (network_id, T::MinProposedFee::get())).collect::<Vec<_>>().try_into().expect("Fees are within bounds");
+		AssetsRegistry::<T>::propose_asset(
+			RawOrigin::Signed(proposer).into(),
+			location,
+			Some(BiBoundedAssetName::from_vec(b"Kusama".to_vec()).expect("String is within bounds")),
+			Some(BiBoundedAssetSymbol::from_vec(b"KSM".to_vec()).expect("String is within bounds")),
+			Some(12),
+			T::MinProposedExistentialDeposit::get(),
+			min_fees,
+		)
+		.expect("Proposal is valid");
+	}: _(RawOrigin::Root, 0)
+
+	reject_asset_proposal {
+		let proposer: T::AccountId = account("proposer", 0, 0);
+		T::Currency::make_free_balance_be(&proposer, T::ProposalDeposit::get().saturating_mul(10_u64.into()));
+		let location = T::ForeignAssetId::decode(&mut ForeignAssetId::Xcm(VersionedMultiLocation::V3(MultiLocation::here())).encode().as_ref()).expect("ok");
+		AssetsRegistry::<T>::propose_asset(
+			RawOrigin::Signed(proposer).into(),
+			location,
+			None,
+			None,
+			None,
+			T::MinProposedExistentialDeposit::get(),
+			Default::default(),
+		)
+		.expect("Proposal is valid");
+	}: _(RawOrigin::Root, 0)
+
+	set_fee_price_source {
+		let protocol_id = *b"benc";
+		let nonce = 1_u64;
+		let asset_info = AssetInfo {
+			name: None,
+			symbol: None,
+			decimals: Some(6),
+			existential_deposit: T::Balance::zero(),
+			ratio: Some(rational!(42 / 123)),
+		};
+		AssetsRegistry::<T>::register_asset(RawOrigin::Root.into(), protocol_id, nonce, None, asset_info)
+			.expect("Asset details are non-duplicate and valid");
+		let local_asset_id = AssetsRegistry::<T>::generate_asset_id(protocol_id, nonce);
+	}: _(RawOrigin::Root, local_asset_id, Some((FeeAssetPriceSource::Oracle, Permill::from_percent(20))))
 }
 
 impl_benchmark_test_suite!(AssetsRegistry, crate::runtime::new_test_ext(), crate::runtime::Runtime);
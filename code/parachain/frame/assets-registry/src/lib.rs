@@ -35,25 +35,72 @@ pub mod pallet {
 	use composable_support::math::safe::safe_multiply_by_rational;
 	use composable_traits::{
 		assets::{
-			Asset, AssetInfo, AssetInfoUpdate, AssetType, AssetTypeInspect, BiBoundedAssetName,
-			BiBoundedAssetSymbol, CreateAsset, GenerateAssetId, InspectRegistryMetadata,
+			Asset, AssetFilter, AssetInfo, AssetInfoUpdate, AssetType, AssetTypeInspect,
+			BiBoundedAssetName, BiBoundedAssetSymbol, CreateAsset, ExtendedAsset,
+			ExtendedAssetMetadata, GenerateAssetId, InspectRegistryMetadata,
 			MutateRegistryMetadata,
 		},
-		currency::{AssetExistentialDepositInspect, BalanceLike, ForeignByNative},
+		currency::{
+			AssetExistentialDepositInspect, BalanceLike, FeeAssetPriceSource, FeeAssetPriceSources,
+			ForeignByNative,
+		},
 		storage::UpdateValue,
 		xcm::assets::{RemoteAssetRegistryInspect, RemoteAssetRegistryMutate},
 	};
 	use frame_support::{
 		dispatch::DispatchResultWithPostInfo,
 		pallet_prelude::*,
-		traits::{tokens::ConversionToAssetBalance, EnsureOrigin},
+		storage::with_storage_layer,
+		traits::{
+			tokens::ConversionToAssetBalance, BalanceStatus, EnsureOrigin, ReservableCurrency,
+		},
 	};
 
 	use frame_system::pallet_prelude::*;
 	use scale_info::TypeInfo;
-	use sp_runtime::traits::Convert;
+	use sp_runtime::{
+		traits::{Convert, Saturating},
+		Permill,
+	};
 	use sp_std::{borrow::ToOwned, fmt::Debug, str, vec::Vec};
 
+	/// Number of previous foreign locations kept per asset.
+	pub const MAX_LOCATION_HISTORY: u32 = 8;
+
+	/// Number of networks a proposal can set minimal fees for.
+	pub const MAX_PROPOSAL_MIN_FEES: u32 = 8;
+
+	/// Number of proposals whose challenge period can end in the same block.
+	pub const MAX_PROPOSALS_PER_BLOCK: u32 = 16;
+
+	/// Protocol id of the assets registered by proposals, the proposal id being the nonce.
+	pub const PROPOSAL_PROTOCOL_ID: [u8; 4] = *b"prop";
+
+	pub type ProposalId = u64;
+
+	pub type AssetProposalOf<T> = AssetProposal<
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::Balance,
+		<T as frame_system::Config>::BlockNumber,
+		<T as Config>::ForeignAssetId,
+	>;
+
+	/// Foreign asset proposed by anyone, registered unless rejected before `challenge_end`.
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct AssetProposal<AccountId, Balance, BlockNumber, ForeignAssetId> {
+		pub proposer: AccountId,
+		/// Reserved from the proposer, slashed if the proposal is rejected.
+		pub deposit: Balance,
+		pub location: ForeignAssetId,
+		pub name: Option<BiBoundedAssetName>,
+		pub symbol: Option<BiBoundedAssetSymbol>,
+		pub decimals: Option<u8>,
+		pub existential_deposit: Balance,
+		/// Minimal fees to send the asset to other networks, by network id.
+		pub min_fees: BoundedVec<(u32, Balance), ConstU32<MAX_PROPOSAL_MIN_FEES>>,
+		pub challenge_end: BlockNumber,
+	}
+
 	/// The module configuration trait.
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -99,6 +146,34 @@ pub mod pallet {
 		/// Network id, unique per chain
 		#[pallet::constant]
 		type NetworkId: Get<u32>;
+
+		/// Currency of the deposits of asset proposals.
+		type Currency: ReservableCurrency<Self::AccountId, Balance = Self::Balance>;
+
+		/// Deposit reserved from the proposer of an asset.
+		#[pallet::constant]
+		type ProposalDeposit: Get<Self::Balance>;
+
+		/// Minimal existential deposit of proposed assets.
+		#[pallet::constant]
+		type MinProposedExistentialDeposit: Get<Self::Balance>;
+
+		/// Minimal fee to send a proposed asset to another network.
+		#[pallet::constant]
+		type MinProposedFee: Get<Self::Balance>;
+
+		/// Blocks during which an asset proposal can be rejected before it is registered.
+		#[pallet::constant]
+		type ChallengePeriod: Get<Self::BlockNumber>;
+
+		/// Origin rejecting asset proposals.
+		type RejectProposalOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Deposits of rejected proposals are transferred to treasury.
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// Identifier of the pablo pools pricing fee assets.
+		type PoolId: FullCodec + Copy + Eq + PartialEq + Debug + TypeInfo + MaxEncodedLen;
 	}
 
 	#[pallet::pallet]
@@ -134,6 +209,18 @@ pub mod pallet {
 	pub type AssetRatio<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::LocalAssetId, Rational, OptionQuery>;
 
+	/// Source of the price of fee assets in native currency, and the maximum deviation of that
+	/// price from their ratio.
+	#[pallet::storage]
+	#[pallet::getter(fn fee_price_source)]
+	pub type FeePriceSources<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::LocalAssetId,
+		(FeeAssetPriceSource<T::PoolId>, Permill),
+		OptionQuery,
+	>;
+
 	/// The minimum balance of an asset required for the balance to be stored on chain
 	#[pallet::storage]
 	#[pallet::getter(fn existential_deposit)]
@@ -158,6 +245,53 @@ pub mod pallet {
 	pub type AssetDecimals<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::LocalAssetId, Exponent, OptionQuery>;
 
+	/// Extended metadata of an asset
+	#[pallet::storage]
+	#[pallet::getter(fn extended_metadata)]
+	pub type ExtendedMetadata<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::LocalAssetId, ExtendedAssetMetadata, OptionQuery>;
+
+	/// Mapping the SHA-256 hash of an IBC denom, as in `ibc/{hash}` denoms, to local asset.
+	#[pallet::storage]
+	#[pallet::getter(fn from_ibc_denom_hash)]
+	pub type IbcDenomHashToLocal<T: Config> =
+		StorageMap<_, Identity, [u8; 32], T::LocalAssetId, OptionQuery>;
+
+	/// Previous foreign locations of an asset, oldest first.
+	#[pallet::storage]
+	#[pallet::getter(fn location_history)]
+	pub type LocationHistory<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::LocalAssetId,
+		BoundedVec<T::ForeignAssetId, ConstU32<MAX_LOCATION_HISTORY>>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	pub type ProposalCount<T: Config> = StorageValue<_, ProposalId, OptionQuery>;
+
+	/// Pending asset proposals.
+	#[pallet::storage]
+	#[pallet::getter(fn asset_proposal)]
+	pub type AssetProposals<T: Config> =
+		StorageMap<_, Twox64Concat, ProposalId, AssetProposalOf<T>, OptionQuery>;
+
+	/// Pending asset proposal of a foreign location.
+	#[pallet::storage]
+	pub type ProposedLocations<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ForeignAssetId, ProposalId, OptionQuery>;
+
+	/// Proposals whose challenge period ends at a block.
+	#[pallet::storage]
+	pub type ProposalsDue<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<ProposalId, ConstU32<MAX_PROPOSALS_PER_BLOCK>>,
+		OptionQuery,
+	>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub assets: sp_std::vec::Vec<(u64, Option<T::ForeignAssetId>, AssetInfo<T::Balance>)>,
@@ -209,6 +343,34 @@ pub mod pallet {
 			foreign_asset_id: T::ForeignAssetId,
 			amount: Option<T::Balance>,
 		},
+		ExtendedMetadataUpdated {
+			asset_id: T::LocalAssetId,
+			metadata: ExtendedAssetMetadata,
+		},
+		FeePriceSourceUpdated {
+			asset_id: T::LocalAssetId,
+			source: Option<(FeeAssetPriceSource<T::PoolId>, Permill)>,
+		},
+		AssetProposed {
+			proposal_id: ProposalId,
+			proposer: T::AccountId,
+			location: T::ForeignAssetId,
+			challenge_end: T::BlockNumber,
+		},
+		AssetProposalApproved {
+			proposal_id: ProposalId,
+			asset_id: T::LocalAssetId,
+		},
+		/// The proposal could not be registered, e.g. because its location was registered in the
+		/// meantime. The deposit is returned.
+		AssetProposalFailed {
+			proposal_id: ProposalId,
+			error: DispatchError,
+		},
+		AssetProposalRejected {
+			proposal_id: ProposalId,
+			slashed: T::Balance,
+		},
 	}
 
 	#[pallet::error]
@@ -218,6 +380,30 @@ pub mod pallet {
 		AssetLocationIsNone,
 		StringExceedsMaxLength,
 		LocationIsUsed,
+		IbcDenomIsUsed,
+		ProposalNotFound,
+		LocationIsProposed,
+		TooManyProposals,
+		ExistentialDepositTooLow,
+		MinFeeTooLow,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let Some(proposal_ids) = ProposalsDue::<T>::take(now) else {
+				return T::DbWeight::get().reads(1)
+			};
+			for proposal_id in proposal_ids.iter() {
+				// approved or rejected during the challenge period otherwise
+				if let Some(proposal) = AssetProposals::<T>::get(proposal_id) {
+					Self::approve_proposal(*proposal_id, proposal);
+				}
+			}
+			T::DbWeight::get().reads_writes(1, 1).saturating_add(
+				T::WeightInfo::approve_asset_proposal().saturating_mul(proposal_ids.len() as u64),
+			)
+		}
 	}
 
 	#[pallet::call]
@@ -308,38 +494,305 @@ pub mod pallet {
 			} else {
 				let old_location = LocalToForeign::<T>::try_get(asset_id)
 					.map_err(|_| Error::<T>::AssetLocationIsNone)?;
-				ForeignToLocal::<T>::remove(old_location);
+				ForeignToLocal::<T>::remove(&old_location);
 				LocalToForeign::<T>::remove(asset_id);
+				Self::record_location(asset_id, old_location);
 				Self::deposit_event(Event::AssetLocationRemoved { asset_id });
 			}
 			Ok(().into())
 		}
+
+		/// Sets the extended metadata of an asset, replacing the previous one.
+		///
+		/// Emits:
+		/// * `ExtendedMetadataUpdated`
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_extended_metadata())]
+		pub fn set_extended_metadata(
+			origin: OriginFor<T>,
+			asset_id: T::LocalAssetId,
+			metadata: ExtendedAssetMetadata,
+		) -> DispatchResult {
+			T::UpdateAssetRegistryOrigin::ensure_origin(origin)?;
+			ensure!(ExistentialDeposit::<T>::contains_key(asset_id), Error::<T>::AssetNotFound);
+			let ibc_denom_hash =
+				metadata.ibc_denom.as_ref().map(|denom| Self::ibc_denom_hash(denom.as_vec()));
+			if let Some(ibc_denom_hash) = ibc_denom_hash {
+				ensure!(
+					IbcDenomHashToLocal::<T>::get(ibc_denom_hash)
+						.map_or(true, |other_asset_id| other_asset_id == asset_id),
+					Error::<T>::IbcDenomIsUsed
+				);
+			}
+			if let Some(old_ibc_denom) =
+				ExtendedMetadata::<T>::get(asset_id).and_then(|x| x.ibc_denom)
+			{
+				IbcDenomHashToLocal::<T>::remove(Self::ibc_denom_hash(old_ibc_denom.as_vec()));
+			}
+			if let Some(ibc_denom_hash) = ibc_denom_hash {
+				IbcDenomHashToLocal::<T>::insert(ibc_denom_hash, asset_id);
+			}
+			ExtendedMetadata::<T>::insert(asset_id, metadata.clone());
+			Self::deposit_event(Event::ExtendedMetadataUpdated { asset_id, metadata });
+			Ok(())
+		}
+
+		/// Proposes a foreign asset, reserving `ProposalDeposit` from the caller.
+		///
+		/// The asset is registered with the minimal fees to send it to other networks once the
+		/// challenge period ends, unless the proposal is rejected. The existential deposit and the
+		/// minimal fees cannot be lower than `MinProposedExistentialDeposit` and `MinProposedFee`.
+		///
+		/// Emits:
+		/// * `AssetProposed`
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::propose_asset())]
+		pub fn propose_asset(
+			origin: OriginFor<T>,
+			location: T::ForeignAssetId,
+			name: Option<BiBoundedAssetName>,
+			symbol: Option<BiBoundedAssetSymbol>,
+			decimals: Option<u8>,
+			existential_deposit: T::Balance,
+			min_fees: BoundedVec<(u32, T::Balance), ConstU32<MAX_PROPOSAL_MIN_FEES>>,
+		) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+			ensure!(
+				existential_deposit >= T::MinProposedExistentialDeposit::get(),
+				Error::<T>::ExistentialDepositTooLow
+			);
+			ensure!(
+				min_fees.iter().all(|(_, amount)| *amount >= T::MinProposedFee::get()),
+				Error::<T>::MinFeeTooLow
+			);
+			ensure!(!ForeignToLocal::<T>::contains_key(&location), Error::<T>::LocationIsUsed);
+			ensure!(
+				!ProposedLocations::<T>::contains_key(&location),
+				Error::<T>::LocationIsProposed
+			);
+			let challenge_end =
+				<frame_system::Pallet<T>>::block_number().saturating_add(T::ChallengePeriod::get());
+			let proposal_id = ProposalCount::<T>::get().unwrap_or_default();
+			ProposalsDue::<T>::try_mutate(challenge_end, |due| {
+				due.get_or_insert_with(Default::default)
+					.try_push(proposal_id)
+					.map_err(|_| Error::<T>::TooManyProposals)
+			})?;
+			let deposit = T::ProposalDeposit::get();
+			T::Currency::reserve(&proposer, deposit)?;
+			ProposalCount::<T>::set(Some(proposal_id.saturating_add(1)));
+			ProposedLocations::<T>::insert(&location, proposal_id);
+			AssetProposals::<T>::insert(
+				proposal_id,
+				AssetProposal {
+					proposer: proposer.clone(),
+					deposit,
+					location: location.clone(),
+					name,
+					symbol,
+					decimals,
+					existential_deposit,
+					min_fees,
+					challenge_end,
+				},
+			);
+			Self::deposit_event(Event::AssetProposed {
+				proposal_id,
+				proposer,
+				location,
+				challenge_end,
+			});
+			Ok(())
+		}
+
+		/// Registers a proposed asset before the end of its challenge period.
+		///
+		/// Emits:
+		/// * `AssetProposalApproved` or `AssetProposalFailed`
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::approve_asset_proposal())]
+		pub fn approve_asset_proposal(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+		) -> DispatchResult {
+			T::UpdateAssetRegistryOrigin::ensure_origin(origin)?;
+			let proposal =
+				AssetProposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+			Self::approve_proposal(proposal_id, proposal);
+			Ok(())
+		}
+
+		/// Rejects a proposed asset, transferring its deposit to treasury.
+		///
+		/// Emits:
+		/// * `AssetProposalRejected`
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::reject_asset_proposal())]
+		pub fn reject_asset_proposal(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+		) -> DispatchResult {
+			T::RejectProposalOrigin::ensure_origin(origin)?;
+			let proposal =
+				AssetProposals::<T>::take(proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+			ProposedLocations::<T>::remove(&proposal.location);
+			let not_slashed = T::Currency::repatriate_reserved(
+				&proposal.proposer,
+				&T::TreasuryAccount::get(),
+				proposal.deposit,
+				BalanceStatus::Free,
+			)?;
+			Self::deposit_event(Event::AssetProposalRejected {
+				proposal_id,
+				slashed: proposal.deposit.saturating_sub(not_slashed),
+			});
+			Ok(())
+		}
+
+		/// Sets how to price a fee asset in native currency, and the maximum deviation of that
+		/// price from the ratio of the asset. Without a source, the asset is priced by its ratio.
+		///
+		/// Emits:
+		/// * `FeePriceSourceUpdated`
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_fee_price_source())]
+		pub fn set_fee_price_source(
+			origin: OriginFor<T>,
+			asset_id: T::LocalAssetId,
+			source: Option<(FeeAssetPriceSource<T::PoolId>, Permill)>,
+		) -> DispatchResult {
+			T::UpdateAssetRegistryOrigin::ensure_origin(origin)?;
+			ensure!(ExistentialDeposit::<T>::contains_key(asset_id), Error::<T>::AssetNotFound);
+			FeePriceSources::<T>::set(asset_id, source);
+			Self::deposit_event(Event::FeePriceSourceUpdated { asset_id, source });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
 		pub fn get_all_assets() -> Vec<Asset<T::LocalAssetId, T::Balance, T::ForeignAssetId>> {
-			ExistentialDeposit::<T>::iter_keys()
-				.map(|asset_id| {
-					let name = AssetName::<T>::get(asset_id).map(Into::into);
-					let symbol = AssetSymbol::<T>::get(asset_id).map(Into::into);
-					let foreign_id = LocalToForeign::<T>::get(asset_id);
-					let decimals =
-						<Pallet<T> as InspectRegistryMetadata>::decimals(&asset_id).unwrap_or(12);
-					let ratio = AssetRatio::<T>::get(asset_id);
-					let existential_deposit =
-						ExistentialDeposit::<T>::get(asset_id).unwrap_or_default();
-
-					Asset {
-						name,
-						symbol,
-						id: asset_id,
-						decimals,
-						ratio,
-						foreign_id,
-						existential_deposit,
+			ExistentialDeposit::<T>::iter_keys().map(Self::get_asset).collect::<Vec<_>>()
+		}
+
+		/// Registered assets matching `filter`, ordered by id, skipping the first `offset` and
+		/// returning at most `limit` of them.
+		pub fn get_assets_filtered(
+			filter: AssetFilter,
+			offset: u32,
+			limit: u32,
+		) -> Vec<ExtendedAsset<T::LocalAssetId, T::Balance, T::ForeignAssetId>> {
+			let mut asset_ids = ExistentialDeposit::<T>::iter_keys().collect::<Vec<_>>();
+			asset_ids.sort();
+			asset_ids
+				.into_iter()
+				.map(Self::get_extended_asset)
+				.filter(|asset| filter.matches(asset))
+				.skip(offset as usize)
+				.take(limit as usize)
+				.collect()
+		}
+
+		/// Registered assets with `symbol`, ordered by id.
+		pub fn get_assets_by_symbol(
+			symbol: Vec<u8>,
+		) -> Vec<ExtendedAsset<T::LocalAssetId, T::Balance, T::ForeignAssetId>> {
+			let mut asset_ids = AssetSymbol::<T>::iter()
+				.filter(|(_, asset_symbol)| asset_symbol.as_vec() == &symbol)
+				.map(|(asset_id, _)| asset_id)
+				.collect::<Vec<_>>();
+			asset_ids.sort();
+			asset_ids.into_iter().map(Self::get_extended_asset).collect()
+		}
+
+		/// The asset with the IBC denom hashing to `ibc_denom_hash`.
+		pub fn get_asset_by_ibc_denom_hash(
+			ibc_denom_hash: [u8; 32],
+		) -> Option<ExtendedAsset<T::LocalAssetId, T::Balance, T::ForeignAssetId>> {
+			IbcDenomHashToLocal::<T>::get(ibc_denom_hash).map(Self::get_extended_asset)
+		}
+
+		/// Hash of an IBC denom path, as in `ibc/{hash}` denoms.
+		pub fn ibc_denom_hash(ibc_denom: &[u8]) -> [u8; 32] {
+			sp_io::hashing::sha2_256(ibc_denom)
+		}
+
+		fn get_asset(
+			asset_id: T::LocalAssetId,
+		) -> Asset<T::LocalAssetId, T::Balance, T::ForeignAssetId> {
+			let name = AssetName::<T>::get(asset_id).map(Into::into);
+			let symbol = AssetSymbol::<T>::get(asset_id).map(Into::into);
+			let foreign_id = LocalToForeign::<T>::get(asset_id);
+			let decimals =
+				<Pallet<T> as InspectRegistryMetadata>::decimals(&asset_id).unwrap_or(12);
+			let ratio = AssetRatio::<T>::get(asset_id);
+			let existential_deposit = ExistentialDeposit::<T>::get(asset_id).unwrap_or_default();
+
+			Asset { name, symbol, id: asset_id, decimals, ratio, foreign_id, existential_deposit }
+		}
+
+		fn get_extended_asset(
+			asset_id: T::LocalAssetId,
+		) -> ExtendedAsset<T::LocalAssetId, T::Balance, T::ForeignAssetId> {
+			ExtendedAsset {
+				asset: Self::get_asset(asset_id),
+				metadata: ExtendedMetadata::<T>::get(asset_id).unwrap_or_default(),
+				location_history: LocationHistory::<T>::get(asset_id)
+					.map(Into::into)
+					.unwrap_or_default(),
+			}
+		}
+
+		/// Registers a proposed asset with its minimal fees and returns the deposit, or only
+		/// returns the deposit if the asset cannot be registered.
+		fn approve_proposal(proposal_id: ProposalId, proposal: AssetProposalOf<T>) {
+			AssetProposals::<T>::remove(proposal_id);
+			ProposedLocations::<T>::remove(&proposal.location);
+			T::Currency::unreserve(&proposal.proposer, proposal.deposit);
+			let asset_id = Self::generate_asset_id(PROPOSAL_PROTOCOL_ID, proposal_id);
+			let registered = with_storage_layer(|| {
+				<Self as RemoteAssetRegistryMutate>::register_asset(
+					asset_id,
+					Some(proposal.location.clone()),
+					AssetInfo {
+						name: proposal.name,
+						symbol: proposal.symbol,
+						decimals: proposal.decimals,
+						existential_deposit: proposal.existential_deposit,
+						ratio: None,
+					},
+				)
+			});
+			match registered {
+				Ok(()) => {
+					for (target_parachain_id, amount) in proposal.min_fees {
+						MinFeeAmounts::<T>::insert(
+							target_parachain_id,
+							proposal.location.clone(),
+							amount,
+						);
+						Self::deposit_event(Event::<T>::MinFeeUpdated {
+							target_parachain_id,
+							foreign_asset_id: proposal.location.clone(),
+							amount: Some(amount),
+						});
 					}
-				})
-				.collect::<Vec<_>>()
+					Self::deposit_event(Event::AssetProposalApproved { proposal_id, asset_id });
+				},
+				Err(error) =>
+					Self::deposit_event(Event::AssetProposalFailed { proposal_id, error }),
+			}
+		}
+
+		/// Appends `location` to the previous locations of `asset_id`, forgetting the oldest one
+		/// if there are too many.
+		fn record_location(asset_id: T::LocalAssetId, location: T::ForeignAssetId) {
+			LocationHistory::<T>::mutate(asset_id, |history| {
+				let history = history.get_or_insert_with(Default::default);
+				if history.is_full() {
+					history.remove(0);
+				}
+				let _ = history.try_push(location);
+			});
 		}
 	}
 
@@ -382,7 +835,8 @@ pub mod pallet {
 			ensure!(!ForeignToLocal::<T>::contains_key(&location), Error::<T>::LocationIsUsed);
 			let old_location = LocalToForeign::<T>::try_get(asset_id);
 			if let Ok(inner_old_location) = old_location {
-				ForeignToLocal::<T>::remove(inner_old_location);
+				ForeignToLocal::<T>::remove(&inner_old_location);
+				Self::record_location(asset_id, inner_old_location);
 			}
 			ForeignToLocal::<T>::insert(&location, asset_id);
 			LocalToForeign::<T>::insert(asset_id, location.clone());
@@ -442,26 +896,7 @@ pub mod pallet {
 		fn get_foreign_assets_list(
 		) -> Vec<Asset<Self::AssetId, T::Balance, Self::AssetNativeLocation>> {
 			ForeignToLocal::<T>::iter()
-				.map(|(_, asset_id)| {
-					let name = AssetName::<T>::get(asset_id).map(Into::into);
-					let symbol = AssetSymbol::<T>::get(asset_id).map(Into::into);
-					let foreign_id = LocalToForeign::<T>::get(asset_id);
-					let decimals =
-						<Pallet<T> as InspectRegistryMetadata>::decimals(&asset_id).unwrap_or(12);
-					let ratio = AssetRatio::<T>::get(asset_id);
-					let existential_deposit =
-						ExistentialDeposit::<T>::get(asset_id).unwrap_or_default();
-
-					Asset {
-						name,
-						symbol,
-						id: asset_id,
-						decimals,
-						ratio,
-						foreign_id,
-						existential_deposit,
-					}
-				})
+				.map(|(_, asset_id)| Self::get_asset(asset_id))
 				.collect::<Vec<_>>()
 		}
 	}
@@ -473,6 +908,17 @@ pub mod pallet {
 		}
 	}
 
+	impl<T: Config> FeeAssetPriceSources for Pallet<T> {
+		type AssetId = T::LocalAssetId;
+		type PoolId = T::PoolId;
+
+		fn price_source(
+			asset_id: Self::AssetId,
+		) -> Option<(FeeAssetPriceSource<Self::PoolId>, Permill)> {
+			FeePriceSources::<T>::get(asset_id)
+		}
+	}
+
 	impl<T: Config> AssetExistentialDepositInspect for Pallet<T> {
 		type AssetId = T::LocalAssetId;
 		type Balance = T::Balance;
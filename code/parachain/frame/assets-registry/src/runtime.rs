@@ -1,7 +1,7 @@
 use crate::{self as pallet_assets_registry, weights::SubstrateWeight};
 use frame_support::{
 	ord_parameter_types, parameter_types,
-	traits::{EitherOfDiverse, Everything},
+	traits::{ConstU128, ConstU32, ConstU64, EitherOfDiverse, Everything},
 };
 use frame_system::{self as system, EnsureRoot, EnsureSignedBy};
 use primitives::currency::ForeignAssetId;
@@ -18,6 +18,11 @@ type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>
 
 pub const ROOT: AccountId = 0_u32;
 pub const ALICE: AccountId = 1_u32;
+pub const BOB: AccountId = 2_u32;
+pub const TREASURY: AccountId = 3_u32;
+
+pub const PROPOSAL_DEPOSIT: Balance = 1_000;
+pub const CHALLENGE_PERIOD: u64 = 10;
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
@@ -27,6 +32,7 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system,
+		Balances: pallet_balances,
 		AssetsRegistry: pallet_assets_registry,
 	}
 );
@@ -54,7 +60,7 @@ impl system::Config for Runtime {
 	type BlockHashCount = BlockHashCount;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<Balance>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -63,8 +69,25 @@ impl system::Config for Runtime {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type FreezeIdentifier = [u8; 8];
+	type HoldIdentifier = [u8; 8];
+	type MaxHolds = ConstU32<32>;
+	type MaxFreezes = ConstU32<32>;
+}
+
 ord_parameter_types! {
 	pub const RootAccount: AccountId = ROOT;
+	pub const TreasuryAccount: AccountId = TREASURY;
 }
 
 parameter_types! {
@@ -90,12 +113,28 @@ impl pallet_assets_registry::Config for Runtime {
 	type WeightInfo = SubstrateWeight<Self>;
 	type Convert = ConvertInto;
 	type NetworkId = PicassoNetworkId;
+	type Currency = Balances;
+	type ProposalDeposit = ConstU128<PROPOSAL_DEPOSIT>;
+	type MinProposedExistentialDeposit = ConstU128<1>;
+	type MinProposedFee = ConstU128<100>;
+	type ChallengePeriod = ConstU64<CHALLENGE_PERIOD>;
+	type RejectProposalOrigin = EitherOfDiverse<
+		EnsureSignedBy<RootAccount, AccountId>, // for tests
+		EnsureRoot<AccountId>,                  // for benchmarks
+	>;
+	type TreasuryAccount = TreasuryAccount;
+	type PoolId = u128;
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	system::GenesisConfig::default()
+	let mut storage = system::GenesisConfig::default()
 		.build_storage::<Runtime>()
-		.expect("Storage is valid")
-		.into()
+		.expect("Storage is valid");
+	pallet_balances::GenesisConfig::<Runtime> {
+		balances: vec![(ALICE, 10 * PROPOSAL_DEPOSIT), (BOB, 10 * PROPOSAL_DEPOSIT)],
+	}
+	.assimilate_storage(&mut storage)
+	.expect("Storage is valid");
+	storage.into()
 }
@@ -2,14 +2,23 @@ use crate::{prelude::*, runtime::*, Error};
 use codec::{Decode, Encode};
 use composable_support::collections::vec::bounded::BiBoundedVec;
 use composable_traits::{
-	assets::{Asset, AssetInfo, AssetInfoUpdate, GenerateAssetId},
+	assets::{
+		Asset, AssetFilter, AssetInfo, AssetInfoUpdate, AssetVerification, ExtendedAsset,
+		ExtendedAssetMetadata, GenerateAssetId,
+	},
+	currency::{FeeAssetPriceSource, FeeAssetPriceSources},
 	rational,
 	storage::UpdateValue,
 	xcm::assets::RemoteAssetRegistryInspect,
 };
-use frame_support::{assert_noop, assert_ok, error::BadOrigin, traits::PalletInfoAccess};
+use frame_support::{
+	assert_noop, assert_ok,
+	error::BadOrigin,
+	traits::{Currency, Hooks, PalletInfoAccess, ReservableCurrency},
+};
 use frame_system::RawOrigin;
 use primitives::currency::{ForeignAssetId, VersionedMultiLocation};
+use sp_runtime::Permill;
 use xcm::latest::MultiLocation;
 
 #[test]
@@ -373,6 +382,10 @@ fn update_asset_location() {
 		assert_eq!(AssetsRegistry::from_local_asset(local_asset_id), Some(location.clone()));
 		assert_eq!(AssetsRegistry::from_foreign_asset(location_new.clone()), None);
 		assert_eq!(AssetsRegistry::from_foreign_asset(location.clone()), Some(local_asset_id));
+		assert_eq!(
+			AssetsRegistry::location_history(local_asset_id).map(Into::into),
+			Some(vec![location, location_new])
+		);
 	})
 }
 
@@ -589,3 +602,390 @@ fn get_all_assets_should_work() {
 		);
 	})
 }
+
+fn register_asset_with_symbol(
+	nonce: u64,
+	symbol: &[u8],
+) -> <Runtime as crate::Config>::LocalAssetId {
+	let protocol_id = (AssetsRegistry::index() as u32).to_be_bytes();
+	let asset_info = AssetInfo {
+		name: None,
+		symbol: Some(BiBoundedVec::from_vec(symbol.to_vec()).unwrap()),
+		decimals: Some(6),
+		existential_deposit: 0,
+		ratio: None,
+	};
+	assert_ok!(AssetsRegistry::register_asset(
+		RuntimeOrigin::root(),
+		protocol_id,
+		nonce,
+		None,
+		asset_info,
+	));
+	AssetsRegistry::generate_asset_id(protocol_id, nonce)
+}
+
+fn ibc_metadata(ibc_denom: &[u8]) -> ExtendedAssetMetadata {
+	ExtendedAssetMetadata {
+		ibc_denom: Some(BiBoundedVec::from_vec(ibc_denom.to_vec()).unwrap()),
+		..Default::default()
+	}
+}
+
+#[test]
+fn set_extended_metadata() {
+	new_test_ext().execute_with(|| {
+		let asset_id = register_asset_with_symbol(1, b"ATOM");
+		let metadata = ExtendedAssetMetadata {
+			logo_uri: Some(
+				BiBoundedVec::from_vec(b"https://cosmos.network/atom.svg".to_vec()).unwrap(),
+			),
+			coingecko_id: Some(BiBoundedVec::from_vec(b"cosmos".to_vec()).unwrap()),
+			verification: AssetVerification::Verified,
+			..ibc_metadata(b"transfer/channel-2/uatom")
+		};
+		let hash = AssetsRegistry::ibc_denom_hash(b"transfer/channel-2/uatom");
+
+		assert_noop!(
+			AssetsRegistry::set_extended_metadata(
+				RuntimeOrigin::signed(ALICE),
+				asset_id,
+				metadata.clone()
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			AssetsRegistry::set_extended_metadata(RuntimeOrigin::root(), 0, metadata.clone()),
+			Error::<Runtime>::AssetNotFound
+		);
+
+		assert_ok!(AssetsRegistry::set_extended_metadata(
+			RuntimeOrigin::root(),
+			asset_id,
+			metadata.clone()
+		));
+		assert_eq!(AssetsRegistry::extended_metadata(asset_id), Some(metadata.clone()));
+		let asset = AssetsRegistry::get_asset_by_ibc_denom_hash(hash).expect("asset is indexed");
+		assert_eq!(asset.asset.id, asset_id);
+		assert_eq!(asset.metadata, metadata);
+
+		// replacing the IBC denom drops the previous hash
+		assert_ok!(AssetsRegistry::set_extended_metadata(
+			RuntimeOrigin::root(),
+			asset_id,
+			ibc_metadata(b"transfer/channel-3/uatom")
+		));
+		assert_eq!(AssetsRegistry::from_ibc_denom_hash(hash), None);
+		assert_eq!(
+			AssetsRegistry::from_ibc_denom_hash(AssetsRegistry::ibc_denom_hash(
+				b"transfer/channel-3/uatom"
+			)),
+			Some(asset_id)
+		);
+	})
+}
+
+#[test]
+fn set_extended_metadata_ibc_denom_is_used() {
+	new_test_ext().execute_with(|| {
+		let asset_id = register_asset_with_symbol(1, b"ATOM");
+		let other_asset_id = register_asset_with_symbol(2, b"ATOM");
+		let metadata = ibc_metadata(b"transfer/channel-2/uatom");
+
+		assert_ok!(AssetsRegistry::set_extended_metadata(
+			RuntimeOrigin::root(),
+			asset_id,
+			metadata.clone()
+		));
+		assert_noop!(
+			AssetsRegistry::set_extended_metadata(RuntimeOrigin::root(), other_asset_id, metadata),
+			Error::<Runtime>::IbcDenomIsUsed
+		);
+	})
+}
+
+#[test]
+fn set_fee_price_source() {
+	new_test_ext().execute_with(|| {
+		let asset_id = register_asset_with_symbol(1, b"USDT");
+		let source = (FeeAssetPriceSource::Pablo(7), Permill::from_percent(20));
+
+		assert_noop!(
+			AssetsRegistry::set_fee_price_source(
+				RuntimeOrigin::signed(ALICE),
+				asset_id,
+				Some(source)
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			AssetsRegistry::set_fee_price_source(RuntimeOrigin::root(), 0, Some(source)),
+			Error::<Runtime>::AssetNotFound
+		);
+
+		assert_ok!(AssetsRegistry::set_fee_price_source(
+			RuntimeOrigin::root(),
+			asset_id,
+			Some(source)
+		));
+		assert_eq!(<AssetsRegistry as FeeAssetPriceSources>::price_source(asset_id), Some(source));
+
+		// removing the source prices the asset by its ratio again
+		assert_ok!(AssetsRegistry::set_fee_price_source(RuntimeOrigin::root(), asset_id, None));
+		assert_eq!(<AssetsRegistry as FeeAssetPriceSources>::price_source(asset_id), None);
+	})
+}
+
+#[test]
+fn get_assets_filtered_and_by_symbol() {
+	new_test_ext().execute_with(|| {
+		let atom = register_asset_with_symbol(1, b"ATOM");
+		let fake_atom = register_asset_with_symbol(2, b"ATOM");
+		let usdt = register_asset_with_symbol(3, b"USDT");
+		let stablecoin = BiBoundedVec::from_vec(b"stablecoin".to_vec()).unwrap();
+
+		assert_ok!(AssetsRegistry::set_extended_metadata(
+			RuntimeOrigin::root(),
+			atom,
+			ExtendedAssetMetadata {
+				verification: AssetVerification::Verified,
+				..ibc_metadata(b"transfer/channel-2/uatom")
+			}
+		));
+		assert_ok!(AssetsRegistry::set_extended_metadata(
+			RuntimeOrigin::root(),
+			fake_atom,
+			ExtendedAssetMetadata {
+				verification: AssetVerification::Flagged,
+				..Default::default()
+			}
+		));
+		assert_ok!(AssetsRegistry::set_extended_metadata(
+			RuntimeOrigin::root(),
+			usdt,
+			ExtendedAssetMetadata {
+				verification: AssetVerification::Verified,
+				tags: vec![stablecoin].try_into().unwrap(),
+				..Default::default()
+			}
+		));
+
+		let ids = |assets: Vec<ExtendedAsset<_, _, _>>| {
+			assets.into_iter().map(|asset| asset.asset.id).collect::<Vec<_>>()
+		};
+		let mut all = vec![atom, fake_atom, usdt];
+		all.sort();
+
+		assert_eq!(ids(AssetsRegistry::get_assets_filtered(Default::default(), 0, 10)), all);
+		assert_eq!(
+			ids(AssetsRegistry::get_assets_filtered(Default::default(), 1, 1)),
+			all[1..2].to_vec()
+		);
+		assert_eq!(
+			ids(AssetsRegistry::get_assets_filtered(
+				AssetFilter {
+					verification: Some(AssetVerification::Verified),
+					..Default::default()
+				},
+				0,
+				10
+			))
+			.len(),
+			2
+		);
+		assert_eq!(
+			ids(AssetsRegistry::get_assets_filtered(
+				AssetFilter { tag: Some(b"stablecoin".to_vec()), ..Default::default() },
+				0,
+				10
+			)),
+			vec![usdt]
+		);
+		assert_eq!(
+			ids(AssetsRegistry::get_assets_filtered(
+				AssetFilter { ibc: Some(true), ..Default::default() },
+				0,
+				10
+			)),
+			vec![atom]
+		);
+
+		let mut atoms = vec![atom, fake_atom];
+		atoms.sort();
+		assert_eq!(ids(AssetsRegistry::get_assets_by_symbol(b"ATOM".to_vec())), atoms);
+		assert_eq!(ids(AssetsRegistry::get_assets_by_symbol(b"DOT".to_vec())), vec![]);
+	})
+}
+
+fn parachain_location(para_id: u32) -> <Runtime as crate::Config>::ForeignAssetId {
+	<Runtime as crate::Config>::ForeignAssetId::decode(
+		&mut &ForeignAssetId::Xcm(VersionedMultiLocation::V3(MultiLocation::new(
+			1,
+			xcm::latest::Junctions::X1(xcm::latest::Junction::Parachain(para_id)),
+		)))
+		.encode()[..],
+	)
+	.expect("Location bytes translate to foreign ID bytes")
+}
+
+fn propose(
+	proposer: AccountId,
+	location: <Runtime as crate::Config>::ForeignAssetId,
+) -> frame_support::dispatch::DispatchResult {
+	AssetsRegistry::propose_asset(
+		RuntimeOrigin::signed(proposer),
+		location,
+		None,
+		Some(BiBoundedVec::from_vec(b"USDC".to_vec()).unwrap()),
+		Some(6),
+		1,
+		vec![(2000, 500)].try_into().unwrap(),
+	)
+}
+
+#[test]
+fn asset_proposal_is_registered_after_challenge_period() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let location = parachain_location(2000);
+		assert_ok!(propose(ALICE, location.clone()));
+		assert_eq!(Balances::reserved_balance(ALICE), PROPOSAL_DEPOSIT);
+		assert_noop!(propose(BOB, location.clone()), Error::<Runtime>::LocationIsProposed);
+
+		AssetsRegistry::on_initialize(CHALLENGE_PERIOD);
+		assert!(AssetsRegistry::asset_proposal(0).is_some());
+
+		AssetsRegistry::on_initialize(1 + CHALLENGE_PERIOD);
+		let asset_id = AssetsRegistry::generate_asset_id(crate::PROPOSAL_PROTOCOL_ID, 0);
+		assert_eq!(AssetsRegistry::asset_proposal(0), None);
+		assert_eq!(AssetsRegistry::from_foreign_asset(location.clone()), Some(asset_id));
+		assert_eq!(
+			AssetsRegistry::asset_symbol(asset_id).map(|x| x.as_vec().clone()),
+			Some(b"USDC".to_vec())
+		);
+		assert_eq!(AssetsRegistry::asset_ratio(asset_id), None);
+		assert_eq!(AssetsRegistry::minimal_amount(2000, location.clone()), Some(500));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_noop!(propose(BOB, location), Error::<Runtime>::LocationIsUsed);
+	})
+}
+
+#[test]
+fn approve_asset_proposal_during_challenge_period() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let location = parachain_location(2000);
+		assert_ok!(propose(ALICE, location.clone()));
+
+		assert_noop!(
+			AssetsRegistry::approve_asset_proposal(RuntimeOrigin::signed(BOB), 0),
+			BadOrigin
+		);
+		assert_ok!(AssetsRegistry::approve_asset_proposal(RuntimeOrigin::root(), 0));
+		assert_noop!(
+			AssetsRegistry::approve_asset_proposal(RuntimeOrigin::root(), 0),
+			Error::<Runtime>::ProposalNotFound
+		);
+		assert!(AssetsRegistry::from_foreign_asset(location).is_some());
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+
+		// already approved
+		AssetsRegistry::on_initialize(1 + CHALLENGE_PERIOD);
+		assert_eq!(Balances::free_balance(ALICE), 10 * PROPOSAL_DEPOSIT);
+	})
+}
+
+#[test]
+fn reject_asset_proposal_slashes_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let location = parachain_location(2000);
+		assert_ok!(propose(ALICE, location.clone()));
+
+		assert_noop!(
+			AssetsRegistry::reject_asset_proposal(RuntimeOrigin::signed(BOB), 0),
+			BadOrigin
+		);
+		assert_ok!(AssetsRegistry::reject_asset_proposal(RuntimeOrigin::root(), 0));
+		assert_eq!(Balances::total_balance(&ALICE), 9 * PROPOSAL_DEPOSIT);
+		assert_eq!(Balances::free_balance(TREASURY), PROPOSAL_DEPOSIT);
+
+		AssetsRegistry::on_initialize(1 + CHALLENGE_PERIOD);
+		assert_eq!(AssetsRegistry::from_foreign_asset(location.clone()), None);
+		assert_eq!(AssetsRegistry::minimal_amount(2000, location.clone()), None);
+
+		// can be proposed again
+		assert_ok!(propose(BOB, location));
+	})
+}
+
+#[test]
+fn asset_proposal_fails_if_location_is_registered_meanwhile() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let location = parachain_location(2000);
+		assert_ok!(propose(ALICE, location.clone()));
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			[0; 4],
+			1,
+			Some(location.clone()),
+			AssetInfo {
+				name: None,
+				symbol: None,
+				decimals: Some(6),
+				existential_deposit: 0,
+				ratio: None,
+			},
+		));
+
+		AssetsRegistry::on_initialize(1 + CHALLENGE_PERIOD);
+		assert_eq!(AssetsRegistry::asset_proposal(0), None);
+		assert_eq!(
+			AssetsRegistry::from_foreign_asset(location.clone()),
+			Some(AssetsRegistry::generate_asset_id([0; 4], 1))
+		);
+		assert_eq!(AssetsRegistry::minimal_amount(2000, location), None);
+		assert_eq!(Balances::free_balance(ALICE), 10 * PROPOSAL_DEPOSIT);
+	})
+}
+
+#[test]
+fn propose_asset_requires_deposit() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&ALICE, PROPOSAL_DEPOSIT / 2);
+		assert!(propose(ALICE, parachain_location(2000)).is_err());
+		assert_eq!(AssetsRegistry::asset_proposal(0), None);
+	})
+}
+
+#[test]
+fn propose_asset_requires_minimal_existential_deposit_and_fees() {
+	new_test_ext().execute_with(|| {
+		let location = parachain_location(2000);
+		assert_noop!(
+			AssetsRegistry::propose_asset(
+				RuntimeOrigin::signed(ALICE),
+				location.clone(),
+				None,
+				None,
+				Some(6),
+				0,
+				vec![(2000, 500)].try_into().unwrap(),
+			),
+			Error::<Runtime>::ExistentialDepositTooLow
+		);
+		assert_noop!(
+			AssetsRegistry::propose_asset(
+				RuntimeOrigin::signed(ALICE),
+				location,
+				None,
+				None,
+				Some(6),
+				1,
+				vec![(2000, 500), (2001, 99)].try_into().unwrap(),
+			),
+			Error::<Runtime>::MinFeeTooLow
+		);
+	})
+}
@@ -11,6 +11,11 @@ pub trait WeightInfo {
 	fn update_asset() -> Weight;
 	fn set_min_fee() -> Weight;
 	fn update_asset_location() -> Weight;
+	fn set_extended_metadata() -> Weight;
+	fn propose_asset() -> Weight;
+	fn approve_asset_proposal() -> Weight;
+	fn reject_asset_proposal() -> Weight;
+	fn set_fee_price_source() -> Weight;
 }
 
 impl WeightInfo for () {
@@ -29,6 +34,26 @@ impl WeightInfo for () {
 	fn update_asset_location() -> Weight {
 		Weight::from_parts(100_000, 0)
 	}
+
+	fn set_extended_metadata() -> Weight {
+		Weight::from_parts(100_000, 0)
+	}
+
+	fn propose_asset() -> Weight {
+		Weight::from_parts(100_000, 0)
+	}
+
+	fn approve_asset_proposal() -> Weight {
+		Weight::from_parts(100_000, 0)
+	}
+
+	fn reject_asset_proposal() -> Weight {
+		Weight::from_parts(100_000, 0)
+	}
+
+	fn set_fee_price_source() -> Weight {
+		Weight::from_parts(100_000, 0)
+	}
 }
 
 /// Weights for pallet_assets_registry using the Substrate node and recommended hardware.
@@ -49,4 +74,34 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn update_asset_location() -> Weight {
 		Weight::from_parts(9_958_000_u64, 0).saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+
+	fn set_extended_metadata() -> Weight {
+		Weight::from_parts(9_958_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	fn propose_asset() -> Weight {
+		Weight::from_parts(31_470_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	fn approve_asset_proposal() -> Weight {
+		Weight::from_parts(52_130_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(18_u64))
+	}
+
+	fn reject_asset_proposal() -> Weight {
+		Weight::from_parts(30_210_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	fn set_fee_price_source() -> Weight {
+		Weight::from_parts(9_958_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
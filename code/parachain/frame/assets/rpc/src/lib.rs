@@ -1,7 +1,7 @@
 use assets_runtime_api::AssetsRuntimeApi;
 use codec::Codec;
 use composable_support::rpc_helpers::SafeRpcWrapper;
-use composable_traits::assets::Asset;
+use composable_traits::assets::{Asset, AssetFilter, ExtendedAsset, PortfolioEntry};
 use core::{fmt::Display, str::FromStr};
 use jsonrpsee::{
 	core::{Error as RpcError, RpcResult},
@@ -32,6 +32,39 @@ where
 		&self,
 		at: Option<BlockHash>,
 	) -> RpcResult<Vec<Asset<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>>>;
+
+	#[method(name = "assets_listAssetsExtended")]
+	fn list_assets_extended(
+		&self,
+		filter: AssetFilter,
+		offset: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<ExtendedAsset<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>>>;
+
+	#[method(name = "assets_assetsBySymbol")]
+	fn assets_by_symbol(
+		&self,
+		symbol: Vec<u8>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<ExtendedAsset<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>>>;
+
+	#[method(name = "assets_assetByIbcDenomHash")]
+	fn asset_by_ibc_denom_hash(
+		&self,
+		hash: [u8; 32],
+		at: Option<BlockHash>,
+	) -> RpcResult<
+		Option<ExtendedAsset<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>>,
+	>;
+
+	#[method(name = "assets_portfolio")]
+	fn portfolio(
+		&self,
+		account: AccountId,
+		valuation: bool,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<PortfolioEntry<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>>>;
 }
 
 pub struct Assets<C, Block> {
@@ -104,4 +137,100 @@ where
 			)))
 		})
 	}
+
+	fn list_assets_extended(
+		&self,
+		filter: AssetFilter,
+		offset: u32,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<ExtendedAsset<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>>>
+	{
+		let api = self.client.runtime_api();
+
+		let at = at.unwrap_or_else(|| {
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		});
+
+		let runtime_api_result = api.list_assets_extended(at, filter, offset, limit);
+		runtime_api_result.map_err(|e| {
+			RpcError::Call(CallError::Custom(ErrorObject::owned(
+				9876,
+				"Something wrong",
+				Some(format!("{:?}", e)),
+			)))
+		})
+	}
+
+	fn assets_by_symbol(
+		&self,
+		symbol: Vec<u8>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<ExtendedAsset<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>>>
+	{
+		let api = self.client.runtime_api();
+
+		let at = at.unwrap_or_else(|| {
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		});
+
+		let runtime_api_result = api.assets_by_symbol(at, symbol);
+		runtime_api_result.map_err(|e| {
+			RpcError::Call(CallError::Custom(ErrorObject::owned(
+				9876,
+				"Something wrong",
+				Some(format!("{:?}", e)),
+			)))
+		})
+	}
+
+	fn asset_by_ibc_denom_hash(
+		&self,
+		hash: [u8; 32],
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<
+		Option<ExtendedAsset<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>>,
+	> {
+		let api = self.client.runtime_api();
+
+		let at = at.unwrap_or_else(|| {
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		});
+
+		let runtime_api_result = api.asset_by_ibc_denom_hash(at, hash);
+		runtime_api_result.map_err(|e| {
+			RpcError::Call(CallError::Custom(ErrorObject::owned(
+				9876,
+				"Something wrong",
+				Some(format!("{:?}", e)),
+			)))
+		})
+	}
+
+	fn portfolio(
+		&self,
+		account_id: AccountId,
+		valuation: bool,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<PortfolioEntry<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>>>
+	{
+		let api = self.client.runtime_api();
+
+		let at = at.unwrap_or_else(|| {
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		});
+
+		let runtime_api_result = api.portfolio(at, account_id, valuation);
+		runtime_api_result.map_err(|e| {
+			RpcError::Call(CallError::Custom(ErrorObject::owned(
+				9876,
+				"Something wrong",
+				Some(format!("{:?}", e)),
+			)))
+		})
+	}
 }
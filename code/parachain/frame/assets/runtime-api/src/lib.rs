@@ -4,13 +4,14 @@
 
 use codec::Codec;
 use composable_support::rpc_helpers::SafeRpcWrapper;
-use composable_traits::assets::Asset;
+use composable_traits::assets::{Asset, AssetFilter, ExtendedAsset, PortfolioEntry};
 use sp_std::vec::Vec;
 
 // Here we declare the runtime API. It is implemented it the `impl` block in
 // runtime amalgamator file (the `runtime/src/lib.rs`)
 sp_api::decl_runtime_apis! {
 	// REVIEW(benluelo): Should the AssetId type parameter be removed and then just use CurrencyId directly?
+	#[api_version(3)]
 	pub trait AssetsRuntimeApi<AssetId, AccountId, Balance, ForeignAssetId>
 	where
 		AccountId: Codec,
@@ -21,5 +22,21 @@ sp_api::decl_runtime_apis! {
 		fn balance_of(asset_id: SafeRpcWrapper<AssetId>, account_id: AccountId) -> SafeRpcWrapper<Balance>;
 
 		fn list_assets() -> Vec<Asset<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>>;
+
+		/// Assets matching `filter` with their extended metadata, ordered by id.
+		#[api_version(2)]
+		fn list_assets_extended(filter: AssetFilter, offset: u32, limit: u32) -> Vec<ExtendedAsset<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>>;
+
+		#[api_version(2)]
+		fn assets_by_symbol(symbol: Vec<u8>) -> Vec<ExtendedAsset<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>>;
+
+		/// The asset with the IBC denom hashing to `hash`, as in `ibc/{hash}` denoms.
+		#[api_version(2)]
+		fn asset_by_ibc_denom_hash(hash: [u8; 32]) -> Option<ExtendedAsset<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>>;
+
+		/// Balances of `account_id` in every registered asset it has funds in, valued with oracle
+		/// prices if `valuation` is set. Runtimes without an oracle never value them.
+		#[api_version(3)]
+		fn portfolio(account_id: AccountId, valuation: bool) -> Vec<PortfolioEntry<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>>;
 	}
 }
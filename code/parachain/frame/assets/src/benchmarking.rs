@@ -4,18 +4,42 @@ use super::*;
 #[allow(unused_imports)]
 use crate::Pallet as Assets;
 use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
-use frame_support::traits::{
-	fungible::{Inspect as NativeInspect, Mutate as NativeMutate},
-	fungibles::{Inspect, Mutate},
+use frame_support::{
+	traits::{
+		fungible::{Inspect as NativeInspect, Mutate as NativeMutate},
+		fungibles::{Inspect, Mutate},
+		ConstU32, Hooks, LockableCurrency, ReservableCurrency,
+	},
+	BoundedVec,
 };
-use frame_system::{Config as SystemConfig, RawOrigin};
-use sp_runtime::traits::StaticLookup;
+use frame_system::{pallet_prelude::BlockNumberFor, Config as SystemConfig, RawOrigin};
+use sp_runtime::traits::{One, Saturating, StaticLookup};
+use sp_std::{vec, vec::Vec};
 
 const FROM_ACCOUNT: u64 = 1;
 const TO_ACCOUNT: u64 = 2;
 const ASSET_ID: u64 = 2;
 const TRANSFER_AMOUNT: u32 = 500;
 
+/// Fills the recurring payment slots of the blocks from `from`, but the last one a payment can be
+/// pushed back to.
+fn fill_recurring_payment_slots<T: Config>(from: BlockNumberFor<T>) {
+	let mut block = from;
+	for _ in 1..MAX_RECURRING_PAYMENT_DELAY {
+		let payment_ids = (0..MAX_RECURRING_PAYMENTS_PER_BLOCK)
+			.map(|id| RecurringPaymentId::MAX - id as RecurringPaymentId)
+			.collect::<Vec<_>>();
+		let payment_ids: BoundedVec<_, ConstU32<MAX_RECURRING_PAYMENTS_PER_BLOCK>> =
+			payment_ids.try_into().expect("within bounds");
+		RecurringPaymentsDue::<T>::insert(block, payment_ids);
+		block = block.saturating_add(One::one());
+	}
+}
+
+fn recurring_payment_interval<T: Config>() -> BlockNumberFor<T> {
+	T::MinRecurringPaymentInterval::get().max(One::one())
+}
+
 benchmarks! {
 	where_clause {
 		 where <T as Config>::NativeCurrency:  NativeInspect<T::AccountId, Balance = T::Balance>
@@ -23,6 +47,8 @@ benchmarks! {
 			   <T as Config>::MultiCurrency: Inspect<T::AccountId, Balance = T::Balance, AssetId = T::AssetId>
 
 				   + Mutate<T::AccountId, Balance = T::Balance, AssetId = T::AssetId>,
+				<T as Config>::NativeCurrency: LockableCurrency<T::AccountId, Balance = T::Balance>,
+				<T as Config>::NativeCurrency: ReservableCurrency<T::AccountId, Balance = T::Balance>,
 				<T as Config>::AssetId: From<u64>,
 				<T as SystemConfig>::AccountId: From<u64>,
 
@@ -117,6 +143,83 @@ benchmarks! {
 		T::MultiCurrency::mint_into(asset_id, &caller, amount).expect("always can mint in test");
 	}: _(RawOrigin::Root, asset_id, dest, amount)
 
+	freeze {
+		let target = FreezeTarget::Account(FROM_ACCOUNT.into());
+		let reason: FreezeReason = vec![0; MAX_FREEZE_REASON_LEN as usize].try_into().expect("reason within bounds");
+	}: _(RawOrigin::Root, target, reason)
+
+	thaw {
+		let target = FreezeTarget::Account(FROM_ACCOUNT.into());
+		Assets::<T>::freeze(RawOrigin::Root.into(), target.clone(), Default::default())?;
+	}: _(RawOrigin::Root, target)
+
+	transfer_many {
+		let n in 1 .. MAX_TRANSFER_MANY;
+		let caller: T::AccountId = whitelisted_caller();
+		let asset_id: T::AssetId = ASSET_ID.into();
+		let amount: T::Balance = TRANSFER_AMOUNT.into();
+		T::MultiCurrency::mint_into(asset_id, &caller, amount * n.into()).expect("always can mint in test");
+		let transfers: BoundedVec<_, _> = (0..n)
+			.map(|_| (asset_id, T::Lookup::unlookup(TO_ACCOUNT.into()), amount))
+			.collect::<Vec<_>>()
+			.try_into()
+			.expect("n within bounds");
+	}: _(RawOrigin::Signed(caller), transfers, false)
+
+	// the first payment is pushed back to the last block it can be
+	schedule_recurring_payment {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset_id: T::AssetId = ASSET_ID.into();
+		let payee = T::Lookup::unlookup(TO_ACCOUNT.into());
+		let amount: T::Balance = TRANSFER_AMOUNT.into();
+		let interval = recurring_payment_interval::<T>();
+		T::NativeCurrency::mint_into(&caller, T::RecurringPaymentDeposit::get() * 2_u32.into()).expect("always can mint in test");
+		let first_payment = frame_system::Pallet::<T>::block_number().saturating_add(interval);
+		fill_recurring_payment_slots::<T>(first_payment);
+	}: _(RawOrigin::Signed(caller), payee, asset_id, amount, interval, None)
+	verify {
+		let payment = Assets::<T>::recurring_payment(0).expect("payment scheduled");
+		assert_eq!(
+			payment.next_payment,
+			first_payment.saturating_add((MAX_RECURRING_PAYMENT_DELAY - 1).into())
+		);
+	}
+
+	cancel_recurring_payment {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset_id: T::AssetId = ASSET_ID.into();
+		let payee = T::Lookup::unlookup(TO_ACCOUNT.into());
+		let amount: T::Balance = TRANSFER_AMOUNT.into();
+		T::NativeCurrency::mint_into(&caller, T::RecurringPaymentDeposit::get() * 2_u32.into()).expect("always can mint in test");
+		Assets::<T>::schedule_recurring_payment(RawOrigin::Signed(caller.clone()).into(), payee, asset_id, amount, recurring_payment_interval::<T>(), None)?;
+	}: _(RawOrigin::Signed(caller), 0)
+	verify {
+		assert!(Assets::<T>::recurring_payment(0).is_none());
+	}
+
+	// the payment is made and the next one is pushed back to the last block it can be
+	execute_recurring_payment {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset_id: T::AssetId = ASSET_ID.into();
+		let payee = T::Lookup::unlookup(TO_ACCOUNT.into());
+		let amount: T::Balance = TRANSFER_AMOUNT.into();
+		let interval = recurring_payment_interval::<T>();
+		T::MultiCurrency::mint_into(asset_id, &caller, amount * 2_u32.into()).expect("always can mint in test");
+		T::NativeCurrency::mint_into(&caller, T::RecurringPaymentDeposit::get() * 2_u32.into()).expect("always can mint in test");
+		Assets::<T>::schedule_recurring_payment(RawOrigin::Signed(caller).into(), payee, asset_id, amount, interval, None)?;
+		let now = frame_system::Pallet::<T>::block_number().saturating_add(interval);
+		fill_recurring_payment_slots::<T>(now.saturating_add(interval));
+	}: {
+		Assets::<T>::on_initialize(now);
+	}
+	verify {
+		let payment = Assets::<T>::recurring_payment(0).expect("payment rescheduled");
+		assert_eq!(
+			payment.next_payment,
+			now.saturating_add(interval).saturating_add((MAX_RECURRING_PAYMENT_DELAY - 1).into())
+		);
+	}
+
 }
 
 impl_benchmark_test_suite!(Assets, crate::mocks::new_test_ext(), crate::mocks::Test,);
@@ -35,20 +35,117 @@ pub mod pallet {
 		dispatch::DispatchResultWithPostInfo,
 		pallet_prelude::*,
 		sp_runtime::traits::StaticLookup,
+		storage::with_storage_layer,
 		traits::{
 			fungible::{Inspect as NativeInspect, Mutate as NativeMutate},
 			fungibles::{Inspect, Mutate},
 			tokens::{Fortitude, Precision, Preservation},
-			EnsureOrigin,
+			EnsureOrigin, LockIdentifier, LockableCurrency, ReservableCurrency, WithdrawReasons,
 		},
 	};
-	use frame_system::{ensure_root, ensure_signed, pallet_prelude::OriginFor};
-	use num_traits::Zero;
+	use frame_system::{
+		ensure_root, ensure_signed,
+		pallet_prelude::{BlockNumberFor, OriginFor},
+	};
+	use num_traits::{One, Zero};
 	use primitives::currency::ValidateCurrencyId;
-	use sp_runtime::{DispatchError, FixedPointOperand};
+	use sp_runtime::{
+		traits::{Bounded, Saturating},
+		DispatchError, FixedPointOperand, TokenError,
+	};
+
+	/// Maximum length of the reason attached to a freeze, e.g. an incident reference.
+	pub const MAX_FREEZE_REASON_LEN: u32 = 64;
+
+	/// Lock set on the native currency of frozen accounts, so that transfers bypassing this
+	/// pallet are blocked too.
+	pub const FREEZE_LOCK_ID: LockIdentifier = *b"assetfrz";
+
+	/// What a freeze applies to.
+	#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum FreezeTarget<AccountId, AssetId> {
+		/// No account can send the asset.
+		Asset(AssetId),
+		/// The account cannot send any asset.
+		Account(AccountId),
+		/// The account cannot send the asset.
+		AccountAsset(AccountId, AssetId),
+	}
+
+	pub type FreezeReason = BoundedVec<u8, ConstU32<MAX_FREEZE_REASON_LEN>>;
+
+	#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct FreezeInfo<BlockNumber> {
+		pub reason: FreezeReason,
+		/// Block at which the freeze was issued.
+		pub since: BlockNumber,
+	}
+
+	pub type FreezeTargetOf<T> =
+		FreezeTarget<<T as frame_system::Config>::AccountId, <T as Config>::AssetId>;
+
+	/// Maximum number of transfers in one `transfer_many`.
+	pub const MAX_TRANSFER_MANY: u32 = 64;
+
+	/// Maximum number of recurring payments executed in one block.
+	pub const MAX_RECURRING_PAYMENTS_PER_BLOCK: u32 = 16;
+
+	/// How many blocks a recurring payment may be pushed back when its block is full.
+	pub const MAX_RECURRING_PAYMENT_DELAY: u32 = 32;
+
+	/// Maximum number of active recurring payments. A payment being scheduled always finds room
+	/// within `MAX_RECURRING_PAYMENT_DELAY` blocks while there are fewer.
+	pub const MAX_RECURRING_PAYMENTS: u32 =
+		MAX_RECURRING_PAYMENTS_PER_BLOCK * MAX_RECURRING_PAYMENT_DELAY;
+
+	pub type RecurringPaymentId = u64;
+
+	/// Payment of `amount` of `asset_id` from `payer` to `payee` every `interval` blocks,
+	/// until `end` if any.
+	#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct RecurringPayment<AccountId, AssetId, Balance, BlockNumber> {
+		pub payer: AccountId,
+		pub payee: AccountId,
+		pub asset_id: AssetId,
+		pub amount: Balance,
+		pub interval: BlockNumber,
+		/// Last block at which a payment can be made.
+		pub end: Option<BlockNumber>,
+		/// Block the next payment is due at.
+		pub next_payment: BlockNumber,
+		/// Native deposit reserved from the payer until the recurring payment ends.
+		pub deposit: Balance,
+		/// Number of payments which failed in a row.
+		pub failures: u32,
+	}
+
+	/// Why a recurring payment ended.
+	#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum RecurringPaymentEnd {
+		/// No payment is left before the end block.
+		Completed,
+		/// `MaxRecurringPaymentFailures` payments failed in a row.
+		TooManyFailures,
+		/// No block with room for the next payment was found.
+		NotRescheduled,
+	}
+
+	pub type RecurringPaymentOf<T> = RecurringPayment<
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::AssetId,
+		<T as Config>::Balance,
+		BlockNumberFor<T>,
+	>;
+
+	pub type TransferOf<T> = (
+		<T as Config>::AssetId,
+		<<T as frame_system::Config>::Lookup as StaticLookup>::Source,
+		<T as Config>::Balance,
+	);
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// currency id
 		type AssetId: AssetIdLike;
 		type Balance: BalanceLike + FixedPointOperand;
@@ -63,16 +160,169 @@ pub mod pallet {
 		/// An identifier for a hold. Used for disambiguating different holds so that
 		/// they can be individually replaced or removed and funds from one hold don't accidentally
 		/// become unreserved or slashed for another.
-		type RuntimeHoldReason: codec::Encode + TypeInfo + 'static;
+		///
+		/// Holds are tracked per reason by this pallet, the underlying currencies hold the funds
+		/// under their unit reason.
+		type RuntimeHoldReason: Parameter + MaxEncodedLen + 'static;
+		/// Origin allowed to freeze and thaw assets and accounts.
+		type FreezeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Native deposit reserved from the payer of a recurring payment until it ends.
+		#[pallet::constant]
+		type RecurringPaymentDeposit: Get<Self::Balance>;
+		/// Maximum number of active recurring payments of a payer.
+		#[pallet::constant]
+		type MaxRecurringPaymentsPerPayer: Get<u32>;
+		/// Minimum number of blocks between two payments of a recurring payment.
+		#[pallet::constant]
+		type MinRecurringPaymentInterval: Get<BlockNumberFor<Self>>;
+		/// Number of payments failing in a row after which a recurring payment ends.
+		#[pallet::constant]
+		type MaxRecurringPaymentFailures: Get<u32>;
 	}
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
+	/// Active freezes. Frozen funds cannot be sent, except by root through the `force_*` calls.
+	#[pallet::storage]
+	#[pallet::getter(fn freeze)]
+	pub type Freezes<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		FreezeTargetOf<T>,
+		FreezeInfo<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Funds on hold per `(account, asset)` and reason.
+	#[pallet::storage]
+	pub type Holds<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(T::AccountId, T::AssetId),
+		Blake2_128Concat,
+		T::RuntimeHoldReason,
+		T::Balance,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	pub type RecurringPaymentCount<T: Config> = StorageValue<_, RecurringPaymentId, OptionQuery>;
+
+	/// Active recurring payments.
+	#[pallet::storage]
+	#[pallet::getter(fn recurring_payment)]
+	pub type RecurringPayments<T: Config> =
+		CountedStorageMap<_, Twox64Concat, RecurringPaymentId, RecurringPaymentOf<T>, OptionQuery>;
+
+	/// Number of active recurring payments per payer.
+	#[pallet::storage]
+	pub type PayerRecurringPayments<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, OptionQuery>;
+
+	/// Recurring payments to execute at a block.
+	#[pallet::storage]
+	pub type RecurringPaymentsDue<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<RecurringPaymentId, ConstU32<MAX_RECURRING_PAYMENTS_PER_BLOCK>>,
+		OptionQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		Frozen {
+			target: FreezeTargetOf<T>,
+			reason: FreezeReason,
+		},
+		Thawed {
+			target: FreezeTargetOf<T>,
+		},
+		Held {
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			reason: T::RuntimeHoldReason,
+			amount: T::Balance,
+		},
+		Released {
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			reason: T::RuntimeHoldReason,
+			amount: T::Balance,
+		},
+		RecurringPaymentScheduled {
+			payment_id: RecurringPaymentId,
+			payment: RecurringPaymentOf<T>,
+			first_payment: BlockNumberFor<T>,
+		},
+		RecurringPaymentExecuted {
+			payment_id: RecurringPaymentId,
+		},
+		/// The payment of this interval failed, the next ones are still scheduled unless too many
+		/// failed in a row.
+		RecurringPaymentFailed {
+			payment_id: RecurringPaymentId,
+			error: DispatchError,
+		},
+		RecurringPaymentCancelled {
+			payment_id: RecurringPaymentId,
+		},
+		/// The recurring payment ended and its deposit was unreserved.
+		RecurringPaymentEnded {
+			payment_id: RecurringPaymentId,
+			reason: RecurringPaymentEnd,
+		},
+	}
+
 	#[pallet::error]
 	pub enum Error<T> {
 		CannotSetNewCurrencyToRegistry,
 		InvalidCurrency,
+		/// The asset or the account is frozen.
+		Frozen,
+		AlreadyFrozen,
+		NotFrozen,
+		/// Freezing the native asset as a whole is not supported.
+		CannotFreezeNativeAsset,
+		TooManyTransfers,
+		/// The interval is shorter than `MinRecurringPaymentInterval`.
+		IntervalTooShort,
+		/// The recurring payment would end before its first payment.
+		PaymentEndsBeforeStart,
+		/// `MAX_RECURRING_PAYMENTS` are already active.
+		TooManyRecurringPayments,
+		RecurringPaymentNotFound,
+		NotPaymentPayer,
+		/// The amount is zero or below the existential deposit of the asset.
+		PaymentAmountTooLow,
+		/// The payer already has `MaxRecurringPaymentsPerPayer` active recurring payments.
+		TooManyPayerRecurringPayments,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T>
+	where
+		<T as Config>::NativeCurrency: NativeInspect<T::AccountId, Balance = T::Balance>
+			+ NativeMutate<T::AccountId, Balance = T::Balance>,
+		<T as Config>::MultiCurrency: Inspect<T::AccountId, Balance = T::Balance, AssetId = T::AssetId>
+			+ Mutate<T::AccountId, Balance = T::Balance, AssetId = T::AssetId>,
+		<T as Config>::NativeCurrency: ReservableCurrency<T::AccountId, Balance = T::Balance>,
+	{
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let Some(payment_ids) = RecurringPaymentsDue::<T>::take(now) else {
+				return T::DbWeight::get().reads(1)
+			};
+			for payment_id in payment_ids.iter() {
+				if let Some(payment) = RecurringPayments::<T>::get(payment_id) {
+					Self::execute_recurring_payment(now, *payment_id, payment);
+				}
+			}
+			T::DbWeight::get().reads_writes(1, 1).saturating_add(
+				T::WeightInfo::execute_recurring_payment().saturating_mul(payment_ids.len() as u64),
+			)
+		}
 	}
 
 	#[pallet::call]
@@ -82,6 +332,8 @@ pub mod pallet {
 			+ NativeMutate<T::AccountId, Balance = T::Balance>,
 		<T as Config>::MultiCurrency: Inspect<T::AccountId, Balance = T::Balance, AssetId = T::AssetId>
 			+ Mutate<T::AccountId, Balance = T::Balance, AssetId = T::AssetId>,
+		<T as Config>::NativeCurrency: LockableCurrency<T::AccountId, Balance = T::Balance>,
+		<T as Config>::NativeCurrency: ReservableCurrency<T::AccountId, Balance = T::Balance>,
 	{
 		/// Transfer `amount` of `asset` from `origin` to `dest`.
 		///
@@ -153,7 +405,9 @@ pub mod pallet {
 			let dest = T::Lookup::lookup(dest)?;
 			let keep_alive =
 				if keep_alive { Preservation::Preserve } else { Preservation::Expendable };
-			<Self as Mutate<_>>::transfer(asset, &source, &dest, value, keep_alive)?;
+			Self::with_freeze_lock_lifted(&source, || {
+				Self::transfer_ignoring_freezes(asset, &source, &dest, value, keep_alive)
+			})?;
 			Ok(().into())
 		}
 
@@ -178,7 +432,9 @@ pub mod pallet {
 			let dest = T::Lookup::lookup(dest)?;
 			let keep_alive =
 				if keep_alive { Preservation::Preserve } else { Preservation::Expendable };
-			<Self as NativeMutate<_>>::transfer(&source, &dest, value, keep_alive)?;
+			Self::with_freeze_lock_lifted(&source, || {
+				<T::NativeCurrency as NativeMutate<_>>::transfer(&source, &dest, value, keep_alive)
+			})?;
 			Ok(().into())
 		}
 
@@ -329,19 +585,21 @@ pub mod pallet {
 			let dest = T::Lookup::lookup(dest)?;
 			let keep_alive =
 				if keep_alive { Preservation::Preserve } else { Preservation::Expendable };
-			let reducible_balance = <Self as Inspect<T::AccountId>>::reducible_balance(
-				asset,
-				&source,
-				keep_alive,
-				Fortitude::Polite,
-			);
-			<Self as Mutate<T::AccountId>>::transfer(
-				asset,
-				&source,
-				&dest,
-				reducible_balance,
-				keep_alive,
-			)?;
+			Self::with_freeze_lock_lifted(&source, || {
+				let reducible_balance = <Self as Inspect<T::AccountId>>::reducible_balance(
+					asset,
+					&source,
+					keep_alive,
+					Fortitude::Polite,
+				);
+				Self::transfer_ignoring_freezes(
+					asset,
+					&source,
+					&dest,
+					reducible_balance,
+					keep_alive,
+				)
+			})?;
 			Ok(())
 		}
 
@@ -363,19 +621,418 @@ pub mod pallet {
 			let dest = T::Lookup::lookup(dest)?;
 			let keep_alive =
 				if keep_alive { Preservation::Preserve } else { Preservation::Expendable };
-			let reducible_balance = <Self as NativeInspect<T::AccountId>>::reducible_balance(
-				&source,
-				keep_alive,
-				Fortitude::Polite,
+			Self::with_freeze_lock_lifted(&source, || {
+				let reducible_balance = <Self as NativeInspect<T::AccountId>>::reducible_balance(
+					&source,
+					keep_alive,
+					Fortitude::Polite,
+				);
+				<T::NativeCurrency as NativeMutate<T::AccountId>>::transfer(
+					&source,
+					&dest,
+					reducible_balance,
+					keep_alive,
+				)
+			})?;
+			Ok(())
+		}
+
+		/// Freeze `target`, blocking outgoing transfers of the frozen funds through this pallet,
+		/// XCM and IBC. Frozen accounts also cannot move the native asset through other pallets.
+		///
+		/// # Errors
+		///  - When `origin` is not `FreezeOrigin`.
+		///  - If `target` is already frozen.
+		///  - If `target` is the native asset.
+		#[pallet::weight(T::WeightInfo::freeze())]
+		#[pallet::call_index(12)]
+		pub fn freeze(
+			origin: OriginFor<T>,
+			target: FreezeTargetOf<T>,
+			reason: FreezeReason,
+		) -> DispatchResult {
+			T::FreezeOrigin::ensure_origin(origin)?;
+			ensure!(
+				target != FreezeTarget::Asset(T::NativeAssetId::get()),
+				Error::<T>::CannotFreezeNativeAsset
 			);
-			<Self as NativeMutate<T::AccountId>>::transfer(
-				&source,
-				&dest,
-				reducible_balance,
-				keep_alive,
-			)?;
+			ensure!(!Freezes::<T>::contains_key(&target), Error::<T>::AlreadyFrozen);
+			Freezes::<T>::insert(
+				&target,
+				FreezeInfo {
+					reason: reason.clone(),
+					since: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+			Self::update_freeze_lock(&target);
+			Self::deposit_event(Event::<T>::Frozen { target, reason });
+			Ok(())
+		}
+
+		/// Lift the freeze on `target`.
+		///
+		/// # Errors
+		///  - When `origin` is not `FreezeOrigin`.
+		///  - If `target` is not frozen.
+		#[pallet::weight(T::WeightInfo::thaw())]
+		#[pallet::call_index(13)]
+		pub fn thaw(origin: OriginFor<T>, target: FreezeTargetOf<T>) -> DispatchResult {
+			T::FreezeOrigin::ensure_origin(origin)?;
+			Freezes::<T>::take(&target).ok_or(Error::<T>::NotFrozen)?;
+			Self::update_freeze_lock(&target);
+			Self::deposit_event(Event::<T>::Thawed { target });
+			Ok(())
+		}
+
+		/// Transfer each `(asset, dest, amount)` of `transfers` from `origin`. Either all
+		/// transfers succeed or none is made.
+		///
+		/// # Errors
+		///  - When `origin` is not signed.
+		///  - If any of the transfers fails.
+		#[pallet::weight(T::WeightInfo::transfer_many(transfers.len() as u32))]
+		#[pallet::call_index(14)]
+		pub fn transfer_many(
+			origin: OriginFor<T>,
+			transfers: BoundedVec<TransferOf<T>, ConstU32<MAX_TRANSFER_MANY>>,
+			keep_alive: bool,
+		) -> DispatchResult {
+			let src = ensure_signed(origin)?;
+			let keep_alive =
+				if keep_alive { Preservation::Preserve } else { Preservation::Expendable };
+			for (asset, dest, amount) in transfers {
+				let dest = T::Lookup::lookup(dest)?;
+				<Self as Mutate<T::AccountId>>::transfer(asset, &src, &dest, amount, keep_alive)?;
+			}
+			Ok(())
+		}
+
+		/// Authorise a payment of `amount` of `asset_id` to `payee` every `interval` blocks,
+		/// starting `interval` blocks from now and until `end` if any. Payments never reap the
+		/// payer. `RecurringPaymentDeposit` is reserved from the payer until the recurring payment
+		/// ends, which it also does after `MaxRecurringPaymentFailures` failed payments in a row.
+		///
+		/// # Errors
+		///  - When `origin` is not signed.
+		///  - If `interval` is below `MinRecurringPaymentInterval` or `end` is before the first
+		///    payment.
+		///  - If `amount` is below the existential deposit of `asset_id`.
+		///  - If the payer or the chain has too many recurring payments.
+		///  - If the deposit cannot be reserved.
+		#[pallet::weight(T::WeightInfo::schedule_recurring_payment())]
+		#[pallet::call_index(15)]
+		pub fn schedule_recurring_payment(
+			origin: OriginFor<T>,
+			payee: <T::Lookup as StaticLookup>::Source,
+			asset_id: T::AssetId,
+			#[pallet::compact] amount: T::Balance,
+			interval: BlockNumberFor<T>,
+			end: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let payer = ensure_signed(origin)?;
+			let payee = T::Lookup::lookup(payee)?;
+			ensure!(
+				!interval.is_zero() && interval >= T::MinRecurringPaymentInterval::get(),
+				Error::<T>::IntervalTooShort
+			);
+			valid_asset_id::<T>(asset_id).ok_or(Error::<T>::InvalidCurrency)?;
+			ensure!(
+				!amount.is_zero() &&
+					amount >= <Self as Inspect<T::AccountId>>::minimum_balance(asset_id),
+				Error::<T>::PaymentAmountTooLow
+			);
+			let first_payment = frame_system::Pallet::<T>::block_number().saturating_add(interval);
+			ensure!(
+				end.map_or(true, |end| first_payment <= end),
+				Error::<T>::PaymentEndsBeforeStart
+			);
+			ensure!(
+				RecurringPayments::<T>::count() < MAX_RECURRING_PAYMENTS,
+				Error::<T>::TooManyRecurringPayments
+			);
+			let payer_payments = PayerRecurringPayments::<T>::get(&payer).unwrap_or_default();
+			ensure!(
+				payer_payments < T::MaxRecurringPaymentsPerPayer::get(),
+				Error::<T>::TooManyPayerRecurringPayments
+			);
+
+			let deposit = T::RecurringPaymentDeposit::get();
+			<T::NativeCurrency as ReservableCurrency<T::AccountId>>::reserve(&payer, deposit)?;
+			let payment_id = RecurringPaymentCount::<T>::get().unwrap_or_default();
+			let first_payment = Self::schedule_recurring_payment_at(payment_id, first_payment)?;
+			RecurringPaymentCount::<T>::set(Some(payment_id.saturating_add(1)));
+			PayerRecurringPayments::<T>::insert(&payer, payer_payments.saturating_add(1));
+			let payment = RecurringPayment {
+				payer,
+				payee,
+				asset_id,
+				amount,
+				interval,
+				end,
+				next_payment: first_payment,
+				deposit,
+				failures: 0,
+			};
+			RecurringPayments::<T>::insert(payment_id, &payment);
+			Self::deposit_event(Event::<T>::RecurringPaymentScheduled {
+				payment_id,
+				payment,
+				first_payment,
+			});
+			Ok(())
+		}
+
+		/// Revoke a recurring payment of `origin` and unreserve its deposit.
+		///
+		/// # Errors
+		///  - When `origin` is not the payer of the recurring payment.
+		///  - If the recurring payment does not exist.
+		#[pallet::weight(T::WeightInfo::cancel_recurring_payment())]
+		#[pallet::call_index(16)]
+		pub fn cancel_recurring_payment(
+			origin: OriginFor<T>,
+			payment_id: RecurringPaymentId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let payment = RecurringPayments::<T>::get(payment_id)
+				.ok_or(Error::<T>::RecurringPaymentNotFound)?;
+			ensure!(payment.payer == who, Error::<T>::NotPaymentPayer);
+			RecurringPaymentsDue::<T>::mutate_exists(payment.next_payment, |due| {
+				if let Some(payment_ids) = due {
+					payment_ids.retain(|id| *id != payment_id);
+					if payment_ids.is_empty() {
+						*due = None;
+					}
+				}
+			});
+			Self::remove_recurring_payment(payment_id, &payment);
+			Self::deposit_event(Event::<T>::RecurringPaymentCancelled { payment_id });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T>
+	where
+		<T as Config>::NativeCurrency: NativeInspect<T::AccountId, Balance = T::Balance>
+			+ NativeMutate<T::AccountId, Balance = T::Balance>,
+		<T as Config>::MultiCurrency: Inspect<T::AccountId, Balance = T::Balance, AssetId = T::AssetId>
+			+ Mutate<T::AccountId, Balance = T::Balance, AssetId = T::AssetId>,
+		<T as Config>::NativeCurrency: ReservableCurrency<T::AccountId, Balance = T::Balance>,
+	{
+		/// Pays the current interval of a recurring payment and schedules the next one.
+		fn execute_recurring_payment(
+			now: BlockNumberFor<T>,
+			payment_id: RecurringPaymentId,
+			mut payment: RecurringPaymentOf<T>,
+		) {
+			let paid = with_storage_layer(|| {
+				<Self as Mutate<T::AccountId>>::transfer(
+					payment.asset_id,
+					&payment.payer,
+					&payment.payee,
+					payment.amount,
+					Preservation::Preserve,
+				)
+			});
+			match paid {
+				Ok(_) => {
+					payment.failures = 0;
+					Self::deposit_event(Event::<T>::RecurringPaymentExecuted { payment_id });
+				},
+				Err(error) => {
+					payment.failures = payment.failures.saturating_add(1);
+					Self::deposit_event(Event::<T>::RecurringPaymentFailed { payment_id, error });
+				},
+			}
+
+			let next_payment = now.saturating_add(payment.interval);
+			let ended = if payment.end.map_or(false, |end| next_payment > end) {
+				Some(RecurringPaymentEnd::Completed)
+			} else if payment.failures >= T::MaxRecurringPaymentFailures::get() {
+				Some(RecurringPaymentEnd::TooManyFailures)
+			} else {
+				// cannot fail while there are fewer than `MAX_RECURRING_PAYMENTS`
+				match Self::schedule_recurring_payment_at(payment_id, next_payment) {
+					Ok(block) => {
+						payment.next_payment = block;
+						None
+					},
+					Err(_) => Some(RecurringPaymentEnd::NotRescheduled),
+				}
+			};
+			match ended {
+				Some(reason) => {
+					Self::remove_recurring_payment(payment_id, &payment);
+					Self::deposit_event(Event::<T>::RecurringPaymentEnded { payment_id, reason });
+				},
+				None => RecurringPayments::<T>::insert(payment_id, payment),
+			}
+		}
+
+		/// Removes a recurring payment and unreserves its deposit.
+		fn remove_recurring_payment(
+			payment_id: RecurringPaymentId,
+			payment: &RecurringPaymentOf<T>,
+		) {
+			RecurringPayments::<T>::remove(payment_id);
+			PayerRecurringPayments::<T>::mutate_exists(&payment.payer, |payments| {
+				*payments = payments.map(|payments| payments.saturating_sub(1)).filter(|n| *n > 0);
+			});
+			<T::NativeCurrency as ReservableCurrency<T::AccountId>>::unreserve(
+				&payment.payer,
+				payment.deposit,
+			);
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether `who` is frozen for `asset`, by any of the freeze targets.
+		pub fn is_frozen(asset: T::AssetId, who: &T::AccountId) -> bool {
+			Freezes::<T>::contains_key(FreezeTarget::Asset(asset)) ||
+				Freezes::<T>::contains_key(FreezeTarget::Account(who.clone())) ||
+				Freezes::<T>::contains_key(FreezeTarget::AccountAsset(who.clone(), asset))
+		}
+
+		/// Fails if `who` may not send `asset`.
+		pub fn ensure_not_frozen(asset: T::AssetId, who: &T::AccountId) -> DispatchResult {
+			ensure!(!Self::is_frozen(asset, who), Error::<T>::Frozen);
 			Ok(())
 		}
+
+		/// Schedules a recurring payment at the first block from `at` with room for it.
+		fn schedule_recurring_payment_at(
+			payment_id: RecurringPaymentId,
+			at: BlockNumberFor<T>,
+		) -> Result<BlockNumberFor<T>, DispatchError> {
+			let mut block = at;
+			for _ in 0..MAX_RECURRING_PAYMENT_DELAY {
+				let scheduled = RecurringPaymentsDue::<T>::try_mutate(block, |due| {
+					due.get_or_insert_with(Default::default).try_push(payment_id)
+				});
+				if scheduled.is_ok() {
+					return Ok(block)
+				}
+				block = block.saturating_add(One::one());
+			}
+			Err(Error::<T>::TooManyRecurringPayments.into())
+		}
+
+		fn is_native_frozen(who: &T::AccountId) -> bool {
+			Freezes::<T>::contains_key(FreezeTarget::Account(who.clone())) ||
+				Freezes::<T>::contains_key(FreezeTarget::AccountAsset(
+					who.clone(),
+					T::NativeAssetId::get(),
+				))
+		}
+
+		fn balance_on_hold_of(
+			asset: T::AssetId,
+			reason: &T::RuntimeHoldReason,
+			who: &T::AccountId,
+		) -> T::Balance {
+			Holds::<T>::get((who, asset), reason).unwrap_or_else(Zero::zero)
+		}
+
+		fn set_balance_on_hold_of(
+			asset: T::AssetId,
+			reason: &T::RuntimeHoldReason,
+			who: &T::AccountId,
+			amount: T::Balance,
+		) {
+			if amount.is_zero() {
+				Holds::<T>::remove((who, asset), reason);
+			} else {
+				Holds::<T>::insert((who, asset), reason, amount);
+			}
+		}
+
+		fn do_hold(
+			asset: T::AssetId,
+			reason: &T::RuntimeHoldReason,
+			who: &T::AccountId,
+			amount: T::Balance,
+		) {
+			let held = Self::balance_on_hold_of(asset, reason, who).saturating_add(amount);
+			Self::set_balance_on_hold_of(asset, reason, who, held);
+			Self::deposit_event(Event::<T>::Held {
+				asset_id: asset,
+				who: who.clone(),
+				reason: reason.clone(),
+				amount,
+			});
+		}
+
+		/// How much of `amount` can be released from the `reason` hold of `who`.
+		fn releasable(
+			asset: T::AssetId,
+			reason: &T::RuntimeHoldReason,
+			who: &T::AccountId,
+			amount: T::Balance,
+			precision: Precision,
+		) -> Result<T::Balance, DispatchError> {
+			let held = Self::balance_on_hold_of(asset, reason, who);
+			match precision {
+				Precision::Exact => {
+					ensure!(amount <= held, TokenError::FundsUnavailable);
+					Ok(amount)
+				},
+				Precision::BestEffort => Ok(amount.min(held)),
+			}
+		}
+
+		fn do_release(
+			asset: T::AssetId,
+			reason: &T::RuntimeHoldReason,
+			who: &T::AccountId,
+			amount: T::Balance,
+		) {
+			let held = Self::balance_on_hold_of(asset, reason, who).saturating_sub(amount);
+			Self::set_balance_on_hold_of(asset, reason, who, held);
+			Self::deposit_event(Event::<T>::Released {
+				asset_id: asset,
+				who: who.clone(),
+				reason: reason.clone(),
+				amount,
+			});
+		}
+	}
+
+	impl<T: Config> Pallet<T>
+	where
+		<T as Config>::NativeCurrency: LockableCurrency<T::AccountId, Balance = T::Balance>,
+	{
+		/// Sets or removes the native currency lock of the account frozen by `target`.
+		fn update_freeze_lock(target: &FreezeTargetOf<T>) {
+			let who = match target {
+				FreezeTarget::Account(who) => who,
+				FreezeTarget::AccountAsset(who, asset) if *asset == T::NativeAssetId::get() => who,
+				_ => return,
+			};
+			if Self::is_native_frozen(who) {
+				<T::NativeCurrency as LockableCurrency<T::AccountId>>::set_lock(
+					FREEZE_LOCK_ID,
+					who,
+					T::Balance::max_value(),
+					WithdrawReasons::all(),
+				);
+			} else {
+				<T::NativeCurrency as LockableCurrency<T::AccountId>>::remove_lock(
+					FREEZE_LOCK_ID,
+					who,
+				);
+			}
+		}
+
+		/// Runs `f` with the native currency lock of a frozen `who` removed.
+		fn with_freeze_lock_lifted<R>(who: &T::AccountId, f: impl FnOnce() -> R) -> R {
+			if !Self::is_native_frozen(who) {
+				return f()
+			}
+			<T::NativeCurrency as LockableCurrency<T::AccountId>>::remove_lock(FREEZE_LOCK_ID, who);
+			let result = f();
+			Self::update_freeze_lock(&FreezeTarget::Account(who.clone()));
+			result
+		}
 	}
 
 	pub(crate) fn valid_asset_id<T: Config>(asset_id: T::AssetId) -> Option<T::AssetId> {
@@ -576,31 +1233,38 @@ pub mod pallet {
 
 		impl<T: Config> UnbalancedHold<T::AccountId> for Pallet<T>
 		where
-			T::NativeCurrency:
-				UnbalancedHold<T::AccountId, Balance = T::Balance, Reason = T::RuntimeHoldReason>,
+			T::NativeCurrency: UnbalancedHold<T::AccountId, Balance = T::Balance, Reason = ()>
+				+ InspectHold<T::AccountId, Balance = T::Balance, Reason = ()>,
 		{
 			fn set_balance_on_hold(
 				reason: &Self::Reason,
 				who: &T::AccountId,
 				amount: Self::Balance,
 			) -> sp_runtime::DispatchResult {
-				<<T as Config>::NativeCurrency>::set_balance_on_hold(reason, who, amount)
+				let asset = T::NativeAssetId::get();
+				let held = <<T as Config>::NativeCurrency>::balance_on_hold(&(), who)
+					.saturating_sub(Self::balance_on_hold_of(asset, reason, who))
+					.saturating_add(amount);
+				<<T as Config>::NativeCurrency>::set_balance_on_hold(&(), who, held)?;
+				Self::set_balance_on_hold_of(asset, reason, who, amount);
+				Ok(())
 			}
 		}
 
 		impl<T: Config> MutateHold<T::AccountId> for Pallet<T>
 		where
-			<T as Config>::NativeCurrency:
-				InspectHold<T::AccountId, Balance = T::Balance, Reason = T::RuntimeHoldReason>,
-			<T as Config>::NativeCurrency:
-				MutateHold<T::AccountId, Balance = T::Balance, Reason = T::RuntimeHoldReason>,
+			<T as Config>::NativeCurrency: InspectHold<T::AccountId, Balance = T::Balance, Reason = ()>
+				+ UnbalancedHold<T::AccountId, Balance = T::Balance, Reason = ()>
+				+ MutateHold<T::AccountId, Balance = T::Balance, Reason = ()>,
 		{
 			fn hold(
 				reason: &Self::Reason,
 				who: &T::AccountId,
 				amount: Self::Balance,
 			) -> DispatchResult {
-				<<T as Config>::NativeCurrency>::hold(reason, who, amount)
+				<<T as Config>::NativeCurrency>::hold(&(), who, amount)?;
+				Self::do_hold(T::NativeAssetId::get(), reason, who, amount);
+				Ok(())
 			}
 
 			fn release(
@@ -609,7 +1273,12 @@ pub mod pallet {
 				amount: Self::Balance,
 				best_effort: Precision,
 			) -> Result<Self::Balance, DispatchError> {
-				<<T as Config>::NativeCurrency>::release(reason, who, amount, best_effort)
+				let asset = T::NativeAssetId::get();
+				let amount = Self::releasable(asset, reason, who, amount, best_effort)?;
+				let released =
+					<<T as Config>::NativeCurrency>::release(&(), who, amount, best_effort)?;
+				Self::do_release(asset, reason, who, released);
+				Ok(released)
 			}
 		}
 
@@ -632,6 +1301,16 @@ pub mod pallet {
 			) -> Result<Self::Balance, DispatchError> {
 				<<T as Config>::NativeCurrency>::burn_from(who, amount, precision, force)
 			}
+
+			fn transfer(
+				source: &T::AccountId,
+				dest: &T::AccountId,
+				amount: Self::Balance,
+				preservation: Preservation,
+			) -> Result<Self::Balance, DispatchError> {
+				Self::ensure_not_frozen(T::NativeAssetId::get(), source)?;
+				<<T as Config>::NativeCurrency>::transfer(source, dest, amount, preservation)
+			}
 		}
 
 		impl<T: Config> Unbalanced<T::AccountId> for Pallet<T>
@@ -768,15 +1447,15 @@ pub mod pallet {
 		impl<T: Config> InspectHold<T::AccountId> for Pallet<T>
 		where
 			<T as Config>::NativeCurrency: Inspect<T::AccountId, Balance = T::Balance>
-				+ InspectHold<T::AccountId, Reason = T::RuntimeHoldReason>,
+				+ InspectHold<T::AccountId, Reason = ()>,
 		{
 			type Reason = T::RuntimeHoldReason;
 			fn balance_on_hold(reason: &Self::Reason, who: &T::AccountId) -> Self::Balance {
-				<<T as Config>::NativeCurrency>::balance_on_hold(reason, who)
+				Self::balance_on_hold_of(T::NativeAssetId::get(), reason, who)
 			}
 
-			fn can_hold(reason: &Self::Reason, who: &T::AccountId, amount: Self::Balance) -> bool {
-				<<T as Config>::NativeCurrency>::can_hold(reason, who, amount)
+			fn can_hold(_reason: &Self::Reason, who: &T::AccountId, amount: Self::Balance) -> bool {
+				<<T as Config>::NativeCurrency>::can_hold(&(), who, amount)
 			}
 
 			fn total_balance_on_hold(who: &T::AccountId) -> Self::Balance {
@@ -790,8 +1469,8 @@ pub mod pallet {
 				<<T as Config>::NativeCurrency>::reducible_total_balance_on_hold(who, force)
 			}
 
-			fn hold_available(reason: &Self::Reason, who: &T::AccountId) -> bool {
-				<<T as Config>::NativeCurrency>::hold_available(reason, who)
+			fn hold_available(_reason: &Self::Reason, who: &T::AccountId) -> bool {
+				<<T as Config>::NativeCurrency>::hold_available(&(), who)
 			}
 		}
 	}
@@ -805,7 +1484,10 @@ pub mod pallet {
 				MutateHold as NativeMutateHold, Unbalanced as NativeUnbalanced,
 				UnbalancedHold as NativeUnbalancedHold,
 			},
-			fungibles::{Inspect, InspectHold, Mutate, MutateHold, Unbalanced, UnbalancedHold},
+			fungibles::{
+				Balanced, DecreaseIssuance, IncreaseIssuance, Inspect, InspectHold, Mutate,
+				MutateHold, Unbalanced, UnbalancedHold,
+			},
 			DepositConsequence, Fortitude, Precision, Preservation, Provenance,
 			WithdrawConsequence,
 		};
@@ -833,6 +1515,9 @@ pub mod pallet {
 				preservation: Preservation,
 				force: Fortitude,
 			) -> Result<Self::Balance, DispatchError> {
+				if force == Fortitude::Polite {
+					Self::ensure_not_frozen(asset, who)?;
+				}
 				if asset == T::NativeAssetId::get() {
 					return <<T as Config>::NativeCurrency>::decrease_balance(
 						who,
@@ -893,19 +1578,28 @@ pub mod pallet {
 			}
 		}
 
+		/// Withdrawals go through `Unbalanced::decrease_balance`, so frozen funds can only be
+		/// withdrawn with `Fortitude::Force`.
+		impl<T: Config> Balanced<T::AccountId> for Pallet<T>
+		where
+			<T as Config>::NativeCurrency: NativeUnbalanced<T::AccountId, Balance = T::Balance>,
+			<T as Config>::MultiCurrency:
+				Unbalanced<T::AccountId, Balance = T::Balance, AssetId = T::AssetId>,
+		{
+			type OnDropDebt = IncreaseIssuance<T::AccountId, Self>;
+			type OnDropCredit = DecreaseIssuance<T::AccountId, Self>;
+		}
+
 		impl<T: Config> UnbalancedHold<T::AccountId> for Pallet<T>
 		where
 			T::MultiCurrency: UnbalancedHold<
-				T::AccountId,
-				Balance = T::Balance,
-				AssetId = T::AssetId,
-				Reason = T::RuntimeHoldReason,
-			>,
-			T::NativeCurrency: NativeUnbalancedHold<
-				T::AccountId,
-				Balance = T::Balance,
-				Reason = T::RuntimeHoldReason,
-			>,
+					T::AccountId,
+					Balance = T::Balance,
+					AssetId = T::AssetId,
+					Reason = (),
+				> + InspectHold<T::AccountId, Balance = T::Balance, AssetId = T::AssetId, Reason = ()>,
+			T::NativeCurrency: NativeUnbalancedHold<T::AccountId, Balance = T::Balance, Reason = ()>
+				+ NativeInspectHold<T::AccountId, Balance = T::Balance, Reason = ()>,
 		{
 			fn set_balance_on_hold(
 				asset: Self::AssetId,
@@ -913,37 +1607,44 @@ pub mod pallet {
 				who: &T::AccountId,
 				amount: Self::Balance,
 			) -> sp_runtime::DispatchResult {
+				let recorded = Self::balance_on_hold_of(asset, reason, who);
 				if asset == T::NativeAssetId::get() {
-					return <<T as Config>::NativeCurrency>::set_balance_on_hold(reason, who, amount)
+					let held = <<T as Config>::NativeCurrency>::balance_on_hold(&(), who)
+						.saturating_sub(recorded)
+						.saturating_add(amount);
+					<<T as Config>::NativeCurrency>::set_balance_on_hold(&(), who, held)?;
+				} else {
+					let asset = valid_asset_id::<T>(asset).ok_or(Error::<T>::InvalidCurrency)?;
+					let held = <<T as Config>::MultiCurrency>::balance_on_hold(asset, &(), who)
+						.saturating_sub(recorded)
+						.saturating_add(amount);
+					<<T as Config>::MultiCurrency>::set_balance_on_hold(asset, &(), who, held)?;
 				}
-				let asset = valid_asset_id::<T>(asset).ok_or(Error::<T>::InvalidCurrency)?;
-				<<T as Config>::MultiCurrency>::set_balance_on_hold(asset, reason, who, amount)
+				Self::set_balance_on_hold_of(asset, reason, who, amount);
+				Ok(())
 			}
 		}
 
 		impl<T: Config> MutateHold<T::AccountId> for Pallet<T>
 		where
-			<T as Config>::NativeCurrency: NativeInspectHold<
-				T::AccountId,
-				Balance = T::Balance,
-				Reason = T::RuntimeHoldReason,
-			>,
+			<T as Config>::NativeCurrency:
+				NativeInspectHold<T::AccountId, Balance = T::Balance, Reason = ()>,
 			<T as Config>::NativeCurrency: NativeMutate<T::AccountId, Balance = T::Balance>,
 			<T as Config>::NativeCurrency:
-				NativeMutateHold<T::AccountId, Balance = T::Balance, Reason = T::RuntimeHoldReason>,
+				NativeUnbalancedHold<T::AccountId, Balance = T::Balance, Reason = ()>,
+			<T as Config>::NativeCurrency:
+				NativeMutateHold<T::AccountId, Balance = T::Balance, Reason = ()>,
 
-			<T as Config>::MultiCurrency: InspectHold<
-				T::AccountId,
-				Balance = T::Balance,
-				AssetId = T::AssetId,
-				Reason = T::RuntimeHoldReason,
-			>,
-			<T as Config>::MultiCurrency: MutateHold<
+			<T as Config>::MultiCurrency:
+				InspectHold<T::AccountId, Balance = T::Balance, AssetId = T::AssetId, Reason = ()>,
+			<T as Config>::MultiCurrency: UnbalancedHold<
 				T::AccountId,
 				Balance = T::Balance,
 				AssetId = T::AssetId,
-				Reason = T::RuntimeHoldReason,
+				Reason = (),
 			>,
+			<T as Config>::MultiCurrency:
+				MutateHold<T::AccountId, Balance = T::Balance, AssetId = T::AssetId, Reason = ()>,
 		{
 			fn hold(
 				asset: Self::AssetId,
@@ -952,10 +1653,13 @@ pub mod pallet {
 				amount: Self::Balance,
 			) -> DispatchResult {
 				if asset == T::NativeAssetId::get() {
-					return <<T as Config>::NativeCurrency>::hold(reason, who, amount)
+					<<T as Config>::NativeCurrency>::hold(&(), who, amount)?;
+				} else {
+					let asset = valid_asset_id::<T>(asset).ok_or(Error::<T>::InvalidCurrency)?;
+					<<T as Config>::MultiCurrency>::hold(asset, &(), who, amount)?;
 				}
-				let asset = valid_asset_id::<T>(asset).ok_or(Error::<T>::InvalidCurrency)?;
-				<<T as Config>::MultiCurrency>::hold(asset, reason, who, amount)
+				Self::do_hold(asset, reason, who, amount);
+				Ok(())
 			}
 
 			fn release(
@@ -965,16 +1669,15 @@ pub mod pallet {
 				amount: Self::Balance,
 				best_effort: Precision,
 			) -> Result<Self::Balance, DispatchError> {
-				if asset == T::NativeAssetId::get() {
-					return <<T as Config>::NativeCurrency>::release(
-						reason,
-						who,
-						amount,
-						best_effort,
-					)
-				}
-				let asset = valid_asset_id::<T>(asset).ok_or(Error::<T>::InvalidCurrency)?;
-				<<T as Config>::MultiCurrency>::release(asset, reason, who, amount, best_effort)
+				let amount = Self::releasable(asset, reason, who, amount, best_effort)?;
+				let released = if asset == T::NativeAssetId::get() {
+					<<T as Config>::NativeCurrency>::release(&(), who, amount, best_effort)?
+				} else {
+					let asset = valid_asset_id::<T>(asset).ok_or(Error::<T>::InvalidCurrency)?;
+					<<T as Config>::MultiCurrency>::release(asset, &(), who, amount, best_effort)?
+				};
+				Self::do_release(asset, reason, who, released);
+				Ok(released)
 			}
 		}
 
@@ -1005,6 +1708,9 @@ pub mod pallet {
 				precision: Precision,
 				force: Fortitude,
 			) -> Result<Self::Balance, DispatchError> {
+				if force == Fortitude::Polite {
+					Self::ensure_not_frozen(asset, who)?;
+				}
 				if asset == T::NativeAssetId::get() {
 					return <<T as Config>::NativeCurrency>::burn_from(who, amount, precision, force)
 				}
@@ -1019,6 +1725,24 @@ pub mod pallet {
 				amount: Self::Balance,
 				preservation: Preservation,
 			) -> Result<Self::Balance, DispatchError> {
+				Self::ensure_not_frozen(asset, source)?;
+				Self::transfer_ignoring_freezes(asset, source, dest, amount, preservation)
+			}
+		}
+
+		impl<T: Config> Pallet<T>
+		where
+			<T as Config>::MultiCurrency:
+				Mutate<T::AccountId, Balance = T::Balance, AssetId = T::AssetId>,
+			<T as Config>::NativeCurrency: NativeMutate<T::AccountId, Balance = T::Balance>,
+		{
+			pub(crate) fn transfer_ignoring_freezes(
+				asset: T::AssetId,
+				source: &T::AccountId,
+				dest: &T::AccountId,
+				amount: T::Balance,
+				preservation: Preservation,
+			) -> Result<T::Balance, DispatchError> {
 				if asset == T::NativeAssetId::get() {
 					return <<T as Config>::NativeCurrency>::transfer(
 						source,
@@ -1143,9 +1867,9 @@ pub mod pallet {
 		impl<T: Config> InspectHold<T::AccountId> for Pallet<T>
 		where
 			<T as Config>::MultiCurrency: Inspect<T::AccountId, Balance = T::Balance, AssetId = T::AssetId>
-				+ InspectHold<T::AccountId, Reason = T::RuntimeHoldReason>,
+				+ InspectHold<T::AccountId, Reason = ()>,
 			<T as Config>::NativeCurrency: NativeInspect<T::AccountId, Balance = T::Balance>
-				+ NativeInspectHold<T::AccountId, Reason = T::RuntimeHoldReason>,
+				+ NativeInspectHold<T::AccountId, Reason = ()>,
 		{
 			type Reason = T::RuntimeHoldReason;
 			fn balance_on_hold(
@@ -1153,26 +1877,20 @@ pub mod pallet {
 				reason: &Self::Reason,
 				who: &T::AccountId,
 			) -> Self::Balance {
-				if asset == T::NativeAssetId::get() {
-					return <<T as Config>::NativeCurrency>::balance_on_hold(reason, who)
-				}
-				if let Some(asset) = valid_asset_id::<T>(asset) {
-					return <<T as Config>::MultiCurrency>::balance_on_hold(asset, reason, who)
-				}
-				T::Balance::zero()
+				Self::balance_on_hold_of(asset, reason, who)
 			}
 
 			fn can_hold(
 				asset: Self::AssetId,
-				reason: &Self::Reason,
+				_reason: &Self::Reason,
 				who: &T::AccountId,
 				amount: Self::Balance,
 			) -> bool {
 				if asset == T::NativeAssetId::get() {
-					return <<T as Config>::NativeCurrency>::can_hold(reason, who, amount)
+					return <<T as Config>::NativeCurrency>::can_hold(&(), who, amount)
 				}
 				if let Some(asset) = valid_asset_id::<T>(asset) {
-					return <<T as Config>::MultiCurrency>::can_hold(asset, reason, who, amount)
+					return <<T as Config>::MultiCurrency>::can_hold(asset, &(), who, amount)
 				}
 				false
 			}
@@ -1207,14 +1925,14 @@ pub mod pallet {
 
 			fn hold_available(
 				asset: Self::AssetId,
-				reason: &Self::Reason,
+				_reason: &Self::Reason,
 				who: &T::AccountId,
 			) -> bool {
 				if asset == T::NativeAssetId::get() {
-					return <<T as Config>::NativeCurrency>::hold_available(reason, who)
+					return <<T as Config>::NativeCurrency>::hold_available(&(), who)
 				}
 				if let Some(asset) = valid_asset_id::<T>(asset) {
-					return <<T as Config>::MultiCurrency>::hold_available(asset, reason, who)
+					return <<T as Config>::MultiCurrency>::hold_available(asset, &(), who)
 				}
 				false
 			}
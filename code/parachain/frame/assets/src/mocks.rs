@@ -58,8 +58,11 @@ parameter_types! {
 	pub const NativeAssetId: AssetId = 1;
 }
 
+pub const RECURRING_PAYMENT_DEPOSIT: Balance = 10;
+
 impl Config for Test {
-	type RuntimeHoldReason = ();
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = [u8; 8];
 	type AssetId = AssetId;
 	type Balance = Balance;
 	type NativeAssetId = NativeAssetId;
@@ -67,7 +70,12 @@ impl Config for Test {
 	type MultiCurrency = Tokens;
 	type WeightInfo = ();
 	type AdminOrigin = EnsureRoot<AccountId>;
+	type FreezeOrigin = EnsureRoot<AccountId>;
 	type CurrencyValidator = ValidateCurrencyId;
+	type RecurringPaymentDeposit = ConstU64<RECURRING_PAYMENT_DEPOSIT>;
+	type MaxRecurringPaymentsPerPayer = ConstU32<2>;
+	type MinRecurringPaymentInterval = ConstU64<2>;
+	type MaxRecurringPaymentFailures = ConstU32<2>;
 }
 
 parameter_types! {
@@ -143,7 +151,7 @@ impl pallet_balances::Config for Test {
 	type ReserveIdentifier = [u8; 8];
 	type FreezeIdentifier = [u8; 8];
 
-	type HoldIdentifier = [u8; 8];
+	type HoldIdentifier = ();
 
 	type MaxReserves = ();
 	type MaxLocks = ();
@@ -15,13 +15,13 @@ use sp_runtime::{
 	ArithmeticError, DispatchError,
 };
 
-impl<T: Config, AccountId> MultiCurrency<AccountId> for Pallet<T>
+impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T>
 where
 	<T as Config>::Balance: Saturating + AtLeast32BitUnsigned + num_traits::Saturating,
 	<T as Config>::AssetId: MaybeSerializeDeserialize,
-	<T as Config>::NativeCurrency: Currency<AccountId, Balance = T::Balance>,
+	<T as Config>::NativeCurrency: Currency<T::AccountId, Balance = T::Balance>,
 	<T as Config>::MultiCurrency:
-		MultiCurrency<AccountId, Balance = T::Balance, CurrencyId = T::AssetId>,
+		MultiCurrency<T::AccountId, Balance = T::Balance, CurrencyId = T::AssetId>,
 {
 	type CurrencyId = T::AssetId;
 
@@ -47,7 +47,7 @@ where
 		T::Balance::zero()
 	}
 
-	fn total_balance(currency_id: Self::CurrencyId, who: &AccountId) -> Self::Balance {
+	fn total_balance(currency_id: Self::CurrencyId, who: &T::AccountId) -> Self::Balance {
 		if currency_id == T::NativeAssetId::get() {
 			return <<T as Config>::NativeCurrency>::total_balance(who)
 		}
@@ -57,7 +57,7 @@ where
 		T::Balance::zero()
 	}
 
-	fn free_balance(currency_id: Self::CurrencyId, who: &AccountId) -> Self::Balance {
+	fn free_balance(currency_id: Self::CurrencyId, who: &T::AccountId) -> Self::Balance {
 		if currency_id == T::NativeAssetId::get() {
 			return <<T as Config>::NativeCurrency>::free_balance(who)
 		}
@@ -69,9 +69,10 @@ where
 
 	fn ensure_can_withdraw(
 		currency_id: Self::CurrencyId,
-		who: &AccountId,
+		who: &T::AccountId,
 		amount: Self::Balance,
 	) -> DispatchResult {
+		Self::ensure_not_frozen(currency_id, who)?;
 		if currency_id == T::NativeAssetId::get() {
 			let new_balance = <<T as Config>::NativeCurrency>::free_balance(who)
 				.checked_sub(&amount)
@@ -89,10 +90,11 @@ where
 
 	fn transfer(
 		currency_id: Self::CurrencyId,
-		from: &AccountId,
-		to: &AccountId,
+		from: &T::AccountId,
+		to: &T::AccountId,
 		amount: Self::Balance,
 	) -> DispatchResult {
+		Self::ensure_not_frozen(currency_id, from)?;
 		if currency_id == T::NativeAssetId::get() {
 			return <<T as Config>::NativeCurrency>::transfer(
 				from,
@@ -107,7 +109,7 @@ where
 
 	fn deposit(
 		currency_id: Self::CurrencyId,
-		who: &AccountId,
+		who: &T::AccountId,
 		amount: Self::Balance,
 	) -> DispatchResult {
 		if currency_id == T::NativeAssetId::get() {
@@ -122,9 +124,10 @@ where
 
 	fn withdraw(
 		currency_id: Self::CurrencyId,
-		who: &AccountId,
+		who: &T::AccountId,
 		amount: Self::Balance,
 	) -> DispatchResult {
+		Self::ensure_not_frozen(currency_id, who)?;
 		if currency_id == T::NativeAssetId::get() {
 			// Drop the imbalance, causing the total issuance to decrease, in accordance with the
 			// MultiCurrency trait.
@@ -140,7 +143,7 @@ where
 		<<T as Config>::MultiCurrency>::withdraw(currency_id, who, amount)
 	}
 
-	fn can_slash(currency_id: Self::CurrencyId, who: &AccountId, amount: Self::Balance) -> bool {
+	fn can_slash(currency_id: Self::CurrencyId, who: &T::AccountId, amount: Self::Balance) -> bool {
 		if currency_id == T::NativeAssetId::get() {
 			return <<T as Config>::NativeCurrency>::can_slash(who, amount)
 		}
@@ -152,7 +155,7 @@ where
 
 	fn slash(
 		currency_id: Self::CurrencyId,
-		who: &AccountId,
+		who: &T::AccountId,
 		amount: Self::Balance,
 	) -> Self::Balance {
 		if currency_id == T::NativeAssetId::get() {
@@ -167,12 +170,12 @@ where
 	}
 }
 
-impl<T: Config, AccountId> MultiLockableCurrency<AccountId> for Pallet<T>
+impl<T: Config> MultiLockableCurrency<T::AccountId> for Pallet<T>
 where
-	<T as Config>::NativeCurrency: LockableCurrency<AccountId, Balance = T::Balance>
-		+ Currency<AccountId, Balance = T::Balance>,
+	<T as Config>::NativeCurrency: LockableCurrency<T::AccountId, Balance = T::Balance>
+		+ Currency<T::AccountId, Balance = T::Balance>,
 	<T as Config>::MultiCurrency:
-		MultiLockableCurrency<AccountId, Balance = T::Balance, CurrencyId = T::AssetId>,
+		MultiLockableCurrency<T::AccountId, Balance = T::Balance, CurrencyId = T::AssetId>,
 	<T as Config>::Balance: Saturating + num_traits::Saturating,
 	<T as Config>::AssetId: MaybeSerializeDeserialize,
 {
@@ -181,7 +184,7 @@ where
 	fn set_lock(
 		lock_id: orml_traits::LockIdentifier,
 		currency_id: Self::CurrencyId,
-		who: &AccountId,
+		who: &T::AccountId,
 		amount: Self::Balance,
 	) -> DispatchResult {
 		if currency_id == T::NativeAssetId::get() {
@@ -195,7 +198,7 @@ where
 	fn extend_lock(
 		lock_id: orml_traits::LockIdentifier,
 		currency_id: Self::CurrencyId,
-		who: &AccountId,
+		who: &T::AccountId,
 		amount: Self::Balance,
 	) -> DispatchResult {
 		if currency_id == T::NativeAssetId::get() {
@@ -214,7 +217,7 @@ where
 	fn remove_lock(
 		lock_id: orml_traits::LockIdentifier,
 		currency_id: Self::CurrencyId,
-		who: &AccountId,
+		who: &T::AccountId,
 	) -> DispatchResult {
 		if currency_id == T::NativeAssetId::get() {
 			<<T as Config>::NativeCurrency>::remove_lock(lock_id, who);
@@ -225,16 +228,20 @@ where
 	}
 }
 
-impl<T: Config, AccountId> MultiReservableCurrency<AccountId> for Pallet<T>
+impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T>
 where
-	<T as Config>::NativeCurrency: ReservableCurrency<AccountId, Balance = T::Balance>
-		+ Currency<AccountId, Balance = T::Balance>,
+	<T as Config>::NativeCurrency: ReservableCurrency<T::AccountId, Balance = T::Balance>
+		+ Currency<T::AccountId, Balance = T::Balance>,
 	<T as Config>::MultiCurrency:
-		MultiReservableCurrency<AccountId, Balance = T::Balance, CurrencyId = T::AssetId>,
+		MultiReservableCurrency<T::AccountId, Balance = T::Balance, CurrencyId = T::AssetId>,
 	<T as Config>::Balance: Saturating + num_traits::Saturating,
 	<T as Config>::AssetId: MaybeSerializeDeserialize,
 {
-	fn can_reserve(currency_id: Self::CurrencyId, who: &AccountId, amount: Self::Balance) -> bool {
+	fn can_reserve(
+		currency_id: Self::CurrencyId,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> bool {
 		if currency_id == T::NativeAssetId::get() {
 			return <<T as Config>::NativeCurrency>::can_reserve(who, amount)
 		}
@@ -246,7 +253,7 @@ where
 
 	fn slash_reserved(
 		currency_id: Self::CurrencyId,
-		who: &AccountId,
+		who: &T::AccountId,
 		amount: Self::Balance,
 	) -> Self::Balance {
 		if currency_id == T::NativeAssetId::get() {
@@ -260,7 +267,7 @@ where
 		T::Balance::zero()
 	}
 
-	fn reserved_balance(currency_id: Self::CurrencyId, who: &AccountId) -> Self::Balance {
+	fn reserved_balance(currency_id: Self::CurrencyId, who: &T::AccountId) -> Self::Balance {
 		if currency_id == T::NativeAssetId::get() {
 			return <<T as Config>::NativeCurrency>::reserved_balance(who)
 		}
@@ -272,7 +279,7 @@ where
 
 	fn reserve(
 		currency_id: Self::CurrencyId,
-		who: &AccountId,
+		who: &T::AccountId,
 		amount: Self::Balance,
 	) -> sp_runtime::DispatchResult {
 		if currency_id == T::NativeAssetId::get() {
@@ -284,7 +291,7 @@ where
 
 	fn unreserve(
 		currency_id: Self::CurrencyId,
-		who: &AccountId,
+		who: &T::AccountId,
 		amount: Self::Balance,
 	) -> Self::Balance {
 		if currency_id == T::NativeAssetId::get() {
@@ -298,8 +305,8 @@ where
 
 	fn repatriate_reserved(
 		currency_id: Self::CurrencyId,
-		slashed: &AccountId,
-		beneficiary: &AccountId,
+		slashed: &T::AccountId,
+		beneficiary: &T::AccountId,
 		amount: Self::Balance,
 		status: orml_traits::BalanceStatus,
 	) -> core::result::Result<Self::Balance, DispatchError> {
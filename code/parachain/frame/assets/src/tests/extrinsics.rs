@@ -1,4 +1,12 @@
 use crate::*;
+use frame_support::{
+	traits::{
+		fungible, fungibles,
+		tokens::{Fortitude, Precision, Preservation},
+		ConstU32, ReservableCurrency,
+	},
+	BoundedVec,
+};
 use mocks::{new_test_ext, RuntimeOrigin, Test};
 use orml_traits::MultiCurrency;
 
@@ -227,3 +235,540 @@ fn test_burn_from() {
 		);
 	});
 }
+
+fn freeze_reason() -> FreezeReason {
+	b"incident-42".to_vec().try_into().expect("reason within bounds")
+}
+
+#[test]
+fn test_freeze_account_blocks_outgoing_transfers() {
+	new_test_ext().execute_with(|| {
+		frame_system::Pallet::<Test>::set_block_number(1);
+		let target = FreezeTarget::Account(FROM_ACCOUNT);
+		Pallet::<Test>::freeze(RuntimeOrigin::root(), target.clone(), freeze_reason())
+			.expect("freeze should work");
+		frame_system::Pallet::<Test>::assert_last_event(
+			Event::<Test>::Frozen { target: target.clone(), reason: freeze_reason() }.into(),
+		);
+		assert_eq!(
+			Pallet::<Test>::freeze(RuntimeOrigin::root(), target.clone(), freeze_reason()),
+			Err(Error::<Test>::AlreadyFrozen.into())
+		);
+
+		assert_eq!(
+			Pallet::<Test>::transfer_native(
+				RuntimeOrigin::signed(FROM_ACCOUNT),
+				TO_ACCOUNT,
+				TRANSFER_AMOUNT,
+				true,
+			)
+			.map_err(|e| e.error),
+			Err(Error::<Test>::Frozen.into())
+		);
+		assert_eq!(
+			<Pallet<Test> as MultiCurrency<u64>>::transfer(
+				ASSET_ID,
+				&FROM_ACCOUNT,
+				&TO_ACCOUNT,
+				TRANSFER_AMOUNT
+			),
+			Err(Error::<Test>::Frozen.into())
+		);
+		// the native lock blocks transfers bypassing this pallet
+		assert!(mocks::Balances::transfer(
+			RuntimeOrigin::signed(FROM_ACCOUNT),
+			TO_ACCOUNT,
+			TRANSFER_AMOUNT
+		)
+		.is_err());
+		// incoming transfers are still allowed
+		Pallet::<Test>::transfer_native(
+			RuntimeOrigin::signed(TO_ACCOUNT),
+			FROM_ACCOUNT,
+			TRANSFER_AMOUNT,
+			true,
+		)
+		.expect("transfer to a frozen account should work");
+
+		Pallet::<Test>::force_transfer_native(
+			RuntimeOrigin::root(),
+			FROM_ACCOUNT,
+			TO_ACCOUNT,
+			TRANSFER_AMOUNT,
+			true,
+		)
+		.expect("force_transfer_native should ignore freezes");
+		assert_eq!(Pallet::<Test>::total_balance(ASSET_ID, &FROM_ACCOUNT), INIT_AMOUNT);
+		assert!(Pallet::<Test>::is_frozen(ASSET_ID, &FROM_ACCOUNT));
+
+		Pallet::<Test>::thaw(RuntimeOrigin::root(), target.clone()).expect("thaw should work");
+		frame_system::Pallet::<Test>::assert_last_event(Event::<Test>::Thawed { target }.into());
+		mocks::Balances::transfer(RuntimeOrigin::signed(FROM_ACCOUNT), TO_ACCOUNT, TRANSFER_AMOUNT)
+			.expect("thawed account can transfer");
+	});
+}
+
+#[test]
+fn test_freeze_asset() {
+	mocks::new_test_ext_multi_currency().execute_with(|| {
+		assert_eq!(
+			Pallet::<Test>::freeze(
+				RuntimeOrigin::root(),
+				FreezeTarget::Asset(ASSET_ID),
+				freeze_reason()
+			),
+			Err(Error::<Test>::CannotFreezeNativeAsset.into())
+		);
+		assert_eq!(
+			Pallet::<Test>::freeze(
+				RuntimeOrigin::signed(mocks::BOB),
+				FreezeTarget::Asset(mocks::ASSET_2),
+				freeze_reason()
+			),
+			Err(sp_runtime::DispatchError::BadOrigin)
+		);
+		Pallet::<Test>::freeze(
+			RuntimeOrigin::root(),
+			FreezeTarget::Asset(mocks::ASSET_2),
+			freeze_reason(),
+		)
+		.expect("freeze should work");
+
+		assert_eq!(
+			Pallet::<Test>::transfer(
+				RuntimeOrigin::signed(mocks::BOB),
+				mocks::ASSET_2,
+				mocks::ALICE,
+				TRANSFER_AMOUNT,
+				true,
+			)
+			.map_err(|e| e.error),
+			Err(Error::<Test>::Frozen.into())
+		);
+		assert_eq!(
+			<Pallet<Test> as MultiCurrency<u64>>::withdraw(
+				mocks::ASSET_2,
+				&mocks::BOB,
+				TRANSFER_AMOUNT
+			),
+			Err(Error::<Test>::Frozen.into())
+		);
+		// fees are withdrawn through `Balanced`
+		assert_eq!(
+			<Pallet<Test> as fungibles::Balanced<u64>>::withdraw(
+				mocks::ASSET_2,
+				&mocks::BOB,
+				TRANSFER_AMOUNT,
+				Precision::Exact,
+				Preservation::Expendable,
+				Fortitude::Polite,
+			)
+			.map(|_| ()),
+			Err(Error::<Test>::Frozen.into())
+		);
+
+		Pallet::<Test>::force_transfer(
+			RuntimeOrigin::root(),
+			mocks::ASSET_2,
+			mocks::BOB,
+			mocks::ALICE,
+			TRANSFER_AMOUNT,
+			true,
+		)
+		.expect("force_transfer should ignore freezes");
+		assert_eq!(Pallet::<Test>::total_balance(mocks::ASSET_2, &mocks::ALICE), TRANSFER_AMOUNT);
+
+		Pallet::<Test>::thaw(RuntimeOrigin::root(), FreezeTarget::Asset(mocks::ASSET_2))
+			.expect("thaw should work");
+		assert_eq!(
+			Pallet::<Test>::thaw(RuntimeOrigin::root(), FreezeTarget::Asset(mocks::ASSET_2)),
+			Err(Error::<Test>::NotFrozen.into())
+		);
+		Pallet::<Test>::transfer(
+			RuntimeOrigin::signed(mocks::BOB),
+			mocks::ASSET_2,
+			mocks::ALICE,
+			TRANSFER_AMOUNT / 5,
+			true,
+		)
+		.expect("thawed asset can be transferred");
+	});
+}
+
+#[test]
+fn test_freeze_account_asset() {
+	mocks::new_test_ext_multi_currency().execute_with(|| {
+		Pallet::<Test>::freeze(
+			RuntimeOrigin::root(),
+			FreezeTarget::AccountAsset(mocks::BOB, mocks::ASSET_2),
+			freeze_reason(),
+		)
+		.expect("freeze should work");
+		assert!(Pallet::<Test>::is_frozen(mocks::ASSET_2, &mocks::BOB));
+		assert!(!Pallet::<Test>::is_frozen(mocks::ASSET_2, &mocks::ALICE));
+		assert!(!Pallet::<Test>::is_frozen(ASSET_ID, &mocks::BOB));
+		assert_eq!(
+			<Pallet<Test> as fungibles::Mutate<u64>>::transfer(
+				mocks::ASSET_2,
+				&mocks::BOB,
+				&mocks::ALICE,
+				TRANSFER_AMOUNT,
+				Preservation::Expendable,
+			),
+			Err(Error::<Test>::Frozen.into())
+		);
+	});
+}
+
+#[test]
+fn test_reason_coded_holds() {
+	use frame_support::traits::fungibles::{InspectHold, MutateHold};
+
+	const STAKING: [u8; 8] = *b"staking ";
+	const LENDING: [u8; 8] = *b"lending ";
+
+	mocks::new_test_ext_multi_currency().execute_with(|| {
+		let (asset, who) = (mocks::ASSET_2, mocks::BOB);
+		<Pallet<Test> as MutateHold<u64>>::hold(asset, &STAKING, &who, 100)
+			.expect("hold should work");
+		<Pallet<Test> as MutateHold<u64>>::hold(asset, &LENDING, &who, 50)
+			.expect("hold should work");
+		frame_system::Pallet::<Test>::assert_last_event(
+			Event::<Test>::Held { asset_id: asset, who, reason: LENDING, amount: 50 }.into(),
+		);
+
+		assert_eq!(Pallet::<Test>::balance_on_hold(asset, &STAKING, &who), 100);
+		assert_eq!(Pallet::<Test>::balance_on_hold(asset, &LENDING, &who), 50);
+		assert_eq!(Pallet::<Test>::total_balance_on_hold(asset, &who), 150);
+		assert_eq!(
+			<Pallet<Test> as fungibles::Inspect<u64>>::balance(asset, &who),
+			INIT_AMOUNT - 150
+		);
+
+		assert!(<Pallet<Test> as MutateHold<u64>>::release(
+			asset,
+			&LENDING,
+			&who,
+			100,
+			Precision::Exact
+		)
+		.is_err());
+		assert_eq!(
+			<Pallet<Test> as MutateHold<u64>>::release(
+				asset,
+				&LENDING,
+				&who,
+				100,
+				Precision::BestEffort
+			),
+			Ok(50)
+		);
+		frame_system::Pallet::<Test>::assert_last_event(
+			Event::<Test>::Released { asset_id: asset, who, reason: LENDING, amount: 50 }.into(),
+		);
+		assert_eq!(Pallet::<Test>::balance_on_hold(asset, &LENDING, &who), 0);
+		assert_eq!(Pallet::<Test>::balance_on_hold(asset, &STAKING, &who), 100);
+		assert_eq!(Pallet::<Test>::total_balance_on_hold(asset, &who), 100);
+	});
+}
+
+#[test]
+fn test_transfer_many() {
+	use frame_support::dispatch::Dispatchable;
+
+	mocks::new_test_ext_multi_currency().execute_with(|| {
+		<mocks::Balances as frame_support::traits::Currency<u64>>::make_free_balance_be(
+			&mocks::BOB,
+			INIT_AMOUNT,
+		);
+		let transfers = vec![
+			(mocks::ASSET_2, mocks::ALICE, 100),
+			(mocks::ASSET_2, mocks::CHARLIE, 200),
+			(ASSET_ID, mocks::CHARLIE, 300),
+		];
+		Pallet::<Test>::transfer_many(
+			RuntimeOrigin::signed(mocks::BOB),
+			transfers.try_into().expect("within bounds"),
+			true,
+		)
+		.expect("transfer_many should work");
+		assert_eq!(Pallet::<Test>::total_balance(mocks::ASSET_2, &mocks::BOB), INIT_AMOUNT - 300);
+		assert_eq!(Pallet::<Test>::total_balance(mocks::ASSET_2, &mocks::ALICE), 100);
+		assert_eq!(Pallet::<Test>::total_balance(mocks::ASSET_2, &mocks::CHARLIE), 200);
+		assert_eq!(Pallet::<Test>::total_balance(ASSET_ID, &mocks::CHARLIE), 300);
+
+		// all or nothing
+		let transfers =
+			vec![(mocks::ASSET_2, mocks::ALICE, 100), (mocks::ASSET_2, mocks::ALICE, 1000)];
+		let call = mocks::RuntimeCall::Assets(Call::transfer_many {
+			transfers: transfers.try_into().expect("within bounds"),
+			keep_alive: true,
+		});
+		assert!(call.dispatch(RuntimeOrigin::signed(mocks::BOB)).is_err());
+		assert_eq!(Pallet::<Test>::total_balance(mocks::ASSET_2, &mocks::ALICE), 100);
+	});
+}
+
+fn fund_recurring_payment_deposits(payer: u64) {
+	<mocks::Balances as fungible::Mutate<u64>>::mint_into(&payer, 100)
+		.expect("minting native should work");
+}
+
+fn recurring_payment_deposit_of(payer: u64) -> u64 {
+	<mocks::Balances as ReservableCurrency<u64>>::reserved_balance(&payer)
+}
+
+#[test]
+fn test_recurring_payment() {
+	use frame_support::traits::Hooks;
+
+	mocks::new_test_ext_multi_currency().execute_with(|| {
+		let (asset, payer, payee) = (mocks::ASSET_2, mocks::BOB, mocks::CHARLIE);
+		fund_recurring_payment_deposits(payer);
+		assert_eq!(
+			Pallet::<Test>::schedule_recurring_payment(
+				RuntimeOrigin::signed(payer),
+				payee,
+				asset,
+				100,
+				1,
+				None
+			),
+			Err(Error::<Test>::IntervalTooShort.into())
+		);
+		assert_eq!(
+			Pallet::<Test>::schedule_recurring_payment(
+				RuntimeOrigin::signed(payer),
+				payee,
+				asset,
+				0,
+				10,
+				None
+			),
+			Err(Error::<Test>::PaymentAmountTooLow.into())
+		);
+		assert_eq!(
+			Pallet::<Test>::schedule_recurring_payment(
+				RuntimeOrigin::signed(payer),
+				payee,
+				asset,
+				100,
+				10,
+				Some(5)
+			),
+			Err(Error::<Test>::PaymentEndsBeforeStart.into())
+		);
+		Pallet::<Test>::schedule_recurring_payment(
+			RuntimeOrigin::signed(payer),
+			payee,
+			asset,
+			100,
+			10,
+			Some(31),
+		)
+		.expect("schedule_recurring_payment should work");
+		let payment_id = 0;
+		assert!(Pallet::<Test>::recurring_payment(payment_id).is_some());
+		assert_eq!(recurring_payment_deposit_of(payer), mocks::RECURRING_PAYMENT_DEPOSIT);
+
+		for block in 2..=40 {
+			frame_system::Pallet::<Test>::set_block_number(block);
+			Pallet::<Test>::on_initialize(block);
+			let payments = match block {
+				0..=10 => 0,
+				11..=20 => 1,
+				21..=30 => 2,
+				_ => 3,
+			};
+			assert_eq!(Pallet::<Test>::total_balance(asset, &payee), 100 * payments);
+		}
+		frame_system::Pallet::<Test>::assert_has_event(
+			Event::<Test>::RecurringPaymentEnded {
+				payment_id,
+				reason: RecurringPaymentEnd::Completed,
+			}
+			.into(),
+		);
+		assert!(Pallet::<Test>::recurring_payment(payment_id).is_none());
+		assert_eq!(recurring_payment_deposit_of(payer), 0);
+	});
+}
+
+#[test]
+fn test_recurring_payment_limits() {
+	mocks::new_test_ext_multi_currency().execute_with(|| {
+		let (asset, payer, payee) = (mocks::ASSET_2, mocks::BOB, mocks::CHARLIE);
+		// the deposit cannot be held
+		assert!(Pallet::<Test>::schedule_recurring_payment(
+			RuntimeOrigin::signed(payer),
+			payee,
+			asset,
+			100,
+			10,
+			None,
+		)
+		.is_err());
+
+		fund_recurring_payment_deposits(payer);
+		for _ in 0..2 {
+			Pallet::<Test>::schedule_recurring_payment(
+				RuntimeOrigin::signed(payer),
+				payee,
+				asset,
+				100,
+				10,
+				None,
+			)
+			.expect("schedule_recurring_payment should work");
+		}
+		assert_eq!(recurring_payment_deposit_of(payer), 2 * mocks::RECURRING_PAYMENT_DEPOSIT);
+		assert_eq!(
+			Pallet::<Test>::schedule_recurring_payment(
+				RuntimeOrigin::signed(payer),
+				payee,
+				asset,
+				100,
+				10,
+				None
+			),
+			Err(Error::<Test>::TooManyPayerRecurringPayments.into())
+		);
+
+		Pallet::<Test>::cancel_recurring_payment(RuntimeOrigin::signed(payer), 0)
+			.expect("cancel_recurring_payment should work");
+		Pallet::<Test>::schedule_recurring_payment(
+			RuntimeOrigin::signed(payer),
+			payee,
+			asset,
+			100,
+			10,
+			None,
+		)
+		.expect("cancelled payments do not count");
+	});
+}
+
+#[test]
+fn test_recurring_payment_ends_after_failures() {
+	use frame_support::traits::Hooks;
+
+	mocks::new_test_ext_multi_currency().execute_with(|| {
+		let (asset, payer, payee) = (mocks::ASSET_2, mocks::BOB, mocks::CHARLIE);
+		fund_recurring_payment_deposits(payer);
+		Pallet::<Test>::schedule_recurring_payment(
+			RuntimeOrigin::signed(payer),
+			payee,
+			asset,
+			600,
+			2,
+			None,
+		)
+		.expect("schedule_recurring_payment should work");
+		let payment_id = 0;
+
+		// paid, then a failure followed by a success does not end it
+		for block in [3, 5, 7] {
+			frame_system::Pallet::<Test>::set_block_number(block);
+			Pallet::<Test>::on_initialize(block);
+			if block == 5 {
+				<mocks::Tokens as fungibles::Mutate<u64>>::mint_into(asset, &payer, 600)
+					.expect("minting should work");
+			}
+		}
+		assert_eq!(Pallet::<Test>::total_balance(asset, &payee), 1200);
+		assert_eq!(
+			Pallet::<Test>::recurring_payment(payment_id).map(|payment| payment.failures),
+			Some(0)
+		);
+
+		for block in [9, 11] {
+			frame_system::Pallet::<Test>::set_block_number(block);
+			Pallet::<Test>::on_initialize(block);
+		}
+		assert_eq!(Pallet::<Test>::total_balance(asset, &payee), 1200);
+		frame_system::Pallet::<Test>::assert_last_event(
+			Event::<Test>::RecurringPaymentEnded {
+				payment_id,
+				reason: RecurringPaymentEnd::TooManyFailures,
+			}
+			.into(),
+		);
+		assert!(Pallet::<Test>::recurring_payment(payment_id).is_none());
+		assert_eq!(recurring_payment_deposit_of(payer), 0);
+	});
+}
+
+#[test]
+fn test_recurring_payment_pushed_back_when_block_is_full() {
+	use frame_support::traits::Hooks;
+
+	mocks::new_test_ext_multi_currency().execute_with(|| {
+		let (asset, payer, payee) = (mocks::ASSET_2, mocks::BOB, mocks::CHARLIE);
+		fund_recurring_payment_deposits(payer);
+		// payments which do not exist fill the blocks
+		let payment_ids = (100..100 + MAX_RECURRING_PAYMENTS_PER_BLOCK as u64).collect::<Vec<_>>();
+		let full: BoundedVec<_, ConstU32<MAX_RECURRING_PAYMENTS_PER_BLOCK>> =
+			payment_ids.try_into().expect("within bounds");
+		RecurringPaymentsDue::<Test>::insert(3, full.clone());
+		RecurringPaymentsDue::<Test>::insert(6, full);
+		Pallet::<Test>::schedule_recurring_payment(
+			RuntimeOrigin::signed(payer),
+			payee,
+			asset,
+			100,
+			2,
+			None,
+		)
+		.expect("schedule_recurring_payment should work");
+		assert_eq!(
+			Pallet::<Test>::recurring_payment(0).map(|payment| payment.next_payment),
+			Some(4)
+		);
+
+		frame_system::Pallet::<Test>::set_block_number(4);
+		Pallet::<Test>::on_initialize(4);
+		assert_eq!(Pallet::<Test>::total_balance(asset, &payee), 100);
+		assert_eq!(
+			Pallet::<Test>::recurring_payment(0).map(|payment| payment.next_payment),
+			Some(7)
+		);
+	});
+}
+
+#[test]
+fn test_cancel_recurring_payment() {
+	use frame_support::traits::Hooks;
+
+	mocks::new_test_ext_multi_currency().execute_with(|| {
+		let (asset, payer, payee) = (mocks::ASSET_2, mocks::BOB, mocks::CHARLIE);
+		fund_recurring_payment_deposits(payer);
+		Pallet::<Test>::schedule_recurring_payment(
+			RuntimeOrigin::signed(payer),
+			payee,
+			asset,
+			100,
+			2,
+			None,
+		)
+		.expect("schedule_recurring_payment should work");
+		frame_system::Pallet::<Test>::set_block_number(3);
+		Pallet::<Test>::on_initialize(3);
+		assert_eq!(Pallet::<Test>::total_balance(asset, &payee), 100);
+
+		assert_eq!(
+			Pallet::<Test>::cancel_recurring_payment(RuntimeOrigin::signed(payee), 0),
+			Err(Error::<Test>::NotPaymentPayer.into())
+		);
+		Pallet::<Test>::cancel_recurring_payment(RuntimeOrigin::signed(payer), 0)
+			.expect("cancel_recurring_payment should work");
+		assert!(RecurringPaymentsDue::<Test>::get(5).is_none());
+		assert_eq!(recurring_payment_deposit_of(payer), 0);
+		frame_system::Pallet::<Test>::set_block_number(5);
+		Pallet::<Test>::on_initialize(5);
+		assert_eq!(Pallet::<Test>::total_balance(asset, &payee), 100);
+		assert_eq!(
+			Pallet::<Test>::cancel_recurring_payment(RuntimeOrigin::signed(payer), 0),
+			Err(Error::<Test>::RecurringPaymentNotFound.into())
+		);
+	});
+}
@@ -7,6 +7,8 @@ use frame_support::{
 };
 use sp_std::marker::PhantomData;
 
+use crate::MAX_RECURRING_PAYMENT_DELAY;
+
 pub trait WeightInfo {
 	fn transfer() -> Weight;
 	fn transfer_native() -> Weight;
@@ -20,6 +22,12 @@ pub trait WeightInfo {
 	fn set_administrator() -> Weight;
 	fn mint_into() -> Weight;
 	fn burn_from() -> Weight;
+	fn freeze() -> Weight;
+	fn thaw() -> Weight;
+	fn transfer_many(n: u32) -> Weight;
+	fn schedule_recurring_payment() -> Weight;
+	fn cancel_recurring_payment() -> Weight;
+	fn execute_recurring_payment() -> Weight;
 }
 
 pub struct SubstrateWeight<T>(PhantomData<T>);
@@ -94,6 +102,61 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+
+	fn freeze() -> Weight {
+		Weight::from_parts(45_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	fn thaw() -> Weight {
+		Weight::from_parts(42_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	fn transfer_many(n: u32) -> Weight {
+		Weight::from_parts(20_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(80_000_000_u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads((7_u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n as u64)))
+	}
+
+	// Storage: Assets RecurringPayments (r:0 w:1), CounterForRecurringPayments (r:1 w:1)
+	// Storage: Assets PayerRecurringPayments (r:1 w:1), RecurringPaymentCount (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Assets RecurringPaymentsDue (r:MAX_RECURRING_PAYMENT_DELAY w:1)
+	fn schedule_recurring_payment() -> Weight {
+		Weight::from_parts(65_000_000_u64, 0)
+			.saturating_add(
+				Weight::from_parts(4_000_000_u64, 0)
+					.saturating_mul(MAX_RECURRING_PAYMENT_DELAY as u64),
+			)
+			.saturating_add(RocksDbWeight::get().reads(4_u64 + MAX_RECURRING_PAYMENT_DELAY as u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+
+	// Storage: Assets RecurringPayments (r:1 w:1), CounterForRecurringPayments (r:1 w:1)
+	// Storage: Assets PayerRecurringPayments (r:1 w:1), RecurringPaymentsDue (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn cancel_recurring_payment() -> Weight {
+		Weight::from_parts(45_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	// Storage: Assets RecurringPayments (r:1 w:1), Freezes (r:3 w:0)
+	// Storage: Tokens Accounts (r:2 w:2), TotalIssuance (r:1 w:0), System Account (r:2 w:1)
+	// Storage: Assets RecurringPaymentsDue (r:MAX_RECURRING_PAYMENT_DELAY w:1)
+	fn execute_recurring_payment() -> Weight {
+		Weight::from_parts(110_000_000_u64, 0)
+			.saturating_add(
+				Weight::from_parts(4_000_000_u64, 0)
+					.saturating_mul(MAX_RECURRING_PAYMENT_DELAY as u64),
+			)
+			.saturating_add(RocksDbWeight::get().reads(9_u64 + MAX_RECURRING_PAYMENT_DELAY as u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -166,4 +229,59 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+
+	fn freeze() -> Weight {
+		Weight::from_parts(45_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	fn thaw() -> Weight {
+		Weight::from_parts(42_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	fn transfer_many(n: u32) -> Weight {
+		Weight::from_parts(20_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(80_000_000_u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads((7_u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n as u64)))
+	}
+
+	// Storage: Assets RecurringPayments (r:0 w:1), CounterForRecurringPayments (r:1 w:1)
+	// Storage: Assets PayerRecurringPayments (r:1 w:1), RecurringPaymentCount (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Assets RecurringPaymentsDue (r:MAX_RECURRING_PAYMENT_DELAY w:1)
+	fn schedule_recurring_payment() -> Weight {
+		Weight::from_parts(65_000_000_u64, 0)
+			.saturating_add(
+				Weight::from_parts(4_000_000_u64, 0)
+					.saturating_mul(MAX_RECURRING_PAYMENT_DELAY as u64),
+			)
+			.saturating_add(RocksDbWeight::get().reads(4_u64 + MAX_RECURRING_PAYMENT_DELAY as u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+
+	// Storage: Assets RecurringPayments (r:1 w:1), CounterForRecurringPayments (r:1 w:1)
+	// Storage: Assets PayerRecurringPayments (r:1 w:1), RecurringPaymentsDue (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn cancel_recurring_payment() -> Weight {
+		Weight::from_parts(45_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	// Storage: Assets RecurringPayments (r:1 w:1), Freezes (r:3 w:0)
+	// Storage: Tokens Accounts (r:2 w:2), TotalIssuance (r:1 w:0), System Account (r:2 w:1)
+	// Storage: Assets RecurringPaymentsDue (r:MAX_RECURRING_PAYMENT_DELAY w:1)
+	fn execute_recurring_payment() -> Weight {
+		Weight::from_parts(110_000_000_u64, 0)
+			.saturating_add(
+				Weight::from_parts(4_000_000_u64, 0)
+					.saturating_mul(MAX_RECURRING_PAYMENT_DELAY as u64),
+			)
+			.saturating_add(RocksDbWeight::get().reads(9_u64 + MAX_RECURRING_PAYMENT_DELAY as u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 }
@@ -0,0 +1,76 @@
+use super::*;
+use crate::Pallet as BondedFinance;
+use composable_support::validation::Validated;
+use composable_traits::bonded_finance::{BondDuration, BondOffer, BondOfferReward};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{
+	assert_ok,
+	traits::{fungible::Mutate as FungibleMutate, fungibles::Mutate},
+};
+use frame_system::RawOrigin;
+
+const UNIT: u128 = 1_000_000_000_000;
+const BOND_ASSET: u128 = 100;
+const REWARD_ASSET: u128 = 101;
+
+fn bond_offer<T: Config>() -> BondOfferOf<T>
+where
+	T::BlockNumber: From<u32>,
+	T::Balance: From<u128>,
+	T::AssetId: From<u128>,
+{
+	BondOffer {
+		beneficiary: account("beneficiary", 0, 0),
+		asset: BOND_ASSET.into(),
+		bond_price: UNIT.into(),
+		nb_of_bonds: 1_000.into(),
+		maturity: BondDuration::Finite { return_in: 100.into() },
+		reward: BondOfferReward {
+			asset: REWARD_ASSET.into(),
+			amount: (1_000_000 * UNIT).into(),
+			maturity: 100.into(),
+		},
+	}
+}
+
+fn fund_creator<T: Config>(creator: &T::AccountId)
+where
+	T::Balance: From<u128>,
+	T::AssetId: From<u128>,
+{
+	assert_ok!(T::NativeCurrency::mint_into(creator, (1_000_000 * UNIT).into()));
+	assert_ok!(T::Currency::mint_into(REWARD_ASSET.into(), creator, (1_000_000 * UNIT).into()));
+}
+
+fn create_offer<T: Config>(creator: T::AccountId) -> T::BondOfferId
+where
+	T::BlockNumber: From<u32>,
+	T::Balance: From<u128>,
+	T::AssetId: From<u128>,
+{
+	fund_creator::<T>(&creator);
+	let offer = Validated::new(bond_offer::<T>()).expect("valid offer; qed;");
+	assert_ok!(BondedFinance::<T>::offer(RawOrigin::Signed(creator).into(), offer, false));
+	BondOfferCount::<T>::get()
+}
+
+benchmarks! {
+  where_clause { where T::BlockNumber: From<u32>, T::Balance: From<u128>, T::AssetId: From<u128> }
+	offer {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_creator::<T>(&caller);
+		let offer = Validated::new(bond_offer::<T>()).expect("valid offer; qed;");
+	}: _(RawOrigin::Signed(caller), offer, false)
+
+	bond {
+		let offer_id = create_offer::<T>(account("creator", 0, 0));
+		let caller: T::AccountId = whitelisted_caller();
+		assert_ok!(T::Currency::mint_into(BOND_ASSET.into(), &caller, (1_000 * UNIT).into()));
+	}: _(RawOrigin::Signed(caller), offer_id, 1_000.into(), false)
+
+	cancel {
+		let offer_id = create_offer::<T>(account("creator", 0, 0));
+	}: _(RawOrigin::Root, offer_id)
+}
+
+impl_benchmark_test_suite!(BondedFinance, crate::mock::new_test_ext(), crate::mock::Test);
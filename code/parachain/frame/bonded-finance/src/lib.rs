@@ -0,0 +1,439 @@
+//! # Bonded Finance Pallet
+//!
+//! Sells bonds: an offer trades an asset, for example pablo LP tokens, for a reward released
+//! through the vesting pallet, typically at a discount. It implements [`BondedFinance`].
+//!
+//! ## Overview
+//!
+//! - The creator of an offer locks its whole reward and a native `Stake`, returned once every bond
+//!   is sold or the offer is cancelled.
+//! - Anyone can `bond` a number of bonds, paying `bond_price` of the offer asset for each. With a
+//!   [`BondDuration::Infinite`] maturity, the asset is transferred to the beneficiary of the offer,
+//!   which owns the liquidity from then on. With [`BondDuration::Finite`], it stays locked in the
+//!   account of the bonder until `return_in` blocks passed.
+//! - The share of the reward bought is vested to the bonder, claimable after `reward.maturity`
+//!   blocks.
+//! - The creator of an offer or the `AdminOrigin` can `cancel` it, returning the reward left.
+//!
+//! ## Interface
+//!
+//! - `offer`: create a bond offer.
+//! - `bond`: buy bonds of an offer.
+//! - `cancel`: cancel an offer.
+#![cfg_attr(
+	not(test),
+	warn(
+		clippy::disallowed_methods,
+		clippy::disallowed_types,
+		clippy::todo,
+		clippy::unwrap_used,
+		clippy::panic
+	)
+)]
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(
+	bad_style,
+	bare_trait_objects,
+	improper_ctypes,
+	non_shorthand_field_patterns,
+	no_mangle_generic_items,
+	overflowing_literals,
+	path_statements,
+	patterns_in_fns_without_body,
+	private_in_public,
+	unconditional_recursion,
+	unused_allocation,
+	unused_comparisons,
+	unused_parens,
+	while_true,
+	trivial_casts,
+	trivial_numeric_casts,
+	unused_extern_crates
+)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarking;
+
+pub use crate::weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::WeightInfo;
+	use codec::FullCodec;
+	use composable_support::{
+		math::safe::{safe_multiply_by_rational, SafeAdd, SafeArithmetic, SafeMul, SafeSub},
+		validation::Validated,
+	};
+	use composable_traits::{
+		bonded_finance::{BondDuration, BondOffer, BondedFinance, ValidBondOffer},
+		currency::{BalanceLike, MathBalance},
+	};
+	use core::fmt::Debug;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			fungible::Mutate as NativeMutate,
+			fungibles::{Inspect, Mutate},
+			tokens::Preservation,
+		},
+		transactional, PalletId,
+	};
+	use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+	use pallet_vesting::{VestedTransfer, VestingScheduleInfo, VestingWindow};
+	use sp_runtime::traits::{AccountIdConversion, One, SaturatedConversion, Zero};
+
+	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+	pub type AssetIdOf<T> = <T as Config>::AssetId;
+	pub type BalanceOf<T> = <T as Config>::Balance;
+	pub type BlockNumberOf<T> = <T as frame_system::Config>::BlockNumber;
+	pub type BondOfferOf<T> =
+		BondOffer<AccountIdOf<T>, AssetIdOf<T>, BalanceOf<T>, BlockNumberOf<T>>;
+	pub type MinVestedTransferOf<T> = <<T as Config>::Vesting as VestedTransfer>::MinVestedTransfer;
+	pub type ValidBondOfferOf<T> = ValidBondOffer<MinVestedTransferOf<T>, <T as Config>::MinReward>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		#[allow(missing_docs)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Type representing the unique ID of an asset.
+		type AssetId: FullCodec
+			+ MaxEncodedLen
+			+ Eq
+			+ PartialEq
+			+ Copy
+			+ Clone
+			+ MaybeSerializeDeserialize
+			+ Debug
+			+ Default
+			+ TypeInfo
+			+ Ord;
+
+		/// Type representing the Balance of an account.
+		type Balance: BalanceLike + MathBalance;
+
+		/// Type representing the unique ID of a bond offer.
+		type BondOfferId: FullCodec
+			+ MaxEncodedLen
+			+ Default
+			+ Debug
+			+ TypeInfo
+			+ Eq
+			+ PartialEq
+			+ Ord
+			+ Copy
+			+ Zero
+			+ One
+			+ SafeArithmetic;
+
+		/// Currency of the offer `Stake`.
+		type NativeCurrency: NativeMutate<AccountIdOf<Self>, Balance = BalanceOf<Self>>;
+
+		/// Bonded and reward assets.
+		type Currency: Mutate<AccountIdOf<Self>, Balance = BalanceOf<Self>, AssetId = AssetIdOf<Self>>
+			+ Inspect<AccountIdOf<Self>, Balance = BalanceOf<Self>, AssetId = AssetIdOf<Self>>;
+
+		/// Releases rewards and locks bonded assets of finite offers.
+		type Vesting: VestedTransfer<
+			AccountId = AccountIdOf<Self>,
+			AssetId = AssetIdOf<Self>,
+			BlockNumber = BlockNumberOf<Self>,
+			Balance = BalanceOf<Self>,
+		>;
+
+		/// Minimum total reward of an offer.
+		#[pallet::constant]
+		type MinReward: Get<BalanceOf<Self>>;
+
+		/// Native amount locked by the creator of an offer until it is completed or cancelled.
+		#[pallet::constant]
+		type Stake: Get<BalanceOf<Self>>;
+
+		/// Origin allowed to cancel any offer.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		NewOffer {
+			offer_id: T::BondOfferId,
+			beneficiary: T::AccountId,
+		},
+		/// `who` bought `nb_of_bonds` of an offer.
+		NewBond {
+			offer_id: T::BondOfferId,
+			who: T::AccountId,
+			nb_of_bonds: T::Balance,
+		},
+		OfferCancelled {
+			offer_id: T::BondOfferId,
+		},
+		/// Every bond of the offer was sold.
+		OfferCompleted {
+			offer_id: T::BondOfferId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		BondOfferNotFound,
+		/// The number of bonds is zero or more than what is left in the offer.
+		InvalidNumberOfBonds,
+	}
+
+	#[pallet::storage]
+	#[allow(clippy::disallowed_types)] // BondOfferId is ValueQuery with default 0
+	pub type BondOfferCount<T: Config> = StorageValue<_, T::BondOfferId, ValueQuery>;
+
+	/// Offers with their creator.
+	#[pallet::storage]
+	#[pallet::getter(fn bond_offer)]
+	pub type BondOffers<T: Config> =
+		StorageMap<_, Twox64Concat, T::BondOfferId, (T::AccountId, BondOfferOf<T>)>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a bond offer, locking its reward and the `Stake` of the caller.
+		///
+		/// Emits `NewOffer` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::offer())]
+		pub fn offer(
+			origin: OriginFor<T>,
+			offer: Validated<BondOfferOf<T>, ValidBondOfferOf<T>>,
+			keep_alive: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_offer(&who, offer.value(), keep_alive)?;
+			Ok(())
+		}
+
+		/// Buy `nb_of_bonds` of an offer.
+		///
+		/// Emits `NewBond` event when successful, and `OfferCompleted` once every bond is sold.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::bond())]
+		pub fn bond(
+			origin: OriginFor<T>,
+			offer_id: T::BondOfferId,
+			nb_of_bonds: T::Balance,
+			keep_alive: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_bond(offer_id, &who, nb_of_bonds, keep_alive)?;
+			Ok(())
+		}
+
+		/// Cancel an offer, returning the reward left and the stake to its creator. Callable by
+		/// the creator of the offer or the `AdminOrigin`.
+		///
+		/// Emits `OfferCancelled` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::cancel())]
+		pub fn cancel(origin: OriginFor<T>, offer_id: T::BondOfferId) -> DispatchResult {
+			let (creator, offer) = Self::get_offer(offer_id)?;
+			match (ensure_signed(origin.clone()), T::AdminOrigin::ensure_origin(origin)) {
+				(Ok(who), _) if who == creator => Ok(()),
+				(_, Ok(_)) => Ok(()),
+				_ => Err(DispatchError::BadOrigin),
+			}?;
+			let offer_account = Self::account_id(offer_id);
+			T::Currency::transfer(
+				offer.reward.asset,
+				&offer_account,
+				&creator,
+				offer.reward.amount,
+				Preservation::Expendable,
+			)?;
+			Self::release_stake(offer_id, &creator)?;
+			BondOffers::<T>::remove(offer_id);
+			Self::deposit_event(Event::<T>::OfferCancelled { offer_id });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> BondedFinance for Pallet<T> {
+		type AccountId = AccountIdOf<T>;
+		type AssetId = AssetIdOf<T>;
+		type Balance = BalanceOf<T>;
+		type BlockNumber = BlockNumberOf<T>;
+		type BondOfferId = T::BondOfferId;
+		type MinReward = T::MinReward;
+		type MinVestedTransfer = MinVestedTransferOf<T>;
+
+		fn offer(
+			from: &Self::AccountId,
+			offer: Validated<BondOfferOf<T>, ValidBondOfferOf<T>>,
+			keep_alive: bool,
+		) -> Result<Self::BondOfferId, DispatchError> {
+			Self::do_offer(from, offer.value(), keep_alive)
+		}
+
+		fn bond(
+			offer: Self::BondOfferId,
+			from: &Self::AccountId,
+			nb_of_bonds: Self::Balance,
+			keep_alive: bool,
+		) -> Result<Self::Balance, DispatchError> {
+			Self::do_bond(offer, from, nb_of_bonds, keep_alive)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Account holding the reward and the stake of an offer.
+		pub fn account_id(offer_id: T::BondOfferId) -> T::AccountId {
+			T::PalletId::get().into_sub_account_truncating(offer_id)
+		}
+
+		fn get_offer(
+			offer_id: T::BondOfferId,
+		) -> Result<(T::AccountId, BondOfferOf<T>), DispatchError> {
+			BondOffers::<T>::get(offer_id).ok_or_else(|| Error::<T>::BondOfferNotFound.into())
+		}
+
+		fn preservation(keep_alive: bool) -> Preservation {
+			if keep_alive {
+				Preservation::Preserve
+			} else {
+				Preservation::Expendable
+			}
+		}
+
+		/// Vest `amount` of `asset` from the account of an offer to `to`, released at once after
+		/// `period` blocks.
+		fn vest(
+			offer_id: T::BondOfferId,
+			asset: T::AssetId,
+			to: &T::AccountId,
+			amount: T::Balance,
+			period: T::BlockNumber,
+		) -> DispatchResult {
+			T::Vesting::vested_transfer(
+				asset,
+				&Self::account_id(offer_id),
+				to,
+				VestingScheduleInfo {
+					window: VestingWindow::BlockNumberBased {
+						start: frame_system::Pallet::<T>::block_number(),
+						period,
+					},
+					period_count: 1,
+					per_period: amount,
+				},
+			)
+		}
+
+		fn release_stake(offer_id: T::BondOfferId, creator: &T::AccountId) -> DispatchResult {
+			T::NativeCurrency::transfer(
+				&Self::account_id(offer_id),
+				creator,
+				T::Stake::get(),
+				Preservation::Expendable,
+			)?;
+			Ok(())
+		}
+
+		#[transactional]
+		fn do_offer(
+			from: &T::AccountId,
+			offer: BondOfferOf<T>,
+			keep_alive: bool,
+		) -> Result<T::BondOfferId, DispatchError> {
+			let offer_id = BondOfferCount::<T>::try_mutate(|count| -> Result<_, DispatchError> {
+				*count = count.safe_add(&T::BondOfferId::one())?;
+				Ok(*count)
+			})?;
+			let offer_account = Self::account_id(offer_id);
+			T::NativeCurrency::transfer(
+				from,
+				&offer_account,
+				T::Stake::get(),
+				Self::preservation(keep_alive),
+			)?;
+			T::Currency::transfer(
+				offer.reward.asset,
+				from,
+				&offer_account,
+				offer.reward.amount,
+				Self::preservation(keep_alive),
+			)?;
+			let beneficiary = offer.beneficiary.clone();
+			BondOffers::<T>::insert(offer_id, (from.clone(), offer));
+			Self::deposit_event(Event::<T>::NewOffer { offer_id, beneficiary });
+			Ok(offer_id)
+		}
+
+		/// Buy `nb_of_bonds` of an offer, returning the reward vested to `from`.
+		#[transactional]
+		fn do_bond(
+			offer_id: T::BondOfferId,
+			from: &T::AccountId,
+			nb_of_bonds: T::Balance,
+			keep_alive: bool,
+		) -> Result<T::Balance, DispatchError> {
+			let (creator, mut offer) = Self::get_offer(offer_id)?;
+			ensure!(
+				!nb_of_bonds.is_zero() && nb_of_bonds <= offer.nb_of_bonds,
+				Error::<T>::InvalidNumberOfBonds
+			);
+			let value = nb_of_bonds.safe_mul(&offer.bond_price)?;
+			let reward: T::Balance = safe_multiply_by_rational(
+				nb_of_bonds.into(),
+				offer.reward.amount.into(),
+				offer.nb_of_bonds.into(),
+			)?
+			.saturated_into();
+			match offer.maturity {
+				BondDuration::Finite { return_in } => {
+					T::Currency::transfer(
+						offer.asset,
+						from,
+						&Self::account_id(offer_id),
+						value,
+						Self::preservation(keep_alive),
+					)?;
+					Self::vest(offer_id, offer.asset, from, value, return_in)?;
+				},
+				BondDuration::Infinite => {
+					T::Currency::transfer(
+						offer.asset,
+						from,
+						&offer.beneficiary,
+						value,
+						Self::preservation(keep_alive),
+					)?;
+				},
+			}
+			Self::vest(offer_id, offer.reward.asset, from, reward, offer.reward.maturity)?;
+			offer.nb_of_bonds = offer.nb_of_bonds.safe_sub(&nb_of_bonds)?;
+			offer.reward.amount = offer.reward.amount.safe_sub(&reward)?;
+			Self::deposit_event(Event::<T>::NewBond { offer_id, who: from.clone(), nb_of_bonds });
+			if offer.completed() {
+				Self::release_stake(offer_id, &creator)?;
+				BondOffers::<T>::remove(offer_id);
+				Self::deposit_event(Event::<T>::OfferCompleted { offer_id });
+			} else {
+				BondOffers::<T>::insert(offer_id, (creator, offer));
+			}
+			Ok(reward)
+		}
+	}
+}
@@ -0,0 +1,192 @@
+#![cfg(test)]
+
+use crate as pallet_bonded_finance;
+use frame_support::{
+	parameter_types,
+	traits::{ConstU32, ConstU64, Everything},
+	PalletId,
+};
+use frame_system::EnsureRoot;
+use orml_traits::parameter_type_with_key;
+use sp_arithmetic::traits::Zero;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+
+pub type CurrencyId = u128;
+pub type BlockNumber = u64;
+pub type BondOfferId = u64;
+
+pub const PICA_USDT_LP: CurrencyId = 2;
+pub const PICA: CurrencyId = 3;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Tokens: orml_tokens,
+		Timestamp: pallet_timestamp,
+		Vesting: pallet_vesting,
+		BondedFinance: pallet_bonded_finance,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+pub type AccountId = u128;
+
+#[allow(dead_code)]
+pub static ALICE: AccountId = 1;
+#[allow(dead_code)]
+pub static BOB: AccountId = 2;
+#[allow(dead_code)]
+pub static CHARLIE: AccountId = 3;
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+pub type Balance = u128;
+pub type Amount = i128;
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type FreezeIdentifier = [u8; 8];
+	type HoldIdentifier = [u8; 8];
+	type MaxHolds = ConstU32<32>;
+	type MaxFreezes = ConstU32<32>;
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+		Zero::zero()
+	};
+}
+
+pub struct CurrencyHooks;
+impl orml_traits::currency::MutationHooks<AccountId, CurrencyId, Balance> for CurrencyHooks {
+	type OnDust = ();
+	type OnSlash = ();
+	type PreDeposit = ();
+	type PostDeposit = ();
+	type PreTransfer = ();
+	type PostTransfer = ();
+	type OnNewTokenAccount = ();
+	type OnKilledTokenAccount = ();
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = CurrencyId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ConstU32<8>;
+	type ReserveIdentifier = [u8; 8];
+	type MaxReserves = ConstU32<2>;
+	type DustRemovalWhitelist = Everything;
+	type CurrencyHooks = CurrencyHooks;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<5>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const MinVestedTransfer: Balance = 5;
+}
+
+impl pallet_vesting::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Tokens;
+	type MinVestedTransfer = MinVestedTransfer;
+	type VestedTransferOrigin = EnsureRoot<AccountId>;
+	type UpdateSchedulesOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+	type MaxVestingSchedules = ConstU32<16>;
+	type Moment = u64;
+	type Time = Timestamp;
+	type VestingScheduleId = u128;
+}
+
+parameter_types! {
+	pub const BondedFinancePalletId: PalletId = PalletId(*b"bondedfi");
+	pub const MinReward: Balance = 1_000;
+	pub const Stake: Balance = 10;
+}
+
+impl pallet_bonded_finance::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = CurrencyId;
+	type Balance = Balance;
+	type BondOfferId = BondOfferId;
+	type NativeCurrency = Balances;
+	type Currency = Tokens;
+	type Vesting = Vesting;
+	type MinReward = MinReward;
+	type Stake = Stake;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type PalletId = BondedFinancePalletId;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::default()
+		.build_storage::<Test>()
+		.expect("success")
+		.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
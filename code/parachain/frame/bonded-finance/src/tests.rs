@@ -0,0 +1,201 @@
+use crate::{mock::*, pallet::*};
+use composable_support::validation::Validated;
+use composable_traits::bonded_finance::{BondDuration, BondOffer, BondOfferReward};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{
+		fungible::{Inspect as _, Mutate as _},
+		fungibles::{Inspect, Mutate},
+	},
+};
+use sp_runtime::{DispatchError, TokenError};
+
+const REWARD: Balance = 10_000;
+
+fn bond_offer(maturity: BondDuration<BlockNumber>) -> BondOfferOf<Test> {
+	BondOffer {
+		beneficiary: CHARLIE,
+		asset: PICA_USDT_LP,
+		bond_price: 10,
+		nb_of_bonds: 100,
+		maturity,
+		reward: BondOfferReward { asset: PICA, amount: REWARD, maturity: 10 },
+	}
+}
+
+fn create_offer(maturity: BondDuration<BlockNumber>) -> BondOfferId {
+	assert_ok!(Balances::mint_into(&ALICE, 1_000));
+	assert_ok!(Tokens::mint_into(PICA, &ALICE, REWARD));
+	assert_ok!(BondedFinance::offer(
+		RuntimeOrigin::signed(ALICE),
+		Validated::new(bond_offer(maturity)).expect("valid offer"),
+		false
+	));
+	BondOfferCount::<Test>::get()
+}
+
+#[test]
+fn offer_escrows_reward_and_stake() {
+	new_test_ext().execute_with(|| {
+		let mut offer = bond_offer(BondDuration::Infinite);
+		offer.reward.amount = MinReward::get() - 1;
+		assert!(Validated::<_, ValidBondOfferOf<Test>>::new(offer).is_err());
+
+		let offer_id = create_offer(BondDuration::Infinite);
+		System::assert_last_event(
+			Event::<Test>::NewOffer { offer_id, beneficiary: CHARLIE }.into(),
+		);
+		let offer_account = BondedFinance::account_id(offer_id);
+		assert_eq!(Tokens::balance(PICA, &offer_account), REWARD);
+		assert_eq!(Balances::balance(&offer_account), Stake::get());
+		assert_eq!(Tokens::balance(PICA, &ALICE), 0);
+	})
+}
+
+#[test]
+fn invalid_offers_are_rejected() {
+	let validate = |offer| Validated::<BondOfferOf<Test>, ValidBondOfferOf<Test>>::new(offer).err();
+	assert_eq!(
+		validate(bond_offer(BondDuration::Finite { return_in: 0 })),
+		Some("MATURITY_CANNOT_BE_ZERO")
+	);
+	let mut offer = bond_offer(BondDuration::Infinite);
+	offer.bond_price = MinVestedTransfer::get() - 1;
+	assert_eq!(validate(offer), Some("BOND_PRICE_BELOW_MIN_TRANSFER"));
+	let mut offer = bond_offer(BondDuration::Infinite);
+	offer.nb_of_bonds = 0;
+	assert_eq!(validate(offer), Some("NUMBER_OF_BOND_CANNOT_BE_ZERO"));
+	// Every bond must be rewarded at least the minimum vested transfer.
+	let mut offer = bond_offer(BondDuration::Infinite);
+	offer.nb_of_bonds = REWARD / MinVestedTransfer::get() + 1;
+	assert_eq!(validate(offer), Some("INVALID_REWARD"));
+}
+
+#[test]
+fn offer_requires_stake_and_reward() {
+	new_test_ext().execute_with(|| {
+		let offer = || Validated::new(bond_offer(BondDuration::Infinite)).expect("valid offer");
+		assert_noop!(
+			BondedFinance::offer(RuntimeOrigin::signed(ALICE), offer(), false),
+			TokenError::FundsUnavailable
+		);
+
+		// The stake is not taken when the reward is missing.
+		assert_ok!(Balances::mint_into(&ALICE, 1_000));
+		assert_ok!(Tokens::mint_into(PICA, &ALICE, REWARD - 1));
+		assert_noop!(
+			BondedFinance::offer(RuntimeOrigin::signed(ALICE), offer(), false),
+			orml_tokens::Error::<Test>::BalanceTooLow
+		);
+		assert_eq!(Balances::balance(&ALICE), 1_000);
+		assert_eq!(BondOfferCount::<Test>::get(), 0);
+	})
+}
+
+#[test]
+fn bond_requires_open_offer_and_funds() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			BondedFinance::bond(RuntimeOrigin::signed(BOB), 1, 10, false),
+			Error::<Test>::BondOfferNotFound
+		);
+
+		// 1. The bonded asset is missing, nothing is vested.
+		let offer_id = create_offer(BondDuration::Finite { return_in: 5 });
+		assert_ok!(Tokens::mint_into(PICA_USDT_LP, &BOB, 99));
+		assert_noop!(
+			BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 10, false),
+			orml_tokens::Error::<Test>::BalanceTooLow
+		);
+		let (_, offer) = BondedFinance::bond_offer(offer_id).expect("offer exists");
+		assert_eq!(offer.nb_of_bonds, 100);
+		assert_eq!(Tokens::balance(PICA, &BOB), 0);
+
+		// 2. Completed offers can't be bonded anymore.
+		assert_ok!(Tokens::mint_into(PICA_USDT_LP, &BOB, 901));
+		assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 100, false));
+		assert_noop!(
+			BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 1, false),
+			Error::<Test>::BondOfferNotFound
+		);
+		assert_noop!(
+			BondedFinance::cancel(RuntimeOrigin::signed(ALICE), offer_id),
+			Error::<Test>::BondOfferNotFound
+		);
+	})
+}
+
+#[test]
+fn infinite_bonds_pay_beneficiary_and_vest_reward() {
+	new_test_ext().execute_with(|| {
+		let offer_id = create_offer(BondDuration::Infinite);
+		assert_ok!(Tokens::mint_into(PICA_USDT_LP, &BOB, 1_000));
+
+		// 1. The bonded asset goes to the beneficiary, the reward share is locked by vesting.
+		assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 10, false));
+		assert_eq!(Tokens::balance(PICA_USDT_LP, &CHARLIE), 100);
+		assert_eq!(Tokens::balance(PICA, &BOB), 1_000);
+		assert_eq!(Tokens::accounts(BOB, PICA).frozen, 1_000);
+		let (_, offer) = BondedFinance::bond_offer(offer_id).expect("offer exists");
+		assert_eq!(offer.nb_of_bonds, 90);
+		assert_eq!(offer.reward.amount, 9_000);
+
+		// 2. Bonds must be available.
+		assert_noop!(
+			BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 0, false),
+			Error::<Test>::InvalidNumberOfBonds
+		);
+		assert_noop!(
+			BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 91, false),
+			Error::<Test>::InvalidNumberOfBonds
+		);
+
+		// 3. Selling the last bond completes the offer and returns the stake.
+		let stake_before = Balances::balance(&ALICE);
+		assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 90, false));
+		System::assert_last_event(Event::<Test>::OfferCompleted { offer_id }.into());
+		assert_eq!(Tokens::balance(PICA, &BOB), REWARD);
+		assert_eq!(Balances::balance(&ALICE), stake_before + Stake::get());
+		assert!(BondedFinance::bond_offer(offer_id).is_none());
+	})
+}
+
+#[test]
+fn finite_bonds_lock_bonded_asset() {
+	new_test_ext().execute_with(|| {
+		let offer_id = create_offer(BondDuration::Finite { return_in: 5 });
+		assert_ok!(Tokens::mint_into(PICA_USDT_LP, &BOB, 1_000));
+
+		assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 10, false));
+		assert_eq!(Tokens::balance(PICA_USDT_LP, &CHARLIE), 0);
+		assert_eq!(Tokens::balance(PICA_USDT_LP, &BOB), 1_000);
+		assert_eq!(Tokens::accounts(BOB, PICA_USDT_LP).frozen, 100);
+	})
+}
+
+#[test]
+fn cancel_refunds_creator() {
+	new_test_ext().execute_with(|| {
+		let offer_id = create_offer(BondDuration::Infinite);
+		assert_ok!(Tokens::mint_into(PICA_USDT_LP, &BOB, 1_000));
+		assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 10, false));
+
+		// 1. Only the creator or the admin can cancel an offer.
+		assert_noop!(
+			BondedFinance::cancel(RuntimeOrigin::signed(BOB), offer_id),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(BondedFinance::cancel(RuntimeOrigin::signed(ALICE), offer_id));
+		System::assert_last_event(Event::<Test>::OfferCancelled { offer_id }.into());
+		assert_eq!(Tokens::balance(PICA, &ALICE), 9_000);
+		assert!(BondedFinance::bond_offer(offer_id).is_none());
+
+		// 2. Governance can cancel any offer.
+		let offer_id = create_offer(BondDuration::Infinite);
+		assert_ok!(BondedFinance::cancel(RuntimeOrigin::root(), offer_id));
+		assert_noop!(
+			BondedFinance::cancel(RuntimeOrigin::root(), offer_id),
+			Error::<Test>::BondOfferNotFound
+		);
+	})
+}
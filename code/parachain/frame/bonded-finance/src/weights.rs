@@ -0,0 +1,26 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+pub trait WeightInfo {
+	fn offer() -> Weight;
+	fn bond() -> Weight;
+	fn cancel() -> Weight;
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn offer() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn bond() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn cancel() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+}
@@ -122,11 +122,16 @@ pub fn cosmos_recover(
 	match cosmos_address {
 		CosmosPublicKey::Secp256k1(pub_key) => {
 			// Cosmos gives us a 64-byte signature, we convert it into the more standard 65-byte
-			// signature here
-			let sig: EcdsaSignature = CosmosEcdsaSignature(*sig).into();
-
-			if pub_key == sp_io::crypto::secp256k1_ecdsa_recover_compressed(&sig.0, &msg)? {
-				return Ok(CosmosPublicKey::Secp256k1(pub_key))
+			// signature here. It has no recovery id, so both are tried.
+			let mut sig: EcdsaSignature = CosmosEcdsaSignature(*sig).into();
+
+			for recovery_id in [0, 1] {
+				sig.0[64] = recovery_id;
+				if sp_io::crypto::secp256k1_ecdsa_recover_compressed(&sig.0, &msg).ok() ==
+					Some(pub_key)
+				{
+					return Ok(CosmosPublicKey::Secp256k1(pub_key))
+				}
 			}
 
 			Err(SignatureVerificationError::FailedVerification)
@@ -78,11 +78,12 @@ impl sp_std::fmt::Debug for EcdsaSignature {
 	}
 }
 
+/// The recovery id of the converted signature is 0.
 impl From<CosmosEcdsaSignature> for EcdsaSignature {
 	fn from(item: CosmosEcdsaSignature) -> Self {
 		let mut sig: [u8; 65] = [0; 65];
 
-		sig.copy_from_slice(&item.0);
+		sig[..64].copy_from_slice(&item.0);
 
 		EcdsaSignature(sig)
 	}
@@ -2,6 +2,7 @@
 use crate::prelude::*;
 use codec::{Decode, Encode, MaxEncodedLen};
 use composable_support::collections::vec::bounded::BiBoundedVec;
+use frame_support::{traits::ConstU32, BoundedVec};
 
 use scale_info::TypeInfo;
 use sp_runtime::{DispatchError, DispatchResult, RuntimeDebug};
@@ -17,8 +18,18 @@ use serde::{Deserialize, Serialize};
 pub const ASSET_METADATA_NAME_LENGTH: usize = 64;
 pub const ASSET_METADATA_SYMBOL_LENGTH: usize = 16;
 
+pub const ASSET_METADATA_IBC_DENOM_LENGTH: usize = 128;
+pub const ASSET_METADATA_URI_LENGTH: usize = 128;
+pub const ASSET_METADATA_COINGECKO_ID_LENGTH: usize = 64;
+pub const ASSET_METADATA_TAG_LENGTH: usize = 16;
+pub const ASSET_METADATA_MAX_TAGS: u32 = 8;
+
 pub type BiBoundedAssetName = BiBoundedVec<u8, 1, ASSET_METADATA_NAME_LENGTH>;
 pub type BiBoundedAssetSymbol = BiBoundedVec<u8, 1, ASSET_METADATA_SYMBOL_LENGTH>;
+pub type BiBoundedIbcDenom = BiBoundedVec<u8, 1, ASSET_METADATA_IBC_DENOM_LENGTH>;
+pub type BiBoundedAssetUri = BiBoundedVec<u8, 1, ASSET_METADATA_URI_LENGTH>;
+pub type BiBoundedCoingeckoId = BiBoundedVec<u8, 1, ASSET_METADATA_COINGECKO_ID_LENGTH>;
+pub type BiBoundedAssetTag = BiBoundedVec<u8, 1, ASSET_METADATA_TAG_LENGTH>;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -156,6 +167,89 @@ pub struct AssetInfoUpdate<Balance> {
 	pub ratio: UpdateValue<Option<Rational64>>,
 }
 
+/// Whether governance vouches for an asset being what its metadata says.
+#[derive(Decode, Encode, Debug, Clone, Copy, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum AssetVerification {
+	#[default]
+	Unverified,
+	Verified,
+	/// Known to impersonate another asset or to be otherwise unsafe.
+	Flagged,
+}
+
+/// Metadata of an asset beyond what is needed to use it on chain, for wallets and gateways to
+/// resolve assets without off-chain lists.
+#[derive(Decode, Encode, Debug, Clone, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ExtendedAssetMetadata {
+	/// Full IBC denom path of the asset, e.g. `transfer/channel-2/uatom`.
+	pub ibc_denom: Option<BiBoundedIbcDenom>,
+	/// URI of the logo of the asset.
+	pub logo_uri: Option<BiBoundedAssetUri>,
+	/// Identifier of the asset on CoinGecko.
+	pub coingecko_id: Option<BiBoundedCoingeckoId>,
+	pub verification: AssetVerification,
+	/// Free form tags, e.g. `stablecoin` or `lp`.
+	pub tags: BoundedVec<BiBoundedAssetTag, ConstU32<ASSET_METADATA_MAX_TAGS>>,
+}
+
+/// Criteria on the extended metadata of assets, all of which listed assets match.
+#[derive(Decode, Encode, Debug, Clone, PartialEq, Eq, Default, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AssetFilter {
+	pub verification: Option<AssetVerification>,
+	/// Only assets with this tag.
+	pub tag: Option<Vec<u8>>,
+	/// Only assets with, or without, an IBC denom.
+	pub ibc: Option<bool>,
+	/// Only assets with, or without, a foreign location.
+	pub foreign: Option<bool>,
+}
+
+impl AssetFilter {
+	pub fn matches<AssetId, Balance, ForeignId>(
+		&self,
+		asset: &ExtendedAsset<AssetId, Balance, ForeignId>,
+	) -> bool {
+		self.verification
+			.map_or(true, |verification| verification == asset.metadata.verification) &&
+			self.tag.as_ref().map_or(true, |tag| {
+				asset.metadata.tags.iter().any(|asset_tag| asset_tag.as_vec() == tag)
+			}) && self.ibc.map_or(true, |ibc| ibc == asset.metadata.ibc_denom.is_some()) &&
+			self.foreign.map_or(true, |foreign| foreign == asset.asset.foreign_id.is_some())
+	}
+}
+
+/// An asset with its extended metadata and the foreign locations it had.
+#[derive(Decode, Encode, Debug, Clone, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ExtendedAsset<AssetId, Balance, ForeignId> {
+	pub asset: Asset<AssetId, Balance, ForeignId>,
+	pub metadata: ExtendedAssetMetadata,
+	/// Previous foreign locations, oldest first.
+	pub location_history: Vec<ForeignId>,
+}
+
+/// Balances of an account in an asset.
+#[derive(Decode, Encode, Debug, Clone, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct PortfolioEntry<AssetId, Balance, ForeignId> {
+	pub asset: ExtendedAsset<AssetId, Balance, ForeignId>,
+	/// Free balance, including locked funds.
+	pub free: Balance,
+	pub reserved: Balance,
+	/// Part of `free` locked by vesting schedules.
+	pub vesting_locked: Balance,
+	/// Staked in staking positions owned by the account.
+	pub staked: Balance,
+	/// Redeemable from liquidity pools with the LP tokens of the account.
+	pub liquidity: Balance,
+	/// Value of `free + reserved + staked + liquidity` in the oracle quote currency, if asked for
+	/// and priced.
+	pub value: Option<Balance>,
+}
+
 pub trait AssetTypeInspect {
 	type AssetId;
 
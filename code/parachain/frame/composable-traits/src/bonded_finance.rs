@@ -20,7 +20,7 @@ pub trait BondedFinance {
 		from: &Self::AccountId,
 		offer: Validated<
 			BondOffer<Self::AccountId, Self::AssetId, Self::Balance, Self::BlockNumber>,
-			ValidBondOffer<Self::MinReward, Self::MinVestedTransfer>,
+			ValidBondOffer<Self::MinVestedTransfer, Self::MinReward>,
 		>,
 		keep_alive: bool,
 	) -> Result<Self::BondOfferId, DispatchError>;
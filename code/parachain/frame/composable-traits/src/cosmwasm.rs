@@ -16,4 +16,6 @@ pub enum CosmwasmSubstrateError {
 	Ibc,
 	#[error("")]
 	Xcm,
+	#[error("")]
+	Stargate,
 }
@@ -4,7 +4,7 @@ use scale_info::TypeInfo;
 use sp_arithmetic::fixed_point::FixedU64;
 use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, Zero},
-	ArithmeticError, FixedU128, Rational128,
+	ArithmeticError, FixedU128, Permill, Rational128,
 };
 
 use composable_support::math::safe::{SafeAdd, SafeDiv, SafeMul, SafeSub};
@@ -63,6 +63,27 @@ pub trait AssetRatioInspect {
 	}
 }
 
+/// Source of the price of a fee asset in native currency.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeeAssetPriceSource<PoolId> {
+	/// Time weighted average prices of the oracle, for the fee asset and the native asset.
+	Oracle,
+	/// Time weighted average price of a pablo pool of the fee asset and the native asset.
+	Pablo(PoolId),
+}
+
+pub trait FeeAssetPriceSources {
+	type AssetId;
+	type PoolId;
+
+	/// How to price `asset_id` in native currency, and the maximum deviation of that price from
+	/// the static ratio of the asset. Assets without a source are only priced by their static
+	/// ratio.
+	fn price_source(
+		asset_id: Self::AssetId,
+	) -> Option<(FeeAssetPriceSource<Self::PoolId>, Permill)>;
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Unit<T>(PhantomData<T>);
 
@@ -284,6 +284,25 @@ pub trait SellEngine<Configuration>: DeFiEngine {
 	) -> Result<(), DispatchError>;
 }
 
+/// Notified by a [`SellEngine`] of the settlement of its orders, so that the seller can account
+/// for the proceeds and for what was left unsold.
+pub trait SellCallback<OrderId, Balance> {
+	/// `quote_amount` was paid to the seller of `order_id`.
+	fn on_sold(order_id: &OrderId, quote_amount: Balance) -> Result<(), DispatchError>;
+	/// `order_id` was removed, `unsold` amount of `base` being returned to the seller.
+	fn on_closed(order_id: &OrderId, unsold: Balance) -> Result<(), DispatchError>;
+}
+
+impl<OrderId, Balance> SellCallback<OrderId, Balance> for () {
+	fn on_sold(_order_id: &OrderId, _quote_amount: Balance) -> Result<(), DispatchError> {
+		Ok(())
+	}
+
+	fn on_closed(_order_id: &OrderId, _unsold: Balance) -> Result<(), DispatchError> {
+		Ok(())
+	}
+}
+
 pub trait DeFiComposableConfig: frame_system::Config {
 	type MayBeAssetId: AssetIdLike + MaybeSerializeDeserialize + Default + MaxEncodedLen + Debug;
 
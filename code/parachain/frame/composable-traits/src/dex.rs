@@ -1,4 +1,8 @@
-use crate::{currency::BalanceLike, defi::CurrencyPair, prelude::*};
+use crate::{
+	currency::BalanceLike,
+	defi::{CurrencyPair, Rate},
+	prelude::*,
+};
 
 use frame_support::{
 	ensure,
@@ -297,6 +301,20 @@ pub trait Amm {
 	) -> Result<SwapResult<Self::AssetId, Self::Balance>, DispatchError>;
 }
 
+/// Time weighted average prices of the assets of pools.
+pub trait Twap {
+	/// The asset ID type
+	type AssetId;
+	/// Type that represents pool id
+	type PoolId;
+
+	/// Time weighted average price of `asset_id` in the other asset of the pool, that is the
+	/// amount of the other asset exchanged for one of `asset_id`, in their smallest units.
+	///
+	/// Fails if TWAP is not enabled for the pool or `asset_id` is not in the pool.
+	fn get_twap(pool_id: Self::PoolId, asset_id: Self::AssetId) -> Result<Rate, DispatchError>;
+}
+
 pub const REWARD_PERCENTAGE: u32 = 10;
 
 /// Pool Fees
@@ -58,6 +58,7 @@ pub mod governance;
 pub mod oracle;
 pub mod prelude;
 pub mod privilege;
+pub mod rate_model;
 pub mod staking;
 pub mod storage;
 pub mod time;
@@ -14,7 +14,7 @@
 
 use core::ops::Neg;
 
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_std::{cmp::Ordering, convert::TryInto};
 
@@ -23,10 +23,10 @@ use sp_runtime::{
 	ArithmeticError, FixedI128, FixedPointNumber, FixedU128, RuntimeDebug,
 };
 
-use composable_support::math::safe::{LiftedFixedBalance, SafeArithmetic};
+use composable_support::math::safe::SafeArithmetic;
 use sp_arithmetic::per_things::Percent;
 
-use crate::loans::{DurationSeconds, ONE_HOUR};
+use crate::time::{DurationSeconds, ONE_HOUR};
 
 /// The fixed point number from 0..to max.
 /// Unlike `Ratio` it can be more than 1.
@@ -51,7 +51,7 @@ pub trait InterestRate {
 
 /// Parallel interest rate model
 #[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
-#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo)]
+#[derive(Encode, Decode, MaxEncodedLen, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo)]
 pub enum InterestRateModel {
 	Jump(JumpModel),
 	Curve(CurveModel),
@@ -61,7 +61,7 @@ pub enum InterestRateModel {
 
 impl Default for InterestRateModel {
 	// unwrap is used with known parameters, and unit tested right below.
-	#[allow(clippy::disallowed_methods)]
+	#[allow(clippy::disallowed_methods, clippy::unwrap_used)]
 	fn default() -> Self {
 		Self::new_jump_model(
 			Rate::saturating_from_rational(2, 100),
@@ -143,7 +143,9 @@ impl InterestRate for InterestRateModel {
 
 /// The jump interest rate model
 #[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
-#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, Default, TypeInfo)]
+#[derive(
+	Encode, Decode, MaxEncodedLen, Eq, PartialEq, Copy, Clone, RuntimeDebug, Default, TypeInfo,
+)]
 pub struct JumpModel {
 	/// The base interest rate when utilization rate is 0
 	pub base_rate: Rate,
@@ -218,7 +220,9 @@ impl InterestRate for JumpModel {
 
 /// The curve interest rate model
 #[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
-#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, Default, TypeInfo)]
+#[derive(
+	Encode, Decode, MaxEncodedLen, Eq, PartialEq, Copy, Clone, RuntimeDebug, Default, TypeInfo,
+)]
 pub struct CurveModel {
 	base_rate: Rate,
 }
@@ -258,7 +262,9 @@ impl InterestRate for CurveModel {
 ///
 /// To know how `kp`, `ki` and `kd` are derived please check paper at above URL.
 #[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
-#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, Default, TypeInfo)]
+#[derive(
+	Encode, Decode, MaxEncodedLen, Eq, PartialEq, Copy, Clone, RuntimeDebug, Default, TypeInfo,
+)]
 pub struct DynamicPIDControllerModel {
 	/// proportional_parameter
 	kp: FixedI128,
@@ -355,7 +361,9 @@ const EXPECTED_COEFFICIENTS_SUM: u16 = 100;
 /// For reference check https://github.com/dydxprotocol/solo/blob/master/contracts/external/interestsetters/DoubleExponentInterestSetter.sol
 /// https://help.dydx.exchange/en/articles/2924246-how-do-interest-rates-work
 #[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
-#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, Default, TypeInfo)]
+#[derive(
+	Encode, Decode, MaxEncodedLen, Eq, PartialEq, Copy, Clone, RuntimeDebug, Default, TypeInfo,
+)]
 pub struct DoubleExponentModel {
 	coefficients: [u8; 16],
 }
@@ -63,7 +63,7 @@ impl<MaxDurationPresets: Get<u32>> DurationMultipliers<MaxDurationPresets> {
 }
 
 /// staking typed fNFT, usually can be mapped to raw fNFT storage type
-#[derive(Debug, PartialEq, Eq, Copy, Clone, Encode, Decode, TypeInfo)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, MaxEncodedLen, Encode, Decode, TypeInfo)]
 pub struct Lock {
 	/// The date at which this NFT was minted or to which lock was extended too.
 	pub started_at: Timestamp,
@@ -16,7 +16,7 @@ pub mod math;
 
 /// Defines staking duration, rewards and early unstake penalty for a given asset type.
 /// TODO refer to the relevant section in the design doc.
-#[derive(RuntimeDebug, PartialEq, Eq, Clone, Encode, Decode, TypeInfo)]
+#[derive(RuntimeDebug, PartialEq, Eq, Clone, MaxEncodedLen, Encode, Decode, TypeInfo)]
 pub struct Reward<Balance> {
 	/// Total rewards including inflation for adjusting for new stakers joining the pool. All
 	/// stakers in a pool are eligible to receive a part of this value based on their share of the
@@ -107,7 +107,14 @@ impl<Balance: Zero> Reward<Balance> {
 /// particular purpose. Eg: a pool of rewards for incentivizing adding liquidity to a pablo swap
 /// pool. TODO refer to the relevant section in the design doc.
 #[derive(
-	RuntimeDebugNoBound, PartialEqNoBound, EqNoBound, CloneNoBound, Encode, Decode, TypeInfo,
+	RuntimeDebugNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	CloneNoBound,
+	MaxEncodedLen,
+	Encode,
+	Decode,
+	TypeInfo,
 )]
 #[scale_info(skip_type_params(MaxDurationPresets, MaxRewards))]
 pub struct RewardPool<
@@ -190,7 +197,9 @@ pub enum RewardPoolConfiguration<
 /// Staking typed fNFT, usually can be mapped to raw fNFT storage type. A position identifier
 /// should exist for each position when stored in the runtime storage.
 /// TODO refer to the relevant section in the design doc.
-#[derive(DebugNoBound, PartialEqNoBound, EqNoBound, CloneNoBound, Encode, Decode, TypeInfo)]
+#[derive(
+	DebugNoBound, PartialEqNoBound, EqNoBound, CloneNoBound, MaxEncodedLen, Encode, Decode, TypeInfo,
+)]
 #[scale_info(skip_type_params(MaxReductions))]
 pub struct Stake<
 	AssetId: Debug + PartialEq + Eq + Clone,
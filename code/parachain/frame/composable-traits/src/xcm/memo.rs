@@ -62,3 +62,59 @@ pub struct ChainInfo {
 	/// if para id is none, it means send to relay-chain
 	pub para_id: Option<u32>,
 }
+
+/// State of a single hop of a tracked multihop transfer.
+#[derive(
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	codec::Encode,
+	codec::Decode,
+	scale_info::TypeInfo,
+	MaxEncodedLen,
+	Debug,
+)]
+pub enum HopState {
+	/// Waiting for the previous hop to complete
+	Pending,
+	/// Packet was sent and is waiting for acknowledgement
+	Sent,
+	/// Packet was acknowledged successfully
+	Acknowledged,
+	/// Packet timed out, funds were returned to the sender
+	TimedOut,
+	/// Packet was acknowledged with an error, funds were returned to the sender
+	Failed,
+	/// Sender gave up on the failed transfer, funds were sent back to the source chain
+	Refunded,
+}
+
+#[derive(
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	codec::Encode,
+	codec::Decode,
+	scale_info::TypeInfo,
+	MaxEncodedLen,
+	Debug,
+)]
+pub struct HopStatus {
+	/// chain_id of the `ChainInfo` of the hop
+	pub chain_id: u32,
+	pub state: HopState,
+}
+
+/// Status of a multihop transfer as returned by the runtime API.
+#[derive(Clone, PartialEq, Eq, codec::Encode, codec::Decode, scale_info::TypeInfo, Debug)]
+pub struct MultihopTransferStatus<AccountId, AssetId> {
+	pub owner: AccountId,
+	pub route_id: u128,
+	pub asset_id: AssetId,
+	pub amount: u128,
+	/// number of times the transfer was retried
+	pub attempts: u8,
+	pub hops: Vec<HopStatus>,
+}
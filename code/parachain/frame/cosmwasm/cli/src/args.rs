@@ -26,6 +26,8 @@ pub struct QueryCommand {
 pub enum QuerySubcommands {
 	/// Query a CosmWasm contract
 	Wasm(WasmRpcQuery),
+	/// Export the code id, admin, label and full storage of a CosmWasm contract
+	Export(ExportContract),
 }
 
 #[derive(Args, Debug)]
@@ -35,6 +37,11 @@ pub struct TxCommand {
 
 	#[arg(long)]
 	pub dry_run: Option<bool>,
+
+	/// Run the message against the current state without submitting it and print the gas used,
+	/// events, replies and data. Requires an HTTP node endpoint.
+	#[arg(long)]
+	pub simulate: bool,
 }
 
 #[derive(Debug, Subcommand)]
@@ -176,6 +183,13 @@ pub struct UpdateAdmin {
 	pub gas: u64,
 }
 
+#[derive(Args, Debug)]
+pub struct ExportContract {
+	/// Contract to be exported
+	#[arg(short, long)]
+	pub contract: AccountId32,
+}
+
 #[derive(Args, Debug)]
 pub struct WasmRpcQuery {
 	/// Contract to be queried
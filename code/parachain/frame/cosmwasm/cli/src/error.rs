@@ -15,6 +15,9 @@ pub enum Error {
 	#[error("Invalid phrase.")]
 	InvalidPhrase,
 
+	#[error("This transaction cannot be simulated.")]
+	SimulationNotSupported,
+
 	/// The overall format was invalid (e.g. the seed phrase contained symbols).
 	#[error("Invalid format")]
 	SecretStringInvalidFormat,
@@ -1,9 +1,16 @@
 use crate::{
-	args::{QueryCommand, QuerySubcommands, WasmInstantiate, WasmRpcQuery},
+	args::{ExportContract, QueryCommand, QuerySubcommands, WasmInstantiate, WasmRpcQuery},
 	error::Error,
 };
 
-use super::{cosmwasm, OutputType};
+use super::{
+	cosmwasm,
+	types::{
+		cosmwasm::{ContractStateResponse, ExportedContract},
+		PrettyDisplay,
+	},
+	OutputType,
+};
 use clap::{Args, Subcommand};
 use cosmwasm_std::{Binary, QueryRequest, WasmQuery};
 use jsonrpc::{Request, Response};
@@ -42,11 +49,22 @@ impl QueryCommandRunner {
 				}
 				Ok(())
 			},
+			QuerySubcommands::Export(ExportContract { contract }) => {
+				let params = rpc_params!(contract.to_string());
+				let resp: ContractStateResponse =
+					rpc_call("cosmwasm_exportContract", &params, chain_endpoint).await?;
+				let exported = ExportedContract::from(resp);
+				match output {
+					OutputType::Text => exported.pretty_display(0),
+					OutputType::Json => println!("{}", serde_json::to_string_pretty(&exported)?),
+				}
+				Ok(())
+			},
 		}
 	}
 }
 
-async fn rpc_call<Res: DeserializeOwned>(
+pub(crate) async fn rpc_call<Res: DeserializeOwned>(
 	method: &str,
 	params: &[Box<RawValue>],
 	endpoint: String,
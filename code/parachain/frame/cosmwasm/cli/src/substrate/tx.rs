@@ -7,6 +7,7 @@ use crate::{
 
 use super::{
 	cosmwasm::fetch_code,
+	rpc::rpc_call,
 	subxt_api::api::{
 		self,
 		cosmwasm::events,
@@ -18,20 +19,22 @@ use super::{
 	},
 	types::{
 		cosmwasm::{
-			AdminUpdated, Emitted, Extrinsic, ExtrinsicExecuted, Instantiated, Migrated, Uploaded,
+			AdminUpdated, Emitted, Extrinsic, ExtrinsicExecuted, Instantiated, Migrated, Simulated,
+			Uploaded,
 		},
 		PrettyDisplay,
 	},
 	OutputType,
 };
 use serde::Serialize;
+use std::collections::BTreeMap;
 
 use subxt::{
 	blocks::ExtrinsicEvents,
 	ext::{
 		codec::Encode,
 		sp_core::Pair,
-		sp_runtime::{MultiSignature, MultiSigner},
+		sp_runtime::{traits::IdentifyAccount, MultiSignature, MultiSigner},
 	},
 	OnlineClient, SubstrateConfig,
 };
@@ -51,6 +54,9 @@ impl CommandRunner {
 		MultiSigner: From<<P as Pair>::Public>,
 		subxt::utils::MultiSignature: From<<P as sp_core::Pair>::Signature>,
 	{
+		if command.simulate {
+			return simulate(command.subcommands, pair, chain_endpoint, output_type).await
+		}
 		match command.subcommands {
 			TxSubcommands::Store(store_command) => {
 				let code = fetch_code(&store_command)?;
@@ -144,6 +150,78 @@ impl CommandRunner {
 	}
 }
 
+async fn simulate<P: Pair>(
+	subcommand: TxSubcommands,
+	signer: P,
+	endpoint: String,
+	output_type: OutputType,
+) -> Result<(), Error>
+where
+	MultiSigner: From<<P as Pair>::Public>,
+{
+	let sender = MultiSigner::from(signer.public()).into_account().to_string();
+	let funds = |funds: Vec<(u128, u128)>| {
+		funds
+			.into_iter()
+			.map(|(asset, amount)| (asset, (amount, true)))
+			.collect::<BTreeMap<_, _>>()
+	};
+	let simulated: Simulated = match subcommand {
+		TxSubcommands::Instantiate2(WasmInstantiate2 {
+			salt,
+			instantiate:
+				WasmInstantiate {
+					gas,
+					code_id_int64,
+					admin,
+					label,
+					funds: instantiate_funds,
+					json_encoded_init_args,
+				},
+		}) => {
+			let params = [
+				jsonrpc::arg(sender),
+				jsonrpc::arg(code_id_int64),
+				jsonrpc::arg(salt.into_bytes()),
+				jsonrpc::arg(admin.map(|admin| admin.to_string())),
+				jsonrpc::arg(label.into_bytes()),
+				jsonrpc::arg(funds(instantiate_funds)),
+				jsonrpc::arg(gas),
+				jsonrpc::arg(json_encoded_init_args.into_bytes()),
+			];
+			rpc_call("cosmwasm_simulateInstantiate", &params, endpoint).await?
+		},
+		TxSubcommands::Execute(Execute { gas, contract, funds: execute_funds, message }) => {
+			let params = [
+				jsonrpc::arg(sender),
+				jsonrpc::arg(contract.to_string()),
+				jsonrpc::arg(funds(execute_funds)),
+				jsonrpc::arg(gas),
+				jsonrpc::arg(message.into_bytes()),
+			];
+			rpc_call("cosmwasm_simulateExecute", &params, endpoint).await?
+		},
+		TxSubcommands::Migrate(Migrate { gas, contract, new_code_id, message }) => {
+			let params = [
+				jsonrpc::arg(sender),
+				jsonrpc::arg(contract.to_string()),
+				jsonrpc::arg(new_code_id),
+				jsonrpc::arg(gas),
+				jsonrpc::arg(message.into_bytes()),
+			];
+			rpc_call("cosmwasm_simulateMigrate", &params, endpoint).await?
+		},
+		TxSubcommands::Store(_) | TxSubcommands::UpdateAdmin(_) =>
+			return Err(Error::SimulationNotSupported),
+	};
+
+	match output_type {
+		OutputType::Text => simulated.pretty_display(0),
+		OutputType::Json => println!("{}", serde_json::to_string_pretty(&simulated)?),
+	}
+	Ok(())
+}
+
 async fn do_signed_transaction<CallData, P: Pair>(
 	endpoint: String,
 	signer: P,
@@ -1,5 +1,5 @@
 use super::subxt_api;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::{collections::BTreeMap, string::ToString};
 
 pub trait PrettyDisplay {
@@ -172,4 +172,104 @@ pub mod cosmwasm {
 			self.attributes.iter().for_each(|(k, v)| println!("{indent}\t\t- {}: {}", k, v));
 		}
 	}
+
+	#[derive(Debug, Serialize, Deserialize)]
+	pub struct SimulatedEvent {
+		pub ty: String,
+		pub attributes: Vec<(String, String)>,
+	}
+
+	#[derive(Debug, Serialize, Deserialize)]
+	pub struct SimulatedReply {
+		pub id: u64,
+		pub result: String,
+	}
+
+	/// Output of the `cosmwasm_simulate*` RPCs.
+	#[derive(Debug, Serialize, Deserialize)]
+	pub struct Simulated {
+		pub gas_used: u64,
+		pub contract: Option<String>,
+		pub data: Option<Vec<u8>>,
+		pub events: Vec<SimulatedEvent>,
+		pub replies: Vec<SimulatedReply>,
+	}
+
+	impl PrettyDisplay for Simulated {
+		fn pretty_display(&self, indentation_level: usize) {
+			let indent = "\t".repeat(indentation_level);
+			println!("{indent}[ + ] Simulation");
+			println!("{indent}\t- Gas used: {}", self.gas_used);
+			if let Some(contract) = &self.contract {
+				println!("{indent}\t- Contract address: {contract}");
+			}
+			self.events.iter().for_each(|event| {
+				println!("{indent}\t- Event: {}", event.ty);
+				event
+					.attributes
+					.iter()
+					.for_each(|(k, v)| println!("{indent}\t\t- {}: {}", k, v));
+			});
+			self.replies.iter().for_each(|reply| {
+				println!("{indent}\t- Reply {}: {}", reply.id, reply.result);
+			});
+			if let Some(data) = &self.data {
+				println!("{indent}\t- Data: 0x{}", hex::encode(data));
+			}
+		}
+	}
+	/// Output of the `cosmwasm_exportContract` RPC.
+	#[derive(Debug, Deserialize)]
+	pub struct ContractStateResponse {
+		pub code_hash: [u8; 32],
+		pub code_id: u64,
+		pub instantiator: String,
+		pub admin: Option<String>,
+		pub label: Vec<u8>,
+		pub storage: Vec<(Vec<u8>, Vec<u8>)>,
+	}
+
+	/// Exported contract, keys and values are base64 encoded.
+	#[derive(Debug, Serialize, Deserialize)]
+	pub struct ExportedContract {
+		pub code_hash: String,
+		pub code_id: u64,
+		pub instantiator: String,
+		pub admin: Option<String>,
+		pub label: String,
+		pub storage: Vec<(cosmwasm_std::Binary, cosmwasm_std::Binary)>,
+	}
+
+	impl From<ContractStateResponse> for ExportedContract {
+		fn from(state: ContractStateResponse) -> Self {
+			Self {
+				code_hash: hex::encode(state.code_hash),
+				code_id: state.code_id,
+				instantiator: state.instantiator,
+				admin: state.admin,
+				label: String::from_utf8_lossy(&state.label).into_owned(),
+				storage: state
+					.storage
+					.into_iter()
+					.map(|(key, value)| (key.into(), value.into()))
+					.collect(),
+			}
+		}
+	}
+
+	impl PrettyDisplay for ExportedContract {
+		fn pretty_display(&self, indentation_level: usize) {
+			let indent = "\t".repeat(indentation_level);
+			println!("{indent}[ + ] Contract state");
+			println!("{indent}\t- Code Hash: 0x{}", self.code_hash);
+			println!("{indent}\t- Code ID: {}", self.code_id);
+			println!("{indent}\t- Instantiator: {}", self.instantiator);
+			if let Some(admin) = &self.admin {
+				println!("{indent}\t- Admin: {admin}");
+			}
+			println!("{indent}\t- Label: {}", self.label);
+			println!("{indent}\t- Storage:");
+			self.storage.iter().for_each(|(k, v)| println!("{indent}\t\t- {}: {}", k, v));
+		}
+	}
 }
@@ -1,16 +1,96 @@
 use codec::Codec;
 use core::{fmt::Display, str::FromStr};
-use cosmwasm_runtime_api::CosmwasmRuntimeApi;
+use cosmwasm_runtime_api::{ContractState, CosmwasmRuntimeApi, SimulateMsg, Simulation};
 use jsonrpsee::{
 	core::{Error as RpcError, RpcResult},
 	proc_macros::rpc,
 	types::{error::CallError, ErrorObject},
 };
+use serde::{Deserialize, Serialize};
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
 use sp_runtime::traits::Block as BlockT;
 use sp_std::{cmp::Ord, collections::btree_map::BTreeMap, sync::Arc};
 
+/// Contract event emitted during simulation.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SimulatedEvent {
+	pub ty: String,
+	pub attributes: Vec<(String, String)>,
+}
+
+/// Submessage reply delivered during simulation.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SimulatedReply {
+	pub id: u64,
+	/// JSON encoded `SubMsgResult`
+	pub result: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SimulationResponse<AccountId> {
+	pub gas_used: u64,
+	/// Address of the contract, if instantiated
+	pub contract: Option<AccountId>,
+	/// `Response` data
+	pub data: Option<Vec<u8>>,
+	pub events: Vec<SimulatedEvent>,
+	pub replies: Vec<SimulatedReply>,
+}
+
+impl<AccountId> From<Simulation<AccountId>> for SimulationResponse<AccountId> {
+	fn from(simulation: Simulation<AccountId>) -> Self {
+		let lossy = |bytes: Vec<u8>| String::from_utf8_lossy(&bytes).into_owned();
+		Self {
+			gas_used: simulation.gas_used,
+			contract: simulation.contract,
+			data: simulation.data,
+			events: simulation
+				.events
+				.into_iter()
+				.map(|event| SimulatedEvent {
+					ty: lossy(event.ty),
+					attributes: event
+						.attributes
+						.into_iter()
+						.map(|(key, value)| (lossy(key), lossy(value)))
+						.collect(),
+				})
+				.collect(),
+			replies: simulation
+				.replies
+				.into_iter()
+				.map(|reply| SimulatedReply { id: reply.id, result: lossy(reply.result) })
+				.collect(),
+		}
+	}
+}
+
+/// Exported contract, see `cosmwasm_exportContract`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ContractStateResponse<AccountId> {
+	pub code_hash: [u8; 32],
+	pub code_id: u64,
+	pub instantiator: AccountId,
+	pub admin: Option<AccountId>,
+	pub label: Vec<u8>,
+	/// Raw (unhashed) keys and values
+	pub storage: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+impl<AccountId> From<ContractState<AccountId>> for ContractStateResponse<AccountId> {
+	fn from(state: ContractState<AccountId>) -> Self {
+		Self {
+			code_hash: state.code_hash,
+			code_id: state.code_id,
+			instantiator: state.instantiator,
+			admin: state.admin,
+			label: state.label,
+			storage: state.storage,
+		}
+	}
+}
+
 #[allow(clippy::too_many_arguments)]
 mod cosmwasm_api {
 	use super::*;
@@ -43,6 +123,53 @@ mod cosmwasm_api {
 			message: Vec<u8>,
 			at: Option<BlockHash>,
 		) -> RpcResult<AccountId>;
+
+		/// Runs `instantiate` without persisting any changes.
+		#[method(name = "cosmwasm_simulateInstantiate")]
+		fn simulate_instantiate(
+			&self,
+			instantiator: AccountId,
+			code_id: u64,
+			salt: Vec<u8>,
+			admin: Option<AccountId>,
+			label: Vec<u8>,
+			funds: BTreeMap<AssetId, (Balance, bool)>,
+			gas: u64,
+			message: Vec<u8>,
+			at: Option<BlockHash>,
+		) -> RpcResult<SimulationResponse<AccountId>>;
+
+		/// Runs `execute` without persisting any changes.
+		#[method(name = "cosmwasm_simulateExecute")]
+		fn simulate_execute(
+			&self,
+			executor: AccountId,
+			contract: AccountId,
+			funds: BTreeMap<AssetId, (Balance, bool)>,
+			gas: u64,
+			message: Vec<u8>,
+			at: Option<BlockHash>,
+		) -> RpcResult<SimulationResponse<AccountId>>;
+
+		/// Runs `migrate` without persisting any changes.
+		#[method(name = "cosmwasm_simulateMigrate")]
+		fn simulate_migrate(
+			&self,
+			sender: AccountId,
+			contract: AccountId,
+			new_code_id: u64,
+			gas: u64,
+			message: Vec<u8>,
+			at: Option<BlockHash>,
+		) -> RpcResult<SimulationResponse<AccountId>>;
+
+		/// Exports metadata and the whole storage of a contract.
+		#[method(name = "cosmwasm_exportContract")]
+		fn export_contract(
+			&self,
+			contract: AccountId,
+			at: Option<BlockHash>,
+		) -> RpcResult<ContractStateResponse<AccountId>>;
 	}
 }
 
@@ -117,4 +244,90 @@ where
 		runtime_api_result
 			.map_err(|e| runtime_error_into_rpc_error(String::from_utf8_lossy(e.as_ref())))
 	}
+
+	fn simulate_instantiate(
+		&self,
+		instantiator: AccountId,
+		code_id: u64,
+		salt: Vec<u8>,
+		admin: Option<AccountId>,
+		label: Vec<u8>,
+		funds: BTreeMap<AssetId, (Balance, bool)>,
+		gas: u64,
+		message: Vec<u8>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<SimulationResponse<AccountId>> {
+		self.simulate(
+			instantiator,
+			SimulateMsg::Instantiate { code_id, salt, admin, label, funds, message },
+			gas,
+			at,
+		)
+	}
+
+	fn simulate_execute(
+		&self,
+		executor: AccountId,
+		contract: AccountId,
+		funds: BTreeMap<AssetId, (Balance, bool)>,
+		gas: u64,
+		message: Vec<u8>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<SimulationResponse<AccountId>> {
+		self.simulate(executor, SimulateMsg::Execute { contract, funds, message }, gas, at)
+	}
+
+	fn simulate_migrate(
+		&self,
+		sender: AccountId,
+		contract: AccountId,
+		new_code_id: u64,
+		gas: u64,
+		message: Vec<u8>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<SimulationResponse<AccountId>> {
+		self.simulate(sender, SimulateMsg::Migrate { contract, new_code_id, message }, gas, at)
+	}
+
+	fn export_contract(
+		&self,
+		contract: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<ContractStateResponse<AccountId>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let runtime_api_result =
+			api.export_contract(at, contract).map_err(runtime_error_into_rpc_error)?;
+		runtime_api_result
+			.map(Into::into)
+			.map_err(|e| runtime_error_into_rpc_error(String::from_utf8_lossy(e.as_ref())))
+	}
+}
+
+impl<C, Block, AccountId, AssetId, Balance, Error>
+	Cosmwasm<C, (Block, AccountId, AssetId, Balance, Error)>
+where
+	Block: BlockT,
+	AccountId: Codec,
+	AssetId: Codec,
+	Balance: Codec,
+	Error: Codec + AsRef<[u8]>,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: CosmwasmRuntimeApi<Block, AccountId, AssetId, Balance, Error>,
+{
+	fn simulate(
+		&self,
+		sender: AccountId,
+		message: SimulateMsg<AccountId, AssetId, Balance>,
+		gas: u64,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<SimulationResponse<AccountId>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let runtime_api_result =
+			api.simulate(at, sender, message, gas).map_err(runtime_error_into_rpc_error)?;
+		runtime_api_result
+			.map(Into::into)
+			.map_err(|e| runtime_error_into_rpc_error(String::from_utf8_lossy(e.as_ref())))
+	}
 }
@@ -2,13 +2,79 @@
 #![allow(clippy::too_many_arguments)]
 #![allow(clippy::unnecessary_mut_passed)]
 
-use codec::Codec;
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
 use sp_std::collections::btree_map::BTreeMap;
 #[cfg(not(feature = "std"))]
 use sp_std::vec::Vec;
 
+/// Message to simulate against current state.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub enum SimulateMsg<AccountId, AssetId, Balance> {
+	Instantiate {
+		code_id: u64,
+		salt: Vec<u8>,
+		admin: Option<AccountId>,
+		label: Vec<u8>,
+		funds: BTreeMap<AssetId, (Balance, bool)>,
+		message: Vec<u8>,
+	},
+	Execute {
+		contract: AccountId,
+		funds: BTreeMap<AssetId, (Balance, bool)>,
+		message: Vec<u8>,
+	},
+	Migrate {
+		contract: AccountId,
+		new_code_id: u64,
+		message: Vec<u8>,
+	},
+}
+
+/// Event emitted by contracts during simulation.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct SimulatedEvent {
+	pub ty: Vec<u8>,
+	pub attributes: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+/// Submessage reply delivered to a contract during simulation.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct SimulatedReply {
+	pub id: u64,
+	/// JSON encoded `SubMsgResult`
+	pub result: Vec<u8>,
+}
+
+/// Outcome of a simulated message, none of its changes are persisted.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct Simulation<AccountId> {
+	pub gas_used: u64,
+	/// Address of the contract, if instantiated
+	pub contract: Option<AccountId>,
+	/// `Response` data
+	pub data: Option<Vec<u8>>,
+	pub events: Vec<SimulatedEvent>,
+	pub replies: Vec<SimulatedReply>,
+}
+
+/// Full state of a contract, used to move a contract between chains.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct ContractState<AccountId> {
+	/// Hash of the pristine code, the code must be uploaded on the importing chain.
+	pub code_hash: [u8; 32],
+	/// Code id on the exporting chain
+	pub code_id: u64,
+	pub instantiator: AccountId,
+	pub admin: Option<AccountId>,
+	pub label: Vec<u8>,
+	/// Raw (unhashed) keys and values
+	pub storage: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
 // Cosmwasm Runtime API declaration.
 sp_api::decl_runtime_apis! {
+	#[api_version(3)]
 	pub trait CosmwasmRuntimeApi<AccountId, AssetId, Balance, Error>
 	where
 		AccountId: Codec,
@@ -32,5 +98,17 @@ sp_api::decl_runtime_apis! {
 			gas: u64,
 			message: Vec<u8>,
 		) -> Result<AccountId, Error>;
+
+		/// Runs `message` as sent by `sender` and rolls back all changes.
+		#[api_version(2)]
+		fn simulate(
+			sender: AccountId,
+			message: SimulateMsg<AccountId, AssetId, Balance>,
+			gas: u64,
+		) -> Result<Simulation<AccountId>, Error>;
+
+		/// Full state of `contract`, see [`ContractState`].
+		#[api_version(3)]
+		fn export_contract(contract: AccountId) -> Result<ContractState<AccountId>, Error>;
 	}
 }
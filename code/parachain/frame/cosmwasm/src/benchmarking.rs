@@ -22,7 +22,7 @@ use cosmwasm_vm_wasmi::code_gen::{
 	self, Function, FunctionBuilder, Table, WasmModule, INDEX_OF_USER_DEFINED_FNS,
 };
 use entrypoint::*;
-use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, BenchmarkError};
 use frame_support::traits::{fungible, fungibles, fungibles::Mutate, Get};
 use frame_system::RawOrigin;
 use hex_literal::hex;
@@ -438,6 +438,80 @@ benchmarks! {
 		assert_eq!(ContractToInfo::<T>::get(&contract).expect("test").admin, Some(new_admin));
 	}
 
+	freeze_contract {
+		let origin = create_funded_account::<T>("origin");
+		let contract = create_instantiated_contract::<T>(origin);
+		let governance = T::GovernanceWasmOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(governance, contract.clone())
+	verify {
+		assert!(FrozenContracts::<T>::contains_key(&contract));
+	}
+
+	unfreeze_contract {
+		let origin = create_funded_account::<T>("origin");
+		let contract = create_instantiated_contract::<T>(origin);
+		FrozenContracts::<T>::insert(&contract, ());
+		let governance = T::GovernanceWasmOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(governance, contract.clone())
+	verify {
+		assert!(!FrozenContracts::<T>::contains_key(&contract));
+	}
+
+	update_instantiate_config {
+		let origin = create_funded_account::<T>("origin");
+		let _ = create_instantiated_contract::<T>(origin.clone());
+	}: _(RawOrigin::Signed(origin.clone()), 1, AccessConfig::OnlyAddress(origin.clone()))
+	verify {
+		assert_eq!(CodeIdToAccess::<T>::get(1), Some(AccessConfig::OnlyAddress(origin)));
+	}
+
+	pin_code {
+		let origin = create_funded_account::<T>("origin");
+		let _ = create_instantiated_contract::<T>(origin);
+		// Force the reinstrumentation path.
+		CodeIdToInfo::<T>::mutate(1, |info| {
+			if let Some(info) = info {
+				info.instrumentation_version = INSTRUMENTATION_VERSION - 1;
+			}
+		});
+		let governance = T::GovernanceWasmOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(governance, 1)
+	verify {
+		assert!(PinnedCode::<T>::contains_key(1));
+	}
+
+	unpin_code {
+		PinnedCode::<T>::insert(1, ());
+		let governance = T::GovernanceWasmOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(governance, 1)
+	verify {
+		assert!(!PinnedCode::<T>::contains_key(1));
+	}
+
+	import_contract {
+		let n in 0..1024;
+		let origin = create_funded_account::<T>("origin");
+		let contract = create_instantiated_contract::<T>(origin);
+		let mut state = Cosmwasm::<T>::do_export_contract(contract).expect("test");
+		state.storage = (0..n).map(|i| (i.to_le_bytes().to_vec(), vec![0xCA; 32])).collect();
+		let imported: T::AccountId = account("imported", 0, 0xCAFEBABE);
+		let governance = T::GovernanceWasmOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(governance, imported.clone(), state)
+	verify {
+		assert!(ContractToInfo::<T>::contains_key(&imported));
+	}
+
+	import_contract_storage {
+		let n in 0..1024;
+		let origin = create_funded_account::<T>("origin");
+		let contract = create_instantiated_contract::<T>(origin);
+		let entries = (0..n).map(|i| (i.to_le_bytes().to_vec(), vec![0xCA; 32])).collect::<Vec<_>>();
+		let governance = T::GovernanceWasmOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(governance, contract.clone(), entries)
+	verify {
+		assert!(Cosmwasm::<T>::do_export_contract(contract).expect("test").storage.len() >= n as usize);
+	}
+
 	db_read {
 		let sender = create_funded_account::<T>("origin");
 		let contract = create_instantiated_contract::<T>(sender.clone());
@@ -499,7 +573,7 @@ benchmarks! {
 		let mut vm = Cosmwasm::<T>::cosmwasm_new_vm(unsafe { &mut *shared_vm.get() }, sender, contract, vec![]).expect("test");
 		Cosmwasm::<T>::do_db_write(vm.0.data_mut(), "hello".as_bytes(), "world".as_bytes()).expect("test");
 	}: {
-		Cosmwasm::<T>::do_db_remove(vm.0.data_mut(), "hello".as_bytes());
+		Cosmwasm::<T>::do_db_remove(vm.0.data_mut(), "hello".as_bytes()).expect("test");
 	}
 
 	balance {
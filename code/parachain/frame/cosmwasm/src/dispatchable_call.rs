@@ -6,7 +6,10 @@ use crate::{
 };
 use alloc::vec::Vec;
 use core::marker::PhantomData;
-use cosmwasm_std::Coin;
+use cosmwasm_std::{
+	Binary, Coin, ContractResult, CosmosMsg, Empty, Event, QueryRequest, Response, SubMsg, WasmMsg,
+	WasmQuery,
+};
 use cosmwasm_vm::{
 	executor::{cosmwasm_call, AsFunctionName},
 	system::{
@@ -49,9 +52,29 @@ impl<I, O, T: Config> DispatchableCall<I, O, T> {
 			From<CosmwasmVMError<T>> + Into<CosmwasmVMError<T>>,
 		I: AsFunctionName,
 	{
-		Pallet::<T>::top_level_dispatch(
+		let (contract, entrypoint) = (self.contract.clone(), self.entrypoint);
+		let (output, data, events) = self.top_level_run(shared, funds, message)?;
+		Pallet::<T>::deposit_dispatch_events(contract, entrypoint, data, events);
+		Ok(output)
+	}
+
+	/// Same as [`Self::top_level_call`], but response data and events are returned instead of
+	/// being deposited.
+	pub(crate) fn top_level_run(
+		self,
+		shared: &mut CosmwasmVMShared,
+		funds: FundsOf<T>,
+		message: ContractMessageOf<T>,
+	) -> Result<(O, Option<Binary>, Vec<Event>), CosmwasmVMError<T>>
+	where
+		for<'x> OwnedWasmiVM<DefaultCosmwasmVM<'x, T>>:
+			CosmwasmCallVM<I> + CosmwasmDynamicVM<I> + StargateCosmwasmCallVM,
+		for<'x> VmErrorOf<OwnedWasmiVM<DefaultCosmwasmVM<'x, T>>>:
+			From<CosmwasmVMError<T>> + Into<CosmwasmVMError<T>>,
+		I: AsFunctionName,
+	{
+		let (data, events) = Pallet::<T>::top_level_dispatch(
 			shared,
-			self.entrypoint,
 			self.sender,
 			self.contract,
 			funds,
@@ -63,11 +86,12 @@ impl<I, O, T: Config> DispatchableCall<I, O, T> {
 						ContractBackend::Pallet { .. } =>
 							T::PalletHook::execute(vm, self.entrypoint, message),
 					}
+					.and_then(|result| route_stargate::<T, _>(result).map_err(Into::into))
 				})
 				.map_err(Into::into)
 			},
 		)?;
-		Ok(self.output)
+		Ok((self.output, data, events))
 	}
 
 	/// Continue the execution by running an entrypoint. This is used for running
@@ -108,9 +132,63 @@ impl<I, O, T: Config> DispatchableCall<I, O, T> {
 						ContractBackend::Pallet { .. } =>
 							T::PalletHook::execute(vm, self.entrypoint, message),
 					}
+					.and_then(|result| route_stargate::<T, _>(result).map_err(Into::into))
 				})
 				.map_err(Into::into)
 			},
 		)
 	}
 }
+
+/// Send the `CosmosMsg::Stargate` messages of `result` to the
+/// [`PalletHook::stargate_gateway`], the VM is not able to dispatch them by itself.
+///
+/// Messages are left untouched when there is no gateway.
+pub(crate) fn route_stargate<T: Config, C>(
+	result: ContractResult<Response<C>>,
+) -> Result<ContractResult<Response<C>>, CosmwasmVMError<T>> {
+	let Some(gateway) = T::PalletHook::stargate_gateway() else { return Ok(result) };
+	let mut response = match result {
+		ContractResult::Ok(response) => response,
+		err => return Ok(err),
+	};
+	let gateway = Pallet::<T>::account_to_cosmwasm_addr(gateway);
+	for SubMsg { msg, .. } in response.messages.iter_mut() {
+		if let CosmosMsg::Stargate { type_url, value } = msg {
+			let stargate = CosmosMsg::<Empty>::Stargate {
+				type_url: core::mem::take(type_url),
+				value: core::mem::take(value),
+			};
+			*msg = CosmosMsg::Wasm(WasmMsg::Execute {
+				contract_addr: gateway.clone(),
+				msg: serde_json::to_vec(&stargate)
+					.map_err(|_| CosmwasmVMError::<T>::ExecuteSerialize)?
+					.into(),
+				funds: Vec::new(),
+			});
+		}
+	}
+	Ok(ContractResult::Ok(response))
+}
+
+/// Send `QueryRequest::Stargate` to the [`PalletHook::stargate_gateway`] as a smart query, the
+/// VM is not able to answer it by itself.
+///
+/// The query is left untouched when there is no gateway.
+pub(crate) fn route_stargate_query<T: Config>(
+	request: QueryRequest<Empty>,
+) -> Result<QueryRequest<Empty>, CosmwasmVMError<T>> {
+	let Some(gateway) = T::PalletHook::stargate_gateway() else { return Ok(request) };
+	match request {
+		QueryRequest::Stargate { path, data } => {
+			let stargate = QueryRequest::<Empty>::Stargate { path, data };
+			Ok(QueryRequest::Wasm(WasmQuery::Smart {
+				contract_addr: Pallet::<T>::account_to_cosmwasm_addr(gateway),
+				msg: serde_json::to_vec(&stargate)
+					.map_err(|_| CosmwasmVMError::<T>::QuerySerialize)?
+					.into(),
+			}))
+		},
+		request => Ok(request),
+	}
+}
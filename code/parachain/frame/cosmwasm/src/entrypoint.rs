@@ -26,6 +26,7 @@ pub(crate) fn setup_instantiate_call<T: Config>(
 	let code_hash = CodeIdToInfo::<T>::get(code_id)
 		.ok_or(Error::<T>::CodeNotFound)?
 		.pristine_code_hash;
+	Pallet::<T>::ensure_can_instantiate(code_id, &instantiator)?;
 	let contract = Pallet::<T>::derive_contract_address(&instantiator, salt, &code_hash)?;
 	// Make sure that contract address does not already exist
 	ensure!(Pallet::<T>::contract_exists(&contract).is_err(), Error::<T>::ContractAlreadyExists);
@@ -61,6 +62,7 @@ pub(crate) fn setup_execute_call<T: Config>(
 	executor: AccountIdOf<T>,
 	contract: AccountIdOf<T>,
 ) -> Result<DispatchableCall<ExecuteCall, (), T>, Error<T>> {
+	Pallet::<T>::ensure_not_frozen(&contract)?;
 	Ok(DispatchableCall {
 		entrypoint: EntryPoint::Execute,
 		sender: executor,
@@ -78,6 +80,7 @@ pub(crate) fn setup_reply_call<T: Config>(
 	executor: AccountIdOf<T>,
 	contract: AccountIdOf<T>,
 ) -> Result<DispatchableCall<ReplyCall, (), T>, Error<T>> {
+	Pallet::<T>::ensure_not_frozen(&contract)?;
 	Ok(DispatchableCall {
 		entrypoint: EntryPoint::Reply,
 		sender: executor,
@@ -284,6 +284,7 @@ impl<T: Config> Router<T> {
 		I::Output: serde::de::DeserializeOwned + ReadLimit + DeserializeLimit,
 		M: serde::Serialize,
 	{
+		<Pallet<T>>::ensure_not_frozen(&contract)?;
 		<Pallet<T>>::sub_level_dispatch(shared, relayer, contract, Default::default(), |mut vm| {
 			match vm.0.data().contract_runtime {
 				ContractBackend::CosmWasm { .. } =>
@@ -320,6 +321,7 @@ impl<T: Config> Router<T> {
 		I: AsFunctionName + AsEntryName,
 		M: serde::Serialize,
 	{
+		<Pallet<T>>::ensure_not_frozen(&contract)?;
 		<Pallet<T>>::sub_level_dispatch(
 			shared,
 			relayer,
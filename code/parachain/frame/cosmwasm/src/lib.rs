@@ -83,6 +83,9 @@ use alloc::{
 	string::String,
 };
 use composable_support::abstractions::utils::increment::Increment;
+use cosmwasm_runtime_api::{
+	ContractState, SimulateMsg, SimulatedEvent, SimulatedReply, Simulation,
+};
 use cosmwasm_std::{
 	Addr, Attribute as CosmwasmEventAttribute, Binary as CosmwasmBinary, BlockInfo,
 	CodeInfoResponse, Coin, ContractInfo as CosmwasmContractInfo, ContractInfoResponse, Env,
@@ -109,7 +112,7 @@ use frame_support::{
 	},
 	ReversibleStorageHasher, StorageHasher,
 };
-use sp_runtime::traits::SaturatedConversion;
+use sp_runtime::traits::{SaturatedConversion, Zero};
 use sp_std::vec::Vec;
 use wasmi::AsContext;
 use wasmi_validation::PlainValidator;
@@ -154,6 +157,13 @@ pub mod pallet {
 		Emitted { contract: AccountIdOf<T>, ty: Vec<u8>, attributes: Vec<(Vec<u8>, Vec<u8>)> },
 		Migrated { contract: AccountIdOf<T>, to: CosmwasmCodeId },
 		AdminUpdated { contract: AccountIdOf<T>, new_admin: Option<AccountIdOf<T>> },
+		ContractFrozen { contract: AccountIdOf<T> },
+		ContractUnfrozen { contract: AccountIdOf<T> },
+		InstantiateConfigUpdated { code_id: CosmwasmCodeId, config: AccessConfigOf<T> },
+		CodePinned { code_id: CosmwasmCodeId },
+		CodeUnpinned { code_id: CosmwasmCodeId },
+		ContractImported { contract: AccountIdOf<T>, code_id: CosmwasmCodeId },
+		ContractStorageImported { contract: AccountIdOf<T>, entries: u32 },
 	}
 
 	#[pallet::error]
@@ -204,6 +214,9 @@ pub mod pallet {
 		QueryDeserialize,
 		ExecuteSerialize,
 		Xcm,
+		ContractFrozen,
+		InstantiateNotAllowed,
+		NotEnoughFundsForStorageDeposit,
 	}
 
 	#[pallet::config]
@@ -296,6 +309,12 @@ pub mod pallet {
 		#[pallet::constant]
 		type ContractStorageByteReadPrice: Get<u32>;
 
+		/// Deposit per byte of contract storage.
+		/// The deposit is expressed in [`Self::NativeAsset`] and reserved from the contract on
+		/// `db_write`, it is released on `db_remove`. Zero disables storage deposits.
+		#[pallet::constant]
+		type ContractStorageByteDeposit: Get<u32>;
+
 		#[pallet::constant]
 		type WasmCostRules: Get<CostRules<Self>>;
 
@@ -345,6 +364,9 @@ pub mod pallet {
 		type UploadWasmOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
 		type ExecuteWasmOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Origin to freeze contracts, pin codes and override instantiate permissions
+		type GovernanceWasmOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 	}
 
 	#[pallet::pallet]
@@ -387,6 +409,27 @@ pub mod pallet {
 	pub(crate) type ContractToInfo<T: Config> =
 		StorageMap<_, Identity, AccountIdOf<T>, ContractInfoOf<T>>;
 
+	/// Contracts which can no longer be executed, replied to or called back by IBC.
+	/// Queries and migrations are still allowed so that the admin can ship a fix.
+	#[pallet::storage]
+	pub(crate) type FrozenContracts<T: Config> = StorageMap<_, Identity, AccountIdOf<T>, ()>;
+
+	/// Instantiate permission of a code. Codes without an entry can be instantiated by anyone.
+	#[pallet::storage]
+	pub(crate) type CodeIdToAccess<T: Config> =
+		StorageMap<_, Twox64Concat, CosmwasmCodeId, AccessConfigOf<T>>;
+
+	/// Codes kept instrumented with the current [`INSTRUMENTATION_VERSION`] and loaded without
+	/// paying the per byte read price.
+	#[pallet::storage]
+	pub(crate) type PinnedCode<T: Config> = StorageMap<_, Twox64Concat, CosmwasmCodeId, ()>;
+
+	/// Storage deposit currently reserved from a contract, see
+	/// [`Config::ContractStorageByteDeposit`].
+	#[pallet::storage]
+	pub(crate) type ContractToStorageDeposit<T: Config> =
+		StorageMap<_, Identity, AccountIdOf<T>, BalanceOf<T>, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub contracts: sp_std::vec::Vec<(T::AccountIdExtended, ContractCodeOf<T>)>,
@@ -574,6 +617,153 @@ pub mod pallet {
 			Self::deposit_event(Event::<T>::AdminUpdated { contract, new_admin });
 			Self::refund_gas(outcome, initial_gas, shared.gas.remaining())
 		}
+
+		/// Freeze a contract, rejecting any further `execute`, `reply` and IBC entrypoint call.
+		///
+		/// * Emits a `ContractFrozen` event on success.
+		///
+		/// # Arguments
+		///
+		/// * `origin` must be [`Config::GovernanceWasmOrigin`].
+		/// * `contract` the address of the contract to freeze.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::freeze_contract())]
+		pub fn freeze_contract(origin: OriginFor<T>, contract: AccountIdOf<T>) -> DispatchResult {
+			T::GovernanceWasmOrigin::ensure_origin(origin)?;
+			ensure!(ContractToInfo::<T>::contains_key(&contract), Error::<T>::ContractNotFound);
+			FrozenContracts::<T>::insert(&contract, ());
+			Self::deposit_event(Event::<T>::ContractFrozen { contract });
+			Ok(())
+		}
+
+		/// Unfreeze a previously frozen contract.
+		///
+		/// * Emits a `ContractUnfrozen` event on success.
+		///
+		/// # Arguments
+		///
+		/// * `origin` must be [`Config::GovernanceWasmOrigin`].
+		/// * `contract` the address of the contract to unfreeze.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::unfreeze_contract())]
+		pub fn unfreeze_contract(origin: OriginFor<T>, contract: AccountIdOf<T>) -> DispatchResult {
+			T::GovernanceWasmOrigin::ensure_origin(origin)?;
+			ensure!(FrozenContracts::<T>::contains_key(&contract), Error::<T>::ContractNotFound);
+			FrozenContracts::<T>::remove(&contract);
+			Self::deposit_event(Event::<T>::ContractUnfrozen { contract });
+			Ok(())
+		}
+
+		/// Update who is allowed to instantiate a code.
+		///
+		/// * Emits a `InstantiateConfigUpdated` event on success.
+		///
+		/// # Arguments
+		///
+		/// * `origin` must be [`Config::GovernanceWasmOrigin`] or the creator of the code.
+		/// * `code_id` the code to update.
+		/// * `config` the new instantiate permission.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::update_instantiate_config())]
+		pub fn update_instantiate_config(
+			origin: OriginFor<T>,
+			code_id: CosmwasmCodeId,
+			config: AccessConfigOf<T>,
+		) -> DispatchResult {
+			let code_info = CodeIdToInfo::<T>::get(code_id).ok_or(Error::<T>::CodeNotFound)?;
+			if let Err(origin) = T::GovernanceWasmOrigin::try_origin(origin) {
+				let who = ensure_signed(origin)?;
+				ensure!(code_info.creator == who, Error::<T>::NotAuthorized);
+			}
+			CodeIdToAccess::<T>::insert(code_id, &config);
+			Self::deposit_event(Event::<T>::InstantiateConfigUpdated { code_id, config });
+			Ok(())
+		}
+
+		/// Pin a code. The code is reinstrumented right away if needed and contracts using it
+		/// no longer pay [`Config::ContractStorageByteReadPrice`] for loading it.
+		///
+		/// * Emits a `CodePinned` event on success.
+		///
+		/// # Arguments
+		///
+		/// * `origin` must be [`Config::GovernanceWasmOrigin`].
+		/// * `code_id` the code to pin.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::pin_code())]
+		pub fn pin_code(origin: OriginFor<T>, code_id: CosmwasmCodeId) -> DispatchResult {
+			T::GovernanceWasmOrigin::ensure_origin(origin)?;
+			Self::do_check_for_reinstrumentation(code_id)?;
+			PinnedCode::<T>::insert(code_id, ());
+			Self::deposit_event(Event::<T>::CodePinned { code_id });
+			Ok(())
+		}
+
+		/// Unpin a previously pinned code.
+		///
+		/// * Emits a `CodeUnpinned` event on success.
+		///
+		/// # Arguments
+		///
+		/// * `origin` must be [`Config::GovernanceWasmOrigin`].
+		/// * `code_id` the code to unpin.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::unpin_code())]
+		pub fn unpin_code(origin: OriginFor<T>, code_id: CosmwasmCodeId) -> DispatchResult {
+			T::GovernanceWasmOrigin::ensure_origin(origin)?;
+			ensure!(PinnedCode::<T>::contains_key(code_id), Error::<T>::CodeNotFound);
+			PinnedCode::<T>::remove(code_id);
+			Self::deposit_event(Event::<T>::CodeUnpinned { code_id });
+			Ok(())
+		}
+
+		/// Import a contract exported from another chain, see [`export_contract`].
+		/// The code must have been uploaded beforehand, the contract keeps the provided address.
+		///
+		/// * Emits a `ContractImported` event on success.
+		///
+		/// # Arguments
+		///
+		/// * `origin` must be [`Config::GovernanceWasmOrigin`].
+		/// * `contract` the address of the imported contract.
+		/// * `state` the exported contract state.
+		#[transactional]
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::import_contract(state.storage.len() as u32))]
+		pub fn import_contract(
+			origin: OriginFor<T>,
+			contract: AccountIdOf<T>,
+			state: ContractStateOf<T>,
+		) -> DispatchResult {
+			T::GovernanceWasmOrigin::ensure_origin(origin)?;
+			Self::do_import_contract(contract, state)
+		}
+
+		/// Append storage entries to a previously imported contract, allowing big states to be
+		/// imported over multiple blocks.
+		///
+		/// * Emits a `ContractStorageImported` event on success.
+		///
+		/// # Arguments
+		///
+		/// * `origin` must be [`Config::GovernanceWasmOrigin`].
+		/// * `contract` the imported contract.
+		/// * `entries` raw (unhashed) keys and values.
+		#[transactional]
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::import_contract_storage(entries.len() as u32))]
+		pub fn import_contract_storage(
+			origin: OriginFor<T>,
+			contract: AccountIdOf<T>,
+			entries: Vec<(Vec<u8>, Vec<u8>)>,
+		) -> DispatchResult {
+			T::GovernanceWasmOrigin::ensure_origin(origin)?;
+			let info = Self::contract_info(&contract)?;
+			let count = entries.len() as u32;
+			Self::do_import_storage(&contract, &info.trie_id, entries)?;
+			Self::deposit_event(Event::<T>::ContractStorageImported { contract, entries: count });
+			Ok(())
+		}
 	}
 }
 
@@ -590,6 +780,7 @@ pub fn query<T: Config>(
 	let mut shared = Pallet::<T>::do_create_vm_shared(gas, InitialStorageMutability::ReadOnly);
 	let query_request = serde_json::from_slice(&query_request)
 		.map_err(|e| CosmwasmVMError::<T>::Rpc(e.to_string()))?;
+	let query_request = dispatchable_call::route_stargate_query::<T>(query_request)?;
 	Pallet::<T>::sub_level_dispatch(
 		&mut shared,
 		contract.clone(),
@@ -647,6 +838,34 @@ pub fn execute<T: Config>(
 	setup_execute_call(executor, contract)?.top_level_call(&mut shared, funds, message)
 }
 
+/// Simulates `message` sent by `sender` against current state.
+///
+/// Message is executed as by extrinsic, but inside a transaction which is always rolled back, so
+/// nothing is persisted and no events are deposited.
+///
+/// * `gas` the maximum gas to use, the gas actually used is returned.
+pub fn simulate<T: Config>(
+	sender: AccountIdOf<T>,
+	message: SimulateMsg<AccountIdOf<T>, AssetIdOf<T>, BalanceOf<T>>,
+	gas: u64,
+) -> Result<Simulation<AccountIdOf<T>>, CosmwasmVMError<T>> {
+	frame_support::storage::with_transaction(|| {
+		frame_support::storage::TransactionOutcome::Rollback(Ok::<_, DispatchError>(
+			Pallet::<T>::do_simulate(sender, message, gas),
+		))
+	})
+	.map_err(|e| CosmwasmVMError::<T>::Rpc(format!("{:?}", e)))?
+}
+
+/// Export the full state of a contract: its metadata and every key-value pair of its storage.
+///
+/// * `contract` the address of the contract to export.
+pub fn export_contract<T: Config>(
+	contract: AccountIdOf<T>,
+) -> Result<ContractState<AccountIdOf<T>>, CosmwasmVMError<T>> {
+	Pallet::<T>::do_export_contract(contract).map_err(Into::into)
+}
+
 impl<T: Config> VMPallet for T {
 	type VmError = CosmwasmVMError<T>;
 }
@@ -670,19 +889,19 @@ impl<T: Config> Pallet<T> {
 			depth: 0,
 			gas: Gas::new(T::MAX_FRAMES, gas),
 			cache: CosmwasmVMCache { code: Default::default() },
+			replies: None,
 		}
 	}
 
 	/// Wrapper around [`Pallet::<T>::cosmwasm_call`] for extrinsics.
-	/// It's purpose is converting the input and emit events based on the result.
+	/// It's purpose is converting the input, events are deposited by the caller.
 	pub(crate) fn top_level_dispatch<F>(
 		shared: &mut CosmwasmVMShared,
-		entrypoint: EntryPoint,
 		sender: AccountIdOf<T>,
 		contract: AccountIdOf<T>,
 		funds: FundsOf<T>,
 		call: F,
-	) -> Result<(), CosmwasmVMError<T>>
+	) -> Result<(Option<CosmwasmBinary>, Vec<CosmwasmEvent>), CosmwasmVMError<T>>
 	where
 		F: for<'x> FnOnce(
 			OwnedWasmiVM<DefaultCosmwasmVM<'x, T>>,
@@ -696,25 +915,31 @@ impl<T: Config> Pallet<T> {
 			.map(|(asset, (amount, _))| Self::native_asset_to_cosmwasm_asset(asset, amount))
 			.collect::<Vec<_>>();
 
-		Self::sub_level_dispatch(shared, sender, contract.clone(), cosmwasm_funds, call).map(
-			|(data, events)| {
-				for CosmwasmEvent { ty, attributes, .. } in events {
-					Self::deposit_event(Event::<T>::Emitted {
-						contract: contract.clone(),
-						ty: ty.into(),
-						attributes: attributes
-							.into_iter()
-							.map(|CosmwasmEventAttribute { key, value }| (key.into(), value.into()))
-							.collect::<Vec<_>>(),
-					});
-				}
-				Self::deposit_event(Event::<T>::Executed {
-					contract,
-					entrypoint,
-					data: data.map(Into::into),
-				});
-			},
-		)
+		Self::sub_level_dispatch(shared, sender, contract, cosmwasm_funds, call)
+	}
+
+	/// Emit events of a successful top level call.
+	pub(crate) fn deposit_dispatch_events(
+		contract: AccountIdOf<T>,
+		entrypoint: EntryPoint,
+		data: Option<CosmwasmBinary>,
+		events: Vec<CosmwasmEvent>,
+	) {
+		for CosmwasmEvent { ty, attributes, .. } in events {
+			Self::deposit_event(Event::<T>::Emitted {
+				contract: contract.clone(),
+				ty: ty.into(),
+				attributes: attributes
+					.into_iter()
+					.map(|CosmwasmEventAttribute { key, value }| (key.into(), value.into()))
+					.collect::<Vec<_>>(),
+			});
+		}
+		Self::deposit_event(Event::<T>::Executed {
+			contract,
+			entrypoint,
+			data: data.map(Into::into),
+		});
 	}
 
 	/// Low-level cosmwasm call over the VM. Transfers the `funds` before calling the callback.
@@ -842,6 +1067,22 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Ensure that the contract has not been frozen by governance.
+	pub(crate) fn ensure_not_frozen(contract: &AccountIdOf<T>) -> Result<(), Error<T>> {
+		ensure!(!FrozenContracts::<T>::contains_key(contract), Error::<T>::ContractFrozen);
+		Ok(())
+	}
+
+	/// Ensure that `who` is allowed to instantiate `code_id`.
+	pub(crate) fn ensure_can_instantiate(
+		code_id: CosmwasmCodeId,
+		who: &AccountIdOf<T>,
+	) -> Result<(), Error<T>> {
+		let allowed = CodeIdToAccess::<T>::get(code_id).map_or(true, |config| config.allows(who));
+		ensure!(allowed, Error::<T>::InstantiateNotAllowed);
+		Ok(())
+	}
+
 	/// Validate a wasm module against the defined limitations.
 	///
 	/// Notably
@@ -1022,6 +1263,78 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
+	fn do_simulate(
+		sender: AccountIdOf<T>,
+		message: SimulateMsg<AccountIdOf<T>, AssetIdOf<T>, BalanceOf<T>>,
+		gas: u64,
+	) -> Result<Simulation<AccountIdOf<T>>, CosmwasmVMError<T>> {
+		let to_funds = |funds: BTreeMap<AssetIdOf<T>, (BalanceOf<T>, KeepAlive)>| {
+			FundsOf::<T>::try_from(funds)
+				.map_err(|_| CosmwasmVMError::<T>::Rpc(String::from("'funds' is too large")))
+		};
+
+		let mut shared = Self::do_create_vm_shared(gas, InitialStorageMutability::ReadWrite);
+		shared.replies = Some(Vec::new());
+		let (contract, data, events) = match message {
+			SimulateMsg::Instantiate { code_id, salt, admin, label, funds, message } => {
+				let salt: ContractSaltOf<T> = Self::bounded(salt, "salt")?;
+				let (contract, data, events) = setup_instantiate_call(
+					sender,
+					code_id,
+					&salt,
+					admin,
+					Self::bounded(label, "label")?,
+				)?
+				.top_level_run(
+					&mut shared,
+					to_funds(funds)?,
+					Self::bounded(message, "message")?,
+				)?;
+				(Some(contract), data, events)
+			},
+			SimulateMsg::Execute { contract, funds, message } => {
+				let ((), data, events) = setup_execute_call(sender, contract)?.top_level_run(
+					&mut shared,
+					to_funds(funds)?,
+					Self::bounded(message, "message")?,
+				)?;
+				(None, data, events)
+			},
+			SimulateMsg::Migrate { contract, new_code_id, message } => {
+				let ((), data, events) =
+					setup_migrate_call(&mut shared, sender, contract, new_code_id, true)?
+						.top_level_run(
+							&mut shared,
+							Default::default(),
+							Self::bounded(message, "message")?,
+						)?;
+				(None, data, events)
+			},
+		};
+		Ok(Simulation {
+			gas_used: gas.saturating_sub(shared.gas.remaining()),
+			contract,
+			data: data.map(Into::into),
+			events: events
+				.into_iter()
+				.map(|CosmwasmEvent { ty, attributes, .. }| SimulatedEvent {
+					ty: ty.into(),
+					attributes: attributes
+						.into_iter()
+						.map(|CosmwasmEventAttribute { key, value }| (key.into(), value.into()))
+						.collect(),
+				})
+				.collect(),
+			replies: shared.replies.take().unwrap_or_default(),
+		})
+	}
+
+	fn bounded<B: TryFrom<Vec<u8>>>(value: Vec<u8>, name: &str) -> Result<B, CosmwasmVMError<T>> {
+		value
+			.try_into()
+			.map_err(|_| CosmwasmVMError::<T>::Rpc(format!("'{name}' is too large")))
+	}
+
 	fn do_update_admin(
 		shared: &mut CosmwasmVMShared,
 		who: AccountIdOf<T>,
@@ -1202,18 +1515,16 @@ impl<T: Config> Pallet<T> {
 		}))
 	}
 
-	/// Compute the gas required to overwrite the given entry.
+	/// Compute the gas required to overwrite an entry currently holding `current_len` bytes.
 	///
 	/// Equation: len(entry(trie, key)) - len(value)  x [`T::ContractStorageByteWritePrice`]
 	/// With minus saturating.
-	pub(crate) fn do_db_write_gas(trie_id: &ContractTrieIdOf<T>, key: &[u8], value: &[u8]) -> u64 {
-		Self::with_db_entry(trie_id, key, |child_trie, entry| {
-			let bytes_to_write = match storage::child::len(&child_trie, &entry) {
-				Some(current_len) => (value.len() as u32).saturating_sub(current_len),
-				None => value.len() as u32,
-			};
-			u64::from(bytes_to_write).saturating_mul(T::ContractStorageByteWritePrice::get().into())
-		})
+	pub(crate) fn do_db_write_gas(current_len: Option<u32>, value: &[u8]) -> u64 {
+		let bytes_to_write = match current_len {
+			Some(current_len) => (value.len() as u32).saturating_sub(current_len),
+			None => value.len() as u32,
+		};
+		u64::from(bytes_to_write).saturating_mul(T::ContractStorageByteWritePrice::get().into())
 	}
 
 	/// Write an entry from the executing contract, charging the according gas prior to actually
@@ -1223,11 +1534,20 @@ impl<T: Config> Pallet<T> {
 		key: &[u8],
 		value: &[u8],
 	) -> Result<(), CosmwasmVMError<T>> {
-		let price = Self::do_db_write_gas(&vm.contract_info.trie_id, key, value);
-		vm.charge_raw(price)?;
+		let current_len =
+			Self::with_db_entry(&vm.contract_info.trie_id, key, |child_trie, entry| {
+				storage::child::len(&child_trie, &entry)
+			});
+		vm.charge_raw(Self::do_db_write_gas(current_len, value))?;
 		Self::with_db_entry(&vm.contract_info.trie_id, key, |child_trie, entry| {
 			storage::child::put_raw(&child_trie, &entry, value)
 		});
+		let entry_len = |value_len: u32| value_len.saturating_add(key.len() as u32);
+		Self::do_update_storage_deposit(
+			vm.contract_address.as_ref(),
+			current_len.map_or(0, entry_len),
+			entry_len(value.len() as u32),
+		)?;
 		Ok(())
 	}
 
@@ -1263,14 +1583,136 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
-	/// Remove an entry from the executing contract, no gas is charged for this operation.
-	pub(crate) fn do_db_remove(vm: &mut DefaultCosmwasmVM<T>, key: &[u8]) {
+	/// Remove an entry from the executing contract. The storage deposit of the entry is released,
+	/// only the read needed to find that deposit is charged.
+	pub(crate) fn do_db_remove(
+		vm: &mut DefaultCosmwasmVM<T>,
+		key: &[u8],
+	) -> Result<(), CosmwasmVMError<T>> {
+		let old_len = if T::ContractStorageByteDeposit::get().is_zero() {
+			0
+		} else {
+			vm.charge_raw(T::DbWeight::get().reads(1).ref_time())?;
+			Self::do_db_entry_len(&vm.contract_info.trie_id, key)
+		};
 		let trie_id = &vm.contract_info.trie_id;
 		Self::with_db_entry(trie_id, key, |child_trie, entry| {
 			storage::child::kill(&child_trie, &entry)
+		});
+		Self::do_update_storage_deposit(vm.contract_address.as_ref(), old_len, 0)?;
+		Ok(())
+	}
+
+	/// Number of bytes accounted for the storage deposit of an entry, zero if missing.
+	///
+	/// Equation: len(key) + len(entry(trie, key))
+	pub(crate) fn do_db_entry_len(trie_id: &ContractTrieIdOf<T>, key: &[u8]) -> u32 {
+		Self::with_db_entry(trie_id, key, |child_trie, entry| {
+			storage::child::len(&child_trie, &entry)
+				.map_or(0, |len| len.saturating_add(key.len() as u32))
 		})
 	}
 
+	/// Reserve or release the storage deposit of `contract` for an entry going from `old_len`
+	/// to `new_len` bytes.
+	pub(crate) fn do_update_storage_deposit(
+		contract: &AccountIdOf<T>,
+		old_len: u32,
+		new_len: u32,
+	) -> Result<(), Error<T>> {
+		let byte_deposit: BalanceOf<T> = T::ContractStorageByteDeposit::get().into();
+		if byte_deposit.is_zero() {
+			return Ok(())
+		}
+		if new_len > old_len {
+			let deposit = byte_deposit.saturating_mul(new_len.saturating_sub(old_len).into());
+			T::NativeAsset::reserve(contract, deposit)
+				.map_err(|_| Error::<T>::NotEnoughFundsForStorageDeposit)?;
+			ContractToStorageDeposit::<T>::mutate(contract, |reserved| {
+				*reserved = reserved.saturating_add(deposit)
+			});
+		} else if old_len > new_len {
+			ContractToStorageDeposit::<T>::mutate(contract, |reserved| {
+				let deposit = byte_deposit
+					.saturating_mul(old_len.saturating_sub(new_len).into())
+					.min(*reserved);
+				let _ = T::NativeAsset::unreserve(contract, deposit);
+				*reserved = reserved.saturating_sub(deposit);
+			});
+		}
+		Ok(())
+	}
+
+	/// Extract the metadata and the whole storage of a contract.
+	pub(crate) fn do_export_contract(
+		contract: AccountIdOf<T>,
+	) -> Result<ContractState<AccountIdOf<T>>, Error<T>> {
+		let info = Self::contract_info(&contract)?;
+		let code_info = CodeIdToInfo::<T>::get(info.code_id).ok_or(Error::<T>::CodeNotFound)?;
+		let child_info = Self::contract_child_trie(info.trie_id.as_ref());
+		let mut storage = Vec::new();
+		let mut key = Vec::new();
+		while let Some(next_key) =
+			sp_io::default_child_storage::next_key(child_info.storage_key(), &key)
+		{
+			let value = storage::child::get_raw(&child_info, &next_key).unwrap_or_default();
+			storage.push((Blake2_128Concat::reverse(&next_key).to_vec(), value));
+			key = next_key;
+		}
+		Ok(ContractState {
+			code_hash: code_info.pristine_code_hash,
+			code_id: info.code_id,
+			instantiator: info.instantiator,
+			admin: info.admin,
+			label: info.label.into_inner(),
+			storage,
+		})
+	}
+
+	/// Create a contract from an exported state, without calling any entrypoint.
+	pub(crate) fn do_import_contract(
+		contract: AccountIdOf<T>,
+		ContractState { code_hash, instantiator, admin, label, storage, .. }: ContractStateOf<T>,
+	) -> DispatchResult {
+		ensure!(Self::contract_exists(&contract).is_err(), Error::<T>::ContractAlreadyExists);
+		let code_id = CodeHashToId::<T>::get(code_hash).ok_or(Error::<T>::CodeNotFound)?;
+		let label: ContractLabelOf<T> = label.try_into().map_err(|_| Error::<T>::LabelTooBig)?;
+		let nonce = CurrentNonce::<T>::increment().map_err(|_| Error::<T>::NonceOverflow)?;
+		let trie_id = Self::derive_contract_trie_id(&contract, nonce);
+		CodeIdToInfo::<T>::try_mutate(code_id, |entry| -> Result<(), Error<T>> {
+			let code_info = entry.as_mut().ok_or(Error::<T>::CodeNotFound)?;
+			code_info.refcount =
+				code_info.refcount.checked_add(1).ok_or(Error::<T>::RefcountOverflow)?;
+			Ok(())
+		})?;
+		ContractToInfo::<T>::insert(
+			&contract,
+			ContractInfoOf::<T> { instantiator, code_id, trie_id: trie_id.clone(), admin, label },
+		);
+		Self::do_import_storage(&contract, &trie_id, storage)?;
+		Self::deposit_event(Event::<T>::ContractImported { contract, code_id });
+		Ok(())
+	}
+
+	/// Write raw entries into a contract storage, reserving the according storage deposit.
+	pub(crate) fn do_import_storage(
+		contract: &AccountIdOf<T>,
+		trie_id: &ContractTrieIdOf<T>,
+		entries: Vec<(Vec<u8>, Vec<u8>)>,
+	) -> Result<(), Error<T>> {
+		let byte_deposit = T::ContractStorageByteDeposit::get();
+		for (key, value) in entries {
+			let old_len =
+				if byte_deposit.is_zero() { 0 } else { Self::do_db_entry_len(trie_id, &key) };
+			Self::with_db_entry(trie_id, &key, |child_trie, entry| {
+				storage::child::put_raw(&child_trie, &entry, &value)
+			});
+			let new_len = (key.len() as u32).saturating_add(value.len() as u32);
+			Self::do_update_storage_deposit(contract, old_len, new_len)?;
+		}
+		Ok(())
+	}
+
 	pub(crate) fn do_running_contract_meta(
 		vm: &mut DefaultCosmwasmVM<T>,
 	) -> CosmwasmContractMeta<CosmwasmAccount<T>> {
@@ -1293,7 +1735,8 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Execute a transfer of funds between two accounts.
-	pub(crate) fn do_transfer(
+	/// Transfers `funds` like `BankMsg::Send` does.
+	pub fn do_transfer(
 		from: &AccountIdOf<T>,
 		to: &AccountIdOf<T>,
 		funds: &[Coin],
@@ -1350,6 +1793,13 @@ impl<T: Config> Pallet<T> {
 		reply: cosmwasm_std::Reply,
 		event_handler: &mut dyn FnMut(cosmwasm_std::Event),
 	) -> Result<Option<cosmwasm_std::Binary>, CosmwasmVMError<T>> {
+		if let Some(replies) = vm.shared.replies.as_mut() {
+			replies.push(SimulatedReply {
+				id: reply.id,
+				result: serde_json::to_vec(&reply.result)
+					.map_err(|_| Error::<T>::FailedToSerialize)?,
+			});
+		}
 		setup_reply_call(
 			vm.contract_address.clone().into_inner(),
 			vm.contract_address.clone().into_inner(),
@@ -1394,7 +1844,7 @@ impl<T: Config> Pallet<T> {
 				};
 				let code_info = CodeIdToInfo::<T>::get(contract_info.code_id)
 					.ok_or(Error::<T>::CodeNotFound)?;
-				let pinned = vm.shared.cache.code.contains_key(&contract_info.code_id);
+				let pinned = PinnedCode::<T>::contains_key(contract_info.code_id);
 				(contract_info, code_info, pinned)
 			},
 		};
@@ -166,6 +166,10 @@ parameter_types! {
 	pub const PicassoNetworkId: u32 = 0;
 }
 
+parameter_types! {
+	pub AssetsRegistryTreasuryAccount: AccountId = AccountId32::new([0xff; 32]);
+}
+
 impl pallet_assets_registry::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type LocalAssetId = CurrencyId;
@@ -176,9 +180,18 @@ impl pallet_assets_registry::Config for Test {
 	type Balance = Balance;
 	type Convert = ConvertInto;
 	type NetworkId = PicassoNetworkId;
+	type Currency = Balances;
+	type ProposalDeposit = frame_support::traits::ConstU128<1_000>;
+	type MinProposedExistentialDeposit = frame_support::traits::ConstU128<1>;
+	type MinProposedFee = frame_support::traits::ConstU128<1>;
+	type ChallengePeriod = frame_support::traits::ConstU32<10>;
+	type RejectProposalOrigin = EnsureRoot<AccountId>;
+	type TreasuryAccount = AssetsRegistryTreasuryAccount;
+	type PoolId = u128;
 }
 
 impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = ();
 	type NativeAssetId = NativeAssetId;
 	type AssetId = CurrencyId;
@@ -187,7 +200,12 @@ impl pallet_assets::Config for Test {
 	type NativeCurrency = Balances;
 	type WeightInfo = ();
 	type AdminOrigin = EnsureRoot<AccountId>;
+	type FreezeOrigin = EnsureRoot<AccountId>;
 	type CurrencyValidator = Valid;
+	type RecurringPaymentDeposit = frame_support::traits::ConstU128<1>;
+	type MaxRecurringPaymentsPerPayer = frame_support::traits::ConstU32<16>;
+	type MinRecurringPaymentInterval = frame_support::traits::ConstU32<1>;
+	type MaxRecurringPaymentFailures = frame_support::traits::ConstU32<3>;
 }
 
 pub struct Valid;
@@ -242,6 +260,7 @@ parameter_types! {
 	pub const CodeStorageByteDeposit: u32 = 1;
 	pub const ContractStorageByteReadPrice: u32 = 1;
 	pub const ContractStorageByteWritePrice: u32 = 1;
+	pub static ContractStorageByteDeposit: u32 = 0;
 	pub WasmCostRules: CostRules<Test> = Default::default();
 }
 
@@ -290,6 +309,7 @@ pub const MOCK_PALLET_CONTRACT_ADDRESS_1: AccountIdOf<Test> = AccountId32::new([
 pub const MOCK_PALLET_CONTRACT_ADDRESS_2: AccountIdOf<Test> = AccountId32::new([120u8; 32]);
 
 pub const MOCK_PALLET_IBC_CONTRACT_ADDRESS: AccountIdOf<Test> = AccountId32::new([42; 32]);
+pub const MOCK_STARGATE_GATEWAY_ADDRESS: AccountIdOf<Test> = AccountId32::new([7; 32]);
 pub const MOCK_CONTRACT_IBC_EVENT_TYPE_1: &str = "ibc-magic";
 
 pub const MOCK_CONTRACT_EVENT_TYPE_1: &str = "magic";
@@ -455,6 +475,10 @@ impl PalletHook<Test> for MockHook {
 		}
 	}
 
+	fn stargate_gateway() -> Option<AccountIdOf<Test>> {
+		Some(MOCK_STARGATE_GATEWAY_ADDRESS)
+	}
+
 	fn query<'a>(
 		vm: &mut OwnedWasmiVM<CosmwasmVM<'a, Test>>,
 		_message: &[u8],
@@ -509,6 +533,7 @@ impl pallet_cosmwasm::Config for Test {
 	type CodeStorageByteDeposit = CodeStorageByteDeposit;
 	type ContractStorageByteReadPrice = ContractStorageByteReadPrice;
 	type ContractStorageByteWritePrice = ContractStorageByteWritePrice;
+	type ContractStorageByteDeposit = ContractStorageByteDeposit;
 	type UnixTime = Timestamp;
 	type WeightInfo = ();
 	type WasmCostRules = WasmCostRules;
@@ -519,6 +544,8 @@ impl pallet_cosmwasm::Config for Test {
 		EitherOfDiverse<EnsureSignedBy<RootAccount, AccountId>, EnsureRoot<AccountId>>;
 
 	type ExecuteWasmOrigin = EnsureSigned<AccountId>;
+
+	type GovernanceWasmOrigin = EnsureRoot<AccountId>;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -31,6 +31,15 @@ pub trait PalletHook<T: Config> {
 		vm: &mut OwnedWasmiVM<CosmwasmVM<'a, T>>,
 		message: &[u8],
 	) -> Result<ContractResult<QueryResponse>, VmErrorOf<OwnedWasmiVM<CosmwasmVM<'a, T>>>>;
+
+	/// Precompiled contract executing the `CosmosMsg::Stargate` messages of contract responses
+	/// and answering `QueryRequest::Stargate` queries.
+	///
+	/// The messages are sent to it as `{"stargate":{"type_url":..,"value":..}}` and the queries
+	/// as `{"stargate":{"path":..,"data":..}}`.
+	fn stargate_gateway() -> Option<AccountIdOf<T>> {
+		None
+	}
 }
 
 /// Default implementation, acting as identity (unhooked).
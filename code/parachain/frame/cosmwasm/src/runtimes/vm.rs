@@ -94,6 +94,7 @@ impl<T: Config> From<CosmwasmSubstrateError> for CosmwasmVMError<T> {
 			CosmwasmSubstrateError::ExecuteSerialize => Self::ExecuteSerialize,
 			CosmwasmSubstrateError::Ibc => Self::Ibc("CosmwasmSubstrate".to_string()),
 			CosmwasmSubstrateError::Xcm => Self::Ibc("CosmwasmSubstrate".to_string()),
+			CosmwasmSubstrateError::Stargate => Self::Precompile,
 		}
 	}
 }
@@ -181,6 +182,8 @@ pub struct CosmwasmVMShared {
 	pub gas: Gas,
 	/// Shared cache.
 	pub cache: CosmwasmVMCache,
+	/// Submessage replies, recorded only while simulating.
+	pub replies: Option<Vec<cosmwasm_runtime_api::SimulatedReply>>,
 }
 
 impl CosmwasmVMShared {
@@ -193,6 +196,7 @@ impl CosmwasmVMShared {
 			depth: 0,
 			gas: Gas::new(max_frames, initial_value),
 			cache: CosmwasmVMCache::default(),
+			replies: None,
 		}
 	}
 
@@ -501,8 +505,7 @@ impl<'a, T: Config + Send + Sync> VMBase for CosmwasmVM<'a, T> {
 		if self.shared.storage_is_readonly() {
 			Err(CosmwasmVMError::ReadOnlyViolation)
 		} else {
-			Pallet::<T>::do_db_remove(self, &key);
-			Ok(())
+			Pallet::<T>::do_db_remove(self, &key)
 		}
 	}
 
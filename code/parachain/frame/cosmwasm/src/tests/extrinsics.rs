@@ -2,13 +2,15 @@
 
 use super::{helpers::*, *};
 use crate::{
-	types::{CodeInfoOf, ContractCodeOf, ContractLabelOf},
-	CodeHashToId, CodeIdToInfo, CodeIdentifier, Config, CosmwasmAccount, CurrentCodeId,
+	entrypoint::setup_execute_call,
+	types::{AccessConfig, CodeInfoOf, ContractCodeOf, ContractLabelOf},
+	CodeHashToId, CodeIdToInfo, CodeIdentifier, Config, CosmwasmAccount, CurrentCodeId, Error,
 	InstrumentedCode, Pallet as Cosmwasm, PristineCode, INSTRUMENTATION_VERSION,
 };
 use cosmwasm_std::instantiate2_address;
 use cosmwasm_vm::vm::VMBase;
 use cosmwasm_vm_wasmi::code_gen;
+use frame_support::traits::fungibles::Inspect;
 use frame_system::RawOrigin;
 use sha2::{Digest, Sha256};
 use sp_runtime::{traits::Convert, AccountId32};
@@ -289,3 +291,217 @@ fn update_admin() {
 		.is_err());
 	})
 }
+
+#[test]
+fn freeze_contract() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		crate::mock::Timestamp::set_timestamp(1);
+		let mut shared_vm = create_vm();
+		let origin = create_funded_account("origin");
+		let contract = create_instantiated_contract(&mut shared_vm, origin.clone());
+
+		// 1. Only governance can freeze a contract.
+		assert!(Cosmwasm::<Test>::freeze_contract(
+			RawOrigin::Signed(origin.clone()).into(),
+			contract.clone()
+		)
+		.is_err());
+		Cosmwasm::<Test>::freeze_contract(RawOrigin::Root.into(), contract.clone()).unwrap();
+
+		// 2. A frozen contract can't be executed.
+		assert_eq!(
+			setup_execute_call::<Test>(origin.clone(), contract.clone()).err(),
+			Some(Error::<Test>::ContractFrozen)
+		);
+
+		// 3. Execution is possible again once unfrozen.
+		Cosmwasm::<Test>::unfreeze_contract(RawOrigin::Root.into(), contract.clone()).unwrap();
+		assert!(setup_execute_call::<Test>(origin, contract).is_ok());
+	})
+}
+
+#[test]
+fn update_instantiate_config() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		crate::mock::Timestamp::set_timestamp(1);
+		let origin = create_funded_account("origin");
+		let other = create_funded_account("other");
+
+		let wasm_module: code_gen::WasmModule =
+			code_gen::ModuleDefinition::new(Default::default(), 10, None).unwrap().into();
+		let code: ContractCodeOf<Test> = wasm_module.code.try_into().unwrap();
+		Cosmwasm::<Test>::do_upload(&origin, code).unwrap();
+
+		// 1. Anyone can instantiate by default.
+		assert!(Cosmwasm::<Test>::ensure_can_instantiate(1, &other).is_ok());
+
+		// 2. Only the creator or governance can update the permission.
+		assert!(Cosmwasm::<Test>::update_instantiate_config(
+			RawOrigin::Signed(other.clone()).into(),
+			1,
+			AccessConfig::Nobody
+		)
+		.is_err());
+		Cosmwasm::<Test>::update_instantiate_config(
+			RawOrigin::Signed(origin.clone()).into(),
+			1,
+			AccessConfig::OnlyAddress(origin.clone()),
+		)
+		.unwrap();
+		assert_eq!(
+			Cosmwasm::<Test>::ensure_can_instantiate(1, &other),
+			Err(Error::<Test>::InstantiateNotAllowed)
+		);
+		assert!(Cosmwasm::<Test>::ensure_can_instantiate(1, &origin).is_ok());
+
+		// 3. Governance can forbid instantiation altogether.
+		Cosmwasm::<Test>::update_instantiate_config(
+			RawOrigin::Root.into(),
+			1,
+			AccessConfig::Nobody,
+		)
+		.unwrap();
+		assert_eq!(
+			Cosmwasm::<Test>::ensure_can_instantiate(1, &origin),
+			Err(Error::<Test>::InstantiateNotAllowed)
+		);
+	})
+}
+
+#[test]
+fn pin_code() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		crate::mock::Timestamp::set_timestamp(1);
+		let origin = create_funded_account("origin");
+
+		let wasm_module: code_gen::WasmModule =
+			code_gen::ModuleDefinition::new(Default::default(), 10, None).unwrap().into();
+		let code: ContractCodeOf<Test> = wasm_module.code.try_into().unwrap();
+		Cosmwasm::<Test>::do_upload(&origin, code).unwrap();
+
+		// 1. Loading code does not charge gas.
+		let mut shared_vm = create_vm();
+		let gas = shared_vm.gas.remaining();
+		Cosmwasm::<Test>::get_code_from_cache(&mut shared_vm, 1).unwrap();
+		assert_eq!(shared_vm.gas.remaining(), gas);
+
+		// 2. Only governance can pin a code.
+		CodeIdToInfo::<Test>::mutate(1, |info| {
+			info.as_mut().unwrap().instrumentation_version = INSTRUMENTATION_VERSION - 1
+		});
+		assert!(Cosmwasm::<Test>::pin_code(RawOrigin::Signed(origin).into(), 1).is_err());
+		Cosmwasm::<Test>::pin_code(RawOrigin::Root.into(), 1).unwrap();
+
+		// 3. Pinned code is instrumented with the current version.
+		assert_eq!(
+			CodeIdToInfo::<Test>::get(1).unwrap().instrumentation_version,
+			INSTRUMENTATION_VERSION
+		);
+
+		Cosmwasm::<Test>::unpin_code(RawOrigin::Root.into(), 1).unwrap();
+		assert!(Cosmwasm::<Test>::unpin_code(RawOrigin::Root.into(), 1).is_err());
+	})
+}
+
+#[test]
+fn export_import_contract() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		crate::mock::Timestamp::set_timestamp(1);
+		let mut shared_vm = create_vm();
+		let origin = create_funded_account("origin");
+		let contract = create_instantiated_contract(&mut shared_vm, origin.clone());
+		{
+			let mut vm =
+				Cosmwasm::<Test>::cosmwasm_new_vm(&mut shared_vm, origin, contract.clone(), vec![])
+					.unwrap();
+			vm.db_write(b"hello".to_vec(), b"world".to_vec()).unwrap();
+			vm.db_write(b"foo".to_vec(), b"bar".to_vec()).unwrap();
+		}
+
+		// 1. Export contains the raw keys and the contract metadata.
+		let state = crate::export_contract::<Test>(contract.clone()).unwrap();
+		assert!(state.storage.contains(&(b"hello".to_vec(), b"world".to_vec())));
+		assert!(state.storage.contains(&(b"foo".to_vec(), b"bar".to_vec())));
+		assert_eq!(state.code_id, 1);
+
+		// 2. Only governance can import and the address must be free.
+		let imported = create_funded_account("imported");
+		assert!(Cosmwasm::<Test>::import_contract(
+			RawOrigin::Signed(imported.clone()).into(),
+			imported.clone(),
+			state.clone()
+		)
+		.is_err());
+		assert_eq!(
+			Cosmwasm::<Test>::import_contract(
+				RawOrigin::Root.into(),
+				contract.clone(),
+				state.clone()
+			),
+			Err(Error::<Test>::ContractAlreadyExists.into())
+		);
+
+		// 3. Imported contract has the same state and references the same code.
+		Cosmwasm::<Test>::import_contract(RawOrigin::Root.into(), imported.clone(), state.clone())
+			.unwrap();
+		assert_eq!(crate::export_contract::<Test>(imported.clone()).unwrap(), state);
+		assert_eq!(CodeIdToInfo::<Test>::get(1).unwrap().refcount, 2);
+
+		// 4. Additional entries can be appended.
+		Cosmwasm::<Test>::import_contract_storage(
+			RawOrigin::Root.into(),
+			imported.clone(),
+			vec![(b"baz".to_vec(), b"qux".to_vec())],
+		)
+		.unwrap();
+		assert!(crate::export_contract::<Test>(imported)
+			.unwrap()
+			.storage
+			.contains(&(b"baz".to_vec(), b"qux".to_vec())));
+	})
+}
+
+#[test]
+fn simulate_instantiate() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		crate::mock::Timestamp::set_timestamp(1);
+		let origin = create_funded_account("origin");
+
+		let wasm_module: code_gen::WasmModule =
+			code_gen::ModuleDefinition::new(Default::default(), 10, None).unwrap().into();
+		let code: ContractCodeOf<Test> = wasm_module.code.try_into().unwrap();
+		Cosmwasm::<Test>::do_upload(&origin, code).unwrap();
+
+		let mut funds = create_funds(vec![&origin]);
+		funds.get_mut(&1.into()).unwrap().0 = COMMON_AMOUNT_1;
+		let balance_before = <Test as Config>::Assets::balance(1.into(), &origin);
+
+		let simulation = crate::simulate::<Test>(
+			origin.clone(),
+			cosmwasm_runtime_api::SimulateMsg::Instantiate {
+				code_id: 1,
+				salt: COMMON_SALT.to_vec(),
+				admin: Some(origin.clone()),
+				label: COMMON_LABEL.as_bytes().to_vec(),
+				funds: funds.into_inner(),
+				message: b"{}".to_vec(),
+			},
+			u64::MAX,
+		)
+		.unwrap();
+
+		// 1. Address of the would be contract and the gas used are reported.
+		let contract = simulation.contract.unwrap();
+		assert!(simulation.gas_used > 0);
+
+		// 2. Nothing is persisted.
+		assert!(Cosmwasm::<Test>::contract_info(&contract).is_err());
+		assert_eq!(CodeIdToInfo::<Test>::get(1).unwrap().refcount, 0);
+		assert_eq!(<Test as Config>::Assets::balance(1.into(), &origin), balance_before);
+	})
+}
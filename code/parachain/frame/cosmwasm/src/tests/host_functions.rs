@@ -9,6 +9,7 @@ use cosmwasm_std::{CodeInfoResponse, Coin, ContractInfoResponse, Order};
 use cosmwasm_vm::{system::CosmwasmContractMeta, vm::VMBase};
 use cosmwasm_vm_wasmi::code_gen;
 use frame_benchmarking::account;
+use frame_support::traits::{fungible, Get, ReservableCurrency};
 use sp_runtime::AccountId32;
 
 #[test]
@@ -131,6 +132,59 @@ fn db_remove() {
 	})
 }
 
+#[test]
+fn db_storage_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		crate::mock::Timestamp::set_timestamp(1);
+		ContractStorageByteDeposit::set(10);
+		let mut shared_vm = create_vm();
+		let origin = create_funded_account("origin");
+		let contract = create_instantiated_contract(&mut shared_vm, origin.clone());
+		<pallet_balances::Pallet<Test> as fungible::Mutate<AccountId32>>::mint_into(
+			&contract, 1_000_000,
+		)
+		.unwrap();
+		let reserved = Balances::reserved_balance(&contract);
+
+		let key = b"Hello".to_vec();
+		let value = b"World!".to_vec();
+		let moon = b"Cool Moon!".to_vec();
+
+		let mut vm =
+			Cosmwasm::cosmwasm_new_vm(&mut shared_vm, origin, contract.clone(), vec![]).unwrap();
+
+		// 1. Reserves `(len(key) + len(value)) * ContractStorageByteDeposit` for a new entry.
+		vm.db_write(key.clone(), value.clone()).unwrap();
+		assert_eq!(
+			Balances::reserved_balance(&contract),
+			reserved + 10 * (key.len() + value.len()) as u128
+		);
+
+		// 2. Only the difference is reserved when an entry grows.
+		vm.db_write(key.clone(), moon.clone()).unwrap();
+		assert_eq!(
+			Balances::reserved_balance(&contract),
+			reserved + 10 * (key.len() + moon.len()) as u128
+		);
+
+		// 3. The whole deposit of the entry is refunded on removal, reading it is charged.
+		let gas = current_gas(&mut vm);
+		vm.db_remove(key.clone()).unwrap();
+		assert_eq!(Balances::reserved_balance(&contract), reserved);
+		assert_eq!(
+			charged_gas(&mut vm, gas),
+			<Test as Config>::WeightInfo::db_remove().ref_time() +
+				<Test as frame_system::Config>::DbWeight::get().reads(1).ref_time()
+		);
+
+		// 4. Fails to write if the contract can't pay the deposit.
+		ContractStorageByteDeposit::set(u32::MAX);
+		assert!(vm.db_write(key, vec![0; 1024]).is_err());
+		ContractStorageByteDeposit::set(0);
+	})
+}
+
 #[test]
 fn db_scan_next() {
 	new_test_ext().execute_with(|| {
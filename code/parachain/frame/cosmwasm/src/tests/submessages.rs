@@ -6,9 +6,13 @@ use super::{
 	*,
 };
 use crate::{mock::*, types::CodeIdentifier};
-use cosmwasm_std::{BankMsg, Response, WasmMsg};
+use common::cosmwasm::CosmwasmToSubstrateAccount;
+use cosmwasm_std::{
+	BankMsg, BankQuery, ContractResult, CosmosMsg, QueryRequest, Response, SubMsg, WasmMsg,
+	WasmQuery,
+};
 use frame_benchmarking::account;
-use sp_runtime::AccountId32;
+use sp_runtime::{traits::Convert, AccountId32};
 
 #[test]
 fn submessage_instantiate() {
@@ -202,3 +206,68 @@ fn submessage_bank_transfer() {
 		);
 	})
 }
+
+#[test]
+fn stargate_messages_are_routed_to_gateway() {
+	new_test_ext().execute_with(|| {
+		let stargate = CosmosMsg::Stargate {
+			type_url: "/cosmos.bank.v1beta1.MsgSend".into(),
+			value: vec![1, 2, 3].into(),
+		};
+		let response = Response::<cosmwasm_std::Empty>::new()
+			.add_submessage(SubMsg::reply_always(stargate.clone(), 42))
+			.add_message(BankMsg::Burn { funds: vec![] });
+
+		let ContractResult::Ok(routed) =
+			crate::dispatchable_call::route_stargate::<Test, _>(ContractResult::Ok(response))
+				.unwrap()
+		else {
+			panic!("response must stay successful")
+		};
+
+		// 1. Stargate message is executed by the gateway, keeping the submessage settings.
+		assert_eq!(
+			routed.messages[0],
+			SubMsg::reply_always(
+				WasmMsg::Execute {
+					contract_addr: CosmwasmToSubstrateAccount::convert(
+						MOCK_STARGATE_GATEWAY_ADDRESS
+					),
+					msg: serde_json::to_vec(&stargate).unwrap().into(),
+					funds: vec![],
+				},
+				42
+			)
+		);
+
+		// 2. Other messages are left untouched.
+		assert_eq!(routed.messages[1].msg, CosmosMsg::Bank(BankMsg::Burn { funds: vec![] }));
+	})
+}
+
+#[test]
+fn stargate_queries_are_routed_to_gateway() {
+	new_test_ext().execute_with(|| {
+		let stargate = QueryRequest::<cosmwasm_std::Empty>::Stargate {
+			path: "/cosmos.bank.v1beta1.Query/Balance".into(),
+			data: vec![1, 2, 3].into(),
+		};
+
+		// 1. Stargate query is answered by the gateway.
+		assert_eq!(
+			crate::dispatchable_call::route_stargate_query::<Test>(stargate.clone()).unwrap(),
+			QueryRequest::Wasm(WasmQuery::Smart {
+				contract_addr: CosmwasmToSubstrateAccount::convert(MOCK_STARGATE_GATEWAY_ADDRESS),
+				msg: serde_json::to_vec(&stargate).unwrap().into(),
+			})
+		);
+
+		// 2. Other queries are left untouched.
+		let balance =
+			QueryRequest::Bank(BankQuery::Balance { address: "address".into(), denom: "1".into() });
+		assert_eq!(
+			crate::dispatchable_call::route_stargate_query::<Test>(balance.clone()).unwrap(),
+			balance
+		);
+	})
+}
@@ -25,6 +25,8 @@ pub type AssetIdOf<T> = <T as Config>::AssetId;
 pub type BalanceOf<T> = <T as Config>::Balance;
 pub type ContractInfoOf<T> = ContractInfo<AccountIdOf<T>, ContractLabelOf<T>, ContractTrieIdOf<T>>;
 pub type CodeInfoOf<T> = CodeInfo<AccountIdOf<T>>;
+pub type AccessConfigOf<T> = AccessConfig<AccountIdOf<T>>;
+pub type ContractStateOf<T> = cosmwasm_runtime_api::ContractState<AccountIdOf<T>>;
 
 #[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, TypeInfo, Debug)]
 pub enum EntryPoint {
@@ -70,6 +72,27 @@ pub struct CodeInfo<AccountId> {
 	pub ibc_capable: bool,
 }
 
+/// Who is allowed to instantiate a code, mirrors wasmd's `AccessConfig`.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo, Debug)]
+pub enum AccessConfig<AccountId> {
+	/// Anyone can instantiate the code.
+	Everybody,
+	/// Only the given account can instantiate the code.
+	OnlyAddress(AccountId),
+	/// The code can no longer be instantiated.
+	Nobody,
+}
+
+impl<AccountId: PartialEq> AccessConfig<AccountId> {
+	pub fn allows(&self, who: &AccountId) -> bool {
+		match self {
+			AccessConfig::Everybody => true,
+			AccessConfig::OnlyAddress(address) => address == who,
+			AccessConfig::Nobody => false,
+		}
+	}
+}
+
 /// Contract metadata.
 #[derive(Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo, Debug)]
 pub struct ContractInfo<AccountId, Label, TrieId> {
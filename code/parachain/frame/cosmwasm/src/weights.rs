@@ -36,6 +36,13 @@ pub trait WeightInfo {
 	fn execute(n: u32, ) -> Weight;
 	fn migrate() -> Weight;
 	fn update_admin() -> Weight;
+	fn freeze_contract() -> Weight;
+	fn unfreeze_contract() -> Weight;
+	fn update_instantiate_config() -> Weight;
+	fn pin_code() -> Weight;
+	fn unpin_code() -> Weight;
+	fn import_contract(n: u32, ) -> Weight;
+	fn import_contract_storage(n: u32, ) -> Weight;
 	fn db_read() -> Weight;
 	fn db_read_other_contract() -> Weight;
 	fn db_write() -> Weight;
@@ -206,6 +213,65 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
+	// Storage: Cosmwasm ContractToInfo (r:1 w:0)
+	// Storage: Cosmwasm FrozenContracts (r:0 w:1)
+	fn freeze_contract() -> Weight {
+		Weight::from_parts(21_310_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Cosmwasm FrozenContracts (r:1 w:1)
+	fn unfreeze_contract() -> Weight {
+		Weight::from_parts(20_846_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Cosmwasm CodeIdToInfo (r:1 w:0)
+	// Storage: Cosmwasm CodeIdToAccess (r:0 w:1)
+	fn update_instantiate_config() -> Weight {
+		Weight::from_parts(22_102_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Cosmwasm CodeIdToInfo (r:1 w:1)
+	// Storage: Cosmwasm PristineCode (r:1 w:0)
+	// Storage: Cosmwasm InstrumentedCode (r:0 w:1)
+	// Storage: Cosmwasm PinnedCode (r:0 w:1)
+	fn pin_code() -> Weight {
+		Weight::from_parts(1_480_237_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: Cosmwasm PinnedCode (r:1 w:1)
+	fn unpin_code() -> Weight {
+		Weight::from_parts(19_734_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Cosmwasm ContractToInfo (r:1 w:1)
+	// Storage: Cosmwasm CodeHashToId (r:1 w:0)
+	// Storage: Cosmwasm CurrentNonce (r:1 w:1)
+	// Storage: Cosmwasm CodeIdToInfo (r:1 w:1)
+	// Storage: unknown [0x0000000000000000000000000000000000000000000000000000000000000000] (r:1 w:1)
+	fn import_contract(n: u32, ) -> Weight {
+		Weight::from_parts(48_716_000 as u64, 0)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(9_845_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Cosmwasm ContractToInfo (r:1 w:0)
+	// Storage: unknown [0x0000000000000000000000000000000000000000000000000000000000000000] (r:1 w:1)
+	fn import_contract_storage(n: u32, ) -> Weight {
+		Weight::from_parts(21_358_000 as u64, 0)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(9_845_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
 	// Storage: unknown [0xe9a804b2e527fd3601d2ffc0bb023cd668656c6c6f20776f726c64] (r:1 w:0)
 	fn db_read() -> Weight {
 		Weight::from_parts(13_244_000 as u64, 0)
@@ -825,6 +891,65 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5 as u64))
 			.saturating_add(RocksDbWeight::get().writes(2 as u64))
 	}
+	// Storage: Cosmwasm ContractToInfo (r:1 w:0)
+	// Storage: Cosmwasm FrozenContracts (r:0 w:1)
+	fn freeze_contract() -> Weight {
+		Weight::from_parts(21_310_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Cosmwasm FrozenContracts (r:1 w:1)
+	fn unfreeze_contract() -> Weight {
+		Weight::from_parts(20_846_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Cosmwasm CodeIdToInfo (r:1 w:0)
+	// Storage: Cosmwasm CodeIdToAccess (r:0 w:1)
+	fn update_instantiate_config() -> Weight {
+		Weight::from_parts(22_102_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Cosmwasm CodeIdToInfo (r:1 w:1)
+	// Storage: Cosmwasm PristineCode (r:1 w:0)
+	// Storage: Cosmwasm InstrumentedCode (r:0 w:1)
+	// Storage: Cosmwasm PinnedCode (r:0 w:1)
+	fn pin_code() -> Weight {
+		Weight::from_parts(1_480_237_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: Cosmwasm PinnedCode (r:1 w:1)
+	fn unpin_code() -> Weight {
+		Weight::from_parts(19_734_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Cosmwasm ContractToInfo (r:1 w:1)
+	// Storage: Cosmwasm CodeHashToId (r:1 w:0)
+	// Storage: Cosmwasm CurrentNonce (r:1 w:1)
+	// Storage: Cosmwasm CodeIdToInfo (r:1 w:1)
+	// Storage: unknown [0x0000000000000000000000000000000000000000000000000000000000000000] (r:1 w:1)
+	fn import_contract(n: u32, ) -> Weight {
+		Weight::from_parts(48_716_000 as u64, 0)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(9_845_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Cosmwasm ContractToInfo (r:1 w:0)
+	// Storage: unknown [0x0000000000000000000000000000000000000000000000000000000000000000] (r:1 w:1)
+	fn import_contract_storage(n: u32, ) -> Weight {
+		Weight::from_parts(21_358_000 as u64, 0)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(9_845_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
 	// Storage: unknown [0xe9a804b2e527fd3601d2ffc0bb023cd668656c6c6f20776f726c64] (r:1 w:0)
 	fn db_read() -> Weight {
 		Weight::from_parts(13_244_000 as u64, 0)
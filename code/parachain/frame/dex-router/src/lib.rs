@@ -28,16 +28,16 @@ pub mod pallet {
 	use crate::pool_id_pair;
 	use composable_support::math::safe::SafeArithmetic;
 	use composable_traits::{
-		defi::CurrencyPair,
-		dex::{Amm, AssetAmount, DexRoute, DexRouter, SwapResult},
+		defi::{CurrencyPair, Rate},
+		dex::{Amm, AssetAmount, DexRoute, DexRouter, SwapResult, Twap},
 	};
 	use core::fmt::Debug;
 	use frame_support::{pallet_prelude::*, transactional, PalletId};
 	use frame_system::{ensure_signed, pallet_prelude::OriginFor};
 	use sp_arithmetic::Permill;
 	use sp_runtime::{
-		traits::{CheckedAdd, One, Zero},
-		DispatchResult,
+		traits::{CheckedAdd, CheckedMul, One, Zero},
+		ArithmeticError, DispatchResult,
 	};
 	use sp_std::{
 		collections::{btree_map::BTreeMap, btree_set::BTreeSet},
@@ -81,11 +81,11 @@ pub mod pallet {
 			+ Zero
 			+ One;
 		type Pablo: Amm<
-			AssetId = Self::AssetId,
-			Balance = Self::Balance,
-			AccountId = Self::AccountId,
-			PoolId = Self::PoolId,
-		>;
+				AssetId = Self::AssetId,
+				Balance = Self::Balance,
+				AccountId = Self::AccountId,
+				PoolId = Self::PoolId,
+			> + Twap<AssetId = Self::AssetId, PoolId = Self::PoolId>;
 
 		/// Required origin to update route operations.
 		type UpdateRouteOrigin: EnsureOrigin<Self::RuntimeOrigin>;
@@ -368,6 +368,52 @@ pub mod pallet {
 		}
 	}
 
+	impl<T: Config> Twap for Pallet<T> {
+		type AssetId = T::AssetId;
+		type PoolId = CurrencyPair<T::AssetId>;
+
+		/// Time weighted average price of `asset_id` in the other asset of the pair, the product
+		/// of the TWAPs of the pools along the route.
+		fn get_twap(pool_id: Self::PoolId, asset_id: Self::AssetId) -> Result<Rate, DispatchError> {
+			let quote_asset_id = if asset_id == pool_id.base {
+				pool_id.quote
+			} else if asset_id == pool_id.quote {
+				pool_id.base
+			} else {
+				return Err(Error::<T>::NoRouteFound.into())
+			};
+			let (route, _reverse) = Self::get_route(pool_id).ok_or(Error::<T>::NoRouteFound)?;
+			let starts_with_asset = match route.first() {
+				Some(pool_id) => T::Pablo::assets(*pool_id)?.contains_key(&asset_id),
+				None => return Err(Error::<T>::NoRouteFound.into()),
+			};
+			let mut forward_iter;
+			let mut backward_iter;
+			let route_iter: &mut dyn Iterator<Item = &T::PoolId> = if starts_with_asset {
+				forward_iter = route.iter();
+				&mut forward_iter
+			} else {
+				backward_iter = route.iter().rev();
+				&mut backward_iter
+			};
+			let mut price = Rate::one();
+			let mut priced_asset_id = asset_id;
+			for pool_id in route_iter {
+				let out_asset_id = T::Pablo::assets(*pool_id)?
+					.keys()
+					.copied()
+					.find(|a| *a != priced_asset_id)
+					.ok_or(Error::<T>::NoRouteFound)?;
+				price = price
+					.checked_mul(&T::Pablo::get_twap(*pool_id, priced_asset_id)?)
+					.ok_or(ArithmeticError::Overflow)?;
+				priced_asset_id = out_asset_id;
+			}
+			ensure!(priced_asset_id == quote_asset_id, Error::<T>::NoRouteFound);
+			Ok(price)
+		}
+	}
+
 	impl<T: Config> Amm for Pallet<T> {
 		type AssetId = T::AssetId;
 		type Balance = T::Balance;
@@ -404,11 +450,49 @@ pub mod pallet {
 			calculate_with_fees: bool,
 		) -> Result<SwapResult<Self::AssetId, Self::Balance>, DispatchError> {
 			let (route, _reverse) = Self::get_route(pool_id).ok_or(Error::<T>::NoRouteFound)?;
-			match route[..] {
-				[pool_id] =>
-					T::Pablo::spot_price(pool_id, base_asset, quote_asset_id, calculate_with_fees),
-				_ => Err(Error::<T>::UnsupportedOperation.into()),
+			if let [pool_id] = route[..] {
+				return T::Pablo::spot_price(
+					pool_id,
+					base_asset,
+					quote_asset_id,
+					calculate_with_fees,
+				)
 			}
+			// Walk the route from the pool containing the base asset, pricing the output of each
+			// pool in the remaining asset of the next one.
+			let starts_with_base = match route.first() {
+				Some(pool_id) => T::Pablo::assets(*pool_id)?.contains_key(&base_asset.asset_id),
+				None => return Err(Error::<T>::NoRouteFound.into()),
+			};
+			let mut forward_iter;
+			let mut backward_iter;
+			let route_iter: &mut dyn Iterator<Item = &T::PoolId> = if starts_with_base {
+				forward_iter = route.iter();
+				&mut forward_iter
+			} else {
+				backward_iter = route.iter().rev();
+				&mut backward_iter
+			};
+			let mut swap_result: SwapResult<T::AssetId, T::Balance> = SwapResult {
+				value: base_asset,
+				fee: AssetAmount { asset_id: base_asset.asset_id, amount: T::Balance::zero() },
+			};
+			for pool_id in route_iter {
+				let assets = T::Pablo::assets(*pool_id)?;
+				let out_asset_id = assets
+					.keys()
+					.copied()
+					.find(|a| *a != swap_result.value.asset_id)
+					.ok_or(Error::<T>::NoRouteFound)?;
+				swap_result = T::Pablo::spot_price(
+					*pool_id,
+					swap_result.value,
+					out_asset_id,
+					calculate_with_fees,
+				)?;
+			}
+			ensure!(swap_result.value.asset_id == quote_asset_id, Error::<T>::NoRouteFound);
+			Ok(swap_result)
 		}
 
 		fn simulate_add_liquidity(
@@ -176,6 +176,10 @@ parameter_types! {
 	pub const PicassoNetworkId: u32 = 0;
 }
 
+parameter_types! {
+	pub const AssetsRegistryTreasuryAccount: AccountId = 0xffff_ffff;
+}
+
 impl pallet_assets_registry::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type LocalAssetId = AssetId;
@@ -186,6 +190,14 @@ impl pallet_assets_registry::Config for Test {
 	type Balance = Balance;
 	type Convert = ConvertInto;
 	type NetworkId = PicassoNetworkId;
+	type Currency = Balances;
+	type ProposalDeposit = frame_support::traits::ConstU128<1_000>;
+	type MinProposedExistentialDeposit = frame_support::traits::ConstU128<1>;
+	type MinProposedFee = frame_support::traits::ConstU128<1>;
+	type ChallengePeriod = frame_support::traits::ConstU64<10>;
+	type RejectProposalOrigin = EnsureRoot<AccountId>;
+	type TreasuryAccount = AssetsRegistryTreasuryAccount;
+	type PoolId = u128;
 }
 
 impl pallet_assets_transactor_router::Config for Test {
@@ -0,0 +1,36 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::{Codec, Decode, Encode};
+use sp_std::vec::Vec;
+
+/// Auction which was not filled, cancelled or closed yet.
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq)]
+pub struct OpenAuction<OrderId, AssetId, Balance> {
+	pub order_id: OrderId,
+	/// Asset on sale.
+	pub base_asset_id: AssetId,
+	/// Asset paid by takers.
+	pub quote_asset_id: AssetId,
+	/// Amount of `base_asset_id` left on sale.
+	pub amount: Balance,
+	/// Amount of `quote_asset_id` required to take all of `amount` at the current price.
+	pub price: Balance,
+	/// Unix time in seconds after which the auction can only be closed.
+	pub ends_at: u64,
+}
+
+// Dutch Auction Runtime API declaration. Implemented for each runtime at
+// `runtime/<runtime-name>/src/lib.rs`.
+sp_api::decl_runtime_apis! {
+	pub trait DutchAuctionRuntimeApi<OrderId, AssetId, Balance>
+	where
+		OrderId: Codec,
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// Auctions which can still be taken. Ended auctions waiting to be closed are left out.
+		fn open_auctions() -> Vec<OpenAuction<OrderId, AssetId, Balance>>;
+	}
+}
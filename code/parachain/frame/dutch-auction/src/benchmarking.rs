@@ -0,0 +1,83 @@
+use super::*;
+use crate::{models::AuctionConfiguration, Pallet as DutchAuction};
+use composable_traits::{
+	defi::{Ratio, Sell, Take},
+	time::{LinearDecrease, TimeReleaseFunction},
+};
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{
+	assert_ok,
+	traits::{fungibles::Mutate, Currency},
+};
+use frame_system::RawOrigin;
+use sp_runtime::{FixedPointNumber, Permill};
+
+const UNIT: u128 = 1_000_000_000_000;
+const BASE: u128 = 100;
+const QUOTE: u128 = 101;
+
+fn configuration<T: Config>() -> AuctionConfiguration {
+	AuctionConfiguration {
+		price_decay: TimeReleaseFunction::LinearDecrease(LinearDecrease { total: 60 }),
+		start_premium: Permill::from_percent(10),
+		duration: 60,
+		fallback: false,
+	}
+}
+
+fn sell<T: Config>() -> SellOf<T>
+where
+	T::Balance: From<u128>,
+	T::AssetId: From<u128>,
+{
+	Sell::new(BASE.into(), QUOTE.into(), (1_000 * UNIT).into(), Ratio::saturating_from_integer(2))
+}
+
+fn ask<T: Config>(seller: T::AccountId) -> T::OrderId
+where
+	T::Balance: From<u128>,
+	T::AssetId: From<u128>,
+{
+	assert_ok!(T::Assets::mint_into(BASE.into(), &seller, (1_000 * UNIT).into()));
+	T::Currency::make_free_balance_be(&seller, (1_000 * UNIT).into());
+	assert_ok!(DutchAuction::<T>::ask(
+		RawOrigin::Signed(seller).into(),
+		sell::<T>(),
+		configuration::<T>()
+	));
+	OrdersIndex::<T>::get()
+}
+
+benchmarks! {
+  where_clause { where T::Balance: From<u128>, T::AssetId: From<u128> }
+	ask {
+		let caller: T::AccountId = whitelisted_caller();
+		assert_ok!(T::Assets::mint_into(BASE.into(), &caller, (1_000 * UNIT).into()));
+		T::Currency::make_free_balance_be(&caller, (1_000 * UNIT).into());
+	}: _(RawOrigin::Signed(caller), sell::<T>(), configuration::<T>())
+
+	take {
+		let caller: T::AccountId = whitelisted_caller();
+		let order_id = ask::<T>(frame_benchmarking::account("seller", 0, 0));
+		assert_ok!(T::Assets::mint_into(QUOTE.into(), &caller, (10_000 * UNIT).into()));
+		let take = Take::new((1_000 * UNIT).into(), Ratio::saturating_from_integer(3));
+	}: _(RawOrigin::Signed(caller), order_id, take)
+
+	cancel {
+		let caller: T::AccountId = whitelisted_caller();
+		let order_id = ask::<T>(caller.clone());
+	}: _(RawOrigin::Signed(caller), order_id)
+
+	close {
+		let caller: T::AccountId = whitelisted_caller();
+		let order_id = ask::<T>(caller.clone());
+		SellOrders::<T>::mutate(order_id, |order| {
+			if let Some(order) = order {
+				order.started_at = 0;
+				order.configuration.duration = 0;
+			}
+		});
+	}: _(RawOrigin::Signed(caller), order_id)
+}
+
+impl_benchmark_test_suite!(DutchAuction, crate::mock::new_test_ext(), crate::mock::Test);
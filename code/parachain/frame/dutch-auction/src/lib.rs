@@ -0,0 +1,575 @@
+//! # Dutch Auction Pallet
+//!
+//! Sells assets through descending price auctions, implementing [`SellEngine`].
+//!
+//! ## Overview
+//!
+//! - A seller asks to sell an amount of a `base` asset for a `quote` asset at no less than the
+//!   limit of the order. The auction starts at a premium over the limit and the price decays
+//!   towards the limit following the [`TimeReleaseFunction`] of the order, either linearly or by
+//!   steps.
+//! - Anyone can `take` all or part of an order at the current price, paying the seller directly.
+//! - Once the auction ends, anyone can `close` it. If the order falls back to the router, what is
+//!   left unsold is swapped along the route of its pair in [`Config::Fallback`], at no less than
+//!   the limit. Otherwise, or if there is no such route, it is returned to the seller.
+//! - Orders added through `ask` reserve [`Config::OrderDeposit`] from the seller until they are
+//!   removed.
+//!
+//! Other pallets, like lending, sell through [`SellEngine`] and are notified of the proceeds and
+//! of what is returned unsold through [`Config::Callback`]. The runtime API lists open auctions
+//! for keepers.
+//!
+//! ## Interface
+//!
+//! - `ask`: put an amount of an asset on sale.
+//! - `take`: buy from an order at its current price.
+//! - `cancel`: the seller, or [`Config::CancelOrigin`], takes back what is left unsold.
+//! - `close`: settle an ended auction.
+#![cfg_attr(
+	not(test),
+	warn(
+		clippy::disallowed_methods,
+		clippy::disallowed_types,
+		clippy::todo,
+		clippy::unwrap_used,
+		clippy::panic
+	)
+)]
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(
+	bad_style,
+	bare_trait_objects,
+	improper_ctypes,
+	non_shorthand_field_patterns,
+	no_mangle_generic_items,
+	overflowing_literals,
+	path_statements,
+	patterns_in_fns_without_body,
+	private_in_public,
+	unconditional_recursion,
+	unused_allocation,
+	unused_comparisons,
+	unused_parens,
+	while_true,
+	trivial_casts,
+	trivial_numeric_casts,
+	unused_extern_crates
+)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarking;
+
+pub mod models;
+
+pub use crate::weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::{
+		models::{AuctionConfiguration, SellOrder},
+		WeightInfo,
+	};
+	use codec::FullCodec;
+	use composable_support::math::safe::{SafeAdd, SafeArithmetic, SafeMul, SafeSub};
+	use composable_traits::{
+		currency::{BalanceLike, MathBalance},
+		defi::{
+			CurrencyPair, DeFiEngine, OrderIdLike, Ratio, Sell, SellCallback, SellEngine, Take,
+		},
+		dex::{Amm, AssetAmount, DexRouter},
+		time::{
+			DurationSeconds, LinearDecrease, StairstepExponentialDecrease, TimeReleaseFunction,
+			Timestamp,
+		},
+	};
+	use core::fmt::Debug;
+	use frame_support::{
+		pallet_prelude::*,
+		storage::with_storage_layer,
+		traits::{
+			fungibles::{Inspect, Mutate},
+			tokens::Preservation,
+			ReservableCurrency, UnixTime,
+		},
+		transactional, PalletId,
+	};
+	use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+	use sp_arithmetic::Rounding;
+	use sp_runtime::{
+		helpers_128bit::multiply_by_rational_with_rounding,
+		traits::{AccountIdConversion, One, SaturatedConversion, Saturating, Zero},
+		ArithmeticError, FixedPointNumber, Permill,
+	};
+
+	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+	pub type AssetIdOf<T> = <T as Config>::AssetId;
+	pub type BalanceOf<T> = <T as Config>::Balance;
+	pub type SellOf<T> = Sell<AssetIdOf<T>, BalanceOf<T>>;
+	pub type SellOrderOf<T> = SellOrder<AccountIdOf<T>, AssetIdOf<T>, BalanceOf<T>>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		#[allow(missing_docs)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Type representing the unique ID of an asset.
+		type AssetId: FullCodec
+			+ MaxEncodedLen
+			+ Eq
+			+ PartialEq
+			+ Copy
+			+ Clone
+			+ MaybeSerializeDeserialize
+			+ Debug
+			+ Default
+			+ TypeInfo
+			+ Ord;
+
+		/// Type representing the Balance of an account.
+		type Balance: BalanceLike + MathBalance;
+
+		/// Type representing the unique ID of an order.
+		type OrderId: OrderIdLike + Zero + One + SafeArithmetic;
+
+		/// Type representing the unique ID of a pool along fallback routes.
+		type PoolId: FullCodec + MaxEncodedLen + TypeInfo + Debug + Clone + Eq;
+
+		/// Maximal number of pools along a fallback route.
+		type MaxHopsInRoute: Get<u32>;
+
+		/// Assets on sale and paid by takers.
+		type Assets: Mutate<AccountIdOf<Self>, Balance = BalanceOf<Self>, AssetId = AssetIdOf<Self>>
+			+ Inspect<AccountIdOf<Self>, Balance = BalanceOf<Self>, AssetId = AssetIdOf<Self>>;
+
+		/// Router selling what is left of ended auctions along the route of the pair of the
+		/// order, e.g. the dex router.
+		type Fallback: DexRouter<Self::AssetId, Self::PoolId, Self::Balance, Self::MaxHopsInRoute>
+			+ Amm<
+				AssetId = Self::AssetId,
+				Balance = Self::Balance,
+				AccountId = Self::AccountId,
+				PoolId = CurrencyPair<Self::AssetId>,
+			>;
+
+		/// Notified of the proceeds of orders and of what is returned unsold, e.g. lending
+		/// settling its liquidations.
+		type Callback: SellCallback<Self::OrderId, Self::Balance>;
+
+		/// Currency of the deposit reserved by orders added through `ask`.
+		type Currency: ReservableCurrency<Self::AccountId, Balance = Self::Balance>;
+
+		/// Deposit reserved from the seller by orders added through `ask`, returned when the
+		/// order is removed.
+		#[pallet::constant]
+		type OrderDeposit: Get<Self::Balance>;
+
+		/// Origin allowed to cancel any order, e.g. those of liquidations, whose seller can't
+		/// sign.
+		type CancelOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Time driving the price decay.
+		type UnixTime: UnixTime;
+
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Maximum duration of an auction.
+		#[pallet::constant]
+		type MaxAuctionDuration: Get<DurationSeconds>;
+
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		OrderAdded {
+			order_id: T::OrderId,
+			order: SellOrderOf<T>,
+		},
+		/// `amount` of the base asset bought for `quote_amount` of the quote asset.
+		OrderTaken {
+			order_id: T::OrderId,
+			taker: T::AccountId,
+			amount: T::Balance,
+			quote_amount: T::Balance,
+		},
+		/// The whole order was taken.
+		OrderFilled {
+			order_id: T::OrderId,
+		},
+		/// The `amount` left unsold was returned to the seller.
+		OrderCancelled {
+			order_id: T::OrderId,
+			amount: T::Balance,
+		},
+		/// The auction ended with `amount` left unsold. `proceeds` is the amount of quote asset
+		/// received by selling it to the fallback pool, if it was, otherwise it was returned to
+		/// the seller.
+		OrderClosed {
+			order_id: T::OrderId,
+			amount: T::Balance,
+			proceeds: Option<T::Balance>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		OrderNotFound,
+		/// The order has no amount or limit, or sells an asset for itself.
+		InvalidSell,
+		/// The duration or the price decay of the auction is out of bounds.
+		InvalidConfiguration,
+		/// The take has no amount or limit.
+		InvalidTake,
+		/// The current price is above the limit of the take.
+		TakeLimitBelowPrice,
+		NotOrderOwner,
+		/// The auction ended, it can only be closed.
+		AuctionEnded,
+		AuctionNotEnded,
+		/// The router has no route for the pair of the order.
+		NoFallbackRoute,
+	}
+
+	#[pallet::storage]
+	#[allow(clippy::disallowed_types)] // OrderId is ValueQuery with default 0
+	pub type OrdersIndex<T: Config> = StorageValue<_, T::OrderId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn sell_order)]
+	pub type SellOrders<T: Config> = StorageMap<_, Twox64Concat, T::OrderId, SellOrderOf<T>>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Put `order.take.amount` of `order.pair.base` on sale, for `order.pair.quote` at no less
+		/// than `order.take.limit` per unit. [`Config::OrderDeposit`] is reserved until the order
+		/// is removed.
+		///
+		/// Emits `OrderAdded` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::ask())]
+		pub fn ask(
+			origin: OriginFor<T>,
+			order: SellOf<T>,
+			configuration: AuctionConfiguration,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let deposit = T::OrderDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+			Self::add_order(&who, order, configuration, deposit)?;
+			Ok(())
+		}
+
+		/// Buy up to `take.amount` of the base asset of an order, at its current price as long
+		/// as it does not exceed `take.limit` per unit.
+		///
+		/// Emits `OrderTaken` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::take())]
+		pub fn take(
+			origin: OriginFor<T>,
+			order_id: T::OrderId,
+			take: Take<T::Balance>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			<Self as SellEngine<AuctionConfiguration>>::take(&who, order_id, take)
+		}
+
+		/// Remove an order, returning what is left unsold to the seller. Only the seller or
+		/// [`Config::CancelOrigin`] can cancel an order.
+		///
+		/// Emits `OrderCancelled` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::cancel())]
+		pub fn cancel(origin: OriginFor<T>, order_id: T::OrderId) -> DispatchResult {
+			let order = Self::order(&order_id)?;
+			if let Err(origin) = T::CancelOrigin::try_origin(origin) {
+				let who = ensure_signed(origin)?;
+				ensure!(order.from_to == who, Error::<T>::NotOrderOwner);
+			}
+			Self::remove_order(&order_id, &order);
+			let amount = order.order.take.amount;
+			Self::refund(&order_id, &order)?;
+			Self::deposit_event(Event::<T>::OrderCancelled { order_id, amount });
+			Ok(())
+		}
+
+		/// Settle an ended auction. What is left unsold is sold along the fallback route of the
+		/// pair of the order if the order falls back to the router and the route pays at least the
+		/// limit of the order, otherwise returned to the seller.
+		///
+		/// Emits `OrderClosed` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::close())]
+		pub fn close(origin: OriginFor<T>, order_id: T::OrderId) -> DispatchResult {
+			ensure_signed(origin)?;
+			let order = Self::order(&order_id)?;
+			ensure!(T::UnixTime::now().as_secs() >= order.ends_at(), Error::<T>::AuctionNotEnded);
+			Self::remove_order(&order_id, &order);
+			let amount = order.order.take.amount;
+			let proceeds = if order.configuration.fallback {
+				Self::sell_through_router(&order).ok()
+			} else {
+				None
+			};
+			match proceeds {
+				Some(proceeds) => {
+					T::Callback::on_sold(&order_id, proceeds)?;
+					T::Callback::on_closed(&order_id, Zero::zero())?;
+				},
+				None => Self::refund(&order_id, &order)?,
+			}
+			Self::deposit_event(Event::<T>::OrderClosed { order_id, amount, proceeds });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> DeFiEngine for Pallet<T> {
+		type MayBeAssetId = T::AssetId;
+		type Balance = T::Balance;
+		type AccountId = T::AccountId;
+	}
+
+	impl<T: Config> SellEngine<AuctionConfiguration> for Pallet<T> {
+		type OrderId = T::OrderId;
+
+		fn ask(
+			from_to: &Self::AccountId,
+			order: SellOf<T>,
+			configuration: AuctionConfiguration,
+		) -> Result<Self::OrderId, DispatchError> {
+			Self::add_order(from_to, order, configuration, Zero::zero())
+		}
+
+		#[transactional]
+		fn take(
+			from_to: &Self::AccountId,
+			order_id: Self::OrderId,
+			take: Take<Self::Balance>,
+		) -> DispatchResult {
+			ensure!(take.is_valid(), Error::<T>::InvalidTake);
+			let (amount, quote_amount, filled) =
+				SellOrders::<T>::try_mutate_exists(order_id, |maybe_order| {
+					let order = maybe_order.as_mut().ok_or(Error::<T>::OrderNotFound)?;
+					let now = T::UnixTime::now().as_secs();
+					ensure!(now < order.ends_at(), Error::<T>::AuctionEnded);
+					let price = Self::price(order, now)?;
+					ensure!(take.limit >= price, Error::<T>::TakeLimitBelowPrice);
+					let amount = take.amount.min(order.order.take.amount);
+					let quote_amount = Self::quote_amount(amount, price)?;
+					T::Assets::transfer(
+						order.order.pair.quote,
+						from_to,
+						&order.from_to,
+						quote_amount,
+						Preservation::Expendable,
+					)?;
+					T::Assets::transfer(
+						order.order.pair.base,
+						&Self::account_id(),
+						from_to,
+						amount,
+						Preservation::Expendable,
+					)?;
+					order.order.take.amount = order.order.take.amount.safe_sub(&amount)?;
+					let filled = order.order.take.amount.is_zero();
+					if filled {
+						T::Currency::unreserve(&order.from_to, order.deposit);
+						*maybe_order = None;
+					}
+					Ok::<_, DispatchError>((amount, quote_amount, filled))
+				})?;
+			T::Callback::on_sold(&order_id, quote_amount)?;
+			if filled {
+				T::Callback::on_closed(&order_id, Zero::zero())?;
+			}
+			Self::deposit_event(Event::<T>::OrderTaken {
+				order_id,
+				taker: from_to.clone(),
+				amount,
+				quote_amount,
+			});
+			if filled {
+				Self::deposit_event(Event::<T>::OrderFilled { order_id });
+			}
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Put `order` of `from_to` on sale, `deposit` having been reserved from it.
+		#[transactional]
+		fn add_order(
+			from_to: &T::AccountId,
+			order: SellOf<T>,
+			configuration: AuctionConfiguration,
+			deposit: T::Balance,
+		) -> Result<T::OrderId, DispatchError> {
+			Self::validate(&order, &configuration)?;
+			let order_id = OrdersIndex::<T>::try_mutate(|index| -> Result<_, DispatchError> {
+				*index = index.safe_add(&T::OrderId::one())?;
+				Ok(*index)
+			})?;
+			T::Assets::transfer(
+				order.pair.base,
+				from_to,
+				&Self::account_id(),
+				order.take.amount,
+				Preservation::Expendable,
+			)?;
+			let order = SellOrder {
+				from_to: from_to.clone(),
+				order,
+				configuration,
+				started_at: T::UnixTime::now().as_secs(),
+				deposit,
+			};
+			SellOrders::<T>::insert(order_id, order.clone());
+			Self::deposit_event(Event::<T>::OrderAdded { order_id, order });
+			Ok(order_id)
+		}
+
+		/// Account holding the assets on sale.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		fn order(order_id: &T::OrderId) -> Result<SellOrderOf<T>, DispatchError> {
+			SellOrders::<T>::get(order_id).ok_or_else(|| Error::<T>::OrderNotFound.into())
+		}
+
+		fn validate(order: &SellOf<T>, configuration: &AuctionConfiguration) -> DispatchResult {
+			ensure!(
+				order.is_valid() && order.pair.base != order.pair.quote,
+				Error::<T>::InvalidSell
+			);
+			ensure!(
+				!configuration.duration.is_zero() &&
+					configuration.duration <= T::MaxAuctionDuration::get(),
+				Error::<T>::InvalidConfiguration
+			);
+			let valid_decay = match &configuration.price_decay {
+				TimeReleaseFunction::LinearDecrease(LinearDecrease { total }) => !total.is_zero(),
+				TimeReleaseFunction::StairstepExponentialDecrease(
+					StairstepExponentialDecrease { step, cut },
+				) => !step.is_zero() && *cut < Permill::one(),
+			};
+			ensure!(valid_decay, Error::<T>::InvalidConfiguration);
+			Ok(())
+		}
+
+		/// Price of a unit of base asset in quote asset at `now`.
+		pub(crate) fn price(
+			order: &SellOrderOf<T>,
+			now: Timestamp,
+		) -> Result<Ratio, ArithmeticError> {
+			let limit = order.order.take.limit;
+			let premium = limit.safe_mul(&Ratio::from(order.configuration.start_premium))?;
+			let elapsed = now.saturating_sub(order.started_at);
+			let decay = match &order.configuration.price_decay {
+				TimeReleaseFunction::LinearDecrease(LinearDecrease { total }) =>
+					if elapsed >= *total {
+						Ratio::zero()
+					} else {
+						Ratio::checked_from_rational(total - elapsed, *total)
+							.ok_or(ArithmeticError::Overflow)?
+					},
+				TimeReleaseFunction::StairstepExponentialDecrease(
+					StairstepExponentialDecrease { step, cut },
+				) => Ratio::from(cut.saturating_pow((elapsed / step).saturated_into())),
+			};
+			limit.safe_add(&premium.safe_mul(&decay)?)
+		}
+
+		/// Amount of quote asset paid for `amount` of base asset at `price`, rounded up in favor
+		/// of the seller.
+		pub(crate) fn quote_amount(
+			amount: T::Balance,
+			price: Ratio,
+		) -> Result<T::Balance, ArithmeticError> {
+			let quote_amount = multiply_by_rational_with_rounding(
+				amount.into(),
+				price.into_inner(),
+				Ratio::DIV,
+				Rounding::Up,
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+			quote_amount.try_into().map_err(|_| ArithmeticError::Overflow)
+		}
+
+		/// Remove `order` and return its deposit.
+		fn remove_order(order_id: &T::OrderId, order: &SellOrderOf<T>) {
+			SellOrders::<T>::remove(order_id);
+			T::Currency::unreserve(&order.from_to, order.deposit);
+		}
+
+		/// Return what is left of `order` to the seller, notifying [`Config::Callback`].
+		fn refund(order_id: &T::OrderId, order: &SellOrderOf<T>) -> DispatchResult {
+			T::Assets::transfer(
+				order.order.pair.base,
+				&Self::account_id(),
+				&order.from_to,
+				order.order.take.amount,
+				Preservation::Expendable,
+			)?;
+			T::Callback::on_closed(order_id, order.order.take.amount)
+		}
+
+		/// Swap what is left of `order` along the route of its pair, paying the seller. Nothing
+		/// changes if there is no route or the route does not pay the limit of the order.
+		fn sell_through_router(order: &SellOrderOf<T>) -> Result<T::Balance, DispatchError> {
+			// Routers swap along the route of the pair of the received and the paid asset.
+			let pair = CurrencyPair::new(order.order.pair.quote, order.order.pair.base);
+			ensure!(T::Fallback::get_route(pair).is_some(), Error::<T>::NoFallbackRoute);
+			with_storage_layer(|| {
+				let account = Self::account_id();
+				let swapped = T::Fallback::do_swap(
+					&account,
+					pair,
+					AssetAmount::new(order.order.pair.base, order.order.take.amount),
+					AssetAmount::new(
+						order.order.pair.quote,
+						order.order.take.quote_limit_amount()?,
+					),
+					false,
+				)?;
+				T::Assets::transfer(
+					order.order.pair.quote,
+					&account,
+					&order.from_to,
+					swapped.value.amount,
+					Preservation::Expendable,
+				)?;
+				Ok(swapped.value.amount)
+			})
+		}
+	}
+}
+
+/// Open auctions with the amount of quote asset required to take them at the current price, used
+/// by the runtime API. Ended auctions can't be taken, only closed, so they are left out.
+pub fn open_auctions<T: Config>() -> sp_std::vec::Vec<(T::OrderId, SellOrderOf<T>, T::Balance)> {
+	use frame_support::traits::UnixTime;
+	let now = T::UnixTime::now().as_secs();
+	SellOrders::<T>::iter()
+		.filter(|(_, order)| now < order.ends_at())
+		.filter_map(|(order_id, order)| {
+			let price = Pallet::<T>::price(&order, now).ok()?;
+			let quote_amount = Pallet::<T>::quote_amount(order.order.take.amount, price).ok()?;
+			Some((order_id, order, quote_amount))
+		})
+		.collect()
+}
@@ -0,0 +1,334 @@
+#![cfg(test)]
+
+use crate as pallet_dutch_auction;
+use composable_traits::{
+	defi::CurrencyPair,
+	dex::{Amm, AssetAmount, DexRouter, SwapResult},
+};
+use frame_support::{
+	parameter_types,
+	traits::{
+		fungibles::Mutate,
+		tokens::{Fortitude, Precision},
+		ConstU128, ConstU32, ConstU64, Everything,
+	},
+	BoundedVec, PalletId,
+};
+use frame_system::EnsureRoot;
+use orml_traits::parameter_type_with_key;
+use sp_arithmetic::{traits::Zero, Permill};
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+	DispatchError,
+};
+use sp_std::{cell::RefCell, collections::btree_map::BTreeMap};
+
+pub type CurrencyId = u128;
+pub type BlockNumber = u64;
+pub type OrderId = u128;
+pub type PoolId = u128;
+
+pub const BTC: CurrencyId = 2;
+pub const USDT: CurrencyId = 3;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Tokens: orml_tokens,
+		Timestamp: pallet_timestamp,
+		DutchAuction: pallet_dutch_auction,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+pub type AccountId = u128;
+
+#[allow(dead_code)]
+pub static ALICE: AccountId = 1;
+#[allow(dead_code)]
+pub static BOB: AccountId = 2;
+#[allow(dead_code)]
+pub static CHARLIE: AccountId = 3;
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+pub type Balance = u128;
+pub type Amount = i128;
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type FreezeIdentifier = [u8; 8];
+	type HoldIdentifier = [u8; 8];
+	type MaxHolds = ConstU32<32>;
+	type MaxFreezes = ConstU32<32>;
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+		Zero::zero()
+	};
+}
+
+pub struct CurrencyHooks;
+impl orml_traits::currency::MutationHooks<AccountId, CurrencyId, Balance> for CurrencyHooks {
+	type OnDust = ();
+	type OnSlash = ();
+	type PreDeposit = ();
+	type PostDeposit = ();
+	type PreTransfer = ();
+	type PostTransfer = ();
+	type OnNewTokenAccount = ();
+	type OnKilledTokenAccount = ();
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = CurrencyId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type ReserveIdentifier = [u8; 8];
+	type MaxReserves = frame_support::traits::ConstU32<2>;
+	type DustRemovalWhitelist = Everything;
+	type CurrencyHooks = CurrencyHooks;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<5>;
+	type WeightInfo = ();
+}
+
+/// The only pool of [`MockRouter`].
+pub const POOL: PoolId = 1;
+
+thread_local! {
+	static POOL_PRICE: RefCell<Balance> = RefCell::new(1);
+	static ROUTED: RefCell<bool> = RefCell::new(true);
+}
+
+/// Routes BTC/USDT through [`POOL`], which swaps either asset for the other at a fixed price.
+pub struct MockRouter;
+
+impl MockRouter {
+	/// Set the amount of output asset received for a unit of input asset.
+	pub fn set_price(price: Balance) {
+		POOL_PRICE.with(|pool_price| *pool_price.borrow_mut() = price);
+	}
+
+	/// Add or remove the BTC/USDT route.
+	pub fn set_routed(routed: bool) {
+		ROUTED.with(|is_routed| *is_routed.borrow_mut() = routed);
+	}
+}
+
+impl DexRouter<CurrencyId, PoolId, Balance, ConstU32<4>> for MockRouter {
+	fn update_route(
+		_asset_pair: CurrencyPair<CurrencyId>,
+		_route: Option<BoundedVec<PoolId, ConstU32<4>>>,
+	) -> Result<(), DispatchError> {
+		Err(DispatchError::Other("not implemented"))
+	}
+
+	fn get_route(asset_pair: CurrencyPair<CurrencyId>) -> Option<(Vec<PoolId>, bool)> {
+		if !ROUTED.with(|routed| *routed.borrow()) {
+			return None
+		}
+		match (asset_pair.base, asset_pair.quote) {
+			(BTC, USDT) => Some((vec![POOL], false)),
+			(USDT, BTC) => Some((vec![POOL], true)),
+			_ => None,
+		}
+	}
+}
+
+impl Amm for MockRouter {
+	type AssetId = CurrencyId;
+	type Balance = Balance;
+	type AccountId = AccountId;
+	type PoolId = CurrencyPair<CurrencyId>;
+
+	fn pool_exists(pool_id: CurrencyPair<CurrencyId>) -> bool {
+		Self::get_route(pool_id).is_some()
+	}
+
+	fn assets(
+		_pool_id: CurrencyPair<CurrencyId>,
+	) -> Result<BTreeMap<CurrencyId, Permill>, DispatchError> {
+		Ok(BTreeMap::from([(BTC, Permill::from_percent(50)), (USDT, Permill::from_percent(50))]))
+	}
+
+	fn lp_token(_pool_id: CurrencyPair<CurrencyId>) -> Result<CurrencyId, DispatchError> {
+		Err(DispatchError::Other("not implemented"))
+	}
+
+	fn redeemable_assets_for_lp_tokens(
+		_pool_id: CurrencyPair<CurrencyId>,
+		_lp_amount: Balance,
+	) -> Result<BTreeMap<CurrencyId, Balance>, DispatchError> {
+		Err(DispatchError::Other("not implemented"))
+	}
+
+	fn simulate_add_liquidity(
+		_who: &AccountId,
+		_pool_id: CurrencyPair<CurrencyId>,
+		_amounts: BTreeMap<CurrencyId, Balance>,
+	) -> Result<Balance, DispatchError> {
+		Err(DispatchError::Other("not implemented"))
+	}
+
+	fn simulate_remove_liquidity(
+		_who: &AccountId,
+		_pool_id: CurrencyPair<CurrencyId>,
+		_lp_amount: Balance,
+		_min_amounts: BTreeMap<CurrencyId, Balance>,
+	) -> Result<BTreeMap<CurrencyId, Balance>, DispatchError> {
+		Err(DispatchError::Other("not implemented"))
+	}
+
+	fn spot_price(
+		_pool_id: CurrencyPair<CurrencyId>,
+		base_asset: AssetAmount<CurrencyId, Balance>,
+		quote_asset_id: CurrencyId,
+		_calculate_with_fees: bool,
+	) -> Result<SwapResult<CurrencyId, Balance>, DispatchError> {
+		let value = base_asset.amount * POOL_PRICE.with(|price| *price.borrow());
+		Ok(SwapResult::new(quote_asset_id, value, quote_asset_id, 0))
+	}
+
+	fn add_liquidity(
+		_who: &AccountId,
+		_pool_id: CurrencyPair<CurrencyId>,
+		_assets: BTreeMap<CurrencyId, Balance>,
+		_min_mint_amount: Balance,
+		_keep_alive: bool,
+	) -> Result<Balance, DispatchError> {
+		Err(DispatchError::Other("not implemented"))
+	}
+
+	fn remove_liquidity(
+		_who: &AccountId,
+		_pool_id: CurrencyPair<CurrencyId>,
+		_lp_amount: Balance,
+		_min_receive: BTreeMap<CurrencyId, Balance>,
+	) -> Result<BTreeMap<CurrencyId, Balance>, DispatchError> {
+		Err(DispatchError::Other("not implemented"))
+	}
+
+	fn do_buy(
+		_who: &AccountId,
+		_pool_id: CurrencyPair<CurrencyId>,
+		_in_asset_id: CurrencyId,
+		_out_asset: AssetAmount<CurrencyId, Balance>,
+		_keep_alive: bool,
+	) -> Result<SwapResult<CurrencyId, Balance>, DispatchError> {
+		Err(DispatchError::Other("not implemented"))
+	}
+
+	fn do_swap(
+		who: &AccountId,
+		pool_id: CurrencyPair<CurrencyId>,
+		in_asset: AssetAmount<CurrencyId, Balance>,
+		min_receive: AssetAmount<CurrencyId, Balance>,
+		_keep_alive: bool,
+	) -> Result<SwapResult<CurrencyId, Balance>, DispatchError> {
+		let swapped = Self::spot_price(pool_id, in_asset, min_receive.asset_id, false)?;
+		if swapped.value.amount < min_receive.amount {
+			return Err(DispatchError::Other("slippage"))
+		}
+		<Tokens as Mutate<AccountId>>::burn_from(
+			in_asset.asset_id,
+			who,
+			in_asset.amount,
+			Precision::Exact,
+			Fortitude::Polite,
+		)?;
+		<Tokens as Mutate<AccountId>>::mint_into(min_receive.asset_id, who, swapped.value.amount)?;
+		Ok(swapped)
+	}
+}
+
+parameter_types! {
+	pub const DutchAuctionPalletId: PalletId = PalletId(*b"dtch_ctn");
+	pub const MaxAuctionDuration: u64 = 3_600;
+	pub const OrderDeposit: Balance = 10;
+}
+
+impl pallet_dutch_auction::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = CurrencyId;
+	type Balance = Balance;
+	type OrderId = OrderId;
+	type PoolId = PoolId;
+	type MaxHopsInRoute = ConstU32<4>;
+	type Assets = Tokens;
+	type Fallback = MockRouter;
+	type Callback = ();
+	type Currency = Balances;
+	type OrderDeposit = OrderDeposit;
+	type CancelOrigin = EnsureRoot<AccountId>;
+	type UnixTime = Timestamp;
+	type PalletId = DutchAuctionPalletId;
+	type MaxAuctionDuration = MaxAuctionDuration;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::default()
+		.build_storage::<Test>()
+		.expect("success")
+		.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
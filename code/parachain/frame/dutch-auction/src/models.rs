@@ -0,0 +1,40 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use composable_traits::{
+	defi::Sell,
+	time::{DurationSeconds, TimeReleaseFunction, Timestamp},
+};
+use scale_info::TypeInfo;
+use sp_runtime::Permill;
+
+/// How an auction runs, chosen by the seller.
+#[derive(Clone, Encode, Decode, MaxEncodedLen, TypeInfo, Debug, PartialEq, Eq)]
+pub struct AuctionConfiguration {
+	/// Decay of the price from its starting value down to the limit of the order.
+	pub price_decay: TimeReleaseFunction,
+	/// Premium over the limit of the order at which the auction starts.
+	pub start_premium: Permill,
+	/// Seconds during which the order can be taken.
+	pub duration: DurationSeconds,
+	/// Whether what is left of the order once the auction ends is sold along the route of its
+	/// pair in [`Config::Fallback`](crate::Config::Fallback), at no less than its limit.
+	pub fallback: bool,
+}
+
+#[derive(Clone, Encode, Decode, MaxEncodedLen, TypeInfo, Debug, PartialEq, Eq)]
+pub struct SellOrder<AccountId, AssetId, Balance> {
+	/// Seller, paid by takers and refunded what is left unsold.
+	pub from_to: AccountId,
+	/// Amount left on sale and minimal price of the order.
+	pub order: Sell<AssetId, Balance>,
+	pub configuration: AuctionConfiguration,
+	/// Unix time in seconds at which the auction started.
+	pub started_at: Timestamp,
+	/// Reserved from the seller until the order is removed.
+	pub deposit: Balance,
+}
+
+impl<AccountId, AssetId, Balance> SellOrder<AccountId, AssetId, Balance> {
+	pub fn ends_at(&self) -> Timestamp {
+		self.started_at.saturating_add(self.configuration.duration)
+	}
+}
@@ -0,0 +1,270 @@
+use crate::{
+	mock::*,
+	models::{AuctionConfiguration, SellOrder},
+	open_auctions,
+	pallet::*,
+};
+use composable_traits::{
+	defi::{Ratio, Sell, Take},
+	time::{LinearDecrease, StairstepExponentialDecrease, TimeReleaseFunction},
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{
+		fungibles::{Inspect, Mutate},
+		Currency,
+	},
+};
+use sp_runtime::{FixedPointNumber, Permill};
+
+const SECOND: u64 = 1_000;
+
+fn price(price: u128) -> Ratio {
+	Ratio::saturating_from_integer(price)
+}
+
+/// Sells 100 BTC for at least 10 USDT each, starting at 15 USDT and reaching 10 USDT after
+/// 1_000 seconds.
+fn linear_configuration(fallback: bool) -> AuctionConfiguration {
+	AuctionConfiguration {
+		price_decay: TimeReleaseFunction::LinearDecrease(LinearDecrease { total: 1_000 }),
+		start_premium: Permill::from_percent(50),
+		duration: 2_000,
+		fallback,
+	}
+}
+
+fn ask(configuration: AuctionConfiguration) -> OrderId {
+	assert_ok!(Tokens::mint_into(BTC, &ALICE, 100));
+	Balances::make_free_balance_be(&ALICE, 100);
+	assert_ok!(DutchAuction::ask(
+		RuntimeOrigin::signed(ALICE),
+		Sell::new(BTC, USDT, 100, price(10)),
+		configuration
+	));
+	OrdersIndex::<Test>::get()
+}
+
+#[test]
+fn ask_validates_order_and_configuration() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Tokens::mint_into(BTC, &ALICE, 100));
+		let configuration = linear_configuration(false);
+		for order in [
+			Sell::new(BTC, USDT, 0, price(10)),
+			Sell::new(BTC, USDT, 100, price(0)),
+			Sell::new(BTC, BTC, 100, price(10)),
+		] {
+			assert_noop!(
+				DutchAuction::ask(RuntimeOrigin::signed(ALICE), order, configuration.clone()),
+				Error::<Test>::InvalidSell
+			);
+		}
+		let order = Sell::new(BTC, USDT, 100, price(10));
+		for configuration in [
+			AuctionConfiguration { duration: 0, ..linear_configuration(false) },
+			AuctionConfiguration {
+				duration: MaxAuctionDuration::get() + 1,
+				..linear_configuration(false)
+			},
+			AuctionConfiguration {
+				price_decay: TimeReleaseFunction::LinearDecrease(LinearDecrease { total: 0 }),
+				..linear_configuration(false)
+			},
+			AuctionConfiguration {
+				price_decay: TimeReleaseFunction::StairstepExponentialDecrease(
+					StairstepExponentialDecrease { step: 10, cut: Permill::one() },
+				),
+				..linear_configuration(false)
+			},
+		] {
+			assert_noop!(
+				DutchAuction::ask(RuntimeOrigin::signed(ALICE), order.clone(), configuration),
+				Error::<Test>::InvalidConfiguration
+			);
+		}
+
+		assert_ok!(DutchAuction::ask(
+			RuntimeOrigin::signed(ALICE),
+			order.clone(),
+			configuration.clone()
+		));
+		System::assert_last_event(
+			Event::<Test>::OrderAdded {
+				order_id: 1,
+				order: SellOrder { from_to: ALICE, order, configuration, started_at: 0 },
+			}
+			.into(),
+		);
+		assert_eq!(Tokens::balance(BTC, &DutchAuction::account_id()), 100);
+	})
+}
+
+#[test]
+fn linear_auction_is_taken_in_parts() {
+	new_test_ext().execute_with(|| {
+		let order_id = ask(linear_configuration(false));
+		assert_ok!(Tokens::mint_into(USDT, &BOB, 1_000));
+		assert_ok!(Tokens::mint_into(USDT, &CHARLIE, 1_000));
+
+		// 1. Half way through the decay, a unit costs 12.5 USDT.
+		Timestamp::set_timestamp(500 * SECOND);
+		assert_noop!(
+			DutchAuction::take(RuntimeOrigin::signed(BOB), order_id, Take::new(40, price(12))),
+			Error::<Test>::TakeLimitBelowPrice
+		);
+		assert_ok!(DutchAuction::take(
+			RuntimeOrigin::signed(BOB),
+			order_id,
+			Take::new(40, price(13))
+		));
+		System::assert_last_event(
+			Event::<Test>::OrderTaken { order_id, taker: BOB, amount: 40, quote_amount: 500 }
+				.into(),
+		);
+		assert_eq!(Tokens::balance(BTC, &BOB), 40);
+		assert_eq!(Tokens::balance(USDT, &ALICE), 500);
+
+		// 2. Once decayed, the price stays at the limit and takes are capped by what is left.
+		Timestamp::set_timestamp(1_500 * SECOND);
+		assert_ok!(DutchAuction::take(
+			RuntimeOrigin::signed(CHARLIE),
+			order_id,
+			Take::new(100, price(10))
+		));
+		System::assert_last_event(Event::<Test>::OrderFilled { order_id }.into());
+		assert_eq!(Tokens::balance(BTC, &CHARLIE), 60);
+		assert_eq!(Tokens::balance(USDT, &ALICE), 1_100);
+		assert!(DutchAuction::sell_order(order_id).is_none());
+		assert_eq!(Balances::reserved_balance(&ALICE), 0);
+	})
+}
+
+#[test]
+fn stairstep_auction_price_drops_every_step() {
+	new_test_ext().execute_with(|| {
+		let order_id = ask(AuctionConfiguration {
+			price_decay: TimeReleaseFunction::StairstepExponentialDecrease(
+				StairstepExponentialDecrease { step: 100, cut: Permill::from_percent(50) },
+			),
+			start_premium: Permill::one(),
+			duration: 1_000,
+			fallback: false,
+		});
+		let quote_amount = || {
+			let auctions = open_auctions::<Test>();
+			assert_eq!(auctions.len(), 1);
+			assert_eq!(auctions[0].0, order_id);
+			auctions[0].2
+		};
+
+		// The premium over the 10 USDT limit halves every 100 seconds.
+		assert_eq!(quote_amount(), 2_000);
+		Timestamp::set_timestamp(150 * SECOND);
+		assert_eq!(quote_amount(), 1_500);
+		Timestamp::set_timestamp(250 * SECOND);
+		assert_eq!(quote_amount(), 1_250);
+	})
+}
+
+#[test]
+fn close_sells_through_router_or_refunds() {
+	new_test_ext().execute_with(|| {
+		let refunded = ask(linear_configuration(true));
+		assert_noop!(
+			DutchAuction::close(RuntimeOrigin::signed(CHARLIE), refunded),
+			Error::<Test>::AuctionNotEnded
+		);
+
+		// 1. The route does not pay the limit of the order, which is returned to the seller.
+		Timestamp::set_timestamp(2_000 * SECOND);
+		assert_ok!(Tokens::mint_into(USDT, &BOB, 1_000));
+		assert_noop!(
+			DutchAuction::take(RuntimeOrigin::signed(BOB), refunded, Take::new(100, price(10))),
+			Error::<Test>::AuctionEnded
+		);
+		MockRouter::set_price(9);
+		assert_ok!(DutchAuction::close(RuntimeOrigin::signed(CHARLIE), refunded));
+		System::assert_last_event(
+			Event::<Test>::OrderClosed { order_id: refunded, amount: 100, proceeds: None }.into(),
+		);
+		assert_eq!(Tokens::balance(BTC, &ALICE), 100);
+
+		// 2. The route pays above the limit, the seller receives the proceeds.
+		let sold = ask(linear_configuration(true));
+		Timestamp::set_timestamp(4_000 * SECOND);
+		MockRouter::set_price(11);
+		assert_ok!(DutchAuction::close(RuntimeOrigin::signed(CHARLIE), sold));
+		System::assert_last_event(
+			Event::<Test>::OrderClosed { order_id: sold, amount: 100, proceeds: Some(1_100) }
+				.into(),
+		);
+		assert_eq!(Tokens::balance(USDT, &ALICE), 1_100);
+		assert_eq!(Tokens::balance(BTC, &DutchAuction::account_id()), 0);
+		assert_eq!(Balances::reserved_balance(&ALICE), 0);
+
+		// 3. The router has no route for the pair, the order is returned to the seller.
+		let unrouted = ask(linear_configuration(true));
+		Timestamp::set_timestamp(6_000 * SECOND);
+		MockRouter::set_routed(false);
+		assert_ok!(DutchAuction::close(RuntimeOrigin::signed(CHARLIE), unrouted));
+		System::assert_last_event(
+			Event::<Test>::OrderClosed { order_id: unrouted, amount: 100, proceeds: None }.into(),
+		);
+		assert_eq!(Tokens::balance(BTC, &ALICE), 200);
+		assert_eq!(Tokens::balance(USDT, &ALICE), 1_100);
+	})
+}
+
+#[test]
+fn ended_auctions_are_not_listed() {
+	new_test_ext().execute_with(|| {
+		let order_id = ask(linear_configuration(false));
+		Timestamp::set_timestamp(1_999 * SECOND);
+		assert_eq!(open_auctions::<Test>().len(), 1);
+		Timestamp::set_timestamp(2_000 * SECOND);
+		assert!(open_auctions::<Test>().is_empty());
+		assert!(DutchAuction::sell_order(order_id).is_some());
+	})
+}
+
+#[test]
+fn only_seller_or_cancel_origin_can_cancel() {
+	new_test_ext().execute_with(|| {
+		let order_id = ask(linear_configuration(false));
+		assert_eq!(Balances::reserved_balance(&ALICE), OrderDeposit::get());
+		assert_noop!(
+			DutchAuction::cancel(RuntimeOrigin::signed(BOB), order_id),
+			Error::<Test>::NotOrderOwner
+		);
+		assert_ok!(DutchAuction::cancel(RuntimeOrigin::signed(ALICE), order_id));
+		System::assert_last_event(Event::<Test>::OrderCancelled { order_id, amount: 100 }.into());
+		assert_eq!(Tokens::balance(BTC, &ALICE), 100);
+		assert_eq!(Balances::reserved_balance(&ALICE), 0);
+		assert_noop!(
+			DutchAuction::cancel(RuntimeOrigin::signed(ALICE), order_id),
+			Error::<Test>::OrderNotFound
+		);
+
+		// Orders whose seller can't sign, like liquidations, are cancelled by governance.
+		let order_id = ask(linear_configuration(false));
+		assert_ok!(DutchAuction::cancel(RuntimeOrigin::root(), order_id));
+		assert_eq!(Tokens::balance(BTC, &ALICE), 200);
+		assert_eq!(Balances::reserved_balance(&ALICE), 0);
+	})
+}
+
+#[test]
+fn ask_reserves_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Tokens::mint_into(BTC, &ALICE, 100));
+		assert_noop!(
+			DutchAuction::ask(
+				RuntimeOrigin::signed(ALICE),
+				Sell::new(BTC, USDT, 100, price(10)),
+				linear_configuration(false)
+			),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	})
+}
@@ -0,0 +1,30 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+pub trait WeightInfo {
+	fn ask() -> Weight;
+	fn take() -> Weight;
+	fn cancel() -> Weight;
+	fn close() -> Weight;
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn ask() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn take() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn cancel() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn close() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+}
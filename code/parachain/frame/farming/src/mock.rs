@@ -31,7 +31,7 @@ frame_support::construct_runtime!(
 		Rewards: reward::{Pallet, Call, Storage, Event<T>},
 		Farming: farming::{Pallet, Call, Storage, Event<T>},
 
-		Assets: pallet_assets::{Pallet, Call, Storage},
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
 
 	}
@@ -136,6 +136,7 @@ impl composable_support::validation::Validate<CurrencyId, primitives::currency::
 }
 
 impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = ();
 	type NativeAssetId = NativeAssetId;
 	type AssetId = CurrencyId;
@@ -144,7 +145,12 @@ impl pallet_assets::Config for Test {
 	type NativeCurrency = Balances;
 	type WeightInfo = ();
 	type AdminOrigin = EnsureRoot<AccountId>;
+	type FreezeOrigin = EnsureRoot<AccountId>;
 	type CurrencyValidator = Valid;
+	type RecurringPaymentDeposit = frame_support::traits::ConstU128<1>;
+	type MaxRecurringPaymentsPerPayer = frame_support::traits::ConstU32<16>;
+	type MinRecurringPaymentInterval = frame_support::traits::ConstU128<1>;
+	type MaxRecurringPaymentFailures = frame_support::traits::ConstU32<3>;
 }
 
 impl reward::Config for Test {
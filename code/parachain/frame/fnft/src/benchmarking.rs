@@ -0,0 +1,40 @@
+use super::*;
+use crate::Pallet as Fnft;
+use composable_traits::fnft::FinancialNft;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{
+	assert_ok,
+	traits::{
+		tokens::nonfungibles::{Create, Mutate},
+		Currency,
+	},
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Saturating;
+
+benchmarks! {
+	where_clause {
+		where
+			T: proxy::Config,
+			T::FinancialNftCollectionId: From<u128>,
+	}
+
+	transfer {
+		let collection: T::FinancialNftCollectionId = 1_u128.into();
+		let owner: T::AccountId = whitelisted_caller();
+		let to: T::AccountId = account("to", 0, 0);
+		assert_ok!(Fnft::<T>::create_collection(&collection, &owner, &owner));
+		let instance = Fnft::<T>::get_next_nft_id(&collection).expect("next id; qed;");
+		// fund the proxy deposits of the asset account
+		<T as proxy::Config>::Currency::make_free_balance_be(
+			&Fnft::<T>::asset_account(&collection, &instance),
+			<T as proxy::Config>::Currency::minimum_balance().saturating_mul(1_000_u32.into()),
+		);
+		assert_ok!(Fnft::<T>::mint_into(&collection, &instance, &owner));
+	}: _(RawOrigin::Signed(owner), collection, instance, to.clone())
+	verify {
+		assert_eq!(Instance::<T>::get(collection, instance).map(|(owner, _)| owner), Some(to));
+	}
+}
+
+impl_benchmark_test_suite!(Fnft, crate::mock::new_test_ext(), crate::mock::Test);
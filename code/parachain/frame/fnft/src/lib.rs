@@ -0,0 +1,429 @@
+//! # Financial NFT Pallet
+//!
+//! Wraps financial positions into NFTs. It implements the `nonfungibles` traits along with
+//! [`FinancialNft`](composable_traits::fnft::FinancialNft).
+//!
+//! ## Overview
+//!
+//! Collections are created by protocols issuing positions, such as staking or liquid staking
+//! unstake requests, usually one collection per protocol or per pool. The protocol then mints an
+//! NFT for each position, using [`FinancialNft::get_next_nft_id`] to get its id.
+//!
+//! Each NFT has an asset account derived from its collection and instance ids, holding the assets
+//! of the position. The owner of the NFT is registered as a proxy of this account for each of the
+//! `ProxyTypeSelector` proxy types, so transferring the NFT moves control of the account to its
+//! new owner. Registering a proxy reserves the deposit of pallet proxy from the asset account,
+//! which protocols should fund before minting.
+//!
+//! ## Interface
+//!
+//! - `transfer`: transfer an NFT owned by the caller, along with control of its asset account.
+#![cfg_attr(
+	not(test),
+	warn(
+		clippy::disallowed_methods,
+		clippy::disallowed_types,
+		clippy::todo,
+		clippy::unwrap_used,
+		clippy::panic
+	)
+)]
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(
+	bad_style,
+	bare_trait_objects,
+	improper_ctypes,
+	non_shorthand_field_patterns,
+	no_mangle_generic_items,
+	overflowing_literals,
+	path_statements,
+	patterns_in_fns_without_body,
+	private_in_public,
+	unconditional_recursion,
+	unused_allocation,
+	unused_comparisons,
+	unused_parens,
+	while_true,
+	trivial_casts,
+	trivial_numeric_casts,
+	unused_extern_crates
+)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarking;
+
+pub use crate::weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::WeightInfo;
+	use codec::FullCodec;
+	use composable_support::math::safe::{SafeAdd, SafeArithmetic};
+	use composable_traits::{
+		account_proxy::AccountProxy,
+		fnft::{FinancialNft, FnftAccountProxyTypeSelector, Key, Value},
+	};
+	use core::fmt::Debug;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::tokens::nonfungibles::{Create, Inspect, Mutate, Transfer},
+		BoundedBTreeMap, PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{AccountIdConversion, One, Zero};
+	use sp_std::vec::Vec;
+
+	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+	pub type CollectionIdOf<T> = <T as Config>::FinancialNftCollectionId;
+	pub type InstanceIdOf<T> = <T as Config>::FinancialNftInstanceId;
+	pub type PropertiesOf<T> = BoundedBTreeMap<Key, Value, <T as Config>::MaxProperties>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		#[allow(missing_docs)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Type representing the unique ID of a collection, usually the asset id of the position
+		/// issued by a protocol.
+		type FinancialNftCollectionId: FullCodec
+			+ MaxEncodedLen
+			+ Debug
+			+ TypeInfo
+			+ Eq
+			+ PartialEq
+			+ Ord
+			+ Copy;
+
+		/// Type representing the unique ID of an NFT within its collection.
+		type FinancialNftInstanceId: FullCodec
+			+ MaxEncodedLen
+			+ Default
+			+ Debug
+			+ TypeInfo
+			+ Eq
+			+ PartialEq
+			+ Ord
+			+ Copy
+			+ Zero
+			+ One
+			+ SafeArithmetic;
+
+		/// The kinds of proxies given to the owner of an NFT over its asset account.
+		type ProxyType: Parameter + MaxEncodedLen;
+
+		/// Manages the proxies of asset accounts.
+		type AccountProxy: AccountProxy<
+			AccountId = Self::AccountId,
+			ProxyType = Self::ProxyType,
+			BlockNumber = Self::BlockNumber,
+		>;
+
+		/// Selects the proxy types given to the owner of an NFT.
+		type ProxyTypeSelector: FnftAccountProxyTypeSelector<Self::ProxyType>;
+
+		/// Maximum number of attributes of an NFT.
+		#[pallet::constant]
+		type MaxProperties: Get<u32>;
+
+		/// Used to derive the asset accounts of NFTs.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		FinancialNftCollectionCreated {
+			collection_id: CollectionIdOf<T>,
+			who: AccountIdOf<T>,
+			admin: AccountIdOf<T>,
+		},
+		FinancialNftCreated {
+			collection_id: CollectionIdOf<T>,
+			instance_id: InstanceIdOf<T>,
+		},
+		FinancialNftBurned {
+			collection_id: CollectionIdOf<T>,
+			instance_id: InstanceIdOf<T>,
+		},
+		FinancialNftTransferred {
+			collection_id: CollectionIdOf<T>,
+			instance_id: InstanceIdOf<T>,
+			to: AccountIdOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		CollectionAlreadyExists,
+		CollectionNotFound,
+		InstanceAlreadyExists,
+		InstanceNotFound,
+		/// Only the owner of an NFT can transfer it.
+		MustBeOwner,
+		/// Keys and values of attributes must be non empty and at most 64 and 256 bytes long.
+		InvalidAttribute,
+		MaxPropertiesExceeded,
+	}
+
+	/// Collections with their owner and admin.
+	#[pallet::storage]
+	#[pallet::getter(fn collections)]
+	pub type Collection<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionIdOf<T>, (AccountIdOf<T>, AccountIdOf<T>)>;
+
+	/// NFTs with their owner and attributes.
+	#[pallet::storage]
+	#[pallet::getter(fn instance)]
+	pub type Instance<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionIdOf<T>,
+		Blake2_128Concat,
+		InstanceIdOf<T>,
+		(AccountIdOf<T>, PropertiesOf<T>),
+	>;
+
+	/// NFTs owned by an account.
+	#[pallet::storage]
+	pub type OwnerInstances<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Blake2_128Concat,
+		(CollectionIdOf<T>, InstanceIdOf<T>),
+		(),
+	>;
+
+	/// Id of the next NFT of a collection.
+	#[pallet::storage]
+	#[allow(clippy::disallowed_types)] // InstanceId is ValueQuery with default 0
+	pub type FinancialNftId<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionIdOf<T>, InstanceIdOf<T>, ValueQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Transfer an NFT owned by the caller to `to`, who becomes the proxy of its asset
+		/// account in place of the caller.
+		///
+		/// Emits `FinancialNftTransferred` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::transfer())]
+		pub fn transfer(
+			origin: OriginFor<T>,
+			collection: CollectionIdOf<T>,
+			instance: InstanceIdOf<T>,
+			to: AccountIdOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (owner, _) =
+				Instance::<T>::get(collection, instance).ok_or(Error::<T>::InstanceNotFound)?;
+			ensure!(owner == who, Error::<T>::MustBeOwner);
+			<Self as Transfer<AccountIdOf<T>>>::transfer(&collection, &instance, &to)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// NFTs owned by `who`.
+		pub fn owned_instances(who: &AccountIdOf<T>) -> Vec<(CollectionIdOf<T>, InstanceIdOf<T>)> {
+			OwnerInstances::<T>::iter_key_prefix(who).collect()
+		}
+
+		fn add_proxies(asset_account: &AccountIdOf<T>, owner: &AccountIdOf<T>) -> DispatchResult {
+			T::ProxyTypeSelector::get_proxy_types().into_iter().try_for_each(|proxy_type| {
+				T::AccountProxy::add_proxy_delegate(
+					asset_account,
+					owner.clone(),
+					proxy_type,
+					Zero::zero(),
+				)
+			})
+		}
+
+		fn remove_proxies(
+			asset_account: &AccountIdOf<T>,
+			owner: &AccountIdOf<T>,
+		) -> DispatchResult {
+			T::ProxyTypeSelector::get_proxy_types().into_iter().try_for_each(|proxy_type| {
+				T::AccountProxy::remove_proxy_delegate(
+					asset_account,
+					owner.clone(),
+					proxy_type,
+					Zero::zero(),
+				)
+			})
+		}
+	}
+
+	impl<T: Config> Inspect<AccountIdOf<T>> for Pallet<T> {
+		type ItemId = InstanceIdOf<T>;
+		type CollectionId = CollectionIdOf<T>;
+
+		fn owner(collection: &Self::CollectionId, item: &Self::ItemId) -> Option<AccountIdOf<T>> {
+			Instance::<T>::get(collection, item).map(|(owner, _)| owner)
+		}
+
+		fn collection_owner(collection: &Self::CollectionId) -> Option<AccountIdOf<T>> {
+			Collection::<T>::get(collection).map(|(owner, _)| owner)
+		}
+
+		fn attribute(
+			collection: &Self::CollectionId,
+			item: &Self::ItemId,
+			key: &[u8],
+		) -> Option<Vec<u8>> {
+			let key = Key::try_from(key.to_vec()).ok()?;
+			Instance::<T>::get(collection, item).and_then(|(_, properties)| {
+				properties.get(&key).map(|value| value.as_slice().to_vec())
+			})
+		}
+	}
+
+	impl<T: Config> Create<AccountIdOf<T>> for Pallet<T> {
+		fn create_collection(
+			collection: &Self::CollectionId,
+			who: &AccountIdOf<T>,
+			admin: &AccountIdOf<T>,
+		) -> DispatchResult {
+			Collection::<T>::try_mutate(collection, |entry| -> DispatchResult {
+				ensure!(entry.is_none(), Error::<T>::CollectionAlreadyExists);
+				*entry = Some((who.clone(), admin.clone()));
+				Ok(())
+			})?;
+			Self::deposit_event(Event::<T>::FinancialNftCollectionCreated {
+				collection_id: *collection,
+				who: who.clone(),
+				admin: admin.clone(),
+			});
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Mutate<AccountIdOf<T>> for Pallet<T> {
+		/// Mint an NFT, making `who` the proxy of its asset account.
+		fn mint_into(
+			collection: &Self::CollectionId,
+			item: &Self::ItemId,
+			who: &AccountIdOf<T>,
+		) -> DispatchResult {
+			ensure!(Collection::<T>::contains_key(collection), Error::<T>::CollectionNotFound);
+			ensure!(
+				!Instance::<T>::contains_key(collection, item),
+				Error::<T>::InstanceAlreadyExists
+			);
+			Self::add_proxies(&Self::asset_account(collection, item), who)?;
+			Instance::<T>::insert(collection, item, (who.clone(), PropertiesOf::<T>::default()));
+			OwnerInstances::<T>::insert(who, (collection, item), ());
+			Self::deposit_event(Event::<T>::FinancialNftCreated {
+				collection_id: *collection,
+				instance_id: *item,
+			});
+			Ok(())
+		}
+
+		/// Burn an NFT, removing its owner from the proxies of its asset account. The assets left
+		/// in the account are not touched.
+		fn burn(
+			collection: &Self::CollectionId,
+			item: &Self::ItemId,
+			maybe_check_owner: Option<&AccountIdOf<T>>,
+		) -> DispatchResult {
+			let (owner, _) =
+				Instance::<T>::get(collection, item).ok_or(Error::<T>::InstanceNotFound)?;
+			if let Some(check_owner) = maybe_check_owner {
+				ensure!(&owner == check_owner, Error::<T>::MustBeOwner);
+			}
+			Self::remove_proxies(&Self::asset_account(collection, item), &owner)?;
+			Instance::<T>::remove(collection, item);
+			OwnerInstances::<T>::remove(&owner, (collection, item));
+			Self::deposit_event(Event::<T>::FinancialNftBurned {
+				collection_id: *collection,
+				instance_id: *item,
+			});
+			Ok(())
+		}
+
+		/// Set an attribute of an NFT, removing it if `value` is empty.
+		fn set_attribute(
+			collection: &Self::CollectionId,
+			item: &Self::ItemId,
+			key: &[u8],
+			value: &[u8],
+		) -> DispatchResult {
+			let key = Key::try_from(key.to_vec()).map_err(|_| Error::<T>::InvalidAttribute)?;
+			Instance::<T>::try_mutate(collection, item, |entry| -> DispatchResult {
+				let (_, properties) = entry.as_mut().ok_or(Error::<T>::InstanceNotFound)?;
+				if value.is_empty() {
+					properties.remove(&key);
+				} else {
+					let value = Value::try_from(value.to_vec())
+						.map_err(|_| Error::<T>::InvalidAttribute)?;
+					properties
+						.try_insert(key, value)
+						.map_err(|_| Error::<T>::MaxPropertiesExceeded)?;
+				}
+				Ok(())
+			})
+		}
+	}
+
+	impl<T: Config> Transfer<AccountIdOf<T>> for Pallet<T> {
+		/// Transfer an NFT to `destination`, moving the proxies of its asset account from the
+		/// previous owner to `destination`.
+		fn transfer(
+			collection: &Self::CollectionId,
+			item: &Self::ItemId,
+			destination: &AccountIdOf<T>,
+		) -> DispatchResult {
+			let asset_account = Self::asset_account(collection, item);
+			Instance::<T>::try_mutate(collection, item, |entry| -> DispatchResult {
+				let (owner, _) = entry.as_mut().ok_or(Error::<T>::InstanceNotFound)?;
+				if owner != destination {
+					Self::remove_proxies(&asset_account, owner)?;
+					Self::add_proxies(&asset_account, destination)?;
+					OwnerInstances::<T>::remove(&*owner, (collection, item));
+					OwnerInstances::<T>::insert(destination, (collection, item), ());
+					*owner = destination.clone();
+				}
+				Ok(())
+			})?;
+			Self::deposit_event(Event::<T>::FinancialNftTransferred {
+				collection_id: *collection,
+				instance_id: *item,
+				to: destination.clone(),
+			});
+			Ok(())
+		}
+	}
+
+	impl<T: Config> FinancialNft<AccountIdOf<T>> for Pallet<T> {
+		fn asset_account(
+			collection: &Self::CollectionId,
+			instance: &Self::ItemId,
+		) -> AccountIdOf<T> {
+			T::PalletId::get().into_sub_account_truncating((collection, instance))
+		}
+
+		fn get_next_nft_id(collection: &Self::CollectionId) -> Result<Self::ItemId, DispatchError> {
+			FinancialNftId::<T>::try_mutate(collection, |next_id| -> Result<_, DispatchError> {
+				let id = *next_id;
+				*next_id = next_id.safe_add(&One::one())?;
+				Ok(id)
+			})
+		}
+	}
+}
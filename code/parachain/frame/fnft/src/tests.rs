@@ -0,0 +1,163 @@
+use crate::{mock::*, pallet::*};
+use composable_traits::fnft::{FinancialNft, FnftAccountProxyTypeSelector};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::tokens::nonfungibles::{Create, Inspect, Mutate},
+};
+
+const COLLECTION: CollectionId = 1;
+
+fn mint_into(who: AccountId) -> InstanceId {
+	let instance = Fnft::get_next_nft_id(&COLLECTION).expect("next id; qed;");
+	assert_ok!(<Fnft as Mutate<AccountId>>::mint_into(&COLLECTION, &instance, &who));
+	instance
+}
+
+fn controls(who: AccountId, instance: InstanceId) -> bool {
+	let asset_account = Fnft::asset_account(&COLLECTION, &instance);
+	MockFnftAccountProxyType::get_proxy_types()
+		.into_iter()
+		.all(|proxy_type| Proxy::find_proxy(&asset_account, &who, Some(proxy_type)).is_ok())
+}
+
+fn create_collection() {
+	assert_ok!(Fnft::create_collection(&COLLECTION, &ALICE, &ALICE));
+}
+
+#[test]
+fn create_collection_once() {
+	new_test_ext().execute_with(|| {
+		create_collection();
+		System::assert_last_event(
+			Event::<Test>::FinancialNftCollectionCreated {
+				collection_id: COLLECTION,
+				who: ALICE,
+				admin: ALICE,
+			}
+			.into(),
+		);
+		assert_eq!(Fnft::collection_owner(&COLLECTION), Some(ALICE));
+		assert_noop!(
+			Fnft::create_collection(&COLLECTION, &BOB, &BOB),
+			Error::<Test>::CollectionAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn mint_gives_control_of_asset_account() {
+	new_test_ext().execute_with(|| {
+		// 1. NFTs can only be minted in an existing collection.
+		assert_noop!(
+			<Fnft as Mutate<AccountId>>::mint_into(&COLLECTION, &0, &BOB),
+			Error::<Test>::CollectionNotFound
+		);
+		create_collection();
+
+		// 2. Ids of a collection are sequential, and each NFT has its own asset account.
+		let first = mint_into(BOB);
+		let second = mint_into(CHARLIE);
+		assert_eq!((first, second), (0, 1));
+		System::assert_last_event(
+			Event::<Test>::FinancialNftCreated { collection_id: COLLECTION, instance_id: second }
+				.into(),
+		);
+		assert_ne!(
+			Fnft::asset_account(&COLLECTION, &first),
+			Fnft::asset_account(&COLLECTION, &second)
+		);
+		assert_noop!(
+			<Fnft as Mutate<AccountId>>::mint_into(&COLLECTION, &first, &CHARLIE),
+			Error::<Test>::InstanceAlreadyExists
+		);
+
+		// 3. The owner is the proxy of the asset account of its NFT only.
+		assert_eq!(Fnft::owner(&COLLECTION, &first), Some(BOB));
+		assert!(controls(BOB, first));
+		assert!(!controls(BOB, second));
+		assert!(controls(CHARLIE, second));
+		assert_eq!(Fnft::owned_instances(&BOB), vec![(COLLECTION, first)]);
+	});
+}
+
+#[test]
+fn transfer_moves_control_of_asset_account() {
+	new_test_ext().execute_with(|| {
+		create_collection();
+		let instance = mint_into(BOB);
+
+		// 1. Only the owner can transfer an NFT.
+		assert_noop!(
+			Fnft::transfer(RuntimeOrigin::signed(CHARLIE), COLLECTION, instance, CHARLIE),
+			Error::<Test>::MustBeOwner
+		);
+		assert_noop!(
+			Fnft::transfer(RuntimeOrigin::signed(BOB), COLLECTION, instance + 1, CHARLIE),
+			Error::<Test>::InstanceNotFound
+		);
+
+		// 2. The new owner becomes the proxy of the asset account in place of the previous one.
+		assert_ok!(Fnft::transfer(RuntimeOrigin::signed(BOB), COLLECTION, instance, CHARLIE));
+		System::assert_last_event(
+			Event::<Test>::FinancialNftTransferred {
+				collection_id: COLLECTION,
+				instance_id: instance,
+				to: CHARLIE,
+			}
+			.into(),
+		);
+		assert_eq!(Fnft::owner(&COLLECTION, &instance), Some(CHARLIE));
+		assert!(controls(CHARLIE, instance));
+		assert!(!controls(BOB, instance));
+		assert!(Fnft::owned_instances(&BOB).is_empty());
+		assert_eq!(Fnft::owned_instances(&CHARLIE), vec![(COLLECTION, instance)]);
+	});
+}
+
+#[test]
+fn burn_removes_control_of_asset_account() {
+	new_test_ext().execute_with(|| {
+		create_collection();
+		let instance = mint_into(BOB);
+
+		assert_noop!(
+			<Fnft as Mutate<AccountId>>::burn(&COLLECTION, &instance, Some(&CHARLIE)),
+			Error::<Test>::MustBeOwner
+		);
+		assert_ok!(<Fnft as Mutate<AccountId>>::burn(&COLLECTION, &instance, Some(&BOB)));
+		System::assert_last_event(
+			Event::<Test>::FinancialNftBurned { collection_id: COLLECTION, instance_id: instance }
+				.into(),
+		);
+		assert_eq!(Fnft::owner(&COLLECTION, &instance), None);
+		assert!(!controls(BOB, instance));
+		assert!(Fnft::owned_instances(&BOB).is_empty());
+	});
+}
+
+#[test]
+fn set_attribute() {
+	new_test_ext().execute_with(|| {
+		create_collection();
+		let instance = mint_into(BOB);
+
+		// 1. Attributes are set, and removed with an empty value.
+		assert_ok!(Fnft::set_attribute(&COLLECTION, &instance, b"stake", b"100"));
+		assert_eq!(Fnft::attribute(&COLLECTION, &instance, b"stake"), Some(b"100".to_vec()));
+		assert_ok!(Fnft::set_attribute(&COLLECTION, &instance, b"stake", b""));
+		assert_eq!(Fnft::attribute(&COLLECTION, &instance, b"stake"), None);
+
+		// 2. Keys must be non empty, and NFTs have at most `MaxProperties` attributes.
+		assert_noop!(
+			Fnft::set_attribute(&COLLECTION, &instance, b"", b"100"),
+			Error::<Test>::InvalidAttribute
+		);
+		for key in [b"a", b"b", b"c", b"d"] {
+			assert_ok!(Fnft::set_attribute(&COLLECTION, &instance, key, b"1"));
+		}
+		assert_noop!(
+			Fnft::set_attribute(&COLLECTION, &instance, b"e", b"1"),
+			Error::<Test>::MaxPropertiesExceeded
+		);
+	});
+}
@@ -0,0 +1,18 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+pub trait WeightInfo {
+	fn transfer() -> Weight;
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn transfer() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+}
@@ -0,0 +1,148 @@
+use super::*;
+use crate::{
+	models::{MarketConfig, RepayStrategy},
+	BenchmarkHelper, Pallet as Lending,
+};
+use composable_traits::{defi::MoreThanOneFixedU128, rate_model::InterestRateModel};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{assert_ok, traits::fungibles::Mutate, BoundedVec};
+use frame_system::RawOrigin;
+use sp_runtime::{FixedPointNumber, Perquintill};
+use sp_std::vec::Vec;
+
+const UNIT: u128 = 1_000_000_000_000;
+const COLLATERAL: u128 = 100;
+const BORROW: u128 = 101;
+
+fn market_config<T: Config>() -> MarketConfigOf<T>
+where
+	T::BlockNumber: From<u32>,
+	T::AssetId: From<u128>,
+{
+	MarketConfig {
+		collateral_asset: COLLATERAL.into(),
+		borrow_asset: BORROW.into(),
+		collateral_factor: MoreThanOneFixedU128::saturating_from_integer(2),
+		interest_rate_model: InterestRateModel::default(),
+		reserve_factor: Perquintill::from_percent(10),
+		liquidation_discount: Perquintill::from_percent(5),
+		close_factor: Perquintill::from_percent(50),
+		liquidation_incentive: Perquintill::from_percent(10),
+		max_price_age: 10.into(),
+		liquidation_strategy: T::BenchmarkHelper::liquidation_strategy(),
+	}
+}
+
+/// Creates a market with `1_000_000` units supplied by a lender.
+fn create_market<T: Config>() -> T::MarketId
+where
+	T::BlockNumber: From<u32>,
+	T::Balance: From<u128>,
+	T::AssetId: From<u128>,
+{
+	T::BenchmarkHelper::set_price(COLLATERAL.into(), 50_000.into());
+	T::BenchmarkHelper::set_price(BORROW.into(), 1.into());
+	assert_ok!(Lending::<T>::create_market(RawOrigin::Root.into(), market_config::<T>()));
+	let market_id = MarketCount::<T>::get();
+	let lender: T::AccountId = account("lender", 0, 0);
+	let amount: T::Balance = (1_000_000 * UNIT).into();
+	assert_ok!(T::Assets::mint_into(BORROW.into(), &lender, amount));
+	assert_ok!(Lending::<T>::deposit(RawOrigin::Signed(lender).into(), market_id, amount));
+	market_id
+}
+
+/// Locks 100 units of collateral of `borrower` and borrows `1_000` units against it.
+fn open_position<T: Config>(market_id: T::MarketId, borrower: &T::AccountId)
+where
+	T::Balance: From<u128>,
+	T::AssetId: From<u128>,
+{
+	let collateral: T::Balance = (100 * UNIT).into();
+	assert_ok!(T::Assets::mint_into(COLLATERAL.into(), borrower, collateral));
+	assert_ok!(Lending::<T>::deposit_collateral(
+		RawOrigin::Signed(borrower.clone()).into(),
+		market_id,
+		collateral
+	));
+	assert_ok!(Lending::<T>::borrow(
+		RawOrigin::Signed(borrower.clone()).into(),
+		market_id,
+		(1_000 * UNIT).into()
+	));
+}
+
+benchmarks! {
+  where_clause { where T::BlockNumber: From<u32>, T::Balance: From<u128>, T::AssetId: From<u128> }
+	create_market {
+		let config = market_config::<T>();
+	}: _(RawOrigin::Root, config)
+
+	update_market {
+		let market_id = create_market::<T>();
+		let config = market_config::<T>();
+	}: _(RawOrigin::Root, market_id, config)
+
+	deposit {
+		let caller: T::AccountId = whitelisted_caller();
+		let market_id = create_market::<T>();
+		let amount: T::Balance = (1_000 * UNIT).into();
+		assert_ok!(T::Assets::mint_into(BORROW.into(), &caller, amount));
+	}: _(RawOrigin::Signed(caller), market_id, amount)
+
+	withdraw {
+		let caller: T::AccountId = whitelisted_caller();
+		let market_id = create_market::<T>();
+		let amount: T::Balance = (1_000 * UNIT).into();
+		assert_ok!(T::Assets::mint_into(BORROW.into(), &caller, amount));
+		assert_ok!(Lending::<T>::deposit(RawOrigin::Signed(caller.clone()).into(), market_id, amount));
+	}: _(RawOrigin::Signed(caller), market_id, amount)
+
+	deposit_collateral {
+		let caller: T::AccountId = whitelisted_caller();
+		let market_id = create_market::<T>();
+		let amount: T::Balance = (100 * UNIT).into();
+		assert_ok!(T::Assets::mint_into(COLLATERAL.into(), &caller, amount));
+	}: _(RawOrigin::Signed(caller), market_id, amount)
+
+	withdraw_collateral {
+		let caller: T::AccountId = whitelisted_caller();
+		let market_id = create_market::<T>();
+		open_position::<T>(market_id, &caller);
+	}: _(RawOrigin::Signed(caller), market_id, UNIT.into())
+
+	borrow {
+		let caller: T::AccountId = whitelisted_caller();
+		let market_id = create_market::<T>();
+		open_position::<T>(market_id, &caller);
+	}: _(RawOrigin::Signed(caller), market_id, (1_000 * UNIT).into())
+
+	repay_borrow {
+		let caller: T::AccountId = whitelisted_caller();
+		let market_id = create_market::<T>();
+		open_position::<T>(market_id, &caller);
+	}: _(RawOrigin::Signed(caller.clone()), market_id, caller.clone(), RepayStrategy::TotalDebt)
+
+	liquidate {
+		let b in 1 .. T::MaxLiquidationBatchSize::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let market_id = create_market::<T>();
+		let borrowers: Vec<T::AccountId> = (0..b).map(|i| account("borrower", i, 0)).collect();
+		for borrower in &borrowers {
+			open_position::<T>(market_id, borrower);
+		}
+		T::BenchmarkHelper::set_price(COLLATERAL.into(), 1.into());
+		let borrowers = BoundedVec::truncate_from(borrowers);
+	}: _(RawOrigin::Signed(caller), market_id, borrowers)
+
+	withdraw_reserves {
+		let caller: T::AccountId = whitelisted_caller();
+		let market_id = create_market::<T>();
+		Markets::<T>::mutate(market_id, |info| {
+			if let Some(info) = info {
+				info.total_reserves = UNIT.into();
+			}
+		});
+	}: _(RawOrigin::Root, market_id, UNIT.into(), caller)
+}
+
+impl_benchmark_test_suite!(Lending, crate::mock::new_test_ext(), crate::mock::Test);
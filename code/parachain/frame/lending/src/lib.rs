@@ -0,0 +1,1061 @@
+//! # Lending Pallet
+//!
+//! Isolated lending markets, each pairing a collateral asset with a borrow asset.
+//!
+//! ## Overview
+//!
+//! - Lenders `deposit` the borrow asset and receive the LP token of the market, which accrues the
+//!   interest paid by borrowers. `withdraw` burns LP tokens for the underlying asset, as long as
+//!   the market holds enough cash.
+//! - Borrowers lock collateral with `deposit_collateral` and `borrow` against it. Their position
+//!   must stay above the `collateral_factor` of the market, valued with [`Config::Oracle`] prices.
+//! - The borrow rate follows the utilization of the market through its [`InterestRateModel`].
+//!   Interest accrues on every interaction with a market, part of it going to the reserves.
+//! - Anyone can `liquidate` positions below the collateral factor. Up to the `close_factor` of the
+//!   debt is repaid by selling collateral worth it plus the `liquidation_incentive` through
+//!   [`Config::Liquidation`]. The sale is settled through [`SellCallback`]: the proceeds repay the
+//!   debt and unsold collateral is given back to the borrower along with the debt it was to cover.
+//! - The first deposit in a market locks [`Config::LockedLiquidity`] LP tokens, so that the first
+//!   lender can't inflate the price of the LP token.
+//!
+//! ## Interface
+//!
+//! - `create_market`/`update_market`: governance manages markets.
+//! - `deposit`/`withdraw`: supply and redeem the borrow asset.
+//! - `deposit_collateral`/`withdraw_collateral`: manage the collateral of a position.
+//! - `borrow`/`repay_borrow`: open and close debt.
+//! - `liquidate`: sell the collateral of undercollateralized positions.
+//! - `withdraw_reserves`: governance collects the reserves of a market.
+#![cfg_attr(
+	not(test),
+	warn(
+		clippy::disallowed_methods,
+		clippy::disallowed_types,
+		clippy::todo,
+		clippy::unwrap_used,
+		clippy::panic
+	)
+)]
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(
+	bad_style,
+	bare_trait_objects,
+	improper_ctypes,
+	non_shorthand_field_patterns,
+	no_mangle_generic_items,
+	overflowing_literals,
+	path_statements,
+	patterns_in_fns_without_body,
+	private_in_public,
+	unconditional_recursion,
+	unused_allocation,
+	unused_comparisons,
+	unused_parens,
+	while_true,
+	trivial_casts,
+	trivial_numeric_casts,
+	unused_extern_crates
+)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarking;
+
+pub mod models;
+
+pub use crate::weights::WeightInfo;
+
+/// Provides oracle prices and a liquidation strategy to the benchmarks.
+#[cfg(any(feature = "runtime-benchmarks", test))]
+pub trait BenchmarkHelper<AssetId, Balance, LiquidationStrategy> {
+	/// Set the price of a unit of `asset_id`.
+	fn set_price(asset_id: AssetId, price: Balance);
+	/// Strategy accepted by [`Config::Liquidation`].
+	fn liquidation_strategy() -> LiquidationStrategy;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::{
+		models::{BorrowSnapshot, Liquidation, MarketConfig, MarketInfo, RepayStrategy},
+		WeightInfo,
+	};
+	use codec::FullCodec;
+	use composable_support::{
+		abstractions::{
+			nonce::Nonce,
+			utils::{
+				increment::{Increment, SafeIncrement},
+				start_at::OneInit,
+			},
+		},
+		math::safe::{safe_multiply_by_rational, SafeAdd, SafeArithmetic, SafeSub},
+	};
+	use composable_traits::{
+		assets::{AssetInfo, CreateAsset},
+		currency::{BalanceLike, MathBalance},
+		defi::{Rate, Ratio, Sell, SellCallback, SellEngine},
+		oracle::{Oracle, Price},
+		rate_model::{accrued_interest, increment_index, InterestRate},
+	};
+	use core::fmt::Debug;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			fungibles::{Inspect, Mutate},
+			tokens::{Fortitude, Precision, Preservation},
+			UnixTime,
+		},
+		transactional, PalletId,
+	};
+	use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+	use sp_arithmetic::Rounding;
+	use sp_runtime::{
+		helpers_128bit::multiply_by_rational_with_rounding,
+		traits::{AccountIdConversion, One, SaturatedConversion, Saturating, Zero},
+		ArithmeticError, FixedPointNumber, PerThing, Percent,
+	};
+
+	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+	pub type AssetIdOf<T> = <T as Config>::AssetId;
+	pub type BalanceOf<T> = <T as Config>::Balance;
+	pub type BlockNumberOf<T> = <T as frame_system::Config>::BlockNumber;
+	pub type MarketConfigOf<T> =
+		MarketConfig<AssetIdOf<T>, BlockNumberOf<T>, <T as Config>::LiquidationStrategy>;
+	pub type MarketInfoOf<T> = MarketInfo<
+		AssetIdOf<T>,
+		BalanceOf<T>,
+		BlockNumberOf<T>,
+		<T as Config>::LiquidationStrategy,
+	>;
+	pub type OrderIdOf<T> =
+		<<T as Config>::Liquidation as SellEngine<<T as Config>::LiquidationStrategy>>::OrderId;
+	pub type LiquidationOf<T> = Liquidation<<T as Config>::MarketId, AccountIdOf<T>, BalanceOf<T>>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		#[allow(missing_docs)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Type representing the unique ID of an asset.
+		type AssetId: FullCodec
+			+ MaxEncodedLen
+			+ Eq
+			+ PartialEq
+			+ Copy
+			+ Clone
+			+ MaybeSerializeDeserialize
+			+ Debug
+			+ Default
+			+ TypeInfo
+			+ Ord;
+
+		/// Type representing the Balance of an account.
+		type Balance: BalanceLike + MathBalance;
+
+		/// Type representing the unique ID of a market.
+		type MarketId: FullCodec
+			+ MaxEncodedLen
+			+ Default
+			+ Debug
+			+ TypeInfo
+			+ Eq
+			+ PartialEq
+			+ Ord
+			+ Copy
+			+ Zero
+			+ One
+			+ SafeArithmetic;
+
+		/// Factory to create the LP tokens of the markets.
+		type LPTokenFactory: CreateAsset<LocalAssetId = Self::AssetId, Balance = Self::Balance>;
+
+		/// Supplied, borrowed and collateral assets, and LP tokens.
+		type Assets: Mutate<AccountIdOf<Self>, Balance = BalanceOf<Self>, AssetId = AssetIdOf<Self>>
+			+ Inspect<AccountIdOf<Self>, Balance = BalanceOf<Self>, AssetId = AssetIdOf<Self>>;
+
+		/// Prices used to value collateral and debt.
+		type Oracle: Oracle<
+			AssetId = Self::AssetId,
+			Balance = Self::Balance,
+			Timestamp = BlockNumberOf<Self>,
+		>;
+
+		/// Configuration of the liquidation engine, stored per market.
+		type LiquidationStrategy: FullCodec + MaxEncodedLen + TypeInfo + Debug + Clone + Eq;
+
+		/// Sells the collateral of liquidated positions.
+		type Liquidation: SellEngine<
+			Self::LiquidationStrategy,
+			MayBeAssetId = Self::AssetId,
+			Balance = Self::Balance,
+			AccountId = Self::AccountId,
+		>;
+
+		/// Time used to accrue interest.
+		type UnixTime: UnixTime;
+
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Maximum number of positions liquidated in one call.
+		#[pallet::constant]
+		type MaxLiquidationBatchSize: Get<u32>;
+
+		/// Existential deposit of the LP tokens.
+		#[pallet::constant]
+		type LPTokenExistentialDeposit: Get<BalanceOf<Self>>;
+
+		/// LP tokens minted to the market account on the first deposit, which can never be
+		/// redeemed. The first deposit must exceed it.
+		#[pallet::constant]
+		type LockedLiquidity: Get<BalanceOf<Self>>;
+
+		/// Origin allowed to create and update markets and to withdraw reserves.
+		type MarketCreationOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		type WeightInfo: WeightInfo;
+
+		#[cfg(any(feature = "runtime-benchmarks", test))]
+		type BenchmarkHelper: crate::BenchmarkHelper<
+			Self::AssetId,
+			Self::Balance,
+			Self::LiquidationStrategy,
+		>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		MarketCreated {
+			market_id: T::MarketId,
+			lp_token_id: T::AssetId,
+		},
+		MarketUpdated {
+			market_id: T::MarketId,
+		},
+		/// Borrow asset supplied by a lender.
+		Deposited {
+			market_id: T::MarketId,
+			account: T::AccountId,
+			amount: T::Balance,
+			lp_amount: T::Balance,
+		},
+		/// Borrow asset redeemed by a lender.
+		Withdrawn {
+			market_id: T::MarketId,
+			account: T::AccountId,
+			lp_amount: T::Balance,
+			amount: T::Balance,
+		},
+		CollateralDeposited {
+			market_id: T::MarketId,
+			account: T::AccountId,
+			amount: T::Balance,
+		},
+		CollateralWithdrawn {
+			market_id: T::MarketId,
+			account: T::AccountId,
+			amount: T::Balance,
+		},
+		Borrowed {
+			market_id: T::MarketId,
+			account: T::AccountId,
+			amount: T::Balance,
+		},
+		BorrowRepaid {
+			market_id: T::MarketId,
+			sender: T::AccountId,
+			beneficiary: T::AccountId,
+			amount: T::Balance,
+		},
+		/// `debt` of `borrower` was repaid by putting `collateral` on sale.
+		LiquidationInitiated {
+			market_id: T::MarketId,
+			borrower: T::AccountId,
+			collateral: T::Balance,
+			debt: T::Balance,
+			order_id: OrderIdOf<T>,
+		},
+		/// `debt` of `borrower`, who had no collateral left, was written off at the expense of
+		/// lenders.
+		DebtWrittenOff {
+			market_id: T::MarketId,
+			borrower: T::AccountId,
+			debt: T::Balance,
+		},
+		ReservesWithdrawn {
+			market_id: T::MarketId,
+			beneficiary: T::AccountId,
+			amount: T::Balance,
+		},
+		/// The sale of a liquidation closed. `repaid` of its debt was covered by the proceeds, the
+		/// `unsold` collateral was given back to the borrower with the rest of the debt.
+		LiquidationSettled {
+			market_id: T::MarketId,
+			borrower: T::AccountId,
+			order_id: OrderIdOf<T>,
+			repaid: T::Balance,
+			unsold: T::Balance,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		MarketDoesNotExist,
+		/// Collateral and borrow assets must differ.
+		SameCollateralAndBorrowAsset,
+		/// Collateral factor must be greater than one.
+		CollateralFactorMustBeMoreThanOne,
+		CloseFactorMustBeNonZero,
+		/// The assets of a market can't be changed.
+		MarketAssetsAreImmutable,
+		/// The interest rate model failed to compute the borrow rate.
+		CannotCalculateBorrowRate,
+		/// The oracle price is older than the market allows.
+		PriceTooOld,
+		/// The market does not hold enough of the borrow asset.
+		NotEnoughLiquidity,
+		NotEnoughCollateral,
+		NotEnoughReserves,
+		/// The position would fall below the collateral factor.
+		WouldBeUndercollateralized,
+		/// None of the given positions can be liquidated.
+		NoLiquidatablePosition,
+		/// The account has no debt in this market.
+		NoDebt,
+		AmountMustBeNonZero,
+		/// The first deposit must exceed the locked liquidity.
+		InsufficientFirstDeposit,
+	}
+
+	#[pallet::storage]
+	#[allow(clippy::disallowed_types)] // MarketId is ValueQuery with default 0
+	pub type MarketCount<T: Config> = StorageValue<_, T::MarketId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn market)]
+	pub type Markets<T: Config> = StorageMap<_, Twox64Concat, T::MarketId, MarketInfoOf<T>>;
+
+	/// Collateral locked by each account, per market.
+	#[pallet::storage]
+	#[allow(clippy::disallowed_types)] // absent collateral is zero
+	pub type AccountCollateral<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::MarketId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::Balance,
+		ValueQuery,
+	>;
+
+	/// Debt of each account, per market.
+	#[pallet::storage]
+	pub type DebtSnapshots<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::MarketId,
+		Blake2_128Concat,
+		T::AccountId,
+		BorrowSnapshot<T::Balance>,
+	>;
+
+	/// Liquidations whose collateral is on sale, by order of [`Config::Liquidation`].
+	#[pallet::storage]
+	pub type Liquidations<T: Config> =
+		StorageMap<_, Blake2_128Concat, OrderIdOf<T>, LiquidationOf<T>>;
+
+	#[pallet::storage]
+	#[allow(clippy::disallowed_types)]
+	pub type LpTokenNonce<T: Config> =
+		StorageValue<_, u64, ValueQuery, Nonce<OneInit, SafeIncrement>>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a market lending `borrow_asset` against `collateral_asset`.
+		///
+		/// Emits `MarketCreated` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::create_market())]
+		pub fn create_market(origin: OriginFor<T>, config: MarketConfigOf<T>) -> DispatchResult {
+			T::MarketCreationOrigin::ensure_origin(origin)?;
+			Self::validate_config(&config)?;
+			let market_id = MarketCount::<T>::try_mutate(|count| -> Result<_, DispatchError> {
+				*count = count.safe_add(&T::MarketId::one())?;
+				Ok(*count)
+			})?;
+			let lp_token_id = Self::create_lp_token()?;
+			Markets::<T>::insert(
+				market_id,
+				MarketInfo {
+					config,
+					lp_token_id,
+					borrow_index: Rate::one(),
+					total_borrows: Zero::zero(),
+					total_reserves: Zero::zero(),
+					liquidating: Zero::zero(),
+					accrued_at: T::UnixTime::now().as_secs(),
+				},
+			);
+			Self::deposit_event(Event::<T>::MarketCreated { market_id, lp_token_id });
+			Ok(())
+		}
+
+		/// Update the parameters of a market. Its assets can't be changed.
+		///
+		/// Emits `MarketUpdated` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::update_market())]
+		pub fn update_market(
+			origin: OriginFor<T>,
+			market_id: T::MarketId,
+			config: MarketConfigOf<T>,
+		) -> DispatchResult {
+			T::MarketCreationOrigin::ensure_origin(origin)?;
+			Self::validate_config(&config)?;
+			Self::try_mutate_market(market_id, |info| {
+				ensure!(
+					info.config.collateral_asset == config.collateral_asset &&
+						info.config.borrow_asset == config.borrow_asset,
+					Error::<T>::MarketAssetsAreImmutable
+				);
+				info.config = config;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::<T>::MarketUpdated { market_id });
+			Ok(())
+		}
+
+		/// Supply `amount` of the borrow asset in exchange for LP tokens of the market. The first
+		/// deposit locks [`Config::LockedLiquidity`] of the LP tokens in the market account.
+		///
+		/// Emits `Deposited` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::deposit())]
+		pub fn deposit(
+			origin: OriginFor<T>,
+			market_id: T::MarketId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let account = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::AmountMustBeNonZero);
+			let info = Self::try_mutate_market(market_id, |_| Ok(()))?;
+			let market_account = Self::account_id(&market_id);
+			let issued = T::Assets::total_issuance(info.lp_token_id);
+			let supplied = Self::total_supplied(&info, &market_account);
+			let lp_amount = if issued.is_zero() {
+				let locked = T::LockedLiquidity::get();
+				ensure!(amount > locked, Error::<T>::InsufficientFirstDeposit);
+				T::Assets::mint_into(info.lp_token_id, &market_account, locked)?;
+				amount.saturating_sub(locked)
+			} else if supplied.is_zero() {
+				amount
+			} else {
+				safe_multiply_by_rational(amount.into(), issued.into(), supplied.into())?
+					.saturated_into()
+			};
+			T::Assets::transfer(
+				info.config.borrow_asset,
+				&account,
+				&market_account,
+				amount,
+				Preservation::Expendable,
+			)?;
+			T::Assets::mint_into(info.lp_token_id, &account, lp_amount)?;
+			Self::deposit_event(Event::<T>::Deposited { market_id, account, amount, lp_amount });
+			Ok(())
+		}
+
+		/// Burn `lp_amount` of LP tokens for the borrow asset, limited by the market cash.
+		///
+		/// Emits `Withdrawn` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::withdraw())]
+		pub fn withdraw(
+			origin: OriginFor<T>,
+			market_id: T::MarketId,
+			lp_amount: T::Balance,
+		) -> DispatchResult {
+			let account = ensure_signed(origin)?;
+			ensure!(!lp_amount.is_zero(), Error::<T>::AmountMustBeNonZero);
+			let info = Self::try_mutate_market(market_id, |_| Ok(()))?;
+			let market_account = Self::account_id(&market_id);
+			let issued = T::Assets::total_issuance(info.lp_token_id);
+			let supplied = Self::total_supplied(&info, &market_account);
+			let amount: T::Balance =
+				safe_multiply_by_rational(lp_amount.into(), supplied.into(), issued.into())?
+					.saturated_into();
+			ensure!(
+				amount <= Self::available_cash(&info, &market_account),
+				Error::<T>::NotEnoughLiquidity
+			);
+			T::Assets::burn_from(
+				info.lp_token_id,
+				&account,
+				lp_amount,
+				Precision::Exact,
+				Fortitude::Polite,
+			)?;
+			T::Assets::transfer(
+				info.config.borrow_asset,
+				&market_account,
+				&account,
+				amount,
+				Preservation::Expendable,
+			)?;
+			Self::deposit_event(Event::<T>::Withdrawn { market_id, account, lp_amount, amount });
+			Ok(())
+		}
+
+		/// Lock `amount` of the collateral asset of the market.
+		///
+		/// Emits `CollateralDeposited` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::deposit_collateral())]
+		pub fn deposit_collateral(
+			origin: OriginFor<T>,
+			market_id: T::MarketId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let account = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::AmountMustBeNonZero);
+			let info = Self::market_info(&market_id)?;
+			T::Assets::transfer(
+				info.config.collateral_asset,
+				&account,
+				&Self::account_id(&market_id),
+				amount,
+				Preservation::Expendable,
+			)?;
+			AccountCollateral::<T>::try_mutate(market_id, &account, |collateral| {
+				*collateral = collateral.safe_add(&amount)?;
+				Ok::<_, DispatchError>(())
+			})?;
+			Self::deposit_event(Event::<T>::CollateralDeposited { market_id, account, amount });
+			Ok(())
+		}
+
+		/// Unlock `amount` of collateral, as long as the position stays above the collateral
+		/// factor.
+		///
+		/// Emits `CollateralWithdrawn` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::withdraw_collateral())]
+		pub fn withdraw_collateral(
+			origin: OriginFor<T>,
+			market_id: T::MarketId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let account = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::AmountMustBeNonZero);
+			let info = Self::try_mutate_market(market_id, |_| Ok(()))?;
+			let collateral = AccountCollateral::<T>::get(market_id, &account)
+				.safe_sub(&amount)
+				.map_err(|_| Error::<T>::NotEnoughCollateral)?;
+			let debt = Self::debt_of(&info, &market_id, &account)?;
+			ensure!(
+				Self::is_healthy(&info, collateral, debt)?,
+				Error::<T>::WouldBeUndercollateralized
+			);
+			AccountCollateral::<T>::insert(market_id, &account, collateral);
+			T::Assets::transfer(
+				info.config.collateral_asset,
+				&Self::account_id(&market_id),
+				&account,
+				amount,
+				Preservation::Expendable,
+			)?;
+			Self::deposit_event(Event::<T>::CollateralWithdrawn { market_id, account, amount });
+			Ok(())
+		}
+
+		/// Borrow `amount` of the borrow asset against the collateral of the caller.
+		///
+		/// Emits `Borrowed` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::borrow())]
+		pub fn borrow(
+			origin: OriginFor<T>,
+			market_id: T::MarketId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let account = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::AmountMustBeNonZero);
+			let market_account = Self::account_id(&market_id);
+			Self::try_mutate_market(market_id, |info| {
+				ensure!(
+					amount <= Self::available_cash(info, &market_account),
+					Error::<T>::NotEnoughLiquidity
+				);
+				let debt = Self::debt_of(info, &market_id, &account)?.safe_add(&amount)?;
+				let collateral = AccountCollateral::<T>::get(market_id, &account);
+				ensure!(
+					Self::is_healthy(info, collateral, debt)?,
+					Error::<T>::WouldBeUndercollateralized
+				);
+				info.total_borrows = info.total_borrows.safe_add(&amount)?;
+				DebtSnapshots::<T>::insert(
+					market_id,
+					&account,
+					BorrowSnapshot { principal: debt, borrow_index: info.borrow_index },
+				);
+				T::Assets::transfer(
+					info.config.borrow_asset,
+					&market_account,
+					&account,
+					amount,
+					Preservation::Expendable,
+				)?;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::<T>::Borrowed { market_id, account, amount });
+			Ok(())
+		}
+
+		/// Repay the debt of `beneficiary`, paid by the caller.
+		///
+		/// Emits `BorrowRepaid` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::repay_borrow())]
+		pub fn repay_borrow(
+			origin: OriginFor<T>,
+			market_id: T::MarketId,
+			beneficiary: T::AccountId,
+			repay: RepayStrategy<T::Balance>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let mut repaid = Zero::zero();
+			Self::try_mutate_market(market_id, |info| {
+				let debt = Self::debt_of(info, &market_id, &beneficiary)?;
+				ensure!(!debt.is_zero(), Error::<T>::NoDebt);
+				repaid = match repay {
+					RepayStrategy::TotalDebt => debt,
+					RepayStrategy::PartialAmount(amount) => amount.min(debt),
+				};
+				ensure!(!repaid.is_zero(), Error::<T>::AmountMustBeNonZero);
+				Self::set_debt(info, &market_id, &beneficiary, debt.saturating_sub(repaid));
+				// Rounding of individual debts may exceed the market total.
+				info.total_borrows = info.total_borrows.saturating_sub(repaid);
+				T::Assets::transfer(
+					info.config.borrow_asset,
+					&sender,
+					&Self::account_id(&market_id),
+					repaid,
+					Preservation::Expendable,
+				)?;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::<T>::BorrowRepaid {
+				market_id,
+				sender,
+				beneficiary,
+				amount: repaid,
+			});
+			Ok(())
+		}
+
+		/// Liquidate the `borrowers` whose position is below the collateral factor. Up to the
+		/// close factor of their debt is repaid by selling collateral worth it plus the
+		/// liquidation incentive through [`Config::Liquidation`]. The repaid debt stays owed to
+		/// lenders until the sale is settled. The debt of positions without collateral is written
+		/// off. Healthy positions are skipped.
+		///
+		/// Emits `LiquidationInitiated` event for each liquidated position and `DebtWrittenOff`
+		/// event for each position without collateral.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::liquidate(borrowers.len() as u32))]
+		pub fn liquidate(
+			origin: OriginFor<T>,
+			market_id: T::MarketId,
+			borrowers: BoundedVec<T::AccountId, T::MaxLiquidationBatchSize>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let market_account = Self::account_id(&market_id);
+			Self::try_mutate_market(market_id, |info| {
+				let mut liquidated = false;
+				for borrower in borrowers {
+					let collateral = AccountCollateral::<T>::get(market_id, &borrower);
+					let debt = Self::debt_of(info, &market_id, &borrower)?;
+					if Self::is_healthy(info, collateral, debt)? {
+						continue
+					}
+					if collateral.is_zero() {
+						Self::set_debt(info, &market_id, &borrower, Zero::zero());
+						info.total_borrows = info.total_borrows.saturating_sub(debt);
+						Self::deposit_event(Event::<T>::DebtWrittenOff {
+							market_id,
+							borrower,
+							debt,
+						});
+						liquidated = true;
+						continue
+					}
+					let repaid = info.config.close_factor.mul_ceil(debt);
+					let seized = Self::collateral_to_seize(info, collateral, repaid)?;
+					// Repaying debt without seizing collateral would forgive it.
+					if seized.is_zero() {
+						continue
+					}
+					if seized == collateral {
+						AccountCollateral::<T>::remove(market_id, &borrower);
+					} else {
+						AccountCollateral::<T>::insert(
+							market_id,
+							&borrower,
+							collateral.saturating_sub(seized),
+						);
+					}
+					Self::set_debt(info, &market_id, &borrower, debt.saturating_sub(repaid));
+					info.total_borrows = info.total_borrows.saturating_sub(repaid);
+					let order_id = Self::sell_collateral(info, &market_account, seized)?;
+					info.liquidating = info.liquidating.safe_add(&repaid)?;
+					Liquidations::<T>::insert(
+						order_id,
+						Liquidation {
+							market_id,
+							borrower: borrower.clone(),
+							debt: repaid,
+							repaid: Zero::zero(),
+						},
+					);
+					Self::deposit_event(Event::<T>::LiquidationInitiated {
+						market_id,
+						borrower,
+						collateral: seized,
+						debt: repaid,
+						order_id,
+					});
+					liquidated = true;
+				}
+				ensure!(liquidated, Error::<T>::NoLiquidatablePosition);
+				Ok(())
+			})?;
+			Ok(())
+		}
+
+		/// Transfer `amount` of the reserves of a market to `beneficiary`.
+		///
+		/// Emits `ReservesWithdrawn` event when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::withdraw_reserves())]
+		pub fn withdraw_reserves(
+			origin: OriginFor<T>,
+			market_id: T::MarketId,
+			amount: T::Balance,
+			beneficiary: T::AccountId,
+		) -> DispatchResult {
+			T::MarketCreationOrigin::ensure_origin(origin)?;
+			let market_account = Self::account_id(&market_id);
+			Self::try_mutate_market(market_id, |info| {
+				info.total_reserves = info
+					.total_reserves
+					.safe_sub(&amount)
+					.map_err(|_| Error::<T>::NotEnoughReserves)?;
+				T::Assets::transfer(
+					info.config.borrow_asset,
+					&market_account,
+					&beneficiary,
+					amount,
+					Preservation::Expendable,
+				)?;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::<T>::ReservesWithdrawn { market_id, beneficiary, amount });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Account holding the supplied, borrowed and collateral assets of a market.
+		pub fn account_id(market_id: &T::MarketId) -> T::AccountId {
+			T::PalletId::get().into_sub_account_truncating(market_id)
+		}
+
+		pub(crate) fn market_info(
+			market_id: &T::MarketId,
+		) -> Result<MarketInfoOf<T>, DispatchError> {
+			Markets::<T>::get(market_id).ok_or_else(|| Error::<T>::MarketDoesNotExist.into())
+		}
+
+		/// Accrue the interest of a market, then apply `f` to it. Storage is only updated if `f`
+		/// succeeds.
+		#[transactional]
+		pub(crate) fn try_mutate_market(
+			market_id: T::MarketId,
+			f: impl FnOnce(&mut MarketInfoOf<T>) -> DispatchResult,
+		) -> Result<MarketInfoOf<T>, DispatchError> {
+			Markets::<T>::try_mutate(market_id, |info| {
+				let info = info.as_mut().ok_or(Error::<T>::MarketDoesNotExist)?;
+				Self::accrue_interest(info, &Self::account_id(&market_id))?;
+				f(info)?;
+				Ok(info.clone())
+			})
+		}
+
+		fn validate_config(config: &MarketConfigOf<T>) -> DispatchResult {
+			ensure!(
+				config.collateral_asset != config.borrow_asset,
+				Error::<T>::SameCollateralAndBorrowAsset
+			);
+			ensure!(
+				config.collateral_factor > Ratio::one(),
+				Error::<T>::CollateralFactorMustBeMoreThanOne
+			);
+			ensure!(!config.close_factor.is_zero(), Error::<T>::CloseFactorMustBeNonZero);
+			Ok(())
+		}
+
+		/// Borrow asset held by the market, reserves included.
+		fn cash(info: &MarketInfoOf<T>, market_account: &T::AccountId) -> T::Balance {
+			T::Assets::balance(info.config.borrow_asset, market_account)
+		}
+
+		/// Cash which can be borrowed or withdrawn by lenders.
+		pub(crate) fn available_cash(
+			info: &MarketInfoOf<T>,
+			market_account: &T::AccountId,
+		) -> T::Balance {
+			Self::cash(info, market_account).saturating_sub(info.total_reserves)
+		}
+
+		/// Value owed to lenders: available cash, outstanding borrows and debt being liquidated.
+		pub(crate) fn total_supplied(
+			info: &MarketInfoOf<T>,
+			market_account: &T::AccountId,
+		) -> T::Balance {
+			Self::available_cash(info, market_account)
+				.saturating_add(info.total_borrows)
+				.saturating_add(info.liquidating)
+		}
+
+		pub(crate) fn accrue_interest(
+			info: &mut MarketInfoOf<T>,
+			market_account: &T::AccountId,
+		) -> DispatchResult {
+			let now = T::UnixTime::now().as_secs();
+			let delta_time = now.saturating_sub(info.accrued_at);
+			if delta_time.is_zero() {
+				return Ok(())
+			}
+			info.accrued_at = now;
+			if info.total_borrows.is_zero() {
+				return Ok(())
+			}
+			let borrows: u128 = info.total_borrows.into();
+			let supplied: u128 = Self::total_supplied(info, market_account).into();
+			let utilization = Percent::from_rational(borrows, supplied.max(borrows));
+			let borrow_rate = info
+				.config
+				.interest_rate_model
+				.get_borrow_rate(utilization)
+				.ok_or(Error::<T>::CannotCalculateBorrowRate)?;
+			let interest: T::Balance = accrued_interest(borrow_rate, borrows, delta_time)
+				.ok_or(ArithmeticError::Overflow)?
+				.saturated_into();
+			info.total_borrows = info.total_borrows.safe_add(&interest)?;
+			info.total_reserves =
+				info.total_reserves.safe_add(&info.config.reserve_factor.mul_floor(interest))?;
+			info.borrow_index = info.borrow_index.safe_add(&increment_index(
+				borrow_rate,
+				info.borrow_index,
+				delta_time,
+			)?)?;
+			Ok(())
+		}
+
+		/// Current debt of `account`, including interest accrued up to the market borrow index.
+		pub(crate) fn debt_of(
+			info: &MarketInfoOf<T>,
+			market_id: &T::MarketId,
+			account: &T::AccountId,
+		) -> Result<T::Balance, DispatchError> {
+			match DebtSnapshots::<T>::get(market_id, account) {
+				Some(snapshot) => Ok(safe_multiply_by_rational(
+					snapshot.principal.into(),
+					info.borrow_index.into_inner(),
+					snapshot.borrow_index.into_inner(),
+				)?
+				.saturated_into()),
+				None => Ok(Zero::zero()),
+			}
+		}
+
+		/// Record `debt` as the debt of `account` at the current borrow index.
+		fn set_debt(
+			info: &MarketInfoOf<T>,
+			market_id: &T::MarketId,
+			account: &T::AccountId,
+			debt: T::Balance,
+		) {
+			if debt.is_zero() {
+				DebtSnapshots::<T>::remove(market_id, account);
+			} else {
+				DebtSnapshots::<T>::insert(
+					market_id,
+					account,
+					BorrowSnapshot { principal: debt, borrow_index: info.borrow_index },
+				);
+			}
+		}
+
+		/// Oracle value of `amount` of `asset_id`, rejecting prices older than the market allows.
+		fn value_of(
+			info: &MarketInfoOf<T>,
+			asset_id: T::AssetId,
+			amount: T::Balance,
+		) -> Result<T::Balance, DispatchError> {
+			if amount.is_zero() {
+				return Ok(Zero::zero())
+			}
+			let Price { price, block } = T::Oracle::get_price(asset_id, amount)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number().saturating_sub(block) <=
+					info.config.max_price_age,
+				Error::<T>::PriceTooOld
+			);
+			Ok(price)
+		}
+
+		/// Whether `collateral` covers `debt` times the collateral factor of the market.
+		pub(crate) fn is_healthy(
+			info: &MarketInfoOf<T>,
+			collateral: T::Balance,
+			debt: T::Balance,
+		) -> Result<bool, DispatchError> {
+			if debt.is_zero() {
+				return Ok(true)
+			}
+			let collateral_value = Self::value_of(info, info.config.collateral_asset, collateral)?;
+			let debt_value = Self::value_of(info, info.config.borrow_asset, debt)?;
+			let required = info
+				.config
+				.collateral_factor
+				.checked_mul_int(debt_value.into())
+				.ok_or(ArithmeticError::Overflow)?;
+			Ok(collateral_value.into() >= required)
+		}
+
+		/// Collateral worth `repaid` of debt plus the liquidation incentive, at most `collateral`.
+		fn collateral_to_seize(
+			info: &MarketInfoOf<T>,
+			collateral: T::Balance,
+			repaid: T::Balance,
+		) -> Result<T::Balance, DispatchError> {
+			let collateral_value = Self::value_of(info, info.config.collateral_asset, collateral)?;
+			if collateral_value.is_zero() {
+				return Ok(collateral)
+			}
+			let debt_value = Self::value_of(info, info.config.borrow_asset, repaid)?;
+			let seized_value =
+				debt_value.saturating_add(info.config.liquidation_incentive.mul_ceil(debt_value));
+			// Rounded up, so that any repaid debt seizes some collateral.
+			let seized: T::Balance = multiply_by_rational_with_rounding(
+				collateral.into(),
+				seized_value.into(),
+				collateral_value.into(),
+				Rounding::Up,
+			)
+			.ok_or(ArithmeticError::Overflow)?
+			.saturated_into();
+			Ok(seized.min(collateral))
+		}
+
+		/// Put `collateral` on sale for the borrow asset, accepting at most the liquidation
+		/// discount below the oracle price.
+		fn sell_collateral(
+			info: &MarketInfoOf<T>,
+			market_account: &T::AccountId,
+			collateral: T::Balance,
+		) -> Result<OrderIdOf<T>, DispatchError> {
+			let collateral_value = Self::value_of(info, info.config.collateral_asset, collateral)?;
+			let borrow_equivalent =
+				T::Oracle::get_price_inverse(info.config.borrow_asset, collateral_value)?;
+			let limit = Ratio::checked_from_rational(borrow_equivalent.into(), collateral.into())
+				.ok_or(ArithmeticError::Overflow)?
+				.saturating_mul(Ratio::from(info.config.liquidation_discount.left_from_one()));
+			let order = Sell::new(
+				info.config.collateral_asset,
+				info.config.borrow_asset,
+				collateral,
+				limit,
+			);
+			T::Liquidation::ask(market_account, order, info.config.liquidation_strategy.clone())
+		}
+
+		pub(crate) fn create_lp_token() -> Result<T::AssetId, DispatchError> {
+			let protocol_id = (Self::index() as u32).to_be_bytes();
+			let nonce = LpTokenNonce::<T>::increment()?;
+			T::LPTokenFactory::create_local_asset(
+				protocol_id,
+				nonce,
+				AssetInfo {
+					name: None,
+					symbol: None,
+					decimals: Some(12),
+					existential_deposit: T::LPTokenExistentialDeposit::get(),
+					ratio: None,
+				},
+			)
+		}
+	}
+
+	impl<T: Config> SellCallback<OrderIdOf<T>, T::Balance> for Pallet<T> {
+		/// Repay the debt of the liquidation with the proceeds, the excess going to the reserves.
+		fn on_sold(order_id: &OrderIdOf<T>, quote_amount: T::Balance) -> DispatchResult {
+			let Some(mut liquidation) = Liquidations::<T>::get(order_id) else { return Ok(()) };
+			Self::try_mutate_market(liquidation.market_id, |info| {
+				let repaid = quote_amount.min(liquidation.debt.saturating_sub(liquidation.repaid));
+				liquidation.repaid = liquidation.repaid.safe_add(&repaid)?;
+				info.liquidating = info.liquidating.saturating_sub(repaid);
+				info.total_reserves =
+					info.total_reserves.safe_add(&quote_amount.saturating_sub(repaid))?;
+				Ok(())
+			})?;
+			Liquidations::<T>::insert(order_id, liquidation);
+			Ok(())
+		}
+
+		/// Give the unsold collateral back to the borrower along with the debt it was to cover,
+		/// so that the position can be liquidated again at current prices. Without unsold
+		/// collateral, the uncovered debt is written off.
+		fn on_closed(order_id: &OrderIdOf<T>, unsold: T::Balance) -> DispatchResult {
+			let Some(Liquidation { market_id, borrower, debt, repaid }) =
+				Liquidations::<T>::take(order_id) else { return Ok(()) };
+			Self::try_mutate_market(market_id, |info| {
+				let outstanding = debt.saturating_sub(repaid);
+				info.liquidating = info.liquidating.saturating_sub(outstanding);
+				if !unsold.is_zero() {
+					AccountCollateral::<T>::try_mutate(market_id, &borrower, |collateral| {
+						*collateral = collateral.safe_add(&unsold)?;
+						Ok::<_, DispatchError>(())
+					})?;
+					let debt =
+						Self::debt_of(info, &market_id, &borrower)?.safe_add(&outstanding)?;
+					Self::set_debt(info, &market_id, &borrower, debt);
+					info.total_borrows = info.total_borrows.safe_add(&outstanding)?;
+				}
+				Ok(())
+			})?;
+			Self::deposit_event(Event::<T>::LiquidationSettled {
+				market_id,
+				borrower,
+				order_id: *order_id,
+				repaid,
+				unsold,
+			});
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,407 @@
+#![cfg(test)]
+
+use crate as pallet_lending;
+use composable_traits::{
+	defi::{CurrencyPair, DeFiEngine, Ratio, Sell, SellCallback, SellEngine, Take},
+	oracle::{Oracle, Price},
+};
+use frame_support::{
+	assert_ok, ord_parameter_types,
+	pallet_prelude::ConstU32,
+	parameter_types,
+	traits::{fungibles::Mutate, tokens::Preservation, ConstU64, EitherOfDiverse, Everything},
+	PalletId,
+};
+use frame_system::{EnsureRoot, EnsureSignedBy};
+use orml_traits::parameter_type_with_key;
+use primitives::currency::ForeignAssetId;
+use sp_arithmetic::traits::Zero;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, ConvertInto, IdentityLookup},
+	DispatchError,
+};
+use sp_std::{cell::RefCell, collections::btree_map::BTreeMap};
+
+pub type CurrencyId = u128;
+pub type BlockNumber = u64;
+pub type MarketId = u32;
+
+pub const NATIVE: CurrencyId = 1;
+pub const BTC: CurrencyId = 2;
+pub const USDT: CurrencyId = 3;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Tokens: orml_tokens,
+		AssetsRegistry: pallet_assets_registry,
+		Assets: pallet_assets,
+		Timestamp: pallet_timestamp,
+		Lending: pallet_lending,
+	}
+);
+
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type FreezeIdentifier = [u8; 8];
+
+	type HoldIdentifier = [u8; 8];
+
+	type MaxHolds = ConstU32<32>;
+
+	type MaxFreezes = ConstU32<32>;
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+pub type AccountId = u128;
+
+#[allow(dead_code)]
+pub static ALICE: AccountId = 1;
+#[allow(dead_code)]
+pub static BOB: AccountId = 2;
+#[allow(dead_code)]
+pub static CHARLIE: AccountId = 3;
+#[allow(dead_code)]
+pub static DAVE: AccountId = 4;
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u128>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+pub type Balance = u128;
+pub type AssetId = u128;
+pub type Amount = i128;
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		Zero::zero()
+	};
+}
+
+pub struct CurrencyHooks;
+impl orml_traits::currency::MutationHooks<AccountId, AssetId, Balance> for CurrencyHooks {
+	type OnDust = ();
+	type OnSlash = ();
+	type PreDeposit = ();
+	type PostDeposit = ();
+	type PreTransfer = ();
+	type PostTransfer = ();
+	type OnNewTokenAccount = ();
+	type OnKilledTokenAccount = ();
+}
+
+type ReserveIdentifier = [u8; 8];
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type ReserveIdentifier = ReserveIdentifier;
+	type MaxReserves = frame_support::traits::ConstU32<2>;
+	type DustRemovalWhitelist = Everything;
+	type CurrencyHooks = CurrencyHooks;
+}
+
+parameter_types! {
+	pub const NativeAssetId: CurrencyId = NATIVE;
+	pub const NetworkId: u32 = 0;
+}
+
+parameter_types! {
+	pub const AssetsRegistryTreasuryAccount: AccountId = 0xffff_ffff;
+}
+
+impl pallet_assets_registry::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type LocalAssetId = AssetId;
+	type ForeignAssetId = ForeignAssetId;
+	type UpdateAssetRegistryOrigin = EnsureRoot<AccountId>;
+	type ParachainOrGovernanceOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+	type Balance = Balance;
+	type Convert = ConvertInto;
+	type NetworkId = NetworkId;
+	type Currency = Balances;
+	type ProposalDeposit = frame_support::traits::ConstU128<1_000>;
+	type MinProposedExistentialDeposit = frame_support::traits::ConstU128<1>;
+	type MinProposedFee = frame_support::traits::ConstU128<1>;
+	type ChallengePeriod = frame_support::traits::ConstU64<10>;
+	type RejectProposalOrigin = EnsureRoot<AccountId>;
+	type TreasuryAccount = AssetsRegistryTreasuryAccount;
+	type PoolId = u128;
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = ();
+	type NativeAssetId = NativeAssetId;
+	type AssetId = CurrencyId;
+	type Balance = Balance;
+	type MultiCurrency = Tokens;
+	type NativeCurrency = Balances;
+	type WeightInfo = ();
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type FreezeOrigin = EnsureRoot<AccountId>;
+	type CurrencyValidator = Valid;
+	type RecurringPaymentDeposit = frame_support::traits::ConstU128<1>;
+	type MaxRecurringPaymentsPerPayer = frame_support::traits::ConstU32<16>;
+	type MinRecurringPaymentInterval = frame_support::traits::ConstU64<1>;
+	type MaxRecurringPaymentFailures = frame_support::traits::ConstU32<3>;
+}
+
+pub struct Valid;
+impl composable_support::validation::Validate<CurrencyId, primitives::currency::ValidateCurrencyId>
+	for Valid
+{
+	fn validate(input: CurrencyId) -> Result<CurrencyId, &'static str> {
+		Ok(input)
+	}
+}
+
+ord_parameter_types! {
+	pub const RootAccount: AccountId = ALICE;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<5>;
+	type WeightInfo = ();
+}
+
+/// Holds the collateral on sale by [`MockSellEngine`], receives it when filled.
+pub static LIQUIDATOR: AccountId = 100;
+
+thread_local! {
+	static PRICES: RefCell<BTreeMap<CurrencyId, Price<Balance, BlockNumber>>> =
+		RefCell::new(BTreeMap::new());
+	static ORDERS: RefCell<BTreeMap<u64, (AccountId, Sell<CurrencyId, Balance>)>> =
+		RefCell::new(BTreeMap::new());
+	static ORDER_NONCE: RefCell<u64> = RefCell::new(0);
+}
+
+/// Oracle quoting the price of the smallest unit of each asset.
+pub struct MockOracle;
+
+impl MockOracle {
+	pub fn set_price(asset_id: CurrencyId, price: Balance) {
+		let block = System::block_number();
+		PRICES.with(|prices| prices.borrow_mut().insert(asset_id, Price { price, block }));
+	}
+
+	fn unit_price(asset_id: CurrencyId) -> Result<Price<Balance, BlockNumber>, DispatchError> {
+		PRICES
+			.with(|prices| prices.borrow().get(&asset_id).cloned())
+			.ok_or_else(|| DispatchError::Other("price not found"))
+	}
+}
+
+impl Oracle for MockOracle {
+	type AssetId = CurrencyId;
+	type Balance = Balance;
+	type Timestamp = BlockNumber;
+	type LocalAssets = ();
+	type MinAnswerBound = ConstU32<1>;
+	type MaxAnswerBound = ConstU32<1>;
+	type TwapWindow = frame_support::traits::ConstU16<1>;
+
+	fn get_price(
+		asset_id: CurrencyId,
+		amount: Balance,
+	) -> Result<Price<Balance, BlockNumber>, DispatchError> {
+		let Price { price, block } = Self::unit_price(asset_id)?;
+		Ok(Price { price: price * amount, block })
+	}
+
+	fn get_twap_for_amount(
+		asset_id: CurrencyId,
+		amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		Self::get_price(asset_id, amount).map(|price| price.price)
+	}
+
+	fn get_ratio(_pair: CurrencyPair<CurrencyId>) -> Result<Ratio, DispatchError> {
+		Err(DispatchError::Other("not implemented"))
+	}
+
+	fn get_price_inverse(asset_id: CurrencyId, amount: Balance) -> Result<Balance, DispatchError> {
+		Ok(amount / Self::unit_price(asset_id)?.price)
+	}
+}
+
+impl crate::BenchmarkHelper<CurrencyId, Balance, ()> for MockOracle {
+	fn set_price(asset_id: CurrencyId, price: Balance) {
+		Self::set_price(asset_id, price)
+	}
+
+	fn liquidation_strategy() {}
+}
+
+/// Keeps the base asset of orders with [`LIQUIDATOR`] until the tests `fill` or `refund` them,
+/// settling them through the [`SellCallback`] of the lending pallet.
+pub struct MockSellEngine;
+
+impl MockSellEngine {
+	/// Number of open orders.
+	pub fn orders() -> usize {
+		ORDERS.with(|orders| orders.borrow().len())
+	}
+
+	/// Fill `order_id` at its limit price, the quote asset being minted to the seller.
+	pub fn fill(order_id: u64) {
+		let (seller, order) = Self::remove(order_id);
+		let proceeds = order.take.quote_limit_amount().expect("limit amount fits");
+		assert_ok!(Tokens::mint_into(order.pair.quote, &seller, proceeds));
+		assert_ok!(<Lending as SellCallback<_, _>>::on_sold(&order_id, proceeds));
+		assert_ok!(<Lending as SellCallback<_, _>>::on_closed(&order_id, 0));
+	}
+
+	/// Close `order_id` unsold, giving the base asset back to the seller.
+	pub fn refund(order_id: u64) {
+		let (seller, order) = Self::remove(order_id);
+		assert_ok!(<Tokens as Mutate<AccountId>>::transfer(
+			order.pair.base,
+			&LIQUIDATOR,
+			&seller,
+			order.take.amount,
+			Preservation::Expendable,
+		));
+		assert_ok!(<Lending as SellCallback<_, _>>::on_closed(&order_id, order.take.amount));
+	}
+
+	fn remove(order_id: u64) -> (AccountId, Sell<CurrencyId, Balance>) {
+		ORDERS
+			.with(|orders| orders.borrow_mut().remove(&order_id))
+			.expect("order is open")
+	}
+}
+
+impl DeFiEngine for MockSellEngine {
+	type MayBeAssetId = CurrencyId;
+	type Balance = Balance;
+	type AccountId = AccountId;
+}
+
+impl SellEngine<()> for MockSellEngine {
+	type OrderId = u64;
+
+	fn ask(
+		from_to: &AccountId,
+		order: Sell<CurrencyId, Balance>,
+		_configuration: (),
+	) -> Result<u64, DispatchError> {
+		<Tokens as Mutate<AccountId>>::transfer(
+			order.pair.base,
+			from_to,
+			&LIQUIDATOR,
+			order.take.amount,
+			Preservation::Expendable,
+		)?;
+		let order_id = ORDER_NONCE.with(|nonce| {
+			*nonce.borrow_mut() += 1;
+			*nonce.borrow()
+		});
+		ORDERS.with(|orders| orders.borrow_mut().insert(order_id, (*from_to, order)));
+		Ok(order_id)
+	}
+
+	fn take(
+		_from_to: &AccountId,
+		_order_id: u64,
+		_take: Take<Balance>,
+	) -> Result<(), DispatchError> {
+		Ok(())
+	}
+}
+
+parameter_types! {
+	pub const LendingPalletId: PalletId = PalletId(*b"lending_");
+	pub const MaxLiquidationBatchSize: u32 = 10;
+	pub const LPTokenExistentialDeposit: Balance = 0;
+	pub const LockedLiquidity: Balance = 10;
+}
+
+impl pallet_lending::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = CurrencyId;
+	type Balance = Balance;
+	type MarketId = MarketId;
+	type LPTokenFactory = AssetsRegistry;
+	type Assets = Assets;
+	type Oracle = MockOracle;
+	type LiquidationStrategy = ();
+	type Liquidation = MockSellEngine;
+	type UnixTime = Timestamp;
+	type PalletId = LendingPalletId;
+	type MaxLiquidationBatchSize = MaxLiquidationBatchSize;
+	type LPTokenExistentialDeposit = LPTokenExistentialDeposit;
+	type LockedLiquidity = LockedLiquidity;
+	type MarketCreationOrigin = EitherOfDiverse<
+		EnsureSignedBy<RootAccount, AccountId>, // for tests
+		EnsureRoot<AccountId>,                  // for benchmarks
+	>;
+	type WeightInfo = ();
+	type BenchmarkHelper = MockOracle;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::default()
+		.build_storage::<Test>()
+		.expect("success")
+		.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
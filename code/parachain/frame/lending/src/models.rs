@@ -0,0 +1,80 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use composable_traits::{
+	defi::{MoreThanOneFixedU128, Rate},
+	rate_model::InterestRateModel,
+	time::Timestamp,
+};
+use scale_info::TypeInfo;
+use sp_runtime::Perquintill;
+
+/// Parameters of a market, set on creation and updatable by governance.
+#[derive(Clone, Encode, Decode, MaxEncodedLen, TypeInfo, Debug, PartialEq, Eq)]
+pub struct MarketConfig<AssetId, BlockNumber, LiquidationStrategy> {
+	/// Asset locked by borrowers.
+	pub collateral_asset: AssetId,
+	/// Asset supplied by lenders and lent to borrowers.
+	pub borrow_asset: AssetId,
+	/// Minimum ratio of the collateral value to the borrowed value, e.g. `1.5` requires 150%
+	/// collateralization. Positions below it can be liquidated.
+	pub collateral_factor: MoreThanOneFixedU128,
+	/// Borrow rate as a function of the utilization of the market.
+	pub interest_rate_model: InterestRateModel,
+	/// Share of the interest kept as reserves instead of paid to lenders.
+	pub reserve_factor: Perquintill,
+	/// Maximum discount to the oracle price accepted when selling seized collateral.
+	pub liquidation_discount: Perquintill,
+	/// Maximum share of the debt of a position repaid by one liquidation.
+	pub close_factor: Perquintill,
+	/// Collateral seized by a liquidation is worth the repaid debt plus this share of it, which
+	/// covers the liquidation discount. Proceeds above the debt go to the reserves.
+	pub liquidation_incentive: Perquintill,
+	/// Oracle prices older than this number of blocks are rejected.
+	pub max_price_age: BlockNumber,
+	/// Configuration passed to the liquidation engine.
+	pub liquidation_strategy: LiquidationStrategy,
+}
+
+#[derive(Clone, Encode, Decode, MaxEncodedLen, TypeInfo, Debug, PartialEq, Eq)]
+pub struct MarketInfo<AssetId, Balance, BlockNumber, LiquidationStrategy> {
+	pub config: MarketConfig<AssetId, BlockNumber, LiquidationStrategy>,
+	/// Token minted to lenders, representing their share of the supplied asset.
+	pub lp_token_id: AssetId,
+	/// Accumulated interest factor, starts at one.
+	pub borrow_index: Rate,
+	/// Outstanding debt of all borrowers, including accrued interest.
+	pub total_borrows: Balance,
+	/// Interest kept by the protocol, part of the market cash.
+	pub total_reserves: Balance,
+	/// Debt repaid by liquidations whose collateral is still on sale. It no longer accrues
+	/// interest, but is still owed to lenders.
+	pub liquidating: Balance,
+	/// Unix time in seconds of the last interest accrual.
+	pub accrued_at: Timestamp,
+}
+
+/// Debt of an account as of its last interaction with the market.
+#[derive(Copy, Clone, Encode, Decode, MaxEncodedLen, TypeInfo, Debug, PartialEq, Eq)]
+pub struct BorrowSnapshot<Balance> {
+	pub principal: Balance,
+	/// Market borrow index when `principal` was recorded.
+	pub borrow_index: Rate,
+}
+
+/// Collateral of a position put on sale by a liquidation, keyed by its order.
+#[derive(Clone, Encode, Decode, MaxEncodedLen, TypeInfo, Debug, PartialEq, Eq)]
+pub struct Liquidation<MarketId, AccountId, Balance> {
+	pub market_id: MarketId,
+	pub borrower: AccountId,
+	/// Debt to be repaid by the proceeds of the sale.
+	pub debt: Balance,
+	/// Proceeds received so far, up to `debt`.
+	pub repaid: Balance,
+}
+
+#[derive(Copy, Clone, Encode, Decode, MaxEncodedLen, TypeInfo, Debug, PartialEq, Eq)]
+pub enum RepayStrategy<Balance> {
+	/// Repay the whole debt, including accrued interest.
+	TotalDebt,
+	/// Repay at most this amount.
+	PartialAmount(Balance),
+}
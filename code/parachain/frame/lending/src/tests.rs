@@ -0,0 +1,342 @@
+use crate::{
+	mock::*,
+	models::{MarketConfig, RepayStrategy},
+	pallet::*,
+};
+use composable_traits::{
+	defi::MoreThanOneFixedU128, rate_model::InterestRateModel, time::SECONDS_PER_YEAR,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::fungibles::{Inspect, Mutate},
+	BoundedVec,
+};
+use sp_runtime::{FixedPointNumber, Perquintill};
+
+const USDT_PRICE: Balance = 1;
+const BTC_PRICE: Balance = 50_000;
+
+fn market_config() -> MarketConfig<CurrencyId, BlockNumber, ()> {
+	MarketConfig {
+		collateral_asset: BTC,
+		borrow_asset: USDT,
+		collateral_factor: MoreThanOneFixedU128::saturating_from_integer(2),
+		interest_rate_model: InterestRateModel::default(),
+		reserve_factor: Perquintill::from_percent(10),
+		liquidation_discount: Perquintill::from_percent(5),
+		close_factor: Perquintill::from_percent(50),
+		liquidation_incentive: Perquintill::from_percent(10),
+		max_price_age: 10,
+		liquidation_strategy: (),
+	}
+}
+
+/// Creates a market where ALICE supplied 1_000_000 USDT, 10 LP tokens being locked, and BOB
+/// locked 100 BTC.
+fn create_market() -> MarketId {
+	MockOracle::set_price(USDT, USDT_PRICE);
+	MockOracle::set_price(BTC, BTC_PRICE);
+	assert_ok!(Lending::create_market(RuntimeOrigin::root(), market_config()));
+	let market_id = MarketCount::<Test>::get();
+	assert_ok!(Tokens::mint_into(USDT, &ALICE, 1_000_000));
+	assert_ok!(Lending::deposit(RuntimeOrigin::signed(ALICE), market_id, 1_000_000));
+	assert_ok!(Tokens::mint_into(BTC, &BOB, 100));
+	assert_ok!(Lending::deposit_collateral(RuntimeOrigin::signed(BOB), market_id, 100));
+	market_id
+}
+
+#[test]
+fn create_and_update_market_validate_config() {
+	new_test_ext().execute_with(|| {
+		let mut config = market_config();
+		config.borrow_asset = BTC;
+		assert_noop!(
+			Lending::create_market(RuntimeOrigin::root(), config),
+			Error::<Test>::SameCollateralAndBorrowAsset
+		);
+		let mut config = market_config();
+		config.collateral_factor = MoreThanOneFixedU128::one();
+		assert_noop!(
+			Lending::create_market(RuntimeOrigin::root(), config),
+			Error::<Test>::CollateralFactorMustBeMoreThanOne
+		);
+		let mut config = market_config();
+		config.close_factor = Perquintill::zero();
+		assert_noop!(
+			Lending::create_market(RuntimeOrigin::root(), config),
+			Error::<Test>::CloseFactorMustBeNonZero
+		);
+		assert_noop!(
+			Lending::create_market(RuntimeOrigin::signed(BOB), market_config()),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(Lending::create_market(RuntimeOrigin::root(), market_config()));
+		let market_id = MarketCount::<Test>::get();
+		let mut config = market_config();
+		config.collateral_asset = NATIVE;
+		assert_noop!(
+			Lending::update_market(RuntimeOrigin::root(), market_id, config),
+			Error::<Test>::MarketAssetsAreImmutable
+		);
+		let mut config = market_config();
+		config.max_price_age = 100;
+		assert_ok!(Lending::update_market(RuntimeOrigin::root(), market_id, config.clone()));
+		assert_eq!(Lending::market(market_id).expect("market exists").config, config);
+	})
+}
+
+#[test]
+fn borrow_and_withdraw_collateral_keep_position_healthy() {
+	new_test_ext().execute_with(|| {
+		let market_id = create_market();
+
+		// 1. 100 BTC cover up to 2_500_000 USDT, but the market only holds 1_000_000.
+		assert_noop!(
+			Lending::borrow(RuntimeOrigin::signed(BOB), market_id, 1_000_001),
+			Error::<Test>::NotEnoughLiquidity
+		);
+		assert_noop!(
+			Lending::borrow(RuntimeOrigin::signed(CHARLIE), market_id, 1),
+			Error::<Test>::WouldBeUndercollateralized
+		);
+		assert_ok!(Lending::borrow(RuntimeOrigin::signed(BOB), market_id, 500_000));
+		assert_eq!(Tokens::balance(USDT, &BOB), 500_000);
+
+		// 2. 500_000 USDT of debt must stay covered by at least 20 BTC.
+		assert_noop!(
+			Lending::withdraw_collateral(RuntimeOrigin::signed(BOB), market_id, 81),
+			Error::<Test>::WouldBeUndercollateralized
+		);
+		assert_ok!(Lending::withdraw_collateral(RuntimeOrigin::signed(BOB), market_id, 80));
+		assert_eq!(AccountCollateral::<Test>::get(market_id, BOB), 20);
+
+		// 3. Stale prices are rejected.
+		System::set_block_number(12);
+		assert_noop!(
+			Lending::borrow(RuntimeOrigin::signed(BOB), market_id, 1),
+			Error::<Test>::PriceTooOld
+		);
+	})
+}
+
+#[test]
+fn interest_accrues_to_lenders_and_reserves() {
+	new_test_ext().execute_with(|| {
+		let market_id = create_market();
+		let lp_token = Lending::market(market_id).expect("market exists").lp_token_id;
+		assert_eq!(Tokens::balance(lp_token, &ALICE), 999_990);
+		assert_ok!(Lending::borrow(RuntimeOrigin::signed(BOB), market_id, 500_000));
+
+		// 1. At 50% utilization the default model charges 7% a year, 10% of it kept as reserves.
+		Timestamp::set_timestamp(SECONDS_PER_YEAR * 1_000);
+		assert_ok!(Tokens::mint_into(USDT, &BOB, 35_000));
+		assert_ok!(Lending::repay_borrow(
+			RuntimeOrigin::signed(BOB),
+			market_id,
+			BOB,
+			RepayStrategy::PartialAmount(35_000)
+		));
+		let info = Lending::market(market_id).expect("market exists");
+		assert_eq!(info.total_borrows, 500_000);
+		assert_eq!(info.total_reserves, 3_500);
+		assert_noop!(
+			Lending::withdraw_reserves(RuntimeOrigin::root(), market_id, 3_501, CHARLIE),
+			Error::<Test>::NotEnoughReserves
+		);
+
+		// 2. Lenders can only withdraw what isn't borrowed.
+		assert_noop!(
+			Lending::withdraw(RuntimeOrigin::signed(ALICE), market_id, 1_000_000),
+			Error::<Test>::NotEnoughLiquidity
+		);
+		assert_ok!(Lending::repay_borrow(
+			RuntimeOrigin::signed(BOB),
+			market_id,
+			BOB,
+			RepayStrategy::TotalDebt
+		));
+		assert_eq!(Tokens::balance(USDT, &BOB), 0);
+		assert!(DebtSnapshots::<Test>::get(market_id, BOB).is_none());
+		assert_noop!(
+			Lending::repay_borrow(
+				RuntimeOrigin::signed(BOB),
+				market_id,
+				BOB,
+				RepayStrategy::TotalDebt
+			),
+			Error::<Test>::NoDebt
+		);
+
+		// 3. The interest not kept as reserves goes to lenders.
+		assert_ok!(Lending::withdraw(RuntimeOrigin::signed(ALICE), market_id, 999_990));
+		assert_eq!(Tokens::balance(USDT, &ALICE), 1_031_489);
+		assert_ok!(Lending::withdraw_reserves(RuntimeOrigin::root(), market_id, 3_500, CHARLIE));
+		assert_eq!(Tokens::balance(USDT, &CHARLIE), 3_500);
+	})
+}
+
+#[test]
+fn first_deposit_locks_liquidity() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Lending::create_market(RuntimeOrigin::root(), market_config()));
+		let market_id = MarketCount::<Test>::get();
+		let lp_token = Lending::market(market_id).expect("market exists").lp_token_id;
+		assert_ok!(Tokens::mint_into(USDT, &ALICE, 1_000));
+		assert_noop!(
+			Lending::deposit(RuntimeOrigin::signed(ALICE), market_id, 10),
+			Error::<Test>::InsufficientFirstDeposit
+		);
+
+		// 1. The locked LP tokens stay in the market account.
+		assert_ok!(Lending::deposit(RuntimeOrigin::signed(ALICE), market_id, 11));
+		assert_eq!(Tokens::balance(lp_token, &ALICE), 1);
+		assert_eq!(Tokens::balance(lp_token, &Lending::account_id(&market_id)), 10);
+
+		// 2. Donating to the market doesn't round the next deposit down to nothing.
+		assert_ok!(Tokens::mint_into(USDT, &Lending::account_id(&market_id), 989));
+		assert_ok!(Tokens::mint_into(USDT, &BOB, 1_000));
+		assert_ok!(Lending::deposit(RuntimeOrigin::signed(BOB), market_id, 1_000));
+		assert_eq!(Tokens::balance(lp_token, &BOB), 11);
+	})
+}
+
+#[test]
+fn liquidate_sells_collateral_of_unhealthy_positions() {
+	new_test_ext().execute_with(|| {
+		let market_id = create_market();
+		let market_account = Lending::account_id(&market_id);
+		assert_ok!(Lending::borrow(RuntimeOrigin::signed(BOB), market_id, 500_000));
+		let borrowers: BoundedVec<_, _> =
+			vec![BOB, DAVE].try_into().expect("within the batch size");
+
+		// 1. 100 BTC at 10_000 USDT exactly cover twice the debt.
+		MockOracle::set_price(BTC, 10_000);
+		assert_noop!(
+			Lending::liquidate(RuntimeOrigin::signed(CHARLIE), market_id, borrowers.clone()),
+			Error::<Test>::NoLiquidatablePosition
+		);
+
+		// 2. Below it, half of the debt is repaid by selling collateral worth 110% of it.
+		MockOracle::set_price(BTC, 5_000);
+		assert_ok!(Lending::liquidate(RuntimeOrigin::signed(CHARLIE), market_id, borrowers));
+		System::assert_last_event(
+			Event::<Test>::LiquidationInitiated {
+				market_id,
+				borrower: BOB,
+				collateral: 55,
+				debt: 250_000,
+				order_id: 1,
+			}
+			.into(),
+		);
+		assert_eq!(MockSellEngine::orders(), 1);
+		assert_eq!(Tokens::balance(BTC, &LIQUIDATOR), 55);
+		assert_eq!(AccountCollateral::<Test>::get(market_id, BOB), 45);
+		assert_eq!(
+			Lending::debt_of(&Lending::market(market_id).expect("market exists"), &market_id, &BOB),
+			Ok(250_000)
+		);
+
+		// 3. The repaid debt is still owed to lenders until the sale is settled.
+		let info = Lending::market(market_id).expect("market exists");
+		assert_eq!(info.total_borrows, 250_000);
+		assert_eq!(info.liquidating, 250_000);
+		assert_eq!(Lending::total_supplied(&info, &market_account), 1_000_000);
+
+		// 4. The collateral sells at a discount of at most 5%, the excess going to the reserves.
+		MockSellEngine::fill(1);
+		System::assert_last_event(
+			Event::<Test>::LiquidationSettled {
+				market_id,
+				borrower: BOB,
+				order_id: 1,
+				repaid: 250_000,
+				unsold: 0,
+			}
+			.into(),
+		);
+		assert!(Liquidations::<Test>::get(1).is_none());
+		let info = Lending::market(market_id).expect("market exists");
+		assert_eq!(info.liquidating, 0);
+		assert_eq!(info.total_reserves, 11_250);
+		assert_eq!(Tokens::balance(USDT, &market_account), 500_000 + 261_250);
+		assert_eq!(Lending::total_supplied(&info, &market_account), 1_000_000);
+	})
+}
+
+#[test]
+fn unsold_collateral_is_given_back_with_its_debt() {
+	new_test_ext().execute_with(|| {
+		let market_id = create_market();
+		assert_ok!(Lending::borrow(RuntimeOrigin::signed(BOB), market_id, 500_000));
+		MockOracle::set_price(BTC, 5_000);
+		let borrowers: BoundedVec<_, _> = vec![BOB].try_into().expect("within the batch size");
+		assert_ok!(Lending::liquidate(RuntimeOrigin::signed(CHARLIE), market_id, borrowers));
+
+		MockSellEngine::refund(1);
+		System::assert_last_event(
+			Event::<Test>::LiquidationSettled {
+				market_id,
+				borrower: BOB,
+				order_id: 1,
+				repaid: 0,
+				unsold: 55,
+			}
+			.into(),
+		);
+		let info = Lending::market(market_id).expect("market exists");
+		assert_eq!(AccountCollateral::<Test>::get(market_id, BOB), 100);
+		assert_eq!(Lending::debt_of(&info, &market_id, &BOB), Ok(500_000));
+		assert_eq!(info.total_borrows, 500_000);
+		assert_eq!(info.liquidating, 0);
+		assert_eq!(Tokens::balance(BTC, &Lending::account_id(&market_id)), 100);
+	})
+}
+
+#[test]
+fn liquidation_seizes_collateral_rounded_up() {
+	new_test_ext().execute_with(|| {
+		let market_id = create_market();
+		assert_ok!(Tokens::mint_into(BTC, &DAVE, 1));
+		assert_ok!(Lending::deposit_collateral(RuntimeOrigin::signed(DAVE), market_id, 1));
+		assert_ok!(Lending::borrow(RuntimeOrigin::signed(DAVE), market_id, 25_000));
+		MockOracle::set_price(BTC, BTC_PRICE - 1);
+
+		// Collateral worth 13_750 USDT is a fraction of a BTC, rounding it down would repay the
+		// debt for free.
+		let borrowers: BoundedVec<_, _> = vec![DAVE].try_into().expect("within the batch size");
+		assert_ok!(Lending::liquidate(RuntimeOrigin::signed(CHARLIE), market_id, borrowers));
+		System::assert_last_event(
+			Event::<Test>::LiquidationInitiated {
+				market_id,
+				borrower: DAVE,
+				collateral: 1,
+				debt: 12_500,
+				order_id: 1,
+			}
+			.into(),
+		);
+		assert_eq!(AccountCollateral::<Test>::get(market_id, DAVE), 0);
+	})
+}
+
+#[test]
+fn debt_without_collateral_is_written_off() {
+	new_test_ext().execute_with(|| {
+		let market_id = create_market();
+		assert_ok!(Lending::borrow(RuntimeOrigin::signed(BOB), market_id, 500_000));
+		AccountCollateral::<Test>::remove(market_id, BOB);
+
+		let borrowers: BoundedVec<_, _> = vec![BOB].try_into().expect("within the batch size");
+		assert_ok!(Lending::liquidate(RuntimeOrigin::signed(CHARLIE), market_id, borrowers));
+		System::assert_last_event(
+			Event::<Test>::DebtWrittenOff { market_id, borrower: BOB, debt: 500_000 }.into(),
+		);
+		let info = Lending::market(market_id).expect("market exists");
+		assert_eq!(Lending::debt_of(&info, &market_id, &BOB), Ok(0));
+		assert_eq!(info.total_borrows, 0);
+		assert_eq!(info.liquidating, 0);
+		assert_eq!(MockSellEngine::orders(), 0);
+	})
+}
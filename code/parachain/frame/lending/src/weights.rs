@@ -0,0 +1,54 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+pub trait WeightInfo {
+	fn create_market() -> Weight;
+	fn update_market() -> Weight;
+	fn deposit() -> Weight;
+	fn withdraw() -> Weight;
+	fn deposit_collateral() -> Weight;
+	fn withdraw_collateral() -> Weight;
+	fn borrow() -> Weight;
+	fn repay_borrow() -> Weight;
+	fn liquidate(b: u32) -> Weight;
+	fn withdraw_reserves() -> Weight;
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_market() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn update_market() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn deposit() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn withdraw() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn deposit_collateral() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn withdraw_collateral() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn borrow() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn repay_borrow() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn liquidate(_b: u32) -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn withdraw_reserves() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+}
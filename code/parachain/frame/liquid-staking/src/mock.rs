@@ -464,6 +464,8 @@ parameter_types! {
 
 impl pallet_xcm_helper::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
 	type UpdateOrigin = UpdateOrigin;
 	type Assets = Assets;
 	type XcmSender = XcmRouter;
@@ -631,6 +633,7 @@ impl composable_support::validation::Validate<CurrencyId, primitives::currency::
 }
 
 impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = ();
 	type NativeAssetId = NativeAssetId;
 	type AssetId = CurrencyId;
@@ -639,7 +642,12 @@ impl pallet_assets::Config for Test {
 	type NativeCurrency = Balances;
 	type WeightInfo = ();
 	type AdminOrigin = EnsureRoot<AccountId>;
+	type FreezeOrigin = EnsureRoot<AccountId>;
 	type CurrencyValidator = Valid;
+	type RecurringPaymentDeposit = frame_support::traits::ConstU128<1>;
+	type MaxRecurringPaymentsPerPayer = frame_support::traits::ConstU32<16>;
+	type MinRecurringPaymentInterval = frame_support::traits::ConstU64<1>;
+	type MaxRecurringPaymentFailures = frame_support::traits::ConstU32<3>;
 }
 
 pub struct CurrencyHooks;
@@ -693,6 +701,10 @@ parameter_types! {
 use sp_runtime::traits::ConvertInto;
 pub type AssetId = u128;
 
+parameter_types! {
+	pub AssetsRegistryTreasuryAccount: AccountId = AccountId32::new([0xff; 32]);
+}
+
 impl pallet_assets_registry::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type LocalAssetId = u128;
@@ -703,6 +715,14 @@ impl pallet_assets_registry::Config for Test {
 	type Balance = Balance;
 	type Convert = ConvertInto;
 	type NetworkId = ComposableNetworkId;
+	type Currency = Balances;
+	type ProposalDeposit = frame_support::traits::ConstU128<1_000>;
+	type MinProposedExistentialDeposit = frame_support::traits::ConstU128<1>;
+	type MinProposedFee = frame_support::traits::ConstU128<1>;
+	type ChallengePeriod = frame_support::traits::ConstU64<10>;
+	type RejectProposalOrigin = EnsureRoot<AccountId>;
+	type TreasuryAccount = AssetsRegistryTreasuryAccount;
+	type PoolId = u128;
 }
 
 construct_runtime!(
@@ -714,7 +734,7 @@ construct_runtime!(
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
 		Utility: pallet_utility::{Pallet, Call, Event},
-		Assets: pallet_assets::{Pallet, Call, Storage},
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
 		Tokens: orml_tokens::{Pallet, Storage, /*Config<T>,*/ Event<T>},
 		LiquidStaking: crate::{Pallet, Storage, Call, Event<T>},
 		ParachainSystem: cumulus_pallet_parachain_system::{Pallet, Call, Config, Storage, Inherent, Event<T>},
@@ -65,7 +65,7 @@ pub mod pallet {
 	use composable_traits::{
 		assets::CreateAsset,
 		defi::{CurrencyPair, Rate},
-		dex::{Amm, BasicPoolInfo, Fee, PriceAggregate},
+		dex::{Amm, BasicPoolInfo, Fee, PriceAggregate, Twap},
 	};
 	use core::fmt::Debug;
 	use frame_support::{
@@ -231,6 +231,8 @@ pub mod pallet {
 		/// Cannot buy an asset with itself.
 		CannotBuyAssetWithItself,
 		IncorrectPoolConfig,
+		/// TWAP is not enabled for the pool.
+		TwapNotEnabled,
 	}
 
 	#[pallet::config]
@@ -732,6 +734,25 @@ pub mod pallet {
 		}
 	}
 
+	impl<T: Config> Twap for Pallet<T> {
+		type AssetId = T::AssetId;
+		type PoolId = T::PoolId;
+
+		fn get_twap(pool_id: Self::PoolId, asset_id: Self::AssetId) -> Result<Rate, DispatchError> {
+			let twap = TWAPState::<T>::get(pool_id).ok_or(Error::<T>::TwapNotEnabled)?;
+			#[allow(deprecated)]
+			let pair = Self::pool_ordered_pair(pool_id)?;
+			// `base_twap` is the amount of base for one quote, and `quote_twap` the other way round
+			if asset_id == pair.quote {
+				Ok(twap.base_twap)
+			} else if asset_id == pair.base {
+				Ok(twap.quote_twap)
+			} else {
+				Err(Error::<T>::AssetNotFound.into())
+			}
+		}
+	}
+
 	impl<T: Config> Amm for Pallet<T> {
 		type AssetId = T::AssetId;
 		type Balance = T::Balance;
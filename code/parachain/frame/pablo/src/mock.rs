@@ -189,6 +189,10 @@ parameter_types! {
 	pub const NetworkId: u32 = 0;
 }
 
+parameter_types! {
+	pub const AssetsRegistryTreasuryAccount: AccountId = 0xffff_ffff;
+}
+
 impl pallet_assets_registry::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type LocalAssetId = AssetId;
@@ -199,9 +203,18 @@ impl pallet_assets_registry::Config for Test {
 	type Balance = Balance;
 	type Convert = ConvertInto;
 	type NetworkId = NetworkId;
+	type Currency = Balances;
+	type ProposalDeposit = frame_support::traits::ConstU128<1_000>;
+	type MinProposedExistentialDeposit = frame_support::traits::ConstU128<1>;
+	type MinProposedFee = frame_support::traits::ConstU128<1>;
+	type ChallengePeriod = frame_support::traits::ConstU64<10>;
+	type RejectProposalOrigin = EnsureRoot<AccountId>;
+	type TreasuryAccount = AssetsRegistryTreasuryAccount;
+	type PoolId = u128;
 }
 
 impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = ();
 	type NativeAssetId = NativeAssetId;
 	type AssetId = CurrencyId;
@@ -210,7 +223,12 @@ impl pallet_assets::Config for Test {
 	type NativeCurrency = Balances;
 	type WeightInfo = ();
 	type AdminOrigin = EnsureRoot<AccountId>;
+	type FreezeOrigin = EnsureRoot<AccountId>;
 	type CurrencyValidator = Valid;
+	type RecurringPaymentDeposit = frame_support::traits::ConstU128<1>;
+	type MaxRecurringPaymentsPerPayer = frame_support::traits::ConstU32<16>;
+	type MinRecurringPaymentInterval = frame_support::traits::ConstU64<1>;
+	type MaxRecurringPaymentFailures = frame_support::traits::ConstU32<3>;
 }
 
 pub struct Valid;
@@ -907,8 +907,39 @@ mod twap {
 	use super::*;
 	use crate::types::TimeWeightedAveragePrice;
 	use composable_tests_helpers::test::block::process_and_progress_blocks;
-	use composable_traits::defi::Rate;
-	use sp_runtime::traits::One;
+	use composable_traits::{defi::Rate, dex::Twap};
+	use sp_runtime::{traits::One, FixedPointNumber};
+
+	#[test]
+	fn twap_prices_asset_in_other_asset_of_pool() {
+		new_test_ext().execute_with(|| {
+			let unit = 1_000_000_000_000_u128;
+			let pool_id = create_pool(
+				BTC,
+				USDT,
+				unit,
+				2 * unit,
+				LP_TOKEN_ID,
+				Permill::zero(),
+				Permill::zero(),
+			);
+
+			// 1. TWAP must be enabled for the pool.
+			assert_noop!(<Pablo as Twap>::get_twap(pool_id, BTC), Error::<Test>::TwapNotEnabled);
+			assert_ok!(Pablo::enable_twap(RuntimeOrigin::root(), pool_id));
+
+			// 2. Each asset is priced in the other one.
+			assert_eq!(
+				<Pablo as Twap>::get_twap(pool_id, BTC),
+				Ok(Rate::saturating_from_integer(2))
+			);
+			assert_eq!(
+				<Pablo as Twap>::get_twap(pool_id, USDT),
+				Ok(Rate::saturating_from_rational(1, 2))
+			);
+			assert_noop!(<Pablo as Twap>::get_twap(pool_id, USDC), Error::<Test>::AssetNotFound);
+		});
+	}
 
 	#[test]
 	fn twap_asset_prices_change_after_twap_interval() {
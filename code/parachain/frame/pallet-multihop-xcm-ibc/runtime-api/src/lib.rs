@@ -0,0 +1,26 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use composable_traits::xcm::memo::{ChainInfo, MultihopTransferStatus};
+use sp_std::vec::Vec;
+
+// Here we declare the runtime API. It is implemented it the `impl` block in
+// runtime amalgamator file (the `runtime/src/lib.rs`)
+sp_api::decl_runtime_apis! {
+	pub trait MultihopXcmIbcRuntimeApi<AccountId, AssetId>
+	where
+		AccountId: Codec,
+		AssetId: Codec,
+	{
+		/// Status of every hop of a multihop transfer, until it is acknowledged or refunded.
+		fn transfer_status(transfer_id: u128) -> Option<MultihopTransferStatus<AccountId, AssetId>>;
+
+		/// Multihop transfers initiated by `owner` which are still tracked.
+		fn transfers_of(owner: AccountId) -> Vec<(u128, MultihopTransferStatus<AccountId, AssetId>)>;
+
+		/// Configured routes with chains ordered from the first hop.
+		fn routes() -> Vec<(u128, Vec<ChainInfo>)>;
+	}
+}
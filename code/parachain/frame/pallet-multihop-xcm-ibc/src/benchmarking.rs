@@ -0,0 +1,106 @@
+use super::*;
+use crate::{BenchmarkHelper, Pallet as PalletMultihopXcmIbc};
+use codec::Decode;
+use composable_traits::xcm::memo::{ChainHop, ChainInfo, HopState, HopStatus};
+use frame_benchmarking::benchmarks;
+use frame_support::{assert_ok, traits::Get, BoundedVec};
+use frame_system::RawOrigin;
+use sp_std::vec::Vec;
+use xcm::latest::prelude::*;
+
+const ROUTE_ID: u128 = 1;
+const AMOUNT: u128 = 1_000_000_000_000;
+
+/// Route over `r` Substrate IBC chains.
+fn route<T: Config>(r: u32) -> RouteBoundedVec<T> {
+	let route = (0..r)
+		.map(|i| {
+			let chain_info = ChainInfo {
+				chain_id: i,
+				order: i as u8,
+				channel_id: i.into(),
+				timestamp: Some(1),
+				height: Some(1),
+				retries: Some(u8::MAX),
+				timeout: Some(600),
+				chain_hop: ChainHop::SubstrateIbc,
+				para_id: None,
+			};
+			(chain_info, BoundedVec::truncate_from(b"centauri".to_vec()))
+		})
+		.collect::<Vec<_>>();
+	BoundedVec::truncate_from(route)
+}
+
+/// Tracks a failed transfer of `AMOUNT` of `asset_id` over a route of `r` hops.
+fn failed_transfer<T: Config>(
+	r: u32,
+	asset_id: T::AssetId,
+	source: Option<MultiLocation>,
+) -> (T::AccountId, u128) {
+	let address_from = [1; 32];
+	let owner = T::AccountId::decode(&mut &address_from[..]).expect("account id is 32 bytes");
+	let hops = (0..r)
+		.map(|chain_id| HopStatus { chain_id, state: HopState::Pending })
+		.collect::<Vec<_>>();
+	let mut transfer = MultihopTransfer {
+		owner: owner.clone(),
+		route_id: ROUTE_ID,
+		asset_id,
+		amount: AMOUNT,
+		address_from,
+		addresses: BoundedVec::truncate_from((0..r).map(|i| [i as u8; 32]).collect()),
+		hops: BoundedVec::truncate_from(hops),
+		attempts: 0,
+		packet: Some((0, 0)),
+		source,
+	};
+	transfer.set_hops(HopState::TimedOut, HopState::Pending);
+	let transfer_id = 0;
+	PacketToTransfer::<T>::insert(0, 0, transfer_id);
+	assert_ok!(AccountTransfers::<T>::try_append(&owner, transfer_id));
+	MultihopTransfers::<T>::insert(transfer_id, transfer);
+	(owner, transfer_id)
+}
+
+benchmarks! {
+	where_clause {
+		where
+			T: Send + Sync,
+			u32: From<T::BlockNumber>,
+			sp_runtime::AccountId32: From<T::AccountId>,
+			<T as pallet_ibc::Config>::AssetId: Into<<T as orml_xtokens::Config>::CurrencyId>,
+			u128: Into<<T as orml_xtokens::Config>::Balance>,
+	}
+
+	add_route {
+		let r in 1 .. T::MaxMultihopCount::get();
+		let route = route::<T>(r);
+	}: _(RawOrigin::Root, ROUTE_ID, route)
+	verify {
+		assert!(RouteIdToRoutePath::<T>::contains_key(ROUTE_ID));
+	}
+
+	retry_transfer {
+		let r in 1 .. T::MaxMultihopCount::get();
+		RouteIdToRoutePath::<T>::insert(ROUTE_ID, route::<T>(r));
+		let (owner, transfer_id) =
+			failed_transfer::<T>(r, T::BenchmarkHelper::refund_asset(), None);
+	}: {
+		// no channel is open in the benchmarks, the IBC transfer is weighed by `pallet_ibc`
+		let _ = PalletMultihopXcmIbc::<T>::retry_transfer(RawOrigin::Signed(owner).into(), transfer_id);
+	}
+
+	refund_transfer {
+		let asset_id = T::BenchmarkHelper::refund_asset();
+		let (owner, transfer_id) = failed_transfer::<T>(
+			T::MaxMultihopCount::get(),
+			asset_id.clone(),
+			Some(MultiLocation::parent()),
+		);
+		T::BenchmarkHelper::fund(&owner, asset_id, AMOUNT);
+	}: _(RawOrigin::Signed(owner), transfer_id)
+	verify {
+		assert!(!MultihopTransfers::<T>::contains_key(transfer_id));
+	}
+}
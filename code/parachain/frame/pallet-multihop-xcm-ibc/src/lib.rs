@@ -34,22 +34,39 @@
 pub use pallet::*;
 
 mod prelude;
+pub mod tracking;
+pub mod weights;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 #[cfg(test)]
 mod tests;
 
+/// Provides an asset refunds can be sent back over XCM in the benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, AssetId> {
+	/// Asset which can be sent to the relay chain with `orml_xtokens`.
+	fn refund_asset() -> AssetId;
+	/// Give `amount` of `asset_id` to `account`.
+	fn fund(account: &AccountId, asset_id: AssetId, amount: u128);
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 
 	use super::{prelude::*, *};
+	pub use crate::weights::WeightInfo;
 	use bech32_no_std::u5;
 	use composable_traits::{
 		centauri::Map,
 		prelude::{String, Vec},
-		xcm::{assets::MultiCurrencyCallback, memo::ChainHop},
+		xcm::{
+			assets::MultiCurrencyCallback,
+			memo::{ChainHop, HopState, HopStatus, MultihopTransferStatus},
+		},
 	};
 	use frame_support::pallet_prelude::*;
-	use frame_system::{ensure_root, RawOrigin};
+	use frame_system::{ensure_root, ensure_signed, RawOrigin};
 	use ibc_primitives::Timeout as IbcTimeout;
 	use ibc_rs_scale::core::ics24_host::identifier::{ChannelId, PortId};
 	use pallet_ibc::{MultiAddress, TransferParams};
@@ -60,18 +77,106 @@ pub mod pallet {
 	use xcm::latest::prelude::*;
 
 	use composable_traits::xcm::memo::ChainInfo;
-	use sp_std::boxed::Box;
+	use sp_std::{boxed::Box, fmt::Debug};
 
 	use frame_support::BoundedVec;
 
 	type AccoindIdOf<T> = <T as frame_system::Config>::AccountId;
-	type RouteBoundedVec<T> = BoundedVec<
+	pub(crate) type RouteBoundedVec<T> = BoundedVec<
 		(ChainInfo, BoundedVec<u8, <T as Config>::ChainNameVecLimit>),
 		<T as Config>::MaxMultihopCount,
 	>;
 	type ListChainNameAddress = Vec<(ChainInfo, Vec<u8>, [u8; 32])>;
+	pub(crate) type MultihopTransferOf<T> = MultihopTransfer<
+		AccoindIdOf<T>,
+		<T as pallet_ibc::Config>::AssetId,
+		<T as Config>::MaxMultihopCount,
+	>;
+
+	/// Multihop transfer initiated by the pallet, tracked by its first IBC packet until it is
+	/// acknowledged or refunded.
+	#[derive(
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		codec::Encode,
+		codec::Decode,
+		scale_info::TypeInfo,
+		MaxEncodedLen,
+		RuntimeDebugNoBound,
+	)]
+	#[scale_info(skip_type_params(MaxHops))]
+	pub struct MultihopTransfer<
+		AccountId: Clone + PartialEq + Eq + Debug,
+		AssetId: Clone + PartialEq + Eq + Debug,
+		MaxHops: Get<u32>,
+	> {
+		pub owner: AccountId,
+		pub route_id: u128,
+		pub asset_id: AssetId,
+		pub amount: u128,
+		/// sender address on this chain as received in the XCM location
+		pub address_from: [u8; 32],
+		/// receiver addresses of every hop, used to resend the transfer
+		pub addresses: BoundedVec<[u8; 32], MaxHops>,
+		pub hops: BoundedVec<HopStatus, MaxHops>,
+		pub attempts: u8,
+		/// (channel id, sequence) of the first packet
+		pub packet: Option<(u64, u64)>,
+		/// Relay chain or sibling parachain the funds came from, refunds are sent back to
+		/// `address_from` there.
+		pub source: Option<MultiLocation>,
+	}
+
+	impl<AccountId, AssetId, MaxHops> MultihopTransfer<AccountId, AssetId, MaxHops>
+	where
+		AccountId: Clone + PartialEq + Eq + Debug,
+		AssetId: Clone + PartialEq + Eq + Debug,
+		MaxHops: Get<u32>,
+	{
+		/// Transfer failed on the first hop and funds are back at the owner.
+		pub fn is_failed(&self) -> bool {
+			matches!(
+				self.hops.first().map(|hop| hop.state),
+				Some(HopState::TimedOut) | Some(HopState::Failed)
+			)
+		}
+
+		pub fn status(&self) -> MultihopTransferStatus<AccountId, AssetId> {
+			MultihopTransferStatus {
+				owner: self.owner.clone(),
+				route_id: self.route_id,
+				asset_id: self.asset_id.clone(),
+				amount: self.amount,
+				attempts: self.attempts,
+				hops: self.hops.clone().into_inner(),
+			}
+		}
+
+		/// Location of `address_from` on the chain the funds came from.
+		pub fn refund_destination(&self) -> Option<MultiLocation> {
+			self.source?
+				.pushed_with_interior(AccountId32 { network: None, id: self.address_from })
+				.ok()
+		}
+
+		pub(crate) fn set_hops(&mut self, first: HopState, rest: HopState) {
+			self.hops.iter_mut().enumerate().for_each(|(index, hop)| {
+				hop.state = if index == 0 { first } else { rest };
+			});
+		}
+	}
 	use frame_system::pallet_prelude::OriginFor;
 
+	/// Chain an XCM deposit came from, if it is the relay chain or a sibling parachain.
+	pub(crate) fn source_chain(origin: Option<&MultiLocation>) -> Option<MultiLocation> {
+		match origin? {
+			location @ MultiLocation { parents: 1, interior: Here } |
+			location @ MultiLocation { parents: 1, interior: X1(Parachain(_)) } => Some(*location),
+			_ => None,
+		}
+	}
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config + pallet_ibc::Config + orml_xtokens::Config {
 		#[allow(missing_docs)]
@@ -86,6 +191,20 @@ pub mod pallet {
 		/// The maximum length of chain name
 		#[pallet::constant]
 		type ChainNameVecLimit: Get<u32>;
+
+		/// Maximal number of tracked transfers of an account. Further transfers are sent without
+		/// being tracked.
+		#[pallet::constant]
+		type MaxTransfersPerAccount: Get<u32>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: crate::BenchmarkHelper<
+			Self::AccountId,
+			<Self as pallet_ibc::Config>::AssetId,
+		>;
 	}
 
 	// The pallet's events
@@ -120,13 +239,54 @@ pub mod pallet {
 			is_error: bool,
 		},
 		FailedMatchLocation {},
+		MultihopTransferSent {
+			transfer_id: u128,
+			owner: T::AccountId,
+			route_id: u128,
+		},
+		/// First packet of the transfer was acknowledged and its tracking ended. Hops after the
+		/// first one were forwarded, their acknowledgements are not observable on this chain.
+		MultihopTransferAcknowledged {
+			transfer_id: u128,
+			hops: BoundedVec<HopStatus, T::MaxMultihopCount>,
+		},
+		MultihopTransferTimedOut {
+			transfer_id: u128,
+		},
+		MultihopTransferFailed {
+			transfer_id: u128,
+		},
+		MultihopTransferRetried {
+			transfer_id: u128,
+			attempts: u8,
+		},
+		MultihopTransferRefunded {
+			transfer_id: u128,
+		},
+		/// Tracking of a failed transfer without known source chain ended, its funds stay with the
+		/// owner.
+		MultihopTransferClosed {
+			transfer_id: u128,
+		},
+		/// Transfer was sent but is not tracked, as its owner has `MaxTransfersPerAccount` tracked
+		/// transfers.
+		MultihopTransferNotTracked {
+			owner: T::AccountId,
+			route_id: u128,
+		},
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
-		IncorrectAddress { chain_id: u8 },
-		IncorrectChainName { chain_id: u8 },
-		FailedToEncodeBech32Address { chain_id: u8 },
+		IncorrectAddress {
+			chain_id: u8,
+		},
+		IncorrectChainName {
+			chain_id: u8,
+		},
+		FailedToEncodeBech32Address {
+			chain_id: u8,
+		},
 		IncorrectMultiLocation,
 		XcmDepositFailed,
 		MultiHopRouteDoesNotExist,
@@ -134,6 +294,15 @@ pub mod pallet {
 		IncorrectCountOfAddresses,
 		FailedToConstructMemo,
 		FailedToDecodeAccountId,
+		MultihopTransferDoesNotExist,
+		NotTransferOwner,
+		/// Only transfers which timed out or failed can be retried or refunded.
+		MultihopTransferNotFailed,
+		/// All retries allowed by the route were used.
+		RetriesExhausted,
+		RetryFailed,
+		/// Funds could not be sent back to the chain they came from.
+		RefundFailed,
 	}
 
 	#[derive(
@@ -165,6 +334,7 @@ pub mod pallet {
 		DoesNotSupportNonFungible,
 		FailedCreateMemo,
 		FailedToConvertMemoIntoPalletIbcMemoMessageType,
+		IbcTransferFailed,
 	}
 
 	#[pallet::pallet]
@@ -180,11 +350,50 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	#[allow(clippy::disallowed_types)]
+	pub type NextTransferId<T: Config> = StorageValue<_, u128, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn multihop_transfer)]
+	pub type MultihopTransfers<T: Config> =
+		StorageMap<_, Blake2_128Concat, u128, MultihopTransferOf<T>, OptionQuery>;
+
+	/// Ids of the tracked transfers of an account.
+	#[pallet::storage]
+	#[allow(clippy::disallowed_types)]
+	pub type AccountTransfers<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<u128, T::MaxTransfersPerAccount>,
+		ValueQuery,
+	>;
+
+	/// First packets of tracked transfers, used to route IBC callbacks.
+	#[pallet::storage]
+	pub type PacketToTransfer<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		u64, /* channel id */
+		Blake2_128Concat,
+		u64,  /* sequence */
+		u128, /* transfer id */
+		OptionQuery,
+	>;
+
 	// The pallet's dispatchable functions.
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {
+	impl<T: Config> Pallet<T>
+	where
+		T: Send + Sync,
+		u32: From<<T as frame_system::Config>::BlockNumber>,
+		sp_runtime::AccountId32: From<<T as frame_system::Config>::AccountId>,
+		<T as pallet_ibc::Config>::AssetId: Into<<T as orml_xtokens::Config>::CurrencyId>,
+		u128: Into<<T as orml_xtokens::Config>::Balance>,
+	{
 		#[pallet::call_index(0)]
-		#[pallet::weight(100_000)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_route(route.len() as u32))]
 		pub fn add_route(
 			origin: OriginFor<T>,
 			route_id: u128,
@@ -194,6 +403,94 @@ pub mod pallet {
 			RouteIdToRoutePath::<T>::insert(route_id, route);
 			Ok(())
 		}
+
+		/// Send again a transfer which timed out or failed.
+		///
+		/// Funds returned by IBC to the owner are sent over the same route. The first hop uses its
+		/// `ChainInfo::timeout` (seconds) as timeout if set. The number of retries is limited by
+		/// `ChainInfo::retries` of the first hop.
+		#[pallet::call_index(1)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::retry_transfer(T::MaxMultihopCount::get())
+				.saturating_add(<<T as pallet_ibc::Config>::WeightInfo as pallet_ibc::WeightInfo>::transfer())
+		)]
+		pub fn retry_transfer(origin: OriginFor<T>, transfer_id: u128) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut transfer = MultihopTransfers::<T>::get(transfer_id)
+				.ok_or(Error::<T>::MultihopTransferDoesNotExist)?;
+			ensure!(transfer.owner == who, Error::<T>::NotTransferOwner);
+			ensure!(transfer.is_failed(), Error::<T>::MultihopTransferNotFailed);
+
+			let route = RouteIdToRoutePath::<T>::get(transfer.route_id)
+				.ok_or(Error::<T>::MultiHopRouteDoesNotExist)?;
+			let retries = route
+				.iter()
+				.min_by_key(|(chain_info, _)| chain_info.order)
+				.and_then(|(chain_info, _)| chain_info.retries)
+				.unwrap_or_default();
+			ensure!(transfer.attempts < retries, Error::<T>::RetriesExhausted);
+
+			let (_, packet) = Self::send_multihop(
+				transfer.address_from,
+				transfer.route_id,
+				transfer.addresses.clone().into_inner(),
+				transfer.amount,
+				transfer.asset_id.clone(),
+				true,
+			)
+			.ok_or(Error::<T>::RetryFailed)?;
+
+			if let Some((channel_id, sequence)) = transfer.packet {
+				PacketToTransfer::<T>::remove(channel_id, sequence);
+			}
+			if let Some((channel_id, sequence)) = packet {
+				PacketToTransfer::<T>::insert(channel_id, sequence, transfer_id);
+			}
+			transfer.packet = packet;
+			transfer.attempts = transfer.attempts.saturating_add(1);
+			transfer.set_hops(HopState::Sent, HopState::Pending);
+			let attempts = transfer.attempts;
+			MultihopTransfers::<T>::insert(transfer_id, transfer);
+			Self::deposit_event(Event::<T>::MultihopTransferRetried { transfer_id, attempts });
+			Ok(())
+		}
+
+		/// Give up on a transfer which timed out or failed and send its funds back.
+		///
+		/// IBC returns funds of a failed transfer to the owner, they are sent back over XCM to
+		/// `address_from` on the chain they came from. Funds of transfers with unknown source stay
+		/// with the owner and the transfer is closed instead. Either way the transfer is no longer
+		/// tracked.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::refund_transfer())]
+		pub fn refund_transfer(origin: OriginFor<T>, transfer_id: u128) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let transfer = MultihopTransfers::<T>::get(transfer_id)
+				.ok_or(Error::<T>::MultihopTransferDoesNotExist)?;
+			ensure!(transfer.owner == who, Error::<T>::NotTransferOwner);
+			ensure!(transfer.is_failed(), Error::<T>::MultihopTransferNotFailed);
+			let refunded = match transfer.refund_destination() {
+				Some(destination) => {
+					orml_xtokens::Pallet::<T>::transfer(
+						RawOrigin::Signed(who).into(),
+						transfer.asset_id.clone().into(),
+						transfer.amount.into(),
+						Box::new(destination.into()),
+						WeightLimit::Unlimited,
+					)
+					.map_err(|_| Error::<T>::RefundFailed)?;
+					true
+				},
+				None => false,
+			};
+			Self::remove_transfer(transfer_id, &transfer);
+			if refunded {
+				Self::deposit_event(Event::<T>::MultihopTransferRefunded { transfer_id });
+			} else {
+				Self::deposit_event(Event::<T>::MultihopTransferClosed { transfer_id });
+			}
+			Ok(())
+		}
 	}
 
 	impl<T: Config> pallet_ibc::ics20::SubstrateMultihopXcmHandler for Pallet<T>
@@ -448,7 +745,8 @@ pub mod pallet {
 		///					AccountId32 { id: current_network_address, network: _ },
 		///					AccountId32 { id: ibc1, network: _ },
 		///				)
-		/// * `context` - context of the chain to deposit. ingnored
+		/// * `context` - context of the chain to deposit, its origin is recorded to refund the
+		///   transfer
 		/// * `deposit_result` - result of the deposit, should be ok to continue
 		/// * `asset_id` - asset id, asset id of the asset to deposit
 		///
@@ -482,7 +780,7 @@ pub mod pallet {
 		fn deposit_asset(
 			asset: &xcm::latest::MultiAsset,
 			location: &xcm::latest::MultiLocation,
-			_context: &xcm::latest::XcmContext,
+			context: &xcm::latest::XcmContext,
 			deposit_result: xcm::latest::Result,
 			asset_id: Self::AssetId,
 		) -> Option<()> {
@@ -570,7 +868,7 @@ pub mod pallet {
 				},
 			};
 
-			let (pallet_id, address_from, route_id, mut addresses) = location_info;
+			let (pallet_id, address_from, route_id, addresses) = location_info;
 
 			if *pallet_id != T::PalletInstanceId::get() {
 				<Pallet<T>>::deposit_event(crate::Event::<T>::FailedCallback {
@@ -581,6 +879,60 @@ pub mod pallet {
 				return None
 			}
 
+			//do not support non fungible.
+			let Fungibility::Fungible(ref amount) = asset.fun else {
+				<Pallet<T>>::deposit_event(crate::Event::<T>::FailedCallback {
+					origin_address: address_from,
+					route_id,
+					reason: MultihopEventReason::DoesNotSupportNonFungible,
+				});
+				return None
+			};
+
+			let (owner, packet) = Self::send_multihop(
+				address_from,
+				route_id,
+				addresses.clone(),
+				*amount,
+				asset_id.clone(),
+				false,
+			)?;
+			Self::track_transfer(
+				owner,
+				route_id,
+				asset_id,
+				*amount,
+				address_from,
+				addresses,
+				packet,
+				source_chain(context.origin.as_ref()),
+			);
+			Some(())
+		}
+	}
+
+	impl<T: Config> Pallet<T>
+	where
+		T: Send + Sync,
+		u32: From<<T as frame_system::Config>::BlockNumber>,
+		sp_runtime::AccountId32: From<<T as frame_system::Config>::AccountId>,
+	{
+		/// Initiate IBC transfer of `amount` of `asset_id` from `address_from` along the route.
+		///
+		/// Emits events describing the failure the same way `deposit_asset` does.
+		/// On `retry` the first hop uses `ChainInfo::timeout` as timeout.
+		///
+		/// # Returns
+		/// * `Option<(T::AccountId, Option<(u64, u64)>)>` - sender and (channel id, sequence) of
+		///   the sent packet if it is known
+		fn send_multihop(
+			address_from: [u8; 32],
+			route_id: u128,
+			mut addresses: Vec<[u8; 32]>,
+			amount: u128,
+			asset_id: T::AssetId,
+			retry: bool,
+		) -> Option<(T::AccountId, Option<(u64, u64)>)> {
 			//route does not exist
 			let Ok(mut route) = RouteIdToRoutePath::<T>::try_get(route_id) else {
 				<Pallet<T>>::deposit_event(crate::Event::<T>::FailedCallback {
@@ -663,13 +1015,14 @@ pub mod pallet {
 				};
 				MultiAddress::<AccoindIdOf<T>>::Id(account_id_from)
 			};
+			let timestamp = match (retry, next_chain_info.timeout) {
+				(true, Some(timeout)) => Some(timeout.saturating_mul(1_000_000_000)),
+				_ => next_chain_info.timestamp,
+			};
 			let transfer_params = TransferParams::<AccoindIdOf<T>> {
 				to: account_id,
 				source_channel: next_chain_info.channel_id,
-				timeout: IbcTimeout::Offset {
-					timestamp: next_chain_info.timestamp,
-					height: next_chain_info.height,
-				},
+				timeout: IbcTimeout::Offset { timestamp, height: next_chain_info.height },
 			};
 
 			let account_from = sp_runtime::AccountId32::new(address_from);
@@ -684,16 +1037,6 @@ pub mod pallet {
 			};
 			let signed_account_id = RawOrigin::Signed(account_id_from.clone());
 
-			//do not support non fungible.
-			let Fungibility::Fungible(ref amount) = asset.fun else {
-				<Pallet<T>>::deposit_event(crate::Event::<T>::FailedCallback {
-					origin_address: address_from,
-					route_id,
-					reason: MultihopEventReason::DoesNotSupportNonFungible,
-				});
-				return None
-			};
-
 			let mut memo: Option<<T as pallet_ibc::Config>::MemoMessage> = None;
 
 			// chain_info_iter does not contains the first IBC chain in the route, addresses does
@@ -730,11 +1073,12 @@ pub mod pallet {
 				memo = Some(memo_result)
 			}
 
+			let sequence = Self::next_sequence_send(next_chain_info.channel_id);
 			let result = pallet_ibc::Pallet::<T>::transfer(
 				signed_account_id.into(),
 				transfer_params,
 				asset_id.clone(),
-				(*amount).into(),
+				amount.into(),
 				memo.clone(),
 			);
 
@@ -743,7 +1087,7 @@ pub mod pallet {
 					<Pallet<T>>::deposit_event(crate::Event::<T>::SuccessXcmToIbc {
 						origin_address: account_id_from,
 						to: raw_address_to,
-						amount: *amount,
+						amount,
 						asset_id,
 						memo,
 					});
@@ -752,20 +1096,169 @@ pub mod pallet {
 					<Pallet<T>>::deposit_event(crate::Event::<T>::FailedXcmToIbc {
 						origin_address: account_id_from,
 						to: raw_address_to,
-						amount: *amount,
+						amount,
 						asset_id,
 						memo,
 					});
 					frame_support::log::error!(
-						"Failed to deposit asset: {:?} to location: {:?}, with error: {:?}",
-						asset,
-						location,
+						"Failed to send asset: {:?} over route: {:?}, with error: {:?}",
+						asset_id,
+						route_id,
 						e
 					);
 					return None
 				},
 			}
-			Some(())
+			Some((account_id_from, sequence.map(|sequence| (next_chain_info.channel_id, sequence))))
+		}
+
+		#[allow(clippy::too_many_arguments)]
+		fn track_transfer(
+			owner: T::AccountId,
+			route_id: u128,
+			asset_id: T::AssetId,
+			amount: u128,
+			address_from: [u8; 32],
+			addresses: Vec<[u8; 32]>,
+			packet: Option<(u64, u64)>,
+			source: Option<MultiLocation>,
+		) {
+			let Some(mut route) = RouteIdToRoutePath::<T>::get(route_id) else { return };
+			route.sort_by_key(|item| item.0.order);
+			let hops = route
+				.iter()
+				.enumerate()
+				.map(|(index, (chain_info, _))| HopStatus {
+					chain_id: chain_info.chain_id,
+					state: if index == 0 { HopState::Sent } else { HopState::Pending },
+				})
+				.collect::<Vec<_>>();
+			// route and addresses are bounded by the route length
+			let (Ok(hops), Ok(addresses)) =
+				(BoundedVec::try_from(hops), BoundedVec::try_from(addresses))
+			else {
+				return
+			};
+			let transfer_id = NextTransferId::<T>::get();
+			if AccountTransfers::<T>::try_append(&owner, transfer_id).is_err() {
+				Self::deposit_event(Event::<T>::MultihopTransferNotTracked { owner, route_id });
+				return
+			}
+			NextTransferId::<T>::set(transfer_id.wrapping_add(1));
+			if let Some((channel_id, sequence)) = packet {
+				PacketToTransfer::<T>::insert(channel_id, sequence, transfer_id);
+			}
+			MultihopTransfers::<T>::insert(
+				transfer_id,
+				MultihopTransfer {
+					owner: owner.clone(),
+					route_id,
+					asset_id,
+					amount,
+					address_from,
+					addresses,
+					hops,
+					attempts: 0,
+					packet,
+					source,
+				},
+			);
+			Self::deposit_event(Event::<T>::MultihopTransferSent { transfer_id, owner, route_id });
+		}
+
+		fn next_sequence_send(channel_id: u64) -> Option<u64> {
+			use ibc::core::{
+				ics04_channel::context::ChannelReader,
+				ics24_host::identifier::{ChannelId, PortId},
+			};
+			let ctx = pallet_ibc::routing::Context::<T>::new();
+			ctx.get_next_sequence_send(&(PortId::transfer(), ChannelId::new(channel_id)))
+				.ok()
+				.map(Into::into)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Called by [`crate::tracking::MultihopTracker`] when the first packet of a transfer is
+		/// acknowledged.
+		pub(crate) fn on_packet_acknowledged(channel_id: u64, sequence: u64, success: bool) {
+			let Some(transfer_id) = PacketToTransfer::<T>::take(channel_id, sequence) else {
+				return
+			};
+			let Some(mut transfer) = MultihopTransfers::<T>::get(transfer_id) else { return };
+			transfer.packet = None;
+			if success {
+				// only the first packet is sent from this chain, following hops were forwarded
+				transfer.set_hops(HopState::Acknowledged, HopState::Sent);
+				Self::remove_transfer(transfer_id, &transfer);
+				Self::deposit_event(Event::<T>::MultihopTransferAcknowledged {
+					transfer_id,
+					hops: transfer.hops,
+				});
+			} else {
+				// error is propagated back by forwarding hops, so only the first one is known to
+				// fail
+				transfer.set_hops(HopState::Failed, HopState::Pending);
+				MultihopTransfers::<T>::insert(transfer_id, transfer);
+				Self::deposit_event(Event::<T>::MultihopTransferFailed { transfer_id });
+			}
+		}
+
+		/// Stop tracking `transfer`.
+		fn remove_transfer(transfer_id: u128, transfer: &MultihopTransferOf<T>) {
+			if let Some((channel_id, sequence)) = transfer.packet {
+				PacketToTransfer::<T>::remove(channel_id, sequence);
+			}
+			AccountTransfers::<T>::mutate_exists(&transfer.owner, |transfers| {
+				if let Some(ids) = transfers {
+					ids.retain(|id| *id != transfer_id);
+					if ids.is_empty() {
+						*transfers = None;
+					}
+				}
+			});
+			MultihopTransfers::<T>::remove(transfer_id);
+		}
+
+		/// Called by [`crate::tracking::MultihopTracker`] when the first packet of a transfer
+		/// times out.
+		pub(crate) fn on_packet_timeout(channel_id: u64, sequence: u64) {
+			let Some(transfer_id) = PacketToTransfer::<T>::take(channel_id, sequence) else {
+				return
+			};
+			MultihopTransfers::<T>::mutate(transfer_id, |transfer| {
+				if let Some(transfer) = transfer {
+					transfer.set_hops(HopState::TimedOut, HopState::Pending);
+					transfer.packet = None;
+				}
+			});
+			Self::deposit_event(Event::<T>::MultihopTransferTimedOut { transfer_id });
+		}
+
+		pub fn transfer_status(
+			transfer_id: u128,
+		) -> Option<MultihopTransferStatus<T::AccountId, T::AssetId>> {
+			MultihopTransfers::<T>::get(transfer_id).map(|transfer| transfer.status())
+		}
+
+		pub fn transfers_of(
+			owner: T::AccountId,
+		) -> Vec<(u128, MultihopTransferStatus<T::AccountId, T::AssetId>)> {
+			AccountTransfers::<T>::get(owner)
+				.into_iter()
+				.filter_map(|transfer_id| {
+					Self::transfer_status(transfer_id).map(|status| (transfer_id, status))
+				})
+				.collect()
+		}
+
+		pub fn routes() -> Vec<(u128, Vec<ChainInfo>)> {
+			RouteIdToRoutePath::<T>::iter()
+				.map(|(route_id, mut route)| {
+					route.sort_by_key(|item| item.0.order);
+					(route_id, route.into_iter().map(|(chain_info, _)| chain_info).collect())
+				})
+				.collect()
 		}
 	}
 }
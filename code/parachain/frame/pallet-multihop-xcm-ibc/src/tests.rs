@@ -36,3 +36,83 @@ fn test_memo() {
 	println!("res : {}", res);
 	assert_eq!(encoded_address, res);
 }
+
+mod transfers {
+	use crate::{pallet::source_chain, MultihopTransfer};
+	use composable_traits::xcm::memo::{HopState, HopStatus};
+	use frame_support::{traits::ConstU32, BoundedVec};
+	use xcm::latest::prelude::*;
+
+	fn transfer(
+		first: HopState,
+		source: Option<MultiLocation>,
+	) -> MultihopTransfer<u64, u128, ConstU32<4>> {
+		let mut transfer = MultihopTransfer {
+			owner: 1,
+			route_id: 1,
+			asset_id: 4,
+			amount: 1_000,
+			address_from: [7; 32],
+			addresses: BoundedVec::truncate_from(vec![[1; 32], [2; 32]]),
+			hops: BoundedVec::truncate_from(vec![
+				HopStatus { chain_id: 2, state: HopState::Sent },
+				HopStatus { chain_id: 3, state: HopState::Pending },
+			]),
+			attempts: 0,
+			packet: None,
+			source,
+		};
+		transfer.set_hops(first, HopState::Pending);
+		transfer
+	}
+
+	#[test]
+	fn only_failed_or_timed_out_transfers_are_failed() {
+		assert!(transfer(HopState::TimedOut, None).is_failed());
+		assert!(transfer(HopState::Failed, None).is_failed());
+		assert!(!transfer(HopState::Sent, None).is_failed());
+		assert!(!transfer(HopState::Acknowledged, None).is_failed());
+		assert!(!transfer(HopState::Refunded, None).is_failed());
+	}
+
+	#[test]
+	fn set_hops_updates_first_and_following_hops() {
+		let mut transfer = transfer(HopState::Sent, None);
+		transfer.set_hops(HopState::Refunded, HopState::Refunded);
+		let status = transfer.status();
+		assert!(status.hops.iter().all(|hop| hop.state == HopState::Refunded));
+		assert_eq!(status.hops.len(), 2);
+		assert_eq!(status.amount, 1_000);
+	}
+
+	#[test]
+	fn source_chain_accepts_relay_and_siblings_only() {
+		let relay = MultiLocation::parent();
+		let sibling = MultiLocation::new(1, X1(Parachain(2_019)));
+		assert_eq!(source_chain(Some(&relay)), Some(relay));
+		assert_eq!(source_chain(Some(&sibling)), Some(sibling));
+		assert_eq!(source_chain(Some(&MultiLocation::here())), None);
+		assert_eq!(
+			source_chain(Some(&MultiLocation::new(1, X2(Parachain(2_019), PalletInstance(1))))),
+			None
+		);
+		assert_eq!(source_chain(None), None);
+	}
+
+	#[test]
+	fn refund_goes_to_sender_on_source_chain() {
+		let sibling = MultiLocation::new(1, X1(Parachain(2_019)));
+		assert_eq!(
+			transfer(HopState::Failed, Some(sibling)).refund_destination(),
+			Some(MultiLocation::new(
+				1,
+				X2(Parachain(2_019), AccountId32 { network: None, id: [7; 32] })
+			))
+		);
+		assert_eq!(
+			transfer(HopState::Failed, Some(MultiLocation::parent())).refund_destination(),
+			Some(MultiLocation::new(1, X1(AccountId32 { network: None, id: [7; 32] })))
+		);
+		assert_eq!(transfer(HopState::Failed, None).refund_destination(), None);
+	}
+}
@@ -0,0 +1,171 @@
+//! IBC middleware reporting acknowledgements and timeouts of ICS20 packets to the pallet.
+//!
+//! Wraps the ICS20 module in the runtime router, every callback is forwarded to the wrapped
+//! module unchanged.
+use crate::{Config, Pallet};
+use core::marker::PhantomData;
+use ibc::{
+	core::{
+		ics04_channel::{
+			channel::{Counterparty, Order},
+			error::Error as IbcError,
+			msgs::acknowledgement::Acknowledgement,
+			packet::Packet,
+			Version as IbcVersion,
+		},
+		ics24_host::identifier::{ChannelId, ConnectionId, PortId},
+		ics26_routing::context::{Module, ModuleCallbackContext, ModuleOutputBuilder},
+	},
+	signer::Signer,
+};
+
+/// ICS20 error acknowledgement is JSON object with `error` field
+const ICS20_ERROR_ACK_PREFIX: &[u8] = br#"{"error""#;
+
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct MultihopTracker<T, M> {
+	inner: M,
+	_marker: PhantomData<T>,
+}
+
+impl<T: Config + Send + Sync, M: Module> Module for MultihopTracker<T, M> {
+	fn on_chan_open_init(
+		&mut self,
+		ctx: &dyn ModuleCallbackContext,
+		output: &mut ModuleOutputBuilder,
+		order: Order,
+		connection_hops: &[ConnectionId],
+		port_id: &PortId,
+		channel_id: &ChannelId,
+		counterparty: &Counterparty,
+		version: &IbcVersion,
+		relayer: &Signer,
+	) -> Result<(), IbcError> {
+		self.inner.on_chan_open_init(
+			ctx,
+			output,
+			order,
+			connection_hops,
+			port_id,
+			channel_id,
+			counterparty,
+			version,
+			relayer,
+		)
+	}
+
+	fn on_chan_open_try(
+		&mut self,
+		ctx: &dyn ModuleCallbackContext,
+		output: &mut ModuleOutputBuilder,
+		order: Order,
+		connection_hops: &[ConnectionId],
+		port_id: &PortId,
+		channel_id: &ChannelId,
+		counterparty: &Counterparty,
+		version: &IbcVersion,
+		counterparty_version: &IbcVersion,
+		relayer: &Signer,
+	) -> Result<IbcVersion, IbcError> {
+		self.inner.on_chan_open_try(
+			ctx,
+			output,
+			order,
+			connection_hops,
+			port_id,
+			channel_id,
+			counterparty,
+			version,
+			counterparty_version,
+			relayer,
+		)
+	}
+
+	fn on_chan_open_ack(
+		&mut self,
+		ctx: &dyn ModuleCallbackContext,
+		output: &mut ModuleOutputBuilder,
+		port_id: &PortId,
+		channel_id: &ChannelId,
+		counterparty_version: &IbcVersion,
+		relayer: &Signer,
+	) -> Result<(), IbcError> {
+		self.inner
+			.on_chan_open_ack(ctx, output, port_id, channel_id, counterparty_version, relayer)
+	}
+
+	fn on_chan_open_confirm(
+		&mut self,
+		ctx: &dyn ModuleCallbackContext,
+		output: &mut ModuleOutputBuilder,
+		port_id: &PortId,
+		channel_id: &ChannelId,
+		relayer: &Signer,
+	) -> Result<(), IbcError> {
+		self.inner.on_chan_open_confirm(ctx, output, port_id, channel_id, relayer)
+	}
+
+	fn on_chan_close_init(
+		&mut self,
+		ctx: &dyn ModuleCallbackContext,
+		output: &mut ModuleOutputBuilder,
+		port_id: &PortId,
+		channel_id: &ChannelId,
+		relayer: &Signer,
+	) -> Result<(), IbcError> {
+		self.inner.on_chan_close_init(ctx, output, port_id, channel_id, relayer)
+	}
+
+	fn on_chan_close_confirm(
+		&mut self,
+		ctx: &dyn ModuleCallbackContext,
+		output: &mut ModuleOutputBuilder,
+		port_id: &PortId,
+		channel_id: &ChannelId,
+		relayer: &Signer,
+	) -> Result<(), IbcError> {
+		self.inner.on_chan_close_confirm(ctx, output, port_id, channel_id, relayer)
+	}
+
+	fn on_recv_packet(
+		&self,
+		ctx: &dyn ModuleCallbackContext,
+		output: &mut ModuleOutputBuilder,
+		packet: &mut Packet,
+		relayer: &Signer,
+	) -> Result<Acknowledgement, IbcError> {
+		self.inner.on_recv_packet(ctx, output, packet, relayer)
+	}
+
+	fn on_acknowledgement_packet(
+		&mut self,
+		ctx: &dyn ModuleCallbackContext,
+		output: &mut ModuleOutputBuilder,
+		packet: &mut Packet,
+		acknowledgement: &Acknowledgement,
+		relayer: &Signer,
+	) -> Result<(), IbcError> {
+		let channel_id = packet.source_channel.sequence();
+		let sequence = packet.sequence.into();
+		let success =
+			!acknowledgement.clone().into_bytes().starts_with(ICS20_ERROR_ACK_PREFIX);
+		self.inner
+			.on_acknowledgement_packet(ctx, output, packet, acknowledgement, relayer)?;
+		Pallet::<T>::on_packet_acknowledged(channel_id, sequence, success);
+		Ok(())
+	}
+
+	fn on_timeout_packet(
+		&mut self,
+		ctx: &dyn ModuleCallbackContext,
+		output: &mut ModuleOutputBuilder,
+		packet: &mut Packet,
+		relayer: &Signer,
+	) -> Result<(), IbcError> {
+		let channel_id = packet.source_channel.sequence();
+		let sequence = packet.sequence.into();
+		self.inner.on_timeout_packet(ctx, output, packet, relayer)?;
+		Pallet::<T>::on_packet_timeout(channel_id, sequence);
+		Ok(())
+	}
+}
@@ -0,0 +1,62 @@
+#![allow(unused_parens, unused_imports, clippy::unnecessary_cast)]
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+// The weight info trait for `pallet_multihop_xcm_ibc`.
+pub trait WeightInfo {
+	fn add_route(r: u32) -> Weight;
+	fn retry_transfer(r: u32) -> Weight;
+	fn refund_transfer() -> Weight;
+}
+
+/// Weights for pallet_multihop_xcm_ibc using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: PalletMultihopXcmIbc RouteIdToRoutePath (r:0 w:1)
+	fn add_route(r: u32) -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: PalletMultihopXcmIbc MultihopTransfers (r:1 w:1)
+	// Storage: PalletMultihopXcmIbc RouteIdToRoutePath (r:1 w:0)
+	// Storage: PalletMultihopXcmIbc PacketToTransfer (r:0 w:2)
+	// The IBC transfer itself is weighed by `pallet_ibc`.
+	fn retry_transfer(r: u32) -> Weight {
+		Weight::from_parts(40_000_000, 0)
+			.saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: PalletMultihopXcmIbc MultihopTransfers (r:1 w:1)
+	// Storage: PalletMultihopXcmIbc AccountTransfers (r:1 w:1)
+	// Storage: PalletMultihopXcmIbc PacketToTransfer (r:0 w:1)
+	fn refund_transfer() -> Weight {
+		Weight::from_parts(150_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn add_route(r: u32) -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	fn retry_transfer(r: u32) -> Weight {
+		Weight::from_parts(40_000_000, 0)
+			.saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	fn refund_transfer() -> Weight {
+		Weight::from_parts(150_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+}
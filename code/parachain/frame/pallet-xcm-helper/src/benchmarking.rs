@@ -0,0 +1,77 @@
+//! Benchmarks for the XCM helper pallet.
+
+use super::*;
+#[allow(unused_imports)]
+use crate::Pallet as XcmHelper;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, BenchmarkError};
+
+/// Transacts are benchmarked to the relay chain, which every parachain can send to.
+fn dest() -> MultiLocation {
+	MultiLocation::parent()
+}
+
+fn transact_info<T: Config>() -> TransactInfo<CurrencyId, BalanceOf<T>> {
+	TransactInfo {
+		fee_currency: T::RelayCurrency::get(),
+		fee_location: MultiLocation::here(),
+		fee_per_second: 1_000_000_000_000,
+		extra_weight: Weight::from_parts(1_000_000_000, 0),
+		max_weight: Weight::from_parts(10_000_000_000, 0),
+		utility_pallet_index: Some(24),
+	}
+}
+
+benchmarks! {
+	update_xcm_weight_fee {
+		let origin = T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let misc = XcmWeightFeeMisc { weight: Weight::from_parts(3_000_000_000, 0), fee: 10_000_000_000 };
+	}: {
+		XcmHelper::<T>::update_xcm_weight_fee(origin, XcmCall::Bond, misc)?
+	}
+	verify {
+		assert_eq!(XcmHelper::<T>::xcm_weight_fee(XcmCall::Bond), misc);
+	}
+
+	set_transact_info {
+		let origin = T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: {
+		XcmHelper::<T>::set_transact_info(origin, Box::new(dest().into()), Some(transact_info::<T>()))?
+	}
+	verify {
+		assert_eq!(XcmHelper::<T>::transact_info(dest()), Some(transact_info::<T>()));
+	}
+
+	set_derivative_account {
+		let origin = T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let owner: T::AccountId = account("owner", 0, 0);
+	}: {
+		XcmHelper::<T>::set_derivative_account(origin, Box::new(dest().into()), 1, Some(owner.clone()))?
+	}
+	verify {
+		assert_eq!(XcmHelper::<T>::derivative_owner(dest(), 1), Some(owner));
+	}
+
+	transact {
+		let origin = T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		XcmHelper::<T>::set_transact_info(origin.clone(), Box::new(dest().into()), Some(transact_info::<T>()))?;
+		T::Assets::mint_into(T::RelayCurrency::get(), &XcmHelper::<T>::account_id(), 1_000_000_000_000_000)?;
+		let call = vec![0; 1024];
+	}: {
+		XcmHelper::<T>::transact(origin, Box::new(dest().into()), call, Weight::from_parts(1_000_000_000, 0))?
+	}
+
+	refund_surplus {
+		let payer: T::AccountId = account("payer", 0, 0);
+		let refund = PendingRefund { payer: payer.clone(), dest: dest(), fee_currency: T::RelayCurrency::get(), fees: 1_000_000_000_000 };
+		PendingRefunds::<T>::insert(0, refund);
+		let origin = <T as Config>::RuntimeOrigin::from(pallet_xcm::Origin::Response(dest()));
+		let response = Response::Assets(MultiAsset::from((MultiLocation::here(), 100_000_000_000u128)).into());
+	}: {
+		XcmHelper::<T>::refund_surplus(origin.into(), 0, response)?
+	}
+	verify {
+		assert_eq!(T::Assets::balance(T::RelayCurrency::get(), &payer), 100_000_000_000);
+	}
+}
+
+impl_benchmark_test_suite!(XcmHelper, crate::mock::new_test_ext(), crate::mock::Test);
@@ -17,6 +17,20 @@
 //! ## Overview
 //! This pallet should be in charge of everything XCM related including callbacks and sending XCM
 //! calls.
+//!
+//! ## Transacts
+//! Other pallets can transact calls on the relay chain or on sibling parachains with
+//! [`XcmHelper::do_transact`]. Fees are paid with the asset configured per destination by
+//! `set_transact_info`, and the outcome is reported to the given notify call with the returned
+//! query id. Calls are dispatched from a derivative account of the sovereign account, which is
+//! owned by a single local account assigned by `set_derivative_account`. Only `UpdateOrigin` can
+//! transact from the sovereign account itself, with `transact`, paying the fees from the pallet
+//! account.
+//!
+//! Fees are burnt from the payer when the transact is sent. The destination reports the fees left
+//! unused by the call back to `refund_surplus`, which mints them back to the payer, while the
+//! surplus itself stays with the sovereign account on the destination to back them. If the report
+//! does not arrive before `NotifyTimeout`, the surplus is not refunded.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -24,26 +38,34 @@ use frame_support::{
 	dispatch::{DispatchResult, GetDispatchInfo},
 	pallet_prelude::*,
 	traits::fungibles::{Inspect, Mutate},
-	transactional, PalletId,
+	transactional,
+	weights::constants::WEIGHT_REF_TIME_PER_SECOND,
+	PalletId,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
-use sp_runtime::traits::{AccountIdConversion, BlockNumberProvider, Convert, StaticLookup};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{AccountIdConversion, BlockNumberProvider, Convert, StaticLookup},
+	Rounding,
+};
 use sp_std::{boxed::Box, prelude::*, vec, vec::Vec};
-use xcm::{latest::prelude::*, DoubleEncoded};
+use xcm::{latest::prelude::*, DoubleEncoded, VersionedMultiLocation};
 
 pub use pallet::*;
+pub mod transact;
 pub mod ump;
 
-use crate::ump::*;
+use crate::{transact::*, ump::*};
 
 use frame_support::traits::tokens::{Fortitude, Precision, Preservation};
 
-// mod benchmarking;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 
-// #[cfg(test)]
-// mod mock;
-// #[cfg(test)]
-// mod tests;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
 
 pub mod weights;
 pub use weights::WeightInfo;
@@ -60,12 +82,19 @@ pub type CurrencyId = primitives::currency::CurrencyId;
 pub mod pallet {
 	use super::*;
 	use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
-	use sp_runtime::traits::{Convert, Zero};
+	use pallet_xcm::ensure_response;
+	use sp_runtime::traits::{Convert, Saturating, Zero};
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config + pallet_xcm::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+		type RuntimeOrigin: IsType<<Self as frame_system::Config>::RuntimeOrigin>
+			+ From<pallet_xcm::Origin>
+			+ Into<Result<pallet_xcm::Origin, <Self as Config>::RuntimeOrigin>>;
+
+		type RuntimeCall: IsType<<Self as pallet_xcm::Config>::RuntimeCall> + From<Call<Self>>;
+
 		/// Assets for deposit/withdraw assets to/from crowdloan account
 		type Assets: Inspect<AccountIdOf<Self>, AssetId = CurrencyId, Balance = Balance>
 			+ Mutate<AccountIdOf<Self>, AssetId = CurrencyId, Balance = Balance>;
@@ -111,6 +140,18 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// Xcm fee and weight updated
 		XcmWeightFeeUpdated(XcmWeightFeeMisc<Weight, BalanceOf<T>>),
+		/// Fee and weight of transacts to a destination updated
+		TransactInfoUpdated {
+			dest: MultiLocation,
+			info: Option<TransactInfo<CurrencyId, BalanceOf<T>>>,
+		},
+		/// Owner of a derivative account of the sovereign account on a destination updated
+		DerivativeAccountUpdated { dest: MultiLocation, index: u16, owner: Option<T::AccountId> },
+		/// Call transacted on a destination, from the sovereign account if there is no derivative
+		/// index. Only transacts from derivative accounts report their outcome.
+		Transacted { dest: MultiLocation, query_id: Option<QueryId>, derivative_index: Option<u16> },
+		/// Fees left unused by a transact refunded to its payer
+		SurplusRefunded { query_id: QueryId, payer: T::AccountId, amount: BalanceOf<T> },
 	}
 
 	#[pallet::storage]
@@ -119,6 +160,42 @@ pub mod pallet {
 	pub type XcmWeightFee<T: Config> =
 		StorageMap<_, Twox64Concat, XcmCall, XcmWeightFeeMisc<Weight, BalanceOf<T>>, ValueQuery>;
 
+	/// Fee and weight of transacts to a destination.
+	#[pallet::storage]
+	#[pallet::getter(fn transact_info)]
+	pub type TransactInfos<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		MultiLocation,
+		TransactInfo<CurrencyId, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// Local owners of the derivative accounts of the sovereign account on destinations, only
+	/// allowed to transact from them.
+	#[pallet::storage]
+	#[pallet::getter(fn derivative_owner)]
+	pub type DerivativeAccounts<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		MultiLocation,
+		Twox64Concat,
+		u16,
+		T::AccountId,
+		OptionQuery,
+	>;
+
+	/// Transacts waiting for the destination to report their unused fees, by query id.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_refund)]
+	pub type PendingRefunds<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		QueryId,
+		PendingRefund<T::AccountId, CurrencyId, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
 	#[pallet::without_storage_info]
@@ -139,6 +216,18 @@ pub mod pallet {
 		SendFailure,
 		/// Can not convert account success
 		ConvertAccountError,
+		/// Destination cannot be converted to the latest XCM version
+		BadVersion,
+		/// No transact info for the destination
+		TransactInfoNotFound,
+		/// Transacted call weight is above the maximum of the destination
+		TransactWeightTooHigh,
+		/// Destination does not support derivative accounts
+		DerivativesNotSupported,
+		/// Derivative account is not owned by the caller
+		NotDerivativeOwner,
+		/// No transact is waiting for a refund with the query id
+		UnknownRefund,
 	}
 
 	#[pallet::call]
@@ -161,12 +250,112 @@ pub mod pallet {
 			Self::deposit_event(Event::<T>::XcmWeightFeeUpdated(xcm_weight_fee_misc));
 			Ok(())
 		}
+
+		/// Set or remove how to pay for transacts to `dest`
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_transact_info())]
+		pub fn set_transact_info(
+			origin: OriginFor<T>,
+			dest: Box<VersionedMultiLocation>,
+			info: Option<TransactInfo<CurrencyId, BalanceOf<T>>>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			let dest: MultiLocation = (*dest).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			if let Some(info) = &info {
+				ensure!(!info.fee_per_second.is_zero(), Error::<T>::ZeroXcmFees);
+				ensure!(!info.max_weight.is_zero(), Error::<T>::ZeroXcmWeightMisc);
+			}
+			TransactInfos::<T>::set(dest, info.clone());
+			Self::deposit_event(Event::<T>::TransactInfoUpdated { dest, info });
+			Ok(())
+		}
+
+		/// Set or remove the local account allowed to transact from the derivative account
+		/// `index` of the sovereign account on `dest`
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_derivative_account())]
+		pub fn set_derivative_account(
+			origin: OriginFor<T>,
+			dest: Box<VersionedMultiLocation>,
+			index: u16,
+			owner: Option<T::AccountId>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			let dest: MultiLocation = (*dest).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			DerivativeAccounts::<T>::set(dest, index, owner.clone());
+			Self::deposit_event(Event::<T>::DerivativeAccountUpdated { dest, index, owner });
+			Ok(())
+		}
+
+		/// Transact `call` on `dest` from the sovereign account, paying the fees from the pallet
+		/// account
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::transact())]
+		pub fn transact(
+			origin: OriginFor<T>,
+			dest: Box<VersionedMultiLocation>,
+			call: Vec<u8>,
+			weight: Weight,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			let dest: MultiLocation = (*dest).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			Self::send_transact(&Self::account_id(), dest, call, weight, None, None)?;
+			Ok(())
+		}
+
+		/// Refund the fees left unused by a transact to its payer, as reported by the destination
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::refund_surplus())]
+		pub fn refund_surplus(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			response: Response,
+		) -> DispatchResult {
+			let responder = ensure_response(<T as Config>::RuntimeOrigin::from(origin))?;
+			let refund = PendingRefunds::<T>::get(query_id).ok_or(Error::<T>::UnknownRefund)?;
+			ensure!(responder == refund.dest, DispatchError::BadOrigin);
+			PendingRefunds::<T>::remove(query_id);
+
+			let reported = match response {
+				Response::Assets(assets) => assets
+					.into_inner()
+					.into_iter()
+					.filter_map(|asset| match asset.fun {
+						Fungible(amount) => Some(amount),
+						NonFungible(_) => None,
+					})
+					.fold(Zero::zero(), |total: BalanceOf<T>, amount| total.saturating_add(amount)),
+				_ => Zero::zero(),
+			};
+			// the destination cannot refund more than what was paid
+			let amount = reported.min(refund.fees);
+			if !amount.is_zero() {
+				T::Assets::mint_into(refund.fee_currency, &refund.payer, amount)?;
+			}
+			Self::deposit_event(Event::<T>::SurplusRefunded {
+				query_id,
+				payer: refund.payer,
+				amount,
+			});
+			Ok(())
+		}
 	}
 }
 
 pub trait XcmHelper<T: pallet_xcm::Config, Balance, TAccountId> {
 	fn add_xcm_fees(payer: &TAccountId, amount: Balance) -> DispatchResult;
 
+	/// Transacts `call` on `dest` from the derivative account `derivative_index` of the sovereign
+	/// account, owned by `payer`, burning the fees from `payer`.
+	fn do_transact(
+		payer: &TAccountId,
+		dest: MultiLocation,
+		call: Vec<u8>,
+		weight: Weight,
+		derivative_index: u16,
+		notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
+	) -> Result<QueryId, DispatchError>;
+
 	fn do_ump_transact(
 		call: DoubleEncoded<()>,
 		weight: Weight,
@@ -245,6 +434,109 @@ impl<T: Config> Pallet<T> {
 		Ok(query_id)
 	}
 
+	/// Fee of transacting `weight` with `info`.
+	pub fn transact_fee(
+		info: &TransactInfo<CurrencyId, BalanceOf<T>>,
+		weight: Weight,
+	) -> BalanceOf<T> {
+		let ref_time = weight.saturating_add(info.extra_weight).ref_time();
+		multiply_by_rational_with_rounding(
+			info.fee_per_second,
+			ref_time.into(),
+			WEIGHT_REF_TIME_PER_SECOND.into(),
+			Rounding::Up,
+		)
+		.unwrap_or(Balance::MAX)
+	}
+
+	/// Transacts `call` on `dest` from the sovereign account, or from its derivative account
+	/// `derivative_index` if owned by `payer`, burning the fees from `payer` and reporting the
+	/// outcome to `notify`. The unused fees are reported back to `refund_surplus`.
+	fn send_transact(
+		payer: &AccountIdOf<T>,
+		dest: MultiLocation,
+		call: Vec<u8>,
+		weight: Weight,
+		derivative_index: Option<u16>,
+		notify: Option<<T as pallet_xcm::Config>::RuntimeCall>,
+	) -> Result<Option<QueryId>, DispatchError> {
+		let info = Self::transact_info(dest).ok_or(Error::<T>::TransactInfoNotFound)?;
+		ensure!(info.max_weight.all_gte(weight), Error::<T>::TransactWeightTooHigh);
+		let call = match derivative_index {
+			Some(index) => {
+				let utility_pallet_index =
+					info.utility_pallet_index.ok_or(Error::<T>::DerivativesNotSupported)?;
+				ensure!(
+					Self::derivative_owner(dest, index).as_ref() == Some(payer),
+					Error::<T>::NotDerivativeOwner
+				);
+				as_derivative(utility_pallet_index, index, call)
+			},
+			None => call,
+		};
+		let fees = Self::transact_fee(&info, weight);
+		T::Assets::burn_from(info.fee_currency, payer, fees, Precision::Exact, Fortitude::Polite)
+			.map_err(|_| Error::<T>::InsufficientXcmFees)?;
+
+		// the sovereign account as seen from the destination
+		let beneficiary = <T as pallet_xcm::Config>::UniversalLocation::get()
+			.invert_target(&dest)
+			.map_err(|()| Error::<T>::MultiLocationNotInvertible)?;
+		let refund_notify: <T as pallet_xcm::Config>::RuntimeCall =
+			<T as Config>::RuntimeCall::from(Call::<T>::refund_surplus {
+				query_id: Default::default(),
+				response: Default::default(),
+			})
+			.into();
+		let max_weight = refund_notify.get_dispatch_info().weight;
+		let refund_query_id = pallet_xcm::Pallet::<T>::new_notify_query(
+			dest,
+			refund_notify,
+			T::NotifyTimeout::get(),
+			Here,
+		);
+		PendingRefunds::<T>::insert(
+			refund_query_id,
+			PendingRefund { payer: payer.clone(), dest, fee_currency: info.fee_currency, fees },
+		);
+		let asset: MultiAsset = (info.fee_location, fees).into();
+		let mut msg = Xcm(vec![
+			WithdrawAsset(MultiAssets::from(asset.clone())),
+			BuyExecution {
+				fees: asset.clone(),
+				weight_limit: Limited(weight.saturating_add(info.extra_weight)),
+			},
+			Transact {
+				origin_kind: OriginKind::SovereignAccount,
+				require_weight_at_most: weight,
+				call: call.into(),
+			},
+			RefundSurplus,
+			ReportHolding {
+				response_info: QueryResponseInfo {
+					destination: beneficiary,
+					query_id: refund_query_id,
+					max_weight,
+				},
+				assets: asset.clone().into(),
+			},
+			// the surplus backs the fees minted back to the payer
+			DepositAsset { assets: asset.into(), beneficiary },
+		]);
+		let query_id = notify
+			.map(|notify| {
+				Self::report_outcome_notify(&mut msg, dest, notify, T::NotifyTimeout::get())
+			})
+			.transpose()?;
+
+		if let Err(_err) = send_xcm::<T::XcmSender>(dest, msg) {
+			return Err(Error::<T>::SendFailure.into())
+		}
+		Self::deposit_event(Event::<T>::Transacted { dest, query_id, derivative_index });
+
+		Ok(query_id)
+	}
+
 	// Since xcm v3 doesn't support utility.batch_all
 	// instead, here append one more transact msg
 	//
@@ -276,6 +568,18 @@ impl<T: Config> XcmHelper<T, BalanceOf<T>, AccountIdOf<T>> for Pallet<T> {
 		Ok(())
 	}
 
+	fn do_transact(
+		payer: &AccountIdOf<T>,
+		dest: MultiLocation,
+		call: Vec<u8>,
+		weight: Weight,
+		derivative_index: u16,
+		notify: impl Into<<T as pallet_xcm::Config>::RuntimeCall>,
+	) -> Result<QueryId, DispatchError> {
+		Self::send_transact(payer, dest, call, weight, Some(derivative_index), Some(notify.into()))?
+			.ok_or_else(|| Error::<T>::SendFailure.into())
+	}
+
 	fn do_ump_transact(
 		call: DoubleEncoded<()>,
 		weight: Weight,
@@ -1,150 +1,181 @@
-use cumulus_primitives_core::ParaId;
+use crate::{self as pallet_xcm_helper, Balance, CurrencyId};
 use frame_support::{
-	construct_runtime,
-	dispatch::Weight,
-	pallet_prelude::*,
-	parameter_types, sp_io,
-	traits::{
-		AsEnsureOriginWithArg, Everything, GenesisBuild, Nothing, OriginTrait, SortedMembers,
-	},
-	weights::constants::WEIGHT_REF_TIME_PER_SECOND,
-	BoundedSlice, PalletId,
+	construct_runtime, parameter_types,
+	traits::{ConstU32, Everything, Nothing},
+	weights::Weight,
+	PalletId,
 };
-use frame_system::{EnsureRoot, EnsureSigned};
-use orml_xcm_support::IsNativeConcrete;
-use pallet_xcm::XcmPassthrough;
-use polkadot_parachain::primitives::{IsSystem, Sibling};
+use frame_system::EnsureRoot;
+use orml_traits::parameter_type_with_key;
+use parity_scale_codec::Encode;
 use sp_core::H256;
 use sp_runtime::{
-	generic,
-	traits::{
-		AccountIdConversion, AccountIdLookup, BlakeTwo256, BlockNumberProvider, Convert, Zero,
-	},
-	AccountId32, DispatchError,
-	MultiAddress::Id,
+	testing::Header,
+	traits::{BlakeTwo256, Convert, IdentityLookup, Zero},
+	AccountId32,
 };
-pub use xcm::latest::prelude::*;
-pub use xcm_builder::{
-	AccountId32Aliases, AllowTopLevelPaidExecutionFrom, AllowUnpaidExecutionFrom,
-	ChildParachainAsNative, ChildParachainConvertsVia, ChildSystemParachainAsSuperuser,
-	CurrencyAdapter as XcmCurrencyAdapter, EnsureXcmOrigin, FixedRateOfFungible, FixedWeightBounds,
-	IsConcrete, NativeAsset, ParentAsSuperuser, ParentIsPreset, RelayChainAsNative,
-	SiblingParachainAsNative, SiblingParachainConvertsVia, SignedAccountId32AsNative,
-	SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
-};
-use xcm_executor::{traits::ConvertOrigin, Config, XcmExecutor};
-use xcm_simulator::{decl_test_network, decl_test_parachain, decl_test_relay_chain};
+use xcm::latest::prelude::*;
+use xcm_builder::{EnsureXcmOrigin, FixedWeightBounds, SignedToAccountId32};
+use xcm_executor::XcmExecutor;
 
 pub type AccountId = AccountId32;
-pub type CurrencyId = u32;
-pub type Balance = u128;
-pub use kusama_runtime;
-// use pallet_traits::{
-//     ump::{XcmCall, XcmWeightFeeMisc},
-//     xcm::MultiCurrencyAdapter,
-// };
-use orml_xcm_support::MultiCurrencyAdapter;
-pub struct RelayChainBlockNumberProvider<T>(sp_std::marker::PhantomData<T>);
+pub type BlockNumber = u64;
+pub type Amount = i128;
 
-impl<T: cumulus_pallet_parachain_system::Config> BlockNumberProvider
-	for RelayChainBlockNumberProvider<T>
-{
-	type BlockNumber = BlockNumber;
+pub const ALICE: AccountId = AccountId32::new([1; 32]);
+pub const BOB: AccountId = AccountId32::new([2; 32]);
+pub const PARA_ID: u32 = 2087;
 
-	fn current_block_number() -> Self::BlockNumber {
-		cumulus_pallet_parachain_system::Pallet::<T>::validation_data()
-			.map(|d| d.relay_parent_number)
-			.unwrap_or_default()
-			.into()
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Tokens: orml_tokens,
+		PolkadotXcm: pallet_xcm,
+		XcmHelper: pallet_xcm_helper,
 	}
-}
+);
 
 parameter_types! {
-	pub const ReservedXcmpWeight: Weight = Weight::from_ref_time(WEIGHT_REF_TIME_PER_SECOND.saturating_div(4));
-	pub const ReservedDmpWeight: Weight = Weight::from_ref_time(WEIGHT_REF_TIME_PER_SECOND.saturating_div(4));
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
 }
 
-impl cumulus_pallet_parachain_system::Config for Test {
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
 	type RuntimeEvent = RuntimeEvent;
-	type OnSystemEvent = ();
-	type SelfParaId = ParachainInfo;
-	type DmpMessageHandler = DmpQueue;
-	type ReservedDmpWeight = ReservedDmpWeight;
-	type OutboundXcmpMessageSource = XcmpQueue;
-	type XcmpMessageHandler = XcmpQueue;
-	type ReservedXcmpWeight = ReservedXcmpWeight;
-	type CheckAssociatedRelayNumber = cumulus_pallet_parachain_system::RelayNumberStrictlyIncreases;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
 }
 
-impl parachain_info::Config for Test {}
-
-const DOT: CurrencyId = 0;
-const NATIVE: CurrencyId = 1;
-const SDOT: CurrencyId = 2;
-
 parameter_types! {
-	pub RelayNetwork: NetworkId = NetworkId::Kusama;
-	pub RelayCurrency: CurrencyId = DOT;
-	pub RelayChainOrigin: RuntimeOrigin = cumulus_pallet_xcm::Origin::Relay.into();
-	pub UniversalLocation: InteriorMultiLocation = X2(GlobalConsensus(RelayNetwork::get()), Parachain(ParachainInfo::parachain_id().into()));
+	pub const ExistentialDeposit: Balance = 1;
 }
 
-pub type LocationToAccountId = (
-	ParentIsPreset<AccountId>,
-	SiblingParachainConvertsVia<Sibling, AccountId>,
-	AccountId32Aliases<RelayNetwork, AccountId>,
-);
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type FreezeIdentifier = [u8; 8];
+	type HoldIdentifier = [u8; 8];
+	type MaxHolds = ConstU32<32>;
+	type MaxFreezes = ConstU32<32>;
+}
 
-pub type XcmOriginToCallOrigin = (
-	SovereignSignedViaLocation<LocationToAccountId, RuntimeOrigin>,
-	RelayChainAsNative<RelayChainOrigin, RuntimeOrigin>,
-	SiblingParachainAsNative<cumulus_pallet_xcm::Origin, RuntimeOrigin>,
-	SignedAccountId32AsNative<RelayNetwork, RuntimeOrigin>,
-	XcmPassthrough<RuntimeOrigin>,
-);
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+		Zero::zero()
+	};
+}
 
-parameter_types! {
-	pub const UnitWeightCost: u64 = 1;
-	pub DotPerSecond: (AssetId, u128, u128) = (AssetId::Concrete(MultiLocation::parent()), 1, 1);
+pub struct CurrencyHooks;
+impl orml_traits::currency::MutationHooks<AccountId, CurrencyId, Balance> for CurrencyHooks {
+	type OnDust = ();
+	type OnSlash = ();
+	type PreDeposit = ();
+	type PostDeposit = ();
+	type PreTransfer = ();
+	type PostTransfer = ();
+	type OnNewTokenAccount = ();
+	type OnKilledTokenAccount = ();
 }
 
-parameter_types! {
-	pub const NativeCurrencyId: CurrencyId = NATIVE;
-	pub GiftAccount: AccountId = PalletId(*b"par/gift").into_account_truncating();
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = CurrencyId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type ReserveIdentifier = [u8; 8];
+	type MaxReserves = ConstU32<2>;
+	type DustRemovalWhitelist = Everything;
+	type CurrencyHooks = CurrencyHooks;
 }
 
-pub type LocalAssetTransactor = MultiCurrencyAdapter<
-	Assets,
-	IsNativeConcrete<CurrencyId, CurrencyIdConvert>,
-	AccountId,
-	Balance,
-	LocationToAccountId,
-	CurrencyIdConvert,
-	NativeCurrencyId,
-	ExistentialDeposit,
->;
+parameter_types! {
+	pub const RelayNetwork: NetworkId = NetworkId::Kusama;
+	pub UniversalLocation: InteriorMultiLocation =
+		X2(GlobalConsensus(RelayNetwork::get()), Parachain(PARA_ID));
+	pub const UnitWeightCost: Weight = Weight::from_parts(10, 10);
+	pub const MaxInstructions: u32 = 100;
+	/// Messages sent through `TestSendXcm`.
+	pub static SentXcm: Vec<(MultiLocation, Xcm<()>)> = vec![];
+}
+
+/// Records the messages sent instead of routing them.
+pub struct TestSendXcm;
+impl SendXcm for TestSendXcm {
+	type Ticket = (MultiLocation, Xcm<()>);
+
+	fn validate(
+		dest: &mut Option<MultiLocation>,
+		msg: &mut Option<Xcm<()>>,
+	) -> SendResult<Self::Ticket> {
+		let dest = dest.take().ok_or(SendError::MissingArgument)?;
+		let msg = msg.take().ok_or(SendError::MissingArgument)?;
+		Ok(((dest, msg), MultiAssets::new()))
+	}
 
-pub type XcmRouter = ParachainXcmRouter<ParachainInfo>;
-pub type Barrier = AllowUnpaidExecutionFrom<Everything>;
+	fn deliver((dest, msg): Self::Ticket) -> Result<XcmHash, SendError> {
+		let hash = msg.using_encoded(sp_io::hashing::blake2_256);
+		SentXcm::mutate(|sent| sent.push((dest, msg)));
+		Ok(hash)
+	}
+}
 
 pub struct XcmConfig;
-impl Config for XcmConfig {
+impl xcm_executor::Config for XcmConfig {
 	type RuntimeCall = RuntimeCall;
-	type XcmSender = XcmRouter;
-	type AssetTransactor = LocalAssetTransactor;
-	type OriginConverter = XcmOriginToCallOrigin;
-	type IsReserve = NativeAsset;
+	type XcmSender = TestSendXcm;
+	type AssetTransactor = ();
+	type OriginConverter = ();
+	type IsReserve = ();
 	type IsTeleporter = ();
 	type UniversalLocation = UniversalLocation;
-	type Barrier = Barrier;
+	type Barrier = ();
 	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
-	type Trader = FixedRateOfFungible<DotPerSecond, ()>;
-	type ResponseHandler = ();
-	type SubscriptionService = PolkadotXcm;
+	type Trader = ();
+	type ResponseHandler = PolkadotXcm;
 	type AssetTrap = PolkadotXcm;
-	type AssetClaims = PolkadotXcm;
 	type AssetLocker = ();
 	type AssetExchanger = ();
+	type AssetClaims = PolkadotXcm;
+	type SubscriptionService = PolkadotXcm;
 	type PalletInstancesInfo = ();
 	type MaxAssetsIntoHolding = ConstU32<64>;
 	type FeeManager = ();
@@ -154,51 +185,6 @@ impl Config for XcmConfig {
 	type SafeCallFilter = Everything;
 }
 
-pub struct SystemParachainAsSuperuser<Origin>(PhantomData<Origin>);
-impl<Origin: OriginTrait> ConvertOrigin<Origin> for SystemParachainAsSuperuser<Origin> {
-	fn convert_origin(
-		origin: impl Into<MultiLocation>,
-		kind: OriginKind,
-	) -> Result<Origin, MultiLocation> {
-		let origin = origin.into();
-		if kind == OriginKind::Superuser &&
-			matches!(
-				origin,
-				MultiLocation {
-					parents: 1,
-					interior: X1(Parachain(id)),
-				} if ParaId::from(id).is_system(),
-			) {
-			Ok(Origin::root())
-		} else {
-			Err(origin)
-		}
-	}
-}
-
-impl cumulus_pallet_xcmp_queue::Config for Test {
-	type RuntimeEvent = RuntimeEvent;
-	type XcmExecutor = XcmExecutor<XcmConfig>;
-	type ExecuteOverweightOrigin = EnsureRoot<AccountId>;
-	type ChannelInfo = ParachainSystem;
-	type VersionWrapper = ();
-	type ControllerOrigin = EnsureRoot<AccountId>;
-	type ControllerOriginConverter = SystemParachainAsSuperuser<RuntimeOrigin>;
-	type WeightInfo = cumulus_pallet_xcmp_queue::weights::SubstrateWeight<Test>;
-	type PriceForSiblingDelivery = ();
-}
-
-impl cumulus_pallet_dmp_queue::Config for Test {
-	type RuntimeEvent = RuntimeEvent;
-	type XcmExecutor = XcmExecutor<XcmConfig>;
-	type ExecuteOverweightOrigin = EnsureRoot<AccountId>;
-}
-
-impl cumulus_pallet_xcm::Config for Test {
-	type RuntimeEvent = RuntimeEvent;
-	type XcmExecutor = XcmExecutor<XcmConfig>;
-}
-
 pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, RelayNetwork>;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -213,13 +199,12 @@ impl pallet_xcm::Config for Test {
 	type RuntimeCall = RuntimeCall;
 	type RuntimeEvent = RuntimeEvent;
 	type SendXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
-	type XcmRouter = XcmRouter;
+	type XcmRouter = TestSendXcm;
 	type ExecuteXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
 	type XcmExecuteFilter = Nothing;
 	type XcmExecutor = XcmExecutor<XcmConfig>;
 	type XcmTeleportFilter = Nothing;
-
-	type XcmReserveTransferFilter = Everything;
+	type XcmReserveTransferFilter = Nothing;
 	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
 	type UniversalLocation = UniversalLocation;
 	type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
@@ -229,371 +214,47 @@ impl pallet_xcm::Config for Test {
 	type SovereignAccountOf = ();
 	type MaxLockers = ConstU32<8>;
 	type WeightInfo = pallet_xcm::TestWeightInfo;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type MaxRemoteLockConsumers = ConstU32<0>;
+	type RemoteLockConsumerIdentifier = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type ReachableDest = ReachableDest;
 }
 
-pub struct CurrencyIdConvert;
-impl Convert<CurrencyId, Option<MultiLocation>> for CurrencyIdConvert {
-	fn convert(id: CurrencyId) -> Option<MultiLocation> {
-		match id {
-			DOT => Some(MultiLocation::parent()),
-			SDOT => Some(MultiLocation::new(
-				1,
-				X2(
-					Parachain(ParachainInfo::parachain_id().into()),
-					BoundedSlice::<u8, ConstU32<32>>::truncate_from(b"sDOT".to_vec().as_ref())
-						.into(),
-				),
-			)),
-			_ => None,
-		}
-	}
-}
-
-impl Convert<MultiLocation, Option<CurrencyId>> for CurrencyIdConvert {
-	fn convert(location: MultiLocation) -> Option<CurrencyId> {
-		match location {
-			MultiLocation { parents: 1, interior: Here } => Some(DOT),
-			MultiLocation {
-				parents: 1,
-				interior: X2(Parachain(id), GeneralKey { data, length }),
-			} => {
-				let key = &data[..data.len().min(length as usize)];
-				if ParaId::from(id) == ParachainInfo::parachain_id() && key == b"sDOT".to_vec() {
-					return Some(SDOT)
-				}
-				None
-			},
-			_ => None,
-		}
-	}
-}
-
-impl Convert<MultiAsset, Option<CurrencyId>> for CurrencyIdConvert {
-	fn convert(a: MultiAsset) -> Option<CurrencyId> {
-		if let MultiAsset { id: AssetId::Concrete(id), fun: _ } = a {
-			Self::convert(id)
-		} else {
-			None
-		}
-	}
-}
-
 pub struct AccountIdToMultiLocation;
 impl Convert<AccountId, MultiLocation> for AccountIdToMultiLocation {
 	fn convert(account_id: AccountId) -> MultiLocation {
-		MultiLocation::from(Junction::AccountId32 { network: None, id: account_id.into() })
-	}
-}
-
-parameter_types! {
-	pub SelfLocation: MultiLocation = MultiLocation::new(1, X1(Parachain(ParachainInfo::parachain_id().into())));
-	pub const BaseXcmWeight: u64 = 100_000_000;
-	pub const MaxInstructions: u32 = 100;
-}
-
-type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
-type Block = frame_system::mocking::MockBlock<Test>;
-type BlockNumber = u32;
-type Index = u32;
-pub const DOT_DECIMAL: u128 = 10u128.pow(10);
-
-parameter_types! {
-	pub const BlockHashCount: u32 = 250;
-	pub const SS58Prefix: u8 = 42;
-}
-
-impl frame_system::Config for Test {
-	type BaseCallFilter = Everything;
-	type BlockWeights = ();
-	type BlockLength = ();
-	type DbWeight = ();
-	type RuntimeOrigin = RuntimeOrigin;
-	type RuntimeCall = RuntimeCall;
-	type Index = Index;
-	type BlockNumber = BlockNumber;
-	type Hash = H256;
-	type Hashing = BlakeTwo256;
-	type AccountId = AccountId;
-	type Lookup = AccountIdLookup<AccountId, ()>;
-	type Header = generic::Header<BlockNumber, BlakeTwo256>;
-	type RuntimeEvent = RuntimeEvent;
-	type BlockHashCount = BlockHashCount;
-	type Version = ();
-	type PalletInfo = PalletInfo;
-	type AccountData = pallet_balances::AccountData<Balance>;
-	type OnNewAccount = ();
-	type OnKilledAccount = ();
-	type SystemWeightInfo = ();
-	type SS58Prefix = SS58Prefix;
-	type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
-	type MaxConsumers = frame_support::traits::ConstU32<16>;
-}
-
-parameter_types! {
-	pub const ExistentialDeposit: Balance = 1;
-	pub const MaxLocks: u32 = 50;
-}
-
-impl pallet_balances::Config for Test {
-	type MaxLocks = MaxLocks;
-	type Balance = Balance;
-	type RuntimeEvent = RuntimeEvent;
-	type DustRemoval = ();
-	type MaxReserves = ();
-	type ReserveIdentifier = [u8; 8];
-	type ExistentialDeposit = ExistentialDeposit;
-	type AccountStore = System;
-	type WeightInfo = ();
-}
-
-pub struct AliceOrigin;
-impl SortedMembers<AccountId> for AliceOrigin {
-	fn sorted_members() -> Vec<AccountId> {
-		vec![ALICE]
-	}
-}
-
-pub struct BobOrigin;
-impl SortedMembers<AccountId> for BobOrigin {
-	fn sorted_members() -> Vec<AccountId> {
-		vec![BOB]
+		X1(Junction::AccountId32 { network: None, id: account_id.into() }).into()
 	}
 }
 
-parameter_types! {
-	pub SelfParaId: ParaId = para_a_id();
-}
-
 parameter_types! {
 	pub const XcmHelperPalletId: PalletId = PalletId(*b"par/fees");
+	pub const RefundLocation: AccountId = AccountId32::new([0xff; 32]);
 	pub const NotifyTimeout: BlockNumber = 100;
-	pub RefundLocation: AccountId = para_a_id().into_account_truncating();
+	pub const RelayCurrency: CurrencyId = CurrencyId::KSM;
 }
 
-impl crate::Config for Test {
+impl pallet_xcm_helper::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
-	type UpdateOrigin = EnsureRoot<AccountId>;
-	type Assets = Assets;
-	type XcmSender = XcmRouter;
-	type PalletId = XcmHelperPalletId;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Assets = Tokens;
+	type XcmSender = TestSendXcm;
 	type RelayNetwork = RelayNetwork;
-	type NotifyTimeout = NotifyTimeout;
-	type AccountIdToMultiLocation = AccountIdToMultiLocation;
+	type PalletId = XcmHelperPalletId;
 	type RefundLocation = RefundLocation;
-	type BlockNumberProvider = frame_system::Pallet<Test>;
+	type AccountIdToMultiLocation = AccountIdToMultiLocation;
+	type NotifyTimeout = NotifyTimeout;
+	type BlockNumberProvider = System;
+	type UpdateOrigin = EnsureRoot<AccountId>;
 	type WeightInfo = ();
 	type RelayCurrency = RelayCurrency;
 }
 
-parameter_types! {
-	pub const AssetDeposit: Balance = DOT_DECIMAL;
-	pub const ApprovalDeposit: Balance = 0;
-	pub const AssetAccountDeposit: Balance = 0;
-	pub const AssetsStringLimit: u32 = 50;
-	pub const MetadataDepositBase: Balance = 0;
-	pub const MetadataDepositPerByte: Balance = 0;
-}
-
-impl pallet_assets::Config for Test {
-	type RuntimeEvent = RuntimeEvent;
-	type Balance = Balance;
-	type AssetId = CurrencyId;
-	type AssetIdParameter = parity_scale_codec::Compact<CurrencyId>;
-	type Currency = Balances;
-	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
-	type ForceOrigin = EnsureRoot<AccountId>;
-	type AssetDeposit = AssetDeposit;
-	type MetadataDepositBase = MetadataDepositBase;
-	type MetadataDepositPerByte = MetadataDepositPerByte;
-	type AssetAccountDeposit = AssetAccountDeposit;
-	type ApprovalDeposit = ApprovalDeposit;
-	type StringLimit = AssetsStringLimit;
-	type Freezer = ();
-	type WeightInfo = ();
-	type Extra = ();
-	type RemoveItemsLimit = frame_support::traits::ConstU32<1000>;
-	type CallbackHandle = ();
-	#[cfg(feature = "runtime-benchmarks")]
-	type BenchmarkHelper = ();
-}
-
-construct_runtime!(
-	pub enum Test where
-		Block = Block,
-		NodeBlock = Block,
-		UncheckedExtrinsic = UncheckedExtrinsic,
-	{
-		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
-		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
-		ParachainSystem: cumulus_pallet_parachain_system::{Pallet, Call, Config, Storage, Inherent, Event<T>},
-		ParachainInfo: parachain_info::{Pallet, Storage, Config},
-		XcmpQueue: cumulus_pallet_xcmp_queue::{Pallet, Call, Storage, Event<T>},
-		DmpQueue: cumulus_pallet_dmp_queue::{Pallet, Call, Storage, Event<T>},
-		CumulusXcm: cumulus_pallet_xcm::{Pallet, Event<T>, Origin},
-		PolkadotXcm: pallet_xcm::{Pallet, Call, Event<T>, Origin},
-		XcmHelpers: crate::{Pallet, Storage, Call, Event<T>},
-	}
-);
-
-pub const ALICE: AccountId32 = AccountId32::new([1u8; 32]);
-pub const BOB: AccountId32 = AccountId32::new([2u8; 32]);
-
-// pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
-//     let t = frame_system::GenesisConfig::default()
-//         .build_storage::<Test>()
-//         .unwrap();
-
-//     let xcm_weight_fee_misc = XcmWeightFeeMisc {
-//         weight: Weight::from_ref_time(3_000_000_000),
-//         fee: dot(10f64),
-//     };
-
-//     let mut ext = sp_io::TestExternalities::new(t);
-//     ext.execute_with(|| {
-//         Assets::force_create(RuntimeOrigin::root(), DOT.into(), Id(ALICE), true, 1).unwrap();
-//         Assets::force_create(RuntimeOrigin::root(), SDOT.into(), Id(ALICE), true, 1).unwrap();
-//         Assets::mint(
-//             RuntimeOrigin::signed(ALICE),
-//             DOT.into(),
-//             Id(ALICE),
-//             100 * DOT_DECIMAL,
-//         )
-//         .unwrap();
-//         Assets::mint(
-//             RuntimeOrigin::signed(ALICE),
-//             SDOT.into(),
-//             Id(ALICE),
-//             100 * DOT_DECIMAL,
-//         )
-//         .unwrap();
-//         Assets::mint(
-//             RuntimeOrigin::signed(ALICE),
-//             DOT.into(),
-//             Id(XcmHelpers::account_id()),
-//             dot(30f64),
-//         )
-//         .unwrap();
-//         XcmHelpers::update_xcm_weight_fee(
-//             RuntimeOrigin::root(),
-//             XcmCall::AddMemo,
-//             xcm_weight_fee_misc,
-//         )
-//         .unwrap();
-//     });
-
-//     ext
-// }
-
-//initial parchain and relaychain for testing
-// decl_test_parachain! {
-//     pub struct ParaA {
-//         Runtime = Test,
-//         XcmpMessageHandler = XcmpQueue,
-//         DmpMessageHandler = DmpQueue,
-//         new_ext = para_ext(1),
-//     }
-// }
-
-decl_test_relay_chain! {
-	pub struct Relay {
-		Runtime = kusama_runtime::Runtime,
-		XcmConfig = kusama_runtime::xcm_config::XcmConfig,
-		new_ext = relay_ext(),
-	}
-}
-
-decl_test_network! {
-	pub struct TestNet {
-		relay_chain = Relay,
-		parachains = vec![
-			// (1, ParaA),
-		],
-	}
-}
-
-pub fn para_a_id() -> ParaId {
-	ParaId::from(1)
-}
-
-use crate::XcmWeightFeeMisc;
-
-pub fn para_ext(para_id: u32) -> sp_io::TestExternalities {
-	// let mut t = frame_system::GenesisConfig::default()
-	//     .build_storage::<Test>()
-	//     .unwrap();
-
-	// let xcm_weight_fee_misc = XcmWeightFeeMisc {
-	//     weight: Weight::from_ref_time(3_000_000_000),
-	//     fee: dot(10f64),
-	// };
-
-	// let parachain_info_config = parachain_info::GenesisConfig {
-	//     parachain_id: para_id.into(),
-	// };
-	// <parachain_info::GenesisConfig as GenesisBuild<Test, _>>::assimilate_storage(
-	//     &parachain_info_config,
-	//     &mut t,
-	// )
-	// .unwrap();
-
-	// let mut ext = sp_io::TestExternalities::new(t);
-	// ext.execute_with(|| {
-	//     System::set_block_number(1);
-	//     Assets::force_create(RuntimeOrigin::root(), DOT.into(), Id(ALICE), true, 1).unwrap();
-	//     Assets::force_create(RuntimeOrigin::root(), SDOT.into(), Id(ALICE), true, 1).unwrap();
-	//     Assets::mint(
-	//         RuntimeOrigin::signed(ALICE),
-	//         DOT.into(),
-	//         Id(ALICE),
-	//         100 * DOT_DECIMAL,
-	//     )
-	//     .unwrap();
-	//     Assets::mint(
-	//         RuntimeOrigin::signed(ALICE),
-	//         SDOT.into(),
-	//         Id(ALICE),
-	//         100 * DOT_DECIMAL,
-	//     )
-	//     .unwrap();
-	//     Assets::mint(
-	//         RuntimeOrigin::signed(ALICE),
-	//         DOT.into(),
-	//         Id(XcmHelpers::account_id()),
-	//         dot(30f64),
-	//     )
-	//     .unwrap();
-	//     XcmHelpers::update_xcm_weight_fee(
-	//         RuntimeOrigin::root(),
-	//         XcmCall::AddMemo,
-	//         xcm_weight_fee_misc,
-	//     )
-	//     .unwrap();
-	// });
-	todo!()
-	// ext
-}
-
-pub fn relay_ext() -> sp_io::TestExternalities {
-	use kusama_runtime::{Runtime, System};
-	let mut t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
-
-	pallet_balances::GenesisConfig::<Runtime> {
-		balances: vec![
-			(ALICE, 100 * DOT_DECIMAL),
-			(para_a_id().into_account_truncating(), 1_000_000 * DOT_DECIMAL),
-		],
-	}
-	.assimilate_storage(&mut t)
-	.unwrap();
-
-	let mut ext = sp_io::TestExternalities::new(t);
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let storage = frame_system::GenesisConfig::default().build_storage::<Test>().expect("success");
+	let mut ext = sp_io::TestExternalities::new(storage);
 	ext.execute_with(|| System::set_block_number(1));
 	ext
 }
-
-pub fn dot(n: f64) -> Balance {
-	((n * 1000000f64) as u128) * DOT_DECIMAL / 1000000u128
-}
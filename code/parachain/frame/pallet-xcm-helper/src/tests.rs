@@ -0,0 +1,294 @@
+use crate::{
+	mock::{XcmHelper, *},
+	XcmHelper as _, *,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::fungibles::{Inspect, Mutate},
+};
+use sp_runtime::{DispatchError::BadOrigin, DispatchResult};
+
+const UTILITY_PALLET_INDEX: u8 = 24;
+
+fn sibling() -> MultiLocation {
+	MultiLocation::new(1, X1(Parachain(2000)))
+}
+
+fn transact_info() -> TransactInfo<CurrencyId, Balance> {
+	TransactInfo {
+		fee_currency: CurrencyId::KSM,
+		fee_location: MultiLocation::parent(),
+		// one unit of fees per unit of ref time
+		fee_per_second: WEIGHT_REF_TIME_PER_SECOND.into(),
+		extra_weight: Weight::from_parts(500, 0),
+		max_weight: Weight::from_parts(10_000, 0),
+		utility_pallet_index: Some(UTILITY_PALLET_INDEX),
+	}
+}
+
+fn notify() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
+
+fn set_up_sibling() {
+	assert_ok!(XcmHelper::set_transact_info(
+		RuntimeOrigin::root(),
+		Box::new(sibling().into()),
+		Some(transact_info()),
+	));
+	assert_ok!(XcmHelper::set_derivative_account(
+		RuntimeOrigin::root(),
+		Box::new(sibling().into()),
+		1,
+		Some(ALICE),
+	));
+	assert_ok!(Tokens::mint_into(CurrencyId::KSM, &ALICE, 10_000));
+}
+
+fn transacted_call(msg: &Xcm<()>) -> Vec<u8> {
+	msg.0
+		.iter()
+		.find_map(|instruction| match instruction {
+			Transact { origin_kind: OriginKind::SovereignAccount, call, .. } =>
+				Some(call.clone().into_encoded()),
+			_ => None,
+		})
+		.expect("message transacts a call")
+}
+
+fn refund_query_id(msg: &Xcm<()>) -> QueryId {
+	msg.0
+		.iter()
+		.find_map(|instruction| match instruction {
+			ReportHolding { response_info, .. } => Some(response_info.query_id),
+			_ => None,
+		})
+		.expect("message reports the unused fees")
+}
+
+fn report_surplus(from: MultiLocation, query_id: QueryId, amount: Balance) -> DispatchResult {
+	XcmHelper::refund_surplus(
+		pallet_xcm::Origin::Response(from).into(),
+		query_id,
+		Response::Assets(MultiAsset::from((MultiLocation::parent(), amount)).into()),
+	)
+}
+
+#[test]
+fn transact_info_is_set_by_update_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XcmHelper::set_transact_info(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(sibling().into()),
+				Some(transact_info()),
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			XcmHelper::set_transact_info(
+				RuntimeOrigin::root(),
+				Box::new(sibling().into()),
+				Some(TransactInfo { fee_per_second: 0, ..transact_info() }),
+			),
+			Error::<Test>::ZeroXcmFees
+		);
+		assert_ok!(XcmHelper::set_transact_info(
+			RuntimeOrigin::root(),
+			Box::new(sibling().into()),
+			Some(transact_info()),
+		));
+		assert_eq!(XcmHelper::transact_info(sibling()), Some(transact_info()));
+	});
+}
+
+#[test]
+fn transact_from_derivative_account_of_caller() {
+	new_test_ext().execute_with(|| {
+		set_up_sibling();
+		let weight = Weight::from_parts(1_000, 0);
+		assert_noop!(
+			XcmHelper::do_transact(&BOB, sibling(), vec![7, 7], weight, 1, notify()),
+			Error::<Test>::NotDerivativeOwner
+		);
+		assert_noop!(
+			XcmHelper::do_transact(&ALICE, sibling(), vec![7, 7], weight, 2, notify()),
+			Error::<Test>::NotDerivativeOwner
+		);
+
+		let query_id = XcmHelper::do_transact(&ALICE, sibling(), vec![7, 7], weight, 1, notify())
+			.expect("caller owns the derivative account");
+		// the weight and the extra weight are paid at one unit per ref time
+		assert_eq!(Tokens::balance(CurrencyId::KSM, &ALICE), 10_000 - 1_500);
+		System::assert_last_event(
+			Event::<Test>::Transacted {
+				dest: sibling(),
+				query_id: Some(query_id),
+				derivative_index: Some(1),
+			}
+			.into(),
+		);
+
+		let sent = SentXcm::get();
+		assert_eq!(sent.len(), 1);
+		let (dest, msg) = &sent[0];
+		assert_eq!(*dest, sibling());
+		assert_eq!(
+			transacted_call(msg),
+			transact::as_derivative(UTILITY_PALLET_INDEX, 1, vec![7, 7])
+		);
+		assert!(msg.0.iter().any(|instruction| matches!(instruction, SetAppendix(_))));
+		assert_eq!(
+			XcmHelper::pending_refund(refund_query_id(msg)),
+			Some(PendingRefund {
+				payer: ALICE,
+				dest: sibling(),
+				fee_currency: CurrencyId::KSM,
+				fees: 1_500
+			})
+		);
+	});
+}
+
+#[test]
+fn unused_fees_are_refunded_to_the_payer() {
+	new_test_ext().execute_with(|| {
+		set_up_sibling();
+		XcmHelper::do_transact(
+			&ALICE,
+			sibling(),
+			vec![7, 7],
+			Weight::from_parts(1_000, 0),
+			1,
+			notify(),
+		)
+		.expect("caller owns the derivative account");
+		let query_id = refund_query_id(&SentXcm::get()[0].1);
+
+		assert_noop!(report_surplus(sibling(), query_id + 1, 400), Error::<Test>::UnknownRefund);
+		assert_noop!(report_surplus(MultiLocation::parent(), query_id, 400), BadOrigin);
+		assert_noop!(
+			XcmHelper::refund_surplus(RuntimeOrigin::signed(ALICE), query_id, Response::Null),
+			BadOrigin
+		);
+
+		assert_ok!(report_surplus(sibling(), query_id, 400));
+		assert_eq!(Tokens::balance(CurrencyId::KSM, &ALICE), 10_000 - 1_500 + 400);
+		System::assert_last_event(
+			Event::<Test>::SurplusRefunded { query_id, payer: ALICE, amount: 400 }.into(),
+		);
+		assert_eq!(XcmHelper::pending_refund(query_id), None);
+		assert_noop!(report_surplus(sibling(), query_id, 400), Error::<Test>::UnknownRefund);
+	});
+}
+
+#[test]
+fn refund_is_capped_at_the_paid_fees() {
+	new_test_ext().execute_with(|| {
+		set_up_sibling();
+		assert_ok!(Tokens::mint_into(CurrencyId::KSM, &XcmHelper::account_id(), 2_000));
+		assert_ok!(XcmHelper::transact(
+			RuntimeOrigin::root(),
+			Box::new(sibling().into()),
+			vec![7, 7],
+			Weight::from_parts(1_000, 0)
+		));
+		let query_id = refund_query_id(&SentXcm::get()[0].1);
+
+		assert_ok!(report_surplus(sibling(), query_id, 5_000));
+		assert_eq!(Tokens::balance(CurrencyId::KSM, &XcmHelper::account_id()), 2_000);
+		System::assert_last_event(
+			Event::<Test>::SurplusRefunded {
+				query_id,
+				payer: XcmHelper::account_id(),
+				amount: 1_500,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn transact_weight_and_fees_are_checked() {
+	new_test_ext().execute_with(|| {
+		set_up_sibling();
+		assert_noop!(
+			XcmHelper::do_transact(
+				&ALICE,
+				sibling(),
+				vec![],
+				Weight::from_parts(10_001, 0),
+				1,
+				notify()
+			),
+			Error::<Test>::TransactWeightTooHigh
+		);
+		assert_noop!(
+			XcmHelper::do_transact(
+				&ALICE,
+				sibling(),
+				vec![],
+				Weight::from_parts(10_000, 0),
+				1,
+				notify()
+			),
+			Error::<Test>::InsufficientXcmFees
+		);
+		assert_noop!(
+			XcmHelper::do_transact(
+				&ALICE,
+				MultiLocation::parent(),
+				vec![],
+				Weight::from_parts(1_000, 0),
+				1,
+				notify()
+			),
+			Error::<Test>::TransactInfoNotFound
+		);
+		assert!(SentXcm::get().is_empty());
+	});
+}
+
+#[test]
+fn sovereign_transact_is_only_for_update_origin() {
+	new_test_ext().execute_with(|| {
+		set_up_sibling();
+		let weight = Weight::from_parts(1_000, 0);
+		assert_noop!(
+			XcmHelper::transact(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(sibling().into()),
+				vec![7, 7],
+				weight
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			XcmHelper::transact(
+				RuntimeOrigin::root(),
+				Box::new(sibling().into()),
+				vec![7, 7],
+				weight
+			),
+			Error::<Test>::InsufficientXcmFees
+		);
+
+		assert_ok!(Tokens::mint_into(CurrencyId::KSM, &XcmHelper::account_id(), 2_000));
+		assert_ok!(XcmHelper::transact(
+			RuntimeOrigin::root(),
+			Box::new(sibling().into()),
+			vec![7, 7],
+			weight
+		));
+		assert_eq!(Tokens::balance(CurrencyId::KSM, &XcmHelper::account_id()), 500);
+		assert_eq!(Tokens::balance(CurrencyId::KSM, &ALICE), 10_000);
+		System::assert_last_event(
+			Event::<Test>::Transacted { dest: sibling(), query_id: None, derivative_index: None }
+				.into(),
+		);
+
+		let sent = SentXcm::get();
+		assert_eq!(sent.len(), 1);
+		assert_eq!(transacted_call(&sent[0].1), vec![7, 7]);
+	});
+}
@@ -0,0 +1,48 @@
+use frame_support::pallet_prelude::Weight;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+use xcm::latest::MultiLocation;
+
+/// Index of `utility.as_derivative` in the utility pallets of Substrate chains.
+pub const AS_DERIVATIVE_CALL_INDEX: u8 = 1;
+
+/// How to pay for transacts on a destination.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TransactInfo<CurrencyId, Balance> {
+	/// Local asset burned from the payer for the fees, representing `fee_location`.
+	pub fee_currency: CurrencyId,
+	/// Fee asset withdrawn from the sovereign account, as seen from the destination.
+	pub fee_location: MultiLocation,
+	/// Fee per second of execution on the destination, in `fee_currency`.
+	pub fee_per_second: Balance,
+	/// Weight of the instructions around the transact.
+	pub extra_weight: Weight,
+	/// Maximum weight of a transacted call.
+	pub max_weight: Weight,
+	/// Index of the utility pallet on the destination, if transacts can be dispatched from
+	/// derivative accounts.
+	pub utility_pallet_index: Option<u8>,
+}
+
+/// Transact waiting for the destination to report its unused fees.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PendingRefund<AccountId, CurrencyId, Balance> {
+	/// Account the fees were burnt from.
+	pub payer: AccountId,
+	/// Destination expected to report the unused fees.
+	pub dest: MultiLocation,
+	/// Local asset the fees were burnt in.
+	pub fee_currency: CurrencyId,
+	/// Fees burnt from the payer, the most that can be refunded.
+	pub fees: Balance,
+}
+
+/// Wraps an encoded `call` into `utility.as_derivative(index, call)` of the utility pallet at
+/// `utility_pallet_index`.
+pub fn as_derivative(utility_pallet_index: u8, index: u16, call: Vec<u8>) -> Vec<u8> {
+	let mut encoded = (utility_pallet_index, AS_DERIVATIVE_CALL_INDEX, index).encode();
+	encoded.extend(call);
+	encoded
+}
@@ -46,6 +46,10 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for pallet_xcm_helper.
 pub trait WeightInfo {
 	fn update_xcm_weight_fee() -> Weight;
+	fn set_transact_info() -> Weight;
+	fn set_derivative_account() -> Weight;
+	fn transact() -> Weight;
+	fn refund_surplus() -> Weight;
 }
 
 /// Weights for pallet_xcm_helper using the Substrate node and recommended hardware.
@@ -58,6 +62,37 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2u64))
 			.saturating_add(T::DbWeight::get().writes(2u64))
 	}
+	// Storage: XcmHelper TransactInfos (r:0 w:1)
+	fn set_transact_info() -> Weight {
+		Weight::from_ref_time(24_318_000u64)
+			.saturating_add(T::DbWeight::get().writes(1u64))
+	}
+	// Storage: XcmHelper DerivativeAccounts (r:0 w:1)
+	fn set_derivative_account() -> Weight {
+		Weight::from_ref_time(21_904_000u64)
+			.saturating_add(T::DbWeight::get().writes(1u64))
+	}
+	// Storage: XcmHelper TransactInfos (r:1 w:0)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	// Storage: PolkadotXcm Queries (r:0 w:1)
+	// Storage: XcmHelper PendingRefunds (r:0 w:1)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	fn transact() -> Weight {
+		Weight::from_ref_time(71_240_000u64)
+			.saturating_add(T::DbWeight::get().reads(6u64))
+			.saturating_add(T::DbWeight::get().writes(6u64))
+	}
+	// Storage: XcmHelper PendingRefunds (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	fn refund_surplus() -> Weight {
+		Weight::from_ref_time(34_670_000u64)
+			.saturating_add(T::DbWeight::get().reads(3u64))
+			.saturating_add(T::DbWeight::get().writes(3u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -69,4 +104,35 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2u64))
 			.saturating_add(RocksDbWeight::get().writes(2u64))
 	}
+	// Storage: XcmHelper TransactInfos (r:0 w:1)
+	fn set_transact_info() -> Weight {
+		Weight::from_ref_time(24_318_000u64)
+			.saturating_add(RocksDbWeight::get().writes(1u64))
+	}
+	// Storage: XcmHelper DerivativeAccounts (r:0 w:1)
+	fn set_derivative_account() -> Weight {
+		Weight::from_ref_time(21_904_000u64)
+			.saturating_add(RocksDbWeight::get().writes(1u64))
+	}
+	// Storage: XcmHelper TransactInfos (r:1 w:0)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: PolkadotXcm QueryCounter (r:1 w:1)
+	// Storage: PolkadotXcm Queries (r:0 w:1)
+	// Storage: XcmHelper PendingRefunds (r:0 w:1)
+	// Storage: ParachainSystem HostConfiguration (r:1 w:0)
+	// Storage: ParachainSystem PendingUpwardMessages (r:1 w:1)
+	fn transact() -> Weight {
+		Weight::from_ref_time(71_240_000u64)
+			.saturating_add(RocksDbWeight::get().reads(6u64))
+			.saturating_add(RocksDbWeight::get().writes(6u64))
+	}
+	// Storage: XcmHelper PendingRefunds (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	fn refund_surplus() -> Weight {
+		Weight::from_ref_time(34_670_000u64)
+			.saturating_add(RocksDbWeight::get().reads(3u64))
+			.saturating_add(RocksDbWeight::get().writes(3u64))
+	}
 }
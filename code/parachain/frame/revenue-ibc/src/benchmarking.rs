@@ -0,0 +1,87 @@
+use super::*;
+use crate::{BenchmarkHelper, Pallet as RevenueIbc};
+use frame_benchmarking::{benchmarks, BenchmarkError};
+use frame_support::{
+	assert_ok,
+	traits::{fungibles::Mutate, EnsureOrigin, Get, Hooks},
+	BoundedVec,
+};
+use sp_runtime::{AccountId32, FixedPointOperand, Perbill};
+use sp_std::vec::Vec;
+
+const REVENUE: u128 = 1_000_000_000_000_000;
+
+/// Allows `a` assets with revenue to forward, the first `s` of them having a swap route, and
+/// splits them across `d` destinations.
+fn set_up<T: Config>(a: u32, s: u32, d: u32)
+where
+	T: Send + Sync,
+	AccountId32: From<T::AccountId>,
+	u32: From<T::BlockNumber>,
+	BalanceOf<T>: FixedPointOperand + From<u128>,
+{
+	let target_asset = T::BenchmarkHelper::create_asset(u32::MAX);
+	for i in 0..a {
+		let asset_id = T::BenchmarkHelper::create_asset(i);
+		AllowedAssets::<T>::insert(&asset_id, ());
+		assert_ok!(T::Assets::mint_into(asset_id.clone(), &T::FeeAccount::get(), REVENUE.into()));
+		assert_ok!(T::Assets::mint_into(
+			asset_id.clone(),
+			&RevenueIbc::<T>::pallet_account_id(),
+			REVENUE.into()
+		));
+		if i < s {
+			let pool_id = T::BenchmarkHelper::create_pool(asset_id.clone(), target_asset.clone());
+			SwapRoutes::<T>::insert(
+				&asset_id,
+				SwapRoute {
+					pool_id,
+					target_asset: target_asset.clone(),
+					max_slippage: Perbill::one(),
+				},
+			);
+		}
+	}
+	let share = Perbill::from_rational(1, d);
+	let destinations = (0..d)
+		.map(|_| Destination::<T> {
+			channel: T::BenchmarkHelper::channel(),
+			address: BoundedVec::truncate_from(
+				b"centauri1mgx8nqxr4sy0zjqxrnn6nlg6gyvemafrzpxgga".to_vec(),
+			),
+			memo: None,
+			share,
+		})
+		.collect::<Vec<_>>();
+	Destinations::<T>::set(BoundedVec::truncate_from(destinations));
+}
+
+benchmarks! {
+	where_clause {
+		where
+			T: Send + Sync,
+			AccountId32: From<T::AccountId>,
+			u32: From<T::BlockNumber>,
+			BalanceOf<T>: FixedPointOperand + From<u128>,
+	}
+
+	on_initialize {
+		let a in 1 .. T::MaxAllowedAssets::get();
+		let s in 0 .. T::MaxAllowedAssets::get();
+		let d in 1 .. T::MaxDestinations::get();
+		set_up::<T>(a, s.min(a), d);
+		Period::<T>::set(1_u32.into());
+	}: {
+		RevenueIbc::<T>::on_initialize(1_u32.into());
+	}
+
+	trigger_transfer {
+		let a in 1 .. T::MaxAllowedAssets::get();
+		let s in 0 .. T::MaxAllowedAssets::get();
+		let d in 1 .. T::MaxDestinations::get();
+		set_up::<T>(a, s.min(a), d);
+		let origin = T::Admin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: {
+		RevenueIbc::<T>::trigger_transfer(origin)?;
+	}
+}
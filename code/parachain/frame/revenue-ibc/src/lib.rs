@@ -37,13 +37,33 @@ pub use pallet::*;
 
 pub mod weights;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+
+/// Creates the assets, pools and destinations revenue is forwarded with in the benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId, PoolId> {
+	/// Registers a new asset, different for every `index`.
+	fn create_asset(index: u32) -> AssetId;
+	/// Creates a pool with liquidity `asset_id` can be swapped through for `target_asset`.
+	fn create_pool(asset_id: AssetId, target_asset: AssetId) -> PoolId;
+	/// Channel revenue is transferred over.
+	fn channel() -> u64;
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	pub use crate::weights::WeightInfo;
 	pub use composable_traits::prelude::ToString;
 
+	use crate::{min_receive, split_shares};
+
 	use composable_traits::{
 		currency::AssetExistentialDepositInspect,
+		dex::{Amm, AssetAmount, Twap},
+		oracle::Oracle,
 		prelude::{String, Vec},
 		xcm::assets::RemoteAssetRegistryInspect,
 	};
@@ -63,7 +83,7 @@ pub mod pallet {
 
 	use sp_runtime::{
 		traits::{AccountIdConversion, CheckedSub, Zero},
-		AccountId32, Perbill,
+		AccountId32, ArithmeticError, FixedPointNumber, FixedPointOperand, Perbill,
 	};
 	pub use sp_std::{prelude::*, str::FromStr, vec};
 
@@ -72,6 +92,41 @@ pub mod pallet {
 
 	pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
+	/// How a collected asset is converted before it is forwarded.
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct SwapRoute<PoolId, AssetId> {
+		/// Pool (pablo pool id or dex-router pair) used for the swap.
+		pub pool_id: PoolId,
+		/// Asset received in exchange for the collected one.
+		pub target_asset: AssetId,
+		/// Maximal deviation from the oracle quoted output accepted for the swap.
+		pub max_slippage: Perbill,
+	}
+
+	pub(crate) type SwapRouteOf<T> = SwapRoute<<T as Config>::ExchangePoolId, AssetIdOf<T>>;
+
+	/// One of the IBC destinations revenue is split across.
+	#[derive(
+		Encode,
+		Decode,
+		MaxEncodedLen,
+		TypeInfo,
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+	)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Destination<T: Config> {
+		pub channel: u64,
+		pub address: BoundedVec<u8, T::MaxStringSizeAddress>,
+		/// Memo sent along the transfer, `{osmo}` and `{centauri}` are replaced as for
+		/// `ForwardMemo`.
+		pub memo: Option<BoundedVec<u8, T::MaxStringSizeMemo>>,
+		/// Part of every forwarded asset sent to this destination.
+		pub share: Perbill,
+	}
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config + pallet_ibc::Config {
 		#[allow(missing_docs)]
@@ -141,8 +196,41 @@ pub mod pallet {
 		// root and council
 		type Admin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
 
+		/// Pablo or dex-router, used to convert collected assets before forwarding them. Assets
+		/// without an oracle price are quoted at the TWAP of the pool they are swapped through.
+		type Exchange: Amm<
+				AssetId = AssetIdOf<Self>,
+				Balance = BalanceOf<Self>,
+				AccountId = AccountIdOf<Self>,
+				PoolId = Self::ExchangePoolId,
+			> + Twap<AssetId = AssetIdOf<Self>, PoolId = Self::ExchangePoolId>;
+
+		type ExchangePoolId: codec::FullCodec
+			+ MaxEncodedLen
+			+ Eq
+			+ PartialEq
+			+ Clone
+			+ Debug
+			+ TypeInfo;
+
+		/// Quotes swaps, so that the minimal output does not depend on the state of the pool the
+		/// swap goes through.
+		type Oracle: Oracle<AssetId = AssetIdOf<Self>, Balance = BalanceOf<Self>>;
+
+		/// Maximal number of destinations revenue can be split across.
+		#[pallet::constant]
+		type MaxDestinations: Get<u32>;
+
+		/// Maximal number of allowed assets, every one of them may be swapped and transferred to
+		/// every destination each period.
+		#[pallet::constant]
+		type MaxAllowedAssets: Get<u32>;
+
 		/// Weight information for the extrinsics.
 		type WeightInfo: WeightInfo;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: crate::BenchmarkHelper<AssetIdOf<Self>, Self::ExchangePoolId>;
 	}
 
 	// The pallet's events
@@ -192,6 +280,23 @@ pub mod pallet {
 		Memo {
 			memo: String,
 		},
+		SwapRouteSet {
+			asset_id: AssetIdOf<T>,
+			route: Option<SwapRouteOf<T>>,
+		},
+		Swapped {
+			asset_id: AssetIdOf<T>,
+			amount: BalanceOf<T>,
+			target_asset: AssetIdOf<T>,
+			received: BalanceOf<T>,
+		},
+		SwapFailed {
+			asset_id: AssetIdOf<T>,
+			amount: BalanceOf<T>,
+		},
+		DestinationsSet {
+			destinations: BoundedVec<Destination<T>, T::MaxDestinations>,
+		},
 		RevenueCalcutions,
 		SetAllowed,
 		AddAllowed,
@@ -207,6 +312,14 @@ pub mod pallet {
 	pub enum Error<T> {
 		ChannelNotSet,
 		CentauriAddressNotSet,
+		/// Shares of destinations do not add up to 100%.
+		InvalidDestinationShares,
+		/// Swap route targets the asset it swaps.
+		SwapToSameAsset,
+		/// Swap route pool does not hold the swapped or the target asset.
+		AssetNotInPool,
+		/// More than `MaxAllowedAssets` assets would be allowed.
+		TooManyAllowedAssets,
 	}
 
 	#[pallet::pallet]
@@ -261,12 +374,28 @@ pub mod pallet {
 	pub type ForwardMemo<T: Config> =
 		StorageValue<_, BoundedVec<u8, T::MaxStringSizeMemo>, OptionQuery>;
 
+	/// Assets converted to `SwapRoute::target_asset` before being forwarded.
+	#[pallet::storage]
+	#[pallet::getter(fn swap_route)]
+	#[allow(clippy::disallowed_types)]
+	pub type SwapRoutes<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetIdOf<T>, SwapRouteOf<T>, OptionQuery>;
+
+	/// When not empty, forwarded assets are split across these destinations instead of being sent
+	/// to `CentauriAddress` over `CentauriChannel`.
+	#[pallet::storage]
+	#[pallet::getter(fn destinations)]
+	#[allow(clippy::disallowed_types)]
+	pub type Destinations<T: Config> =
+		StorageValue<_, BoundedVec<Destination<T>, T::MaxDestinations>, ValueQuery>;
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T>
 	where
 		T: Send + Sync,
 		AccountId32: From<<T as frame_system::Config>::AccountId>,
 		u32: From<<T as frame_system::Config>::BlockNumber>,
+		BalanceOf<T>: FixedPointOperand,
 	{
 		// on every period, for every ibc asset from CentauriChannel - Disallowed Assets + Allowed
 		// Asset if 20 percent of new treasury balance for a token - old balance of token >= token's
@@ -329,11 +458,17 @@ pub mod pallet {
 						Self::deposit_event(Event::<T>::SkipAsset { asset_id: asset_id.clone() });
 					}
 				});
-				if Self::transfer_from_intermediate().is_err() {
-					Self::deposit_event(Event::<T>::IntermediateTransferFail);
-				}
+				let (swaps, destinations) =
+					Self::transfer_from_intermediate().unwrap_or_else(|_| {
+						Self::deposit_event(Event::<T>::IntermediateTransferFail);
+						(0, 0)
+					});
 
-				<T as Config>::WeightInfo::on_initialize(Self::get_ibc_assets().len())
+				<T as Config>::WeightInfo::on_initialize(
+					Self::get_ibc_assets().len() as u32,
+					swaps,
+					destinations,
+				)
 			} else {
 				Weight::zero()
 			}
@@ -347,6 +482,7 @@ pub mod pallet {
 		T: Send + Sync,
 		AccountId32: From<<T as frame_system::Config>::AccountId>,
 		u32: From<<T as frame_system::Config>::BlockNumber>,
+		BalanceOf<T>: FixedPointOperand,
 	{
 		#[pallet::call_index(0)]
 		#[pallet::weight(<T as Config>::WeightInfo::set_period())]
@@ -388,18 +524,31 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as Config>::WeightInfo::trigger_transfer())]
-		pub fn trigger_transfer(origin: OriginFor<T>) -> DispatchResult {
+		#[pallet::weight(<T as Config>::WeightInfo::trigger_transfer(
+			T::MaxAllowedAssets::get(),
+			T::MaxAllowedAssets::get(),
+			T::MaxDestinations::get(),
+		))]
+		pub fn trigger_transfer(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			T::Admin::ensure_origin(origin)?;
-			Self::transfer_from_intermediate()?;
+			let (swaps, destinations) = Self::transfer_from_intermediate()?;
 			Self::deposit_event(Event::<T>::TransferTriggered);
-			Ok(())
+			Ok(Some(<T as Config>::WeightInfo::trigger_transfer(
+				Self::get_ibc_assets().len() as u32,
+				swaps,
+				destinations,
+			))
+			.into())
 		}
 
 		#[pallet::call_index(3)]
 		#[pallet::weight(<T as Config>::WeightInfo::set_allowed())]
 		pub fn set_allowed(origin: OriginFor<T>, assets: Vec<AssetIdOf<T>>) -> DispatchResult {
 			T::Admin::ensure_origin(origin)?;
+			ensure!(
+				assets.len() <= T::MaxAllowedAssets::get() as usize,
+				Error::<T>::TooManyAllowedAssets
+			);
 			AllowedAssets::<T>::drain().for_each(|(asset, _val)| {
 				TokenPrevPeriodBalance::<T>::remove(asset);
 			});
@@ -425,6 +574,12 @@ pub mod pallet {
 		#[pallet::weight(<T as Config>::WeightInfo::add_allowed())]
 		pub fn add_allowed(origin: OriginFor<T>, asset: AssetIdOf<T>) -> DispatchResult {
 			T::Admin::ensure_origin(origin)?;
+			ensure!(
+				AllowedAssets::<T>::contains_key(&asset) ||
+					AllowedAssets::<T>::iter_keys().count() <
+						T::MaxAllowedAssets::get() as usize,
+				Error::<T>::TooManyAllowedAssets
+			);
 			AllowedAssets::<T>::insert(&asset, ());
 			TokenPrevPeriodBalance::<T>::insert(
 				&asset,
@@ -523,6 +678,58 @@ pub mod pallet {
 			Self::deposit_event(Event::<T>::CvmCentauriAddress { asset_id, cvm_centauri });
 			Ok(())
 		}
+
+		/// Set or remove (with `None`) conversion of `asset_id` before it is forwarded.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_swap_route())]
+		pub fn set_swap_route(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			route: Option<SwapRouteOf<T>>,
+		) -> DispatchResult {
+			T::Admin::ensure_origin(origin)?;
+			match &route {
+				Some(route) => {
+					ensure!(route.target_asset != asset_id, Error::<T>::SwapToSameAsset);
+					let pool_assets = T::Exchange::assets(route.pool_id.clone())?;
+					ensure!(
+						pool_assets.contains_key(&asset_id) &&
+							pool_assets.contains_key(&route.target_asset),
+						Error::<T>::AssetNotInPool
+					);
+					SwapRoutes::<T>::insert(&asset_id, route)
+				},
+				None => SwapRoutes::<T>::remove(&asset_id),
+			}
+			Self::deposit_event(Event::<T>::SwapRouteSet { asset_id, route });
+			Ok(())
+		}
+
+		/// Split forwarded revenue across `destinations`, shares must add up to 100%.
+		/// Empty list restores forwarding to `CentauriAddress`.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_destinations(destinations.len()))]
+		pub fn set_destinations(
+			origin: OriginFor<T>,
+			destinations: BoundedVec<Destination<T>, T::MaxDestinations>,
+		) -> DispatchResult {
+			T::Admin::ensure_origin(origin)?;
+			if !destinations.is_empty() {
+				let total = destinations
+					.iter()
+					.try_fold(0_u32, |acc, destination| {
+						acc.checked_add(destination.share.deconstruct())
+					})
+					.ok_or(Error::<T>::InvalidDestinationShares)?;
+				ensure!(
+					total == Perbill::one().deconstruct(),
+					Error::<T>::InvalidDestinationShares
+				);
+			}
+			Destinations::<T>::set(destinations.clone());
+			Self::deposit_event(Event::<T>::DestinationsSet { destinations });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T>
@@ -530,78 +737,170 @@ pub mod pallet {
 		T: Send + Sync,
 		AccountId32: From<<T as frame_system::Config>::AccountId>,
 		u32: From<<T as frame_system::Config>::BlockNumber>,
+		BalanceOf<T>: FixedPointOperand,
 	{
 		pub fn pallet_account_id() -> T::AccountId {
 			T::IntermediatePalletId::get().into_account_truncating()
 		}
 
-		fn transfer_from_intermediate() -> DispatchResult {
-			if let Some(channel_id) = Self::centauri_channel() {
-				if let Some(centauri_address) = Self::centauri_address() {
-					let transfer_params: TransferParams<T::AccountId> =
-						TransferParams::<T::AccountId> {
-							to: MultiAddress::<T::AccountId>::Raw(centauri_address.into()),
-							source_channel: channel_id,
-							timeout: IbcTimeout::Offset {
-								timestamp: Some(6_000_000_000_000),
-								height: Some(1000),
-							},
-						};
-					Self::get_ibc_assets().into_iter().for_each(|asset_id| {
-						let memo = match Self::memo() {
-							Some(m) => match String::from_utf8(m.into()) {
-								Ok(m) => {
-									let mut replaced_memo = m;
-									if let Some(osmo_id) = CvmOsmoAddress::<T>::get(&asset_id) {
-										replaced_memo = replaced_memo
-											.replace("{osmo}", osmo_id.to_string().as_str());
-									}
-									if let Some(centauri) = CvmCentauriAddress::<T>::get(&asset_id)
-									{
-										replaced_memo = replaced_memo
-											.replace("{centauri}", centauri.to_string().as_str());
-									}
-									Self::deposit_event(Event::<T>::Memo {
-										memo: replaced_memo.clone(),
-									});
-									<T as pallet_ibc::Config>::MemoMessage::from_str(&replaced_memo)
-										.ok()
-								},
-								_ => None,
-							},
-							_ => None,
-						};
-						let amount = T::Assets::reducible_balance(
-							asset_id.clone(),
-							&Self::pallet_account_id(),
-							Preservation::Expendable,
-							frame_support::traits::tokens::Fortitude::Polite,
-						);
-						if amount > Zero::zero() {
-							let result = pallet_ibc::Pallet::<T>::transfer(
-								frame_system::RawOrigin::Signed(Self::pallet_account_id()).into(),
-								transfer_params.clone(),
-								asset_id.clone(),
-								amount,
-								memo,
-							);
-							if result.is_err() {
-								Self::deposit_event(Event::<T>::TransferFailed {
-									asset_id,
-									amount,
-								});
-							}
+		// returns the number of swapped assets and of destinations transferred to
+		fn transfer_from_intermediate() -> Result<(u32, u32), DispatchError> {
+			let destinations = Self::destinations();
+			let destinations = if destinations.is_empty() {
+				let channel = Self::centauri_channel().ok_or(Error::<T>::ChannelNotSet)?;
+				let address = Self::centauri_address().ok_or(Error::<T>::CentauriAddressNotSet)?;
+				vec![Destination::<T> {
+					channel,
+					address,
+					memo: Self::memo(),
+					share: Perbill::one(),
+				}]
+			} else {
+				destinations.into_inner()
+			};
+
+			let (forwarded, swaps) = Self::forwarded_assets();
+			forwarded.into_iter().for_each(|asset_id| {
+				let total = T::Assets::reducible_balance(
+					asset_id.clone(),
+					&Self::pallet_account_id(),
+					Preservation::Expendable,
+					frame_support::traits::tokens::Fortitude::Polite,
+				);
+				if total.is_zero() {
+					return
+				}
+				let amounts =
+					split_shares(total, destinations.iter().map(|destination| destination.share));
+				for (destination, amount) in destinations.iter().zip(amounts) {
+					if amount.is_zero() {
+						continue
+					}
+					let transfer_params = TransferParams::<T::AccountId> {
+						to: MultiAddress::<T::AccountId>::Raw(destination.address.clone().into()),
+						source_channel: destination.channel,
+						timeout: IbcTimeout::Offset {
+							timestamp: Some(6_000_000_000_000),
+							height: Some(1000),
+						},
+					};
+					let result = pallet_ibc::Pallet::<T>::transfer(
+						frame_system::RawOrigin::Signed(Self::pallet_account_id()).into(),
+						transfer_params,
+						asset_id.clone(),
+						amount,
+						Self::build_memo(destination.memo.clone(), &asset_id),
+					);
+					if result.is_err() {
+						Self::deposit_event(Event::<T>::TransferFailed {
+							asset_id: asset_id.clone(),
+							amount,
+						});
+					}
+				}
+			});
+			Ok((swaps, destinations.len() as u32))
+		}
+
+		// swaps whole intermediate balance of assets having a route into their target asset,
+		// returns assets to be forwarded and the number of swaps. Assets which failed to swap are
+		// forwarded as is.
+		fn forwarded_assets() -> (Vec<AssetIdOf<T>>, u32) {
+			let mut assets = Vec::<AssetIdOf<T>>::new();
+			let mut swaps = 0_u32;
+			Self::get_ibc_assets().into_iter().for_each(|asset_id| {
+				let forwarded = match Self::swap_route(&asset_id) {
+					Some(route) => {
+						swaps = swaps.saturating_add(1);
+						match Self::swap(&asset_id, &route) {
+							Some(()) => route.target_asset,
+							None => asset_id,
 						}
-					});
-					Ok(())
-				} else {
-					Err(Error::<T>::CentauriAddressNotSet.into())
+					},
+					None => asset_id,
+				};
+				if !assets.contains(&forwarded) {
+					assets.push(forwarded);
 				}
-			} else {
-				Err(Error::<T>::ChannelNotSet.into())
+			});
+			(assets, swaps)
+		}
+
+		fn swap(asset_id: &AssetIdOf<T>, route: &SwapRouteOf<T>) -> Option<()> {
+			let amount = T::Assets::reducible_balance(
+				asset_id.clone(),
+				&Self::pallet_account_id(),
+				Preservation::Expendable,
+				frame_support::traits::tokens::Fortitude::Polite,
+			);
+			if amount.is_zero() {
+				return Some(())
+			}
+			let result = Self::quote(asset_id, route, amount).and_then(|expected| {
+				T::Exchange::do_swap(
+					&Self::pallet_account_id(),
+					route.pool_id.clone(),
+					AssetAmount::new(asset_id.clone(), amount),
+					AssetAmount::new(
+						route.target_asset.clone(),
+						min_receive(expected, route.max_slippage),
+					),
+					false,
+				)
+			});
+			match result {
+				Ok(swapped) => {
+					Self::deposit_event(Event::<T>::Swapped {
+						asset_id: asset_id.clone(),
+						amount,
+						target_asset: route.target_asset.clone(),
+						received: swapped.value.amount,
+					});
+					Some(())
+				},
+				Err(_) => {
+					Self::deposit_event(Event::<T>::SwapFailed {
+						asset_id: asset_id.clone(),
+						amount,
+					});
+					None
+				},
 			}
 		}
 
+		// quoted from oracle TWAP, pool spot price can be moved by the same block transactions.
+		// Assets without an oracle price, such as dust collected as fees, are quoted at the TWAP
+		// of the pool they are swapped through.
+		fn quote(
+			asset_id: &AssetIdOf<T>,
+			route: &SwapRouteOf<T>,
+			amount: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			T::Oracle::get_twap_for_amount(asset_id.clone(), amount)
+				.and_then(|value| T::Oracle::get_price_inverse(route.target_asset.clone(), value))
+				.or_else(|_| {
+					T::Exchange::get_twap(route.pool_id.clone(), asset_id.clone())?
+						.checked_mul_int(amount)
+						.ok_or_else(|| ArithmeticError::Overflow.into())
+				})
+		}
+
+		fn build_memo(
+			memo: Option<BoundedVec<u8, T::MaxStringSizeMemo>>,
+			asset_id: &AssetIdOf<T>,
+		) -> Option<<T as pallet_ibc::Config>::MemoMessage> {
+			let memo = String::from_utf8(memo?.into()).ok()?;
+			let mut replaced_memo = memo;
+			if let Some(osmo_id) = CvmOsmoAddress::<T>::get(asset_id) {
+				replaced_memo = replaced_memo.replace("{osmo}", osmo_id.to_string().as_str());
+			}
+			if let Some(centauri) = CvmCentauriAddress::<T>::get(asset_id) {
+				replaced_memo = replaced_memo.replace("{centauri}", centauri.to_string().as_str());
+			}
+			Self::deposit_event(Event::<T>::Memo { memo: replaced_memo.clone() });
+			<T as pallet_ibc::Config>::MemoMessage::from_str(&replaced_memo).ok()
+		}
+
 		fn get_ibc_assets() -> Vec<AssetIdOf<T>> {
 			AllowedAssets::<T>::iter_keys().collect::<Vec<AssetIdOf<T>>>()
 			// TODO: send all ibc tokens to specified address
@@ -625,3 +924,33 @@ pub mod pallet {
 		}
 	}
 }
+
+/// Minimal amount accepted out of a swap quoted at `expected`.
+pub(crate) fn min_receive<B>(expected: B, max_slippage: sp_runtime::Perbill) -> B
+where
+	B: Copy + sp_runtime::traits::CheckedSub + sp_runtime::traits::Zero,
+	sp_runtime::Perbill: core::ops::Mul<B, Output = B>,
+{
+	expected.checked_sub(&(max_slippage * expected)).unwrap_or_else(B::zero)
+}
+
+/// Splits `total` by `shares`, the last share takes the rounding dust.
+pub(crate) fn split_shares<B>(
+	total: B,
+	shares: impl ExactSizeIterator<Item = sp_runtime::Perbill>,
+) -> sp_std::vec::Vec<B>
+where
+	B: Copy + sp_runtime::traits::CheckedSub + sp_runtime::traits::Zero,
+	sp_runtime::Perbill: core::ops::Mul<B, Output = B>,
+{
+	let mut remaining = total;
+	let last = shares.len().saturating_sub(1);
+	shares
+		.enumerate()
+		.map(|(index, share)| {
+			let amount = if index == last { remaining } else { share * total };
+			remaining = remaining.checked_sub(&amount).unwrap_or_else(B::zero);
+			amount
+		})
+		.collect()
+}
@@ -0,0 +1,25 @@
+use crate::{min_receive, split_shares};
+use sp_runtime::Perbill;
+
+#[test]
+fn min_receive_applies_slippage_to_quote() {
+	assert_eq!(min_receive(1_000_u128, Perbill::from_percent(1)), 990);
+	assert_eq!(min_receive(1_000_u128, Perbill::zero()), 1_000);
+	assert_eq!(min_receive(1_000_u128, Perbill::one()), 0);
+}
+
+#[test]
+fn split_shares_gives_rounding_dust_to_last_destination() {
+	let shares = [Perbill::from_percent(33), Perbill::from_percent(33), Perbill::from_percent(34)];
+	assert_eq!(split_shares(1_000_u128, shares.into_iter()), vec![330, 330, 340]);
+	let shares = [Perbill::from_rational(1_u32, 3_u32), Perbill::from_rational(2_u32, 3_u32)];
+	let amounts = split_shares(100_u128, shares.into_iter());
+	assert_eq!(amounts.iter().sum::<u128>(), 100);
+	assert_eq!(amounts, vec![33, 67]);
+}
+
+#[test]
+fn split_shares_to_single_destination_sends_everything() {
+	assert_eq!(split_shares(7_u128, [Perbill::one()].into_iter()), vec![7]);
+	assert_eq!(split_shares(0_u128, [Perbill::one()].into_iter()), vec![0]);
+}
@@ -7,10 +7,10 @@ use sp_std::marker::PhantomData;
 
 // The weight info trait for `pallet_oracle`.
 pub trait WeightInfo {
-	fn on_initialize(c: usize) -> Weight;
+	fn on_initialize(a: u32, s: u32, d: u32) -> Weight;
 	fn set_period() -> Weight;
 	fn set_memo() -> Weight;
-	fn trigger_transfer() -> Weight;
+	fn trigger_transfer(a: u32, s: u32, d: u32) -> Weight;
 	fn set_allowed() -> Weight;
 	fn add_allowed() -> Weight;
 	fn remove_allowed() -> Weight;
@@ -21,13 +21,22 @@ pub trait WeightInfo {
 	fn set_address() -> Weight;
 	fn set_cvm_osmo_address() -> Weight;
 	fn set_cvm_centauri_address() -> Weight;
+	fn set_swap_route() -> Weight;
+	fn set_destinations(c: usize) -> Weight;
 }
 
 /// Weights for pallet_oracle using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	fn on_initialize(c: usize) -> Weight {
-		Weight::from_parts(100_000, 0).saturating_mul(c as u64)
+	/// `a` allowed assets, `s` of them swapped and transferred to `d` destinations.
+	fn on_initialize(a: u32, s: u32, d: u32) -> Weight {
+		Weight::from_parts(100_000, 0)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(a as u64))
+			.saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(s as u64))
+			.saturating_add(
+				Weight::from_parts(1_000_000, 0)
+					.saturating_mul((a as u64).saturating_mul(d as u64)),
+			)
 	}
 	fn set_period() -> Weight {
 		Weight::from_parts(100_000, 0)
@@ -35,8 +44,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn set_memo() -> Weight {
 		Weight::from_parts(100_000, 0)
 	}
-	fn trigger_transfer() -> Weight {
+	/// `a` allowed assets, `s` of them swapped and transferred to `d` destinations.
+	fn trigger_transfer(a: u32, s: u32, d: u32) -> Weight {
 		Weight::from_parts(100_000, 0)
+			.saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(s as u64))
+			.saturating_add(
+				Weight::from_parts(1_000_000, 0)
+					.saturating_mul((a as u64).saturating_mul(d as u64)),
+			)
 	}
 	fn set_allowed() -> Weight {
 		Weight::from_parts(100_000, 0)
@@ -68,12 +83,25 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn set_cvm_centauri_address() -> Weight {
 		Weight::from_parts(100_000, 0)
 	}
+	fn set_swap_route() -> Weight {
+		Weight::from_parts(100_000, 0)
+	}
+	fn set_destinations(c: usize) -> Weight {
+		Weight::from_parts(100_000, 0).saturating_mul(c.max(1) as u64)
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	fn on_initialize(c: usize) -> Weight {
-		Weight::from_parts(100_000, 0).saturating_mul(c as u64)
+	/// `a` allowed assets, `s` of them swapped and transferred to `d` destinations.
+	fn on_initialize(a: u32, s: u32, d: u32) -> Weight {
+		Weight::from_parts(100_000, 0)
+			.saturating_add(Weight::from_parts(100_000, 0).saturating_mul(a as u64))
+			.saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(s as u64))
+			.saturating_add(
+				Weight::from_parts(1_000_000, 0)
+					.saturating_mul((a as u64).saturating_mul(d as u64)),
+			)
 	}
 	fn set_period() -> Weight {
 		Weight::from_parts(100_000, 0)
@@ -81,8 +109,14 @@ impl WeightInfo for () {
 	fn set_memo() -> Weight {
 		Weight::from_parts(100_000, 0)
 	}
-	fn trigger_transfer() -> Weight {
+	/// `a` allowed assets, `s` of them swapped and transferred to `d` destinations.
+	fn trigger_transfer(a: u32, s: u32, d: u32) -> Weight {
 		Weight::from_parts(100_000, 0)
+			.saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(s as u64))
+			.saturating_add(
+				Weight::from_parts(1_000_000, 0)
+					.saturating_mul((a as u64).saturating_mul(d as u64)),
+			)
 	}
 	fn set_allowed() -> Weight {
 		Weight::from_parts(100_000, 0)
@@ -114,4 +148,10 @@ impl WeightInfo for () {
 	fn set_cvm_centauri_address() -> Weight {
 		Weight::from_parts(100_000, 0)
 	}
+	fn set_swap_route() -> Weight {
+		Weight::from_parts(100_000, 0)
+	}
+	fn set_destinations(c: usize) -> Weight {
+		Weight::from_parts(100_000, 0).saturating_mul(c.max(1) as u64)
+	}
 }
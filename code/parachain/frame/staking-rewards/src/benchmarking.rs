@@ -0,0 +1,221 @@
+use super::*;
+use crate::Pallet as StakingRewards;
+use composable_support::validation::Validated;
+use composable_traits::{
+	staking::{
+		lock::{DurationMultipliers, LockConfig},
+		ManageStaking, ProtocolStaking, RewardConfig, RewardPoolConfiguration, RewardRate,
+		RewardUpdate, Staking,
+	},
+	time::{ONE_MONTH, ONE_WEEK},
+};
+use frame_benchmarking::{
+	benchmarks, impl_benchmark_test_suite, whitelisted_caller, BenchmarkError,
+};
+use frame_support::{
+	assert_ok,
+	traits::{fungibles::Mutate, EnsureOrigin, Get, Hooks},
+	BoundedBTreeMap,
+};
+use frame_system::RawOrigin;
+use sp_runtime::{traits::Saturating, FixedU64, Perbill, Permill};
+
+const STAKED_ASSET: u128 = 1_000;
+const STAKE: u128 = 1_000_000_000_000;
+
+fn reward_asset<T: Config>(i: u32) -> T::AssetId
+where
+	T::AssetId: From<u128>,
+{
+	(2_000 + i as u128).into()
+}
+
+fn pool_config<T: Config>(r: u32) -> RewardPoolConfigurationOf<T>
+where
+	T::AssetId: From<u128>,
+	T::Balance: From<u128>,
+{
+	let mut reward_configs = BoundedBTreeMap::new();
+	for i in 0..r {
+		reward_configs
+			.try_insert(
+				reward_asset::<T>(i),
+				RewardConfig { reward_rate: RewardRate::per_second(1_000_u128) },
+			)
+			.expect("within bounds; qed;");
+	}
+	let mut presets = BoundedBTreeMap::new();
+	for (duration, multiplier) in [(ONE_WEEK, 1), (ONE_MONTH, 2)] {
+		presets
+			.try_insert(
+				duration,
+				Validated::new(FixedU64::from_u32(multiplier))
+					.expect("multiplier is at least one; qed;"),
+			)
+			.expect("within bounds; qed;");
+	}
+	RewardPoolConfiguration::RewardRateBasedIncentive {
+		owner: whitelisted_caller(),
+		asset_id: STAKED_ASSET.into(),
+		start_block: 1_u32.into(),
+		reward_configs,
+		lock: LockConfig {
+			duration_multipliers: DurationMultipliers::Presets(presets),
+			unlock_penalty: Perbill::from_percent(10),
+		},
+		minimum_staking_amount: 1_000_u128.into(),
+	}
+}
+
+fn create_pool<T: Config>(r: u32)
+where
+	T::AssetId: From<u128>,
+	T::Balance: From<u128>,
+{
+	<StakingRewards<T> as ManageStaking>::create_staking_pool(pool_config::<T>(r))
+		.expect("valid configuration; qed;");
+}
+
+/// Stake in the pool with rewards for every reward asset, so that claims transfer all of them.
+fn stake<T: Config>(who: &T::AccountId, r: u32) -> PositionIdOf<T>
+where
+	T::AssetId: From<u128>,
+	T::Balance: From<u128>,
+{
+	assert_ok!(T::Assets::mint_into(
+		T::NativeAssetId::get(),
+		who,
+		T::PositionDeposit::get()
+			.saturating_mul(10_u128.into())
+			.saturating_add(STAKE.into())
+	));
+	assert_ok!(T::Assets::mint_into(STAKED_ASSET.into(), who, STAKE.into()));
+	let position = <StakingRewards<T> as Staking>::stake(
+		who,
+		&STAKED_ASSET.into(),
+		STAKE.into(),
+		ONE_WEEK,
+		true,
+	)
+	.expect("staking succeeds; qed;");
+	for i in 0..r {
+		assert_ok!(T::Assets::mint_into(reward_asset::<T>(i), who, STAKE.into()));
+		assert_ok!(<StakingRewards<T> as ProtocolStaking>::transfer_reward(
+			who,
+			&STAKED_ASSET.into(),
+			reward_asset::<T>(i),
+			STAKE.into(),
+			false
+		));
+	}
+	position
+}
+
+benchmarks! {
+	where_clause {
+		where
+			T::AssetId: From<u128>,
+			T::Balance: From<u128>,
+	}
+
+	create_reward_pool {
+		let r in 1 .. T::MaxRewardConfigsPerPool::get();
+		let origin = T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, pool_config::<T>(r))
+	verify {
+		assert!(RewardPools::<T>::contains_key(T::AssetId::from(STAKED_ASSET)));
+	}
+
+	stake {
+		let r in 1 .. T::MaxRewardConfigsPerPool::get();
+		let who: T::AccountId = whitelisted_caller();
+		create_pool::<T>(r);
+		stake::<T>(&who, r);
+		assert_ok!(T::Assets::mint_into(STAKED_ASSET.into(), &who, STAKE.into()));
+	}: _(RawOrigin::Signed(who), STAKED_ASSET.into(), STAKE.into(), ONE_WEEK)
+
+	extend {
+		let r in 1 .. T::MaxRewardConfigsPerPool::get();
+		let who: T::AccountId = whitelisted_caller();
+		create_pool::<T>(r);
+		let (collection, instance) = stake::<T>(&who, r);
+		assert_ok!(T::Assets::mint_into(STAKED_ASSET.into(), &who, STAKE.into()));
+	}: _(RawOrigin::Signed(who), collection, instance, STAKE.into())
+
+	unstake {
+		let r in 1 .. T::MaxRewardConfigsPerPool::get();
+		let who: T::AccountId = whitelisted_caller();
+		create_pool::<T>(r);
+		let (collection, instance) = stake::<T>(&who, r);
+	}: _(RawOrigin::Signed(who), collection, instance)
+	verify {
+		assert!(Stakes::<T>::get(collection, instance).is_none());
+	}
+
+	split {
+		let r in 1 .. T::MaxRewardConfigsPerPool::get();
+		let who: T::AccountId = whitelisted_caller();
+		create_pool::<T>(r);
+		let (collection, instance) = stake::<T>(&who, r);
+	}: _(RawOrigin::Signed(who), collection, instance, Permill::from_percent(50))
+
+	merge {
+		let r in 1 .. T::MaxRewardConfigsPerPool::get();
+		let who: T::AccountId = whitelisted_caller();
+		create_pool::<T>(r);
+		let (collection, instance) = stake::<T>(&who, r);
+		let (_, other_instance) = stake::<T>(&who, r);
+	}: _(RawOrigin::Signed(who), collection, instance, other_instance)
+	verify {
+		assert!(Stakes::<T>::get(collection, other_instance).is_none());
+	}
+
+	claim {
+		let r in 1 .. T::MaxRewardConfigsPerPool::get();
+		let who: T::AccountId = whitelisted_caller();
+		create_pool::<T>(r);
+		let (collection, instance) = stake::<T>(&who, r);
+	}: _(RawOrigin::Signed(who), collection, instance)
+
+	extend_duration {
+		let r in 1 .. T::MaxRewardConfigsPerPool::get();
+		let who: T::AccountId = whitelisted_caller();
+		create_pool::<T>(r);
+		let (collection, instance) = stake::<T>(&who, r);
+	}: _(RawOrigin::Signed(who), collection, instance, Some(ONE_MONTH))
+
+	add_to_rewards_pot {
+		let who: T::AccountId = whitelisted_caller();
+		create_pool::<T>(1);
+		assert_ok!(T::Assets::mint_into(reward_asset::<T>(0), &who, STAKE.into()));
+	}: _(RawOrigin::Signed(who), STAKED_ASSET.into(), reward_asset::<T>(0), STAKE.into(), false)
+
+	update_rewards_pool {
+		let r in 1 .. T::MaxRewardConfigsPerPool::get();
+		create_pool::<T>(1);
+		let mut updates = BoundedBTreeMap::new();
+		for i in 0..r {
+			updates
+				.try_insert(
+					reward_asset::<T>(i),
+					RewardUpdate { reward_rate: RewardRate::per_second(2_000_u128) },
+				)
+				.expect("within bounds; qed;");
+		}
+		let origin = T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, STAKED_ASSET.into(), updates)
+
+	accumulate_rewards {
+		let r in 1 .. T::MaxRewardConfigsPerPool::get();
+		let who: T::AccountId = whitelisted_caller();
+		create_pool::<T>(r);
+		stake::<T>(&who, 0);
+		for i in 0..r {
+			assert_ok!(T::Assets::mint_into(reward_asset::<T>(i), &StakingRewards::<T>::pot_account(&STAKED_ASSET.into()), STAKE.into()));
+		}
+	}: {
+		StakingRewards::<T>::on_initialize(2_u32.into());
+	}
+}
+
+impl_benchmark_test_suite!(StakingRewards, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,1316 @@
+//! # Staking Rewards Pallet
+//!
+//! Time-locked staking of protocol assets into reward pools. It implements
+//! [`ManageStaking`](composable_traits::staking::ManageStaking),
+//! [`Staking`](composable_traits::staking::Staking),
+//! [`ProtocolStaking`](composable_traits::staking::ProtocolStaking) and
+//! [`Locking`](composable_traits::staking::lock::Locking).
+//!
+//! ## Overview
+//!
+//! A reward pool is created for a staked asset, with a set of lock durations each multiplying
+//! the shares received for a stake. Every position is a financial NFT of the collection of the
+//! pool, its staked amount and shares being held by the asset account of the NFT. Unstaking before
+//! the end of the lock sends `unlock_penalty` of the stake to the treasury.
+//!
+//! A pool can distribute many reward assets. Rewards are added to a pool either at once with
+//! `transfer_reward`, or at the reward rate of the asset from the rewards pot of the pool. Stakers
+//! receive rewards added while they stake, pro rata of their shares: each position records the
+//! rewards of the pool it is not entitled to as its reductions.
+//!
+//! ## Interface
+//!
+//! - `create_reward_pool`: create a reward pool for an asset.
+//! - `stake`: stake in a pool for one of its lock durations, minting a position.
+//! - `extend`: stake more in a position.
+//! - `unstake`: claim the rewards of a position and withdraw its stake, burning it.
+//! - `split`: split a position in two.
+//! - `merge`: merge a position into another one of the same pool.
+//! - `claim`: claim the rewards of a position.
+//! - `extend_duration`: restart the lock of a position, possibly with a longer duration.
+//! - `add_to_rewards_pot`: fund the reward rates of a pool.
+//! - `update_rewards_pool`: update the reward rates of a pool.
+#![cfg_attr(
+	not(test),
+	warn(
+		clippy::disallowed_methods,
+		clippy::disallowed_types,
+		clippy::todo,
+		clippy::unwrap_used,
+		clippy::panic
+	)
+)]
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(
+	bad_style,
+	bare_trait_objects,
+	improper_ctypes,
+	non_shorthand_field_patterns,
+	no_mangle_generic_items,
+	overflowing_literals,
+	path_statements,
+	patterns_in_fns_without_body,
+	private_in_public,
+	unconditional_recursion,
+	unused_allocation,
+	unused_comparisons,
+	unused_parens,
+	while_true,
+	trivial_casts,
+	trivial_numeric_casts,
+	unused_extern_crates
+)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarking;
+
+pub use crate::weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::WeightInfo;
+	use codec::FullCodec;
+	use composable_support::{
+		abstractions::{
+			nonce::Nonce,
+			utils::{
+				increment::{Increment, SafeIncrement},
+				start_at::OneInit,
+			},
+		},
+		math::safe::{safe_multiply_by_rational, SafeAdd, SafeSub},
+		validation::{validators::GeOne, Validated},
+	};
+	use composable_traits::{
+		assets::{AssetInfo, CreateAsset},
+		currency::{BalanceLike, MathBalance},
+		fnft::FinancialNft,
+		staking::{
+			lock::{Lock, Locking},
+			math::honest_locked_stake_increase,
+			ManageStaking, ProtocolStaking, Reward, RewardConfig, RewardPool,
+			RewardPoolConfiguration, RewardRate, RewardUpdate, Stake, Staking,
+		},
+		time::DurationSeconds,
+	};
+	use core::fmt::Debug;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			fungibles::{Inspect, Mutate},
+			tokens::{
+				nonfungibles::{Create, Inspect as NftInspect, Mutate as NftMutate},
+				Fortitude, Precision, Preservation,
+			},
+			UnixTime,
+		},
+		transactional, BoundedBTreeMap, PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::{
+		traits::{AccountIdConversion, Saturating, Zero},
+		ArithmeticError, FixedPointNumber, FixedU64, PerThing, Permill, SaturatedConversion,
+	};
+	use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+
+	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+	pub type AssetIdOf<T> = <T as Config>::AssetId;
+	pub type BalanceOf<T> = <T as Config>::Balance;
+	pub type InstanceIdOf<T> = <T as Config>::FinancialNftInstanceId;
+	pub type RewardPoolOf<T> = RewardPool<
+		AccountIdOf<T>,
+		AssetIdOf<T>,
+		BalanceOf<T>,
+		BlockNumberFor<T>,
+		<T as Config>::MaxStakingDurationPresets,
+		<T as Config>::MaxRewardConfigsPerPool,
+	>;
+	pub type RewardPoolConfigurationOf<T> = RewardPoolConfiguration<
+		AccountIdOf<T>,
+		AssetIdOf<T>,
+		BalanceOf<T>,
+		BlockNumberFor<T>,
+		<T as Config>::MaxRewardConfigsPerPool,
+		<T as Config>::MaxStakingDurationPresets,
+	>;
+	pub type StakeOf<T> =
+		Stake<AssetIdOf<T>, AssetIdOf<T>, BalanceOf<T>, <T as Config>::MaxRewardConfigsPerPool>;
+	pub type ReductionsOf<T> =
+		BoundedBTreeMap<AssetIdOf<T>, BalanceOf<T>, <T as Config>::MaxRewardConfigsPerPool>;
+	pub type RewardUpdatesOf<T> = BoundedBTreeMap<
+		AssetIdOf<T>,
+		RewardUpdate<BalanceOf<T>>,
+		<T as Config>::MaxRewardConfigsPerPool,
+	>;
+	/// A position, identified by the collection and instance ids of its fNFT.
+	pub type PositionIdOf<T> = (AssetIdOf<T>, InstanceIdOf<T>);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		#[allow(missing_docs)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Type representing the unique ID of an asset. Reward pools are identified by their
+		/// staked asset.
+		type AssetId: FullCodec
+			+ MaxEncodedLen
+			+ Default
+			+ Debug
+			+ TypeInfo
+			+ Eq
+			+ PartialEq
+			+ Ord
+			+ Copy;
+
+		/// Type representing the Balance of an account.
+		type Balance: BalanceLike + MathBalance;
+
+		/// Staked, share and reward assets.
+		type Assets: Inspect<Self::AccountId, Balance = Self::Balance, AssetId = Self::AssetId>
+			+ Mutate<Self::AccountId, Balance = Self::Balance, AssetId = Self::AssetId>;
+
+		/// Creates the share asset and the fNFT collection of reward pools.
+		type AssetFactory: CreateAsset<LocalAssetId = Self::AssetId, Balance = Self::Balance>;
+
+		/// Type representing the unique ID of a position within its collection.
+		type FinancialNftInstanceId: FullCodec
+			+ MaxEncodedLen
+			+ Debug
+			+ TypeInfo
+			+ Eq
+			+ PartialEq
+			+ Ord
+			+ Copy;
+
+		/// The financial NFTs representing positions.
+		type FinancialNft: FinancialNft<
+				Self::AccountId,
+				CollectionId = Self::AssetId,
+				ItemId = Self::FinancialNftInstanceId,
+			> + Create<Self::AccountId>
+			+ NftMutate<Self::AccountId>;
+
+		/// The time provider.
+		type UnixTime: UnixTime;
+
+		/// Origin able to create and update reward pools.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The native asset, used for `PositionDeposit`.
+		#[pallet::constant]
+		type NativeAssetId: Get<Self::AssetId>;
+
+		/// Amount of the native asset paid by the owner of a new position to its fNFT asset
+		/// account, funding the deposits of its proxies. It is returned when the position is burnt.
+		#[pallet::constant]
+		type PositionDeposit: Get<Self::Balance>;
+
+		/// Account receiving early unlock penalties.
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Maximum number of lock durations of a reward pool.
+		#[pallet::constant]
+		type MaxStakingDurationPresets: Get<u32>;
+
+		/// Maximum number of reward assets of a reward pool.
+		#[pallet::constant]
+		type MaxRewardConfigsPerPool: Get<u32>;
+
+		/// Maximum number of reward pools whose rewards are accumulated per block.
+		#[pallet::constant]
+		type MaxPoolsPerBlock: Get<u32>;
+
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		RewardPoolCreated {
+			pool_id: T::AssetId,
+			owner: T::AccountId,
+		},
+		Staked {
+			pool_id: T::AssetId,
+			owner: T::AccountId,
+			amount: T::Balance,
+			duration_preset: DurationSeconds,
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+		},
+		StakeAmountExtended {
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+			amount: T::Balance,
+		},
+		/// The stake of a position was withdrawn, `slash` of it being sent to the treasury.
+		Unstaked {
+			owner: T::AccountId,
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+			slash: Option<T::Balance>,
+		},
+		SplitPosition {
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+			new_fnft_instance_id: T::FinancialNftInstanceId,
+		},
+		PositionsMerged {
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+			merged_fnft_instance_id: T::FinancialNftInstanceId,
+		},
+		Claimed {
+			owner: T::AccountId,
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+		},
+		LockExtended {
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+			duration: DurationSeconds,
+		},
+		RewardTransferred {
+			from: T::AccountId,
+			pool_id: T::AssetId,
+			reward_currency: T::AssetId,
+			reward_increment: T::Balance,
+		},
+		RewardsPotIncreased {
+			pool_id: T::AssetId,
+			asset_id: T::AssetId,
+			amount: T::Balance,
+		},
+		RewardPoolUpdated {
+			pool_id: T::AssetId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		RewardPoolAlreadyExists,
+		RewardPoolNotFound,
+		UnimplementedRewardPoolConfiguration,
+		NoDurationPresetsProvided,
+		DurationPresetNotFound,
+		/// The lock of a position can only be extended.
+		LockDurationCannotDecrease,
+		StakedAmountTooLow,
+		StakeNotFound,
+		OnlyStakeOwnerCanInteractWithStake,
+		/// The ratio of a split must be strictly between 0 and 1.
+		InvalidSplitRatio,
+		CannotMergeSamePosition,
+		RewardAssetNotFound,
+		TooManyRewardAssetTypes,
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn pools)]
+	pub type RewardPools<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, RewardPoolOf<T>>;
+
+	/// Positions, by fNFT.
+	#[pallet::storage]
+	#[pallet::getter(fn stakes)]
+	pub type Stakes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::FinancialNftInstanceId,
+		StakeOf<T>,
+	>;
+
+	#[pallet::storage]
+	#[allow(clippy::disallowed_types)] // Allow for `ValueQuery` because of nonce
+	pub type AssetNonce<T: Config> =
+		StorageValue<_, u64, ValueQuery, Nonce<OneInit, SafeIncrement>>;
+
+	/// Last reward pool whose rewards were accumulated, the next block continuing after it.
+	#[pallet::storage]
+	pub type RewardsCursor<T: Config> = StorageValue<_, T::AssetId>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let (pools, rewards) = Self::accumulate_rewards(now);
+			T::WeightInfo::accumulate_rewards(rewards)
+				.saturating_add(T::DbWeight::get().reads_writes(pools.into(), 1))
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a reward pool for the staked asset of `pool_config`, which is the id of the
+		/// pool.
+		///
+		/// Emits `RewardPoolCreated` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::create_reward_pool(T::MaxRewardConfigsPerPool::get()))]
+		pub fn create_reward_pool(
+			origin: OriginFor<T>,
+			pool_config: RewardPoolConfigurationOf<T>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			<Self as ManageStaking>::create_staking_pool(pool_config)?;
+			Ok(())
+		}
+
+		/// Stake `amount` in a pool, locked for `duration_preset`, minting a position to the
+		/// caller.
+		///
+		/// Emits `Staked` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::stake(T::MaxRewardConfigsPerPool::get()))]
+		pub fn stake(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			amount: T::Balance,
+			duration_preset: DurationSeconds,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			<Self as Staking>::stake(&who, &pool_id, amount, duration_preset, true)?;
+			Ok(())
+		}
+
+		/// Stake `amount` more in a position. Its lock is shortened as much as the stake grows,
+		/// see [`honest_locked_stake_increase`].
+		///
+		/// Emits `StakeAmountExtended` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::extend(T::MaxRewardConfigsPerPool::get()))]
+		pub fn extend(
+			origin: OriginFor<T>,
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			<Self as Staking>::extend(&who, (fnft_collection_id, fnft_instance_id), amount, true)
+		}
+
+		/// Claim the rewards of a position and withdraw its stake, burning it. The unlock penalty
+		/// is sent to the treasury if the lock did not end.
+		///
+		/// Emits `Unstaked` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::unstake(T::MaxRewardConfigsPerPool::get()))]
+		pub fn unstake(
+			origin: OriginFor<T>,
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			<Self as Staking>::unstake(&who, &(fnft_collection_id, fnft_instance_id))
+		}
+
+		/// Split a position in two with the same lock, the original position retaining `ratio` of
+		/// it.
+		///
+		/// Emits `SplitPosition` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::split(T::MaxRewardConfigsPerPool::get()))]
+		pub fn split(
+			origin: OriginFor<T>,
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+			ratio: Permill,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			<Self as Staking>::split(&who, &(fnft_collection_id, fnft_instance_id), ratio)?;
+			Ok(())
+		}
+
+		/// Merge the position `other_fnft_instance_id` into `fnft_instance_id`, burning it. The
+		/// merged position keeps the lock ending last.
+		///
+		/// Emits `PositionsMerged` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::merge(T::MaxRewardConfigsPerPool::get()))]
+		pub fn merge(
+			origin: OriginFor<T>,
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+			other_fnft_instance_id: T::FinancialNftInstanceId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_merge(&who, fnft_collection_id, fnft_instance_id, other_fnft_instance_id)
+		}
+
+		/// Claim the rewards of a position.
+		///
+		/// Emits `Claimed` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::claim(T::MaxRewardConfigsPerPool::get()))]
+		pub fn claim(
+			origin: OriginFor<T>,
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			<Self as Staking>::claim(&who, &(fnft_collection_id, fnft_instance_id))
+		}
+
+		/// Restart the lock of a position, for `duration` if provided. Shares are updated to the
+		/// multiplier of the new duration.
+		///
+		/// Emits `LockExtended` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::extend_duration(T::MaxRewardConfigsPerPool::get()))]
+		pub fn extend_duration(
+			origin: OriginFor<T>,
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+			duration: Option<DurationSeconds>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			<Self as Locking>::extend_duration(
+				&who,
+				&(fnft_collection_id, fnft_instance_id),
+				duration,
+			)
+		}
+
+		/// Fund the rewards pot of a pool, from which rewards are added to the pool at the rate
+		/// of their asset.
+		///
+		/// Emits `RewardsPotIncreased` event when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::add_to_rewards_pot())]
+		pub fn add_to_rewards_pot(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			asset_id: T::AssetId,
+			amount: T::Balance,
+			keep_alive: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let pool = RewardPools::<T>::get(pool_id).ok_or(Error::<T>::RewardPoolNotFound)?;
+			ensure!(pool.rewards.contains_key(&asset_id), Error::<T>::RewardAssetNotFound);
+			T::Assets::transfer(
+				asset_id,
+				&who,
+				&Self::pot_account(&pool_id),
+				amount,
+				Self::preservation(keep_alive),
+			)?;
+			Self::deposit_event(Event::<T>::RewardsPotIncreased { pool_id, asset_id, amount });
+			Ok(())
+		}
+
+		/// Update the reward rates of a pool, adding the reward assets it does not distribute
+		/// yet.
+		///
+		/// Emits `RewardPoolUpdated` event when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::update_rewards_pool(reward_updates.len() as u32))]
+		pub fn update_rewards_pool(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			reward_updates: RewardUpdatesOf<T>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let now = Self::now();
+			RewardPools::<T>::try_mutate(pool_id, |pool| -> DispatchResult {
+				let pool = pool.as_mut().ok_or(Error::<T>::RewardPoolNotFound)?;
+				for (asset_id, update) in reward_updates {
+					match pool.rewards.get_mut(&asset_id) {
+						Some(reward) => reward.reward_rate = update.reward_rate,
+						None => {
+							let reward = Reward::from_config(
+								RewardConfig { reward_rate: update.reward_rate },
+								now,
+							);
+							pool.rewards
+								.try_insert(asset_id, reward)
+								.map_err(|_| Error::<T>::TooManyRewardAssetTypes)?;
+						},
+					}
+				}
+				Ok(())
+			})?;
+			Self::deposit_event(Event::<T>::RewardPoolUpdated { pool_id });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Account holding the rewards of a pool.
+		pub fn pool_account(pool_id: &T::AssetId) -> AccountIdOf<T> {
+			T::PalletId::get().into_sub_account_truncating(pool_id)
+		}
+
+		/// Account funding the reward rates of a pool.
+		pub fn pot_account(pool_id: &T::AssetId) -> AccountIdOf<T> {
+			T::PalletId::get().into_sub_account_truncating((pool_id, b"pot"))
+		}
+
+		/// Rewards of each asset claimable by a position.
+		pub fn claimable_amount(
+			(fnft_collection_id, fnft_instance_id): PositionIdOf<T>,
+		) -> Result<BTreeMap<T::AssetId, T::Balance>, DispatchError> {
+			let stake = Stakes::<T>::get(fnft_collection_id, fnft_instance_id)
+				.ok_or(Error::<T>::StakeNotFound)?;
+			let pool = RewardPools::<T>::get(stake.reward_pool_id)
+				.ok_or(Error::<T>::RewardPoolNotFound)?;
+			let total_shares = T::Assets::total_issuance(pool.share_asset_id);
+			pool.rewards
+				.iter()
+				.map(|(asset_id, reward)| {
+					let inflation = Self::inflation(reward, stake.share, total_shares)?;
+					let reduction = stake.reductions.get(asset_id).copied().unwrap_or_default();
+					Ok((*asset_id, inflation.saturating_sub(reduction)))
+				})
+				.collect()
+		}
+
+		pub(crate) fn now() -> u64 {
+			T::UnixTime::now().as_secs()
+		}
+
+		fn preservation(keep_alive: bool) -> Preservation {
+			if keep_alive {
+				Preservation::Preserve
+			} else {
+				Preservation::Expendable
+			}
+		}
+
+		fn create_asset() -> Result<T::AssetId, DispatchError> {
+			let protocol_id = (Self::index() as u32).to_be_bytes();
+			let nonce = AssetNonce::<T>::increment()?;
+			T::AssetFactory::create_local_asset(
+				protocol_id,
+				nonce,
+				AssetInfo {
+					name: None,
+					symbol: None,
+					decimals: Some(12),
+					existential_deposit: Zero::zero(),
+					ratio: None,
+				},
+			)
+		}
+
+		/// The position owned by `who`.
+		fn owned_stake(
+			who: &T::AccountId,
+			(fnft_collection_id, fnft_instance_id): &PositionIdOf<T>,
+		) -> Result<StakeOf<T>, DispatchError> {
+			let stake = Stakes::<T>::get(fnft_collection_id, fnft_instance_id)
+				.ok_or(Error::<T>::StakeNotFound)?;
+			ensure!(
+				T::FinancialNft::owner(fnft_collection_id, fnft_instance_id).as_ref() == Some(who),
+				Error::<T>::OnlyStakeOwnerCanInteractWithStake
+			);
+			Ok(stake)
+		}
+
+		fn shares(
+			amount: T::Balance,
+			multiplier: &Validated<FixedU64, GeOne>,
+		) -> Result<T::Balance, DispatchError> {
+			let share = multiplier
+				.checked_mul_int::<u128>(amount.into())
+				.ok_or(ArithmeticError::Overflow)?;
+			Ok(share.saturated_into())
+		}
+
+		/// Rewards of `reward` the holder of `share` shares is entitled to, reductions included.
+		fn inflation(
+			reward: &Reward<T::Balance>,
+			share: T::Balance,
+			total_shares: T::Balance,
+		) -> Result<T::Balance, DispatchError> {
+			if total_shares.is_zero() {
+				return Ok(Zero::zero())
+			}
+			let inflation = safe_multiply_by_rational(
+				reward.total_rewards.into(),
+				share.into(),
+				total_shares.into(),
+			)?;
+			Ok(inflation.saturated_into())
+		}
+
+		/// Increase the rewards of `pool` as if `share` new shares were entitled to the rewards
+		/// accumulated so far, returning the reductions of these shares.
+		fn dilute(
+			pool: &mut RewardPoolOf<T>,
+			share: T::Balance,
+		) -> Result<ReductionsOf<T>, DispatchError> {
+			let total_shares = T::Assets::total_issuance(pool.share_asset_id);
+			let mut reductions = ReductionsOf::<T>::new();
+			for (asset_id, reward) in pool.rewards.iter_mut() {
+				let reduction = Self::inflation(reward, share, total_shares)?;
+				reward.total_rewards = reward.total_rewards.safe_add(&reduction)?;
+				reward.total_dilution_adjustment =
+					reward.total_dilution_adjustment.safe_add(&reduction)?;
+				reductions
+					.try_insert(*asset_id, reduction)
+					.map_err(|_| Error::<T>::TooManyRewardAssetTypes)?;
+			}
+			Ok(reductions)
+		}
+
+		/// Remove the rewards of `stake`, claimed beforehand, from `pool` as it leaves the pool.
+		fn undilute(pool: &mut RewardPoolOf<T>, stake: &StakeOf<T>) -> DispatchResult {
+			let total_shares = T::Assets::total_issuance(pool.share_asset_id);
+			for (asset_id, reward) in pool.rewards.iter_mut() {
+				let inflation = Self::inflation(reward, stake.share, total_shares)?;
+				reward.total_rewards = reward.total_rewards.saturating_sub(inflation);
+				let reduction = stake.reductions.get(asset_id).copied().unwrap_or_default();
+				let adjustment = reduction.min(reward.total_dilution_adjustment);
+				reward.total_dilution_adjustment =
+					reward.total_dilution_adjustment.safe_sub(&adjustment)?;
+				reward.claimed_rewards =
+					reward.claimed_rewards.saturating_sub(reduction.safe_sub(&adjustment)?);
+			}
+			Ok(())
+		}
+
+		fn add_reductions(
+			reductions: &mut ReductionsOf<T>,
+			other: ReductionsOf<T>,
+		) -> DispatchResult {
+			for (asset_id, amount) in other {
+				let reduction = reductions.get(&asset_id).copied().unwrap_or_default();
+				reductions
+					.try_insert(asset_id, reduction.safe_add(&amount)?)
+					.map_err(|_| Error::<T>::TooManyRewardAssetTypes)?;
+			}
+			Ok(())
+		}
+
+		/// Pay the rewards of `stake` accumulated in `pool` to `who`.
+		fn do_claim(
+			who: &T::AccountId,
+			pool: &mut RewardPoolOf<T>,
+			stake: &mut StakeOf<T>,
+		) -> DispatchResult {
+			let total_shares = T::Assets::total_issuance(pool.share_asset_id);
+			let pool_account = Self::pool_account(&stake.reward_pool_id);
+			for (asset_id, reward) in pool.rewards.iter_mut() {
+				let inflation = Self::inflation(reward, stake.share, total_shares)?;
+				let reduction = stake.reductions.get(asset_id).copied().unwrap_or_default();
+				let claim = inflation.saturating_sub(reduction).min(T::Assets::reducible_balance(
+					*asset_id,
+					&pool_account,
+					Preservation::Expendable,
+					Fortitude::Polite,
+				));
+				if claim.is_zero() {
+					continue
+				}
+				T::Assets::transfer(
+					*asset_id,
+					&pool_account,
+					who,
+					claim,
+					Preservation::Expendable,
+				)?;
+				reward.claimed_rewards = reward.claimed_rewards.safe_add(&claim)?;
+				stake
+					.reductions
+					.try_insert(*asset_id, reduction.safe_add(&claim)?)
+					.map_err(|_| Error::<T>::TooManyRewardAssetTypes)?;
+			}
+			Ok(())
+		}
+
+		/// Pay `PositionDeposit` from `who` to the fNFT asset account of a new position.
+		fn fund_position(who: &T::AccountId, account: &T::AccountId) -> DispatchResult {
+			let deposit = T::PositionDeposit::get();
+			if !deposit.is_zero() {
+				T::Assets::transfer(
+					T::NativeAssetId::get(),
+					who,
+					account,
+					deposit,
+					Preservation::Preserve,
+				)?;
+			}
+			Ok(())
+		}
+
+		/// Return `PositionDeposit` from the fNFT asset account of a burnt position to `who`.
+		fn refund_position(account: &T::AccountId, who: &T::AccountId) -> DispatchResult {
+			let deposit = T::PositionDeposit::get();
+			if !deposit.is_zero() {
+				T::Assets::transfer(
+					T::NativeAssetId::get(),
+					account,
+					who,
+					deposit,
+					Preservation::Expendable,
+				)?;
+			}
+			Ok(())
+		}
+
+		#[transactional]
+		fn do_merge(
+			who: &T::AccountId,
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+			other_fnft_instance_id: T::FinancialNftInstanceId,
+		) -> DispatchResult {
+			ensure!(
+				fnft_instance_id != other_fnft_instance_id,
+				Error::<T>::CannotMergeSamePosition
+			);
+			let mut stake = Self::owned_stake(who, &(fnft_collection_id, fnft_instance_id))?;
+			let other = Self::owned_stake(who, &(fnft_collection_id, other_fnft_instance_id))?;
+			let pool = RewardPools::<T>::get(stake.reward_pool_id)
+				.ok_or(Error::<T>::RewardPoolNotFound)?;
+			let account = T::FinancialNft::asset_account(&fnft_collection_id, &fnft_instance_id);
+			let other_account =
+				T::FinancialNft::asset_account(&fnft_collection_id, &other_fnft_instance_id);
+			T::Assets::transfer(
+				stake.reward_pool_id,
+				&other_account,
+				&account,
+				other.stake,
+				Preservation::Expendable,
+			)?;
+			T::Assets::transfer(
+				pool.share_asset_id,
+				&other_account,
+				&account,
+				other.share,
+				Preservation::Expendable,
+			)?;
+			stake.stake = stake.stake.safe_add(&other.stake)?;
+			stake.share = stake.share.safe_add(&other.share)?;
+			Self::add_reductions(&mut stake.reductions, other.reductions)?;
+			if other.lock.started_at.saturating_add(other.lock.duration) >
+				stake.lock.started_at.saturating_add(stake.lock.duration)
+			{
+				stake.lock = other.lock;
+			}
+			T::FinancialNft::burn(&fnft_collection_id, &other_fnft_instance_id, Some(who))?;
+			Self::refund_position(&other_account, who)?;
+			Stakes::<T>::remove(fnft_collection_id, other_fnft_instance_id);
+			Stakes::<T>::insert(fnft_collection_id, fnft_instance_id, stake);
+			Self::deposit_event(Event::<T>::PositionsMerged {
+				fnft_collection_id,
+				fnft_instance_id,
+				merged_fnft_instance_id: other_fnft_instance_id,
+			});
+			Ok(())
+		}
+
+		/// Add the rewards due since the last update of started pools with stakers, from their
+		/// pot at the rate of each reward asset. At most `MaxPoolsPerBlock` pools are updated,
+		/// continuing after the last pool updated by the previous block. Returns the number of
+		/// pools and rewards updated.
+		pub(crate) fn accumulate_rewards(now: BlockNumberFor<T>) -> (u32, u32) {
+			let now_seconds = Self::now();
+			let max_pools = T::MaxPoolsPerBlock::get() as usize;
+			let mut pool_ids: Vec<_> = match RewardsCursor::<T>::get() {
+				Some(cursor) =>
+					RewardPools::<T>::iter_keys_from(RewardPools::<T>::hashed_key_for(cursor))
+						.take(max_pools)
+						.collect(),
+				None => RewardPools::<T>::iter_keys().take(max_pools).collect(),
+			};
+			if pool_ids.len() < max_pools && RewardsCursor::<T>::get().is_some() {
+				// wrap around up to the first pool of this block
+				let first = pool_ids.first().copied();
+				pool_ids.extend(
+					RewardPools::<T>::iter_keys()
+						.take_while(|pool_id| Some(*pool_id) != first)
+						.take(max_pools - pool_ids.len())
+						.collect::<Vec<_>>(),
+				);
+			}
+			RewardsCursor::<T>::set(pool_ids.last().copied());
+			let mut rewards = 0;
+			for pool_id in &pool_ids {
+				RewardPools::<T>::mutate(pool_id, |pool| {
+					if let Some(pool) = pool {
+						let started = pool.start_block <= now &&
+							!T::Assets::total_issuance(pool.share_asset_id).is_zero();
+						for (asset_id, reward) in pool.rewards.iter_mut() {
+							rewards += 1;
+							if !started {
+								reward.last_updated_timestamp = now_seconds;
+							} else if let Err(error) =
+								Self::accumulate_reward(pool_id, *asset_id, reward, now_seconds)
+							{
+								// not updated, the reward is retried with the next update
+								log::warn!(
+									target: "staking-rewards",
+									"reward {asset_id:?} of pool {pool_id:?} not accumulated: {error:?}"
+								);
+							}
+						}
+					}
+				});
+			}
+			(pool_ids.len() as u32, rewards)
+		}
+
+		/// Transfer the reward due for the whole periods elapsed since its last update, up to
+		/// the balance of the pot. The update is only recorded on success.
+		#[transactional]
+		fn accumulate_reward(
+			pool_id: &T::AssetId,
+			asset_id: T::AssetId,
+			reward: &mut Reward<T::Balance>,
+			now: u64,
+		) -> DispatchResult {
+			let period = reward.reward_rate.period.as_secs().get();
+			let periods = now.saturating_sub(reward.last_updated_timestamp) / period;
+			let rate: u128 = reward.reward_rate.amount.into();
+			let pot_account = Self::pot_account(pool_id);
+			let amount = rate.saturating_mul(periods.into()).saturated_into::<T::Balance>().min(
+				T::Assets::reducible_balance(
+					asset_id,
+					&pot_account,
+					Preservation::Expendable,
+					Fortitude::Polite,
+				),
+			);
+			if !amount.is_zero() {
+				T::Assets::transfer(
+					asset_id,
+					&pot_account,
+					&Self::pool_account(pool_id),
+					amount,
+					Preservation::Expendable,
+				)?;
+				reward.total_rewards = reward.total_rewards.safe_add(&amount)?;
+			}
+			// the remainder of a period accumulates with the next update
+			reward.last_updated_timestamp =
+				reward.last_updated_timestamp.saturating_add(periods.saturating_mul(period));
+			Ok(())
+		}
+	}
+
+	impl<T: Config> ManageStaking for Pallet<T> {
+		type AccountId = T::AccountId;
+		type AssetId = T::AssetId;
+		type BlockNumber = BlockNumberFor<T>;
+		type Balance = T::Balance;
+		type RewardPoolId = T::AssetId;
+		type RewardConfigsLimit = T::MaxRewardConfigsPerPool;
+		type StakingDurationPresetsLimit = T::MaxStakingDurationPresets;
+
+		#[transactional]
+		fn create_staking_pool(
+			pool_config: RewardPoolConfigurationOf<T>,
+		) -> Result<Self::RewardPoolId, DispatchError> {
+			match pool_config {
+				RewardPoolConfiguration::RewardRateBasedIncentive {
+					owner,
+					asset_id,
+					start_block,
+					reward_configs,
+					lock,
+					minimum_staking_amount,
+				} => {
+					ensure!(
+						!RewardPools::<T>::contains_key(asset_id),
+						Error::<T>::RewardPoolAlreadyExists
+					);
+					ensure!(
+						lock.duration_multipliers.has_at_least_one_valid_duration(),
+						Error::<T>::NoDurationPresetsProvided
+					);
+					let now = Self::now();
+					let rewards = reward_configs
+						.into_iter()
+						.map(|(reward_asset_id, config)| {
+							(reward_asset_id, Reward::from_config(config, now))
+						})
+						.collect::<BTreeMap<_, _>>()
+						.try_into()
+						.map_err(|_| Error::<T>::TooManyRewardAssetTypes)?;
+					let share_asset_id = Self::create_asset()?;
+					let financial_nft_asset_id = Self::create_asset()?;
+					let pallet_account: T::AccountId = T::PalletId::get().into_account_truncating();
+					T::FinancialNft::create_collection(
+						&financial_nft_asset_id,
+						&pallet_account,
+						&pallet_account,
+					)?;
+					RewardPools::<T>::insert(
+						asset_id,
+						RewardPool {
+							owner: owner.clone(),
+							rewards,
+							start_block,
+							lock,
+							share_asset_id,
+							financial_nft_asset_id,
+							minimum_staking_amount,
+						},
+					);
+					Self::deposit_event(Event::<T>::RewardPoolCreated { pool_id: asset_id, owner });
+					Ok(asset_id)
+				},
+				_ => Err(Error::<T>::UnimplementedRewardPoolConfiguration.into()),
+			}
+		}
+	}
+
+	impl<T: Config> Staking for Pallet<T> {
+		type AccountId = T::AccountId;
+		type RewardPoolId = T::AssetId;
+		type Balance = T::Balance;
+		type PositionId = PositionIdOf<T>;
+
+		#[transactional]
+		fn stake(
+			who: &T::AccountId,
+			pool_id: &T::AssetId,
+			amount: T::Balance,
+			duration_preset: DurationSeconds,
+			keep_alive: bool,
+		) -> Result<Self::PositionId, DispatchError> {
+			let mut pool = RewardPools::<T>::get(pool_id).ok_or(Error::<T>::RewardPoolNotFound)?;
+			ensure!(amount >= pool.minimum_staking_amount, Error::<T>::StakedAmountTooLow);
+			let multiplier = pool
+				.lock
+				.duration_multipliers
+				.multiplier(duration_preset)
+				.ok_or(Error::<T>::DurationPresetNotFound)?;
+			let share = Self::shares(amount, multiplier)?;
+			let reductions = Self::dilute(&mut pool, share)?;
+
+			let fnft_collection_id = pool.financial_nft_asset_id;
+			let fnft_instance_id = T::FinancialNft::get_next_nft_id(&fnft_collection_id)?;
+			let account = T::FinancialNft::asset_account(&fnft_collection_id, &fnft_instance_id);
+			T::Assets::transfer(*pool_id, who, &account, amount, Self::preservation(keep_alive))?;
+			Self::fund_position(who, &account)?;
+			T::FinancialNft::mint_into(&fnft_collection_id, &fnft_instance_id, who)?;
+			T::Assets::mint_into(pool.share_asset_id, &account, share)?;
+
+			Stakes::<T>::insert(
+				fnft_collection_id,
+				fnft_instance_id,
+				Stake {
+					reward_pool_id: *pool_id,
+					stake: amount,
+					share,
+					reductions,
+					lock: Lock {
+						started_at: Self::now(),
+						duration: duration_preset,
+						unlock_penalty: pool.lock.unlock_penalty,
+					},
+				},
+			);
+			RewardPools::<T>::insert(pool_id, pool);
+			Self::deposit_event(Event::<T>::Staked {
+				pool_id: *pool_id,
+				owner: who.clone(),
+				amount,
+				duration_preset,
+				fnft_collection_id,
+				fnft_instance_id,
+			});
+			Ok((fnft_collection_id, fnft_instance_id))
+		}
+
+		#[transactional]
+		fn extend(
+			who: &T::AccountId,
+			(fnft_collection_id, fnft_instance_id): Self::PositionId,
+			amount: T::Balance,
+			keep_alive: bool,
+		) -> DispatchResult {
+			let mut stake = Self::owned_stake(who, &(fnft_collection_id, fnft_instance_id))?;
+			let mut pool = RewardPools::<T>::get(stake.reward_pool_id)
+				.ok_or(Error::<T>::RewardPoolNotFound)?;
+			let multiplier = pool
+				.lock
+				.duration_multipliers
+				.multiplier(stake.lock.duration)
+				.ok_or(Error::<T>::DurationPresetNotFound)?;
+			let share = Self::shares(amount, multiplier)?;
+			let reductions = Self::dilute(&mut pool, share)?;
+			Self::add_reductions(&mut stake.reductions, reductions)?;
+
+			let now = Self::now();
+			let duration = stake.lock.duration;
+			let remaining = honest_locked_stake_increase(
+				stake.lock.unlock_penalty.left_from_one(),
+				stake.stake.into(),
+				amount.into(),
+				duration,
+				now.saturating_sub(stake.lock.started_at).min(duration),
+			)?;
+			stake.lock.started_at = now.saturating_sub(duration.saturating_sub(remaining));
+
+			let account = T::FinancialNft::asset_account(&fnft_collection_id, &fnft_instance_id);
+			T::Assets::transfer(
+				stake.reward_pool_id,
+				who,
+				&account,
+				amount,
+				Self::preservation(keep_alive),
+			)?;
+			T::Assets::mint_into(pool.share_asset_id, &account, share)?;
+			stake.stake = stake.stake.safe_add(&amount)?;
+			stake.share = stake.share.safe_add(&share)?;
+
+			RewardPools::<T>::insert(stake.reward_pool_id, pool);
+			Stakes::<T>::insert(fnft_collection_id, fnft_instance_id, stake);
+			Self::deposit_event(Event::<T>::StakeAmountExtended {
+				fnft_collection_id,
+				fnft_instance_id,
+				amount,
+			});
+			Ok(())
+		}
+
+		#[transactional]
+		fn unstake(who: &T::AccountId, position: &Self::PositionId) -> DispatchResult {
+			let (fnft_collection_id, fnft_instance_id) = position;
+			let mut stake = Self::owned_stake(who, position)?;
+			let mut pool = RewardPools::<T>::get(stake.reward_pool_id)
+				.ok_or(Error::<T>::RewardPoolNotFound)?;
+			Self::do_claim(who, &mut pool, &mut stake)?;
+			Self::undilute(&mut pool, &stake)?;
+
+			let account = T::FinancialNft::asset_account(fnft_collection_id, fnft_instance_id);
+			T::Assets::burn_from(
+				pool.share_asset_id,
+				&account,
+				stake.share,
+				Precision::Exact,
+				Fortitude::Force,
+			)?;
+			let penalty = if Self::now() < stake.lock.started_at.saturating_add(stake.lock.duration)
+			{
+				stake.lock.unlock_penalty.mul_floor(stake.stake)
+			} else {
+				Zero::zero()
+			};
+			if !penalty.is_zero() {
+				T::Assets::transfer(
+					stake.reward_pool_id,
+					&account,
+					&T::TreasuryAccount::get(),
+					penalty,
+					Preservation::Expendable,
+				)?;
+			}
+			T::Assets::transfer(
+				stake.reward_pool_id,
+				&account,
+				who,
+				stake.stake.safe_sub(&penalty)?,
+				Preservation::Expendable,
+			)?;
+			T::FinancialNft::burn(fnft_collection_id, fnft_instance_id, Some(who))?;
+			Self::refund_position(&account, who)?;
+
+			RewardPools::<T>::insert(stake.reward_pool_id, pool);
+			Stakes::<T>::remove(fnft_collection_id, fnft_instance_id);
+			Self::deposit_event(Event::<T>::Unstaked {
+				owner: who.clone(),
+				fnft_collection_id: *fnft_collection_id,
+				fnft_instance_id: *fnft_instance_id,
+				slash: (!penalty.is_zero()).then_some(penalty),
+			});
+			Ok(())
+		}
+
+		#[transactional]
+		fn split(
+			who: &T::AccountId,
+			position: &Self::PositionId,
+			ratio: Permill,
+		) -> Result<Self::PositionId, DispatchError> {
+			ensure!(!ratio.is_zero() && ratio != Permill::one(), Error::<T>::InvalidSplitRatio);
+			let (fnft_collection_id, fnft_instance_id) = position;
+			let mut stake = Self::owned_stake(who, position)?;
+			let pool = RewardPools::<T>::get(stake.reward_pool_id)
+				.ok_or(Error::<T>::RewardPoolNotFound)?;
+
+			let mut new_stake = stake.clone();
+			stake.stake = ratio.mul_floor(stake.stake);
+			new_stake.stake = new_stake.stake.safe_sub(&stake.stake)?;
+			ensure!(
+				stake.stake >= pool.minimum_staking_amount &&
+					new_stake.stake >= pool.minimum_staking_amount,
+				Error::<T>::StakedAmountTooLow
+			);
+			stake.share = ratio.mul_floor(stake.share);
+			new_stake.share = new_stake.share.safe_sub(&stake.share)?;
+			for (asset_id, reduction) in stake.reductions.iter_mut() {
+				*reduction = ratio.mul_floor(*reduction);
+				if let Some(new_reduction) = new_stake.reductions.get_mut(asset_id) {
+					*new_reduction = new_reduction.safe_sub(reduction)?;
+				}
+			}
+
+			let new_fnft_instance_id = T::FinancialNft::get_next_nft_id(fnft_collection_id)?;
+			let account = T::FinancialNft::asset_account(fnft_collection_id, fnft_instance_id);
+			let new_account =
+				T::FinancialNft::asset_account(fnft_collection_id, &new_fnft_instance_id);
+			T::Assets::transfer(
+				stake.reward_pool_id,
+				&account,
+				&new_account,
+				new_stake.stake,
+				Preservation::Expendable,
+			)?;
+			T::Assets::transfer(
+				pool.share_asset_id,
+				&account,
+				&new_account,
+				new_stake.share,
+				Preservation::Expendable,
+			)?;
+			Self::fund_position(who, &new_account)?;
+			T::FinancialNft::mint_into(fnft_collection_id, &new_fnft_instance_id, who)?;
+
+			Stakes::<T>::insert(fnft_collection_id, fnft_instance_id, stake);
+			Stakes::<T>::insert(fnft_collection_id, new_fnft_instance_id, new_stake);
+			Self::deposit_event(Event::<T>::SplitPosition {
+				fnft_collection_id: *fnft_collection_id,
+				fnft_instance_id: *fnft_instance_id,
+				new_fnft_instance_id,
+			});
+			Ok((*fnft_collection_id, new_fnft_instance_id))
+		}
+
+		#[transactional]
+		fn claim(who: &T::AccountId, position: &Self::PositionId) -> DispatchResult {
+			let (fnft_collection_id, fnft_instance_id) = position;
+			let mut stake = Self::owned_stake(who, position)?;
+			let mut pool = RewardPools::<T>::get(stake.reward_pool_id)
+				.ok_or(Error::<T>::RewardPoolNotFound)?;
+			Self::do_claim(who, &mut pool, &mut stake)?;
+			RewardPools::<T>::insert(stake.reward_pool_id, pool);
+			Stakes::<T>::insert(fnft_collection_id, fnft_instance_id, stake);
+			Self::deposit_event(Event::<T>::Claimed {
+				owner: who.clone(),
+				fnft_collection_id: *fnft_collection_id,
+				fnft_instance_id: *fnft_instance_id,
+			});
+			Ok(())
+		}
+	}
+
+	impl<T: Config> ProtocolStaking for Pallet<T> {
+		type AccountId = T::AccountId;
+		type AssetId = T::AssetId;
+		type Balance = T::Balance;
+		type RewardPoolId = T::AssetId;
+
+		#[transactional]
+		fn transfer_reward(
+			from: &T::AccountId,
+			pool_id: &T::AssetId,
+			reward_currency: T::AssetId,
+			amount: T::Balance,
+			keep_alive: bool,
+		) -> DispatchResult {
+			RewardPools::<T>::try_mutate(pool_id, |pool| -> DispatchResult {
+				let pool = pool.as_mut().ok_or(Error::<T>::RewardPoolNotFound)?;
+				match pool.rewards.get_mut(&reward_currency) {
+					Some(reward) =>
+						reward.total_rewards = reward.total_rewards.safe_add(&amount)?,
+					None => {
+						let mut reward = Reward::from_config(
+							RewardConfig {
+								reward_rate: RewardRate::per_second(T::Balance::zero()),
+							},
+							Self::now(),
+						);
+						reward.total_rewards = amount;
+						pool.rewards
+							.try_insert(reward_currency, reward)
+							.map_err(|_| Error::<T>::TooManyRewardAssetTypes)?;
+					},
+				}
+				T::Assets::transfer(
+					reward_currency,
+					from,
+					&Self::pool_account(pool_id),
+					amount,
+					Self::preservation(keep_alive),
+				)?;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::<T>::RewardTransferred {
+				from: from.clone(),
+				pool_id: *pool_id,
+				reward_currency,
+				reward_increment: amount,
+			});
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Locking for Pallet<T> {
+		type AccountId = T::AccountId;
+		type InstanceId = PositionIdOf<T>;
+
+		#[transactional]
+		fn extend_duration(
+			who: &T::AccountId,
+			position: &Self::InstanceId,
+			duration: Option<DurationSeconds>,
+		) -> DispatchResult {
+			let (fnft_collection_id, fnft_instance_id) = position;
+			let mut stake = Self::owned_stake(who, position)?;
+			let mut pool = RewardPools::<T>::get(stake.reward_pool_id)
+				.ok_or(Error::<T>::RewardPoolNotFound)?;
+			let duration = duration.unwrap_or(stake.lock.duration);
+			ensure!(duration >= stake.lock.duration, Error::<T>::LockDurationCannotDecrease);
+			let multiplier = pool
+				.lock
+				.duration_multipliers
+				.multiplier(duration)
+				.ok_or(Error::<T>::DurationPresetNotFound)?;
+			let share = Self::shares(stake.stake, multiplier)?;
+			if share > stake.share {
+				let extra = share.safe_sub(&stake.share)?;
+				let reductions = Self::dilute(&mut pool, extra)?;
+				Self::add_reductions(&mut stake.reductions, reductions)?;
+				T::Assets::mint_into(
+					pool.share_asset_id,
+					&T::FinancialNft::asset_account(fnft_collection_id, fnft_instance_id),
+					extra,
+				)?;
+				stake.share = share;
+			}
+			stake.lock = Lock {
+				started_at: Self::now(),
+				duration,
+				unlock_penalty: pool.lock.unlock_penalty,
+			};
+
+			RewardPools::<T>::insert(stake.reward_pool_id, pool);
+			Stakes::<T>::insert(fnft_collection_id, fnft_instance_id, stake);
+			Self::deposit_event(Event::<T>::LockExtended {
+				fnft_collection_id: *fnft_collection_id,
+				fnft_instance_id: *fnft_instance_id,
+				duration,
+			});
+			Ok(())
+		}
+	}
+}
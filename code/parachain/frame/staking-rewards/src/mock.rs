@@ -0,0 +1,336 @@
+#![cfg(test)]
+
+use crate::{self as pallet_staking_rewards};
+use codec::{Decode, Encode, MaxEncodedLen};
+use composable_traits::fnft::FnftAccountProxyTypeSelector;
+use frame_support::{
+	parameter_types,
+	traits::{ConstU32, Everything, InstanceFilter},
+	PalletId, RuntimeDebug,
+};
+use frame_system::EnsureRoot;
+use orml_traits::parameter_type_with_key;
+use primitives::currency::ForeignAssetId;
+use scale_info::TypeInfo;
+use sp_arithmetic::traits::Zero;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, ConvertInto, IdentityLookup},
+};
+
+pub type BlockNumber = u64;
+pub type InstanceId = u64;
+
+pub const NATIVE: AssetId = 1;
+pub const PICA: AssetId = 2;
+pub const USDT: AssetId = 3;
+pub const BTC: AssetId = 4;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Timestamp: pallet_timestamp,
+		Tokens: orml_tokens,
+		AssetsRegistry: pallet_assets_registry,
+		Assets: pallet_assets,
+		Proxy: proxy,
+		Fnft: pallet_fnft,
+		StakingRewards: pallet_staking_rewards,
+	}
+);
+
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type FreezeIdentifier = [u8; 8];
+
+	type HoldIdentifier = [u8; 8];
+
+	type MaxHolds = ConstU32<32>;
+
+	type MaxFreezes = ConstU32<32>;
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+pub type AccountId = u128;
+
+#[allow(dead_code)]
+pub static ALICE: AccountId = 1;
+#[allow(dead_code)]
+pub static BOB: AccountId = 2;
+#[allow(dead_code)]
+pub static CHARLIE: AccountId = 3;
+#[allow(dead_code)]
+pub static TREASURY: AccountId = 42;
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u128>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+parameter_types! {
+	pub const MinimumPeriod: u64 = 6_000;
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
+pub type Balance = u128;
+pub type AssetId = u128;
+pub type Amount = i128;
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		Zero::zero()
+	};
+}
+
+pub struct CurrencyHooks;
+impl orml_traits::currency::MutationHooks<AccountId, AssetId, Balance> for CurrencyHooks {
+	type OnDust = ();
+	type OnSlash = ();
+	type PreDeposit = ();
+	type PostDeposit = ();
+	type PreTransfer = ();
+	type PostTransfer = ();
+	type OnNewTokenAccount = ();
+	type OnKilledTokenAccount = ();
+}
+
+type ReserveIdentifier = [u8; 8];
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type ReserveIdentifier = ReserveIdentifier;
+	type MaxReserves = frame_support::traits::ConstU32<2>;
+	type DustRemovalWhitelist = Everything;
+	type CurrencyHooks = CurrencyHooks;
+}
+
+parameter_types! {
+	pub const NativeAssetId: AssetId = NATIVE;
+	pub const NetworkId: u32 = 0;
+}
+
+parameter_types! {
+	pub const AssetsRegistryTreasuryAccount: AccountId = 0xffff_ffff;
+}
+
+impl pallet_assets_registry::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type LocalAssetId = AssetId;
+	type ForeignAssetId = ForeignAssetId;
+	type UpdateAssetRegistryOrigin = EnsureRoot<AccountId>;
+	type ParachainOrGovernanceOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+	type Balance = Balance;
+	type Convert = ConvertInto;
+	type NetworkId = NetworkId;
+	type Currency = Balances;
+	type ProposalDeposit = frame_support::traits::ConstU128<1_000>;
+	type MinProposedExistentialDeposit = frame_support::traits::ConstU128<1>;
+	type MinProposedFee = frame_support::traits::ConstU128<1>;
+	type ChallengePeriod = frame_support::traits::ConstU64<10>;
+	type RejectProposalOrigin = EnsureRoot<AccountId>;
+	type TreasuryAccount = AssetsRegistryTreasuryAccount;
+	type PoolId = u128;
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = ();
+	type NativeAssetId = NativeAssetId;
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type MultiCurrency = Tokens;
+	type NativeCurrency = Balances;
+	type WeightInfo = ();
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type FreezeOrigin = EnsureRoot<AccountId>;
+	type CurrencyValidator = Valid;
+	type RecurringPaymentDeposit = frame_support::traits::ConstU128<1>;
+	type MaxRecurringPaymentsPerPayer = frame_support::traits::ConstU32<16>;
+	type MinRecurringPaymentInterval = frame_support::traits::ConstU64<1>;
+	type MaxRecurringPaymentFailures = frame_support::traits::ConstU32<3>;
+}
+
+pub struct Valid;
+impl composable_support::validation::Validate<AssetId, primitives::currency::ValidateCurrencyId>
+	for Valid
+{
+	fn validate(input: AssetId) -> Result<AssetId, &'static str> {
+		Ok(input)
+	}
+}
+
+#[derive(
+	Copy,
+	Clone,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Encode,
+	Decode,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub enum ProxyType {
+	Any,
+	Governance,
+	CancelProxy,
+}
+
+impl Default for ProxyType {
+	fn default() -> Self {
+		Self::Any
+	}
+}
+
+impl InstanceFilter<RuntimeCall> for ProxyType {
+	fn filter(&self, c: &RuntimeCall) -> bool {
+		match self {
+			ProxyType::Any => true,
+			ProxyType::Governance => false,
+			ProxyType::CancelProxy => {
+				matches!(c, RuntimeCall::Proxy(proxy::Call::reject_announcement { .. }))
+			},
+		}
+	}
+
+	fn is_superset(&self, o: &Self) -> bool {
+		self == o || *self == ProxyType::Any
+	}
+}
+
+pub struct MockFnftAccountProxyType;
+impl FnftAccountProxyTypeSelector<ProxyType> for MockFnftAccountProxyType {
+	fn get_proxy_types() -> Vec<ProxyType> {
+		[ProxyType::Governance, ProxyType::CancelProxy].into()
+	}
+}
+
+impl proxy::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type ProxyType = ProxyType;
+	type ProxyDepositBase = ();
+	type ProxyDepositFactor = ();
+	type MaxProxies = ConstU32<4>;
+	type WeightInfo = ();
+	type MaxPending = ConstU32<4>;
+	type CallHasher = BlakeTwo256;
+	type AnnouncementDepositBase = ();
+	type AnnouncementDepositFactor = ();
+}
+
+parameter_types! {
+	pub const FnftPalletId: PalletId = PalletId(*b"pal_fnft");
+}
+
+impl pallet_fnft::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type FinancialNftCollectionId = AssetId;
+	type FinancialNftInstanceId = InstanceId;
+	type ProxyType = ProxyType;
+	type AccountProxy = composable_traits::account_proxy::AccountProxyWrapper<Test>;
+	type ProxyTypeSelector = MockFnftAccountProxyType;
+	type MaxProperties = ConstU32<4>;
+	type PalletId = FnftPalletId;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const StakingRewardsPalletId: PalletId = PalletId(*b"stk_rwrd");
+	pub const PositionDeposit: Balance = 0;
+	pub const TreasuryAccount: AccountId = TREASURY;
+	pub const MaxStakingDurationPresets: u32 = 10;
+	pub const MaxRewardConfigsPerPool: u32 = 10;
+}
+
+impl pallet_staking_rewards::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type Assets = Assets;
+	type AssetFactory = AssetsRegistry;
+	type FinancialNftInstanceId = InstanceId;
+	type FinancialNft = Fnft;
+	type UnixTime = Timestamp;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type NativeAssetId = NativeAssetId;
+	type PositionDeposit = PositionDeposit;
+	type TreasuryAccount = TreasuryAccount;
+	type PalletId = StakingRewardsPalletId;
+	type MaxStakingDurationPresets = MaxStakingDurationPresets;
+	type MaxRewardConfigsPerPool = MaxRewardConfigsPerPool;
+	type MaxPoolsPerBlock = ConstU32<2>;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::default()
+		.build_storage::<Test>()
+		.expect("success")
+		.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,395 @@
+use crate::{mock::*, pallet::*};
+use composable_support::validation::Validated;
+use composable_traits::{
+	fnft::FinancialNft,
+	staking::{
+		lock::{DurationMultipliers, LockConfig},
+		ProtocolStaking, RewardConfig, RewardPoolConfiguration, RewardRate,
+	},
+	time::{ONE_MONTH, ONE_WEEK},
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{
+		fungibles::{Inspect, Mutate},
+		tokens::nonfungibles::Inspect as _,
+		Hooks,
+	},
+	BoundedBTreeMap,
+};
+use sp_runtime::{FixedU64, Perbill, Permill};
+
+const STAKE: Balance = 10_000;
+
+fn pool_config() -> RewardPoolConfigurationOf<Test> {
+	let mut reward_configs = BoundedBTreeMap::new();
+	reward_configs
+		.try_insert(USDT, RewardConfig { reward_rate: RewardRate::per_second(10_u128) })
+		.expect("within bounds; qed;");
+	let mut presets = BoundedBTreeMap::new();
+	presets
+		.try_insert(
+			ONE_WEEK,
+			Validated::new(FixedU64::from_u32(1)).expect("multiplier is at least one; qed;"),
+		)
+		.expect("within bounds; qed;");
+	presets
+		.try_insert(
+			ONE_MONTH,
+			Validated::new(FixedU64::from_u32(2)).expect("multiplier is at least one; qed;"),
+		)
+		.expect("within bounds; qed;");
+	RewardPoolConfiguration::RewardRateBasedIncentive {
+		owner: ALICE,
+		asset_id: PICA,
+		start_block: 1,
+		reward_configs,
+		lock: LockConfig {
+			duration_multipliers: DurationMultipliers::Presets(presets),
+			unlock_penalty: Perbill::from_percent(10),
+		},
+		minimum_staking_amount: 1_000,
+	}
+}
+
+fn create_pool() -> RewardPoolOf<Test> {
+	assert_ok!(StakingRewards::create_reward_pool(RuntimeOrigin::root(), pool_config()));
+	StakingRewards::pools(PICA).expect("pool was created; qed;")
+}
+
+fn stake(who: AccountId, amount: Balance, duration: u64) -> PositionIdOf<Test> {
+	assert_ok!(Assets::mint_into(PICA, &who, amount));
+	assert_ok!(StakingRewards::stake(RuntimeOrigin::signed(who), PICA, amount, duration));
+	match System::events().last().map(|record| record.event.clone()) {
+		Some(RuntimeEvent::StakingRewards(Event::Staked {
+			fnft_collection_id,
+			fnft_instance_id,
+			..
+		})) => (fnft_collection_id, fnft_instance_id),
+		_ => panic!("stake emits Staked"),
+	}
+}
+
+fn set_time(seconds: u64) {
+	Timestamp::set_timestamp(seconds * 1_000);
+}
+
+#[test]
+fn create_reward_pool_once() {
+	new_test_ext().execute_with(|| {
+		// 1. Only the admin origin creates pools.
+		assert_noop!(
+			StakingRewards::create_reward_pool(RuntimeOrigin::signed(ALICE), pool_config()),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		// 2. The pool is identified by its staked asset, and has its own share asset and fNFT
+		// collection.
+		let pool = create_pool();
+		System::assert_last_event(
+			Event::<Test>::RewardPoolCreated { pool_id: PICA, owner: ALICE }.into(),
+		);
+		assert_ne!(pool.share_asset_id, pool.financial_nft_asset_id);
+		assert!(pool.rewards.contains_key(&USDT));
+
+		// 3. There is only one pool per staked asset.
+		assert_noop!(
+			StakingRewards::create_reward_pool(RuntimeOrigin::root(), pool_config()),
+			Error::<Test>::RewardPoolAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn stake_mints_position_with_multiplied_shares() {
+	new_test_ext().execute_with(|| {
+		let pool = create_pool();
+
+		// 1. Stakes must meet the minimum amount for one of the lock durations.
+		assert_ok!(Assets::mint_into(PICA, &ALICE, STAKE));
+		assert_noop!(
+			StakingRewards::stake(RuntimeOrigin::signed(ALICE), PICA, 999, ONE_WEEK),
+			Error::<Test>::StakedAmountTooLow
+		);
+		assert_noop!(
+			StakingRewards::stake(RuntimeOrigin::signed(ALICE), PICA, STAKE, ONE_WEEK + 1),
+			Error::<Test>::DurationPresetNotFound
+		);
+		assert_noop!(
+			StakingRewards::stake(RuntimeOrigin::signed(ALICE), BTC, STAKE, ONE_WEEK),
+			Error::<Test>::RewardPoolNotFound
+		);
+
+		// 2. The stake and its shares are held by the asset account of the position.
+		let (collection, instance) = stake(BOB, STAKE, ONE_MONTH);
+		assert_eq!(collection, pool.financial_nft_asset_id);
+		let account = Fnft::asset_account(&collection, &instance);
+		assert_eq!(Assets::balance(PICA, &account), STAKE);
+		assert_eq!(Assets::balance(pool.share_asset_id, &account), 2 * STAKE);
+		assert_eq!(Fnft::owner(&collection, &instance), Some(BOB));
+		let position = StakingRewards::stakes(collection, instance).expect("staked; qed;");
+		assert_eq!((position.stake, position.share), (STAKE, 2 * STAKE));
+		assert_eq!(position.lock.duration, ONE_MONTH);
+		assert_eq!(position.lock.unlock_penalty, Perbill::from_percent(10));
+	});
+}
+
+#[test]
+fn rewards_are_shared_by_stakers_pro_rata() {
+	new_test_ext().execute_with(|| {
+		create_pool();
+		let alice = stake(ALICE, STAKE, ONE_WEEK);
+		let bob = stake(BOB, STAKE, ONE_MONTH);
+
+		// 1. Bob holds two thirds of the shares.
+		assert_ok!(Assets::mint_into(USDT, &CHARLIE, 30_000));
+		assert_ok!(<StakingRewards as ProtocolStaking>::transfer_reward(
+			&CHARLIE, &PICA, USDT, 30_000, false
+		));
+		assert_ok!(StakingRewards::claim(RuntimeOrigin::signed(ALICE), alice.0, alice.1));
+		assert_ok!(StakingRewards::claim(RuntimeOrigin::signed(BOB), bob.0, bob.1));
+		assert_eq!(Assets::balance(USDT, &ALICE), 10_000);
+		assert_eq!(Assets::balance(USDT, &BOB), 20_000);
+
+		// 2. Claimed rewards cannot be claimed again.
+		assert_ok!(StakingRewards::claim(RuntimeOrigin::signed(ALICE), alice.0, alice.1));
+		assert_eq!(Assets::balance(USDT, &ALICE), 10_000);
+
+		// 3. Only the owner of a position claims its rewards.
+		assert_noop!(
+			StakingRewards::claim(RuntimeOrigin::signed(BOB), alice.0, alice.1),
+			Error::<Test>::OnlyStakeOwnerCanInteractWithStake
+		);
+	});
+}
+
+#[test]
+fn new_stakers_are_not_entitled_to_previous_rewards() {
+	new_test_ext().execute_with(|| {
+		create_pool();
+		let alice = stake(ALICE, STAKE, ONE_WEEK);
+		assert_ok!(Assets::mint_into(USDT, &CHARLIE, 10_000));
+		assert_ok!(<StakingRewards as ProtocolStaking>::transfer_reward(
+			&CHARLIE, &PICA, USDT, 10_000, false
+		));
+
+		let bob = stake(BOB, STAKE, ONE_WEEK);
+		assert_eq!(
+			StakingRewards::claimable_amount(bob).expect("staked; qed;").get(&USDT),
+			Some(&0)
+		);
+		assert_ok!(StakingRewards::claim(RuntimeOrigin::signed(ALICE), alice.0, alice.1));
+		assert_eq!(Assets::balance(USDT, &ALICE), 10_000);
+	});
+}
+
+#[test]
+fn unstake_before_end_of_lock_is_penalized() {
+	new_test_ext().execute_with(|| {
+		create_pool();
+		let alice = stake(ALICE, STAKE, ONE_WEEK);
+		let bob = stake(BOB, STAKE, ONE_WEEK);
+
+		// 1. The penalty goes to the treasury.
+		assert_ok!(StakingRewards::unstake(RuntimeOrigin::signed(ALICE), alice.0, alice.1));
+		System::assert_last_event(
+			Event::<Test>::Unstaked {
+				owner: ALICE,
+				fnft_collection_id: alice.0,
+				fnft_instance_id: alice.1,
+				slash: Some(1_000),
+			}
+			.into(),
+		);
+		assert_eq!(Assets::balance(PICA, &ALICE), 9_000);
+		assert_eq!(Assets::balance(PICA, &TREASURY), 1_000);
+		assert!(StakingRewards::stakes(alice.0, alice.1).is_none());
+		assert_eq!(Fnft::owner(&alice.0, &alice.1), None);
+
+		// 2. There is no penalty once the lock ended.
+		set_time(ONE_WEEK);
+		assert_ok!(StakingRewards::unstake(RuntimeOrigin::signed(BOB), bob.0, bob.1));
+		assert_eq!(Assets::balance(PICA, &BOB), STAKE);
+	});
+}
+
+#[test]
+fn split_and_merge_positions() {
+	new_test_ext().execute_with(|| {
+		let pool = create_pool();
+		let (collection, instance) = stake(ALICE, STAKE, ONE_WEEK);
+
+		// 1. Both positions must be meet the minimum stake.
+		assert_noop!(
+			StakingRewards::split(
+				RuntimeOrigin::signed(ALICE),
+				collection,
+				instance,
+				Permill::from_percent(95)
+			),
+			Error::<Test>::StakedAmountTooLow
+		);
+		assert_noop!(
+			StakingRewards::split(
+				RuntimeOrigin::signed(ALICE),
+				collection,
+				instance,
+				Permill::one()
+			),
+			Error::<Test>::InvalidSplitRatio
+		);
+
+		// 2. The original position retains the ratio.
+		assert_ok!(StakingRewards::split(
+			RuntimeOrigin::signed(ALICE),
+			collection,
+			instance,
+			Permill::from_percent(40)
+		));
+		let new_instance = instance + 1;
+		System::assert_last_event(
+			Event::<Test>::SplitPosition {
+				fnft_collection_id: collection,
+				fnft_instance_id: instance,
+				new_fnft_instance_id: new_instance,
+			}
+			.into(),
+		);
+		let position = StakingRewards::stakes(collection, instance).expect("split; qed;");
+		let new_position = StakingRewards::stakes(collection, new_instance).expect("split; qed;");
+		assert_eq!((position.stake, new_position.stake), (4_000, 6_000));
+		assert_eq!(
+			Assets::balance(pool.share_asset_id, &Fnft::asset_account(&collection, &new_instance)),
+			6_000
+		);
+
+		// 3. Merging adds up the positions.
+		assert_noop!(
+			StakingRewards::merge(RuntimeOrigin::signed(ALICE), collection, instance, instance),
+			Error::<Test>::CannotMergeSamePosition
+		);
+		assert_ok!(StakingRewards::merge(
+			RuntimeOrigin::signed(ALICE),
+			collection,
+			instance,
+			new_instance
+		));
+		let position = StakingRewards::stakes(collection, instance).expect("merged; qed;");
+		assert_eq!((position.stake, position.share), (STAKE, STAKE));
+		assert!(StakingRewards::stakes(collection, new_instance).is_none());
+		assert_eq!(Assets::balance(PICA, &Fnft::asset_account(&collection, &instance)), STAKE);
+	});
+}
+
+#[test]
+fn extend_duration_updates_shares() {
+	new_test_ext().execute_with(|| {
+		let pool = create_pool();
+		let (collection, instance) = stake(ALICE, STAKE, ONE_MONTH);
+
+		// 1. Locks cannot be shortened.
+		assert_noop!(
+			StakingRewards::extend_duration(
+				RuntimeOrigin::signed(ALICE),
+				collection,
+				instance,
+				Some(ONE_WEEK)
+			),
+			Error::<Test>::LockDurationCannotDecrease
+		);
+
+		// 2. Restarting the lock keeps the shares of the duration.
+		set_time(ONE_WEEK);
+		assert_ok!(StakingRewards::extend_duration(
+			RuntimeOrigin::signed(ALICE),
+			collection,
+			instance,
+			None
+		));
+		let position = StakingRewards::stakes(collection, instance).expect("staked; qed;");
+		assert_eq!((position.share, position.lock.started_at), (2 * STAKE, ONE_WEEK));
+		assert_eq!(Assets::total_issuance(pool.share_asset_id), 2 * STAKE);
+	});
+}
+
+#[test]
+fn rewards_accumulate_from_pot_at_reward_rate() {
+	new_test_ext().execute_with(|| {
+		create_pool();
+		assert_ok!(Assets::mint_into(USDT, &CHARLIE, 1_000));
+		assert_ok!(StakingRewards::add_to_rewards_pot(
+			RuntimeOrigin::signed(CHARLIE),
+			PICA,
+			USDT,
+			150,
+			false
+		));
+		assert_noop!(
+			StakingRewards::add_to_rewards_pot(RuntimeOrigin::signed(CHARLIE), PICA, BTC, 1, false),
+			Error::<Test>::RewardAssetNotFound
+		);
+
+		// 1. Nothing accumulates without stakers.
+		set_time(10);
+		StakingRewards::on_initialize(2);
+		assert_eq!(
+			StakingRewards::pools(PICA).expect("pool; qed;").rewards[&USDT].total_rewards,
+			0
+		);
+
+		// 2. Rewards accumulate at 10 per second.
+		let (collection, instance) = stake(ALICE, STAKE, ONE_WEEK);
+		set_time(20);
+		StakingRewards::on_initialize(3);
+		assert_eq!(
+			StakingRewards::pools(PICA).expect("pool; qed;").rewards[&USDT].total_rewards,
+			100
+		);
+
+		// 3. Up to the balance of the pot.
+		set_time(30);
+		StakingRewards::on_initialize(4);
+		assert_eq!(
+			StakingRewards::pools(PICA).expect("pool; qed;").rewards[&USDT].total_rewards,
+			150
+		);
+		assert_eq!(Assets::balance(USDT, &StakingRewards::pot_account(&PICA)), 0);
+		assert_ok!(StakingRewards::claim(RuntimeOrigin::signed(ALICE), collection, instance));
+		assert_eq!(Assets::balance(USDT, &ALICE), 150);
+	});
+}
+
+#[test]
+fn rewards_accumulate_for_a_bounded_number_of_pools_per_block() {
+	new_test_ext().execute_with(|| {
+		for asset_id in [PICA, BTC, NATIVE] {
+			let mut config = pool_config();
+			let RewardPoolConfiguration::RewardRateBasedIncentive { asset_id: pool_asset, .. } =
+				&mut config;
+			*pool_asset = asset_id;
+			assert_ok!(StakingRewards::create_reward_pool(RuntimeOrigin::root(), config));
+		}
+
+		// 1. Each block updates `MaxPoolsPerBlock` pools, continuing after the previous one.
+		assert_eq!(StakingRewards::accumulate_rewards(2), (2, 2));
+		let cursor = RewardsCursor::<Test>::get().expect("pools were updated; qed;");
+		assert_eq!(StakingRewards::accumulate_rewards(3), (2, 2));
+		assert_ne!(RewardsCursor::<Test>::get(), Some(cursor));
+
+		// 2. Every pool is updated over two blocks.
+		let mut updated = Vec::new();
+		for block in 4..6 {
+			let before = RewardPools::<Test>::iter_keys().collect::<Vec<_>>();
+			set_time(block);
+			StakingRewards::on_initialize(block);
+			updated.extend(before.into_iter().filter(|pool_id| {
+				StakingRewards::pools(pool_id).expect("pool; qed;").rewards[&USDT]
+					.last_updated_timestamp ==
+					block
+			}));
+		}
+		updated.sort();
+		updated.dedup();
+		assert_eq!(updated.len(), 3);
+	});
+}
@@ -0,0 +1,58 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+pub trait WeightInfo {
+	fn create_reward_pool(r: u32) -> Weight;
+	fn stake(r: u32) -> Weight;
+	fn extend(r: u32) -> Weight;
+	fn unstake(r: u32) -> Weight;
+	fn split(r: u32) -> Weight;
+	fn merge(r: u32) -> Weight;
+	fn claim(r: u32) -> Weight;
+	fn extend_duration(r: u32) -> Weight;
+	fn add_to_rewards_pot() -> Weight;
+	fn update_rewards_pool(r: u32) -> Weight;
+	fn accumulate_rewards(r: u32) -> Weight;
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_reward_pool(_r: u32) -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn stake(_r: u32) -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn extend(_r: u32) -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn unstake(_r: u32) -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn split(_r: u32) -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn merge(_r: u32) -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn claim(_r: u32) -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn extend_duration(_r: u32) -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn add_to_rewards_pot() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn update_rewards_pool(_r: u32) -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn accumulate_rewards(_r: u32) -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+}
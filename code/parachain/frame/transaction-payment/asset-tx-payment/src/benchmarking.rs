@@ -25,6 +25,21 @@ use crate::Pallet as AssetTxPayment;
 use frame_benchmarking::{benchmarks, whitelisted_caller};
 use frame_system::RawOrigin;
 
+fn sponsorship_policy<T: Config>() -> SponsorshipPolicyOf<T> {
+	let allowed_calls = (0..T::MaxSponsoredCalls::get())
+		.map(|i| (i as u8, 0))
+		.collect::<Vec<_>>()
+		.try_into()
+		.expect("bounded by MaxSponsoredCalls; qed");
+	SponsorshipPolicy {
+		allowed_calls,
+		user_budget: 1_000_u32.into(),
+		period_budget: 1_000_000_u32.into(),
+		period: 100_u32.into(),
+		expiry: 1_000_u32.into(),
+	}
+}
+
 benchmarks! {
 	set_payment_asset {
 		let caller: T::AccountId = whitelisted_caller();
@@ -34,5 +49,21 @@ benchmarks! {
 		let actual = <PaymentAssets::<T>>::get(&caller);
 		assert_eq!(actual, None);
 	}
+	set_sponsorship_policy {
+		let caller: T::AccountId = whitelisted_caller();
+		let policy = sponsorship_policy::<T>();
+	}: _(RawOrigin::Signed(caller.clone()), policy.clone())
+	verify {
+		assert_eq!(<SponsorshipPolicies::<T>>::get(&caller), Some(policy));
+	}
+
+	remove_sponsorship_policy {
+		let caller: T::AccountId = whitelisted_caller();
+		<SponsorshipPolicies::<T>>::insert(&caller, sponsorship_policy::<T>());
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert_eq!(<SponsorshipPolicies::<T>>::get(&caller), None);
+	}
+
 	impl_benchmark_test_suite!(AssetTxPayment, crate::tests::new_test_ext(), crate::tests::Runtime);
 }
@@ -25,13 +25,26 @@
 //! [`OnChargeAssetTransaction`] implementation analogously to [`pallet-transaction-payment`]. The
 //! included [`FungiblesAdapter`] (implementing [`OnChargeAssetTransaction`]) determines the fee
 //! amount by converting the fee calculated by [`pallet-transaction-payment`] into the desired
-//! asset.
+//! asset. The [`SwapFungiblesAdapter`] also accepts assets without a price but with a DEX route
+//! to the native token, swapping the fee to it.
 //!
 //! ## Integration
 
 //! This pallet wraps FRAME's transaction payment pallet and functions as a replacement. This means
 //! you should include both pallets in your `construct_runtime` macro, but only include this
 //! pallet's [`SignedExtension`] ([`ChargeAssetTxPayment`]).
+//!
+//! ## Sponsorship
+
+//! A sponsor account can pay the fees of transactions signed by other accounts, for example a dApp
+//! onboarding users without native tokens. It registers a [`SponsorshipPolicy`] with the calls it
+//! pays for, a budget per account and for all accounts for each period of blocks, and an expiry.
+//! Transactions naming the sponsor in their [`ChargeAssetTxPayment`] are then charged to the
+//! sponsor, in its payment asset, when the policy covers them, and to the signer otherwise. The
+//! tip of sponsored transactions is ignored, so that signers cannot spend the budget on tips, and
+//! the part of the fee refunded after dispatch is returned to the budgets. Policies restricting
+//! the sponsored calls never pay for [`Config::WrapperCalls`], like `utility.batch`, which would
+//! dispatch calls the policy does not allow.
 #![feature(associated_type_defaults)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -47,16 +60,19 @@ use frame_support::{
 			fungibles::{Balanced, Inspect, MutateHold},
 			ConversionToAssetBalance, WithdrawConsequence,
 		},
-		Get, IsSubType, IsType,
+		Contains, Get, IsSubType, IsType,
 	},
-	DefaultNoBound,
+	CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
 };
 use frame_system::pallet_prelude::*;
 
 use pallet_transaction_payment::OnChargeTransaction;
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SignedExtension, Zero},
+	traits::{
+		CheckedAdd, DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SaturatedConversion,
+		Saturating, SignedExtension, Zero,
+	},
 	transaction_validity::{
 		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
 	},
@@ -69,8 +85,10 @@ mod tests;
 mod benchmarking;
 
 mod payment;
+mod swap;
 pub mod weights;
 pub use payment::*;
+pub use swap::*;
 pub use weights::*;
 
 // Default implementation for [`ConversionToAssetBalance`].
@@ -112,6 +130,45 @@ pub(crate) type ChargeAssetIdOf<T> =
 pub(crate) type ChargeAssetLiquidityOf<T> =
 	<<T as Config>::OnChargeAssetTransaction as OnChargeAssetTransaction<T>>::LiquidityInfo;
 
+/// Index of a call in the runtime: the index of its pallet and its index in the pallet.
+pub type CallIndex = (u8, u8);
+
+/// Fees paid in a budget period, by period.
+pub(crate) type SpendingOf<T> = (BlockNumberFor<T>, ChargeAssetBalanceOf<T>);
+
+/// Sponsorship policy of the pallet.
+pub type SponsorshipPolicyOf<T> =
+	SponsorshipPolicy<ChargeAssetBalanceOf<T>, BlockNumberFor<T>, <T as Config>::MaxSponsoredCalls>;
+
+/// Transactions of other accounts a sponsor pays the fees of. Budgets are amounts of native fees.
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxCalls))]
+pub struct SponsorshipPolicy<
+	Balance: Clone + PartialEq + Eq + sp_std::fmt::Debug,
+	BlockNumber: Clone + PartialEq + Eq + sp_std::fmt::Debug,
+	MaxCalls: Get<u32>,
+> {
+	/// Calls sponsored, or all of them if empty.
+	pub allowed_calls: BoundedVec<CallIndex, MaxCalls>,
+	/// Maximum fees paid for one account in a period.
+	pub user_budget: Balance,
+	/// Maximum fees paid for all accounts in a period.
+	pub period_budget: Balance,
+	/// Length of budget periods, in blocks.
+	pub period: BlockNumber,
+	/// Block from which the policy does not apply.
+	pub expiry: BlockNumber,
+}
+
 /// Used to pass the initial payment info from pre- to post-dispatch.
 #[derive(Encode, Decode, DefaultNoBound, TypeInfo)]
 pub enum InitialPayment<T: Config> {
@@ -179,11 +236,29 @@ pub mod pallet {
 			ChargeAssetIdOf<Self>,
 			ChargeAssetBalanceOf<Self>,
 		>;
+
+		/// Maximum number of calls a sponsorship policy can allow.
+		#[pallet::constant]
+		type MaxSponsoredCalls: Get<u32>;
+
+		/// Calls dispatching other calls, like `utility.batch` or `proxy.proxy`. Policies
+		/// restricting the sponsored calls never pay for them, whatever calls they wrap.
+		type WrapperCalls: Contains<Self::RuntimeCall>;
 	}
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Sponsorship budget periods must last at least one block.
+		ZeroSponsorshipPeriod,
+		/// The caller has no sponsorship policy.
+		SponsorshipPolicyNotFound,
+		/// The spending of the previous policy of the caller is still being removed.
+		SponsoredSpendingBeingRemoved,
+	}
+
 	/// Stores default payment asset of user with ED locked.
 	#[pallet::storage]
 	#[pallet::getter(fn payment_assets)]
@@ -195,6 +270,56 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Sponsorship policies, by sponsor.
+	#[pallet::storage]
+	#[pallet::getter(fn sponsorship_policies)]
+	pub type SponsorshipPolicies<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, SponsorshipPolicyOf<T>, OptionQuery>;
+
+	/// Fees paid by sponsors in their last budget period.
+	#[pallet::storage]
+	pub type SponsorSpending<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, SpendingOf<T>, OptionQuery>;
+
+	/// Fees paid by sponsors for each account in their last budget period.
+	#[pallet::storage]
+	pub type SponsoredSpending<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		SpendingOf<T>,
+		OptionQuery,
+	>;
+
+	/// Sponsors whose policy was removed, while their spending for each account is removed.
+	#[pallet::storage]
+	pub type RemovedSponsors<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let removal_weight = T::DbWeight::get().reads_writes(1, 1);
+			if remaining_weight.any_lt(removal_weight.saturating_mul(2)) {
+				return Weight::zero()
+			}
+			let Some(sponsor) = <RemovedSponsors<T>>::iter_keys().next() else {
+				return T::DbWeight::get().reads(1)
+			};
+			let limit = remaining_weight.saturating_sub(removal_weight).ref_time() /
+				removal_weight.ref_time().max(1);
+			let removal =
+				<SponsoredSpending<T>>::clear_prefix(&sponsor, limit.saturated_into(), None);
+			if removal.maybe_cursor.is_none() {
+				<RemovedSponsors<T>>::remove(&sponsor);
+			}
+			removal_weight.saturating_add(
+				T::DbWeight::get().reads_writes(removal.loops.into(), removal.unique.into()),
+			)
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Sets or resets payment asset.
@@ -239,6 +364,99 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Sets or replaces the sponsorship policy of the caller, which then pays the fees of the
+		/// transactions it covers. Fees already paid in the current period count against the new
+		/// budgets. Fails while the spending of a removed policy is being removed.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::set_sponsorship_policy())]
+		pub fn set_sponsorship_policy(
+			origin: OriginFor<T>,
+			policy: SponsorshipPolicyOf<T>,
+		) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			ensure!(!policy.period.is_zero(), Error::<T>::ZeroSponsorshipPeriod);
+			ensure!(
+				!<RemovedSponsors<T>>::contains_key(&sponsor),
+				Error::<T>::SponsoredSpendingBeingRemoved
+			);
+			<SponsorshipPolicies<T>>::insert(sponsor, policy);
+			Ok(())
+		}
+
+		/// Removes the sponsorship policy of the caller. Its spending for each account is removed
+		/// in `on_idle`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::remove_sponsorship_policy())]
+		pub fn remove_sponsorship_policy(origin: OriginFor<T>) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			ensure!(
+				<SponsorshipPolicies<T>>::contains_key(&sponsor),
+				Error::<T>::SponsorshipPolicyNotFound
+			);
+			<SponsorshipPolicies<T>>::remove(&sponsor);
+			<SponsorSpending<T>>::remove(&sponsor);
+			<RemovedSponsors<T>>::insert(&sponsor, ());
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The spending of `sponsor`, overall and on `who`, once paying `fee` for `call`, if the
+		/// policy of `sponsor` covers it.
+		pub(crate) fn sponsored_spending(
+			sponsor: &T::AccountId,
+			who: &T::AccountId,
+			call: &T::RuntimeCall,
+			fee: ChargeAssetBalanceOf<T>,
+		) -> Option<(SpendingOf<T>, SpendingOf<T>)> {
+			let policy = <SponsorshipPolicies<T>>::get(sponsor)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			if now >= policy.expiry || policy.period.is_zero() {
+				return None
+			}
+			if !policy.allowed_calls.is_empty() {
+				if T::WrapperCalls::contains(call) {
+					return None
+				}
+				let call_index =
+					call.using_encoded(|call| Some((*call.first()?, *call.get(1)?)))?;
+				if !policy.allowed_calls.contains(&call_index) {
+					return None
+				}
+			}
+			let period = now / policy.period;
+			let spend = |spending: Option<SpendingOf<T>>, budget| {
+				let spent = spending
+					.filter(|(spending_period, _)| *spending_period == period)
+					.map(|(_, spent)| spent)
+					.unwrap_or_default();
+				spent
+					.checked_add(&fee)
+					.filter(|spent| *spent <= budget)
+					.map(|spent| (period, spent))
+			};
+			Some((
+				spend(<SponsorSpending<T>>::get(sponsor), policy.period_budget)?,
+				spend(<SponsoredSpending<T>>::get(sponsor, who), policy.user_budget)?,
+			))
+		}
+
+		/// Return `refund` to the budgets `sponsor` spent on `who`, once the fee is corrected
+		/// after dispatch.
+		pub(crate) fn refund_sponsored_spending(
+			sponsor: &T::AccountId,
+			who: &T::AccountId,
+			refund: ChargeAssetBalanceOf<T>,
+		) {
+			let unspend = |spending: &mut Option<SpendingOf<T>>| {
+				if let Some((_, spent)) = spending {
+					*spent = spent.saturating_sub(refund);
+				}
+			};
+			<SponsorSpending<T>>::mutate(sponsor, unspend);
+			<SponsoredSpending<T>>::mutate(sponsor, who, unspend);
+		}
 	}
 }
 
@@ -253,6 +471,7 @@ pub struct ChargeAssetTxPayment<T: Config> {
 	#[codec(compact)]
 	tip: BalanceOf<T>,
 	asset_id: Option<ChargeAssetIdOf<T>>,
+	sponsor: Option<T::AccountId>,
 }
 
 impl<T: Config> ChargeAssetTxPayment<T>
@@ -265,7 +484,65 @@ where
 {
 	/// Utility constructor. Used only in client/factory code.
 	pub fn from(tip: BalanceOf<T>, asset_id: Option<ChargeAssetIdOf<T>>) -> Self {
-		Self { tip, asset_id }
+		Self { tip, asset_id, sponsor: None }
+	}
+
+	/// Requests `sponsor` to pay the fees, the tip being ignored if it does. Used only in
+	/// client/factory code.
+	pub fn with_sponsor(mut self, sponsor: T::AccountId) -> Self {
+		self.sponsor = Some(sponsor);
+		self
+	}
+
+	/// Get the account paying for this transaction, its payment asset and the tip it pays, with
+	/// the spending of the sponsor if it is sponsored. Sponsors do not pay tips.
+	#[allow(clippy::type_complexity)]
+	fn get_payer(
+		&self,
+		who: &T::AccountId,
+		call: &T::RuntimeCall,
+		info: &DispatchInfoOf<T::RuntimeCall>,
+		len: usize,
+	) -> (
+		T::AccountId,
+		Option<ChargeAssetIdOf<T>>,
+		BalanceOf<T>,
+		Option<(SpendingOf<T>, SpendingOf<T>)>,
+	) {
+		if let Some(sponsor) = &self.sponsor {
+			let fee = pallet_transaction_payment::Pallet::<T>::compute_fee(
+				len as u32,
+				info,
+				Zero::zero(),
+			);
+			if let Some(spending) = Pallet::<T>::sponsored_spending(sponsor, who, call, fee.into())
+			{
+				let asset_id = if <T as Config>::UseUserConfiguration::get() {
+					<PaymentAssets<T>>::get(sponsor).map(|x| x.0)
+				} else {
+					None
+				};
+				return (sponsor.clone(), asset_id, Zero::zero(), Some(spending))
+			}
+		}
+		(who.clone(), self.get_payment_asset(who, call), self.tip, None)
+	}
+
+	/// Weight of reading the policy, spending and payment asset of the sponsor of a transaction,
+	/// and of writing its spending, before and after dispatch.
+	fn sponsorship_weight(&self, sponsored: bool) -> Weight {
+		match (&self.sponsor, sponsored) {
+			(None, _) => Weight::zero(),
+			(Some(_), false) => T::DbWeight::get().reads(1),
+			(Some(_), true) => T::DbWeight::get().reads_writes(6, 4),
+		}
+	}
+
+	/// Weight of correcting the fee after dispatch when it is paid in `asset_id`.
+	fn correction_weight(asset_id: Option<ChargeAssetIdOf<T>>) -> Weight {
+		asset_id.map_or_else(Weight::zero, |asset_id| {
+			T::OnChargeAssetTransaction::correction_weight(asset_id)
+		})
 	}
 
 	/// Fee withdrawal logic that dispatches to either `OnChargeAssetTransaction` or
@@ -277,9 +554,10 @@ where
 		info: &DispatchInfoOf<T::RuntimeCall>,
 		len: usize,
 		asset_id: Option<ChargeAssetIdOf<T>>,
+		tip: BalanceOf<T>,
 	) -> Result<(BalanceOf<T>, InitialPayment<T>), TransactionValidityError> {
-		let fee = pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, self.tip);
-		debug_assert!(self.tip <= fee, "tip should be included in the computed fee");
+		let fee = pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, tip);
+		debug_assert!(tip <= fee, "tip should be included in the computed fee");
 		if fee.is_zero() {
 			Ok((fee, InitialPayment::Nothing))
 		} else if let Some(asset_id) = asset_id {
@@ -289,12 +567,12 @@ where
 				info,
 				asset_id,
 				fee.into(),
-				self.tip.into(),
+				tip.into(),
 			)
 			.map(|i| (fee, InitialPayment::Asset(i.into())))
 		} else {
 			<OnChargeTransactionOf<T> as OnChargeTransaction<T>>::withdraw_fee(
-				who, call, info, fee, self.tip,
+				who, call, info, fee, tip,
 			)
 			.map(|i| (fee, InitialPayment::Native(i)))
 			.map_err(|_| -> TransactionValidityError { InvalidTransaction::Payment.into() })
@@ -328,7 +606,13 @@ where
 impl<T: Config> sp_std::fmt::Debug for ChargeAssetTxPayment<T> {
 	#[cfg(feature = "std")]
 	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
-		write!(f, "ChargeAssetTxPayment<{:?}, {:?}>", self.tip, self.asset_id.encode())
+		write!(
+			f,
+			"ChargeAssetTxPayment<{:?}, {:?}, {:?}>",
+			self.tip,
+			self.asset_id.encode(),
+			self.sponsor.encode()
+		)
 	}
 	#[cfg(not(feature = "std"))]
 	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
@@ -355,6 +639,10 @@ where
 		Self::AccountId,
 		// imbalance resulting from withdrawing the fee
 		InitialPayment<T>,
+		// weight of correcting the fee paid in an asset, charged before dispatch
+		Weight,
+		// account sponsored and the fee spent from the budgets of the sponsor
+		Option<(Self::AccountId, BalanceOf<T>)>,
 	);
 
 	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
@@ -369,9 +657,11 @@ where
 		len: usize,
 	) -> TransactionValidity {
 		use pallet_transaction_payment::ChargeTransactionPayment;
-		let asset_id = self.get_payment_asset(who, call);
-		let (fee, _) = self.withdraw_fee(who, call, info, len, asset_id)?;
-		let priority = ChargeTransactionPayment::<T>::get_priority(info, len, self.tip, fee);
+		let (payer, asset_id, tip, _) = self.get_payer(who, call, info, len);
+		let correction_weight = Self::correction_weight(asset_id);
+		let info = &DispatchInfo { weight: info.weight.saturating_add(correction_weight), ..*info };
+		let (fee, _) = self.withdraw_fee(&payer, call, info, len, asset_id, tip)?;
+		let priority = ChargeTransactionPayment::<T>::get_priority(info, len, tip, fee);
 		Ok(ValidTransaction { priority, ..Default::default() })
 	}
 
@@ -382,9 +672,28 @@ where
 		info: &DispatchInfoOf<Self::Call>,
 		len: usize,
 	) -> Result<Self::Pre, TransactionValidityError> {
-		let asset_id = self.get_payment_asset(who, call);
-		let (_fee, initial_payment) = self.withdraw_fee(who, call, info, len, asset_id)?;
-		Ok((self.tip, who.clone(), initial_payment))
+		let (payer, asset_id, tip, spending) = self.get_payer(who, call, info, len);
+		// the budgets are spent in fees without the correction, as in `get_payer`
+		let sponsored = spending.as_ref().map(|_| {
+			let fee = pallet_transaction_payment::Pallet::<T>::compute_fee(
+				len as u32,
+				info,
+				Zero::zero(),
+			);
+			(who.clone(), fee)
+		});
+		let correction_weight = Self::correction_weight(asset_id);
+		let info = &DispatchInfo { weight: info.weight.saturating_add(correction_weight), ..*info };
+		let (_fee, initial_payment) = self.withdraw_fee(&payer, call, info, len, asset_id, tip)?;
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(
+			self.sponsorship_weight(spending.is_some()).saturating_add(correction_weight),
+			info.class,
+		);
+		if let Some((sponsor_spending, sponsored_spending)) = spending {
+			<SponsorSpending<T>>::insert(&payer, sponsor_spending);
+			<SponsoredSpending<T>>::insert(&payer, who, sponsored_spending);
+		}
+		Ok((tip, payer, initial_payment, correction_weight, sponsored))
 	}
 
 	fn post_dispatch(
@@ -394,7 +703,20 @@ where
 		len: usize,
 		result: &DispatchResult,
 	) -> Result<(), TransactionValidityError> {
-		if let Some((tip, who, initial_payment)) = pre {
+		if let Some((tip, who, initial_payment, correction_weight, sponsored)) = pre {
+			if let Some((signer, spent)) = sponsored {
+				let actual_fee = pallet_transaction_payment::Pallet::<T>::compute_actual_fee(
+					len as u32,
+					info,
+					post_info,
+					Zero::zero(),
+				);
+				let refund = spent.saturating_sub(actual_fee);
+				if !refund.is_zero() {
+					// the sponsor paid, so `who` is the sponsor
+					Pallet::<T>::refund_sponsored_spending(&who, &signer, refund.into());
+				}
+			}
 			match initial_payment {
 				InitialPayment::Native(already_withdrawn) => {
 					pallet_transaction_payment::ChargeTransactionPayment::<T>::post_dispatch(
@@ -406,6 +728,17 @@ where
 					)?;
 				},
 				InitialPayment::Asset(already_withdrawn) => {
+					// The correction is charged as if it was part of the dispatch.
+					let info = &DispatchInfo {
+						weight: info.weight.saturating_add(correction_weight),
+						..*info
+					};
+					let post_info = &PostDispatchInfo {
+						actual_weight: post_info
+							.actual_weight
+							.map(|weight| weight.saturating_add(correction_weight)),
+						..*post_info
+					};
 					let actual_fee = pallet_transaction_payment::Pallet::<T>::compute_actual_fee(
 						len as u32, info, post_info, tip,
 					);
@@ -83,6 +83,12 @@ pub trait OnChargeAssetTransaction<T: Config> {
 		tip: Self::Balance,
 		already_withdrawn: Self::LiquidityInfo,
 	) -> Result<(), TransactionValidityError>;
+
+	/// Weight of `correct_and_deposit_fee` for fees paid in `asset_id`, beyond what the transaction
+	/// payment accounts for. It is charged to the payer and registered before dispatch.
+	fn correction_weight(_asset_id: Self::AssetId) -> Weight {
+		Weight::zero()
+	}
 }
 
 /// Allows specifying what to do with the withdrawn asset fees.
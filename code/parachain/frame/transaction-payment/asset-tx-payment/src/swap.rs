@@ -0,0 +1,263 @@
+// Copyright (C) 2021-2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Paying transaction fees in assets without a price, by swapping them to the native asset.
+use super::*;
+use crate::Config;
+
+use codec::{FullCodec, MaxEncodedLen};
+use composable_traits::{
+	defi::CurrencyPair,
+	dex::{Amm, AssetAmount, Twap},
+};
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	traits::{
+		fungibles::{Balanced, Credit, Inspect},
+		tokens::{ConversionToAssetBalance, Fortitude, Precision, Preservation},
+	},
+	unsigned::TransactionValidityError,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, MaybeSerializeDeserialize, One, PostDispatchInfoOf},
+	transaction_validity::InvalidTransaction,
+	FixedPointNumber, FixedPointOperand, Permill,
+};
+use sp_std::{fmt::Debug, marker::PhantomData};
+
+/// Implements the asset transaction for assets priced by a balance to asset converter
+/// (implementing [`ConversionToAssetBalance`]) like [`FungiblesAdapter`], and for assets with a
+/// `Dex` route to the native asset.
+///
+/// Fees in assets without a price are quoted at the TWAP of the route, increased by `MaxSlippage`
+/// to cover the fees and price impact of the swap, and rejected while the spot price of the route
+/// deviates from the TWAP by more than `MaxDeviation`. The quoted amount is withdrawn before
+/// dispatch and the unused part is refunded in the same asset after dispatch. The rest is
+/// deposited into `FeeAccount` and swapped there to at least the actual fee in the native asset,
+/// which costs `SwapWeight` charged to the payer before dispatch. The credit handler is only given
+/// the fees which cannot be deposited.
+pub struct SwapFungiblesAdapter<
+	CON,
+	HC,
+	Dex,
+	NativeAssetId,
+	MaxSlippage,
+	MaxDeviation,
+	FeeAccount,
+	SwapWeight,
+>(PhantomData<(CON, HC, Dex, NativeAssetId, MaxSlippage, MaxDeviation, FeeAccount, SwapWeight)>);
+
+/// Amount of `asset_id` paying `fee` when swapped to the native asset, or `None` if the asset has
+/// no TWAP or its spot price deviates too much from it.
+fn quote<T, Dex, NativeAssetId, MaxSlippage, MaxDeviation>(
+	fee: BalanceOf<T>,
+	asset_id: AssetIdOf<T>,
+) -> Option<AssetBalanceOf<T>>
+where
+	T: Config,
+	Dex: Amm<
+			AssetId = AssetIdOf<T>,
+			Balance = AssetBalanceOf<T>,
+			PoolId = CurrencyPair<AssetIdOf<T>>,
+		> + Twap<AssetId = AssetIdOf<T>, PoolId = CurrencyPair<AssetIdOf<T>>>,
+	NativeAssetId: Get<AssetIdOf<T>>,
+	MaxSlippage: Get<Permill>,
+	MaxDeviation: Get<Permill>,
+	BalanceOf<T>: IsType<AssetBalanceOf<T>>,
+	AssetBalanceOf<T>: FixedPointOperand,
+{
+	let native_asset_id = NativeAssetId::get();
+	let pair = CurrencyPair::new(native_asset_id, asset_id);
+	let quote = Dex::get_twap(pair, native_asset_id).ok()?.checked_mul_int(fee.into())?;
+	let spot_quote =
+		Dex::spot_price(pair, AssetAmount::new(native_asset_id, fee.into()), asset_id, false)
+			.ok()?
+			.value
+			.amount;
+	if quote.max(spot_quote) - quote.min(spot_quote) > MaxDeviation::get().mul_ceil(quote) {
+		return None
+	}
+	quote.checked_add(&MaxSlippage::get().mul_ceil(quote))
+}
+
+/// Deposits `paid` into `FeeAccount` and swaps it there to at least `fee` in the native asset,
+/// or returns it if it cannot be deposited.
+fn deposit_swapped<T, Dex, NativeAssetId, FeeAccount>(
+	paid: Credit<T::AccountId, T::Fungibles>,
+	fee: BalanceOf<T>,
+) -> Result<(), Credit<T::AccountId, T::Fungibles>>
+where
+	T: Config,
+	Dex: Amm<
+		AssetId = AssetIdOf<T>,
+		Balance = AssetBalanceOf<T>,
+		AccountId = T::AccountId,
+		PoolId = CurrencyPair<AssetIdOf<T>>,
+	>,
+	NativeAssetId: Get<AssetIdOf<T>>,
+	FeeAccount: Get<T::AccountId>,
+	BalanceOf<T>: IsType<AssetBalanceOf<T>>,
+{
+	let account = FeeAccount::get();
+	let (asset_id, amount) = (paid.asset(), paid.peek());
+	<T::Fungibles as Balanced<T::AccountId>>::resolve(&account, paid)?;
+	if amount.is_zero() {
+		return Ok(())
+	}
+	let native_asset_id = NativeAssetId::get();
+	// If the swap fails, e.g. because the price moved more than the slippage allowed, the fee
+	// account keeps the asset.
+	let _ = with_transaction(|| {
+		let swapped = Dex::do_swap(
+			&account,
+			CurrencyPair::new(native_asset_id, asset_id),
+			AssetAmount::new(asset_id, amount),
+			AssetAmount::new(native_asset_id, fee.into()),
+			false,
+		);
+		match swapped {
+			Ok(_) => TransactionOutcome::Commit(swapped),
+			Err(_) => TransactionOutcome::Rollback(swapped),
+		}
+	});
+	Ok(())
+}
+
+impl<T, CON, HC, Dex, NativeAssetId, MaxSlippage, MaxDeviation, FeeAccount, SwapWeight>
+	OnChargeAssetTransaction<T>
+	for SwapFungiblesAdapter<
+		CON,
+		HC,
+		Dex,
+		NativeAssetId,
+		MaxSlippage,
+		MaxDeviation,
+		FeeAccount,
+		SwapWeight,
+	> where
+	T: Config,
+	CON: ConversionToAssetBalance<BalanceOf<T>, AssetIdOf<T>, AssetBalanceOf<T>>,
+	HC: HandleCredit<T::AccountId, T::Fungibles>,
+	Dex: Amm<
+			AssetId = AssetIdOf<T>,
+			Balance = AssetBalanceOf<T>,
+			AccountId = T::AccountId,
+			PoolId = CurrencyPair<AssetIdOf<T>>,
+		> + Twap<AssetId = AssetIdOf<T>, PoolId = CurrencyPair<AssetIdOf<T>>>,
+	NativeAssetId: Get<AssetIdOf<T>>,
+	MaxSlippage: Get<Permill>,
+	MaxDeviation: Get<Permill>,
+	FeeAccount: Get<T::AccountId>,
+	SwapWeight: Get<Weight>,
+	BalanceOf<T>: IsType<AssetBalanceOf<T>>,
+	AssetBalanceOf<T>: FixedPointOperand,
+	AssetIdOf<T>: FullCodec
+		+ Copy
+		+ MaybeSerializeDeserialize
+		+ Debug
+		+ Default
+		+ Eq
+		+ TypeInfo
+		+ MaxEncodedLen,
+{
+	type Balance = BalanceOf<T>;
+	type AssetId = AssetIdOf<T>;
+	type LiquidityInfo = Credit<T::AccountId, T::Fungibles>;
+
+	/// Withdraw the predicted fee from the transaction origin, in the quoted amount of the asset
+	/// if it has no price.
+	///
+	/// Note: The `fee` already includes the `tip`.
+	fn withdraw_fee(
+		who: &T::AccountId,
+		call: &T::RuntimeCall,
+		info: &DispatchInfoOf<T::RuntimeCall>,
+		asset_id: Self::AssetId,
+		fee: Self::Balance,
+		tip: Self::Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		if CON::to_asset_balance(fee, asset_id).is_ok() {
+			return FungiblesAdapter::<CON, HC>::withdraw_fee(who, call, info, asset_id, fee, tip)
+		}
+		let min_quoted_fee = if fee.is_zero() { Zero::zero() } else { One::one() };
+		let quoted_fee = quote::<T, Dex, NativeAssetId, MaxSlippage, MaxDeviation>(fee, asset_id)
+			.ok_or_else(|| TransactionValidityError::from(InvalidTransaction::Payment))?
+			.max(min_quoted_fee);
+		let can_withdraw =
+			<T::Fungibles as Inspect<T::AccountId>>::can_withdraw(asset_id, who, quoted_fee);
+		if !matches!(can_withdraw, WithdrawConsequence::Success) {
+			return Err(InvalidTransaction::Payment.into())
+		}
+		<T::Fungibles as Balanced<T::AccountId>>::withdraw(
+			asset_id,
+			who,
+			quoted_fee,
+			Precision::Exact,
+			Preservation::Preserve,
+			Fortitude::Polite,
+		)
+		.map_err(|_| TransactionValidityError::from(InvalidTransaction::Payment))
+	}
+
+	/// Refund the overpaid fee in the asset paid and swap the fee and the tip to the native asset
+	/// if the asset has no price, or hand them over to the `[HandleCredit]` implementation.
+	///
+	/// Note: The `corrected_fee` already includes the `tip`.
+	fn correct_and_deposit_fee(
+		who: &T::AccountId,
+		dispatch_info: &DispatchInfoOf<T::RuntimeCall>,
+		post_info: &PostDispatchInfoOf<T::RuntimeCall>,
+		corrected_fee: Self::Balance,
+		tip: Self::Balance,
+		paid: Self::LiquidityInfo,
+	) -> Result<(), TransactionValidityError> {
+		if CON::to_asset_balance(corrected_fee, paid.asset()).is_ok() {
+			return FungiblesAdapter::<CON, HC>::correct_and_deposit_fee(
+				who,
+				dispatch_info,
+				post_info,
+				corrected_fee,
+				tip,
+				paid,
+			)
+		}
+		let min_quoted_fee = if corrected_fee.is_zero() { Zero::zero() } else { One::one() };
+		// Keep everything if the route disappeared during dispatch.
+		let quoted_fee =
+			quote::<T, Dex, NativeAssetId, MaxSlippage, MaxDeviation>(corrected_fee, paid.asset())
+				.unwrap_or_else(|| paid.peek())
+				.max(min_quoted_fee);
+		let (final_fee, refund) = paid.split(quoted_fee);
+		// Refund to the account that paid the fees. If this fails, the account might have dropped
+		// below the existential balance. In that case we don't refund anything.
+		let _ = <T::Fungibles as Balanced<T::AccountId>>::resolve(who, refund);
+		if let Err(final_fee) =
+			deposit_swapped::<T, Dex, NativeAssetId, FeeAccount>(final_fee, corrected_fee)
+		{
+			HC::handle_credit(final_fee);
+		}
+		Ok(())
+	}
+
+	/// The weight of the swap for assets without a price.
+	fn correction_weight(asset_id: Self::AssetId) -> Weight {
+		if CON::to_asset_balance(One::one(), asset_id).is_ok() {
+			Weight::zero()
+		} else {
+			SwapWeight::get()
+		}
+	}
+}
@@ -16,11 +16,19 @@
 use super::*;
 use crate as pallet_asset_tx_payment;
 
+use composable_traits::{
+	defi::{CurrencyPair, Rate},
+	dex::{AssetAmount, SwapResult, Twap},
+};
 use frame_support::{
-	assert_ok,
+	assert_noop, assert_ok,
 	dispatch::{DispatchClass, DispatchInfo, PostDispatchInfo},
 	parameter_types,
-	traits::{fungibles::*, ConstU32, ConstU64, ConstU8, FindAuthor},
+	traits::{
+		fungibles::*,
+		tokens::{Fortitude, Precision},
+		ConstU32, ConstU64, ConstU8, FindAuthor,
+	},
 	weights::{Weight, WeightToFee as WeightToFeeT},
 	ConsensusEngineId,
 };
@@ -32,6 +40,7 @@ use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup, SaturatedConversion, StaticLookup},
+	FixedPointNumber, Permill,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
@@ -52,6 +61,7 @@ frame_support::construct_runtime!(
 		Assets: orml_tokens,
 		Authorship: pallet_authorship,
 		AssetTxPayment: pallet_asset_tx_payment,
+		Utility: pallet_utility,
 	}
 );
 
@@ -81,13 +91,14 @@ parameter_types! {
 	pub static WeightToFee: u64 = 1;
 	pub static TransactionByteFee: u64 = 1;
 	pub static UseUserConfiguration: bool = true;
+	pub const MaxSponsoredCalls: u32 = 4;
 }
 
 impl frame_system::Config for Runtime {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = BlockWeights;
 	type BlockLength = ();
-	type DbWeight = ();
+	type DbWeight = frame_support::weights::constants::RocksDbWeight;
 	type RuntimeOrigin = RuntimeOrigin;
 	type Index = u64;
 	type BlockNumber = u64;
@@ -249,6 +260,23 @@ impl pallet_asset_tx_payment::Config for Runtime {
 	type HoldIdentifierValue = ();
 
 	type HoldIdentifier = ();
+
+	type MaxSponsoredCalls = MaxSponsoredCalls;
+	type WrapperCalls = IsUtilityCall;
+}
+
+pub struct IsUtilityCall;
+impl Contains<RuntimeCall> for IsUtilityCall {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(call, RuntimeCall::Utility(_))
+	}
+}
+
+impl pallet_utility::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type PalletsOrigin = OriginCaller;
+	type WeightInfo = ();
 }
 
 pub struct ExtBuilder {
@@ -721,3 +749,488 @@ fn post_dispatch_fee_is_zero_if_unsigned_pre_dispatch_fee_is_zero() {
 			assert_eq!(Assets::balance(asset_id, &caller), balance);
 		});
 }
+
+fn sponsorship_policy(
+	allowed_calls: Vec<CallIndex>,
+	user_budget: Balance,
+) -> SponsorshipPolicyOf<Runtime> {
+	SponsorshipPolicy {
+		allowed_calls: allowed_calls.try_into().unwrap(),
+		user_budget,
+		period_budget: 1_000,
+		period: 10,
+		expiry: 100,
+	}
+}
+
+#[test]
+fn sponsored_transaction_is_paid_by_sponsor() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		// `CALL` is the first call of the second pallet
+		assert_ok!(AssetTxPayment::set_sponsorship_policy(
+			RuntimeOrigin::signed(2),
+			sponsorship_policy(vec![(1, 0)], 100),
+		));
+		let len = 10;
+		let pre = ChargeAssetTxPayment::<Runtime>::from(0, None)
+			.with_sponsor(2)
+			.pre_dispatch(&1, CALL, &info_from_weight(5), len)
+			.unwrap();
+		assert_eq!(Balances::free_balance(1), 1_000);
+		assert_eq!(Balances::free_balance(2), 2_000 - 5 - 5 - 10);
+		assert_eq!(SponsorSpending::<Runtime>::get(2), Some((0, 20)));
+		assert_eq!(SponsoredSpending::<Runtime>::get(2, 1), Some((0, 20)));
+
+		assert_ok!(ChargeAssetTxPayment::<Runtime>::post_dispatch(
+			Some(pre),
+			&info_from_weight(5),
+			&post_info_from_weight(0),
+			len,
+			&Ok(())
+		));
+		// refund goes to the sponsor, and back to its budgets
+		assert_eq!(Balances::free_balance(1), 1_000);
+		assert_eq!(Balances::free_balance(2), 2_000 - 5 - 10);
+		assert_eq!(SponsorSpending::<Runtime>::get(2), Some((0, 15)));
+		assert_eq!(SponsoredSpending::<Runtime>::get(2, 1), Some((0, 15)));
+	});
+}
+
+#[test]
+fn wrapper_calls_are_only_sponsored_by_policies_allowing_every_call() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		// `utility.batch` of the only sponsored call
+		let batch =
+			&RuntimeCall::Utility(pallet_utility::Call::batch { calls: vec![CALL.clone()] });
+		assert_ok!(AssetTxPayment::set_sponsorship_policy(
+			RuntimeOrigin::signed(2),
+			sponsorship_policy(vec![(1, 0), (6, 0)], 100),
+		));
+		ChargeAssetTxPayment::<Runtime>::from(0, None)
+			.with_sponsor(2)
+			.pre_dispatch(&1, batch, &info_from_weight(5), 10)
+			.unwrap();
+		assert_eq!(Balances::free_balance(1), 1_000 - 5 - 5 - 10);
+		assert_eq!(Balances::free_balance(2), 2_000);
+
+		assert_ok!(AssetTxPayment::set_sponsorship_policy(
+			RuntimeOrigin::signed(2),
+			sponsorship_policy(vec![], 100),
+		));
+		ChargeAssetTxPayment::<Runtime>::from(0, None)
+			.with_sponsor(2)
+			.pre_dispatch(&1, batch, &info_from_weight(5), 10)
+			.unwrap();
+		assert_eq!(Balances::free_balance(1), 1_000 - 5 - 5 - 10);
+		assert_eq!(Balances::free_balance(2), 2_000 - 5 - 5 - 10);
+	});
+}
+
+#[test]
+fn signer_pays_for_calls_not_sponsored() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AssetTxPayment::set_sponsorship_policy(
+			RuntimeOrigin::signed(2),
+			sponsorship_policy(vec![(0, 0)], 100),
+		));
+		ChargeAssetTxPayment::<Runtime>::from(0, None)
+			.with_sponsor(2)
+			.pre_dispatch(&1, CALL, &info_from_weight(5), 10)
+			.unwrap();
+		assert_eq!(Balances::free_balance(1), 1_000 - 5 - 5 - 10);
+		assert_eq!(Balances::free_balance(2), 2_000);
+
+		// expired policy
+		assert_ok!(AssetTxPayment::set_sponsorship_policy(
+			RuntimeOrigin::signed(2),
+			sponsorship_policy(vec![], 100),
+		));
+		System::set_block_number(100);
+		ChargeAssetTxPayment::<Runtime>::from(0, None)
+			.with_sponsor(2)
+			.pre_dispatch(&1, CALL, &info_from_weight(5), 10)
+			.unwrap();
+		assert_eq!(Balances::free_balance(1), 1_000 - 2 * (5 + 5 + 10));
+		assert_eq!(Balances::free_balance(2), 2_000);
+
+		// no policy
+		ChargeAssetTxPayment::<Runtime>::from(0, None)
+			.with_sponsor(3)
+			.pre_dispatch(&1, CALL, &info_from_weight(5), 10)
+			.unwrap();
+		assert_eq!(Balances::free_balance(1), 1_000 - 3 * (5 + 5 + 10));
+		assert_eq!(Balances::free_balance(3), 3_000);
+	});
+}
+
+#[test]
+fn sponsorship_budget_resets_every_period() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AssetTxPayment::set_sponsorship_policy(
+			RuntimeOrigin::signed(2),
+			sponsorship_policy(vec![], 30),
+		));
+		let sponsored_pre_dispatch = || {
+			ChargeAssetTxPayment::<Runtime>::from(0, None)
+				.with_sponsor(2)
+				.pre_dispatch(&1, CALL, &info_from_weight(5), 10)
+				.unwrap();
+		};
+		sponsored_pre_dispatch();
+		assert_eq!(Balances::free_balance(2), 2_000 - 20);
+		// over the budget of the user
+		sponsored_pre_dispatch();
+		assert_eq!(Balances::free_balance(1), 1_000 - 20);
+		assert_eq!(Balances::free_balance(2), 2_000 - 20);
+
+		System::set_block_number(10);
+		sponsored_pre_dispatch();
+		assert_eq!(Balances::free_balance(1), 1_000 - 20);
+		assert_eq!(Balances::free_balance(2), 2_000 - 2 * 20);
+		assert_eq!(SponsoredSpending::<Runtime>::get(2, 1), Some((1, 20)));
+	});
+}
+
+#[test]
+fn sponsorship_policy_can_be_removed() {
+	new_test_ext().execute_with(|| {
+		let mut policy = sponsorship_policy(vec![], 100);
+		policy.period = 0;
+		assert_noop!(
+			AssetTxPayment::set_sponsorship_policy(RuntimeOrigin::signed(2), policy),
+			Error::<Runtime>::ZeroSponsorshipPeriod,
+		);
+		assert_noop!(
+			AssetTxPayment::remove_sponsorship_policy(RuntimeOrigin::signed(2)),
+			Error::<Runtime>::SponsorshipPolicyNotFound,
+		);
+		assert_ok!(AssetTxPayment::set_sponsorship_policy(
+			RuntimeOrigin::signed(2),
+			sponsorship_policy(vec![], 100),
+		));
+		assert_ok!(AssetTxPayment::remove_sponsorship_policy(RuntimeOrigin::signed(2)));
+		assert_eq!(AssetTxPayment::sponsorship_policies(2), None);
+	});
+}
+
+#[test]
+fn spending_of_removed_policy_is_removed_when_idle() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AssetTxPayment::set_sponsorship_policy(
+			RuntimeOrigin::signed(2),
+			sponsorship_policy(vec![], 100),
+		));
+		ChargeAssetTxPayment::<Runtime>::from(0, None)
+			.with_sponsor(2)
+			.pre_dispatch(&1, CALL, &info_from_weight(5), 10)
+			.unwrap();
+		assert_ok!(AssetTxPayment::remove_sponsorship_policy(RuntimeOrigin::signed(2)));
+		assert_eq!(SponsorSpending::<Runtime>::get(2), None);
+		assert_eq!(SponsoredSpending::<Runtime>::get(2, 1), Some((0, 20)));
+		assert_noop!(
+			AssetTxPayment::set_sponsorship_policy(
+				RuntimeOrigin::signed(2),
+				sponsorship_policy(vec![], 100)
+			),
+			Error::<Runtime>::SponsoredSpendingBeingRemoved,
+		);
+
+		// 1. Nothing is removed without enough weight left.
+		assert_eq!(AssetTxPayment::on_idle(1, Weight::zero()), Weight::zero());
+		assert_eq!(SponsoredSpending::<Runtime>::get(2, 1), Some((0, 20)));
+
+		// 2. The spending is removed, after which the sponsor can set a new policy.
+		AssetTxPayment::on_idle(1, Weight::MAX);
+		assert_eq!(SponsoredSpending::<Runtime>::get(2, 1), None);
+		assert!(!RemovedSponsors::<Runtime>::contains_key(2));
+		assert_ok!(AssetTxPayment::set_sponsorship_policy(
+			RuntimeOrigin::signed(2),
+			sponsorship_policy(vec![], 100),
+		));
+	});
+}
+
+#[test]
+fn sponsor_does_not_pay_the_tip() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AssetTxPayment::set_sponsorship_policy(
+			RuntimeOrigin::signed(2),
+			sponsorship_policy(vec![], 100),
+		));
+		let block_weight = System::block_weight().total();
+		let pre = ChargeAssetTxPayment::<Runtime>::from(50, None)
+			.with_sponsor(2)
+			.pre_dispatch(&1, CALL, &info_from_weight(5), 10)
+			.unwrap();
+		assert_eq!(pre.0, 0);
+		assert_eq!(Balances::free_balance(1), 1_000);
+		assert_eq!(Balances::free_balance(2), 2_000 - 5 - 5 - 10);
+		assert_eq!(SponsoredSpending::<Runtime>::get(2, 1), Some((0, 20)));
+		// reading and writing the spending of the sponsor is accounted for
+		assert!(System::block_weight().total().all_gt(block_weight));
+	});
+}
+
+parameter_types! {
+	pub const NativeInAssets: AssetId = 0;
+	pub const MaxFeeSlippage: Permill = Permill::from_percent(10);
+	pub const MaxFeeDeviation: Permill = Permill::from_percent(10);
+	pub const FeeAccount: AccountId = 4321;
+	pub const FeeSwapWeight: Weight = Weight::from_parts(100, 0);
+	/// Native asset received for one of `SWAPPABLE_ASSET` at the spot price.
+	pub storage SpotPrice: Balance = 2;
+}
+
+const SWAPPABLE_ASSET: AssetId = 2;
+
+/// Prices no asset, so that all fees are paid by swapping.
+pub struct Unpriced;
+impl ConversionToAssetBalance<Balance, AssetId, Balance> for Unpriced {
+	type Error = ();
+	fn to_asset_balance(_balance: Balance, _asset_id: AssetId) -> Result<Balance, ()> {
+		Err(())
+	}
+}
+
+/// Exchanges `SpotPrice` of `SWAPPABLE_ASSET` for one native asset, without fees, with a TWAP
+/// of two.
+pub struct FixedPriceDex;
+impl FixedPriceDex {
+	fn pair(pool_id: CurrencyPair<AssetId>) -> Result<(), DispatchError> {
+		let mut pair = [pool_id.base, pool_id.quote];
+		pair.sort();
+		if pair == [NativeInAssets::get(), SWAPPABLE_ASSET] {
+			Ok(())
+		} else {
+			Err(DispatchError::Other("no route"))
+		}
+	}
+
+	fn price(amount: AssetAmount<AssetId, Balance>) -> Balance {
+		if amount.asset_id == SWAPPABLE_ASSET {
+			amount.amount / SpotPrice::get()
+		} else {
+			amount.amount * SpotPrice::get()
+		}
+	}
+}
+
+impl Twap for FixedPriceDex {
+	type AssetId = AssetId;
+	type PoolId = CurrencyPair<AssetId>;
+
+	fn get_twap(pool_id: Self::PoolId, asset_id: AssetId) -> Result<Rate, DispatchError> {
+		Self::pair(pool_id)?;
+		if asset_id == SWAPPABLE_ASSET {
+			Ok(Rate::saturating_from_rational(1, 2))
+		} else {
+			Ok(Rate::saturating_from_integer(2))
+		}
+	}
+}
+
+impl composable_traits::dex::Amm for FixedPriceDex {
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type AccountId = AccountId;
+	type PoolId = CurrencyPair<AssetId>;
+
+	fn pool_exists(pool_id: Self::PoolId) -> bool {
+		Self::pair(pool_id).is_ok()
+	}
+
+	fn assets(
+		_pool_id: Self::PoolId,
+	) -> Result<sp_std::collections::btree_map::BTreeMap<AssetId, Permill>, DispatchError> {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn lp_token(_pool_id: Self::PoolId) -> Result<AssetId, DispatchError> {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn redeemable_assets_for_lp_tokens(
+		_pool_id: Self::PoolId,
+		_lp_amount: Balance,
+	) -> Result<sp_std::collections::btree_map::BTreeMap<AssetId, Balance>, DispatchError> {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn simulate_add_liquidity(
+		_who: &AccountId,
+		_pool_id: Self::PoolId,
+		_amounts: sp_std::collections::btree_map::BTreeMap<AssetId, Balance>,
+	) -> Result<Balance, DispatchError> {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn simulate_remove_liquidity(
+		_who: &AccountId,
+		_pool_id: Self::PoolId,
+		_lp_amount: Balance,
+		_min_amounts: sp_std::collections::btree_map::BTreeMap<AssetId, Balance>,
+	) -> Result<sp_std::collections::btree_map::BTreeMap<AssetId, Balance>, DispatchError> {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn spot_price(
+		pool_id: Self::PoolId,
+		base_asset: AssetAmount<AssetId, Balance>,
+		quote_asset_id: AssetId,
+		_calculate_with_fees: bool,
+	) -> Result<SwapResult<AssetId, Balance>, DispatchError> {
+		Self::pair(pool_id)?;
+		Ok(SwapResult::new(quote_asset_id, Self::price(base_asset), quote_asset_id, 0))
+	}
+
+	fn add_liquidity(
+		_who: &AccountId,
+		_pool_id: Self::PoolId,
+		_assets: sp_std::collections::btree_map::BTreeMap<AssetId, Balance>,
+		_min_mint_amount: Balance,
+		_keep_alive: bool,
+	) -> Result<Balance, DispatchError> {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn remove_liquidity(
+		_who: &AccountId,
+		_pool_id: Self::PoolId,
+		_lp_amount: Balance,
+		_min_receive: sp_std::collections::btree_map::BTreeMap<AssetId, Balance>,
+	) -> Result<sp_std::collections::btree_map::BTreeMap<AssetId, Balance>, DispatchError> {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn do_buy(
+		_who: &AccountId,
+		_pool_id: Self::PoolId,
+		_in_asset_id: AssetId,
+		_out_asset: AssetAmount<AssetId, Balance>,
+		_keep_alive: bool,
+	) -> Result<SwapResult<AssetId, Balance>, DispatchError> {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn do_swap(
+		who: &AccountId,
+		pool_id: Self::PoolId,
+		in_asset: AssetAmount<AssetId, Balance>,
+		min_receive: AssetAmount<AssetId, Balance>,
+		_keep_alive: bool,
+	) -> Result<SwapResult<AssetId, Balance>, DispatchError> {
+		Self::pair(pool_id)?;
+		let amount = Self::price(in_asset);
+		ensure!(amount >= min_receive.amount, DispatchError::Other("slippage"));
+		Assets::burn_from(
+			in_asset.asset_id,
+			who,
+			in_asset.amount,
+			Precision::Exact,
+			Fortitude::Polite,
+		)?;
+		Assets::mint_into(min_receive.asset_id, who, amount)?;
+		Ok(SwapResult::new(min_receive.asset_id, amount, min_receive.asset_id, 0))
+	}
+}
+
+type SwapAdapter = SwapFungiblesAdapter<
+	Unpriced,
+	CreditToBlockAuthor,
+	FixedPriceDex,
+	NativeInAssets,
+	MaxFeeSlippage,
+	MaxFeeDeviation,
+	FeeAccount,
+	FeeSwapWeight,
+>;
+
+#[test]
+fn fee_in_asset_without_price_is_swapped_to_native() {
+	new_test_ext().execute_with(|| {
+		let caller = 1;
+		assert_ok!(Assets::mint_into(SWAPPABLE_ASSET, &caller, 1_000));
+		// 20 native are quoted 40 of the asset, with 10% slippage
+		let paid = <SwapAdapter as OnChargeAssetTransaction<Runtime>>::withdraw_fee(
+			&caller,
+			CALL,
+			&info_from_weight(5),
+			SWAPPABLE_ASSET,
+			20,
+			0,
+		)
+		.unwrap();
+		assert_eq!(paid.peek(), 44);
+		assert_eq!(Assets::balance(SWAPPABLE_ASSET, &caller), 1_000 - 44);
+
+		assert_ok!(<SwapAdapter as OnChargeAssetTransaction<Runtime>>::correct_and_deposit_fee(
+			&caller,
+			&info_from_weight(5),
+			&default_post_info(),
+			10,
+			0,
+			paid,
+		));
+		// refunded in the asset paid
+		assert_eq!(Assets::balance(SWAPPABLE_ASSET, &caller), 1_000 - 22);
+		assert_eq!(Assets::balance(SWAPPABLE_ASSET, &FeeAccount::get()), 0);
+		assert_eq!(Assets::balance(NativeInAssets::get(), &FeeAccount::get()), 11);
+		assert_eq!(Assets::balance(SWAPPABLE_ASSET, &BLOCK_AUTHOR), 0);
+	});
+}
+
+#[test]
+fn fee_in_asset_without_price_or_route_is_rejected() {
+	new_test_ext().execute_with(|| {
+		let caller = 1;
+		let asset_id = 3;
+		assert_ok!(Assets::mint_into(asset_id, &caller, 1_000));
+		assert!(<SwapAdapter as OnChargeAssetTransaction<Runtime>>::withdraw_fee(
+			&caller,
+			CALL,
+			&info_from_weight(5),
+			asset_id,
+			20,
+			0,
+		)
+		.is_err());
+		assert_eq!(Assets::balance(asset_id, &caller), 1_000);
+	});
+}
+
+#[test]
+fn fee_in_asset_with_spot_price_deviating_from_twap_is_rejected() {
+	new_test_ext().execute_with(|| {
+		let caller = 1;
+		assert_ok!(Assets::mint_into(SWAPPABLE_ASSET, &caller, 1_000));
+		SpotPrice::set(&3);
+		assert!(<SwapAdapter as OnChargeAssetTransaction<Runtime>>::withdraw_fee(
+			&caller,
+			CALL,
+			&info_from_weight(5),
+			SWAPPABLE_ASSET,
+			20,
+			0,
+		)
+		.is_err());
+		assert_eq!(Assets::balance(SWAPPABLE_ASSET, &caller), 1_000);
+	});
+}
+
+#[test]
+fn swap_weight_is_charged_for_assets_without_price() {
+	assert_eq!(
+		<SwapAdapter as OnChargeAssetTransaction<Runtime>>::correction_weight(SWAPPABLE_ASSET),
+		FeeSwapWeight::get()
+	);
+	assert_eq!(
+		<Runtime as Config>::OnChargeAssetTransaction::correction_weight(SWAPPABLE_ASSET),
+		Weight::zero()
+	);
+}
@@ -45,6 +45,8 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for asset_tx_payment.
 pub trait WeightInfo {
 	fn set_payment_asset() -> Weight;
+	fn set_sponsorship_policy() -> Weight;
+	fn remove_sponsorship_policy() -> Weight;
 }
 
 /// Weights for asset_tx_payment using the Substrate node and recommended hardware.
@@ -59,6 +61,21 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	// Storage: AssetTxPayment RemovedSponsors (r:1 w:0)
+	// Storage: AssetTxPayment SponsorshipPolicies (r:0 w:1)
+	fn set_sponsorship_policy() -> Weight {
+		Weight::from_parts(23_418_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: AssetTxPayment SponsorshipPolicies (r:1 w:1)
+	// Storage: AssetTxPayment SponsorSpending (r:0 w:1)
+	// Storage: AssetTxPayment RemovedSponsors (r:0 w:1)
+	fn remove_sponsorship_policy() -> Weight {
+		Weight::from_parts(27_211_000_u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -72,4 +89,19 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	// Storage: AssetTxPayment RemovedSponsors (r:1 w:0)
+	// Storage: AssetTxPayment SponsorshipPolicies (r:0 w:1)
+	fn set_sponsorship_policy() -> Weight {
+		Weight::from_parts(23_418_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: AssetTxPayment SponsorshipPolicies (r:1 w:1)
+	// Storage: AssetTxPayment SponsorSpending (r:0 w:1)
+	// Storage: AssetTxPayment RemovedSponsors (r:0 w:1)
+	fn remove_sponsorship_policy() -> Weight {
+		Weight::from_parts(27_211_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
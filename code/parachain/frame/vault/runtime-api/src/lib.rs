@@ -0,0 +1,30 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use composable_support::rpc_helpers::SafeRpcWrapper;
+
+// Vault Runtime API declaration. Implemented for each runtime at
+// `runtime/<runtime-name>/src/lib.rs`.
+sp_api::decl_runtime_apis! {
+	pub trait VaultRuntimeApi<VaultId, Balance>
+	where
+		VaultId: Codec,
+		Balance: Codec,
+	{
+		/// Amount of underlying asset redeemed by burning `lp_amount` of the vault LP token.
+		/// `None` if the vault does not exist.
+		fn lp_share_value(
+			vault_id: VaultId,
+			lp_amount: Balance,
+		) -> Option<SafeRpcWrapper<Balance>>;
+
+		/// Amount of LP token minted by depositing `asset_amount` of the underlying asset.
+		/// `None` if the vault does not exist.
+		fn amount_of_lp_token_for_added_liquidity(
+			vault_id: VaultId,
+			asset_amount: Balance,
+		) -> Option<SafeRpcWrapper<Balance>>;
+	}
+}
@@ -0,0 +1,128 @@
+use super::*;
+use crate::Pallet as Vault;
+use composable_traits::vault::{CapabilityVault, StrategicVault, Vault as VaultTrait, VaultConfig};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{
+	assert_ok,
+	traits::{
+		fungible::Mutate as FungibleMutate,
+		fungibles::{Inspect, Mutate},
+		Get,
+	},
+};
+use frame_system::RawOrigin;
+use sp_runtime::Perquintill;
+use sp_std::collections::btree_map::BTreeMap;
+
+const UNIT: u128 = 1_000_000_000_000;
+
+fn strategy<T: Config>() -> T::AccountId {
+	account("strategy", 0, 0)
+}
+
+fn vault_config<T: Config>(asset_id: T::AssetId, manager: T::AccountId) -> VaultConfigOf<T> {
+	VaultConfig {
+		asset_id,
+		manager,
+		reserved: Perquintill::from_percent(50),
+		strategies: BTreeMap::from([(strategy::<T>(), Perquintill::from_percent(50))]),
+	}
+}
+
+fn create_vault<T: Config>(
+	asset_id: T::AssetId,
+	manager: T::AccountId,
+	deposit: T::Balance,
+) -> T::VaultId
+where
+	T::Balance: From<u128>,
+{
+	assert_ok!(T::NativeCurrency::mint_into(&manager, (1_000_000 * UNIT).into()));
+	assert_ok!(Vault::<T>::create(
+		RawOrigin::Signed(manager.clone()).into(),
+		vault_config::<T>(asset_id, manager),
+		deposit
+	));
+	VaultCount::<T>::get()
+}
+
+benchmarks! {
+  where_clause { where T::BlockNumber: From<u32>, T::Balance: From<u128>, T::AssetId: From<u128> }
+	create {
+		let caller: T::AccountId = whitelisted_caller();
+		assert_ok!(T::NativeCurrency::mint_into(&caller, (1_000_000 * UNIT).into()));
+		let config = vault_config::<T>(100.into(), caller.clone());
+		let deposit = T::ExistentialDeposit::get();
+	}: _(RawOrigin::Signed(caller), config, deposit)
+
+	claim_surcharge {
+		let caller: T::AccountId = whitelisted_caller();
+		let vault_id = create_vault::<T>(100.into(), caller.clone(), T::CreationDeposit::get());
+		frame_system::Pallet::<T>::set_block_number(2.into());
+	}: _(RawOrigin::Signed(caller), vault_id, None)
+
+	add_surcharge {
+		let caller: T::AccountId = whitelisted_caller();
+		let vault_id = create_vault::<T>(100.into(), caller.clone(), T::CreationDeposit::get());
+		let amount = T::CreationDeposit::get();
+	}: _(RawOrigin::Signed(caller), vault_id, amount)
+
+	delete_tombstoned {
+		let caller: T::AccountId = whitelisted_caller();
+		let vault_id = create_vault::<T>(100.into(), caller.clone(), T::CreationDeposit::get());
+		assert_ok!(<Vault<T> as CapabilityVault>::tombstone(&vault_id));
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now + T::TombstoneDuration::get());
+	}: _(RawOrigin::Signed(caller), vault_id)
+
+	deposit {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset_id = 100.into();
+		let vault_id = create_vault::<T>(asset_id, caller.clone(), T::ExistentialDeposit::get());
+		let amount: T::Balance = (1_000 * UNIT).into();
+		assert_ok!(T::Assets::mint_into(asset_id, &caller, amount));
+	}: _(RawOrigin::Signed(caller), vault_id, amount)
+
+	withdraw {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset_id = 100.into();
+		let vault_id = create_vault::<T>(asset_id, caller.clone(), T::ExistentialDeposit::get());
+		let amount: T::Balance = (1_000 * UNIT).into();
+		assert_ok!(T::Assets::mint_into(asset_id, &caller, amount));
+		let lp_amount = <Vault<T> as VaultTrait>::deposit(&vault_id, &caller, amount)
+			.expect("vault accepts deposits; qed;");
+	}: _(RawOrigin::Signed(caller), vault_id, lp_amount)
+
+	emergency_shutdown {
+		let caller: T::AccountId = whitelisted_caller();
+		let vault_id = create_vault::<T>(100.into(), caller, T::ExistentialDeposit::get());
+	}: _(RawOrigin::Root, vault_id)
+
+	start {
+		let caller: T::AccountId = whitelisted_caller();
+		let vault_id = create_vault::<T>(100.into(), caller, T::ExistentialDeposit::get());
+		assert_ok!(<Vault<T> as CapabilityVault>::stop(&vault_id));
+	}: _(RawOrigin::Root, vault_id)
+
+	liquidate {
+		let caller: T::AccountId = whitelisted_caller();
+		let vault_id = create_vault::<T>(100.into(), caller.clone(), T::ExistentialDeposit::get());
+	}: _(RawOrigin::Signed(caller), vault_id, strategy::<T>())
+
+	report {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset_id = 100.into();
+		let vault_id = create_vault::<T>(asset_id, caller.clone(), T::ExistentialDeposit::get());
+		let amount: T::Balance = (1_000 * UNIT).into();
+		assert_ok!(T::Assets::mint_into(asset_id, &caller, amount));
+		assert_ok!(<Vault<T> as VaultTrait>::deposit(&vault_id, &caller, amount));
+		let strategy = strategy::<T>();
+		let withdrawn: T::Balance = (100 * UNIT).into();
+		assert_ok!(<Vault<T> as StrategicVault>::withdraw(&vault_id, &strategy, withdrawn));
+		let balance: T::Balance = T::Assets::balance(asset_id, &strategy) + (10 * UNIT).into();
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now + 1_000.into());
+	}: _(RawOrigin::Signed(strategy.clone()), vault_id, strategy, balance)
+}
+
+impl_benchmark_test_suite!(Vault, crate::mock::new_test_ext(), crate::mock::Test);
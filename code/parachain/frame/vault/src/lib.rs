@@ -0,0 +1,1144 @@
+//! # Vault Pallet
+//!
+//! Pools a single asset and lends it to strategies, such as other pallets, which put the capital
+//! to use. Depositors receive an LP token representing their share of the vault, including the
+//! funds currently held by strategies.
+//!
+//! ## Overview
+//!
+//! - A vault is created with an underlying asset, a manager and a set of strategies, each with an
+//!   allocation which caps the share of the vault it may hold. The remaining `reserved` share stays
+//!   idle in the vault account to serve withdrawals.
+//! - Strategies query [`StrategicVault::available_funds`] to know whether they should withdraw,
+//!   return funds or liquidate, and periodically report the balance they hold so that the LP token
+//!   value reflects gains and losses. A report can move the balance by at most
+//!   [`Config::MaxReportChangePerBlock`] of the vault per block since the previous report.
+//! - The first deposit locks [`Config::LockedLiquidity`] LP tokens in the vault account, so the
+//!   share value can't be manipulated while the vault is almost empty.
+//! - Vaults created with less than [`Config::ExistentialDeposit`] pay rent every block out of their
+//!   creation deposit. Anyone can claim the accrued rent, and a vault which can't pay its rent is
+//!   tombstoned: only withdrawals are allowed and, after [`Config::TombstoneDuration`], an emptied
+//!   vault can be deleted.
+//!
+//! ## Interface
+//!
+//! - `create`: create a vault, paying the creation deposit.
+//! - `deposit`/`withdraw`: exchange the underlying asset for LP tokens and back.
+//! - `claim_surcharge`/`add_surcharge`: collect or top up the rent of a vault.
+//! - `delete_tombstoned`: remove a tombstoned and emptied vault.
+//! - `emergency_shutdown`/`start`: stop and restart every functionality of a vault.
+//! - `liquidate`: order a strategy to return all of its funds.
+//! - `report`: update the balance held by a strategy.
+#![cfg_attr(
+	not(test),
+	warn(
+		clippy::disallowed_methods,
+		clippy::disallowed_types,
+		clippy::todo,
+		clippy::unwrap_used,
+		clippy::panic
+	)
+)]
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(
+	bad_style,
+	bare_trait_objects,
+	improper_ctypes,
+	non_shorthand_field_patterns,
+	no_mangle_generic_items,
+	overflowing_literals,
+	path_statements,
+	patterns_in_fns_without_body,
+	private_in_public,
+	unconditional_recursion,
+	unused_allocation,
+	unused_comparisons,
+	unused_parens,
+	while_true,
+	trivial_casts,
+	trivial_numeric_casts,
+	unused_extern_crates
+)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarking;
+
+pub mod models;
+
+pub use crate::weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::{
+		models::{Capabilities, StrategyOverview, VaultInfo},
+		WeightInfo,
+	};
+	use codec::FullCodec;
+	use composable_support::{
+		abstractions::{
+			nonce::Nonce,
+			utils::{
+				increment::{Increment, SafeIncrement},
+				start_at::OneInit,
+			},
+		},
+		math::safe::{safe_multiply_by_rational, SafeAdd, SafeArithmetic},
+	};
+	use composable_traits::{
+		assets::{AssetInfo, CreateAsset},
+		currency::BalanceLike,
+		defi::Rate,
+		vault::{
+			CapabilityVault, Deposit, FundsAvailability, ReportableStrategicVault, StrategicVault,
+			Vault, VaultConfig,
+		},
+	};
+	use core::fmt::Debug;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			fungible::{Inspect as FungibleInspect, Mutate as FungibleMutate},
+			fungibles::{Inspect, Mutate},
+			tokens::{Fortitude, Precision, Preservation},
+		},
+		transactional, PalletId,
+	};
+	use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+	use sp_runtime::{
+		traits::{AccountIdConversion, CheckedAdd, One, SaturatedConversion, Saturating, Zero},
+		ArithmeticError, FixedPointNumber, PerThing, Perquintill,
+	};
+
+	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+	pub type AssetIdOf<T> = <T as Config>::AssetId;
+	pub type BalanceOf<T> = <T as Config>::Balance;
+	pub type BlockNumberOf<T> = <T as frame_system::Config>::BlockNumber;
+	pub type VaultInfoOf<T> =
+		VaultInfo<AccountIdOf<T>, BalanceOf<T>, AssetIdOf<T>, BlockNumberOf<T>>;
+	pub type VaultConfigOf<T> = VaultConfig<AccountIdOf<T>, AssetIdOf<T>>;
+	pub type DepositOf<T> = Deposit<BalanceOf<T>, BlockNumberOf<T>>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		#[allow(missing_docs)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Type representing the unique ID of an asset.
+		type AssetId: FullCodec
+			+ MaxEncodedLen
+			+ Eq
+			+ PartialEq
+			+ Copy
+			+ Clone
+			+ MaybeSerializeDeserialize
+			+ Debug
+			+ Default
+			+ TypeInfo
+			+ Ord;
+
+		/// Type representing the Balance of an account.
+		type Balance: BalanceLike + Into<u128>;
+
+		/// Type representing the unique ID of a vault.
+		type VaultId: FullCodec
+			+ MaxEncodedLen
+			+ Default
+			+ Debug
+			+ TypeInfo
+			+ Eq
+			+ PartialEq
+			+ Ord
+			+ Copy
+			+ Zero
+			+ One
+			+ SafeArithmetic;
+
+		/// Factory to create new LP tokens.
+		type LPTokenFactory: CreateAsset<LocalAssetId = Self::AssetId, Balance = Self::Balance>;
+
+		/// Underlying assets and LP tokens.
+		type Assets: Mutate<AccountIdOf<Self>, Balance = BalanceOf<Self>, AssetId = AssetIdOf<Self>>
+			+ Inspect<AccountIdOf<Self>, Balance = BalanceOf<Self>, AssetId = AssetIdOf<Self>>;
+
+		/// Currency in which creation deposits and rent are paid.
+		type NativeCurrency: FungibleMutate<AccountIdOf<Self>, Balance = BalanceOf<Self>>
+			+ FungibleInspect<AccountIdOf<Self>, Balance = BalanceOf<Self>>;
+
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Maximum number of strategies of a vault.
+		#[pallet::constant]
+		type MaxStrategies: Get<u32>;
+
+		/// Minimum deposit to create a vault.
+		#[pallet::constant]
+		type CreationDeposit: Get<BalanceOf<Self>>;
+
+		/// Deposit from which a vault is exempt from rent.
+		#[pallet::constant]
+		type ExistentialDeposit: Get<BalanceOf<Self>>;
+
+		/// Rent paid every block by vaults below [`Config::ExistentialDeposit`].
+		#[pallet::constant]
+		type RentPerBlock: Get<BalanceOf<Self>>;
+
+		/// Number of blocks a vault stays tombstoned before it can be deleted.
+		#[pallet::constant]
+		type TombstoneDuration: Get<BlockNumberOf<Self>>;
+
+		/// Minimum amount of underlying asset to deposit.
+		#[pallet::constant]
+		type MinimumDeposit: Get<BalanceOf<Self>>;
+
+		/// Minimum amount of LP token to burn on withdrawal.
+		#[pallet::constant]
+		type MinimumWithdrawal: Get<BalanceOf<Self>>;
+
+		/// Existential deposit of the LP tokens.
+		#[pallet::constant]
+		type LPTokenExistentialDeposit: Get<BalanceOf<Self>>;
+
+		/// LP tokens of the first deposit locked in the vault account for good, so the value of
+		/// a share can't be inflated while the vault is almost empty.
+		#[pallet::constant]
+		type LockedLiquidity: Get<BalanceOf<Self>>;
+
+		/// Maximum change of a strategy balance a report can make, as a share of the assets under
+		/// management of the vault per block elapsed since the previous report.
+		#[pallet::constant]
+		type MaxReportChangePerBlock: Get<Perquintill>;
+
+		/// Origin allowed to stop and restart vaults.
+		type EmergencyShutdownOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Vault `vault_id` was created, minting `lp_token_id` on deposits.
+		VaultCreated { vault_id: T::VaultId, lp_token_id: T::AssetId },
+		/// `account` deposited `asset_amount` and received `lp_amount`.
+		Deposited {
+			vault_id: T::VaultId,
+			account: T::AccountId,
+			asset_amount: T::Balance,
+			lp_amount: T::Balance,
+		},
+		/// `account` burnt `lp_amount` and received `asset_amount`.
+		Withdrawn {
+			vault_id: T::VaultId,
+			account: T::AccountId,
+			lp_amount: T::Balance,
+			asset_amount: T::Balance,
+		},
+		/// Every functionality of the vault has been stopped.
+		EmergencyShutdown { vault_id: T::VaultId },
+		/// The vault has been restarted after an emergency shutdown.
+		VaultStarted { vault_id: T::VaultId },
+		/// `strategy` withdrew `amount` from the vault.
+		StrategyWithdrawn { vault_id: T::VaultId, strategy: T::AccountId, amount: T::Balance },
+		/// `strategy` returned `amount` to the vault.
+		StrategyDeposited { vault_id: T::VaultId, strategy: T::AccountId, amount: T::Balance },
+		/// `strategy` reported holding `balance`.
+		StrategyReported { vault_id: T::VaultId, strategy: T::AccountId, balance: T::Balance },
+		/// The allocation of `strategy` was set to zero, it must return its funds.
+		StrategyLiquidated { vault_id: T::VaultId, strategy: T::AccountId },
+		/// `amount` of rent was collected and paid to `beneficiary`.
+		RentClaimed { vault_id: T::VaultId, beneficiary: T::AccountId, amount: T::Balance },
+		/// The rent deposit was topped up by `amount`.
+		SurchargeAdded { vault_id: T::VaultId, amount: T::Balance },
+		/// The vault could not pay its rent and was tombstoned.
+		VaultTombstoned { vault_id: T::VaultId },
+		/// The tombstoned vault was deleted, its idle `swept` assets and `refunded` rent deposit
+		/// were paid to the manager.
+		VaultDeleted {
+			vault_id: T::VaultId,
+			manager: T::AccountId,
+			swept: T::Balance,
+			refunded: T::Balance,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		VaultDoesNotExist,
+		/// The allocations of the strategies and the reserved share must sum to 100%.
+		AllocationMustSumToOne,
+		TooManyStrategies,
+		/// The creation deposit is below [`Config::CreationDeposit`].
+		InsufficientCreationDeposit,
+		OnlyManagerCanDoThisOperation,
+		AccountIsNotManagerOrStrategy,
+		StrategyNotRegistered,
+		/// The strategy would hold more than its allocation.
+		StrategyCapExceeded,
+		DepositsHalted,
+		WithdrawalsHalted,
+		/// Strategies can't withdraw from a stopped or tombstoned vault.
+		AllocationsHalted,
+		AmountMustGteMinimumDeposit,
+		AmountMustGteMinimumWithdrawal,
+		/// The vault does not hold enough idle funds to serve the withdrawal.
+		NotEnoughLiquidity,
+		/// The vault is exempt from rent or its rent is not due yet.
+		RentNotDue,
+		VaultNotTombstoned,
+		TombstoneDurationNotExceeded,
+		/// LP tokens of the vault are still in circulation.
+		VaultNotEmpty,
+		/// Strategies still hold funds of the vault.
+		StrategiesNotWithdrawn,
+		/// The first deposit must exceed [`Config::LockedLiquidity`].
+		InsufficientFirstDeposit,
+		/// LP tokens are in circulation but the vault holds no asset, shares can't be priced.
+		VaultHasNoAssets,
+		/// The reported balance changes more than [`Config::MaxReportChangePerBlock`] allows.
+		ReportChangeTooLarge,
+	}
+
+	#[allow(clippy::disallowed_types)]
+	#[pallet::storage]
+	pub type VaultCount<T: Config> = StorageValue<_, T::VaultId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn vault_data)]
+	pub type Vaults<T: Config> = StorageMap<_, Blake2_128Concat, T::VaultId, VaultInfoOf<T>>;
+
+	/// Reverse lookup from an LP token to its vault.
+	#[pallet::storage]
+	pub type LpTokensToVaults<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, T::VaultId>;
+
+	/// Strategies of a vault and the capital they hold.
+	#[pallet::storage]
+	pub type CapitalStructure<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::VaultId,
+		Blake2_128Concat,
+		T::AccountId,
+		StrategyOverview<T::Balance, BlockNumberOf<T>>,
+	>;
+
+	#[pallet::storage]
+	#[allow(clippy::disallowed_types)] // Allow for `ValueQuery` because of nonce
+	pub type LpTokenNonce<T: Config> =
+		StorageValue<_, u64, ValueQuery, Nonce<OneInit, SafeIncrement>>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a new vault, paying `deposit_amount` of [`Config::NativeCurrency`].
+		///
+		/// A deposit of at least [`Config::ExistentialDeposit`] exempts the vault from rent,
+		/// otherwise rent is paid every block out of the deposit.
+		///
+		/// Emits `VaultCreated` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::create())]
+		pub fn create(
+			origin: OriginFor<T>,
+			vault: VaultConfigOf<T>,
+			deposit_amount: T::Balance,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			ensure!(
+				deposit_amount >= T::CreationDeposit::get(),
+				Error::<T>::InsufficientCreationDeposit
+			);
+			T::NativeCurrency::transfer(
+				&from,
+				&Self::account_id_of_pallet(),
+				deposit_amount,
+				Preservation::Preserve,
+			)?;
+			let deposit = if deposit_amount >= T::ExistentialDeposit::get() {
+				Deposit::Existential
+			} else {
+				Deposit::Rent {
+					amount: deposit_amount,
+					at: frame_system::Pallet::<T>::block_number(),
+				}
+			};
+			<Self as Vault>::create(deposit, vault)?;
+			Ok(())
+		}
+
+		/// Collect the rent accrued by vault `dest`, paying it to `address` or the caller.
+		/// A vault which can't pay its rent is tombstoned.
+		///
+		/// Emits `RentClaimed` and possibly `VaultTombstoned` events when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::claim_surcharge())]
+		pub fn claim_surcharge(
+			origin: OriginFor<T>,
+			dest: T::VaultId,
+			address: Option<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_claim_surcharge(dest, address.unwrap_or(who))
+		}
+
+		/// Top up the rent deposit of vault `dest`. A tombstoned vault is restored, and the
+		/// vault becomes exempt from rent once its deposit reaches
+		/// [`Config::ExistentialDeposit`].
+		///
+		/// Emits `SurchargeAdded` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::add_surcharge())]
+		pub fn add_surcharge(
+			origin: OriginFor<T>,
+			dest: T::VaultId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			Self::do_add_surcharge(dest, &from, amount)
+		}
+
+		/// Delete a vault which has been tombstoned for at least [`Config::TombstoneDuration`],
+		/// whose LP tokens have all been burnt and whose strategies returned all funds.
+		///
+		/// Remaining idle assets and rent deposit are paid to the manager.
+		///
+		/// Emits `VaultDeleted` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::delete_tombstoned())]
+		pub fn delete_tombstoned(origin: OriginFor<T>, dest: T::VaultId) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_delete_tombstoned(dest)
+		}
+
+		/// Deposit `asset_amount` of the underlying asset in exchange for LP tokens.
+		///
+		/// Emits `Deposited` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::deposit())]
+		pub fn deposit(
+			origin: OriginFor<T>,
+			vault: T::VaultId,
+			asset_amount: T::Balance,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			<Self as Vault>::deposit(&vault, &from, asset_amount)?;
+			Ok(())
+		}
+
+		/// Burn `lp_amount` of LP tokens in exchange for the underlying asset.
+		///
+		/// Emits `Withdrawn` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::withdraw())]
+		pub fn withdraw(
+			origin: OriginFor<T>,
+			vault: T::VaultId,
+			lp_amount: T::Balance,
+		) -> DispatchResult {
+			let to = ensure_signed(origin)?;
+			<Self as Vault>::withdraw(&vault, &to, lp_amount)?;
+			Ok(())
+		}
+
+		/// Stop every functionality of the vault, strategies are asked to liquidate.
+		///
+		/// Emits `EmergencyShutdown` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::emergency_shutdown())]
+		pub fn emergency_shutdown(origin: OriginFor<T>, vault: T::VaultId) -> DispatchResult {
+			T::EmergencyShutdownOrigin::ensure_origin(origin)?;
+			<Self as CapabilityVault>::stop(&vault)?;
+			Self::deposit_event(Event::<T>::EmergencyShutdown { vault_id: vault });
+			Ok(())
+		}
+
+		/// Restart a vault after an emergency shutdown.
+		///
+		/// Emits `VaultStarted` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::start())]
+		pub fn start(origin: OriginFor<T>, vault: T::VaultId) -> DispatchResult {
+			T::EmergencyShutdownOrigin::ensure_origin(origin)?;
+			<Self as CapabilityVault>::start(&vault)?;
+			Self::deposit_event(Event::<T>::VaultStarted { vault_id: vault });
+			Ok(())
+		}
+
+		/// Set the allocation of `strategy` to zero, moving it to the reserved share. The
+		/// strategy is then ordered to return all of its funds. Only the manager can do this.
+		///
+		/// Emits `StrategyLiquidated` event when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::liquidate())]
+		pub fn liquidate(
+			origin: OriginFor<T>,
+			vault: T::VaultId,
+			strategy: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Vaults::<T>::try_mutate(vault, |info| -> DispatchResult {
+				let info = info.as_mut().ok_or(Error::<T>::VaultDoesNotExist)?;
+				ensure!(info.manager == who, Error::<T>::OnlyManagerCanDoThisOperation);
+				CapitalStructure::<T>::try_mutate(vault, &strategy, |overview| {
+					let overview = overview.as_mut().ok_or(Error::<T>::StrategyNotRegistered)?;
+					info.reserved = info.reserved.saturating_add(overview.allocation);
+					overview.allocation = Perquintill::zero();
+					Ok::<_, DispatchError>(())
+				})
+			})?;
+			Self::deposit_event(Event::<T>::StrategyLiquidated { vault_id: vault, strategy });
+			Ok(())
+		}
+
+		/// Report the balance held by the calling strategy, or on its behalf by the manager.
+		///
+		/// Emits `StrategyReported` event when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::report())]
+		pub fn report(
+			origin: OriginFor<T>,
+			vault: T::VaultId,
+			strategy: T::AccountId,
+			balance: T::Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let info = Self::vault_info(&vault)?;
+			ensure!(
+				who == strategy || who == info.manager,
+				Error::<T>::AccountIsNotManagerOrStrategy
+			);
+			<Self as ReportableStrategicVault>::update_strategy_report(&vault, &strategy, &balance)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Account holding creation deposits and rent.
+		pub(crate) fn account_id_of_pallet() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		pub(crate) fn vault_info(vault_id: &T::VaultId) -> Result<VaultInfoOf<T>, DispatchError> {
+			Vaults::<T>::get(vault_id).ok_or_else(|| Error::<T>::VaultDoesNotExist.into())
+		}
+
+		/// Underlying assets held by the vault, idle or lent to strategies.
+		pub(crate) fn assets_under_management(
+			vault_id: &T::VaultId,
+			info: &VaultInfoOf<T>,
+		) -> Result<T::Balance, DispatchError> {
+			let idle = T::Assets::balance(info.asset_id, &Self::account_id(vault_id));
+			CapitalStructure::<T>::iter_prefix_values(vault_id)
+				.try_fold(idle, |total, overview| total.safe_add(&overview.balance))
+				.map_err(Into::into)
+		}
+
+		#[transactional]
+		pub(crate) fn do_claim_surcharge(
+			vault_id: T::VaultId,
+			beneficiary: T::AccountId,
+		) -> DispatchResult {
+			let now = frame_system::Pallet::<T>::block_number();
+			let (claimed, tombstoned) =
+				Vaults::<T>::try_mutate(vault_id, |info| -> Result<_, DispatchError> {
+					let info = info.as_mut().ok_or(Error::<T>::VaultDoesNotExist)?;
+					let (amount, at) = match info.deposit {
+						Deposit::Rent { amount, at } if now > at && !amount.is_zero() =>
+							(amount, at),
+						_ => return Err(Error::<T>::RentNotDue.into()),
+					};
+					let blocks: u128 = now.saturating_sub(at).saturated_into();
+					let due = T::RentPerBlock::get().saturating_mul(blocks.saturated_into());
+					let tombstoned = due >= amount;
+					if tombstoned {
+						info.capabilities.tombstoned = true;
+						info.deposit = Deposit::Rent { amount: Zero::zero(), at: now };
+					} else {
+						info.deposit =
+							Deposit::Rent { amount: amount.saturating_sub(due), at: now };
+					}
+					Ok((due.min(amount), tombstoned))
+				})?;
+			T::NativeCurrency::transfer(
+				&Self::account_id_of_pallet(),
+				&beneficiary,
+				claimed,
+				Preservation::Expendable,
+			)?;
+			Self::deposit_event(Event::<T>::RentClaimed { vault_id, beneficiary, amount: claimed });
+			if tombstoned {
+				Self::deposit_event(Event::<T>::VaultTombstoned { vault_id });
+			}
+			Ok(())
+		}
+
+		#[transactional]
+		pub(crate) fn do_add_surcharge(
+			vault_id: T::VaultId,
+			from: &T::AccountId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let now = frame_system::Pallet::<T>::block_number();
+			Vaults::<T>::try_mutate(vault_id, |info| -> DispatchResult {
+				let info = info.as_mut().ok_or(Error::<T>::VaultDoesNotExist)?;
+				if let Deposit::Rent { amount: current, at } = info.deposit {
+					let total = current.safe_add(&amount)?;
+					info.deposit = if total >= T::ExistentialDeposit::get() {
+						Deposit::Existential
+					} else if info.capabilities.tombstoned {
+						Deposit::Rent { amount: total, at: now }
+					} else {
+						Deposit::Rent { amount: total, at }
+					};
+					info.capabilities.tombstoned = false;
+				}
+				Ok(())
+			})?;
+			T::NativeCurrency::transfer(
+				from,
+				&Self::account_id_of_pallet(),
+				amount,
+				Preservation::Preserve,
+			)?;
+			Self::deposit_event(Event::<T>::SurchargeAdded { vault_id, amount });
+			Ok(())
+		}
+
+		#[transactional]
+		pub(crate) fn do_delete_tombstoned(vault_id: T::VaultId) -> DispatchResult {
+			let info = Self::vault_info(&vault_id)?;
+			ensure!(info.capabilities.tombstoned, Error::<T>::VaultNotTombstoned);
+			let (refunded, at) = match info.deposit {
+				Deposit::Rent { amount, at } => (amount, at),
+				Deposit::Existential => return Err(Error::<T>::VaultNotTombstoned.into()),
+			};
+			ensure!(
+				frame_system::Pallet::<T>::block_number().saturating_sub(at) >=
+					T::TombstoneDuration::get(),
+				Error::<T>::TombstoneDurationNotExceeded
+			);
+			// Only the locked liquidity may remain.
+			let vault_account = Self::account_id(&vault_id);
+			let locked = T::Assets::balance(info.lp_token_id, &vault_account);
+			ensure!(
+				T::Assets::total_issuance(info.lp_token_id) == locked,
+				Error::<T>::VaultNotEmpty
+			);
+			ensure!(
+				CapitalStructure::<T>::iter_prefix_values(vault_id)
+					.all(|overview| overview.balance.is_zero()),
+				Error::<T>::StrategiesNotWithdrawn
+			);
+			if !locked.is_zero() {
+				T::Assets::burn_from(
+					info.lp_token_id,
+					&vault_account,
+					locked,
+					Precision::Exact,
+					Fortitude::Force,
+				)?;
+			}
+			// Nobody holds a share of what is left, e.g. donations and rounding dust.
+			let swept = T::Assets::balance(info.asset_id, &vault_account);
+			if !swept.is_zero() {
+				T::Assets::transfer(
+					info.asset_id,
+					&vault_account,
+					&info.manager,
+					swept,
+					Preservation::Expendable,
+				)?;
+			}
+			if !refunded.is_zero() {
+				T::NativeCurrency::transfer(
+					&Self::account_id_of_pallet(),
+					&info.manager,
+					refunded,
+					Preservation::Expendable,
+				)?;
+			}
+			Vaults::<T>::remove(vault_id);
+			LpTokensToVaults::<T>::remove(info.lp_token_id);
+			let _ = CapitalStructure::<T>::clear_prefix(vault_id, T::MaxStrategies::get(), None);
+			Self::deposit_event(Event::<T>::VaultDeleted {
+				vault_id,
+				manager: info.manager,
+				swept,
+				refunded,
+			});
+			Ok(())
+		}
+
+		pub(crate) fn create_lp_token() -> Result<T::AssetId, DispatchError> {
+			let protocol_id = (Self::index() as u32).to_be_bytes();
+			let nonce = LpTokenNonce::<T>::increment()?;
+			T::LPTokenFactory::create_local_asset(
+				protocol_id,
+				nonce,
+				AssetInfo {
+					name: None,
+					symbol: None,
+					decimals: Some(12),
+					existential_deposit: T::LPTokenExistentialDeposit::get(),
+					ratio: None,
+				},
+			)
+		}
+	}
+
+	impl<T: Config> Vault for Pallet<T> {
+		type AccountId = T::AccountId;
+		type AssetId = T::AssetId;
+		type Balance = T::Balance;
+		type BlockNumber = T::BlockNumber;
+		type VaultId = T::VaultId;
+
+		fn token_vault(token: Self::AssetId) -> Result<Self::VaultId, DispatchError> {
+			LpTokensToVaults::<T>::get(token).ok_or_else(|| Error::<T>::VaultDoesNotExist.into())
+		}
+
+		fn asset_id(vault_id: &Self::VaultId) -> Result<Self::AssetId, DispatchError> {
+			Ok(Self::vault_info(vault_id)?.asset_id)
+		}
+
+		fn lp_asset_id(vault_id: &Self::VaultId) -> Result<Self::AssetId, DispatchError> {
+			Ok(Self::vault_info(vault_id)?.lp_token_id)
+		}
+
+		fn account_id(vault: &Self::VaultId) -> Self::AccountId {
+			T::PalletId::get().into_sub_account_truncating(vault)
+		}
+
+		#[transactional]
+		fn create(
+			deposit: DepositOf<T>,
+			config: VaultConfigOf<T>,
+		) -> Result<Self::VaultId, DispatchError> {
+			ensure!(
+				config.strategies.len() <= T::MaxStrategies::get() as usize,
+				Error::<T>::TooManyStrategies
+			);
+			let allocated = config
+				.strategies
+				.values()
+				.try_fold(config.reserved, |total, allocation| total.checked_add(allocation))
+				.ok_or(Error::<T>::AllocationMustSumToOne)?;
+			ensure!(allocated == Perquintill::one(), Error::<T>::AllocationMustSumToOne);
+
+			let vault_id = VaultCount::<T>::try_mutate(|count| -> Result<_, DispatchError> {
+				*count = count.safe_add(&T::VaultId::one())?;
+				Ok(*count)
+			})?;
+			let lp_token_id = Self::create_lp_token()?;
+			let now = frame_system::Pallet::<T>::block_number();
+			for (strategy, allocation) in config.strategies {
+				CapitalStructure::<T>::insert(
+					vault_id,
+					strategy,
+					StrategyOverview { allocation, reported_at: now, ..Default::default() },
+				);
+			}
+			Vaults::<T>::insert(
+				vault_id,
+				VaultInfo {
+					asset_id: config.asset_id,
+					lp_token_id,
+					manager: config.manager,
+					reserved: config.reserved,
+					deposit,
+					capabilities: Capabilities::default(),
+				},
+			);
+			LpTokensToVaults::<T>::insert(lp_token_id, vault_id);
+			Self::deposit_event(Event::<T>::VaultCreated { vault_id, lp_token_id });
+			Ok(vault_id)
+		}
+
+		#[transactional]
+		fn deposit(
+			vault_id: &Self::VaultId,
+			from: &Self::AccountId,
+			asset_amount: Self::Balance,
+		) -> Result<Self::Balance, DispatchError> {
+			ensure!(
+				<Self as CapabilityVault>::deposits_allowed(vault_id)?,
+				Error::<T>::DepositsHalted
+			);
+			ensure!(
+				asset_amount >= T::MinimumDeposit::get(),
+				Error::<T>::AmountMustGteMinimumDeposit
+			);
+			let info = Self::vault_info(vault_id)?;
+			let first_deposit = T::Assets::total_issuance(info.lp_token_id).is_zero();
+			let lp_amount = Self::calculate_lp_tokens_to_mint(vault_id, asset_amount)?;
+			T::Assets::transfer(
+				info.asset_id,
+				from,
+				&Self::account_id(vault_id),
+				asset_amount,
+				Preservation::Expendable,
+			)?;
+			if first_deposit {
+				T::Assets::mint_into(
+					info.lp_token_id,
+					&Self::account_id(vault_id),
+					T::LockedLiquidity::get(),
+				)?;
+			}
+			T::Assets::mint_into(info.lp_token_id, from, lp_amount)?;
+			Self::deposit_event(Event::<T>::Deposited {
+				vault_id: *vault_id,
+				account: from.clone(),
+				asset_amount,
+				lp_amount,
+			});
+			Ok(lp_amount)
+		}
+
+		#[transactional]
+		fn withdraw(
+			vault_id: &Self::VaultId,
+			to: &Self::AccountId,
+			lp_amount: Self::Balance,
+		) -> Result<Self::Balance, DispatchError> {
+			ensure!(
+				<Self as CapabilityVault>::withdrawals_allowed(vault_id)?,
+				Error::<T>::WithdrawalsHalted
+			);
+			ensure!(
+				lp_amount >= T::MinimumWithdrawal::get(),
+				Error::<T>::AmountMustGteMinimumWithdrawal
+			);
+			let info = Self::vault_info(vault_id)?;
+			let asset_amount = Self::lp_share_value(vault_id, lp_amount)?;
+			let vault_account = Self::account_id(vault_id);
+			ensure!(
+				T::Assets::balance(info.asset_id, &vault_account) >= asset_amount,
+				Error::<T>::NotEnoughLiquidity
+			);
+			T::Assets::burn_from(
+				info.lp_token_id,
+				to,
+				lp_amount,
+				Precision::Exact,
+				Fortitude::Polite,
+			)?;
+			T::Assets::transfer(
+				info.asset_id,
+				&vault_account,
+				to,
+				asset_amount,
+				Preservation::Expendable,
+			)?;
+			Self::deposit_event(Event::<T>::Withdrawn {
+				vault_id: *vault_id,
+				account: to.clone(),
+				lp_amount,
+				asset_amount,
+			});
+			Ok(asset_amount)
+		}
+
+		fn stock_dilution_rate(vault_id: &Self::VaultId) -> Result<Rate, DispatchError> {
+			let info = Self::vault_info(vault_id)?;
+			let issued = T::Assets::total_issuance(info.lp_token_id);
+			if issued.is_zero() {
+				return Ok(Rate::one())
+			}
+			let aum = Self::assets_under_management(vault_id, &info)?;
+			Rate::checked_from_rational(aum.into(), issued.into())
+				.ok_or_else(|| ArithmeticError::Overflow.into())
+		}
+
+		fn calculate_lp_tokens_to_mint(
+			vault_id: &Self::VaultId,
+			amount: Self::Balance,
+		) -> Result<Self::Balance, DispatchError> {
+			let info = Self::vault_info(vault_id)?;
+			let issued = T::Assets::total_issuance(info.lp_token_id);
+			if issued.is_zero() {
+				// One LP token per asset, minus the LP tokens locked by the first deposit.
+				let locked = T::LockedLiquidity::get();
+				ensure!(amount > locked, Error::<T>::InsufficientFirstDeposit);
+				return Ok(amount.saturating_sub(locked))
+			}
+			let aum = Self::assets_under_management(vault_id, &info)?;
+			ensure!(!aum.is_zero(), Error::<T>::VaultHasNoAssets);
+			let lp = safe_multiply_by_rational(amount.into(), issued.into(), aum.into())?;
+			Ok(lp.saturated_into())
+		}
+
+		fn lp_share_value(
+			vault_id: &Self::VaultId,
+			lp_amount: Self::Balance,
+		) -> Result<Self::Balance, DispatchError> {
+			let info = Self::vault_info(vault_id)?;
+			let issued = T::Assets::total_issuance(info.lp_token_id);
+			let aum = Self::assets_under_management(vault_id, &info)?;
+			let value = safe_multiply_by_rational(lp_amount.into(), aum.into(), issued.into())?;
+			Ok(value.saturated_into())
+		}
+
+		fn amount_of_lp_token_for_added_liquidity(
+			vault_id: &Self::VaultId,
+			asset_amount: Self::Balance,
+		) -> Result<Self::Balance, DispatchError> {
+			Self::calculate_lp_tokens_to_mint(vault_id, asset_amount)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn update_capabilities(
+			vault_id: &T::VaultId,
+			f: impl FnOnce(&mut Capabilities, &mut DepositOf<T>),
+		) -> DispatchResult {
+			Vaults::<T>::try_mutate(vault_id, |info| {
+				let info = info.as_mut().ok_or(Error::<T>::VaultDoesNotExist)?;
+				f(&mut info.capabilities, &mut info.deposit);
+				Ok(())
+			})
+		}
+
+		fn capabilities(vault_id: &T::VaultId) -> Result<Capabilities, DispatchError> {
+			Ok(Self::vault_info(vault_id)?.capabilities)
+		}
+	}
+
+	impl<T: Config> CapabilityVault for Pallet<T> {
+		fn stop(vault_id: &Self::VaultId) -> DispatchResult {
+			Self::update_capabilities(vault_id, |capabilities, _| capabilities.stopped = true)
+		}
+
+		fn is_stopped(vault_id: &Self::VaultId) -> Result<bool, DispatchError> {
+			Ok(Self::capabilities(vault_id)?.stopped)
+		}
+
+		fn start(vault_id: &Self::VaultId) -> DispatchResult {
+			Self::update_capabilities(vault_id, |capabilities, _| capabilities.stopped = false)
+		}
+
+		fn tombstone(vault_id: &Self::VaultId) -> DispatchResult {
+			let now = frame_system::Pallet::<T>::block_number();
+			Self::update_capabilities(vault_id, |capabilities, deposit| {
+				capabilities.tombstoned = true;
+				if let Deposit::Rent { at, .. } = deposit {
+					*at = now;
+				}
+			})
+		}
+
+		fn untombstone(vault_id: &Self::VaultId) -> DispatchResult {
+			Self::update_capabilities(vault_id, |capabilities, _| capabilities.tombstoned = false)
+		}
+
+		fn is_tombstoned(vault_id: &Self::VaultId) -> Result<bool, DispatchError> {
+			Ok(Self::capabilities(vault_id)?.tombstoned)
+		}
+
+		fn stop_withdrawals(vault_id: &Self::VaultId) -> DispatchResult {
+			Self::update_capabilities(vault_id, |capabilities, _| {
+				capabilities.withdrawals_stopped = true
+			})
+		}
+
+		fn allow_withdrawals(vault_id: &Self::VaultId) -> DispatchResult {
+			Self::update_capabilities(vault_id, |capabilities, _| {
+				capabilities.withdrawals_stopped = false
+			})
+		}
+
+		fn withdrawals_allowed(vault_id: &Self::VaultId) -> Result<bool, DispatchError> {
+			let capabilities = Self::capabilities(vault_id)?;
+			Ok(!capabilities.stopped && !capabilities.withdrawals_stopped)
+		}
+
+		fn stop_deposits(vault_id: &Self::VaultId) -> DispatchResult {
+			Self::update_capabilities(vault_id, |capabilities, _| {
+				capabilities.deposits_stopped = true
+			})
+		}
+
+		fn allow_deposits(vault_id: &Self::VaultId) -> DispatchResult {
+			Self::update_capabilities(vault_id, |capabilities, _| {
+				capabilities.deposits_stopped = false
+			})
+		}
+
+		fn deposits_allowed(vault_id: &Self::VaultId) -> Result<bool, DispatchError> {
+			let capabilities = Self::capabilities(vault_id)?;
+			Ok(!capabilities.stopped && !capabilities.tombstoned && !capabilities.deposits_stopped)
+		}
+	}
+
+	impl<T: Config> StrategicVault for Pallet<T> {
+		fn available_funds(
+			vault_id: &Self::VaultId,
+			account: &Self::AccountId,
+		) -> Result<FundsAvailability<Self::Balance>, DispatchError> {
+			let info = Self::vault_info(vault_id)?;
+			let overview = CapitalStructure::<T>::get(vault_id, account)
+				.ok_or(Error::<T>::StrategyNotRegistered)?;
+			if info.capabilities.stopped ||
+				info.capabilities.tombstoned ||
+				overview.allocation.is_zero()
+			{
+				return Ok(if overview.balance.is_zero() {
+					FundsAvailability::None
+				} else {
+					FundsAvailability::MustLiquidate
+				})
+			}
+			let aum = Self::assets_under_management(vault_id, &info)?;
+			let cap = overview.allocation.mul_floor(aum);
+			if overview.balance < cap {
+				let idle = T::Assets::balance(info.asset_id, &Self::account_id(vault_id));
+				let spare = idle.saturating_sub(info.reserved.mul_floor(aum));
+				let amount = cap.saturating_sub(overview.balance).min(spare);
+				Ok(if amount.is_zero() {
+					FundsAvailability::None
+				} else {
+					FundsAvailability::Withdrawable(amount)
+				})
+			} else if overview.balance > cap {
+				Ok(FundsAvailability::Depositable(overview.balance.saturating_sub(cap)))
+			} else {
+				Ok(FundsAvailability::None)
+			}
+		}
+
+		#[transactional]
+		fn withdraw(
+			vault_id: &Self::VaultId,
+			to: &Self::AccountId,
+			amount: Self::Balance,
+		) -> Result<(), DispatchError> {
+			let info = Self::vault_info(vault_id)?;
+			ensure!(
+				!info.capabilities.stopped && !info.capabilities.tombstoned,
+				Error::<T>::AllocationsHalted
+			);
+			let aum = Self::assets_under_management(vault_id, &info)?;
+			CapitalStructure::<T>::try_mutate(vault_id, to, |overview| -> DispatchResult {
+				let overview = overview.as_mut().ok_or(Error::<T>::StrategyNotRegistered)?;
+				let balance = overview.balance.safe_add(&amount)?;
+				ensure!(
+					balance <= overview.allocation.mul_floor(aum),
+					Error::<T>::StrategyCapExceeded
+				);
+				overview.balance = balance;
+				overview.lifetime_withdrawn = overview.lifetime_withdrawn.safe_add(&amount)?;
+				Ok(())
+			})?;
+			T::Assets::transfer(
+				info.asset_id,
+				&Self::account_id(vault_id),
+				to,
+				amount,
+				Preservation::Expendable,
+			)?;
+			Self::deposit_event(Event::<T>::StrategyWithdrawn {
+				vault_id: *vault_id,
+				strategy: to.clone(),
+				amount,
+			});
+			Ok(())
+		}
+
+		#[transactional]
+		fn deposit(
+			vault_id: &Self::VaultId,
+			from: &Self::AccountId,
+			amount: Self::Balance,
+		) -> Result<(), DispatchError> {
+			let info = Self::vault_info(vault_id)?;
+			CapitalStructure::<T>::try_mutate(vault_id, from, |overview| -> DispatchResult {
+				let overview = overview.as_mut().ok_or(Error::<T>::StrategyNotRegistered)?;
+				// Returned profits may exceed the last reported balance.
+				overview.balance = overview.balance.saturating_sub(amount);
+				overview.lifetime_deposited = overview.lifetime_deposited.safe_add(&amount)?;
+				Ok(())
+			})?;
+			T::Assets::transfer(
+				info.asset_id,
+				from,
+				&Self::account_id(vault_id),
+				amount,
+				Preservation::Expendable,
+			)?;
+			Self::deposit_event(Event::<T>::StrategyDeposited {
+				vault_id: *vault_id,
+				strategy: from.clone(),
+				amount,
+			});
+			Ok(())
+		}
+	}
+
+	impl<T: Config> ReportableStrategicVault for Pallet<T> {
+		/// Balance currently held by the strategy.
+		type Report = T::Balance;
+
+		fn update_strategy_report(
+			vault_id: &Self::VaultId,
+			strategy: &Self::AccountId,
+			report: &Self::Report,
+		) -> Result<(), DispatchError> {
+			let info = Self::vault_info(vault_id)?;
+			let aum = Self::assets_under_management(vault_id, &info)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			CapitalStructure::<T>::try_mutate(vault_id, strategy, |overview| {
+				let overview = overview.as_mut().ok_or(Error::<T>::StrategyNotRegistered)?;
+				let blocks: u64 = now.saturating_sub(overview.reported_at).saturated_into();
+				let max_change = Perquintill::from_parts(
+					T::MaxReportChangePerBlock::get().deconstruct().saturating_mul(blocks),
+				)
+				.mul_floor(aum);
+				let change = if *report > overview.balance {
+					report.saturating_sub(overview.balance)
+				} else {
+					overview.balance.saturating_sub(*report)
+				};
+				ensure!(change <= max_change, Error::<T>::ReportChangeTooLarge);
+				overview.balance = *report;
+				overview.reported_at = now;
+				Ok::<_, DispatchError>(())
+			})?;
+			Self::deposit_event(Event::<T>::StrategyReported {
+				vault_id: *vault_id,
+				strategy: strategy.clone(),
+				balance: *report,
+			});
+			Ok(())
+		}
+	}
+}
+
+/// Amount of underlying asset redeemed by burning `lp_amount`, used by the runtime API.
+pub fn lp_share_value<T: Config>(
+	vault_id: T::VaultId,
+	lp_amount: T::Balance,
+) -> Option<T::Balance> {
+	<Pallet<T> as composable_traits::vault::Vault>::lp_share_value(&vault_id, lp_amount).ok()
+}
+
+/// Amount of LP token minted by depositing `asset_amount`, used by the runtime API.
+pub fn amount_of_lp_token_for_added_liquidity<T: Config>(
+	vault_id: T::VaultId,
+	asset_amount: T::Balance,
+) -> Option<T::Balance> {
+	<Pallet<T> as composable_traits::vault::Vault>::amount_of_lp_token_for_added_liquidity(
+		&vault_id,
+		asset_amount,
+	)
+	.ok()
+}
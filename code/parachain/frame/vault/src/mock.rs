@@ -0,0 +1,258 @@
+#![cfg(test)]
+
+use crate as pallet_vault;
+use frame_support::{
+	ord_parameter_types,
+	pallet_prelude::ConstU32,
+	parameter_types,
+	traits::{EitherOfDiverse, Everything},
+	PalletId,
+};
+use frame_system::{EnsureRoot, EnsureSignedBy};
+use orml_traits::parameter_type_with_key;
+use primitives::currency::ForeignAssetId;
+use sp_arithmetic::traits::Zero;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, ConvertInto, IdentityLookup},
+	Perquintill,
+};
+
+pub type CurrencyId = u128;
+pub type BlockNumber = u64;
+pub type VaultId = u64;
+
+pub const NATIVE: CurrencyId = 1;
+pub const BTC: CurrencyId = 2;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Tokens: orml_tokens,
+		AssetsRegistry: pallet_assets_registry,
+		Assets: pallet_assets,
+		Vault: pallet_vault,
+	}
+);
+
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type FreezeIdentifier = [u8; 8];
+
+	type HoldIdentifier = [u8; 8];
+
+	type MaxHolds = ConstU32<32>;
+
+	type MaxFreezes = ConstU32<32>;
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+pub type AccountId = u128;
+
+#[allow(dead_code)]
+pub static ALICE: AccountId = 1;
+#[allow(dead_code)]
+pub static BOB: AccountId = 2;
+#[allow(dead_code)]
+pub static CHARLIE: AccountId = 3;
+#[allow(dead_code)]
+pub static DAVE: AccountId = 4;
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u128>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+pub type Balance = u128;
+pub type AssetId = u128;
+pub type Amount = i128;
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		Zero::zero()
+	};
+}
+
+pub struct CurrencyHooks;
+impl orml_traits::currency::MutationHooks<AccountId, AssetId, Balance> for CurrencyHooks {
+	type OnDust = ();
+	type OnSlash = ();
+	type PreDeposit = ();
+	type PostDeposit = ();
+	type PreTransfer = ();
+	type PostTransfer = ();
+	type OnNewTokenAccount = ();
+	type OnKilledTokenAccount = ();
+}
+
+type ReserveIdentifier = [u8; 8];
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type ReserveIdentifier = ReserveIdentifier;
+	type MaxReserves = frame_support::traits::ConstU32<2>;
+	type DustRemovalWhitelist = Everything;
+	type CurrencyHooks = CurrencyHooks;
+}
+
+parameter_types! {
+	pub const NativeAssetId: CurrencyId = NATIVE;
+	pub const NetworkId: u32 = 0;
+}
+
+parameter_types! {
+	pub const AssetsRegistryTreasuryAccount: AccountId = 0xffff_ffff;
+}
+
+impl pallet_assets_registry::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type LocalAssetId = AssetId;
+	type ForeignAssetId = ForeignAssetId;
+	type UpdateAssetRegistryOrigin = EnsureRoot<AccountId>;
+	type ParachainOrGovernanceOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+	type Balance = Balance;
+	type Convert = ConvertInto;
+	type NetworkId = NetworkId;
+	type Currency = Balances;
+	type ProposalDeposit = frame_support::traits::ConstU128<1_000>;
+	type MinProposedExistentialDeposit = frame_support::traits::ConstU128<1>;
+	type MinProposedFee = frame_support::traits::ConstU128<1>;
+	type ChallengePeriod = frame_support::traits::ConstU64<10>;
+	type RejectProposalOrigin = EnsureRoot<AccountId>;
+	type TreasuryAccount = AssetsRegistryTreasuryAccount;
+	type PoolId = u128;
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = ();
+	type NativeAssetId = NativeAssetId;
+	type AssetId = CurrencyId;
+	type Balance = Balance;
+	type MultiCurrency = Tokens;
+	type NativeCurrency = Balances;
+	type WeightInfo = ();
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type FreezeOrigin = EnsureRoot<AccountId>;
+	type CurrencyValidator = Valid;
+	type RecurringPaymentDeposit = frame_support::traits::ConstU128<1>;
+	type MaxRecurringPaymentsPerPayer = frame_support::traits::ConstU32<16>;
+	type MinRecurringPaymentInterval = frame_support::traits::ConstU64<1>;
+	type MaxRecurringPaymentFailures = frame_support::traits::ConstU32<3>;
+}
+
+pub struct Valid;
+impl composable_support::validation::Validate<CurrencyId, primitives::currency::ValidateCurrencyId>
+	for Valid
+{
+	fn validate(input: CurrencyId) -> Result<CurrencyId, &'static str> {
+		Ok(input)
+	}
+}
+
+ord_parameter_types! {
+	pub const RootAccount: AccountId = ALICE;
+}
+
+parameter_types! {
+	pub const VaultPalletId: PalletId = PalletId(*b"cubic___");
+	pub const MaxStrategies: u32 = 10;
+	pub const CreationDeposit: Balance = 10;
+	pub const VaultExistentialDeposit: Balance = 1_000;
+	pub const RentPerBlock: Balance = 1;
+	pub const TombstoneDuration: BlockNumber = 42;
+	pub const MinimumDeposit: Balance = 0;
+	pub const MinimumWithdrawal: Balance = 0;
+	pub const LPTokenExistentialDeposit: Balance = 0;
+	pub const LockedLiquidity: Balance = 10;
+	pub const MaxReportChangePerBlock: Perquintill = Perquintill::from_percent(10);
+}
+
+impl pallet_vault::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = CurrencyId;
+	type Balance = Balance;
+	type VaultId = VaultId;
+	type LPTokenFactory = AssetsRegistry;
+	type Assets = Assets;
+	type NativeCurrency = Balances;
+	type PalletId = VaultPalletId;
+	type MaxStrategies = MaxStrategies;
+	type CreationDeposit = CreationDeposit;
+	type ExistentialDeposit = VaultExistentialDeposit;
+	type RentPerBlock = RentPerBlock;
+	type TombstoneDuration = TombstoneDuration;
+	type MinimumDeposit = MinimumDeposit;
+	type MinimumWithdrawal = MinimumWithdrawal;
+	type LPTokenExistentialDeposit = LPTokenExistentialDeposit;
+	type LockedLiquidity = LockedLiquidity;
+	type MaxReportChangePerBlock = MaxReportChangePerBlock;
+	type EmergencyShutdownOrigin = EitherOfDiverse<
+		EnsureSignedBy<RootAccount, AccountId>, // for tests
+		EnsureRoot<AccountId>,                  // for benchmarks
+	>;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::default()
+		.build_storage::<Test>()
+		.expect("success")
+		.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
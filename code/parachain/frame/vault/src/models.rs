@@ -0,0 +1,44 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use composable_traits::vault::Deposit;
+use scale_info::TypeInfo;
+use sp_runtime::Perquintill;
+
+/// Functionalities of a vault which have been disabled, see
+/// [`CapabilityVault`](composable_traits::vault::CapabilityVault).
+#[derive(Copy, Clone, Encode, Decode, MaxEncodedLen, TypeInfo, Debug, Default, PartialEq, Eq)]
+pub struct Capabilities {
+	/// Everything but starting the vault again is disabled.
+	pub stopped: bool,
+	/// The vault is marked for deletion, only withdrawals are allowed.
+	pub tombstoned: bool,
+	pub withdrawals_stopped: bool,
+	pub deposits_stopped: bool,
+}
+
+#[derive(Copy, Clone, Encode, Decode, MaxEncodedLen, TypeInfo, Debug, PartialEq, Eq)]
+pub struct VaultInfo<AccountId, Balance, AssetId, BlockNumber> {
+	/// Underlying asset.
+	pub asset_id: AssetId,
+	/// Receipt token minted on deposit.
+	pub lp_token_id: AssetId,
+	pub manager: AccountId,
+	/// Share of the vault kept idle in the vault account to serve withdrawals.
+	pub reserved: Perquintill,
+	pub deposit: Deposit<Balance, BlockNumber>,
+	pub capabilities: Capabilities,
+}
+
+/// Capital allocated to a strategy of a vault.
+#[derive(Copy, Clone, Encode, Decode, MaxEncodedLen, TypeInfo, Debug, Default, PartialEq, Eq)]
+pub struct StrategyOverview<Balance, BlockNumber> {
+	/// Maximum share of the vault the strategy may hold.
+	pub allocation: Perquintill,
+	/// Amount currently held by the strategy, as last reported.
+	pub balance: Balance,
+	/// Total amount withdrawn from the vault over the strategy lifetime.
+	pub lifetime_withdrawn: Balance,
+	/// Total amount returned to the vault over the strategy lifetime.
+	pub lifetime_deposited: Balance,
+	/// Block of the last report, or of the registration of the strategy.
+	pub reported_at: BlockNumber,
+}
@@ -0,0 +1,359 @@
+use crate::{mock::*, pallet::*};
+use composable_traits::vault::{
+	CapabilityVault, Deposit, FundsAvailability, StrategicVault, Vault as VaultTrait, VaultConfig,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{
+		fungible::{Inspect as _, Mutate as _},
+		fungibles::{Inspect, Mutate},
+	},
+};
+use sp_runtime::Perquintill;
+
+const STRATEGY: AccountId = 42;
+
+fn vault_config(strategy_allocation: Perquintill) -> VaultConfig<AccountId, CurrencyId> {
+	VaultConfig {
+		asset_id: BTC,
+		reserved: Perquintill::one() - strategy_allocation,
+		manager: BOB,
+		strategies: [(STRATEGY, strategy_allocation)].into_iter().collect(),
+	}
+}
+
+fn create_vault(deposit: Balance) -> VaultId {
+	assert_ok!(Balances::mint_into(&BOB, 1_000_000));
+	assert_ok!(Vault::create(
+		RuntimeOrigin::signed(BOB),
+		vault_config(Perquintill::from_percent(50)),
+		deposit
+	));
+	VaultCount::<Test>::get()
+}
+
+#[test]
+fn create_checks_config_and_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Balances::mint_into(&BOB, 1_000_000));
+		let mut config = vault_config(Perquintill::from_percent(50));
+		config.reserved = Perquintill::from_percent(10);
+		assert_noop!(
+			Vault::create(RuntimeOrigin::signed(BOB), config, 1_000),
+			Error::<Test>::AllocationMustSumToOne
+		);
+		assert_noop!(
+			Vault::create(
+				RuntimeOrigin::signed(BOB),
+				vault_config(Perquintill::from_percent(50)),
+				CreationDeposit::get() - 1
+			),
+			Error::<Test>::InsufficientCreationDeposit
+		);
+
+		let vault_id = create_vault(VaultExistentialDeposit::get());
+		let info = Vault::vault_data(vault_id).expect("vault exists");
+		assert_eq!(info.deposit, Deposit::Existential);
+		assert_eq!(LpTokensToVaults::<Test>::get(info.lp_token_id), Some(vault_id));
+		assert_eq!(
+			Balances::balance(&Vault::account_id_of_pallet()),
+			VaultExistentialDeposit::get()
+		);
+	})
+}
+
+#[test]
+fn deposit_and_withdraw_track_strategy_reports() {
+	new_test_ext().execute_with(|| {
+		let vault_id = create_vault(VaultExistentialDeposit::get());
+		let lp_token = <Vault as VaultTrait>::lp_asset_id(&vault_id).expect("vault exists");
+		assert_ok!(Tokens::mint_into(BTC, &ALICE, 1_000));
+
+		// 1. The first depositor receives one LP token per asset, minus the locked liquidity.
+		assert_ok!(Vault::deposit(RuntimeOrigin::signed(ALICE), vault_id, 1_000));
+		assert_eq!(Tokens::balance(lp_token, &ALICE), 1_000 - LockedLiquidity::get());
+		assert_eq!(
+			Tokens::balance(lp_token, &<Vault as VaultTrait>::account_id(&vault_id)),
+			LockedLiquidity::get()
+		);
+
+		// 2. The strategy can withdraw up to its allocation.
+		assert_eq!(
+			<Vault as StrategicVault>::available_funds(&vault_id, &STRATEGY),
+			Ok(FundsAvailability::Withdrawable(500))
+		);
+		assert_noop!(
+			<Vault as StrategicVault>::withdraw(&vault_id, &STRATEGY, 501),
+			Error::<Test>::StrategyCapExceeded
+		);
+		assert_ok!(<Vault as StrategicVault>::withdraw(&vault_id, &STRATEGY, 500));
+		assert_eq!(Tokens::balance(BTC, &STRATEGY), 500);
+
+		// 3. Reported gains increase the value of the LP token, reports can't change the
+		// balance faster than `MaxReportChangePerBlock`.
+		assert_noop!(
+			Vault::report(RuntimeOrigin::signed(STRATEGY), vault_id, STRATEGY, 1_500),
+			Error::<Test>::ReportChangeTooLarge
+		);
+		System::set_block_number(10);
+		assert_noop!(
+			Vault::report(RuntimeOrigin::signed(STRATEGY), vault_id, STRATEGY, 1_500),
+			Error::<Test>::ReportChangeTooLarge
+		);
+		System::set_block_number(11);
+		assert_ok!(Vault::report(RuntimeOrigin::signed(STRATEGY), vault_id, STRATEGY, 1_500));
+		assert_eq!(<Vault as VaultTrait>::lp_share_value(&vault_id, 1_000), Ok(2_000));
+		assert_eq!(
+			<Vault as StrategicVault>::available_funds(&vault_id, &STRATEGY),
+			Ok(FundsAvailability::Depositable(500))
+		);
+
+		// 4. Withdrawals are limited to idle funds.
+		assert_noop!(
+			Vault::withdraw(RuntimeOrigin::signed(ALICE), vault_id, 1_000),
+			Error::<Test>::NotEnoughLiquidity
+		);
+		assert_ok!(Vault::withdraw(RuntimeOrigin::signed(ALICE), vault_id, 250));
+		assert_eq!(Tokens::balance(BTC, &ALICE), 500);
+		assert_eq!(Tokens::balance(lp_token, &ALICE), 740);
+	})
+}
+
+#[test]
+fn first_deposit_locks_liquidity() {
+	new_test_ext().execute_with(|| {
+		let vault_id = create_vault(VaultExistentialDeposit::get());
+		let vault_account = <Vault as VaultTrait>::account_id(&vault_id);
+		assert_ok!(Tokens::mint_into(BTC, &CHARLIE, 10_000));
+		assert_ok!(Tokens::mint_into(BTC, &ALICE, 1_000));
+
+		// 1. The first deposit must exceed the locked liquidity.
+		assert_noop!(
+			Vault::deposit(RuntimeOrigin::signed(CHARLIE), vault_id, LockedLiquidity::get()),
+			Error::<Test>::InsufficientFirstDeposit
+		);
+
+		// 2. Inflating the share value with a donation costs the donor most of the donation.
+		assert_ok!(Vault::deposit(
+			RuntimeOrigin::signed(CHARLIE),
+			vault_id,
+			LockedLiquidity::get() + 1
+		));
+		assert_ok!(<Tokens as Mutate<AccountId>>::transfer(
+			BTC,
+			&CHARLIE,
+			&vault_account,
+			1_000,
+			frame_support::traits::tokens::Preservation::Expendable
+		));
+		assert_ok!(Vault::deposit(RuntimeOrigin::signed(ALICE), vault_id, 1_000));
+		assert_ok!(Vault::withdraw(RuntimeOrigin::signed(CHARLIE), vault_id, 1));
+		let charlie_loss = 10_000 - Tokens::balance(BTC, &CHARLIE);
+		let alice_lp = Tokens::balance(
+			<Vault as VaultTrait>::lp_asset_id(&vault_id).expect("vault exists"),
+			&ALICE,
+		);
+		let alice_loss =
+			1_000 - <Vault as VaultTrait>::lp_share_value(&vault_id, alice_lp).unwrap();
+		assert!(charlie_loss > 10 * alice_loss);
+	})
+}
+
+#[test]
+fn liquidate_and_emergency_shutdown() {
+	new_test_ext().execute_with(|| {
+		let vault_id = create_vault(VaultExistentialDeposit::get());
+		assert_ok!(Tokens::mint_into(BTC, &ALICE, 1_000));
+		assert_ok!(Vault::deposit(RuntimeOrigin::signed(ALICE), vault_id, 1_000));
+		assert_ok!(<Vault as StrategicVault>::withdraw(&vault_id, &STRATEGY, 100));
+
+		// 1. Only the manager can liquidate a strategy.
+		assert_noop!(
+			Vault::liquidate(RuntimeOrigin::signed(ALICE), vault_id, STRATEGY),
+			Error::<Test>::OnlyManagerCanDoThisOperation
+		);
+		assert_ok!(Vault::liquidate(RuntimeOrigin::signed(BOB), vault_id, STRATEGY));
+		assert_eq!(
+			<Vault as StrategicVault>::available_funds(&vault_id, &STRATEGY),
+			Ok(FundsAvailability::MustLiquidate)
+		);
+		assert_eq!(Vault::vault_data(vault_id).expect("vault exists").reserved, Perquintill::one());
+		assert_ok!(<Vault as StrategicVault>::deposit(&vault_id, &STRATEGY, 100));
+		assert_eq!(
+			<Vault as StrategicVault>::available_funds(&vault_id, &STRATEGY),
+			Ok(FundsAvailability::None)
+		);
+
+		// 2. A stopped vault rejects deposits and withdrawals until started again.
+		assert_noop!(
+			Vault::emergency_shutdown(RuntimeOrigin::signed(BOB), vault_id),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Vault::emergency_shutdown(RuntimeOrigin::signed(ALICE), vault_id));
+		assert_noop!(
+			Vault::deposit(RuntimeOrigin::signed(ALICE), vault_id, 1),
+			Error::<Test>::DepositsHalted
+		);
+		assert_noop!(
+			Vault::withdraw(RuntimeOrigin::signed(ALICE), vault_id, 1),
+			Error::<Test>::WithdrawalsHalted
+		);
+		assert_ok!(Vault::start(RuntimeOrigin::signed(ALICE), vault_id));
+		assert_ok!(Vault::withdraw(RuntimeOrigin::signed(ALICE), vault_id, 990));
+	})
+}
+
+#[test]
+fn rent_tombstones_and_deletes_vault() {
+	new_test_ext().execute_with(|| {
+		let vault_id = create_vault(100);
+		let lp_token = <Vault as VaultTrait>::lp_asset_id(&vault_id).expect("vault exists");
+		assert_eq!(
+			Vault::vault_data(vault_id).expect("vault exists").deposit,
+			Deposit::Rent { amount: 100, at: 1 }
+		);
+		assert_ok!(Tokens::mint_into(BTC, &ALICE, 1_000));
+		assert_ok!(Vault::deposit(RuntimeOrigin::signed(ALICE), vault_id, 1_000));
+
+		// 1. Rent accrues every block and is paid to the claimer.
+		System::set_block_number(11);
+		assert_ok!(Vault::claim_surcharge(RuntimeOrigin::signed(CHARLIE), vault_id, None));
+		assert_eq!(Balances::balance(&CHARLIE), 10);
+		assert_noop!(
+			Vault::claim_surcharge(RuntimeOrigin::signed(CHARLIE), vault_id, None),
+			Error::<Test>::RentNotDue
+		);
+
+		// 2. A vault which can't pay its rent is tombstoned, only withdrawals remain.
+		System::set_block_number(200);
+		assert_ok!(Vault::claim_surcharge(RuntimeOrigin::signed(CHARLIE), vault_id, None));
+		assert_eq!(Balances::balance(&CHARLIE), 100);
+		assert_eq!(<Vault as CapabilityVault>::is_tombstoned(&vault_id), Ok(true));
+		assert_noop!(
+			Vault::deposit(RuntimeOrigin::signed(ALICE), vault_id, 1),
+			Error::<Test>::DepositsHalted
+		);
+
+		// 3. Deletion requires the tombstone duration to elapse and every LP token to be burnt.
+		assert_noop!(
+			Vault::delete_tombstoned(RuntimeOrigin::signed(CHARLIE), vault_id),
+			Error::<Test>::TombstoneDurationNotExceeded
+		);
+		System::set_block_number(200 + TombstoneDuration::get());
+		assert_noop!(
+			Vault::delete_tombstoned(RuntimeOrigin::signed(CHARLIE), vault_id),
+			Error::<Test>::VaultNotEmpty
+		);
+		assert_ok!(Vault::withdraw(RuntimeOrigin::signed(ALICE), vault_id, 990));
+		assert_ok!(Vault::delete_tombstoned(RuntimeOrigin::signed(CHARLIE), vault_id));
+		assert!(Vault::vault_data(vault_id).is_none());
+		assert_eq!(<Tokens as Inspect<AccountId>>::total_issuance(lp_token), 0);
+		assert!(CapitalStructure::<Test>::get(vault_id, STRATEGY).is_none());
+
+		// 4. The assets backing the locked liquidity are swept to the manager.
+		assert_eq!(Tokens::balance(BTC, &BOB), LockedLiquidity::get());
+		System::assert_last_event(
+			Event::<Test>::VaultDeleted {
+				vault_id,
+				manager: BOB,
+				swept: LockedLiquidity::get(),
+				refunded: 0,
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn add_surcharge_restores_tombstoned_vault() {
+	new_test_ext().execute_with(|| {
+		let vault_id = create_vault(100);
+		System::set_block_number(200);
+		assert_ok!(Vault::claim_surcharge(RuntimeOrigin::signed(CHARLIE), vault_id, None));
+		assert_eq!(<Vault as CapabilityVault>::is_tombstoned(&vault_id), Ok(true));
+
+		assert_ok!(Vault::add_surcharge(RuntimeOrigin::signed(BOB), vault_id, 50));
+		assert_eq!(<Vault as CapabilityVault>::is_tombstoned(&vault_id), Ok(false));
+		assert_eq!(
+			Vault::vault_data(vault_id).expect("vault exists").deposit,
+			Deposit::Rent { amount: 50, at: 200 }
+		);
+
+		// Reaching the existential deposit exempts the vault from rent.
+		assert_ok!(Vault::add_surcharge(
+			RuntimeOrigin::signed(BOB),
+			vault_id,
+			VaultExistentialDeposit::get()
+		));
+		assert_eq!(
+			Vault::vault_data(vault_id).expect("vault exists").deposit,
+			Deposit::Existential
+		);
+	})
+}
+
+#[test]
+fn delete_tombstoned_requires_withdrawn_strategies_and_refunds_deposit() {
+	new_test_ext().execute_with(|| {
+		let vault_id = create_vault(100);
+		assert_eq!(Balances::balance(&BOB), 1_000_000 - 100);
+		assert_ok!(Tokens::mint_into(BTC, &ALICE, 1_000));
+		assert_ok!(Vault::deposit(RuntimeOrigin::signed(ALICE), vault_id, 1_000));
+		assert_ok!(<Vault as StrategicVault>::withdraw(&vault_id, &STRATEGY, 5));
+		assert_ok!(<Vault as CapabilityVault>::tombstone(&vault_id));
+		assert_ok!(Vault::withdraw(RuntimeOrigin::signed(ALICE), vault_id, 990));
+		System::set_block_number(1 + TombstoneDuration::get());
+
+		// 1. Strategies must return what they hold first.
+		assert_noop!(
+			Vault::delete_tombstoned(RuntimeOrigin::signed(CHARLIE), vault_id),
+			Error::<Test>::StrategiesNotWithdrawn
+		);
+		assert_ok!(<Vault as StrategicVault>::deposit(&vault_id, &STRATEGY, 5));
+
+		// 2. The unused rent deposit is refunded to the manager.
+		assert_ok!(Vault::delete_tombstoned(RuntimeOrigin::signed(CHARLIE), vault_id));
+		assert_eq!(Balances::balance(&BOB), 1_000_000);
+		assert_eq!(Balances::balance(&Vault::account_id_of_pallet()), 0);
+		assert_eq!(Tokens::balance(BTC, &BOB), LockedLiquidity::get());
+		assert_eq!(Tokens::balance(BTC, &<Vault as VaultTrait>::account_id(&vault_id)), 0);
+	})
+}
+
+#[test]
+fn vault_exempt_from_rent_is_not_deleted() {
+	new_test_ext().execute_with(|| {
+		let vault_id = create_vault(VaultExistentialDeposit::get());
+		assert_noop!(
+			Vault::claim_surcharge(RuntimeOrigin::signed(CHARLIE), vault_id, None),
+			Error::<Test>::RentNotDue
+		);
+		assert_noop!(
+			Vault::delete_tombstoned(RuntimeOrigin::signed(CHARLIE), vault_id),
+			Error::<Test>::VaultNotTombstoned
+		);
+
+		assert_ok!(<Vault as CapabilityVault>::tombstone(&vault_id));
+		System::set_block_number(1 + TombstoneDuration::get());
+		assert_noop!(
+			Vault::delete_tombstoned(RuntimeOrigin::signed(CHARLIE), vault_id),
+			Error::<Test>::VaultNotTombstoned
+		);
+	})
+}
+
+#[test]
+fn report_requires_manager_or_registered_strategy() {
+	new_test_ext().execute_with(|| {
+		let vault_id = create_vault(VaultExistentialDeposit::get());
+		assert_noop!(
+			Vault::report(RuntimeOrigin::signed(CHARLIE), vault_id, STRATEGY, 0),
+			Error::<Test>::AccountIsNotManagerOrStrategy
+		);
+		assert_noop!(
+			Vault::report(RuntimeOrigin::signed(CHARLIE), vault_id, CHARLIE, 0),
+			Error::<Test>::StrategyNotRegistered
+		);
+		assert_ok!(Vault::report(RuntimeOrigin::signed(BOB), vault_id, STRATEGY, 0));
+	})
+}
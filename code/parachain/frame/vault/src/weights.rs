@@ -0,0 +1,54 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+pub trait WeightInfo {
+	fn create() -> Weight;
+	fn claim_surcharge() -> Weight;
+	fn add_surcharge() -> Weight;
+	fn delete_tombstoned() -> Weight;
+	fn deposit() -> Weight;
+	fn withdraw() -> Weight;
+	fn emergency_shutdown() -> Weight;
+	fn start() -> Weight;
+	fn liquidate() -> Weight;
+	fn report() -> Weight;
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn claim_surcharge() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn add_surcharge() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn delete_tombstoned() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn deposit() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn withdraw() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn emergency_shutdown() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn start() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn liquidate() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+	fn report() -> Weight {
+    Weight::from_parts(10_000 , 0)
+  }
+}
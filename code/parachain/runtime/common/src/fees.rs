@@ -1,6 +1,11 @@
 use crate::{prelude::*, Balance};
 use composable_support::math::safe::safe_multiply_by_rational;
-use composable_traits::currency::{AssetRatioInspect, Rational64};
+pub use composable_traits::currency::{FeeAssetPriceSource, FeeAssetPriceSources};
+use composable_traits::{
+	currency::{AssetRatioInspect, LocalAssets, Rational64},
+	dex::Twap,
+	oracle::Oracle,
+};
 
 use composable_traits::currency::AssetExistentialDepositInspect;
 use frame_support::{
@@ -12,7 +17,7 @@ use frame_support::{
 	},
 };
 use primitives::currency::CurrencyId;
-use sp_runtime::Perbill;
+use sp_runtime::{ArithmeticError, FixedPointNumber, Perbill};
 
 pub const NATIVE_EXISTENTIAL_DEPOSIT: NativeBalance = 100_000_000_000;
 pub type NativeExistentialDeposit = ConstU128<NATIVE_EXISTENTIAL_DEPOSIT>;
@@ -105,6 +110,87 @@ impl<
 	}
 }
 
+/// Converts native fees to fee assets at their oracle or pablo TWAP, as configured by `Sources`,
+/// falling back to the static ratio of `Static` when the TWAP is not available.
+///
+/// When `Static` prices the asset, the converted amount is bounded to the maximum deviation of
+/// the asset from its static amount, so that a manipulated or stale TWAP cannot make fees
+/// arbitrarily cheap or expensive.
+pub struct TwapPriceConverter<NativeAssetId, Oracle, Dex, Sources, Static>(
+	PhantomData<(NativeAssetId, Oracle, Dex, Sources, Static)>,
+);
+
+impl<NativeAssetId, OracleT, Dex, Sources, Static>
+	TwapPriceConverter<NativeAssetId, OracleT, Dex, Sources, Static>
+where
+	NativeAssetId: Get<CurrencyId>,
+	OracleT: Oracle<AssetId = CurrencyId, Balance = Balance>,
+	Dex: Twap<AssetId = CurrencyId, PoolId = Sources::PoolId>,
+	Sources: FeeAssetPriceSources<AssetId = CurrencyId>,
+	Sources::PoolId: Copy,
+{
+	fn twap_to_asset_balance(
+		native_amount: NativeBalance,
+		asset_id: CurrencyId,
+		source: FeeAssetPriceSource<Sources::PoolId>,
+	) -> Result<Balance, DispatchError> {
+		match source {
+			FeeAssetPriceSource::Oracle => {
+				let native_value =
+					OracleT::get_twap_for_amount(NativeAssetId::get(), native_amount)?;
+				let unit: Balance = OracleT::LocalAssets::unit(asset_id)?;
+				let unit_value = OracleT::get_twap_for_amount(asset_id, unit)?;
+				Ok(safe_multiply_by_rational(native_value, unit, unit_value)?)
+			},
+			FeeAssetPriceSource::Pablo(pool_id) => {
+				// the pool must be of the fee asset and the native asset
+				Dex::get_twap(pool_id, asset_id)?;
+				Dex::get_twap(pool_id, NativeAssetId::get())?
+					.checked_mul_int(native_amount)
+					.ok_or(DispatchError::Arithmetic(ArithmeticError::Overflow))
+			},
+		}
+	}
+}
+
+impl<NativeAssetId, OracleT, Dex, Sources, Static>
+	ConversionToAssetBalance<NativeBalance, CurrencyId, Balance>
+	for TwapPriceConverter<NativeAssetId, OracleT, Dex, Sources, Static>
+where
+	NativeAssetId: Get<CurrencyId>,
+	OracleT: Oracle<AssetId = CurrencyId, Balance = Balance>,
+	Dex: Twap<AssetId = CurrencyId, PoolId = Sources::PoolId>,
+	Sources: FeeAssetPriceSources<AssetId = CurrencyId>,
+	Sources::PoolId: Copy,
+	Static: ConversionToAssetBalance<NativeBalance, CurrencyId, Balance, Error = DispatchError>,
+{
+	type Error = DispatchError;
+
+	fn to_asset_balance(
+		native_amount: NativeBalance,
+		asset_id: CurrencyId,
+	) -> Result<Balance, Self::Error> {
+		if asset_id == NativeAssetId::get() {
+			return Ok(native_amount)
+		}
+		let static_amount = Static::to_asset_balance(native_amount, asset_id);
+		let Some((source, max_deviation)) = Sources::price_source(asset_id) else {
+			return static_amount
+		};
+		match (Self::twap_to_asset_balance(native_amount, asset_id, source), static_amount) {
+			(Ok(amount), Ok(static_amount)) => {
+				let deviation = max_deviation.mul_ceil(static_amount);
+				Ok(amount.clamp(
+					static_amount.saturating_sub(deviation),
+					static_amount.saturating_add(deviation),
+				))
+			},
+			(Ok(amount), Err(_)) => Ok(amount),
+			(Err(_), static_amount) => static_amount,
+		}
+	}
+}
+
 parameter_types! {
 	pub IbcIcs20ServiceCharge: Perbill = Perbill::from_rational(5_u32, 1000_u32 );
 	pub const IbcIcs20FeePalletId: frame_support::PalletId = frame_support::PalletId(*b"ics20fee");
@@ -128,6 +214,7 @@ mod commons_sense {
 	use composable_traits::currency::AssetRatioInspect;
 	use frame_support::weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight, WeightToFee};
 	use primitives::currency::CurrencyId;
+	use sp_runtime::Permill;
 
 	#[test]
 	fn reasonable_fee() {
@@ -180,6 +267,122 @@ mod commons_sense {
 		assert_eq!(converted_static, 15);
 	}
 
+	struct DummyOracle;
+	impl Oracle for DummyOracle {
+		type AssetId = CurrencyId;
+		type Balance = Balance;
+		type Timestamp = ();
+		type LocalAssets = ();
+		type MinAnswerBound = ConstU32<0>;
+		type MaxAnswerBound = ConstU32<0>;
+		type TwapWindow = sp_core::ConstU16<0>;
+
+		fn get_price(
+			_asset_id: CurrencyId,
+			_amount: Balance,
+		) -> Result<composable_traits::oracle::Price<Balance, ()>, DispatchError> {
+			Err(DispatchError::Other("Dummy can't find"))
+		}
+
+		fn get_twap_for_amount(
+			asset_id: CurrencyId,
+			amount: Balance,
+		) -> Result<Balance, DispatchError> {
+			match asset_id {
+				CurrencyId::PICA => Ok(amount * 16 / 1_000_000_000),
+				CurrencyId::USDT => Ok(amount),
+				_ => Err(DispatchError::Other("Dummy can't find")),
+			}
+		}
+
+		fn get_ratio(
+			_pair: composable_traits::defi::CurrencyPair<CurrencyId>,
+		) -> Result<sp_runtime::FixedU128, DispatchError> {
+			Err(DispatchError::Other("Dummy can't find"))
+		}
+
+		fn get_price_inverse(
+			_asset_id: CurrencyId,
+			_amount: Balance,
+		) -> Result<Balance, DispatchError> {
+			Err(DispatchError::Other("Dummy can't find"))
+		}
+	}
+
+	/// Pools of PICA and any asset but kUSD price PICA at their id in billionths of the other
+	/// asset.
+	struct DummyDex;
+	impl Twap for DummyDex {
+		type AssetId = CurrencyId;
+		type PoolId = u128;
+
+		fn get_twap(
+			pool_id: u128,
+			asset_id: CurrencyId,
+		) -> Result<composable_traits::defi::Rate, DispatchError> {
+			if pool_id == 0 || asset_id == CurrencyId::kUSD {
+				return Err(DispatchError::Other("Dummy can't find"))
+			}
+			Ok(composable_traits::defi::Rate::saturating_from_rational(pool_id, 1_000_000_000))
+		}
+	}
+
+	struct DummySources;
+	impl FeeAssetPriceSources for DummySources {
+		type AssetId = CurrencyId;
+		type PoolId = u128;
+
+		fn price_source(asset_id: CurrencyId) -> Option<(FeeAssetPriceSource<u128>, Permill)> {
+			match asset_id {
+				CurrencyId::USDT => Some((FeeAssetPriceSource::Oracle, Permill::from_percent(50))),
+				CurrencyId::USDC =>
+					Some((FeeAssetPriceSource::Pablo(100), Permill::from_percent(50))),
+				CurrencyId::KSM => Some((FeeAssetPriceSource::Pablo(0), Permill::from_percent(10))),
+				CurrencyId::kUSD =>
+					Some((FeeAssetPriceSource::Pablo(100), Permill::from_percent(50))),
+				_ => None,
+			}
+		}
+	}
+
+	parameter_types! {
+		pub const NativeAssetId: CurrencyId = CurrencyId::PICA;
+	}
+
+	type DummyTwapConverter = TwapPriceConverter<
+		NativeAssetId,
+		DummyOracle,
+		DummyDex,
+		DummySources,
+		PriceConverter<Dummy, Dummy>,
+	>;
+
+	#[test]
+	fn twap_price_is_bounded_by_static_ratio() {
+		// 1. The oracle TWAP is within the maximum deviation from the static ratio.
+		assert_eq!(DummyTwapConverter::to_asset_balance(1_000_000_000, CurrencyId::USDT), Ok(16));
+		// 2. The pablo TWAP is bounded to the maximum deviation.
+		assert_eq!(
+			DummyTwapConverter::to_asset_balance(1_000_000_000, CurrencyId::USDC),
+			Ok(15 + 8)
+		);
+		// 3. The static ratio is used without TWAP.
+		assert_eq!(
+			DummyTwapConverter::to_asset_balance(1_000_000_000, CurrencyId::KSM),
+			PriceConverter::<Dummy, Dummy>::to_asset_balance(1_000_000_000, CurrencyId::KSM)
+		);
+		// 4. The pool must hold the fee asset.
+		assert_eq!(
+			DummyTwapConverter::to_asset_balance(1_000_000_000, CurrencyId::kUSD),
+			PriceConverter::<Dummy, Dummy>::to_asset_balance(1_000_000_000, CurrencyId::kUSD)
+		);
+		// 5. The native asset is not converted.
+		assert_eq!(
+			DummyTwapConverter::to_asset_balance(1_000_000_000, CurrencyId::PICA),
+			Ok(1_000_000_000)
+		);
+	}
+
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	#[test]
 	fn ksm() {
@@ -26,6 +26,7 @@ pub mod ibc;
 mod prelude;
 pub mod rewards;
 mod smoldot;
+pub mod stargate;
 pub mod xcmp;
 
 pub use constants::*;
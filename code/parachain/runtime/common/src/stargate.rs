@@ -0,0 +1,378 @@
+//! Gateway mapping Stargate (`Any` typed protobuf) messages and queries onto pallets.
+//!
+//! Contracts send `CosmosMsg::Stargate` and `QueryRequest::Stargate` JSON to the gateway
+//! precompile. The `value`/`data` is decoded as the protobuf message identified by the type
+//! URL/path, handled by the matching pallet and, for queries, answered with a protobuf encoded
+//! response.
+use crate::{cosmwasm::*, prelude::*, AccountId, Balance, PoolId};
+use composable_traits::{
+	assets::InspectRegistryMetadata, cosmwasm::CosmwasmSubstrateError, dex::Amm, oracle::Oracle,
+};
+use cosmwasm_std::{Binary, Coin, Event, QueryResponse, Response, Uint128};
+use frame_support::traits::fungibles;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use sp_runtime::traits::Convert;
+
+/// Mirrors `CosmosMsg::Stargate`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+	Stargate { type_url: String, value: Binary },
+}
+
+/// Mirrors `QueryRequest::Stargate`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+	Stargate { path: String, data: Binary },
+}
+
+/// Type URLs and query paths understood by the gateway.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Route {
+	BankSend,
+	PabloSwap,
+	BankBalance,
+	PabloSpotPrice,
+	OraclePrice,
+	AssetsMetadata,
+}
+
+impl Route {
+	pub fn from_type_url(type_url: &str) -> Option<Self> {
+		match type_url {
+			"/cosmos.bank.v1beta1.MsgSend" => Some(Self::BankSend),
+			"/composable.pablo.v1.MsgSwap" => Some(Self::PabloSwap),
+			"/cosmos.bank.v1beta1.Query/Balance" => Some(Self::BankBalance),
+			"/composable.pablo.v1.Query/SpotPrice" => Some(Self::PabloSpotPrice),
+			"/composable.oracle.v1.Query/Price" => Some(Self::OraclePrice),
+			"/composable.assets.v1.Query/Metadata" => Some(Self::AssetsMetadata),
+			_ => None,
+		}
+	}
+
+	pub fn is_query(&self) -> bool {
+		!matches!(self, Self::BankSend | Self::PabloSwap)
+	}
+}
+
+pub mod proto {
+	use super::*;
+
+	#[derive(Clone, PartialEq, Message)]
+	pub struct Coin {
+		#[prost(string, tag = "1")]
+		pub denom: String,
+		#[prost(string, tag = "2")]
+		pub amount: String,
+	}
+
+	#[derive(Clone, PartialEq, Message)]
+	pub struct MsgSend {
+		#[prost(string, tag = "1")]
+		pub from_address: String,
+		#[prost(string, tag = "2")]
+		pub to_address: String,
+		#[prost(message, repeated, tag = "3")]
+		pub amount: Vec<Coin>,
+	}
+
+	#[derive(Clone, PartialEq, Message)]
+	pub struct MsgSwap {
+		#[prost(string, tag = "1")]
+		pub sender: String,
+		#[prost(string, tag = "2")]
+		pub pool_id: String,
+		#[prost(message, optional, tag = "3")]
+		pub in_asset: Option<Coin>,
+		#[prost(message, optional, tag = "4")]
+		pub min_receive: Option<Coin>,
+		#[prost(bool, tag = "5")]
+		pub keep_alive: bool,
+	}
+
+	#[derive(Clone, PartialEq, Message)]
+	pub struct MsgSwapResponse {
+		#[prost(message, optional, tag = "1")]
+		pub value: Option<Coin>,
+		#[prost(message, optional, tag = "2")]
+		pub fee: Option<Coin>,
+	}
+
+	#[derive(Clone, PartialEq, Message)]
+	pub struct QueryBalanceRequest {
+		#[prost(string, tag = "1")]
+		pub address: String,
+		#[prost(string, tag = "2")]
+		pub denom: String,
+	}
+
+	#[derive(Clone, PartialEq, Message)]
+	pub struct QueryBalanceResponse {
+		#[prost(message, optional, tag = "1")]
+		pub balance: Option<Coin>,
+	}
+
+	#[derive(Clone, PartialEq, Message)]
+	pub struct QuerySpotPriceRequest {
+		#[prost(string, tag = "1")]
+		pub pool_id: String,
+		#[prost(message, optional, tag = "2")]
+		pub base_asset: Option<Coin>,
+		#[prost(string, tag = "3")]
+		pub quote_asset_id: String,
+		#[prost(bool, tag = "4")]
+		pub calculate_with_fees: bool,
+	}
+
+	#[derive(Clone, PartialEq, Message)]
+	pub struct QuerySpotPriceResponse {
+		#[prost(message, optional, tag = "1")]
+		pub value: Option<Coin>,
+		#[prost(message, optional, tag = "2")]
+		pub fee: Option<Coin>,
+	}
+
+	#[derive(Clone, PartialEq, Message)]
+	pub struct QueryPriceRequest {
+		#[prost(string, tag = "1")]
+		pub denom: String,
+		#[prost(string, tag = "2")]
+		pub amount: String,
+	}
+
+	#[derive(Clone, PartialEq, Message)]
+	pub struct QueryPriceResponse {
+		#[prost(string, tag = "1")]
+		pub price: String,
+		#[prost(uint64, tag = "2")]
+		pub block: u64,
+	}
+
+	#[derive(Clone, PartialEq, Message)]
+	pub struct QueryMetadataRequest {
+		#[prost(string, tag = "1")]
+		pub denom: String,
+	}
+
+	#[derive(Clone, PartialEq, Message)]
+	pub struct QueryMetadataResponse {
+		#[prost(string, tag = "1")]
+		pub name: String,
+		#[prost(string, tag = "2")]
+		pub symbol: String,
+		#[prost(uint32, tag = "3")]
+		pub decimals: u32,
+	}
+}
+
+/// Moves funds on behalf of contracts.
+///
+/// Implementations are expected to go through the same path as `BankMsg::Send`, so that asset
+/// checks and freezes apply to Stargate sends too.
+pub trait Bank {
+	fn send(from: &AccountId, to: &AccountId, funds: &[Coin])
+		-> Result<(), CosmwasmSubstrateError>;
+}
+
+pub struct StargatePrecompile<Dex, Bank, Assets, Prices, Registry>(
+	PhantomData<(Dex, Bank, Assets, Prices, Registry)>,
+);
+
+impl<Dex, B, Assets, Prices, Registry> StargatePrecompile<Dex, B, Assets, Prices, Registry>
+where
+	Dex: Amm<AssetId = CurrencyId, PoolId = PoolId, Balance = Balance, AccountId = AccountId>,
+	B: Bank,
+	Assets: fungibles::Inspect<AccountId, AssetId = CurrencyId, Balance = Balance>,
+	Prices: Oracle<AssetId = CurrencyId, Balance = Balance>,
+	Prices::Timestamp: Into<u64>,
+	Registry: InspectRegistryMetadata<AssetId = CurrencyId>,
+{
+	fn decode<M: Message + Default>(value: &[u8]) -> Result<M, CosmwasmSubstrateError> {
+		M::decode(value).map_err(|_| CosmwasmSubstrateError::Stargate)
+	}
+
+	fn account(address: &str) -> Result<AccountId, CosmwasmSubstrateError> {
+		CosmwasmToSubstrateAccount::convert(address.to_string())
+			.map_err(|_| CosmwasmSubstrateError::AccountConvert)
+	}
+
+	fn to_substrate(
+		coin: Option<proto::Coin>,
+	) -> Result<(CurrencyId, Balance), CosmwasmSubstrateError> {
+		let coin = coin.ok_or(CosmwasmSubstrateError::Stargate)?;
+		let asset_id = CosmwasmToSubstrateAssetId::convert(coin.denom)
+			.map_err(|_| CosmwasmSubstrateError::AssetConversion)?;
+		let amount = coin.amount.parse().map_err(|_| CosmwasmSubstrateError::Stargate)?;
+		Ok((asset_id, amount))
+	}
+
+	fn to_proto(asset_id: CurrencyId, amount: Balance) -> proto::Coin {
+		proto::Coin {
+			denom: CosmwasmToSubstrateAssetId::convert(asset_id),
+			amount: amount.to_string(),
+		}
+	}
+
+	pub fn execute(sender: &str, msg: ExecuteMsg) -> Result<Response, CosmwasmSubstrateError> {
+		let ExecuteMsg::Stargate { type_url, value } = msg;
+		match Route::from_type_url(&type_url) {
+			Some(Route::BankSend) => {
+				let msg: proto::MsgSend = Self::decode(value.as_slice())?;
+				if msg.from_address != sender {
+					return Err(CosmwasmSubstrateError::AccountConvert)
+				}
+				let from = Self::account(&msg.from_address)?;
+				let to = Self::account(&msg.to_address)?;
+				let funds = msg
+					.amount
+					.into_iter()
+					.map(|coin| {
+						let amount: u128 =
+							coin.amount.parse().map_err(|_| CosmwasmSubstrateError::Stargate)?;
+						Ok(Coin { denom: coin.denom, amount: Uint128::new(amount) })
+					})
+					.collect::<Result<Vec<_>, CosmwasmSubstrateError>>()?;
+				B::send(&from, &to, &funds)?;
+				Ok(Response::new().add_event(
+					Event::new("stargate.bank.send")
+						.add_attribute("from", msg.from_address)
+						.add_attribute("to", msg.to_address),
+				))
+			},
+			Some(Route::PabloSwap) => {
+				let msg: proto::MsgSwap = Self::decode(value.as_slice())?;
+				if msg.sender != sender {
+					return Err(CosmwasmSubstrateError::AccountConvert)
+				}
+				let who = Self::account(&msg.sender)?;
+				let pool_id = msg.pool_id.parse().map_err(|_| CosmwasmSubstrateError::Stargate)?;
+				let (in_asset_id, in_amount) = Self::to_substrate(msg.in_asset)?;
+				let (min_receive_id, min_receive_amount) = Self::to_substrate(msg.min_receive)?;
+				let result = Dex::do_swap(
+					&who,
+					pool_id,
+					composable_traits::dex::AssetAmount::new(in_asset_id, in_amount),
+					composable_traits::dex::AssetAmount::new(min_receive_id, min_receive_amount),
+					msg.keep_alive,
+				)
+				.map_err(|_| CosmwasmSubstrateError::DispatchError)?;
+				let response = proto::MsgSwapResponse {
+					value: Some(Self::to_proto(result.value.asset_id, result.value.amount)),
+					fee: Some(Self::to_proto(result.fee.asset_id, result.fee.amount)),
+				};
+				Ok(Response::new()
+					.add_event(
+						Event::new("stargate.pablo.swap").add_attribute("pool_id", msg.pool_id),
+					)
+					.set_data(response.encode_to_vec()))
+			},
+			_ => Err(CosmwasmSubstrateError::Stargate),
+		}
+	}
+
+	pub fn query(msg: QueryMsg) -> Result<QueryResponse, CosmwasmSubstrateError> {
+		let QueryMsg::Stargate { path, data } = msg;
+		let response = match Route::from_type_url(&path) {
+			Some(Route::BankBalance) => {
+				let request: proto::QueryBalanceRequest = Self::decode(data.as_slice())?;
+				let who = Self::account(&request.address)?;
+				let asset_id = CosmwasmToSubstrateAssetId::convert(request.denom)
+					.map_err(|_| CosmwasmSubstrateError::AssetConversion)?;
+				proto::QueryBalanceResponse {
+					balance: Some(Self::to_proto(asset_id, Assets::balance(asset_id, &who))),
+				}
+				.encode_to_vec()
+			},
+			Some(Route::PabloSpotPrice) => {
+				let request: proto::QuerySpotPriceRequest = Self::decode(data.as_slice())?;
+				let pool_id =
+					request.pool_id.parse().map_err(|_| CosmwasmSubstrateError::Stargate)?;
+				let (base_asset_id, base_amount) = Self::to_substrate(request.base_asset)?;
+				let quote_asset_id = CosmwasmToSubstrateAssetId::convert(request.quote_asset_id)
+					.map_err(|_| CosmwasmSubstrateError::AssetConversion)?;
+				let result = Dex::spot_price(
+					pool_id,
+					composable_traits::dex::AssetAmount::new(base_asset_id, base_amount),
+					quote_asset_id,
+					request.calculate_with_fees,
+				)
+				.map_err(|_| CosmwasmSubstrateError::DispatchError)?;
+				proto::QuerySpotPriceResponse {
+					value: Some(Self::to_proto(result.value.asset_id, result.value.amount)),
+					fee: Some(Self::to_proto(result.fee.asset_id, result.fee.amount)),
+				}
+				.encode_to_vec()
+			},
+			Some(Route::OraclePrice) => {
+				let request: proto::QueryPriceRequest = Self::decode(data.as_slice())?;
+				let (asset_id, amount) = Self::to_substrate(Some(proto::Coin {
+					denom: request.denom,
+					amount: request.amount,
+				}))?;
+				let price = Prices::get_price(asset_id, amount)
+					.map_err(|_| CosmwasmSubstrateError::DispatchError)?;
+				proto::QueryPriceResponse {
+					price: price.price.to_string(),
+					block: price.block.into(),
+				}
+				.encode_to_vec()
+			},
+			Some(Route::AssetsMetadata) => {
+				let request: proto::QueryMetadataRequest = Self::decode(data.as_slice())?;
+				let asset_id = CosmwasmToSubstrateAssetId::convert(request.denom)
+					.map_err(|_| CosmwasmSubstrateError::AssetConversion)?;
+				let lossy = |bytes: Option<Vec<u8>>| {
+					String::from_utf8_lossy(&bytes.unwrap_or_default()).into_owned()
+				};
+				proto::QueryMetadataResponse {
+					name: lossy(Registry::asset_name(&asset_id)),
+					symbol: lossy(Registry::symbol(&asset_id)),
+					decimals: Registry::decimals(&asset_id)
+						.ok_or(CosmwasmSubstrateError::AssetConversion)?
+						.into(),
+				}
+				.encode_to_vec()
+			},
+			_ => return Err(CosmwasmSubstrateError::Stargate),
+		};
+		Ok(response.into())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn routes() {
+		assert_eq!(Route::from_type_url("/cosmos.bank.v1beta1.MsgSend"), Some(Route::BankSend));
+		assert!(!Route::BankSend.is_query());
+		assert!(Route::OraclePrice.is_query());
+		assert_eq!(Route::from_type_url("/cosmos.staking.v1beta1.MsgDelegate"), None);
+	}
+
+	#[test]
+	fn stargate_json_matches_cosmwasm_std() {
+		let value = proto::MsgSend {
+			from_address: "from".into(),
+			to_address: "to".into(),
+			amount: vec![proto::Coin { denom: "1".into(), amount: "42".into() }],
+		}
+		.encode_to_vec();
+		let msg = cosmwasm_std::CosmosMsg::<cosmwasm_std::Empty>::Stargate {
+			type_url: "/cosmos.bank.v1beta1.MsgSend".into(),
+			value: value.into(),
+		};
+		let msg: ExecuteMsg =
+			serde_json_wasm::from_slice(&serde_json_wasm::to_vec(&msg).expect("serializes"))
+				.expect("deserializes");
+		let ExecuteMsg::Stargate { type_url, value: decoded } = msg;
+		assert_eq!(type_url, "/cosmos.bank.v1beta1.MsgSend");
+		assert_eq!(
+			proto::MsgSend::decode(decoded.as_slice()).expect("decodes").amount,
+			vec![proto::Coin { denom: "1".into(), amount: "42".into() }]
+		);
+	}
+}
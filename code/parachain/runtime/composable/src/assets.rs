@@ -1,6 +1,32 @@
 use super::*;
+use common::governance::native::EnsureRootOrHalfNativeTechnical;
+
+/// Reasons `pallet_assets` holds funds for, the underlying currencies hold them under `()`.
+#[derive(
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	Copy,
+	Clone,
+	codec::Encode,
+	codec::Decode,
+	codec::MaxEncodedLen,
+	Debug,
+	scale_info::TypeInfo,
+)]
+pub enum HoldReason {
+	/// Existential deposit held for a fee payment configuration of `asset_tx_payment`.
+	AssetTxPaymentConfiguration,
+}
+
+parameter_types! {
+	pub RecurringPaymentDeposit: Balance = 10 * CurrencyId::unit::<Balance>();
+	pub const MinRecurringPaymentInterval: BlockNumber = HOURS;
+}
 
 impl pallet_assets::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type NativeAssetId = NativeAssetId;
 	type AssetId = CurrencyId;
 	type Balance = Balance;
@@ -8,8 +34,13 @@ impl pallet_assets::Config for Runtime {
 	type MultiCurrency = Tokens;
 	type WeightInfo = ();
 	type AdminOrigin = EnsureRootOrHalfCouncil;
+	type FreezeOrigin = EnsureRootOrHalfNativeTechnical;
 	type CurrencyValidator = ValidateCurrencyId;
-	type RuntimeHoldReason = TemporalHoldIdentifier;
+	type RuntimeHoldReason = HoldReason;
+	type RecurringPaymentDeposit = RecurringPaymentDeposit;
+	type MaxRecurringPaymentsPerPayer = ConstU32<16>;
+	type MinRecurringPaymentInterval = MinRecurringPaymentInterval;
+	type MaxRecurringPaymentFailures = ConstU32<3>;
 }
 
 pub struct CurrencyHooks;
@@ -58,19 +58,20 @@ impl transaction_payment::Config for Runtime {
 
 pub type AssetsPaymentHeader = asset_tx_payment::ChargeAssetTxPayment<Runtime>;
 pub struct TransferToTreasuryOrDrop;
-impl asset_tx_payment::HandleCredit<AccountId, Tokens> for TransferToTreasuryOrDrop {
-	fn handle_credit(credit: fungibles::Credit<AccountId, Tokens>) {
+impl asset_tx_payment::HandleCredit<AccountId, Assets> for TransferToTreasuryOrDrop {
+	fn handle_credit(credit: fungibles::Credit<AccountId, Assets>) {
 		let _ =
-			<Tokens as fungibles::Balanced<AccountId>>::resolve(&TreasuryAccount::get(), credit);
+			<Assets as fungibles::Balanced<AccountId>>::resolve(&TreasuryAccount::get(), credit);
 	}
 }
 
 parameter_types! {
-	pub AssetConfigHoldIdentifier: TemporalHoldIdentifier = ();
+	pub const AssetConfigHoldReason: HoldReason = HoldReason::AssetTxPaymentConfiguration;
 }
 
 impl asset_tx_payment::Config for Runtime {
-	type Fungibles = Tokens;
+	// withdrawn through `Assets` so that frozen accounts cannot pay fees
+	type Fungibles = Assets;
 	type OnChargeAssetTransaction =
 		asset_tx_payment::FungiblesAdapter<FinalPriceConverter, TransferToTreasuryOrDrop>;
 
@@ -88,7 +89,32 @@ impl asset_tx_payment::Config for Runtime {
 
 	type BalanceConverter = FinalPriceConverter;
 
-	type HoldIdentifierValue = AssetConfigHoldIdentifier;
+	type HoldIdentifierValue = AssetConfigHoldReason;
 
-	type HoldIdentifier = TemporalHoldIdentifier;
+	type HoldIdentifier = HoldReason;
+
+	type MaxSponsoredCalls = ConstU32<16>;
+
+	type WrapperCalls = WrapperCalls;
+}
+
+/// Calls dispatching other calls, now or once scheduled, which sponsorship policies restricting
+/// the sponsored calls never pay for.
+pub struct WrapperCalls;
+impl Contains<RuntimeCall> for WrapperCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::Sudo(_) |
+				RuntimeCall::Multisig(_) |
+				RuntimeCall::Council(_) |
+				RuntimeCall::TechnicalCommittee(_) |
+				RuntimeCall::ReleaseCommittee(_) |
+				RuntimeCall::Scheduler(_) |
+				RuntimeCall::Utility(_) |
+				RuntimeCall::Proxy(_) |
+				RuntimeCall::PolkadotXcm(_) |
+				RuntimeCall::Whitelist(_)
+		)
+	}
 }
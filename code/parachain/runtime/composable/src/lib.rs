@@ -41,7 +41,7 @@ use common::{
 	NORMAL_DISPATCH_RATIO, SLOT_DURATION,
 };
 use composable_support::rpc_helpers::SafeRpcWrapper;
-use composable_traits::assets::Asset;
+use composable_traits::assets::{Asset, AssetFilter, ExtendedAsset, PortfolioEntry};
 use gates::*;
 use governance::*;
 use orml_traits::parameter_type_with_key;
@@ -215,6 +215,23 @@ impl assets_registry::Config for Runtime {
 	type WeightInfo = weights::assets_registry::WeightInfo<Runtime>;
 	type Convert = sp_runtime::traits::ConvertInto;
 	type NetworkId = ComposableNetworkId;
+	type Currency = Balances;
+	type ProposalDeposit = AssetProposalDeposit;
+	type MinProposedExistentialDeposit = MinProposedAssetExistentialDeposit;
+	type MinProposedFee = MinProposedAssetFee;
+	type ChallengePeriod = AssetProposalChallengePeriod;
+	type RejectProposalOrigin = EnsureRootOrHalfCouncil;
+	type TreasuryAccount = TreasuryAccount;
+	type PoolId = common::PoolId;
+}
+
+parameter_types! {
+	pub AssetProposalDeposit: Balance = 100 * CurrencyId::unit::<Balance>();
+	pub const AssetProposalChallengePeriod: BlockNumber = 7 * DAYS;
+	/// Proposed assets need an existential deposit and fees of at least a thousand of their
+	/// smallest units, so that accounts and transfers cannot be spammed for free.
+	pub const MinProposedAssetExistentialDeposit: Balance = 1_000;
+	pub const MinProposedAssetFee: Balance = 1_000;
 }
 
 impl aura::Config for Runtime {
@@ -419,6 +436,7 @@ parameter_types! {
 	pub const MinCandidates: u32 = 5;
 	pub const MaxMultihopCount: u32 = 10;
 	pub const ChainNameVecLimit: u32 = 30;
+	pub const MaxMultihopTransfersPerAccount: u32 = 16;
 }
 
 pub struct MultihopXcmIbcPalletId;
@@ -428,11 +446,33 @@ impl Get<u8> for MultihopXcmIbcPalletId {
 	}
 }
 
+/// Refunds the relay chain asset in the multihop benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MultihopXcmIbcBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_multihop_xcm_ibc::BenchmarkHelper<AccountId, CurrencyId>
+	for MultihopXcmIbcBenchmarkHelper
+{
+	fn refund_asset() -> CurrencyId {
+		CurrencyId::DOT
+	}
+
+	fn fund(account: &AccountId, asset_id: CurrencyId, amount: u128) {
+		use frame_support::traits::fungibles::Mutate;
+		Assets::mint_into(asset_id, account, amount).expect("asset is minted");
+	}
+}
+
 impl pallet_multihop_xcm_ibc::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type PalletInstanceId = MultihopXcmIbcPalletId;
 	type MaxMultihopCount = MaxMultihopCount;
 	type ChainNameVecLimit = ChainNameVecLimit;
+	type MaxTransfersPerAccount = MaxMultihopTransfersPerAccount;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MultihopXcmIbcBenchmarkHelper;
 }
 
 impl collator_selection::Config for Runtime {
@@ -563,6 +603,8 @@ parameter_types! {
 
 impl pallet_xcm_helper::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
 	type UpdateOrigin = EnsureRootOrHalfCouncil;
 	type Assets = Assets;
 	type XcmSender = crate::xcmp::XcmRouter;
@@ -787,10 +829,67 @@ mod benches {
 		[proxy, Proxy]
 		[assets_registry, AssetsRegistry]
 		[multisig, Multisig]
+		[pallet_multihop_xcm_ibc, PalletMultihopXcmIbc]
+		[pallet_xcm_helper, PalletXcmHelper]
 	);
 }
 
+/// Extended asset of the assets registry, as returned by the assets runtime API.
+fn rpc_extended_asset(
+	asset: ExtendedAsset<CurrencyId, Balance, ForeignAssetId>,
+) -> ExtendedAsset<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId> {
+	let ExtendedAsset { asset, metadata, location_history } = asset;
+	ExtendedAsset {
+		asset: Asset {
+			decimals: asset.decimals,
+			existential_deposit: SafeRpcWrapper(multi_existential_deposits::<
+				AssetsRegistry,
+				WellKnownForeignToNativePriceConverter,
+			>(&asset.id)),
+			id: SafeRpcWrapper(asset.id.into()),
+			foreign_id: asset.foreign_id,
+			name: asset.name,
+			symbol: asset.symbol,
+			ratio: asset.ratio,
+		},
+		metadata,
+		location_history,
+	}
+}
+
+/// Free and reserved balances of `account` in every registered asset it has funds in.
+///
+/// There are no vesting, staking, liquidity pools nor oracle on this runtime, so these parts of
+/// the portfolio are always empty, and assets are never valued: `value` is always `None`.
+fn rpc_portfolio(
+	account: &AccountId,
+) -> Vec<PortfolioEntry<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>> {
+	use orml_traits::{MultiCurrency, MultiReservableCurrency};
+	AssetsRegistry::get_assets_filtered(AssetFilter::default(), 0, u32::MAX)
+		.into_iter()
+		.filter_map(|asset| {
+			let asset_id = asset.asset.id;
+			let free = <Assets as MultiCurrency<AccountId>>::free_balance(asset_id, account);
+			let reserved =
+				<Assets as MultiReservableCurrency<AccountId>>::reserved_balance(asset_id, account);
+			if free.is_zero() && reserved.is_zero() {
+				return None
+			}
+			Some(PortfolioEntry {
+				asset: rpc_extended_asset(asset),
+				free: SafeRpcWrapper(free),
+				reserved: SafeRpcWrapper(reserved),
+				vesting_locked: SafeRpcWrapper(Zero::zero()),
+				staked: SafeRpcWrapper(Zero::zero()),
+				liquidity: SafeRpcWrapper(Zero::zero()),
+				value: None,
+			})
+		})
+		.collect()
+}
+
 impl_runtime_apis! {
+	#[api_version(3)]
 	impl assets_runtime_api::AssetsRuntimeApi<Block, CurrencyId, AccountId, Balance, ForeignAssetId> for Runtime {
 		fn balance_of(SafeRpcWrapper(asset_id): SafeRpcWrapper<CurrencyId>, account_id: AccountId) -> SafeRpcWrapper<Balance> /* Balance */ {
 			SafeRpcWrapper(<Assets as frame_support::traits::fungibles::Inspect::<AccountId>>::balance(asset_id, &account_id))
@@ -814,6 +913,37 @@ impl_runtime_apis! {
 			  }
 			).collect::<Vec<Asset<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>>>()
 		}
+
+		fn list_assets_extended(filter: AssetFilter, offset: u32, limit: u32) -> Vec<ExtendedAsset<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>> {
+			AssetsRegistry::get_assets_filtered(filter, offset, limit).into_iter().map(rpc_extended_asset).collect()
+		}
+
+		fn assets_by_symbol(symbol: Vec<u8>) -> Vec<ExtendedAsset<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>> {
+			AssetsRegistry::get_assets_by_symbol(symbol).into_iter().map(rpc_extended_asset).collect()
+		}
+
+		fn asset_by_ibc_denom_hash(hash: [u8; 32]) -> Option<ExtendedAsset<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>> {
+			AssetsRegistry::get_asset_by_ibc_denom_hash(hash).map(rpc_extended_asset)
+		}
+
+		// there is no oracle to value the portfolio with, so `valuation` is ignored
+		fn portfolio(account_id: AccountId, _valuation: bool) -> Vec<PortfolioEntry<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>> {
+			rpc_portfolio(&account_id)
+		}
+	}
+
+	impl pallet_multihop_xcm_ibc_runtime_api::MultihopXcmIbcRuntimeApi<Block, AccountId, CurrencyId> for Runtime {
+		fn transfer_status(transfer_id: u128) -> Option<composable_traits::xcm::memo::MultihopTransferStatus<AccountId, CurrencyId>> {
+			PalletMultihopXcmIbc::transfer_status(transfer_id)
+		}
+
+		fn transfers_of(owner: AccountId) -> Vec<(u128, composable_traits::xcm::memo::MultihopTransferStatus<AccountId, CurrencyId>)> {
+			PalletMultihopXcmIbc::transfers_of(owner)
+		}
+
+		fn routes() -> Vec<(u128, Vec<composable_traits::xcm::memo::ChainInfo>)> {
+			PalletMultihopXcmIbc::routes()
+		}
 	}
 
 	impl crowdloan_rewards_runtime_api::CrowdloanRewardsRuntimeApi<Block, AccountId, Balance> for Runtime {
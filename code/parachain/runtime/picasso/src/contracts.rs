@@ -14,6 +14,7 @@ use cosmwasm_vm::{
 };
 use cosmwasm_vm_wasmi::OwnedWasmiVM;
 use cumulus_primitives_core::WeightLimit;
+use frame_support::traits::tokens::Preservation;
 use sp_core::ConstU32;
 
 use sp_runtime::traits::AccountIdConversion;
@@ -35,7 +36,52 @@ parameter_types! {
 	pub const CodeStorageByteDeposit: u32 = 1_000_000;
 	pub const ContractStorageByteReadPrice: u32 = 1;
 	pub const ContractStorageByteWritePrice: u32 = 1;
+	pub const ContractStorageByteDeposit: u32 = 0;
 	pub WasmCostRules: CostRules<Runtime> = Default::default();
+	pub const StargatePalletId: PalletId = PalletId(*b"cw/stgwy");
+}
+
+type StargatePrecompile =
+	common::stargate::StargatePrecompile<Pablo, StargateBank, Assets, Oracle, AssetsRegistry>;
+
+/// Sends Stargate `MsgSend` funds through the pallet, like `BankMsg::Send`.
+pub struct StargateBank;
+
+impl common::stargate::Bank for StargateBank {
+	fn send(
+		from: &AccountId,
+		to: &AccountId,
+		funds: &[cosmwasm_std::Coin],
+	) -> Result<(), CosmwasmSubstrateError> {
+		Cosmwasm::do_transfer(from, to, funds, Preservation::Expendable)
+			.map_err(|_| CosmwasmSubstrateError::DispatchError)
+	}
+}
+
+/// Weight charged to the calling contract for a Stargate route, on top of the VM gas.
+fn stargate_weight(route: common::stargate::Route) -> Weight {
+	use common::stargate::Route;
+	use cosmwasm::weights::WeightInfo as _;
+	use pablo::weights::WeightInfo as _;
+	match route {
+		Route::BankSend =>
+			<Runtime as cosmwasm::Config>::WeightInfo::transfer(MaxFundsAssets::get()),
+		Route::PabloSwap => <Runtime as pablo::Config>::WeightInfo::swap(),
+		Route::BankBalance => <Runtime as cosmwasm::Config>::WeightInfo::balance(),
+		Route::PabloSpotPrice | Route::OraclePrice | Route::AssetsMetadata =>
+			<Runtime as system::Config>::DbWeight::get().reads(4),
+	}
+}
+
+fn charge_stargate<'a>(
+	vm: &mut OwnedWasmiVM<CosmwasmVM<'a, Runtime>>,
+	type_url: &str,
+	query: bool,
+) -> Result<(), CosmwasmVMError<Runtime>> {
+	let route = common::stargate::Route::from_type_url(type_url)
+		.filter(|route| route.is_query() == query)
+		.ok_or(CosmwasmVMError::Precompile)?;
+	vm.0.data_mut().charge_raw(stargate_weight(route).ref_time())
 }
 
 impl cosmwasm::Config for Runtime {
@@ -67,6 +113,7 @@ impl cosmwasm::Config for Runtime {
 	type CodeStorageByteDeposit = CodeStorageByteDeposit;
 	type ContractStorageByteReadPrice = ContractStorageByteReadPrice;
 	type ContractStorageByteWritePrice = ContractStorageByteWritePrice;
+	type ContractStorageByteDeposit = ContractStorageByteDeposit;
 
 	type WasmCostRules = WasmCostRules;
 	type UnixTime = Timestamp;
@@ -92,6 +139,8 @@ impl cosmwasm::Config for Runtime {
 		system::EnsureSignedBy<TechnicalCommitteeMembership, Self::AccountId>,
 		system::EnsureSignedBy<ReleaseMembership, Self::AccountId>,
 	>;
+
+	type GovernanceWasmOrigin = GeneralAdminOrRoot;
 }
 
 pub struct Precompiles;
@@ -108,7 +157,13 @@ impl PalletHook<Runtime> for Precompiles {
 	> {
 		let dex: AccountIdOf<Runtime> = PabloPalletId::get().into_account_truncating();
 		let xcm = PolkadotXcm::check_account();
+		let stargate: AccountIdOf<Runtime> = StargatePalletId::get().into_account_truncating();
 		match contract_address {
+			address if address == &stargate => Some(PalletContractCodeInfo::new(
+				stargate,
+				false,
+				StargatePalletId::get().0.to_vec().try_into().unwrap_or_default(),
+			)),
 			address if address == &dex => Some(PalletContractCodeInfo::new(
 				dex,
 				false,
@@ -141,7 +196,25 @@ impl PalletHook<Runtime> for Precompiles {
 		);
 		let dex: AccountIdOf<Runtime> = PabloPalletId::get().into_account_truncating();
 		let xcm = PolkadotXcm::check_account();
+		let stargate: AccountIdOf<Runtime> = StargatePalletId::get().into_account_truncating();
 		match contract_address {
+			address if address == stargate => {
+				let message: common::stargate::ExecuteMsg = serde_json_wasm::from_slice(message)
+					.map_err(|_| CosmwasmVMError::ExecuteDeserialize)?;
+				let common::stargate::ExecuteMsg::Stargate { type_url, .. } = &message;
+				charge_stargate(vm, type_url, false)?;
+
+				let result = StargatePrecompile::execute(
+					vm.0.data().cosmwasm_message_info.sender.as_str(),
+					message,
+				)
+				.map_err(|_| CosmwasmVMError::<Runtime>::Precompile);
+
+				match result {
+					Ok(result) => Ok(ContractResult::Ok(result)),
+					Err(err) => Ok(ContractResult::Err(alloc::format!("{:?}", err))),
+				}
+			},
 			address if address == dex => {
 				let message: composable_traits::dex::ExecuteMsg =
 					serde_json_wasm::from_slice(message)
@@ -193,7 +266,21 @@ impl PalletHook<Runtime> for Precompiles {
 		let contract_address = vm.0.data().contract_address.clone().into_inner();
 		log::error!("{:?}{:?}", &contract_address, String::from_utf8_lossy(message));
 		let dex: AccountIdOf<Runtime> = PabloPalletId::get().into_account_truncating();
+		let stargate: AccountIdOf<Runtime> = StargatePalletId::get().into_account_truncating();
 		match contract_address {
+			address if address == stargate => {
+				let message: common::stargate::QueryMsg = serde_json_wasm::from_slice(message)
+					.map_err(|_| CosmwasmVMError::ExecuteDeserialize)?;
+				let common::stargate::QueryMsg::Stargate { path, .. } = &message;
+				charge_stargate(vm, path, true)?;
+
+				let result = StargatePrecompile::query(message)
+					.map_err(|_| CosmwasmVMError::<Runtime>::Precompile);
+				match result {
+					Ok(ok) => Ok(ContractResult::Ok(ok)),
+					Err(err) => Ok(ContractResult::Err(alloc::format!("{:?}", err))),
+				}
+			},
 			address if address == dex => {
 				let message: composable_traits::dex::QueryMsg =
 					serde_json_wasm::from_slice(message)
@@ -211,6 +298,10 @@ impl PalletHook<Runtime> for Precompiles {
 			_ => Err(CosmwasmVMError::ContractNotFound),
 		}
 	}
+
+	fn stargate_gateway() -> Option<AccountIdOf<Runtime>> {
+		Some(StargatePalletId::get().into_account_truncating())
+	}
 }
 
 /// refactoring will be when request would be to do Composable,
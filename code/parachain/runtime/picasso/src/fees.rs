@@ -1,5 +1,5 @@
 use crate::{prelude::*, *};
-use common::fees::{ForeignToNativePriceConverter, PriceConverter};
+use common::fees::{ForeignToNativePriceConverter, PriceConverter, TwapPriceConverter};
 use composable_traits::{currency::Rational64, rational};
 use primitives::currency::CurrencyId;
 use sp_core::ConstU8;
@@ -24,6 +24,11 @@ impl ForeignToNativePriceConverter for WellKnownForeignToNativePriceConverter {
 pub type FinalPriceConverter =
 	PriceConverter<crate::AssetsRegistry, WellKnownForeignToNativePriceConverter>;
 
+/// Prices fee assets at their TWAP from the sources set in `AssetsRegistry`, bounded by and
+/// falling back to `FinalPriceConverter`.
+pub type FeeAssetPriceConverter =
+	TwapPriceConverter<NativeAssetId, Oracle, Pablo, AssetsRegistry, FinalPriceConverter>;
+
 parameter_types! {
 	/// 1 milli-pica/byte should be fine
 	pub TransactionByteFee: Balance = CurrencyId::milli();
@@ -54,17 +59,41 @@ impl transaction_payment::Config for Runtime {
 }
 pub type AssetsPaymentHeader = asset_tx_payment::ChargeAssetTxPayment<Runtime>;
 pub struct TransferToTreasuryOrDrop;
-impl asset_tx_payment::HandleCredit<AccountId, Tokens> for TransferToTreasuryOrDrop {
-	fn handle_credit(credit: fungibles::Credit<AccountId, Tokens>) {
+impl asset_tx_payment::HandleCredit<AccountId, Assets> for TransferToTreasuryOrDrop {
+	fn handle_credit(credit: fungibles::Credit<AccountId, Assets>) {
 		let _ =
-			<Tokens as fungibles::Balanced<AccountId>>::resolve(&TreasuryAccount::get(), credit);
+			<Assets as fungibles::Balanced<AccountId>>::resolve(&TreasuryAccount::get(), credit);
 	}
 }
 
+parameter_types! {
+	pub const AssetConfigHoldReason: assets::HoldReason =
+		assets::HoldReason::AssetTxPaymentConfiguration;
+	/// Fees in assets without a price are swapped to PICA for up to 5% more than the TWAP.
+	pub const MaxFeeSwapSlippage: Permill = Permill::from_percent(5);
+	/// Fees in assets without a price are rejected while the spot price deviates from the TWAP by
+	/// more than the slippage allowed.
+	pub const MaxFeeSwapDeviation: Permill = Permill::from_percent(5);
+	pub FeeSwapWeight: Weight =
+		<weights::dex_router::WeightInfo<Runtime> as dex_router::WeightInfo>::swap();
+}
+
+/// Charges fees in assets without a price by swapping them to PICA in the treasury.
+pub type FeeAssetAdapter = asset_tx_payment::SwapFungiblesAdapter<
+	FeeAssetPriceConverter,
+	TransferToTreasuryOrDrop,
+	DexRouter,
+	NativeAssetId,
+	MaxFeeSwapSlippage,
+	MaxFeeSwapDeviation,
+	TreasuryAccount,
+	FeeSwapWeight,
+>;
+
 impl asset_tx_payment::Config for Runtime {
-	type Fungibles = Tokens;
-	type OnChargeAssetTransaction =
-		asset_tx_payment::FungiblesAdapter<FinalPriceConverter, TransferToTreasuryOrDrop>;
+	// withdrawn through `Assets` so that frozen accounts cannot pay fees
+	type Fungibles = Assets;
+	type OnChargeAssetTransaction = FeeAssetAdapter;
 
 	type UseUserConfiguration = ConstBool<true>;
 
@@ -78,9 +107,35 @@ impl asset_tx_payment::Config for Runtime {
 
 	type Lock = Assets;
 
-	type BalanceConverter = FinalPriceConverter;
+	type BalanceConverter = FeeAssetPriceConverter;
 
-	type HoldIdentifierValue = ();
+	type HoldIdentifierValue = AssetConfigHoldReason;
 
-	type HoldIdentifier = ();
+	type HoldIdentifier = assets::HoldReason;
+
+	type MaxSponsoredCalls = ConstU32<16>;
+
+	type WrapperCalls = WrapperCalls;
+}
+
+/// Calls dispatching other calls, now or once scheduled, which sponsorship policies restricting
+/// the sponsored calls never pay for.
+pub struct WrapperCalls;
+impl Contains<RuntimeCall> for WrapperCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::Sudo(_) |
+				RuntimeCall::Multisig(_) |
+				RuntimeCall::Council(_) |
+				RuntimeCall::TechnicalCommittee(_) |
+				RuntimeCall::ReleaseCommittee(_) |
+				RuntimeCall::RelayerCommittee(_) |
+				RuntimeCall::Scheduler(_) |
+				RuntimeCall::Utility(_) |
+				RuntimeCall::Proxy(_) |
+				RuntimeCall::PolkadotXcm(_) |
+				RuntimeCall::Whitelist(_)
+		)
+	}
 }
@@ -34,7 +34,11 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					c,
 					RuntimeCall::Pablo(..) |
 						RuntimeCall::FarmingRewards(..) |
-						RuntimeCall::Farming(..)
+						RuntimeCall::Farming(..) | RuntimeCall::Vault(..) |
+						RuntimeCall::DutchAuction(..) |
+						RuntimeCall::Lending(..) | RuntimeCall::BondedFinance(..) |
+						RuntimeCall::Fnft(..) | RuntimeCall::StakingRewards(..) |
+						RuntimeCall::DexRouter(..)
 				)
 			},
 			ProxyType::Oracle => {
@@ -78,9 +78,15 @@ type CosmwasmRouter = cosmwasm::ibc::Router<Runtime>;
 
 #[derive(Clone, Debug, Eq, PartialEq, Default)]
 pub struct Router {
-	ics20: pallet_ibc::ics20::memo::Memo<
+	ics20: pallet_multihop_xcm_ibc::tracking::MultihopTracker<
 		Runtime,
-		pallet_ibc::ics20_fee::Ics20ServiceCharge<Runtime, pallet_ibc::ics20::IbcModule<Runtime>>,
+		pallet_ibc::ics20::memo::Memo<
+			Runtime,
+			pallet_ibc::ics20_fee::Ics20ServiceCharge<
+				Runtime,
+				pallet_ibc::ics20::IbcModule<Runtime>,
+			>,
+		>,
 	>,
 	pallet_cosmwasm: CosmwasmRouter,
 }
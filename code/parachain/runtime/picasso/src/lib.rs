@@ -35,6 +35,7 @@ pub use tracks::TracksInfo;
 pub mod governance;
 pub mod ibc;
 mod migrations;
+mod portfolio;
 mod prelude;
 pub mod version;
 mod weights;
@@ -52,13 +53,13 @@ use common::{
 	rewards::StakingPot,
 	xcmp::AccountIdToMultiLocation,
 	AccountId, AccountIndex, Amount, AuraId, Balance, BlockNumber, ComposableBlock,
-	ComposableUncheckedExtrinsic, Hash, Moment, PoolId, ReservedDmpWeight, ReservedXcmpWeight,
-	Signature, AVERAGE_ON_INITIALIZE_RATIO, DAYS, HOURS, MAXIMUM_BLOCK_WEIGHT, MILLISECS_PER_BLOCK,
-	MINUTES, NORMAL_DISPATCH_RATIO, SLOT_DURATION,
+	ComposableUncheckedExtrinsic, FinancialNftInstanceId, Hash, Moment, PoolId, ReservedDmpWeight,
+	ReservedXcmpWeight, Signature, AVERAGE_ON_INITIALIZE_RATIO, DAYS, HOURS, MAXIMUM_BLOCK_WEIGHT,
+	MILLISECS_PER_BLOCK, MINUTES, NORMAL_DISPATCH_RATIO, SLOT_DURATION,
 };
 use composable_support::rpc_helpers::SafeRpcWrapper;
 use composable_traits::{
-	assets::Asset,
+	assets::{Asset, AssetFilter, ExtendedAsset, PortfolioEntry},
 	dex::{Amm, PriceAggregate},
 };
 use pallet_ibc::ics20_fee::FlatFeeConverter;
@@ -230,6 +231,12 @@ parameter_types! {
 	pub AssetIdUSDT: CurrencyId = CurrencyId::INVALID;
 	pub FlatFeeUSDTAmount: Balance = 0;
 	pub const PicassoNetworkId: u32 = 0;
+	pub AssetProposalDeposit: Balance = 100 * CurrencyId::unit::<Balance>();
+	pub const AssetProposalChallengePeriod: BlockNumber = 7 * DAYS;
+	/// Proposed assets need an existential deposit and fees of at least a thousand of their
+	/// smallest units, so that accounts and transfers cannot be spammed for free.
+	pub const MinProposedAssetExistentialDeposit: Balance = 1_000;
+	pub const MinProposedAssetFee: Balance = 1_000;
 }
 
 impl assets_registry::Config for Runtime {
@@ -242,6 +249,14 @@ impl assets_registry::Config for Runtime {
 	type WeightInfo = weights::assets_registry::WeightInfo<Runtime>;
 	type Convert = ConvertInto;
 	type NetworkId = PicassoNetworkId;
+	type Currency = Balances;
+	type ProposalDeposit = AssetProposalDeposit;
+	type MinProposedExistentialDeposit = MinProposedAssetExistentialDeposit;
+	type MinProposedFee = MinProposedAssetFee;
+	type ChallengePeriod = AssetProposalChallengePeriod;
+	type RejectProposalOrigin = EnsureRootOrHalfNativeCouncil;
+	type TreasuryAccount = TreasuryAccount;
+	type PoolId = PoolId;
 }
 
 parameter_types! {
@@ -251,6 +266,70 @@ parameter_types! {
 	pub const MaxStringSizeAddress: u32 = 100;
 	#[derive(PartialEq, Eq, Copy, Clone, codec::Encode, codec::Decode, codec::MaxEncodedLen, Debug, TypeInfo)]
 	pub const MaxStringSizeMemo: u32 = 1000;
+	pub const MaxRevenueDestinations: u32 = 8;
+	pub const MaxRevenueAllowedAssets: u32 = 32;
+}
+
+/// Registers assets and creates Pablo pools revenue is swapped through in the revenue benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct RevenueIbcBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl revenue_ibc::BenchmarkHelper<CurrencyId, PoolId> for RevenueIbcBenchmarkHelper {
+	fn create_asset(index: u32) -> CurrencyId {
+		use composable_traits::assets::{AssetInfo, GenerateAssetId};
+		let asset_info = AssetInfo {
+			name: None,
+			symbol: None,
+			decimals: Some(12),
+			existential_deposit: 1,
+			ratio: None,
+		};
+		AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			*b"rvib",
+			index.into(),
+			None,
+			asset_info,
+		)
+		.expect("asset is registered");
+		AssetsRegistry::generate_asset_id(*b"rvib", index.into())
+	}
+
+	fn create_pool(asset_id: CurrencyId, target_asset: CurrencyId) -> PoolId {
+		use frame_support::traits::fungibles::Mutate;
+		let owner = AccountId::from([0; 32]);
+		let pool_id = Pablo::do_create_pool(
+			pablo::PoolInitConfiguration::DualAssetConstantProduct {
+				owner: owner.clone(),
+				assets_weights: vec![
+					(asset_id, Permill::from_percent(50)),
+					(target_asset, Permill::from_percent(50)),
+				],
+				fee: Permill::zero(),
+			},
+			None,
+		)
+		.expect("pool is created");
+		let liquidity = 1_000_000 * CurrencyId::unit::<Balance>();
+		Assets::mint_into(asset_id, &owner, liquidity).expect("asset is minted");
+		Assets::mint_into(target_asset, &owner, liquidity).expect("asset is minted");
+		Pablo::add_liquidity(
+			RuntimeOrigin::signed(owner),
+			pool_id,
+			[(asset_id, liquidity), (target_asset, liquidity)].into_iter().collect(),
+			0,
+			false,
+		)
+		.expect("liquidity is added");
+		Pablo::enable_twap(RuntimeOrigin::root(), pool_id).expect("TWAP is enabled");
+		pool_id
+	}
+
+	fn channel() -> u64 {
+		// no channel is open in the benchmarks, transfers fail after the IBC transfer checks
+		0
+	}
 }
 
 impl revenue_ibc::Config for Runtime {
@@ -264,7 +343,14 @@ impl revenue_ibc::Config for Runtime {
 	type MaxStringSizeAddress = MaxStringSizeAddress;
 	type MaxStringSizeMemo = MaxStringSizeMemo;
 	type Admin = EnsureRootOrTwoThirdNativeCouncil;
+	type Exchange = Pablo;
+	type ExchangePoolId = PoolId;
+	type Oracle = Oracle;
+	type MaxDestinations = MaxRevenueDestinations;
+	type MaxAllowedAssets = MaxRevenueAllowedAssets;
 	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = RevenueIbcBenchmarkHelper;
 }
 
 parameter_types! {
@@ -290,6 +376,141 @@ impl pablo::Config for Runtime {
 	type LPTokenExistentialDeposit = LPTokenExistentialDeposit;
 }
 
+parameter_types! {
+	pub const VaultPalletId: PalletId = PalletId(*b"pal_vlt_");
+	pub const VaultMaxStrategies: u32 = 16;
+	pub VaultCreationDeposit: Balance = 10 * CurrencyId::unit::<Balance>();
+	pub VaultExistentialDeposit: Balance = 1_000 * CurrencyId::unit::<Balance>();
+	pub VaultRentPerBlock: Balance = CurrencyId::milli::<Balance>();
+	pub const VaultTombstoneDuration: BlockNumber = 7 * DAYS;
+	pub VaultMinimumDeposit: Balance = CurrencyId::milli::<Balance>();
+	pub VaultMinimumWithdrawal: Balance = CurrencyId::milli::<Balance>();
+	pub VaultLockedLiquidity: Balance = CurrencyId::milli::<Balance>();
+	pub VaultMaxReportChangePerBlock: Perquintill = Perquintill::from_perthousand(1);
+}
+
+impl vault::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = CurrencyId;
+	type Balance = Balance;
+	type VaultId = u64;
+	type LPTokenFactory = AssetsRegistry;
+	type Assets = Assets;
+	type NativeCurrency = Balances;
+	type PalletId = VaultPalletId;
+	type MaxStrategies = VaultMaxStrategies;
+	type CreationDeposit = VaultCreationDeposit;
+	type ExistentialDeposit = VaultExistentialDeposit;
+	type RentPerBlock = VaultRentPerBlock;
+	type TombstoneDuration = VaultTombstoneDuration;
+	type MinimumDeposit = VaultMinimumDeposit;
+	type MinimumWithdrawal = VaultMinimumWithdrawal;
+	type LPTokenExistentialDeposit = LPTokenExistentialDeposit;
+	type LockedLiquidity = VaultLockedLiquidity;
+	type MaxReportChangePerBlock = VaultMaxReportChangePerBlock;
+	type EmergencyShutdownOrigin = EnsureRootOrTwoThirdNativeCouncil;
+	type WeightInfo = weights::vault::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const DutchAuctionPalletId: PalletId = PalletId(*b"pal_dtch");
+	pub const MaxAuctionDuration: u64 = composable_traits::time::ONE_DAY;
+	pub DutchAuctionOrderDeposit: Balance = CurrencyId::unit::<Balance>();
+}
+
+impl dutch_auction::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = CurrencyId;
+	type Balance = Balance;
+	type OrderId = u128;
+	type PoolId = PoolId;
+	type MaxHopsInRoute = MaxHopsInRoute;
+	type Assets = Assets;
+	type Fallback = DexRouter;
+	type Callback = Lending;
+	type Currency = Balances;
+	type OrderDeposit = DutchAuctionOrderDeposit;
+	type CancelOrigin = EnsureRootOrTwoThirdNativeCouncil;
+	type UnixTime = Timestamp;
+	type PalletId = DutchAuctionPalletId;
+	type MaxAuctionDuration = MaxAuctionDuration;
+	type WeightInfo = weights::dutch_auction::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const LendingPalletId: PalletId = PalletId(*b"pal_lend");
+	pub const MaxLiquidationBatchSize: u32 = 10;
+	pub LendingLockedLiquidity: Balance = CurrencyId::milli::<Balance>();
+}
+
+/// Sets oracle prices and sells liquidated collateral by auction in the lending benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct LendingBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl lending::BenchmarkHelper<CurrencyId, Balance, dutch_auction::models::AuctionConfiguration>
+	for LendingBenchmarkHelper
+{
+	fn set_price(asset_id: CurrencyId, price: Balance) {
+		oracle::Prices::<Runtime>::insert(
+			asset_id,
+			composable_traits::oracle::Price { price, block: System::block_number() },
+		);
+	}
+
+	fn liquidation_strategy() -> dutch_auction::models::AuctionConfiguration {
+		use composable_traits::time::{LinearDecrease, TimeReleaseFunction, ONE_HOUR};
+		dutch_auction::models::AuctionConfiguration {
+			price_decay: TimeReleaseFunction::LinearDecrease(LinearDecrease { total: ONE_HOUR }),
+			start_premium: Permill::from_percent(10),
+			duration: ONE_HOUR,
+			fallback: false,
+		}
+	}
+}
+
+impl lending::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = CurrencyId;
+	type Balance = Balance;
+	type MarketId = u32;
+	type LPTokenFactory = AssetsRegistry;
+	type Assets = Assets;
+	type Oracle = Oracle;
+	type LiquidationStrategy = dutch_auction::models::AuctionConfiguration;
+	type Liquidation = DutchAuction;
+	type UnixTime = Timestamp;
+	type PalletId = LendingPalletId;
+	type MaxLiquidationBatchSize = MaxLiquidationBatchSize;
+	type LPTokenExistentialDeposit = LPTokenExistentialDeposit;
+	type LockedLiquidity = LendingLockedLiquidity;
+	type MarketCreationOrigin = EnsureRootOrTwoThirdNativeCouncil;
+	type WeightInfo = weights::lending::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = LendingBenchmarkHelper;
+}
+
+parameter_types! {
+	pub const BondedFinancePalletId: PalletId = PalletId(*b"pal_bond");
+	pub BondedFinanceMinReward: Balance = 10 * CurrencyId::unit::<Balance>();
+	pub BondedFinanceStake: Balance = 10 * CurrencyId::unit::<Balance>();
+}
+
+impl bonded_finance::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = CurrencyId;
+	type Balance = Balance;
+	type BondOfferId = u64;
+	type NativeCurrency = Balances;
+	type Currency = Assets;
+	type Vesting = Vesting;
+	type MinReward = BondedFinanceMinReward;
+	type Stake = BondedFinanceStake;
+	type AdminOrigin = EnsureRootOrTwoThirdNativeCouncil;
+	type PalletId = BondedFinancePalletId;
+	type WeightInfo = weights::bonded_finance::WeightInfo<Runtime>;
+}
+
 type FarmingRewardsInstance = reward::Instance1;
 
 impl reward::Config<FarmingRewardsInstance> for Runtime {
@@ -678,6 +899,94 @@ impl crowdloan_rewards::Config for Runtime {
 	type LockByDefault = LockCrowdloanRewards;
 }
 
+parameter_types! {
+	pub const AirdropPalletId: PalletId = PalletId(*b"pal_aird");
+	pub AirdropStake: Balance = 10 * CurrencyId::unit::<Balance>();
+	pub const AirdropClaimPeriod: Moment = 90 * 24 * 60 * 60 * 1_000;
+	pub const MaxMerkleProofLength: u32 = 32;
+	pub const AirdropRecipientRemovalsPerBlock: u32 = 1_000;
+}
+
+impl airdrop::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AirdropId = u64;
+	type Balance = Balance;
+	type Moment = Moment;
+	type Convert = sp_runtime::traits::ConvertInto;
+	type RelayChainAccountId = sp_runtime::AccountId32;
+	type RecipientFundAsset = Balances;
+	type Time = Timestamp;
+	type PalletId = AirdropPalletId;
+	type Prefix = Prefix;
+	type Stake = AirdropStake;
+	type ClaimPeriod = AirdropClaimPeriod;
+	type MaxMerkleProofLength = MaxMerkleProofLength;
+	type RecipientRemovalsPerBlock = AirdropRecipientRemovalsPerBlock;
+	type WeightInfo = weights::airdrop::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const FnftPalletId: PalletId = PalletId(*b"pal_fnft");
+	pub const MaxProperties: u32 = 16;
+}
+
+impl fnft::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type FinancialNftCollectionId = CurrencyId;
+	type FinancialNftInstanceId = FinancialNftInstanceId;
+	type ProxyType = composable_traits::account_proxy::ProxyType;
+	type AccountProxy = composable_traits::account_proxy::AccountProxyWrapper<Runtime>;
+	type ProxyTypeSelector = composable_traits::fnft::FnftAccountProxyType;
+	type MaxProperties = MaxProperties;
+	type PalletId = FnftPalletId;
+	type WeightInfo = weights::fnft::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const StakingRewardsPalletId: PalletId = PalletId(*b"stk_rwrd");
+	pub StakingRewardsPositionDeposit: Balance = 4 * NativeExistentialDeposit::get();
+	pub const MaxStakingDurationPresets: u32 = 10;
+	pub const MaxRewardConfigsPerPool: u32 = 10;
+	pub const StakingRewardsMaxPoolsPerBlock: u32 = 10;
+}
+
+impl staking_rewards::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = CurrencyId;
+	type Balance = Balance;
+	type Assets = Assets;
+	type AssetFactory = AssetsRegistry;
+	type FinancialNftInstanceId = FinancialNftInstanceId;
+	type FinancialNft = Fnft;
+	type UnixTime = Timestamp;
+	type AdminOrigin = EnsureRootOrTwoThirdNativeCouncil;
+	type NativeAssetId = NativeAssetId;
+	type PositionDeposit = StakingRewardsPositionDeposit;
+	type TreasuryAccount = TreasuryAccount;
+	type PalletId = StakingRewardsPalletId;
+	type MaxStakingDurationPresets = MaxStakingDurationPresets;
+	type MaxRewardConfigsPerPool = MaxRewardConfigsPerPool;
+	type MaxPoolsPerBlock = StakingRewardsMaxPoolsPerBlock;
+	type WeightInfo = weights::staking_rewards::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const DexRouterPalletId: PalletId = PalletId(*b"dex_rout");
+	pub const MaxHopsInRoute: u32 = 4;
+}
+
+impl dex_router::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = CurrencyId;
+	type Balance = Balance;
+	type MaxHopsInRoute = MaxHopsInRoute;
+	type PoolId = PoolId;
+	type Pablo = Pablo;
+	type PalletId = DexRouterPalletId;
+	type UpdateRouteOrigin = EnsureRootOrTwoThirdNativeCouncil;
+	type WeightInfo = weights::dex_router::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	  pub const MaxVestingSchedule: u32 = 128;
 	  pub MinVestedTransfer: u64 = CurrencyId::milli::<u64>();
@@ -705,6 +1014,7 @@ parameter_types! {
 parameter_types! {
 		pub const MaxMultihopCount: u32 = 10;
 		pub const ChainNameVecLimit: u32 = 30;
+		pub const MaxMultihopTransfersPerAccount: u32 = 16;
 }
 
 pub struct MultihopXcmIbcPalletId;
@@ -714,11 +1024,33 @@ impl Get<u8> for MultihopXcmIbcPalletId {
 	}
 }
 
+/// Refunds the relay chain asset in the multihop benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MultihopXcmIbcBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_multihop_xcm_ibc::BenchmarkHelper<AccountId, CurrencyId>
+	for MultihopXcmIbcBenchmarkHelper
+{
+	fn refund_asset() -> CurrencyId {
+		CurrencyId::KSM
+	}
+
+	fn fund(account: &AccountId, asset_id: CurrencyId, amount: u128) {
+		use frame_support::traits::fungibles::Mutate;
+		Assets::mint_into(asset_id, account, amount).expect("asset is minted");
+	}
+}
+
 impl pallet_multihop_xcm_ibc::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type PalletInstanceId = MultihopXcmIbcPalletId;
 	type MaxMultihopCount = MaxMultihopCount;
 	type ChainNameVecLimit = ChainNameVecLimit;
+	type MaxTransfersPerAccount = MaxMultihopTransfersPerAccount;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MultihopXcmIbcBenchmarkHelper;
 }
 
 parameter_types! {
@@ -731,6 +1063,8 @@ parameter_types! {
 
 impl pallet_xcm_helper::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
 	type UpdateOrigin = GeneralAdminOrRoot;
 	type Assets = Assets;
 	type XcmSender = crate::xcmp::XcmRouter;
@@ -893,6 +1227,14 @@ construct_runtime!(
 		Oracle: oracle = 60,
 		FarmingRewards: reward::<Instance1> = 62,
 		Farming: farming = 63,
+		Vault: vault = 64,
+		DutchAuction: dutch_auction = 65,
+		Lending: lending = 66,
+		BondedFinance: bonded_finance = 67,
+		Airdrop: airdrop = 68,
+		Fnft: fnft = 69,
+		StakingRewards: staking_rewards = 70,
+		DexRouter: dex_router = 71,
 
 		Referenda: pallet_referenda = 76,
 		ConvictionVoting: pallet_conviction_voting = 77,
@@ -970,7 +1312,17 @@ mod benches {
 		[vesting, Vesting]
 		[assets_registry, AssetsRegistry]
 		[oracle, Oracle]
+		[vault, Vault]
+		[dutch_auction, DutchAuction]
+		[lending, Lending]
+		[bonded_finance, BondedFinance]
+		[airdrop, Airdrop]
+		[fnft, Fnft]
+		[staking_rewards, StakingRewards]
 		[pallet_ibc, Ibc]
+		[revenue_ibc, RevenueIbc]
+		[pallet_multihop_xcm_ibc, PalletMultihopXcmIbc]
+		[pallet_xcm_helper, PalletXcmHelper]
 	);
 }
 
@@ -1003,7 +1355,46 @@ cumulus_pallet_parachain_system::register_validate_block!(
 	CheckInherents = CheckInherents,
 );
 
+/// Extended asset of the assets registry, as returned by the assets runtime API.
+fn rpc_extended_asset(
+	asset: ExtendedAsset<CurrencyId, Balance, ForeignAssetId>,
+) -> ExtendedAsset<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId> {
+	let ExtendedAsset { asset, metadata, location_history } = asset;
+	ExtendedAsset {
+		asset: Asset {
+			decimals: asset.decimals,
+			existential_deposit: SafeRpcWrapper(multi_existential_deposits::<
+				AssetsRegistry,
+				WellKnownForeignToNativePriceConverter,
+			>(&asset.id)),
+			id: SafeRpcWrapper(asset.id.into()),
+			foreign_id: asset.foreign_id,
+			name: asset.name,
+			symbol: asset.symbol,
+			ratio: asset.ratio,
+		},
+		metadata,
+		location_history,
+	}
+}
+
+/// Portfolio entry of an account, as returned by the assets runtime API.
+fn rpc_portfolio_entry(
+	entry: PortfolioEntry<CurrencyId, Balance, ForeignAssetId>,
+) -> PortfolioEntry<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId> {
+	PortfolioEntry {
+		asset: rpc_extended_asset(entry.asset),
+		free: SafeRpcWrapper(entry.free),
+		reserved: SafeRpcWrapper(entry.reserved),
+		vesting_locked: SafeRpcWrapper(entry.vesting_locked),
+		staked: SafeRpcWrapper(entry.staked),
+		liquidity: SafeRpcWrapper(entry.liquidity),
+		value: entry.value.map(SafeRpcWrapper),
+	}
+}
+
 impl_runtime_apis! {
+	#[api_version(3)]
 	impl assets_runtime_api::AssetsRuntimeApi<Block, CurrencyId, AccountId, Balance, ForeignAssetId> for Runtime {
 		fn balance_of(SafeRpcWrapper(asset_id): SafeRpcWrapper<CurrencyId>, account_id: AccountId) -> SafeRpcWrapper<Balance> /* Balance */ {
 			SafeRpcWrapper(<Assets as fungibles::Inspect::<AccountId>>::balance(asset_id, &account_id))
@@ -1027,6 +1418,36 @@ impl_runtime_apis! {
 			  }
 			).collect::<Vec<Asset<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>>>()
 		}
+
+		fn list_assets_extended(filter: AssetFilter, offset: u32, limit: u32) -> Vec<ExtendedAsset<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>> {
+			AssetsRegistry::get_assets_filtered(filter, offset, limit).into_iter().map(rpc_extended_asset).collect()
+		}
+
+		fn assets_by_symbol(symbol: Vec<u8>) -> Vec<ExtendedAsset<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>> {
+			AssetsRegistry::get_assets_by_symbol(symbol).into_iter().map(rpc_extended_asset).collect()
+		}
+
+		fn asset_by_ibc_denom_hash(hash: [u8; 32]) -> Option<ExtendedAsset<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>> {
+			AssetsRegistry::get_asset_by_ibc_denom_hash(hash).map(rpc_extended_asset)
+		}
+
+		fn portfolio(account_id: AccountId, valuation: bool) -> Vec<PortfolioEntry<SafeRpcWrapper<u128>, SafeRpcWrapper<Balance>, ForeignAssetId>> {
+			portfolio::portfolio(&account_id, valuation).into_iter().map(rpc_portfolio_entry).collect()
+		}
+	}
+
+	impl pallet_multihop_xcm_ibc_runtime_api::MultihopXcmIbcRuntimeApi<Block, AccountId, CurrencyId> for Runtime {
+		fn transfer_status(transfer_id: u128) -> Option<composable_traits::xcm::memo::MultihopTransferStatus<AccountId, CurrencyId>> {
+			PalletMultihopXcmIbc::transfer_status(transfer_id)
+		}
+
+		fn transfers_of(owner: AccountId) -> Vec<(u128, composable_traits::xcm::memo::MultihopTransferStatus<AccountId, CurrencyId>)> {
+			PalletMultihopXcmIbc::transfers_of(owner)
+		}
+
+		fn routes() -> Vec<(u128, Vec<composable_traits::xcm::memo::ChainInfo>)> {
+			PalletMultihopXcmIbc::routes()
+		}
 	}
 
 	impl crowdloan_rewards_runtime_api::CrowdloanRewardsRuntimeApi<Block, AccountId, Balance> for Runtime {
@@ -1115,6 +1536,37 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl vault_runtime_api::VaultRuntimeApi<Block, u64, Balance> for Runtime {
+		fn lp_share_value(vault_id: u64, lp_amount: Balance) -> Option<SafeRpcWrapper<Balance>> {
+			vault::lp_share_value::<Runtime>(vault_id, lp_amount).map(SafeRpcWrapper)
+		}
+
+		fn amount_of_lp_token_for_added_liquidity(
+			vault_id: u64,
+			asset_amount: Balance,
+		) -> Option<SafeRpcWrapper<Balance>> {
+			vault::amount_of_lp_token_for_added_liquidity::<Runtime>(vault_id, asset_amount)
+				.map(SafeRpcWrapper)
+		}
+	}
+
+	impl dutch_auction_runtime_api::DutchAuctionRuntimeApi<Block, u128, CurrencyId, Balance> for Runtime {
+		fn open_auctions() -> Vec<dutch_auction_runtime_api::OpenAuction<u128, CurrencyId, Balance>> {
+			dutch_auction::open_auctions::<Runtime>()
+				.into_iter()
+				.map(|(order_id, order, price)| dutch_auction_runtime_api::OpenAuction {
+					order_id,
+					base_asset_id: order.order.pair.base,
+					quote_asset_id: order.order.pair.quote,
+					amount: order.order.take.amount,
+					price,
+					ends_at: order.ends_at(),
+				})
+				.collect()
+		}
+	}
+
+	#[api_version(3)]
 	impl cosmwasm_runtime_api::CosmwasmRuntimeApi<Block, AccountId, CurrencyId, Balance, Vec<u8>> for Runtime {
 		fn query(
 			contract: AccountId,
@@ -1152,6 +1604,22 @@ impl_runtime_apis! {
 				message
 			).map_err(|err| alloc::format!("{:?}", err).into_bytes())
 		}
+
+		fn simulate(
+			sender: AccountId,
+			message: cosmwasm_runtime_api::SimulateMsg<AccountId, CurrencyId, Balance>,
+			gas: u64,
+		) -> Result<cosmwasm_runtime_api::Simulation<AccountId>, Vec<u8>> {
+			cosmwasm::simulate::<Runtime>(sender, message, gas)
+				.map_err(|err| alloc::format!("{:?}", err).into_bytes())
+		}
+
+		fn export_contract(
+			contract: AccountId,
+		) -> Result<cosmwasm_runtime_api::ContractState<AccountId>, Vec<u8>> {
+			cosmwasm::export_contract::<Runtime>(contract)
+				.map_err(|err| alloc::format!("{:?}", err).into_bytes())
+		}
 	}
 
 	impl sp_api::Core<Block> for Runtime {
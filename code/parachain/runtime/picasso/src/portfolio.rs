@@ -0,0 +1,226 @@
+//! Account portfolio across all registered assets, as returned by the assets runtime API.
+
+use composable_traits::{assets::PortfolioEntry, oracle::Oracle as _};
+use orml_traits::{MultiCurrency, MultiReservableCurrency};
+
+use super::*;
+
+/// Balances of `account` in every registered asset it has funds in.
+///
+/// Staked and liquidity positions are attributed to the staked asset and to the pool assets
+/// respectively. LP tokens, held or staked, are only accounted for as the pool assets they redeem
+/// for, so they have no entry of their own. With `valuation`, assets without an oracle price have
+/// no value.
+pub fn portfolio(
+	account: &AccountId,
+	valuation: bool,
+) -> Vec<PortfolioEntry<CurrencyId, Balance, ForeignAssetId>> {
+	let pools = lp_token_pools();
+	let staked = staked_by(account);
+	let liquidity = liquidity_of(account, &pools, &staked);
+	AssetsRegistry::get_assets_filtered(AssetFilter::default(), 0, u32::MAX)
+		.into_iter()
+		.filter_map(|asset| {
+			let asset_id = asset.asset.id;
+			if pools.contains_key(&asset_id) {
+				return None
+			}
+			let free = <Assets as MultiCurrency<AccountId>>::free_balance(asset_id, account);
+			let reserved =
+				<Assets as MultiReservableCurrency<AccountId>>::reserved_balance(asset_id, account);
+			let staked = staked.get(&asset_id).copied().unwrap_or_default();
+			let liquidity = liquidity.get(&asset_id).copied().unwrap_or_default();
+			if free.is_zero() && reserved.is_zero() && staked.is_zero() && liquidity.is_zero() {
+				return None
+			}
+			let value = valuation
+				.then(|| {
+					Oracle::get_price(
+						asset_id,
+						free.saturating_add(reserved)
+							.saturating_add(staked)
+							.saturating_add(liquidity),
+					)
+					.ok()
+				})
+				.flatten()
+				.map(|price| price.price);
+			Some(PortfolioEntry {
+				asset,
+				free,
+				reserved,
+				vesting_locked: vesting_locked(account, asset_id),
+				staked,
+				liquidity,
+				value,
+			})
+		})
+		.collect()
+}
+
+/// Amount of `asset_id` locked by vesting schedules of `account`.
+fn vesting_locked(account: &AccountId, asset_id: CurrencyId) -> Balance {
+	if asset_id == NativeAssetId::get() {
+		balances::Locks::<Runtime>::get(account)
+			.into_iter()
+			.filter(|lock| lock.id == vesting::VESTING_LOCK_ID)
+			.fold(0, |total, lock| total.saturating_add(lock.amount))
+	} else {
+		orml_tokens::Locks::<Runtime>::get(account, asset_id)
+			.into_iter()
+			.filter(|lock| lock.id == vesting::VESTING_LOCK_ID)
+			.fold(0, |total, lock| total.saturating_add(lock.amount))
+	}
+}
+
+/// Amounts staked in the staking positions owned by `account`, by staked asset.
+fn staked_by(account: &AccountId) -> BTreeMap<CurrencyId, Balance> {
+	Fnft::owned_instances(account).into_iter().fold(
+		BTreeMap::new(),
+		|mut staked, (collection, instance)| {
+			if let Some(stake) = StakingRewards::stakes(collection, instance) {
+				let total = staked.entry(stake.reward_pool_id).or_default();
+				*total = total.saturating_add(stake.stake);
+			}
+			staked
+		},
+	)
+}
+
+/// Pablo pools, by their LP token.
+fn lp_token_pools() -> BTreeMap<CurrencyId, PoolId> {
+	pablo::Pools::<Runtime>::iter()
+		.map(|(pool_id, pool)| {
+			let pablo::PoolConfiguration::DualAssetConstantProduct(info) = pool;
+			(info.lp_token, pool_id)
+		})
+		.collect()
+}
+
+/// Pool assets redeemable with the LP tokens held or staked by `account`, by asset.
+fn liquidity_of(
+	account: &AccountId,
+	pools: &BTreeMap<CurrencyId, PoolId>,
+	staked: &BTreeMap<CurrencyId, Balance>,
+) -> BTreeMap<CurrencyId, Balance> {
+	pools.iter().fold(BTreeMap::new(), |mut liquidity, (&lp_token, &pool_id)| {
+		let lp_amount = <Assets as MultiCurrency<AccountId>>::total_balance(lp_token, account)
+			.saturating_add(staked.get(&lp_token).copied().unwrap_or_default());
+		if lp_amount.is_zero() {
+			return liquidity
+		}
+		for (asset_id, amount) in
+			<Pablo as Amm>::redeemable_assets_for_lp_tokens(pool_id, lp_amount).unwrap_or_default()
+		{
+			let total = liquidity.entry(asset_id).or_default();
+			*total = total.saturating_add(amount);
+		}
+		liquidity
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use composable_traits::{
+		assets::{AssetInfo, GenerateAssetId},
+		oracle::Price,
+	};
+	use frame_support::{assert_ok, sp_io};
+	use sp_runtime::Permill;
+
+	use super::*;
+
+	const UNIT: Balance = 1_000_000_000_000;
+
+	pub fn new_test_ext() -> sp_io::TestExternalities {
+		let storage = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.expect("in memory test");
+		let mut externalities = sp_io::TestExternalities::new(storage);
+		externalities.execute_with(|| System::set_block_number(1));
+		externalities
+	}
+
+	fn register_asset(nonce: u64) -> CurrencyId {
+		let asset_info = AssetInfo {
+			name: None,
+			symbol: None,
+			decimals: Some(12),
+			existential_deposit: 1,
+			ratio: None,
+		};
+		assert_ok!(AssetsRegistry::register_asset(
+			RuntimeOrigin::root(),
+			*b"test",
+			nonce,
+			None,
+			asset_info
+		));
+		AssetsRegistry::generate_asset_id(*b"test", nonce)
+	}
+
+	#[test]
+	fn liquidity_is_valued_as_pool_assets() {
+		new_test_ext().execute_with(|| {
+			let alice = AccountId::from([1; 32]);
+			let base = register_asset(1);
+			let quote = register_asset(2);
+			assert_ok!(Pablo::create(
+				RuntimeOrigin::root(),
+				pablo::PoolInitConfiguration::DualAssetConstantProduct {
+					owner: alice.clone(),
+					assets_weights: vec![
+						(base, Permill::from_percent(50)),
+						(quote, Permill::from_percent(50)),
+					],
+					fee: Permill::zero(),
+				},
+			));
+			let (&lp_token, &pool_id) = lp_token_pools().iter().next().expect("pool was created");
+			assert_ok!(<Assets as fungibles::Mutate<AccountId>>::mint_into(
+				base,
+				&alice,
+				1_000 * UNIT
+			));
+			assert_ok!(<Assets as fungibles::Mutate<AccountId>>::mint_into(
+				quote,
+				&alice,
+				1_000 * UNIT
+			));
+			assert_ok!(Pablo::add_liquidity(
+				RuntimeOrigin::signed(alice.clone()),
+				pool_id,
+				BTreeMap::from([(base, 100 * UNIT), (quote, 100 * UNIT)]),
+				0,
+				false,
+			));
+			// one unit of `base` is worth two units of the quote asset, `quote` has no price
+			oracle::Prices::<Runtime>::insert(base, Price { price: 2 * UNIT, block: 1 });
+
+			let lp_amount = <Assets as MultiCurrency<AccountId>>::free_balance(lp_token, &alice);
+			assert!(!lp_amount.is_zero());
+			let redeemable = <Pablo as Amm>::redeemable_assets_for_lp_tokens(pool_id, lp_amount)
+				.expect("pool exists");
+
+			let portfolio = portfolio(&alice, true);
+			assert!(portfolio.iter().all(|entry| entry.asset.asset.id != lp_token));
+			assert_eq!(portfolio.len(), 2);
+			let entry = |asset_id| {
+				portfolio
+					.iter()
+					.find(|entry| entry.asset.asset.id == asset_id)
+					.expect("asset is in the portfolio")
+			};
+
+			let base_entry = entry(base);
+			assert_eq!(base_entry.free, 900 * UNIT);
+			assert_eq!(base_entry.liquidity, redeemable[&base]);
+			assert_eq!(base_entry.value, Some(2 * (900 * UNIT + redeemable[&base])));
+
+			let quote_entry = entry(quote);
+			assert_eq!(quote_entry.free, 900 * UNIT);
+			assert_eq!(quote_entry.liquidity, redeemable[&quote]);
+			assert_eq!(quote_entry.value, None);
+		});
+	}
+}
@@ -0,0 +1,71 @@
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Estimated weights for `airdrop`.
+///
+/// `benchmark pallet` has not been run for `airdrop` yet, execution times are estimates. Proof
+/// sizes assume every storage read proves a map entry of up to 1 KiB, 3_600 bytes with its trie
+/// nodes. Replace this file with the benchmark output before relying on it.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> airdrop::WeightInfo for WeightInfo<T> {
+	fn create_airdrop() -> Weight {
+		Weight::from_parts(50_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 10_800))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn add_recipient(x: u32) -> Weight {
+		Weight::from_parts(40_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(9_000_000_u64, 0).saturating_mul(x as u64))
+			.saturating_add(Weight::from_parts(0, 10_800))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(Weight::from_parts(0, 3_600).saturating_mul(x as u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x as u64)))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x as u64)))
+	}
+	fn remove_recipient() -> Weight {
+		Weight::from_parts(60_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 10_800))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn commit_recipients() -> Weight {
+		Weight::from_parts(50_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 10_800))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn enable_airdrop() -> Weight {
+		Weight::from_parts(30_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 3_600))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn disable_airdrop(x: u32) -> Weight {
+		Weight::from_parts(70_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(2_000_000_u64, 0).saturating_mul(x as u64))
+			.saturating_add(Weight::from_parts(0, 10_800))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x as u64)))
+	}
+	fn claim(x: u32) -> Weight {
+		Weight::from_parts(150_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(10_000_u64, 0).saturating_mul(x as u64))
+			.saturating_add(Weight::from_parts(0, 14_400))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn claim_committed(x: u32) -> Weight {
+		Weight::from_parts(150_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(500_000_u64, 0).saturating_mul(x as u64))
+			.saturating_add(Weight::from_parts(0, 14_400))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+}
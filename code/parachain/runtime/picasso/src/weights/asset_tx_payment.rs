@@ -35,4 +35,14 @@ impl<T: asset_tx_payment::Config> asset_tx_payment::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	fn set_sponsorship_policy() -> Weight {
+		Weight::from_parts(16_104_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn remove_sponsorship_policy() -> Weight {
+		Weight::from_parts(19_562_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
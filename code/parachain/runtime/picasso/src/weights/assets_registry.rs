@@ -102,4 +102,94 @@ impl<T: frame_system::Config> assets_registry::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: AssetsRegistry ExistentialDeposit (r:1 w:0)
+	/// Proof: AssetsRegistry ExistentialDeposit (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: AssetsRegistry IbcDenomHashToLocal (r:1 w:2)
+	/// Proof: AssetsRegistry IbcDenomHashToLocal (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: AssetsRegistry ExtendedMetadata (r:1 w:1)
+	/// Proof: AssetsRegistry ExtendedMetadata (max_values: None, max_size: Some(534), added: 3009, mode: MaxEncodedLen)
+	fn set_extended_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `9555`
+		// Minimum execution time: 42_215 nanoseconds.
+		Weight::from_parts(44_510_000, 0)
+			.saturating_add(Weight::from_parts(0, 9555))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: AssetsRegistry ForeignToLocal (r:1 w:0)
+	/// Proof: AssetsRegistry ForeignToLocal (max_values: None, max_size: Some(2081), added: 4556, mode: MaxEncodedLen)
+	/// Storage: AssetsRegistry ProposedLocations (r:1 w:1)
+	/// Proof: AssetsRegistry ProposedLocations (max_values: None, max_size: Some(2085), added: 4560, mode: MaxEncodedLen)
+	/// Storage: AssetsRegistry ProposalCount (r:1 w:1)
+	/// Proof: AssetsRegistry ProposalCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: AssetsRegistry ProposalsDue (r:1 w:1)
+	/// Proof: AssetsRegistry ProposalsDue (max_values: None, max_size: Some(145), added: 2620, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AssetsRegistry AssetProposals (r:0 w:1)
+	/// Proof: AssetsRegistry AssetProposals (max_values: None, max_size: Some(2524), added: 4999, mode: MaxEncodedLen)
+	fn propose_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `985`
+		//  Estimated: `17841`
+		// Minimum execution time: 33_914 nanoseconds.
+		Weight::from_parts(33_914_000, 0)
+			.saturating_add(Weight::from_parts(0, 17841))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: AssetsRegistry AssetProposals (r:1 w:1)
+	/// Proof: AssetsRegistry AssetProposals (max_values: None, max_size: Some(2524), added: 4999, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AssetsRegistry ExistentialDeposit (r:1 w:1)
+	/// Proof: AssetsRegistry ExistentialDeposit (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: AssetsRegistry ForeignToLocal (r:1 w:1)
+	/// Proof: AssetsRegistry ForeignToLocal (max_values: None, max_size: Some(2081), added: 4556, mode: MaxEncodedLen)
+	/// Storage: AssetsRegistry LocalToForeign (r:1 w:1)
+	/// Proof: AssetsRegistry LocalToForeign (max_values: None, max_size: Some(2081), added: 4556, mode: MaxEncodedLen)
+	/// Storage: AssetsRegistry MinFeeAmounts (r:0 w:8)
+	/// Proof: AssetsRegistry MinFeeAmounts (max_values: None, max_size: Some(2101), added: 4576, mode: MaxEncodedLen)
+	fn approve_asset_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3214`
+		//  Estimated: `19237`
+		// Minimum execution time: 71_306 nanoseconds.
+		Weight::from_parts(71_306_000, 0)
+			.saturating_add(Weight::from_parts(0, 19237))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(18))
+	}
+	/// Storage: AssetsRegistry AssetProposals (r:1 w:1)
+	/// Proof: AssetsRegistry AssetProposals (max_values: None, max_size: Some(2524), added: 4999, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AssetsRegistry ProposedLocations (r:0 w:1)
+	/// Proof: AssetsRegistry ProposedLocations (max_values: None, max_size: Some(2085), added: 4560, mode: MaxEncodedLen)
+	fn reject_asset_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1187`
+		//  Estimated: `10205`
+		// Minimum execution time: 41_523 nanoseconds.
+		Weight::from_parts(41_523_000, 0)
+			.saturating_add(Weight::from_parts(0, 10205))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: AssetsRegistry ExistentialDeposit (r:1 w:0)
+	/// Proof: AssetsRegistry ExistentialDeposit (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: AssetsRegistry FeePriceSources (r:0 w:1)
+	/// Proof: AssetsRegistry FeePriceSources (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	fn set_fee_price_source() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `258`
+		//  Estimated: `2523`
+		// Minimum execution time: 16_208 nanoseconds.
+		Weight::from_parts(16_208_000, 0)
+			.saturating_add(Weight::from_parts(0, 2523))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -0,0 +1,33 @@
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Estimated weights for `bonded_finance`.
+///
+/// `benchmark pallet` has not been run for `bonded_finance` yet, execution times are estimates.
+/// Proof sizes assume every storage read proves a map entry of up to 1 KiB, 3_600 bytes with its
+/// trie nodes. Replace this file with the benchmark output before relying on it.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> bonded_finance::WeightInfo for WeightInfo<T> {
+	fn offer() -> Weight {
+		Weight::from_parts(60_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 14_400))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn bond() -> Weight {
+		Weight::from_parts(120_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 36_000))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	fn cancel() -> Weight {
+		Weight::from_parts(60_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 14_400))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+}
@@ -0,0 +1,39 @@
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Default weights.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> dex_router::WeightInfo for WeightInfo<T> {
+	fn update_route() -> Weight {
+		Weight::from_parts(40_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn swap() -> Weight {
+		Weight::from_parts(200_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(16_u64))
+	}
+	fn buy() -> Weight {
+		Weight::from_parts(240_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(24_u64))
+			.saturating_add(RocksDbWeight::get().writes(16_u64))
+	}
+	fn add_liquidity() -> Weight {
+		Weight::from_parts(120_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	fn remove_liquidity() -> Weight {
+		Weight::from_parts(120_000_000_u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+}
@@ -0,0 +1,40 @@
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Estimated weights for `dutch_auction`.
+///
+/// `benchmark pallet` has not been run for `dutch_auction` yet, execution times are estimates.
+/// Proof sizes assume every storage read proves a map entry of up to 1 KiB, 3_600 bytes with its
+/// trie nodes. Replace this file with the benchmark output before relying on it.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> dutch_auction::WeightInfo for WeightInfo<T> {
+	fn ask() -> Weight {
+		Weight::from_parts(50_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 14_400))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	fn take() -> Weight {
+		Weight::from_parts(60_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 32_400))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	fn cancel() -> Weight {
+		Weight::from_parts(40_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 28_800))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	fn close() -> Weight {
+		// Up to `MaxHopsInRoute` pablo swaps along the fallback route.
+		Weight::from_parts(700_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 176_400))
+			.saturating_add(T::DbWeight::get().reads(49_u64))
+			.saturating_add(T::DbWeight::get().writes(32_u64))
+	}
+}
@@ -0,0 +1,21 @@
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Estimated weights for `fnft`.
+///
+/// `benchmark pallet` has not been run for `fnft` yet, execution times are estimates. Proof sizes
+/// assume every storage read proves a map entry of up to 1 KiB, 3_600 bytes with its trie nodes.
+/// Replace this file with the benchmark output before relying on it.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> fnft::WeightInfo for WeightInfo<T> {
+	fn transfer() -> Weight {
+		Weight::from_parts(90_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 10_800))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+}
@@ -0,0 +1,79 @@
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Estimated weights for `lending`.
+///
+/// `benchmark pallet` has not been run for `lending` yet, execution times are estimates. Proof
+/// sizes assume every storage read proves a map entry of up to 1 KiB, 3_600 bytes with its trie
+/// nodes. Replace this file with the benchmark output before relying on it.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> lending::WeightInfo for WeightInfo<T> {
+	fn create_market() -> Weight {
+		Weight::from_parts(60_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 10_800))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	fn update_market() -> Weight {
+		Weight::from_parts(40_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 10_800))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn deposit() -> Weight {
+		Weight::from_parts(70_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 25_200))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn withdraw() -> Weight {
+		Weight::from_parts(70_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 25_200))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn deposit_collateral() -> Weight {
+		Weight::from_parts(50_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 14_400))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn withdraw_collateral() -> Weight {
+		Weight::from_parts(70_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 28_800))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn borrow() -> Weight {
+		Weight::from_parts(80_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 32_400))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn repay_borrow() -> Weight {
+		Weight::from_parts(60_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 18_000))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn liquidate(b: u32) -> Weight {
+		Weight::from_parts(40_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(60_000_000_u64, 0).saturating_mul(b as u64))
+			.saturating_add(Weight::from_parts(0, 14_400))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(Weight::from_parts(0, 21_600).saturating_mul(b as u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(b as u64)))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(b as u64)))
+	}
+	fn withdraw_reserves() -> Weight {
+		Weight::from_parts(50_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 14_400))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
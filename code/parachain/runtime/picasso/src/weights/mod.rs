@@ -1,13 +1,19 @@
 #![allow(clippy::unnecessary_cast)]
+pub mod airdrop;
 pub mod asset_tx_payment;
 pub mod assets_registry;
 pub mod balances;
+pub mod bonded_finance;
 pub mod collator_selection;
 pub mod collective;
 pub mod crowdloan_rewards;
+pub mod dex_router;
+pub mod dutch_auction;
+pub mod fnft;
 pub mod frame_system;
 pub mod identity;
 pub mod indices;
+pub mod lending;
 pub mod membership;
 pub mod multisig;
 pub mod oracle;
@@ -17,8 +23,10 @@ pub mod pallet_xcm;
 pub mod proxy;
 pub mod scheduler;
 pub mod session;
+pub mod staking_rewards;
 pub mod timestamp;
 pub mod tokens;
 pub mod treasury;
 pub mod utility;
+pub mod vault;
 pub mod vesting;
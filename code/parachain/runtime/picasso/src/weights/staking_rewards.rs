@@ -0,0 +1,104 @@
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Estimated weights for `staking_rewards`.
+///
+/// `benchmark pallet` has not been run for `staking_rewards` yet, execution times are estimates.
+/// Proof sizes assume every storage read proves a map entry of up to 1 KiB, 3_600 bytes with its
+/// trie nodes. Replace this file with the benchmark output before relying on it.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> staking_rewards::WeightInfo for WeightInfo<T> {
+	fn create_reward_pool(r: u32) -> Weight {
+		Weight::from_parts(110_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(2_000_000_u64, 0).saturating_mul(r as u64))
+			.saturating_add(Weight::from_parts(0, 18_000))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	fn stake(r: u32) -> Weight {
+		Weight::from_parts(180_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(6_000_000_u64, 0).saturating_mul(r as u64))
+			.saturating_add(Weight::from_parts(0, 32_400))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(Weight::from_parts(0, 3_600).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	fn extend(r: u32) -> Weight {
+		Weight::from_parts(160_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(6_000_000_u64, 0).saturating_mul(r as u64))
+			.saturating_add(Weight::from_parts(0, 28_800))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(Weight::from_parts(0, 3_600).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	fn unstake(r: u32) -> Weight {
+		Weight::from_parts(220_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(12_000_000_u64, 0).saturating_mul(r as u64))
+			.saturating_add(Weight::from_parts(0, 36_000))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(Weight::from_parts(0, 7_200).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r as u64)))
+	}
+	fn split(r: u32) -> Weight {
+		Weight::from_parts(190_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(2_000_000_u64, 0).saturating_mul(r as u64))
+			.saturating_add(Weight::from_parts(0, 32_400))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	fn merge(r: u32) -> Weight {
+		Weight::from_parts(170_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(2_000_000_u64, 0).saturating_mul(r as u64))
+			.saturating_add(Weight::from_parts(0, 32_400))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	fn claim(r: u32) -> Weight {
+		Weight::from_parts(90_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(12_000_000_u64, 0).saturating_mul(r as u64))
+			.saturating_add(Weight::from_parts(0, 14_400))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(Weight::from_parts(0, 7_200).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r as u64)))
+	}
+	fn extend_duration(r: u32) -> Weight {
+		Weight::from_parts(110_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(6_000_000_u64, 0).saturating_mul(r as u64))
+			.saturating_add(Weight::from_parts(0, 18_000))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn add_to_rewards_pot() -> Weight {
+		Weight::from_parts(60_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 10_800))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn update_rewards_pool(r: u32) -> Weight {
+		Weight::from_parts(40_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(3_000_000_u64, 0).saturating_mul(r as u64))
+			.saturating_add(Weight::from_parts(0, 3_600))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn accumulate_rewards(r: u32) -> Weight {
+		Weight::from_parts(10_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(25_000_000_u64, 0).saturating_mul(r as u64))
+			.saturating_add(Weight::from_parts(0, 3_600))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(Weight::from_parts(0, 7_200).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r as u64)))
+	}
+}
@@ -0,0 +1,75 @@
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Estimated weights for `vault`.
+///
+/// `benchmark pallet` has not been run for `vault` yet, execution times are estimates. Proof
+/// sizes assume every storage read proves a map entry of up to 1 KiB, 3_600 bytes with its trie
+/// nodes. Replace this file with the benchmark output before relying on it.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> vault::WeightInfo for WeightInfo<T> {
+	fn create() -> Weight {
+		Weight::from_parts(70_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 14_400))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	fn claim_surcharge() -> Weight {
+		Weight::from_parts(40_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 10_800))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn add_surcharge() -> Weight {
+		Weight::from_parts(40_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 10_800))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn delete_tombstoned() -> Weight {
+		Weight::from_parts(90_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 82_800))
+			.saturating_add(T::DbWeight::get().reads(23_u64))
+			.saturating_add(T::DbWeight::get().writes(25_u64))
+	}
+	fn deposit() -> Weight {
+		Weight::from_parts(85_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 79_200))
+			.saturating_add(T::DbWeight::get().reads(22_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	fn withdraw() -> Weight {
+		Weight::from_parts(85_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 79_200))
+			.saturating_add(T::DbWeight::get().reads(22_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn emergency_shutdown() -> Weight {
+		Weight::from_parts(20_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 3_600))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn start() -> Weight {
+		Weight::from_parts(20_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 3_600))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn liquidate() -> Weight {
+		Weight::from_parts(25_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 7_200))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn report() -> Weight {
+		Weight::from_parts(45_000_000_u64, 0)
+			.saturating_add(Weight::from_parts(0, 72_000))
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}